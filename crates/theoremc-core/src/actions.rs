@@ -0,0 +1,283 @@
+//! Action manifest loading.
+//!
+//! An action manifest (conventionally `<name>.actions.yaml`) declares the
+//! Rust signature of every action theorems may call: its canonical name,
+//! ordered parameter names and types, return type, and whether it can fail.
+//! An optional `Namespaces` section maps action namespaces to the Rust
+//! modules that implement them.
+//! Manifests are loaded separately from `.theorem` files with the same
+//! strict unknown-key rejection and the same structured diagnostics.
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_saphyr::{Location, Spanned};
+
+use crate::schema::{
+    ActionSignature, SchemaDiagnosticCode, SchemaError, SourceId, Symbol, create_diagnostic,
+    deserialize_failure, rust_type, validate_canonical_action_name, validate_identifier,
+};
+
+#[path = "actions_bindings.rs"]
+mod bindings;
+#[path = "actions_calls.rs"]
+mod calls;
+#[path = "actions_drift.rs"]
+mod drift;
+#[path = "actions_namespace.rs"]
+mod namespace;
+#[path = "actions_registry.rs"]
+mod registry;
+#[path = "actions_skeleton.rs"]
+mod skeleton;
+
+pub use bindings::BindingEnvironment;
+pub(crate) use calls::{CallFailure, CallSite, check_action_calls};
+pub use drift::{ManifestDrift, SourceScanError, verify_against_source};
+pub use registry::{
+    RegisteredAction, collect_registered_actions, registered_actions, render_action_manifest,
+};
+pub use skeleton::render_trait_skeleton;
+
+/// Synthetic source identifier used by [`load_action_manifest`].
+const INLINE_SOURCE: &str = "<inline>";
+
+/// The actions declared by one manifest, keyed by canonical action name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionManifest {
+    /// Declared actions in manifest order.
+    pub actions: IndexMap<String, ManifestAction>,
+    /// Rust module paths keyed by action namespace, such as `account` or
+    /// `hnsw.graph`.
+    pub namespaces: IndexMap<String, String>,
+}
+
+impl ActionManifest {
+    /// Returns the declaration for `action`, if the manifest has one.
+    #[must_use]
+    pub fn get(&self, action: &str) -> Option<&ManifestAction> {
+        self.actions.get(action)
+    }
+
+    /// Returns the fully qualified Rust path of the function implementing
+    /// `action`, when a `Namespaces` entry covers it.
+    ///
+    /// The longest matching namespace wins, and the action's remaining
+    /// segments become the path below that namespace's module.
+    ///
+    ///     use theoremc_core::actions::load_action_manifest;
+    ///
+    ///     let manifest = load_action_manifest(
+    ///         "Namespaces:\n  hnsw: crate::proof_actions::hnsw\nActions: {}\n",
+    ///     )
+    ///     .unwrap();
+    ///     assert_eq!(
+    ///         manifest.call_path("hnsw.graph.insert").as_deref(),
+    ///         Some("crate::proof_actions::hnsw::graph::insert"),
+    ///     );
+    ///     assert_eq!(manifest.call_path("account.deposit"), None);
+    #[must_use]
+    pub fn call_path(&self, action: &str) -> Option<String> {
+        namespace::call_path(&self.namespaces, action)
+    }
+}
+
+/// One action declared in an [`ActionManifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestAction {
+    /// Ordered parameter names and Rust type strings.
+    pub params: IndexMap<String, String>,
+    /// Rust return type. Omitted declarations default to unit.
+    pub returns: String,
+    /// Whether the action can fail. Defaults to `true` when `returns` names
+    /// a `Result` type.
+    pub fallible: bool,
+}
+
+impl ManifestAction {
+    /// Creates a declaration whose fallibility follows its return type, as
+    /// when a manifest omits `fallible`.
+    ///
+    ///     use theoremc_core::actions::ManifestAction;
+    ///
+    ///     let action = ManifestAction::new([], "Result<u64, LedgerError>");
+    ///     assert!(action.fallible);
+    #[must_use]
+    pub fn new(
+        params: impl IntoIterator<Item = (String, String)>,
+        returns: impl Into<String>,
+    ) -> Self {
+        let return_type = returns.into();
+        Self {
+            params: params.into_iter().collect(),
+            fallible: rust_type::is_result(&return_type),
+            returns: return_type,
+        }
+    }
+
+    /// Returns the declaration as a theorem-side [`ActionSignature`].
+    #[must_use]
+    pub fn signature(&self) -> ActionSignature {
+        ActionSignature {
+            params: self
+                .params
+                .iter()
+                .map(|(name, ty)| (name.clone(), Symbol::from(ty)))
+                .collect(),
+            returns: Symbol::from(&self.returns),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawActionManifest {
+    #[serde(rename = "Actions", alias = "actions")]
+    actions: IndexMap<String, Spanned<RawManifestAction>>,
+    #[serde(default, rename = "Namespaces", alias = "namespaces")]
+    namespaces: IndexMap<String, Spanned<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawManifestAction {
+    #[serde(default)]
+    params: IndexMap<String, Spanned<String>>,
+    #[serde(default)]
+    returns: Option<Spanned<String>>,
+    #[serde(default)]
+    fallible: Option<Spanned<bool>>,
+}
+
+/// Loads an action manifest from a YAML string.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_action_manifest_with_source`].
+///
+/// # Examples
+///
+///     use theoremc_core::actions::load_action_manifest;
+///
+///     let yaml = r#"
+///     Actions:
+///       account.deposit:
+///         params:
+///           account: "&mut Account"
+///           amount: u64
+///         returns: "Result<u64, LedgerError>"
+///     "#;
+///     let manifest = load_action_manifest(yaml).unwrap();
+///     assert!(manifest.get("account.deposit").unwrap().fallible);
+pub fn load_action_manifest(input: &str) -> Result<ActionManifest, SchemaError> {
+    load_action_manifest_with_source(&SourceId::new(INLINE_SOURCE), input)
+}
+
+/// Loads an action manifest from YAML and records diagnostics against an
+/// explicit source identifier.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::Deserialize`] when the YAML is malformed, declares
+/// an unknown key, or repeats an action name. Returns
+/// [`SchemaError::InvalidActionManifest`] when an action name is not
+/// canonical, a parameter name is not a valid identifier, a type does not
+/// parse as a Rust type, an action declared `fallible: true` does not
+/// return a `Result`, or a namespace is not a dot-separated run of
+/// identifiers mapped to a plain Rust path.
+pub fn load_action_manifest_with_source(
+    source: &SourceId,
+    input: &str,
+) -> Result<ActionManifest, SchemaError> {
+    let raw: RawActionManifest = serde_saphyr::from_str(input)
+        .map_err(|error| deserialize_failure(source, input, &error))?;
+    let mut manifest = ActionManifest::default();
+    for (name, entry) in raw.actions {
+        let action = convert_action(&name, &entry)
+            .map_err(|(reason, location)| invalid_entry(source, &name, reason, location))?;
+        manifest.actions.insert(name, action);
+    }
+    for (name, module) in raw.namespaces {
+        let path = namespace::check_namespace(&name, &module)
+            .map_err(|(reason, location)| invalid_entry(source, &name, reason, location))?;
+        manifest.namespaces.insert(name, path);
+    }
+    Ok(manifest)
+}
+
+type EntryResult<T> = Result<T, (String, Location)>;
+
+fn convert_action(name: &str, entry: &Spanned<RawManifestAction>) -> EntryResult<ManifestAction> {
+    validate_canonical_action_name(name).map_err(|error| (error.to_string(), entry.referenced))?;
+    let mut params = IndexMap::with_capacity(entry.value.params.len());
+    for (param, ty) in &entry.value.params {
+        validate_identifier(param).map_err(|error| (format!("param {error}"), ty.referenced))?;
+        check_type(ty, &format!("param '{param}' type"))?;
+        params.insert(param.clone(), ty.value.clone());
+    }
+    let returns = match &entry.value.returns {
+        Some(returns) => {
+            check_type(returns, "returns type")?;
+            returns.value.clone()
+        }
+        None => "()".to_owned(),
+    };
+    let fallible = check_fallible(entry.value.fallible.as_ref(), &returns)?;
+    Ok(ManifestAction {
+        params,
+        returns,
+        fallible,
+    })
+}
+
+fn check_type(ty: &Spanned<String>, context: &str) -> EntryResult<()> {
+    match rust_type::parse_with_free_named_lifetime(&ty.value) {
+        Ok(None) => Ok(()),
+        Ok(Some(lifetime)) => Err((
+            format!(
+                "{context} contains a free named lifetime parameter '{lifetime}'; \
+                 use an owned type or an elided lifetime"
+            ),
+            ty.referenced,
+        )),
+        Err(error) => Err((
+            format!("{context} is not a valid Rust type: {error}"),
+            ty.referenced,
+        )),
+    }
+}
+
+/// Resolves `fallible`, defaulting to whether `returns` is a `Result`.
+fn check_fallible(fallible: Option<&Spanned<bool>>, returns: &str) -> EntryResult<bool> {
+    let is_result = rust_type::is_result(returns);
+    match fallible {
+        None => Ok(is_result),
+        Some(declared) if declared.value && !is_result => Err((
+            format!("fallible actions must return a Result, found '{returns}'"),
+            declared.referenced,
+        )),
+        Some(declared) => Ok(declared.value),
+    }
+}
+
+fn invalid_entry(
+    source: &SourceId,
+    action: &str,
+    reason: String,
+    location: Location,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        location,
+    );
+    SchemaError::InvalidActionManifest {
+        action: action.to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+#[cfg(test)]
+#[path = "actions_tests.rs"]
+mod tests;
@@ -0,0 +1,136 @@
+//! Argument type checks for action calls checked against a manifest.
+//!
+//! Only scalar parameter types are checked: Rust integers, floats, `bool`,
+//! `char`, `String`, and `str`, behind any number of references. Literal
+//! arguments must fit the parameter's scalar type, and `ref` arguments must
+//! name a binding of the same scalar type, as recorded in the theorem's
+//! [`BindingEnvironment`]. Other parameter types, and bindings whose type is
+//! not known, are left to rustc.
+
+use syn::{Type, TypePath};
+
+use crate::actions::BindingEnvironment;
+use crate::schema::{ArgValue, LiteralValue, rust_type};
+
+/// Scalar Rust types that argument values can be checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scalar {
+    /// A primitive integer, with its inclusive value range. Integer types
+    /// with the same range, such as `u64` and `usize`, are told apart by
+    /// name.
+    Integer(&'static str, i128, i128),
+    /// `f32` or `f64`.
+    Float,
+    /// `bool`.
+    Bool,
+    /// `String` or `str`.
+    Text,
+    /// `char`.
+    Char,
+}
+
+/// Checks one argument against the declared parameter type.
+pub(super) fn check_argument(
+    param_type: &str,
+    value: &ArgValue,
+    bindings: &BindingEnvironment,
+) -> Result<(), String> {
+    let Some(expected) = scalar(param_type) else {
+        return Ok(());
+    };
+    match value {
+        ArgValue::Literal(literal) => check_literal(expected, literal, param_type),
+        ArgValue::Reference(name) => check_reference(expected, name, bindings).map_err(|bound| {
+            format!("expected '{param_type}', found ref '{name}' of type '{bound}'")
+        }),
+        ArgValue::Expr(_) | ArgValue::RawSequence(_) | ArgValue::RawMap(_) => Ok(()),
+    }
+}
+
+/// Checks that `literal` is a value of the `expected` scalar type.
+fn check_literal(expected: Scalar, literal: &LiteralValue, param_type: &str) -> Result<(), String> {
+    let fits = match (expected, literal) {
+        (Scalar::Integer(_, min, max), LiteralValue::Integer(value)) => {
+            (min..=max).contains(&i128::from(*value))
+        }
+        (Scalar::Float, LiteralValue::Integer(_) | LiteralValue::Float(_))
+        | (Scalar::Bool, LiteralValue::Bool(_))
+        | (Scalar::Text, LiteralValue::String(_)) => true,
+        (Scalar::Char, LiteralValue::String(text)) => text.chars().count() == 1,
+        _ => false,
+    };
+    if fits {
+        return Ok(());
+    }
+    let found = match literal {
+        LiteralValue::Integer(value) if matches!(expected, Scalar::Integer(..)) => {
+            return Err(format!(
+                "integer {value} is out of range for '{param_type}'"
+            ));
+        }
+        LiteralValue::Integer(value) => format!("integer {value}"),
+        LiteralValue::Float(value) => format!("float {value}"),
+        LiteralValue::Bool(value) => format!("bool {value}"),
+        LiteralValue::String(text) => format!("string '{text}'"),
+    };
+    Err(format!("expected '{param_type}', found {found}"))
+}
+
+/// Returns the bound type of `name` when it is a different scalar type.
+fn check_reference(
+    expected: Scalar,
+    name: &str,
+    bindings: &BindingEnvironment,
+) -> Result<(), String> {
+    let Some(bound) = bindings.get(name) else {
+        return Ok(());
+    };
+    match scalar(bound) {
+        Some(found) if found != expected => Err(bound.to_owned()),
+        _ => Ok(()),
+    }
+}
+
+/// Classifies `ty`, looking through references, as a checked scalar type.
+fn scalar(ty: &str) -> Option<Scalar> {
+    let parsed = rust_type::parse(ty).ok()?;
+    let Type::Path(TypePath { qself: None, path }) = referent(&parsed) else {
+        return None;
+    };
+    let ident = path.get_ident()?.to_string();
+    integer(&ident).or(match ident.as_str() {
+        "f32" | "f64" => Some(Scalar::Float),
+        "bool" => Some(Scalar::Bool),
+        "String" | "str" => Some(Scalar::Text),
+        "char" => Some(Scalar::Char),
+        _ => None,
+    })
+}
+
+fn referent(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => referent(&reference.elem),
+        Type::Paren(paren) => referent(&paren.elem),
+        Type::Group(group) => referent(&group.elem),
+        other => other,
+    }
+}
+
+fn integer(ident: &str) -> Option<Scalar> {
+    let (name, min, max): (&'static str, i128, i128) = match ident {
+        "i8" => ("i8", i8::MIN.into(), i8::MAX.into()),
+        "i16" => ("i16", i16::MIN.into(), i16::MAX.into()),
+        "i32" => ("i32", i32::MIN.into(), i32::MAX.into()),
+        "i64" => ("i64", i64::MIN.into(), i64::MAX.into()),
+        "i128" => ("i128", i128::MIN, i128::MAX),
+        "isize" => ("isize", i64::MIN.into(), i64::MAX.into()),
+        "u8" => ("u8", 0, u8::MAX.into()),
+        "u16" => ("u16", 0, u16::MAX.into()),
+        "u32" => ("u32", 0, u32::MAX.into()),
+        "u64" => ("u64", 0, u64::MAX.into()),
+        "u128" => ("u128", 0, i128::MAX),
+        "usize" => ("usize", 0, u64::MAX.into()),
+        _ => return None,
+    };
+    Some(Scalar::Integer(name, min, max))
+}
@@ -0,0 +1,163 @@
+//! Rust types of theorem bindings, propagated from action return types.
+//!
+//! A theorem binds names in three places: `Forall` variables, `Let`
+//! bindings, and the `as` names of `call` and `must` steps in `Do`. Forall
+//! variables carry their declared types. Action results take their type from
+//! the manifest's `returns`: a `call` binds the value as returned, while a
+//! `must` binds the success value of a `Result` or `Option`. Checks over
+//! argument and assertion expressions, and code generation, read the same
+//! [`BindingEnvironment`].
+
+use indexmap::IndexMap;
+use quote::ToTokens;
+use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+use super::ActionManifest;
+use crate::schema::{ActionCall, LetBinding, Step, TheoremDoc, rust_type};
+
+/// The Rust type of every name a theorem binds whose type is known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BindingEnvironment {
+    types: IndexMap<String, String>,
+}
+
+impl BindingEnvironment {
+    /// Collects binding types from `doc`, in binding order: `Forall`
+    /// variables, then `Let` bindings, then `Do` step `as` names, including
+    /// those of nested steps. Results of actions the manifest does not
+    /// declare have no known type and are left out.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::actions::{BindingEnvironment, load_action_manifest};
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let manifest = load_action_manifest(concat!(
+    ///         "Actions:\n",
+    ///         "  account.open:\n",
+    ///         "    returns: 'Result<u64, LedgerError>'\n",
+    ///     ))
+    ///     .unwrap();
+    ///     let docs = load_theorem_docs(concat!(
+    ///         "Theorem: Opening\n",
+    ///         "About: opening an account yields an id\n",
+    ///         "Actions:\n",
+    ///         "  account.open:\n",
+    ///         "    returns: 'Result<u64, LedgerError>'\n",
+    ///         "Let:\n",
+    ///         "  id:\n",
+    ///         "    must: { action: account.open, args: {} }\n",
+    ///         "Prove:\n",
+    ///         "  - assert: 'id > 0'\n",
+    ///         "    because: ids start at one\n",
+    ///         "Evidence:\n",
+    ///         "  kani: { unwind: 1, expect: SUCCESS }\n",
+    ///         "Witness:\n",
+    ///         "  - cover: 'true'\n",
+    ///         "    because: always reachable\n",
+    ///     ))
+    ///     .unwrap();
+    ///     let bindings = BindingEnvironment::new(&docs[0], &manifest);
+    ///     assert_eq!(bindings.get("id"), Some("u64"));
+    #[must_use]
+    pub fn new(doc: &TheoremDoc, manifest: &ActionManifest) -> Self {
+        let mut environment = Self {
+            types: doc
+                .forall
+                .iter()
+                .map(|(name, ty)| (name.as_str().to_owned(), ty.to_string()))
+                .collect(),
+        };
+        for (name, binding) in doc.let_bindings.iter() {
+            let (call, must) = match binding {
+                LetBinding::Call(c) => (&c.call, false),
+                LetBinding::Must(m) => (&m.must, true),
+            };
+            environment.bind(name, result_type(call, must, manifest));
+        }
+        for step in doc.do_steps.iter() {
+            environment.bind_step(step, manifest);
+        }
+        environment
+    }
+
+    /// Returns the Rust type bound to `name`, if it is known.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.types.get(name).map(String::as_str)
+    }
+
+    /// Iterates over bound names and their Rust types, in binding order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.types
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ty.as_str()))
+    }
+
+    fn bind_step(&mut self, step: &Step, manifest: &ActionManifest) {
+        let (call, must) = match step {
+            Step::Call(s) => (&s.call, false),
+            Step::Must(s) => (&s.must, true),
+            Step::Maybe(_) | Step::Repeat(_) | Step::Foreach(_) => {
+                for nested in step
+                    .nested_branches()
+                    .into_iter()
+                    .flat_map(|(_, steps)| steps)
+                {
+                    self.bind_step(nested, manifest);
+                }
+                return;
+            }
+            Step::ExpectErr(_) | Step::Assert(_) => return,
+        };
+        if let Some(name) = &call.as_binding {
+            self.bind(name, result_type(call, must, manifest));
+        }
+    }
+
+    fn bind(&mut self, name: &str, bound: Option<String>) {
+        if let Some(ty) = bound {
+            self.types.insert(name.to_owned(), ty);
+        }
+    }
+}
+
+/// Returns the type `call` binds, or `None` when its action is undeclared.
+fn result_type(call: &ActionCall, must: bool, manifest: &ActionManifest) -> Option<String> {
+    let declared = manifest.get(&call.action)?;
+    Some(if must {
+        success_type(&declared.returns)
+    } else {
+        declared.returns.clone()
+    })
+}
+
+/// Returns the type a `must` call binds: `T` for `Result<T, E>` or
+/// `Option<T>`, and the declared type unchanged otherwise.
+fn success_type(returns: &str) -> String {
+    let Ok(Type::Path(TypePath { qself: None, path })) = rust_type::parse(returns) else {
+        return returns.to_owned();
+    };
+    let Some(segment) = path
+        .segments
+        .last()
+        .filter(|last| last.ident == "Result" || last.ident == "Option")
+    else {
+        return returns.to_owned();
+    };
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return returns.to_owned();
+    };
+    arguments
+        .args
+        .iter()
+        .find_map(|argument| match argument {
+            GenericArgument::Type(success) => Some(success.to_token_stream().to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| returns.to_owned())
+}
+
+#[cfg(test)]
+#[path = "actions_bindings_tests.rs"]
+mod tests;
@@ -0,0 +1,142 @@
+//! Unit tests for binding types propagated from action return types.
+
+use std::io;
+
+use pretty_assertions::assert_eq;
+
+use super::BindingEnvironment;
+use crate::actions::load_action_manifest;
+use crate::schema::{IncludeResolver, SourceId, TheoremDoc, load_theorem_docs_with_manifest};
+
+const MANIFEST: &str = concat!(
+    "Actions:\n",
+    "  account.open:\n",
+    "    returns: 'Result<Account, LedgerError>'\n",
+    "  account.balance:\n",
+    "    params:\n",
+    "      account: '&Account'\n",
+    "    returns: u64\n",
+    "  account.find:\n",
+    "    params:\n",
+    "      id: u64\n",
+    "    returns: 'Option<Account>'\n",
+    "  account.close:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+);
+
+const THEOREM: &str = concat!(
+    "Theorem: Bindings\n",
+    "About: binding types follow action return types\n",
+    "Forall:\n",
+    "  id: u64\n",
+    "Actions:\n",
+    "  account.open:\n",
+    "    returns: 'Result<Account, LedgerError>'\n",
+    "  account.balance:\n",
+    "    params:\n",
+    "      account: '&Account'\n",
+    "    returns: u64\n",
+    "  account.find:\n",
+    "    params:\n",
+    "      id: u64\n",
+    "    returns: 'Option<Account>'\n",
+    "  account.close:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "Let:\n",
+    "  opened:\n",
+    "    must: { action: account.open, args: {} }\n",
+    "  attempt:\n",
+    "    call: { action: account.open, args: {} }\n",
+    "Do:\n",
+    "  - call:\n",
+    "      action: account.balance\n",
+    "      args: { account: { ref: opened } }\n",
+    "      as: before\n",
+    "  - maybe:\n",
+    "      because: the account may be looked up again\n",
+    "      do:\n",
+    "        - call:\n",
+    "            action: account.find\n",
+    "            args: { id: { ref: id } }\n",
+    "            as: found\n",
+    "  - call:\n",
+    "      action: account.close\n",
+    "      args: { account: { ref: opened } }\n",
+    "      as: closed\n",
+    "Prove:\n",
+    "  - assert: '{assertion}'\n",
+    "    because: balances are never negative\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 1\n",
+    "    expect: SUCCESS\n",
+    "Witness:\n",
+    "  - cover: 'true'\n",
+    "    because: always reachable\n",
+);
+
+struct NoFragments;
+
+impl IncludeResolver for NoFragments {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        Err(io::Error::new(io::ErrorKind::NotFound, path.to_owned()))
+    }
+}
+
+fn load(assertion: &str) -> Result<Vec<TheoremDoc>, crate::schema::SchemaError> {
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+    load_theorem_docs_with_manifest(
+        &SourceId::new("bindings.theorem"),
+        &THEOREM.replace("{assertion}", assertion),
+        &NoFragments,
+        &manifest,
+    )
+}
+
+#[test]
+fn bindings_take_forall_and_manifest_return_types_in_binding_order() {
+    let docs = load("before >= 0").expect("theorem should load");
+    let doc = docs.first().expect("one theorem");
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+
+    let bindings = BindingEnvironment::new(doc, &manifest);
+
+    let bound: Vec<(&str, &str)> = bindings.iter().collect();
+    assert_eq!(
+        bound,
+        [
+            ("id", "u64"),
+            ("opened", "Account"),
+            ("attempt", "Result<Account, LedgerError>"),
+            ("before", "u64"),
+            ("found", "Option<Account>"),
+            ("closed", "()"),
+        ]
+    );
+}
+
+#[test]
+fn undeclared_actions_leave_bindings_untyped() {
+    let docs = load("before >= 0").expect("theorem should load");
+    let doc = docs.first().expect("one theorem");
+
+    let bindings = BindingEnvironment::new(doc, &crate::actions::ActionManifest::default());
+
+    assert_eq!(bindings.get("id"), Some("u64"));
+    assert_eq!(bindings.get("opened"), None);
+}
+
+#[test]
+fn assertion_over_unit_result_is_rejected_at_the_assertion() {
+    let error = load("closed == ()").expect_err("unit assertion should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        "validation failed for theorem 'Bindings': Prove assertion 1: 'closed' holds the \
+         unit result of an action, so the assertion cannot check it"
+    );
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.location.line, 41);
+}
@@ -0,0 +1,193 @@
+//! Cross-validation of theorem action calls against an action manifest.
+//!
+//! Every `ActionCall` in `Let` and `Do` must name a declared action, pass
+//! exactly the declared parameters, and only use `must` on an action that
+//! can fail. Scalar arguments must also match their declared parameter
+//! types. `Prove` assertions must not read a binding that holds the unit
+//! result of an action, since such an assertion checks nothing about it.
+//! Failures record the `Let` binding, top-level `Do` step, or `Prove` entry
+//! that holds the problem so the loader can point its diagnostic at it.
+
+#[path = "actions_arg_types.rs"]
+mod arg_types;
+
+use self::arg_types::check_argument;
+use super::{ActionManifest, BindingEnvironment, ManifestAction};
+use crate::schema::{ActionCall, LetBinding, Step, TheoremDoc, referenced_variables, rust_type};
+
+/// Where a rejected action call appears in its theorem document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CallSite {
+    /// The `Let` binding with this name.
+    Let(String),
+    /// The top-level `Do` step at this one-based index. Calls nested in
+    /// `maybe`, `repeat`, or `foreach` blocks report their enclosing step.
+    Do(usize),
+    /// The `Prove` entry at this zero-based index.
+    Prove(usize),
+}
+
+/// An action call that disagrees with the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CallFailure {
+    /// Where the call appears.
+    pub(crate) site: CallSite,
+    /// A human-readable explanation naming the call's position.
+    pub(crate) reason: String,
+}
+
+/// Checks every action call in `doc` against `manifest`.
+pub(crate) fn check_action_calls(
+    doc: &TheoremDoc,
+    manifest: &ActionManifest,
+) -> Result<(), CallFailure> {
+    let checker = CallChecker {
+        manifest,
+        bindings: BindingEnvironment::new(doc, manifest),
+    };
+    for (name, binding) in doc.let_bindings.iter() {
+        let (call, must) = match binding {
+            LetBinding::Call(c) => (&c.call, false),
+            LetBinding::Must(m) => (&m.must, true),
+        };
+        checker
+            .check_call(call, must)
+            .map_err(|reason| CallFailure {
+                site: CallSite::Let(name.clone()),
+                reason: format!("Let binding '{name}': {reason}"),
+            })?;
+    }
+    for (index, step) in doc.do_steps.iter().enumerate() {
+        let pos = index + 1;
+        checker
+            .check_step(step, &format!("Do step {pos}"))
+            .map_err(|reason| CallFailure {
+                site: CallSite::Do(pos),
+                reason,
+            })?;
+    }
+    check_assertions(doc, &checker.bindings)
+}
+
+/// Rejects `Prove` assertions that read a binding holding a unit result.
+fn check_assertions(doc: &TheoremDoc, bindings: &BindingEnvironment) -> Result<(), CallFailure> {
+    for (index, assertion) in doc.prove.iter().enumerate() {
+        let unit = referenced_variables(assertion.assert_expr.as_str())
+            .into_iter()
+            .find(|name| bindings.get(name).is_some_and(is_unit));
+        if let Some(name) = unit {
+            return Err(CallFailure {
+                site: CallSite::Prove(index),
+                reason: format!(
+                    "Prove assertion {}: '{name}' holds the unit result of an action, \
+                     so the assertion cannot check it",
+                    index + 1
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn is_unit(ty: &str) -> bool {
+    rust_type::canonical_token_stream(ty).is_some_and(|canonical| canonical == "()")
+}
+
+/// The manifest and the types `ref` arguments can resolve to.
+struct CallChecker<'a> {
+    manifest: &'a ActionManifest,
+    bindings: BindingEnvironment,
+}
+
+impl CallChecker<'_> {
+    fn check_step(&self, step: &Step, path: &str) -> Result<(), String> {
+        let (call, must) = match step {
+            Step::Call(s) => (&s.call, false),
+            Step::Must(s) => (&s.must, true),
+            Step::ExpectErr(s) => (&s.expect_err, false),
+            Step::Maybe(_) | Step::Repeat(_) | Step::Foreach(_) => {
+                return self.check_nested_steps(step, path);
+            }
+            Step::Assert(_) => return Ok(()),
+        };
+        self.check_call(call, must)
+            .map_err(|reason| format!("{path}: {reason}"))
+    }
+
+    fn check_nested_steps(&self, step: &Step, path: &str) -> Result<(), String> {
+        for (branch, steps) in step.nested_branches() {
+            for (index, nested) in steps.iter().enumerate() {
+                let nested_path = format!("{path}: {}.{branch} step {}", step.keyword(), index + 1);
+                self.check_step(nested, &nested_path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_call(&self, call: &ActionCall, must: bool) -> Result<(), String> {
+        let action = call.action.as_str();
+        let declared = check_signature(self.manifest, call, must)?;
+        for (param, value) in &call.args {
+            let Some(param_type) = declared.params.get(param) else {
+                continue;
+            };
+            check_argument(param_type, value, &self.bindings)
+                .map_err(|reason| format!("action '{action}' argument '{param}': {reason}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `call` names a declared action with exactly its parameters,
+/// returning the declaration.
+fn check_signature<'a>(
+    manifest: &'a ActionManifest,
+    call: &ActionCall,
+    must: bool,
+) -> Result<&'a ManifestAction, String> {
+    let action = call.action.as_str();
+    let Some(declared) = manifest.get(action) else {
+        return Err(format!(
+            "action '{action}' is not declared in the action manifest"
+        ));
+    };
+    if let Some(param) = declared
+        .params
+        .keys()
+        .find(|param| !call.args.contains_key(*param))
+    {
+        return Err(format!("action '{action}' is missing argument '{param}'"));
+    }
+    if let Some(arg) = call
+        .args
+        .keys()
+        .find(|arg| !declared.params.contains_key(*arg))
+    {
+        return Err(format!(
+            "action '{action}' has no parameter '{arg}' (declared parameters: {})",
+            declared_params(declared)
+        ));
+    }
+    if must && !declared.fallible {
+        return Err(format!(
+            "must requires a fallible action, but '{action}' is declared infallible"
+        ));
+    }
+    Ok(declared)
+}
+
+fn declared_params(declared: &ManifestAction) -> String {
+    if declared.params.is_empty() {
+        return "none".to_owned();
+    }
+    declared
+        .params
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+#[path = "actions_calls_tests.rs"]
+mod tests;
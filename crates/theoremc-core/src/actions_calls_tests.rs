@@ -0,0 +1,222 @@
+//! Unit tests for checking theorem action calls against a manifest.
+
+use std::io;
+
+use rstest::rstest;
+
+use crate::actions::load_action_manifest;
+use crate::schema::{
+    IncludeResolver, SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_manifest,
+};
+
+const MANIFEST: &str = concat!(
+    "Actions:\n",
+    "  account.deposit:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "      amount: u64\n",
+    "    returns: 'Result<u64, LedgerError>'\n",
+    "  account.balance:\n",
+    "    params:\n",
+    "      account: '&Account'\n",
+    "    returns: u64\n",
+    "  account.flag:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "      frozen: bool\n",
+);
+
+const DEPOSIT: &str =
+    "account.deposit, args: { account: { ref: ledger }, amount: { ref: amount } }";
+const BALANCE: &str = "account.balance, args: { account: { ref: ledger } }";
+
+struct NoFragments;
+
+impl IncludeResolver for NoFragments {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        Err(io::Error::new(io::ErrorKind::NotFound, path.to_owned()))
+    }
+}
+
+/// Builds a theorem whose `Let` binding `current` calls its action on line
+/// 26 and whose first `Do` step starts on line 29.
+fn theorem(let_call: &str, do_step: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: Ledger\n",
+            "About: manifest call checks\n",
+            "Forall:\n",
+            "  ledger: Account\n",
+            "  amount: u64\n",
+            "  limit: u32\n",
+            "Actions:\n",
+            "  account.deposit:\n",
+            "    params:\n",
+            "      account: '&mut Account'\n",
+            "      amount: u64\n",
+            "    returns: 'Result<u64, LedgerError>'\n",
+            "  account.balance:\n",
+            "    params:\n",
+            "      account: '&Account'\n",
+            "    returns: u64\n",
+            "  account.close:\n",
+            "    params: {{}}\n",
+            "  account.flag:\n",
+            "    params:\n",
+            "      account: '&mut Account'\n",
+            "      frozen: bool\n",
+            "Let:\n",
+            "  current:\n",
+            "{let_call}",
+            "Do:\n",
+            "{do_step}",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "Witness:\n",
+            "  - cover: 'true'\n",
+            "    because: always reachable\n",
+        ),
+        let_call = let_call,
+        do_step = do_step,
+    )
+}
+
+fn let_call(keyword: &str, call: &str) -> String {
+    let (action, args) = call.split_once(", ").unwrap_or((call, "args: {}"));
+    format!("    {keyword}:\n      action: {action}\n      {args}\n")
+}
+
+fn do_step(keyword: &str, call: &str) -> String {
+    let (action, args) = call.split_once(", ").unwrap_or((call, "args: {}"));
+    format!("  - {keyword}:\n      action: {action}\n      {args}\n")
+}
+
+fn load(yaml: &str) -> Result<Vec<TheoremDoc>, SchemaError> {
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+    load_theorem_docs_with_manifest(
+        &SourceId::new("ledger.theorem"),
+        yaml,
+        &NoFragments,
+        &manifest,
+    )
+}
+
+#[rstest]
+#[case::references_and_literals(DEPOSIT)]
+#[case::let_binding_of_matching_type(
+    "account.deposit, args: { account: { ref: ledger }, amount: { ref: current } }"
+)]
+#[case::integer_literal("account.deposit, args: { account: { ref: ledger }, amount: 10 }")]
+#[case::bool_literal("account.flag, args: { account: { ref: ledger }, frozen: true }")]
+fn calls_matching_the_manifest_load(#[case] call: &str) {
+    let yaml = theorem(&let_call("call", BALANCE), &do_step("call", call));
+    let docs = load(&yaml).expect("calls should match the manifest");
+    assert_eq!(docs.len(), 1);
+}
+
+#[rstest]
+#[case::undeclared_let_action(
+    &theorem(&let_call("call", "account.close"), &do_step("must", DEPOSIT)),
+    "Let binding 'current': action 'account.close' is not declared in the action manifest",
+    26
+)]
+#[case::missing_argument(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("call", "account.deposit, args: { account: { ref: ledger } }"),
+    ),
+    "Do step 1: action 'account.deposit' is missing argument 'amount'",
+    30
+)]
+#[case::undeclared_argument(
+    &theorem(
+        &let_call("call", "account.balance, args: { account: { ref: ledger }, amount: { ref: amount } }"),
+        &do_step("must", DEPOSIT),
+    ),
+    "Let binding 'current': action 'account.balance' has no parameter 'amount' \
+     (declared parameters: account)",
+    26
+)]
+#[case::must_on_infallible_action(
+    &theorem(&let_call("must", BALANCE), &do_step("must", DEPOSIT)),
+    "Let binding 'current': must requires a fallible action, \
+     but 'account.balance' is declared infallible",
+    26
+)]
+#[case::nested_must_on_infallible_action(
+    &theorem(
+        &let_call("call", BALANCE),
+        concat!(
+            "  - maybe:\n",
+            "      because: balance may be checked\n",
+            "      do:\n",
+            "        - must:\n",
+            "            action: account.balance\n",
+            "            args: { account: { ref: ledger } }\n",
+        ),
+    ),
+    "Do step 1: maybe.do step 1: must requires a fallible action, \
+     but 'account.balance' is declared infallible",
+    33
+)]
+#[case::string_for_integer(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("must", "account.deposit, args: { account: { ref: ledger }, amount: ten }"),
+    ),
+    "Do step 1: action 'account.deposit' argument 'amount': expected 'u64', found string 'ten'",
+    30
+)]
+#[case::integer_out_of_range(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("must", "account.deposit, args: { account: { ref: ledger }, amount: -1 }"),
+    ),
+    "Do step 1: action 'account.deposit' argument 'amount': integer -1 is out of range for 'u64'",
+    30
+)]
+#[case::string_for_bool(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("call", "account.flag, args: { account: { ref: ledger }, frozen: 'yes' }"),
+    ),
+    "Do step 1: action 'account.flag' argument 'frozen': expected 'bool', found string 'yes'",
+    30
+)]
+#[case::forall_ref_of_other_type(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("must", "account.deposit, args: { account: { ref: ledger }, amount: { ref: limit } }"),
+    ),
+    "Do step 1: action 'account.deposit' argument 'amount': expected 'u64', \
+     found ref 'limit' of type 'u32'",
+    30
+)]
+#[case::let_ref_of_other_type(
+    &theorem(
+        &let_call("call", BALANCE),
+        &do_step("call", "account.flag, args: { account: { ref: ledger }, frozen: { ref: current } }"),
+    ),
+    "Do step 1: action 'account.flag' argument 'frozen': expected 'bool', \
+     found ref 'current' of type 'u64'",
+    30
+)]
+fn mismatched_calls_are_rejected_at_the_call(
+    #[case] yaml: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error = load(yaml).expect_err("call should be rejected");
+    let SchemaError::ValidationFailed { ref reason, .. } = error else {
+        panic!("expected ValidationFailed, got {error}");
+    };
+    assert_eq!(reason, expected);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.location.source, "ledger.theorem");
+    assert_eq!(diagnostic.location.line, line);
+}
@@ -0,0 +1,232 @@
+//! Drift detection between an action manifest and the crate implementing it.
+//!
+//! A manifest is written by hand, so it can fall behind the functions it
+//! describes. [`verify_against_source`] scans the crate's source with `syn`,
+//! finds the function behind each action, and reports every parameter or
+//! return type that no longer matches. It runs in milliseconds, so a build
+//! script or test can call it before any Kani run starts.
+
+#[path = "actions_source.rs"]
+mod source;
+
+use std::fmt;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use cap_std::{ambient_authority, fs_utf8::Dir as Utf8Dir};
+use quote::ToTokens;
+use syn::{FnArg, Pat, ReturnType, Signature, ext::IdentExt};
+
+use self::source::{CrateSource, scan_crate};
+use super::{ActionManifest, ManifestAction};
+use crate::schema::rust_type;
+use crate::theorem_file::io_error_code;
+
+/// One difference between a manifest entry and its implementing function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestDrift {
+    /// Canonical name of the drifted action.
+    pub action: String,
+    /// Path of the implementing function, such as `crate::ledger::deposit`.
+    pub function: String,
+    /// What no longer matches.
+    pub reason: String,
+}
+
+impl fmt::Display for ManifestDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "action '{}' no longer matches `{}`: {}",
+            self.action, self.function, self.reason
+        )
+    }
+}
+
+/// Errors raised while scanning a crate's source for manifest drift.
+#[derive(Debug, thiserror::Error)]
+pub enum SourceScanError {
+    /// The crate's manifest directory could not be opened.
+    #[error(
+        "failed to open crate directory '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    OpenCrateDir {
+        /// Crate directory that failed to open.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A source file of the crate's module tree could not be read.
+    #[error(
+        "failed to read source file '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    ReadSource {
+        /// Crate-relative path of the source file.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A source file did not parse as Rust.
+    #[error("failed to parse source file '{path}': {source}")]
+    ParseSource {
+        /// Crate-relative path of the source file.
+        path: Utf8PathBuf,
+        /// Underlying parse failure.
+        #[source]
+        source: syn::Error,
+    },
+}
+
+/// Compares every action in `manifest` with its function in the crate whose
+/// manifest directory is `crate_dir`, returning the differences in manifest
+/// order.
+///
+/// An action is matched to the function registering it with
+/// `#[theorem_action]`, or else to the function its `Namespaces` entry maps
+/// it to. Actions matched by neither are not checked. Parameter names, their
+/// order, their types, and the return type must agree; types are compared by
+/// token, so `&mut Account` matches `& mut Account`.
+///
+/// # Errors
+///
+/// Returns [`SourceScanError`] when the crate directory cannot be opened, or
+/// a file in its module tree cannot be read or parsed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use camino::Utf8Path;
+/// use theoremc_core::actions::{load_action_manifest, verify_against_source};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let manifest = load_action_manifest(concat!(
+///         "Namespaces:\n  account: crate::account\n",
+///         "Actions:\n  account.balance:\n    returns: u64\n",
+///     ))?;
+///     let crate_dir = Utf8Path::new(env!("CARGO_MANIFEST_DIR"));
+///     for drift in verify_against_source(&manifest, crate_dir)? {
+///         eprintln!("{drift}");
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn verify_against_source(
+    manifest: &ActionManifest,
+    crate_dir: &Utf8Path,
+) -> Result<Vec<ManifestDrift>, SourceScanError> {
+    let root = Utf8Dir::open_ambient_dir(crate_dir, ambient_authority()).map_err(|source| {
+        SourceScanError::OpenCrateDir {
+            path: crate_dir.to_owned(),
+            source,
+        }
+    })?;
+    let crate_source = scan_crate(&root)?;
+    Ok(manifest
+        .actions
+        .iter()
+        .flat_map(|(name, declared)| action_drift(manifest, &crate_source, name, declared))
+        .collect())
+}
+
+fn action_drift(
+    manifest: &ActionManifest,
+    crate_source: &CrateSource,
+    name: &str,
+    declared: &ManifestAction,
+) -> Vec<ManifestDrift> {
+    let Some(function) = crate_source
+        .registered
+        .get(name)
+        .cloned()
+        .or_else(|| manifest.call_path(name))
+    else {
+        return Vec::new();
+    };
+    let reasons = crate_source.functions.get(&function).map_or_else(
+        || vec!["the function does not exist in the crate source".to_owned()],
+        |signature| signature_differences(declared, signature),
+    );
+    reasons
+        .into_iter()
+        .map(|reason| ManifestDrift {
+            action: name.to_owned(),
+            function: function.clone(),
+            reason,
+        })
+        .collect()
+}
+
+fn signature_differences(declared: &ManifestAction, signature: &Signature) -> Vec<String> {
+    let params = signature.inputs.iter().map(parameter).collect::<Vec<_>>();
+    let declared_names = declared
+        .params
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let found_names = params
+        .iter()
+        .map(|(param, _)| param.as_str())
+        .collect::<Vec<_>>();
+    let mut reasons = Vec::new();
+    if declared_names == found_names {
+        for ((param, declared_type), (_, found_type)) in declared.params.iter().zip(&params) {
+            if !same_type(declared_type, found_type) {
+                reasons.push(format!(
+                    "parameter '{param}' is declared as '{}' but the function takes '{found_type}'",
+                    declared_type.trim()
+                ));
+            }
+        }
+    } else {
+        reasons.push(format!(
+            "parameters are declared as ({}) but the function takes ({})",
+            declared_names.join(", "),
+            found_names.join(", ")
+        ));
+    }
+    let returns = match &signature.output {
+        ReturnType::Default => "()".to_owned(),
+        ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+    };
+    if !same_type(&declared.returns, &returns) {
+        reasons.push(format!(
+            "return type is declared as '{}' but the function returns '{returns}'",
+            declared.returns.trim()
+        ));
+    }
+    reasons
+}
+
+/// Returns a parameter's name, or its pattern for a destructuring
+/// parameter, and its type.
+fn parameter(input: &FnArg) -> (String, String) {
+    match input {
+        FnArg::Receiver(receiver) => ("self".to_owned(), receiver.ty.to_token_stream().to_string()),
+        FnArg::Typed(typed) => {
+            let name = match typed.pat.as_ref() {
+                Pat::Ident(binding) => binding.ident.unraw().to_string(),
+                other => other.to_token_stream().to_string(),
+            };
+            (name, typed.ty.to_token_stream().to_string())
+        }
+    }
+}
+
+fn same_type(declared: &str, found: &str) -> bool {
+    match (
+        rust_type::canonical_token_stream(declared),
+        rust_type::canonical_token_stream(found),
+    ) {
+        (Some(left), Some(right)) => left == right,
+        _ => declared.trim() == found.trim(),
+    }
+}
+
+#[cfg(test)]
+#[path = "actions_drift_tests.rs"]
+mod tests;
@@ -0,0 +1,171 @@
+//! Unit tests for manifest drift detection against crate source.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use cap_std::{ambient_authority, fs_utf8::Dir as Utf8Dir};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::{SourceScanError, verify_against_source};
+use crate::actions::load_action_manifest;
+
+const LIB: &str = concat!(
+    "mod ledger;\n",
+    "#[path = \"proof/audit_impl.rs\"]\n",
+    "pub mod audit;\n",
+    "pub mod inline {\n",
+    "    pub fn ping() {}\n",
+    "}\n",
+    "#[cfg(test)]\n",
+    "mod tests;\n",
+);
+
+const LEDGER: &str = concat!(
+    "pub mod history;\n",
+    "pub fn deposit(account: &mut Account, amount: u64) -> Result<u64, LedgerError> {\n",
+    "    todo!()\n",
+    "}\n",
+);
+
+const HISTORY: &str = "pub fn count(account: &Account) -> usize { 0 }\n";
+
+const AUDIT: &str = concat!(
+    "#[theoremc::theorem_action(\"audit.flush\")]\n",
+    "pub fn flush_all(force: bool) {}\n",
+);
+
+/// Maps `ledger` and `inline` to their modules; `audit.flush` is found by
+/// its registration.
+const MANIFEST: &str = concat!(
+    "Namespaces:\n",
+    "  ledger: crate::ledger\n",
+    "  inline: crate::inline\n",
+    "Actions:\n",
+    "  ledger.deposit:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "      amount: u64\n",
+    "    returns: 'Result<u64, LedgerError>'\n",
+    "  ledger.history.count:\n",
+    "    params:\n",
+    "      account: '&Account'\n",
+    "    returns: usize\n",
+    "  inline.ping: {}\n",
+    "  audit.flush:\n",
+    "    params:\n",
+    "      force: bool\n",
+    "  unmapped.action: {}\n",
+);
+
+struct CrateDir {
+    _temp_dir: TempDir,
+    path: Utf8PathBuf,
+}
+
+fn crate_dir(files: &[(&str, &str)]) -> CrateDir {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let path = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf())
+        .expect("temp path should be UTF-8");
+    let root = Utf8Dir::open_ambient_dir(&path, ambient_authority()).expect("temp dir opens");
+    for (file, contents) in files {
+        if let Some(parent) = Utf8Path::new(file).parent() {
+            root.create_dir_all(parent)
+                .expect("parent should be created");
+        }
+        root.write(file, contents)
+            .expect("fixture should be written");
+    }
+    CrateDir {
+        _temp_dir: temp_dir,
+        path,
+    }
+}
+
+fn ledger_crate(ledger: &str) -> CrateDir {
+    crate_dir(&[
+        ("src/lib.rs", LIB),
+        ("src/ledger.rs", ledger),
+        ("src/ledger/history.rs", HISTORY),
+        ("src/proof/audit_impl.rs", AUDIT),
+    ])
+}
+
+fn reasons(ledger: &str, manifest_yaml: &str) -> Vec<String> {
+    let crate_dir = ledger_crate(ledger);
+    let manifest = load_action_manifest(manifest_yaml).expect("manifest should load");
+    verify_against_source(&manifest, &crate_dir.path)
+        .expect("crate source should scan")
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn matching_manifest_reports_no_drift() {
+    assert_eq!(reasons(LEDGER, MANIFEST), Vec::<String>::new());
+}
+
+#[rstest]
+#[case::parameter_type(
+    "pub mod history;\npub fn deposit(account: &mut Account, amount: u32) -> Result<u64, LedgerError> { todo!() }\n",
+    "action 'ledger.deposit' no longer matches `crate::ledger::deposit`: \
+     parameter 'amount' is declared as 'u64' but the function takes 'u32'"
+)]
+#[case::renamed_parameter(
+    "pub mod history;\npub fn deposit(account: &mut Account, value: u64) -> Result<u64, LedgerError> { todo!() }\n",
+    "action 'ledger.deposit' no longer matches `crate::ledger::deposit`: \
+     parameters are declared as (account, amount) but the function takes (account, value)"
+)]
+#[case::return_type(
+    "pub mod history;\npub fn deposit(account: &mut Account, amount: u64) -> u64 { 0 }\n",
+    "action 'ledger.deposit' no longer matches `crate::ledger::deposit`: \
+     return type is declared as 'Result<u64, LedgerError>' but the function returns 'u64'"
+)]
+#[case::missing_function(
+    "pub mod history;\npub fn credit(account: &mut Account, amount: u64) -> Result<u64, LedgerError> { todo!() }\n",
+    "action 'ledger.deposit' no longer matches `crate::ledger::deposit`: \
+     the function does not exist in the crate source"
+)]
+fn changed_functions_are_reported(#[case] ledger: &str, #[case] expected: &str) {
+    assert_eq!(reasons(ledger, MANIFEST), vec![expected.to_owned()]);
+}
+
+#[test]
+fn registered_function_is_checked_under_its_action_name() {
+    let manifest = MANIFEST.replace("      force: bool\n", "      force: u8\n");
+
+    assert_eq!(
+        reasons(LEDGER, &manifest),
+        vec![
+            "action 'audit.flush' no longer matches `crate::audit::flush_all`: \
+             parameter 'force' is declared as 'u8' but the function takes 'bool'"
+                .to_owned()
+        ]
+    );
+}
+
+#[test]
+fn unparsable_source_names_the_file() {
+    let crate_dir = ledger_crate("pub fn deposit(");
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+
+    let error = verify_against_source(&manifest, &crate_dir.path).expect_err("parse should fail");
+
+    assert!(
+        matches!(&error, SourceScanError::ParseSource { path, .. } if path == "src/ledger.rs"),
+        "{error}"
+    );
+}
+
+#[test]
+fn missing_module_file_names_the_file() {
+    let crate_dir = crate_dir(&[("src/lib.rs", "mod ledger;\n")]);
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+
+    let error = verify_against_source(&manifest, &crate_dir.path).expect_err("read should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "failed to read source file 'src/ledger/mod.rs': io:NotFound"
+    );
+}
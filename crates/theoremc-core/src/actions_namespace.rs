@@ -0,0 +1,54 @@
+//! Namespace-to-module mappings for action manifests.
+//!
+//! A manifest's optional `Namespaces` mapping ties a leading run of action
+//! name segments, such as `account` or `hnsw.graph`, to the Rust module that
+//! implements those actions. An action resolves through the longest mapped
+//! namespace, and its remaining segments name the function inside that
+//! module: with `account: crate::proof_actions::account`, the action
+//! `account.deposit` calls `crate::proof_actions::account::deposit`.
+
+use indexmap::IndexMap;
+use serde_saphyr::Spanned;
+use syn::{Path, PathArguments};
+
+use super::EntryResult;
+use crate::schema::validate_identifier;
+
+/// Validates one `Namespaces` entry and returns its module path.
+pub(super) fn check_namespace(namespace: &str, module: &Spanned<String>) -> EntryResult<String> {
+    for segment in namespace.split('.') {
+        validate_identifier(segment)
+            .map_err(|error| (format!("namespace {error}"), module.referenced))?;
+    }
+    let path = module.value.trim();
+    let parsed: Path = syn::parse_str(path).map_err(|error| {
+        (
+            format!("namespace module '{path}' is not a valid Rust path: {error}"),
+            module.referenced,
+        )
+    })?;
+    if parsed
+        .segments
+        .iter()
+        .any(|segment| !matches!(segment.arguments, PathArguments::None))
+    {
+        return Err((
+            format!("namespace module '{path}' must not have generic arguments"),
+            module.referenced,
+        ));
+    }
+    Ok(path.to_owned())
+}
+
+/// Returns the Rust path of the function implementing `action`, resolved
+/// through the longest namespace in `namespaces` that prefixes it.
+pub(super) fn call_path(namespaces: &IndexMap<String, String>, action: &str) -> Option<String> {
+    namespaces
+        .iter()
+        .filter_map(|(namespace, module)| {
+            let function = action.strip_prefix(namespace.as_str())?.strip_prefix('.')?;
+            Some((namespace.len(), module, function))
+        })
+        .max_by_key(|(length, ..)| *length)
+        .map(|(_, module, function)| format!("{module}::{}", function.replace('.', "::")))
+}
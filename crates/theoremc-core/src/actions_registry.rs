@@ -0,0 +1,177 @@
+//! Action manifests assembled from `#[theorem_action]` registrations.
+//!
+//! The `theorem_action` attribute records each annotated function as a
+//! one-action manifest document in a string constant next to the function,
+//! and submits it to the program's inventory of [`RegisteredAction`]s.
+//! Collecting that inventory rebuilds the manifest from code, and rendering
+//! it produces YAML that a checked-in manifest can be compared against, so
+//! the manifest cannot drift from the functions it describes.
+
+use super::{ActionManifest, ManifestAction, load_action_manifest_with_source};
+use crate::schema::{SchemaError, SourceId, rust_type};
+
+/// Source identifier recorded in diagnostics for registration documents.
+const REGISTRY_SOURCE: &str = "<registered actions>";
+
+/// The registration document of one `#[theorem_action]` function, submitted
+/// to the program-wide inventory by the attribute.
+#[derive(Debug)]
+pub struct RegisteredAction {
+    registration: &'static str,
+}
+
+impl RegisteredAction {
+    /// Wraps a registration document. Called by `#[theorem_action]`
+    /// expansions.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new(registration: &'static str) -> Self {
+        Self { registration }
+    }
+
+    /// Returns the one-action manifest document.
+    #[must_use]
+    pub const fn registration(&self) -> &'static str {
+        self.registration
+    }
+}
+
+inventory::collect!(RegisteredAction);
+
+/// Merges every [`RegisteredAction`] linked into the running program into one
+/// manifest, with actions sorted by name.
+///
+/// The inventory has no defined order, so sorting keeps the rendered
+/// manifest stable from one build to the next.
+///
+/// # Errors
+///
+/// Returns the errors of [`collect_registered_actions`].
+pub fn registered_actions() -> Result<ActionManifest, SchemaError> {
+    let registrations: Vec<&str> = inventory::iter::<RegisteredAction>
+        .into_iter()
+        .map(RegisteredAction::registration)
+        .collect();
+    let mut manifest = collect_registered_actions(&registrations)?;
+    manifest.actions.sort_unstable_keys();
+    Ok(manifest)
+}
+
+/// Merges `#[theorem_action]` registration documents into one manifest, in
+/// the order given.
+///
+/// Use [`registered_actions`] to collect every registration in the program;
+/// this function suits an explicit subset, or documents from elsewhere.
+///
+/// # Errors
+///
+/// Returns the errors of
+/// [`load_action_manifest_with_source`](crate::actions::load_action_manifest_with_source)
+/// for a malformed registration, and
+/// [`SchemaError::InvalidActionManifest`] when two registrations declare the
+/// same action.
+///
+/// # Examples
+///
+///     use theoremc_core::actions::collect_registered_actions;
+///
+///     let manifest = collect_registered_actions(&[
+///         "Actions:\n  account.open: {}\n",
+///         "Actions:\n  account.close: {}\n",
+///     ])
+///     .unwrap();
+///     assert_eq!(manifest.actions.len(), 2);
+pub fn collect_registered_actions(registrations: &[&str]) -> Result<ActionManifest, SchemaError> {
+    let source = SourceId::new(REGISTRY_SOURCE);
+    let mut manifest = ActionManifest::default();
+    for registration in registrations {
+        let registered = load_action_manifest_with_source(&source, registration)?;
+        for (name, action) in registered.actions {
+            if manifest.actions.contains_key(&name) {
+                return Err(SchemaError::InvalidActionManifest {
+                    action: name,
+                    reason: "action is registered more than once".to_owned(),
+                    diagnostic: None,
+                });
+            }
+            manifest.actions.insert(name, action);
+        }
+    }
+    Ok(manifest)
+}
+
+/// Renders `manifest` as action manifest YAML that loads back unchanged.
+///
+/// Unit return types and `fallible` flags that match the return type are
+/// left out, as a hand-written manifest would omit them.
+///
+/// # Examples
+///
+///     use theoremc_core::actions::{ActionManifest, ManifestAction, render_action_manifest};
+///
+///     let mut manifest = ActionManifest::default();
+///     let params = [("account".to_owned(), "&Account".to_owned())];
+///     manifest
+///         .actions
+///         .insert("account.balance".to_owned(), ManifestAction::new(params, "u64"));
+///     assert_eq!(
+///         render_action_manifest(&manifest),
+///         "Actions:\n  account.balance:\n    params:\n      account: '&Account'\n    returns: 'u64'\n",
+///     );
+#[must_use]
+pub fn render_action_manifest(manifest: &ActionManifest) -> String {
+    let namespaces = if manifest.namespaces.is_empty() {
+        String::new()
+    } else {
+        let entries = manifest
+            .namespaces
+            .iter()
+            .map(|(namespace, module)| format!("  {namespace}: {}\n", quoted(module)))
+            .collect::<Vec<_>>()
+            .concat();
+        format!("Namespaces:\n{entries}")
+    };
+    if manifest.actions.is_empty() {
+        return format!("{namespaces}Actions: {{}}\n");
+    }
+    let entries = manifest
+        .actions
+        .iter()
+        .map(|(name, action)| render_entry(name, action))
+        .collect::<Vec<_>>()
+        .concat();
+    format!("{namespaces}Actions:\n{entries}")
+}
+
+fn render_entry(name: &str, action: &ManifestAction) -> String {
+    let mut fields = Vec::new();
+    if !action.params.is_empty() {
+        let params = action
+            .params
+            .iter()
+            .map(|(param, ty)| format!("      {param}: {}\n", quoted(ty)))
+            .collect::<Vec<_>>()
+            .concat();
+        fields.push(format!("    params:\n{params}"));
+    }
+    if action.returns.trim() != "()" {
+        fields.push(format!("    returns: {}\n", quoted(&action.returns)));
+    }
+    if action.fallible != rust_type::is_result(&action.returns) {
+        fields.push(format!("    fallible: {}\n", action.fallible));
+    }
+    if fields.is_empty() {
+        format!("  {name}: {{}}\n")
+    } else {
+        format!("  {name}:\n{}", fields.concat())
+    }
+}
+
+/// Quotes `text` as a single-quoted YAML scalar.
+fn quoted(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+#[cfg(test)]
+#[path = "actions_registry_tests.rs"]
+mod tests;
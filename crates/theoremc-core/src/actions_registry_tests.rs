@@ -0,0 +1,95 @@
+//! Unit tests for manifests assembled from action registrations.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{
+    RegisteredAction, collect_registered_actions, registered_actions, render_action_manifest,
+};
+use crate::actions::load_action_manifest;
+use crate::schema::SchemaError;
+
+const DEPOSIT: &str = concat!(
+    "Actions:\n",
+    "  account.deposit:\n",
+    "    params:\n",
+    "      account: '& mut Account'\n",
+    "      amount: 'u64'\n",
+    "    returns: 'Result < u64 , LedgerError >'\n",
+);
+const CLOSE: &str = "Actions:\n  account.close: {}\n";
+
+inventory::submit!(RegisteredAction::new(DEPOSIT));
+inventory::submit!(RegisteredAction::new(CLOSE));
+
+#[test]
+fn linked_registrations_are_collected_in_name_order() {
+    let manifest = registered_actions().expect("linked registrations merge");
+
+    let names: Vec<&str> = manifest.actions.keys().map(String::as_str).collect();
+    assert_eq!(names, ["account.close", "account.deposit"]);
+}
+
+#[test]
+fn registrations_merge_in_the_order_given() {
+    let manifest = collect_registered_actions(&[DEPOSIT, CLOSE]).expect("registrations merge");
+
+    let names: Vec<&str> = manifest.actions.keys().map(String::as_str).collect();
+    assert_eq!(names, ["account.deposit", "account.close"]);
+    assert!(
+        manifest
+            .get("account.deposit")
+            .is_some_and(|action| action.fallible)
+    );
+}
+
+#[test]
+fn duplicate_registration_is_rejected() {
+    let error = collect_registered_actions(&[CLOSE, DEPOSIT, CLOSE])
+        .expect_err("duplicate registration should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "invalid action manifest entry 'account.close': action is registered more than once"
+    );
+}
+
+#[test]
+fn malformed_registration_is_rejected() {
+    let error = collect_registered_actions(&["Actions:\n  deposit: {}\n"])
+        .expect_err("non-canonical name should fail");
+
+    assert!(
+        matches!(error, SchemaError::InvalidActionManifest { .. }),
+        "{error}"
+    );
+}
+
+#[rstest]
+#[case::registration(DEPOSIT)]
+#[case::unit_action(CLOSE)]
+#[case::empty("Actions: {}\n")]
+#[case::namespaces(concat!(
+    "Namespaces:\n",
+    "  account: 'crate::proof_actions::account'\n",
+    "Actions: {}\n",
+))]
+#[case::infallible_result(concat!(
+    "Actions:\n",
+    "  cache.lookup:\n",
+    "    params:\n",
+    "      key: '&''static str'\n",
+    "    returns: 'Result<u64, Miss>'\n",
+    "    fallible: false\n",
+))]
+fn rendered_manifest_round_trips(#[case] yaml: &str) {
+    let manifest = load_action_manifest(yaml).expect("manifest loads");
+
+    let rendered = render_action_manifest(&manifest);
+
+    assert_eq!(rendered, yaml);
+    assert_eq!(
+        load_action_manifest(&rendered).expect("rendered manifest loads"),
+        manifest
+    );
+}
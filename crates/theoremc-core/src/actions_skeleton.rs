@@ -0,0 +1,117 @@
+//! Rust trait skeletons generated from an action manifest.
+//!
+//! The skeleton declares one `TheoremActions` method per manifest action,
+//! named by its [`action_slug`], plus an `UnimplementedActions` type whose
+//! methods are `todo!()` stubs. Teams copy the output into their crate and
+//! replace each stub with a call into their real API. Actions whose
+//! namespace the manifest maps to a module get a stub that already calls the
+//! implementing function by its fully qualified path.
+
+use super::{ActionManifest, ManifestAction};
+use crate::mangle::action_slug;
+
+/// Renders the `TheoremActions` trait and its `todo!()` stub implementation
+/// for every action in `manifest`, in manifest order.
+///
+/// Method names are action slugs such as `account__deposit`, so the trait
+/// carries `#[expect(non_snake_case)]`. Stubs for actions in a mapped
+/// namespace forward their arguments to [`ActionManifest::call_path`]; other
+/// stubs prefix their parameters with `_` to keep unused-variable warnings out
+/// of the generated code.
+///
+/// # Examples
+///
+///     use theoremc_core::actions::{load_action_manifest, render_trait_skeleton};
+///
+///     let manifest = load_action_manifest("Actions:\n  audit.flush: {}\n").unwrap();
+///     let skeleton = render_trait_skeleton(&manifest);
+///     assert!(skeleton.contains("fn audit__flush();"));
+#[must_use]
+pub fn render_trait_skeleton(manifest: &ActionManifest) -> String {
+    let trait_methods = manifest
+        .actions
+        .iter()
+        .map(|(name, action)| {
+            format!(
+                "    /// Performs the `{name}` theorem action.\n    fn {}({}){};\n",
+                action_slug(name),
+                parameters(action, ""),
+                return_clause(action),
+            )
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    let stub_methods = manifest
+        .actions
+        .iter()
+        .map(|(name, action)| stub_method(manifest, name, action))
+        .collect::<Vec<_>>()
+        .concat();
+    let lint = if manifest.actions.is_empty() {
+        ""
+    } else {
+        "#[expect(non_snake_case, reason = \"methods are named by action slug\")]\n"
+    };
+    format!(
+        concat!(
+            "/// Glue between theorem actions and the crate's API.\n",
+            "{lint}",
+            "pub trait TheoremActions {{\n",
+            "{trait_methods}",
+            "}}\n",
+            "\n",
+            "/// Placeholder actions; replace each `todo!()` with a call into the API.\n",
+            "pub struct UnimplementedActions;\n",
+            "\n",
+            "impl TheoremActions for UnimplementedActions {{\n",
+            "{stub_methods}",
+            "}}\n",
+        ),
+        lint = lint,
+        trait_methods = trait_methods,
+        stub_methods = stub_methods,
+    )
+}
+
+fn stub_method(manifest: &ActionManifest, name: &str, action: &ManifestAction) -> String {
+    let (prefix, body) = manifest.call_path(name).map_or_else(
+        || ("_", format!("todo!(\"{name}\")")),
+        |path| {
+            let args = action
+                .params
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            ("", format!("{path}({args})"))
+        },
+    );
+    format!(
+        "    fn {}({}){} {{\n        {body}\n    }}\n",
+        action_slug(name),
+        parameters(action, prefix),
+        return_clause(action),
+    )
+}
+
+fn parameters(action: &ManifestAction, prefix: &str) -> String {
+    action
+        .params
+        .iter()
+        .map(|(param, ty)| format!("{prefix}{param}: {}", ty.trim()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn return_clause(action: &ManifestAction) -> String {
+    let returns = action.returns.trim();
+    if returns == "()" {
+        String::new()
+    } else {
+        format!(" -> {returns}")
+    }
+}
+
+#[cfg(test)]
+#[path = "actions_skeleton_tests.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+//! Unit tests for trait skeleton rendering.
+
+use pretty_assertions::assert_eq;
+
+use super::render_trait_skeleton;
+use crate::actions::{ActionManifest, load_action_manifest};
+
+const MANIFEST: &str = concat!(
+    "Actions:\n",
+    "  account.deposit:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "      amount: u64\n",
+    "    returns: 'Result<u64, LedgerError>'\n",
+    "  audit.flush_all: {}\n",
+);
+
+fn manifest() -> ActionManifest {
+    load_action_manifest(MANIFEST).expect("manifest should load")
+}
+
+#[test]
+fn renders_trait_and_todo_stub_in_manifest_order() {
+    let expected = concat!(
+        "/// Glue between theorem actions and the crate's API.\n",
+        "#[expect(non_snake_case, reason = \"methods are named by action slug\")]\n",
+        "pub trait TheoremActions {\n",
+        "    /// Performs the `account.deposit` theorem action.\n",
+        "    fn account__deposit(account: &mut Account, amount: u64) -> Result<u64, LedgerError>;\n",
+        "    /// Performs the `audit.flush_all` theorem action.\n",
+        "    fn audit__flush_uall();\n",
+        "}\n",
+        "\n",
+        "/// Placeholder actions; replace each `todo!()` with a call into the API.\n",
+        "pub struct UnimplementedActions;\n",
+        "\n",
+        "impl TheoremActions for UnimplementedActions {\n",
+        "    fn account__deposit(_account: &mut Account, _amount: u64) -> Result<u64, LedgerError> {\n",
+        "        todo!(\"account.deposit\")\n",
+        "    }\n",
+        "    fn audit__flush_uall() {\n",
+        "        todo!(\"audit.flush_all\")\n",
+        "    }\n",
+        "}\n",
+    );
+
+    assert_eq!(render_trait_skeleton(&manifest()), expected);
+}
+
+#[test]
+fn mapped_namespaces_forward_to_their_module() {
+    let manifest = load_action_manifest(&format!(
+        "Namespaces:\n  account: crate::proof_actions::account\n{MANIFEST}"
+    ))
+    .expect("manifest should load");
+
+    let skeleton = render_trait_skeleton(&manifest);
+
+    assert!(
+        skeleton.contains(concat!(
+            "    fn account__deposit(account: &mut Account, amount: u64) -> Result<u64, LedgerError> {\n",
+            "        crate::proof_actions::account::deposit(account, amount)\n",
+            "    }\n",
+        )),
+        "{skeleton}"
+    );
+    assert!(
+        skeleton.contains("todo!(\"audit.flush_all\")"),
+        "{skeleton}"
+    );
+    assert!(syn::parse_file(&skeleton).is_ok(), "{skeleton}");
+}
+
+#[test]
+fn rendered_skeleton_parses_as_rust() {
+    let skeleton = render_trait_skeleton(&manifest());
+
+    assert!(syn::parse_file(&skeleton).is_ok(), "{skeleton}");
+}
+
+#[test]
+fn empty_manifest_renders_without_lint_expectation() {
+    let skeleton = render_trait_skeleton(&ActionManifest::default());
+
+    assert!(!skeleton.contains("#[expect"), "{skeleton}");
+    assert!(syn::parse_file(&skeleton).is_ok(), "{skeleton}");
+}
@@ -0,0 +1,182 @@
+//! Function signatures scanned from a crate's Rust source.
+//!
+//! The scan starts at `src/lib.rs`, or `src/main.rs` for a binary crate, and
+//! follows `mod` declarations the way rustc resolves them, including
+//! `#[path]` attributes, so each free function is recorded under its full
+//! module path. Functions annotated `#[theorem_action("name")]` are also
+//! recorded under their action name. `#[cfg(test)]` modules are skipped.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use cap_std::fs_utf8::Dir as Utf8Dir;
+use indexmap::IndexMap;
+use syn::ext::IdentExt;
+use syn::{Attribute, Expr, ExprLit, Ident, Item, ItemFn, ItemMod, Lit, LitStr, Meta, Signature};
+
+use super::SourceScanError;
+
+/// Crate roots tried in order.
+const CRATE_ROOTS: [&str; 2] = ["src/lib.rs", "src/main.rs"];
+
+/// The free functions found in one crate.
+#[derive(Default)]
+pub(super) struct CrateSource {
+    /// Signatures keyed by function path, such as `crate::ledger::deposit`.
+    pub(super) functions: IndexMap<String, Signature>,
+    /// Function paths keyed by the action name they register.
+    pub(super) registered: IndexMap<String, String>,
+}
+
+/// Where the items being scanned live.
+struct Scope {
+    /// Module path, such as `crate::ledger`.
+    module: String,
+    /// Directory `#[path]` attributes are relative to.
+    file_dir: Utf8PathBuf,
+    /// Directory holding the files of child modules.
+    child_dir: Utf8PathBuf,
+}
+
+/// Scans the crate whose manifest directory is `root`.
+pub(super) fn scan_crate(root: &Utf8Dir) -> Result<CrateSource, SourceScanError> {
+    let entry = CRATE_ROOTS
+        .into_iter()
+        .find(|path| root.is_file(path))
+        .unwrap_or("src/lib.rs");
+    let mut scanner = Scanner {
+        root,
+        found: CrateSource::default(),
+    };
+    scanner.scan_file(Utf8Path::new(entry), "crate".to_owned(), true)?;
+    Ok(scanner.found)
+}
+
+/// Walks the module tree below one crate directory.
+struct Scanner<'a> {
+    root: &'a Utf8Dir,
+    found: CrateSource,
+}
+
+impl Scanner<'_> {
+    /// Scans the module `module` held in `file`. Child modules of a `mod.rs`
+    /// style file live beside it; those of any other file live in a directory
+    /// named after it.
+    fn scan_file(
+        &mut self,
+        file: &Utf8Path,
+        module: String,
+        mod_rs: bool,
+    ) -> Result<(), SourceScanError> {
+        let text =
+            self.root
+                .read_to_string(file)
+                .map_err(|source| SourceScanError::ReadSource {
+                    path: file.to_owned(),
+                    source,
+                })?;
+        let parsed = syn::parse_file(&text).map_err(|source| SourceScanError::ParseSource {
+            path: file.to_owned(),
+            source,
+        })?;
+        let file_dir = file
+            .parent()
+            .unwrap_or_else(|| Utf8Path::new(""))
+            .to_owned();
+        let child_dir = if mod_rs {
+            file_dir.clone()
+        } else {
+            file_dir.join(file.file_stem().unwrap_or_default())
+        };
+        let scope = Scope {
+            module,
+            file_dir,
+            child_dir,
+        };
+        self.scan_items(&scope, &parsed.items)
+    }
+
+    fn scan_items(&mut self, scope: &Scope, items: &[Item]) -> Result<(), SourceScanError> {
+        for item in items {
+            match item {
+                Item::Fn(function) => self.record(&scope.module, function),
+                Item::Mod(module) if !is_test_only(&module.attrs) => {
+                    self.scan_module(scope, module)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn scan_module(&mut self, scope: &Scope, item: &ItemMod) -> Result<(), SourceScanError> {
+        let name = item.ident.unraw().to_string();
+        let module = format!("{}::{name}", scope.module);
+        let path = path_attribute(&item.attrs);
+        if let Some((_, items)) = &item.content {
+            let dir = scope.child_dir.join(path.as_deref().unwrap_or(&name));
+            let inline = Scope {
+                module,
+                file_dir: dir.clone(),
+                child_dir: dir,
+            };
+            return self.scan_items(&inline, items);
+        }
+        let (file, mod_rs) = path.map_or_else(
+            || {
+                let file = scope.child_dir.join(format!("{name}.rs"));
+                if self.root.is_file(&file) {
+                    (file, false)
+                } else {
+                    (scope.child_dir.join(&name).join("mod.rs"), true)
+                }
+            },
+            |relative| (scope.file_dir.join(relative), true),
+        );
+        self.scan_file(&file, module, mod_rs)
+    }
+
+    fn record(&mut self, module: &str, function: &ItemFn) {
+        let path = format!("{module}::{}", function.sig.ident.unraw());
+        if let Some(action) = registered_action(&function.attrs) {
+            self.found.registered.insert(action, path.clone());
+        }
+        self.found.functions.insert(path, function.sig.clone());
+    }
+}
+
+/// Returns the action name of a `#[theorem_action("name")]` attribute.
+fn registered_action(attrs: &[Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "theorem_action")
+        })
+        .and_then(|attr| attr.parse_args::<LitStr>().ok())
+        .map(|name| name.value())
+}
+
+/// Returns the value of a `#[path = "..."]` attribute.
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(pair) if pair.path.is_ident("path") => match &pair.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(path),
+                ..
+            }) => Some(path.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Returns whether `attrs` include `#[cfg(test)]`.
+fn is_test_only(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<Ident>()
+                .is_ok_and(|predicate| predicate == "test")
+    })
+}
@@ -0,0 +1,163 @@
+//! Unit tests for action manifest loading.
+
+use rstest::rstest;
+
+use super::{load_action_manifest, load_action_manifest_with_source};
+use crate::schema::{SchemaDiagnosticCode, SchemaError, SourceId};
+
+const MANIFEST: &str = concat!(
+    "Actions:\n",
+    "  account.deposit:\n",
+    "    params:\n",
+    "      account: '&mut Account'\n",
+    "      amount: u64\n",
+    "    returns: 'Result<u64, LedgerError>'\n",
+    "  account.balance:\n",
+    "    params:\n",
+    "      account: '&Account'\n",
+    "    returns: u64\n",
+    "  ledger.reset:\n",
+    "    returns: 'Result<(), LedgerError>'\n",
+    "    fallible: false\n",
+);
+
+fn load_error(yaml: &str) -> SchemaError {
+    load_action_manifest_with_source(&SourceId::new("ledger.actions.yaml"), yaml)
+        .expect_err("manifest should be rejected")
+}
+
+fn entry(body: &str) -> String {
+    format!("Actions:\n  account.deposit:\n{body}")
+}
+
+#[test]
+fn loads_actions_in_declaration_order() {
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+    let names: Vec<&str> = manifest.actions.keys().map(String::as_str).collect();
+    assert_eq!(
+        names,
+        ["account.deposit", "account.balance", "ledger.reset"]
+    );
+    let deposit = manifest.get("account.deposit").expect("deposit declared");
+    let params: Vec<(&str, &str)> = deposit
+        .params
+        .iter()
+        .map(|(name, ty)| (name.as_str(), ty.as_str()))
+        .collect();
+    assert_eq!(params, [("account", "&mut Account"), ("amount", "u64")]);
+    assert_eq!(deposit.returns, "Result<u64, LedgerError>");
+}
+
+#[rstest]
+#[case::result_defaults_fallible("account.deposit", true)]
+#[case::plain_return_defaults_infallible("account.balance", false)]
+#[case::explicit_infallible_result("ledger.reset", false)]
+fn resolves_fallibility(#[case] action: &str, #[case] fallible: bool) {
+    let manifest = load_action_manifest(MANIFEST).expect("manifest should load");
+    let declared = manifest.get(action).expect("action declared");
+    assert_eq!(declared.fallible, fallible);
+}
+
+#[test]
+fn omitted_returns_defaults_to_unit() {
+    let manifest =
+        load_action_manifest(&entry("    params:\n      amount: u64\n")).expect("should load");
+    let deposit = manifest.get("account.deposit").expect("deposit declared");
+    assert_eq!(deposit.returns, "()");
+    assert!(!deposit.fallible);
+    assert_eq!(deposit.signature().returns, "()");
+}
+
+#[test]
+fn lowercase_actions_key_is_accepted() {
+    let manifest = load_action_manifest("actions:\n  account.open: {}\n").expect("should load");
+    assert!(manifest.get("account.open").is_some());
+}
+
+#[rstest]
+#[case::unknown_top_level_key("Actions: {}\nExtra: 1\n", 2)]
+#[case::unknown_action_key("Actions:\n  account.open:\n    params: {}\n    throws: true\n", 3)]
+#[case::duplicate_action("Actions:\n  account.open: {}\n  account.open: {}\n", 3)]
+fn rejects_malformed_manifest_with_location(#[case] yaml: &str, #[case] line: usize) {
+    let error = load_error(yaml);
+    assert!(matches!(error, SchemaError::Deserialize { .. }), "{error}");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.code, SchemaDiagnosticCode::ParseFailure);
+    assert_eq!(diagnostic.location.source, "ledger.actions.yaml");
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::mapped_namespace("account.deposit", Some("crate::proof_actions::account::deposit"))]
+#[case::longest_namespace_wins("hnsw.graph.insert", Some("crate::graph::insert"))]
+#[case::remaining_segments_nest("hnsw.index.build", Some("crate::hnsw::index::build"))]
+#[case::whole_segment_match_only("accounts.open", None)]
+#[case::unmapped_namespace("ledger.reset", None)]
+fn resolves_call_paths_through_namespaces(#[case] action: &str, #[case] expected: Option<&str>) {
+    let manifest = load_action_manifest(concat!(
+        "Namespaces:\n",
+        "  account: crate::proof_actions::account\n",
+        "  hnsw: crate::hnsw\n",
+        "  hnsw.graph: crate::graph\n",
+        "Actions: {}\n",
+    ))
+    .expect("manifest should load");
+
+    assert_eq!(manifest.call_path(action).as_deref(), expected);
+}
+
+#[rstest]
+#[case::non_canonical_name(
+    "Actions:\n  deposit:\n    returns: u64\n",
+    "action must be a dot-separated canonical name",
+    3
+)]
+#[case::invalid_param_name(
+    &entry("    params:\n      2amount: u64\n"),
+    "param invalid identifier '2amount'",
+    4
+)]
+#[case::invalid_param_type(
+    &entry("    params:\n      amount: 'u64 <'\n"),
+    "param 'amount' type is not a valid Rust type",
+    4
+)]
+#[case::free_lifetime(
+    &entry("    returns: \"&'a str\"\n"),
+    "returns type contains a free named lifetime parameter 'a",
+    3
+)]
+#[case::fallible_without_result(
+    &entry("    returns: u64\n    fallible: true\n"),
+    "fallible actions must return a Result, found 'u64'",
+    4
+)]
+#[case::invalid_namespace_segment(
+    "Actions: {}\nNamespaces:\n  account.2x: crate::account\n",
+    "namespace invalid identifier '2x'",
+    3
+)]
+#[case::invalid_namespace_module(
+    "Actions: {}\nNamespaces:\n  account: 'crate::account::'\n",
+    "namespace module 'crate::account::' is not a valid Rust path",
+    3
+)]
+#[case::generic_namespace_module(
+    "Actions: {}\nNamespaces:\n  account: 'crate::Ledger<u8>'\n",
+    "namespace module 'crate::Ledger<u8>' must not have generic arguments",
+    3
+)]
+fn rejects_invalid_entries_with_location(
+    #[case] yaml: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error = load_error(yaml);
+    let SchemaError::InvalidActionManifest { ref reason, .. } = error else {
+        panic!("expected InvalidActionManifest, got {error}");
+    };
+    assert!(reason.contains(expected), "{reason}");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.code, SchemaDiagnosticCode::ValidationFailure);
+    assert_eq!(diagnostic.location.line, line);
+}
@@ -0,0 +1,406 @@
+//! Fluent construction of validated theorem documents.
+//!
+//! [`TheoremDocBuilder`] assembles a [`TheoremDoc`] section by section, so
+//! spec generators, migrations, and tests can produce theorems without
+//! formatting YAML. Nested builders cover the parts with structure of their
+//! own: [`CallBuilder`] for action calls, [`StepBuilder`] for `Do` steps,
+//! [`AssertionBuilder`] for `Prove` assertions, and [`KaniBuilder`] for Kani
+//! evidence.
+//!
+//! [`TheoremDocBuilder::build`] applies the checks the loader applies to a
+//! `.theorem` source: names are validated, type aliases are expanded, and
+//! the document is validated as a whole. A built document therefore equals
+//! the document loaded from the equivalent YAML.
+//!
+//! # Examples
+//!
+//!     use theoremc_core::builder::{
+//!         AssertionBuilder, CallBuilder, KaniBuilder, StepBuilder, TheoremDocBuilder,
+//!     };
+//!     use theoremc_core::schema::{ActionSignature, KaniExpectation, Symbol};
+//!
+//!     let doc = TheoremDocBuilder::new("Deposit", "Deposits grow the balance")
+//!         .forall("amount", "u64")
+//!         .action(
+//!             "ledger.deposit",
+//!             ActionSignature {
+//!                 params: [("amount".to_owned(), Symbol::new("u64"))].into(),
+//!                 returns: Symbol::new("u64"),
+//!             },
+//!         )
+//!         .step(
+//!             StepBuilder::call(
+//!                 CallBuilder::new("ledger.deposit")
+//!                     .arg_ref("amount", "amount")
+//!                     .bind("after"),
+//!             )
+//!             .id("deposit"),
+//!         )
+//!         .prove(AssertionBuilder::new("after >= amount", "deposits add").step("deposit"))
+//!         .witness("amount == 1", "a deposit is reachable")
+//!         .kani(KaniBuilder::new(1, KaniExpectation::Success))
+//!         .build()
+//!         .unwrap();
+//!
+//!     assert_eq!(doc.theorem.as_str(), "Deposit");
+//!     assert_eq!(doc.do_steps[0].id(), Some("deposit"));
+
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use indexmap::map::Entry;
+
+use crate::schema::IndexedValidationSection;
+use crate::schema::{
+    ActionCall, ActionSignature, Assertion, Assumption, Evidence, ForallDomain, ForallVar,
+    KaniEvidence, LetBinding, LetCall, LetMust, SchemaError, Step, Symbol, TheoremDoc, TheoremExpr,
+    TheoremName, TheoremValue, TraceLink, WitnessCheck, expand_type_aliases, validate_theorem_doc,
+};
+
+#[path = "builder_kani.rs"]
+mod kani;
+#[path = "builder_steps.rs"]
+mod steps;
+
+pub use kani::KaniBuilder;
+pub use steps::{AssertionBuilder, CallBuilder, StepBuilder};
+
+/// Builds a [`TheoremDoc`] one section at a time.
+///
+/// Every method takes the builder by value and returns it, so a document is
+/// written as one chain ending in [`TheoremDocBuilder::build`]. Sections
+/// keep the order in which their entries are added. Mistakes that cannot
+/// be reported by a chained method, such as a second `Forall` variable of
+/// the same name or a malformed `ref` argument, are recorded and returned
+/// by `build`.
+#[derive(Debug, Clone)]
+pub struct TheoremDocBuilder {
+    doc: DocParts,
+    issue: Option<String>,
+}
+
+/// The sections of a document under construction, before its names have
+/// been validated.
+#[derive(Debug, Clone, Default)]
+struct DocParts {
+    schema: Option<u32>,
+    theorem: String,
+    about: String,
+    tags: Vec<String>,
+    traces: Vec<TraceLink>,
+    given: Vec<String>,
+    depends_on: Vec<String>,
+    types: IndexMap<String, String>,
+    forall: IndexMap<String, String>,
+    forall_domains: IndexMap<String, ForallDomain>,
+    actions: IndexMap<String, ActionSignature>,
+    assume: Vec<Assumption>,
+    witness: Vec<WitnessCheck>,
+    let_bindings: IndexMap<String, LetBinding>,
+    do_steps: Vec<Step>,
+    prove: Vec<Assertion>,
+    kani: Vec<KaniEvidence>,
+    verus: Option<TheoremValue>,
+    stateright: Option<TheoremValue>,
+}
+
+impl TheoremDocBuilder {
+    /// Starts a document with its required `Theorem` name and `About`
+    /// description.
+    #[must_use]
+    pub fn new(theorem: impl Into<String>, about: impl Into<String>) -> Self {
+        Self {
+            doc: DocParts {
+                theorem: theorem.into(),
+                about: about.into(),
+                ..DocParts::default()
+            },
+            issue: None,
+        }
+    }
+
+    /// Sets the `Schema` version.
+    #[must_use]
+    pub const fn schema(mut self, version: u32) -> Self {
+        self.doc.schema = Some(version);
+        self
+    }
+
+    /// Adds a `Tags` entry.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.doc.tags.push(tag.into());
+        self
+    }
+
+    /// Adds a `Traces` link to an external requirement.
+    #[must_use]
+    pub fn trace(mut self, trace: TraceLink) -> Self {
+        self.doc.traces.push(trace);
+        self
+    }
+
+    /// Adds a `Given` narrative line.
+    #[must_use]
+    pub fn given(mut self, line: impl Into<String>) -> Self {
+        self.doc.given.push(line.into());
+        self
+    }
+
+    /// Adds a `DependsOn` entry naming a theorem this one builds on.
+    #[must_use]
+    pub fn depends_on(mut self, theorem: impl Into<String>) -> Self {
+        self.doc.depends_on.push(theorem.into());
+        self
+    }
+
+    /// Declares a `Types` alias for a Rust type.
+    #[must_use]
+    pub fn type_alias(mut self, alias: impl Into<String>, ty: impl Into<String>) -> Self {
+        let issue = insert_unique(&mut self.doc.types, "Types alias", alias.into(), ty.into());
+        self.record(issue)
+    }
+
+    /// Declares a `Forall` variable of an arbitrary value of `ty`.
+    #[must_use]
+    pub fn forall(mut self, var: impl Into<String>, ty: impl Into<String>) -> Self {
+        let issue = insert_unique(
+            &mut self.doc.forall,
+            "Forall variable",
+            var.into(),
+            ty.into(),
+        );
+        self.record(issue)
+    }
+
+    /// Declares a `Forall` variable whose value is drawn from `domain`.
+    ///
+    /// `ty` is the variable's full Rust type, as the loader records it: a
+    /// `{ vec: u32 }` entry has type `Vec<u32>`, and an optional entry has
+    /// type `Option<...>`.
+    #[must_use]
+    pub fn forall_domain(
+        mut self,
+        var: impl Into<String>,
+        ty: impl Into<String>,
+        domain: ForallDomain,
+    ) -> Self {
+        let name = var.into();
+        let issue = insert_unique(
+            &mut self.doc.forall,
+            "Forall variable",
+            name.clone(),
+            ty.into(),
+        );
+        if issue.is_none() {
+            self.doc.forall_domains.insert(name, domain);
+        }
+        self.record(issue)
+    }
+
+    /// Declares the Rust signature of an action the theorem calls.
+    #[must_use]
+    pub fn action(mut self, name: impl Into<String>, signature: ActionSignature) -> Self {
+        let issue = insert_unique(&mut self.doc.actions, "action", name.into(), signature);
+        self.record(issue)
+    }
+
+    /// Adds an `Assume` constraint on the `Forall` variables.
+    #[must_use]
+    pub fn assume(mut self, expr: impl Into<String>, because: impl Into<String>) -> Self {
+        let index = self.doc.assume.len();
+        match TheoremExpr::for_entry(expr.into(), IndexedValidationSection::Assume, index) {
+            Ok(checked) => {
+                self.doc.assume.push(Assumption {
+                    expr: checked,
+                    because: Symbol::from(because.into()),
+                });
+                self
+            }
+            Err(rejected) => self.record(Some(rejected.reason)),
+        }
+    }
+
+    /// Adds a `Witness` cover expression showing the theorem is not vacuous.
+    #[must_use]
+    pub fn witness(mut self, cover: impl Into<String>, because: impl Into<String>) -> Self {
+        let index = self.doc.witness.len();
+        match TheoremExpr::for_entry(cover.into(), IndexedValidationSection::Witness, index) {
+            Ok(checked) => {
+                self.doc.witness.push(WitnessCheck {
+                    cover: checked,
+                    because: Symbol::from(because.into()),
+                });
+                self
+            }
+            Err(rejected) => self.record(Some(rejected.reason)),
+        }
+    }
+
+    /// Adds a `Let` binding that calls an action and binds its result.
+    #[must_use]
+    pub fn let_call(self, name: impl Into<String>, call: CallBuilder) -> Self {
+        self.bind(name.into(), call, |built| {
+            LetBinding::Call(LetCall { call: built })
+        })
+    }
+
+    /// Adds a `Let` binding that calls an action, proves it cannot fail,
+    /// and binds the unwrapped success value.
+    #[must_use]
+    pub fn let_must(self, name: impl Into<String>, call: CallBuilder) -> Self {
+        self.bind(name.into(), call, |must| LetBinding::Must(LetMust { must }))
+    }
+
+    /// Appends a step to the `Do` sequence.
+    #[must_use]
+    pub fn step(mut self, step: StepBuilder) -> Self {
+        let (built, issue) = step.finish();
+        let index = self.doc.do_steps.len() + 1;
+        self.doc.do_steps.push(built);
+        self.record(issue.map(|found| format!("Do step {index}: {found}")))
+    }
+
+    /// Adds a `Prove` assertion.
+    #[must_use]
+    pub fn prove(mut self, assertion: AssertionBuilder) -> Self {
+        match assertion.finish(self.doc.prove.len()) {
+            Ok(built) => {
+                self.doc.prove.push(built);
+                self
+            }
+            Err(reason) => self.record(Some(reason)),
+        }
+    }
+
+    /// Adds a Kani harness configuration to `Evidence`.
+    #[must_use]
+    pub fn kani(mut self, kani: KaniBuilder) -> Self {
+        self.doc.kani.push(kani.finish());
+        self
+    }
+
+    /// Sets the placeholder `Evidence.verus` configuration.
+    #[must_use]
+    pub fn verus(mut self, config: TheoremValue) -> Self {
+        self.doc.verus = Some(config);
+        self
+    }
+
+    /// Sets the placeholder `Evidence.stateright` configuration.
+    #[must_use]
+    pub fn stateright(mut self, config: TheoremValue) -> Self {
+        self.doc.stateright = Some(config);
+        self
+    }
+
+    /// Validates the assembled document and returns it.
+    ///
+    /// Type aliases are expanded before validation, as they are when a
+    /// document is loaded. `Include` is always empty, because a built
+    /// document has no fragments to merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError::InvalidIdentifier`] when the theorem name, a
+    /// `DependsOn` entry, or a `Forall` variable is not a valid identifier,
+    /// and [`SchemaError::ValidationFailed`] for the first mistake recorded
+    /// while building or the first constraint the document violates.
+    pub fn build(self) -> Result<TheoremDoc, SchemaError> {
+        if let Some(reason) = self.issue {
+            return Err(SchemaError::ValidationFailed {
+                theorem: self.doc.theorem,
+                reason,
+                diagnostic: None,
+                source: None,
+            });
+        }
+        let mut doc = self.doc.into_theorem_doc()?;
+        expand_type_aliases(&mut doc);
+        validate_theorem_doc(&doc).map_err(|failure| failure.into_schema_error(None))?;
+        Ok(doc)
+    }
+
+    fn bind(
+        mut self,
+        name: String,
+        call: CallBuilder,
+        wrap: impl FnOnce(ActionCall) -> LetBinding,
+    ) -> Self {
+        let (built, issue) = call.finish();
+        let decode_issue = issue.map(|found| format!("Let binding '{name}': {found}"));
+        let duplicate = insert_unique(&mut self.doc.let_bindings, "Let binding", name, wrap(built));
+        self.record(decode_issue.or(duplicate))
+    }
+
+    /// Keeps the first mistake found while building.
+    fn record(mut self, issue: Option<String>) -> Self {
+        if self.issue.is_none() {
+            self.issue = issue;
+        }
+        self
+    }
+}
+
+impl DocParts {
+    fn into_theorem_doc(self) -> Result<TheoremDoc, SchemaError> {
+        Ok(TheoremDoc {
+            schema: self.schema,
+            theorem: TheoremName::new(self.theorem)?,
+            about: self.about,
+            tags: self.tags.into_iter().map(Symbol::from).collect(),
+            traces: self.traces,
+            given: self.given,
+            depends_on: self
+                .depends_on
+                .into_iter()
+                .map(TheoremName::new)
+                .collect::<Result<_, _>>()?,
+            include: Vec::new(),
+            types: self.types,
+            forall: self
+                .forall
+                .into_iter()
+                .map(|(var, ty)| Ok((ForallVar::new(var)?, Symbol::from(ty))))
+                .collect::<Result<_, SchemaError>>()?,
+            forall_domains: self
+                .forall_domains
+                .into_iter()
+                .map(|(var, domain)| Ok((ForallVar::new(var)?, domain)))
+                .collect::<Result<_, SchemaError>>()?,
+            actions: self.actions,
+            assume: self.assume,
+            witness: self.witness,
+            let_bindings: Arc::new(self.let_bindings),
+            do_steps: Arc::new(self.do_steps),
+            prove: self.prove,
+            evidence: Evidence {
+                kani: self.kani,
+                verus: self.verus,
+                stateright: self.stateright,
+            },
+            lints: IndexMap::new(),
+        })
+    }
+}
+
+/// Inserts `value` under `key`, or reports the duplicate a YAML mapping
+/// would reject.
+fn insert_unique<V>(
+    map: &mut IndexMap<String, V>,
+    label: &str,
+    key: String,
+    value: V,
+) -> Option<String> {
+    match map.entry(key) {
+        Entry::Occupied(entry) => Some(format!("duplicate {label} '{}'", entry.key())),
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "builder_tests.rs"]
+mod tests;
@@ -0,0 +1,145 @@
+//! Builder for Kani evidence configurations.
+
+use indexmap::IndexMap;
+
+use crate::schema::{
+    CounterexampleConstraint, KaniEvidence, KaniExpectation, KaniSolver, KaniStub, KaniTimeout,
+    MemoryLimit,
+};
+
+/// Builds one Kani harness configuration for `Evidence.kani`.
+///
+/// Options left unset keep the defaults of an omitted YAML key.
+#[derive(Debug, Clone)]
+pub struct KaniBuilder {
+    kani: KaniEvidence,
+}
+
+impl KaniBuilder {
+    /// Starts a configuration with the default `unwind` bound and the
+    /// expected verification outcome.
+    #[must_use]
+    pub fn new(unwind: u32, expect: KaniExpectation) -> Self {
+        Self {
+            kani: KaniEvidence {
+                name: None,
+                unwind,
+                loop_unwind: IndexMap::new(),
+                expect,
+                allow_vacuous: false,
+                vacuity_because: None,
+                solver: None,
+                extra_args: Vec::new(),
+                timeout: None,
+                memory_limit: None,
+                stubs: Vec::new(),
+                contracts: Vec::new(),
+                counterexample: IndexMap::new(),
+                playback: false,
+                coverage: false,
+            },
+        }
+    }
+
+    /// Names the harness variant, which is required when a theorem has
+    /// several.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.kani.name = Some(name.into());
+        self
+    }
+
+    /// Sets the unwinding bound of the loop labelled `label`.
+    #[must_use]
+    pub fn loop_unwind(mut self, label: impl Into<String>, unwind: u32) -> Self {
+        self.kani.loop_unwind.insert(label.into(), unwind);
+        self
+    }
+
+    /// Accepts a vacuous proof, for the reason given in `because`.
+    #[must_use]
+    pub fn allow_vacuous(mut self, because: impl Into<String>) -> Self {
+        self.kani.allow_vacuous = true;
+        self.kani.vacuity_because = Some(because.into());
+        self
+    }
+
+    /// Selects the SAT solver.
+    #[must_use]
+    pub const fn solver(mut self, solver: KaniSolver) -> Self {
+        self.kani.solver = Some(solver);
+        self
+    }
+
+    /// Passes an extra command-line argument to Kani.
+    #[must_use]
+    pub fn extra_arg(mut self, arg: impl Into<String>) -> Self {
+        self.kani.extra_args.push(arg.into());
+        self
+    }
+
+    /// Limits how long the harness may run.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: KaniTimeout) -> Self {
+        self.kani.timeout = Some(timeout);
+        self
+    }
+
+    /// Limits how much memory the harness may use.
+    #[must_use]
+    pub const fn memory_limit(mut self, limit: MemoryLimit) -> Self {
+        self.kani.memory_limit = Some(limit);
+        self
+    }
+
+    /// Replaces the function at path `original` with `stub` during
+    /// verification.
+    #[must_use]
+    pub fn stub(mut self, original: impl Into<String>, stub: impl Into<String>) -> Self {
+        self.kani.stubs.push(KaniStub {
+            original: original.into(),
+            stub: stub.into(),
+        });
+        self
+    }
+
+    /// Verifies the Kani contract of the function at path `contract`, which
+    /// yields a `proof_for_contract` harness instead of a plain proof.
+    #[must_use]
+    pub fn contract(mut self, contract: impl Into<String>) -> Self {
+        self.kani.contracts.push(contract.into());
+        self
+    }
+
+    /// Constrains the value the `Forall` variable `var` takes in the
+    /// counterexample of an `expect: FAILURE` run.
+    #[must_use]
+    pub fn counterexample(
+        mut self,
+        var: impl Into<String>,
+        constraint: CounterexampleConstraint,
+    ) -> Self {
+        self.kani.counterexample.insert(var.into(), constraint);
+        self
+    }
+
+    /// Replays counterexamples as unit tests through Kani's concrete
+    /// playback.
+    #[must_use]
+    pub const fn playback(mut self) -> Self {
+        self.kani.playback = true;
+        self
+    }
+
+    /// Verifies the harness in Kani's coverage mode, recording which `Prove`
+    /// assertions are reachable.
+    #[must_use]
+    pub const fn coverage(mut self) -> Self {
+        self.kani.coverage = true;
+        self
+    }
+
+    pub(super) fn finish(self) -> KaniEvidence {
+        self.kani
+    }
+}
@@ -0,0 +1,354 @@
+//! Builders for action calls, `Do` steps, and `Prove` assertions.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use indexmap::map::Entry;
+
+use crate::schema::IndexedValidationSection;
+use crate::schema::arg_value::{ParamName, decode_arg_value};
+use crate::schema::{
+    ActionCall, ArgDecodeError, ArgValue, Assertion, ForeachBlock, InlineAssert, MaybeBlock,
+    RepeatBlock, Step, StepAssert, StepCall, StepExpectErr, StepForeach, StepMaybe, StepMust,
+    StepRepeat, Symbol, TheoremExpr, TheoremValue,
+};
+
+/// A mistake recorded while a call or step was built, reported when the
+/// enclosing document is built.
+#[derive(Debug, Clone)]
+pub(super) enum BuildIssue {
+    /// An argument failed the decoding checks the loader applies.
+    Arg(ArgDecodeError),
+    /// A method was used on a call or step that does not support it.
+    Misuse(String),
+}
+
+impl BuildIssue {
+    /// Locates the issue inside a nested step list, as the loader does for
+    /// nested decode errors.
+    fn nested(self, path: &str) -> Self {
+        match self {
+            Self::Arg(error) => Self::Arg(error.with_param_prefix(path)),
+            Self::Misuse(reason) => Self::Misuse(format!("{path}: {reason}")),
+        }
+    }
+}
+
+impl fmt::Display for BuildIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arg(error) => error.fmt(f),
+            Self::Misuse(reason) => f.write_str(reason),
+        }
+    }
+}
+
+// ── Action calls ────────────────────────────────────────────────────
+
+/// Builds an [`ActionCall`] for a `Let` binding or a `Do` step.
+///
+/// [`ArgValue::Reference`] and [`ArgValue::Expr`] arguments are checked
+/// as the loader checks `{ ref: ... }` and `{ expr: ... }` wrappers.
+#[derive(Debug, Clone)]
+pub struct CallBuilder {
+    call: ActionCall,
+    issue: Option<BuildIssue>,
+}
+
+impl CallBuilder {
+    /// Starts a call of the action with the dot-separated name `action`.
+    #[must_use]
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            call: ActionCall {
+                action: Symbol::from(action.into()),
+                args: IndexMap::new(),
+                as_binding: None,
+            },
+            issue: None,
+        }
+    }
+
+    /// Passes `value` as the argument named `param`.
+    #[must_use]
+    pub fn arg(mut self, param: impl Into<String>, value: ArgValue) -> Self {
+        let name = param.into();
+        let decoded = check_arg(&name, &value).map_err(BuildIssue::Arg);
+        let issue = match self.call.args.entry(name) {
+            Entry::Occupied(entry) => Some(BuildIssue::Misuse(format!(
+                "duplicate argument '{}'",
+                entry.key()
+            ))),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                decoded.err()
+            }
+        };
+        if self.issue.is_none() {
+            self.issue = issue;
+        }
+        self
+    }
+
+    /// Passes the `Forall` variable or binding `variable` as the argument
+    /// named `param`, like `{ ref: variable }`.
+    #[must_use]
+    pub fn arg_ref(self, param: impl Into<String>, variable: impl Into<String>) -> Self {
+        self.arg(param, ArgValue::Reference(variable.into()))
+    }
+
+    /// Passes the Rust expression `expr` as the argument named `param`,
+    /// like `{ expr: expr }`.
+    #[must_use]
+    pub fn arg_expr(self, param: impl Into<String>, expr: impl Into<String>) -> Self {
+        self.arg(param, ArgValue::Expr(expr.into()))
+    }
+
+    /// Binds the call's result to `name`, like `as: name`.
+    #[must_use]
+    pub fn bind(mut self, name: impl Into<String>) -> Self {
+        self.call.as_binding = Some(name.into());
+        self
+    }
+
+    pub(super) fn finish(self) -> (ActionCall, Option<BuildIssue>) {
+        (self.call, self.issue)
+    }
+}
+
+/// Applies the loader's wrapper checks to a reference or expression
+/// argument. Other arguments need none.
+fn check_arg(param: &str, value: &ArgValue) -> Result<(), ArgDecodeError> {
+    let (key, target) = match value {
+        ArgValue::Reference(name) => ("ref", name),
+        ArgValue::Expr(expr) => ("expr", expr),
+        ArgValue::Literal(_) | ArgValue::RawSequence(_) | ArgValue::RawMap(_) => return Ok(()),
+    };
+    let wrapper = IndexMap::from([(key.to_owned(), TheoremValue::String(target.clone()))]);
+    decode_arg_value(ParamName::new(param), TheoremValue::Mapping(wrapper)).map(drop)
+}
+
+// ── Steps ───────────────────────────────────────────────────────────
+
+/// Builds one [`Step`] of a `Do` sequence.
+///
+/// Each constructor starts one kind of step. Blocks receive their nested
+/// steps through [`StepBuilder::step`] and, for `maybe`,
+/// [`StepBuilder::else_step`].
+#[derive(Debug, Clone)]
+pub struct StepBuilder {
+    step: Step,
+    issue: Option<BuildIssue>,
+}
+
+impl StepBuilder {
+    /// Starts a `call` step.
+    #[must_use]
+    pub fn call(call: CallBuilder) -> Self {
+        let (built, issue) = call.finish();
+        Self {
+            step: Step::Call(StepCall {
+                call: built,
+                id: None,
+            }),
+            issue,
+        }
+    }
+
+    /// Starts a `must` step, which proves the call cannot fail.
+    #[must_use]
+    pub fn must(call: CallBuilder) -> Self {
+        let (built, issue) = call.finish();
+        Self {
+            step: Step::Must(StepMust {
+                must: built,
+                id: None,
+            }),
+            issue,
+        }
+    }
+
+    /// Starts an `expect_err` step, which proves the call returns the error
+    /// variant `error`.
+    #[must_use]
+    pub fn expect_err(call: CallBuilder, error: impl Into<String>) -> Self {
+        let (built, issue) = call.finish();
+        Self {
+            step: Step::ExpectErr(StepExpectErr {
+                expect_err: built,
+                error: error.into(),
+            }),
+            issue,
+        }
+    }
+
+    /// Starts a `maybe` block explored both with and without its steps.
+    #[must_use]
+    pub fn maybe(because: impl Into<String>) -> Self {
+        Self::block(Step::Maybe(StepMaybe {
+            maybe: MaybeBlock {
+                because: Symbol::from(because.into()),
+                do_steps: Vec::new(),
+                else_steps: Vec::new(),
+            },
+        }))
+    }
+
+    /// Starts a `repeat` block that runs its steps `times` times.
+    #[must_use]
+    pub const fn repeat(times: u32) -> Self {
+        Self::block(Step::Repeat(StepRepeat {
+            repeat: RepeatBlock {
+                times,
+                do_steps: Vec::new(),
+            },
+        }))
+    }
+
+    /// Starts a `foreach` block that runs its steps once per element of
+    /// `source`, bound to `as_binding`.
+    #[must_use]
+    pub fn foreach(source: impl Into<String>, as_binding: impl Into<String>) -> Self {
+        Self::block(Step::Foreach(StepForeach {
+            foreach: ForeachBlock {
+                source: source.into(),
+                as_binding: as_binding.into(),
+                do_steps: Vec::new(),
+            },
+        }))
+    }
+
+    /// Starts an inline `assert` step.
+    #[must_use]
+    pub fn assert(expr: impl Into<String>, because: impl Into<String>) -> Self {
+        Self::block(Step::Assert(StepAssert {
+            assert: InlineAssert {
+                expr: expr.into(),
+                because: Symbol::from(because.into()),
+            },
+        }))
+    }
+
+    /// Sets the `id` that `Prove` assertions use to refer to a `call` or
+    /// `must` step. Other steps record a mistake.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        match &mut self.step {
+            Step::Call(step) => step.id = Some(id.into()),
+            Step::Must(step) => step.id = Some(id.into()),
+            Step::ExpectErr(_)
+            | Step::Maybe(_)
+            | Step::Repeat(_)
+            | Step::Foreach(_)
+            | Step::Assert(_) => {
+                let reason = format!("{} steps cannot declare an id", self.step.keyword());
+                return self.record(Some(BuildIssue::Misuse(reason)));
+            }
+        }
+        self
+    }
+
+    /// Appends a step to the `do` list of a `maybe`, `repeat`, or
+    /// `foreach` block. Other steps record a mistake.
+    #[must_use]
+    pub fn step(mut self, nested: Self) -> Self {
+        let keyword = self.step.keyword();
+        let target = match &mut self.step {
+            Step::Maybe(step) => Some((&mut step.maybe.do_steps, "maybe.do step")),
+            Step::Repeat(step) => Some((&mut step.repeat.do_steps, "repeat.do step")),
+            Step::Foreach(step) => Some((&mut step.foreach.do_steps, "foreach.do step")),
+            Step::Call(_) | Step::Must(_) | Step::ExpectErr(_) | Step::Assert(_) => None,
+        };
+        let issue = match target {
+            Some((steps, path)) => push_nested(steps, path, nested),
+            None => Some(BuildIssue::Misuse(format!(
+                "{keyword} steps cannot contain nested steps"
+            ))),
+        };
+        self.record(issue)
+    }
+
+    /// Appends a step to the `else` list of a `maybe` block. Other steps
+    /// record a mistake.
+    #[must_use]
+    pub fn else_step(mut self, nested: Self) -> Self {
+        let keyword = self.step.keyword();
+        let issue = match &mut self.step {
+            Step::Maybe(step) => push_nested(&mut step.maybe.else_steps, "maybe.else step", nested),
+            Step::Call(_)
+            | Step::Must(_)
+            | Step::ExpectErr(_)
+            | Step::Repeat(_)
+            | Step::Foreach(_)
+            | Step::Assert(_) => Some(BuildIssue::Misuse(format!(
+                "{keyword} steps cannot contain else steps"
+            ))),
+        };
+        self.record(issue)
+    }
+
+    pub(super) fn finish(self) -> (Step, Option<BuildIssue>) {
+        (self.step, self.issue)
+    }
+
+    const fn block(step: Step) -> Self {
+        Self { step, issue: None }
+    }
+
+    /// Keeps the first mistake found while building.
+    fn record(mut self, issue: Option<BuildIssue>) -> Self {
+        if self.issue.is_none() {
+            self.issue = issue;
+        }
+        self
+    }
+}
+
+/// Appends `nested` to `steps`, locating any mistake it carries by `path`
+/// and its 1-based position.
+fn push_nested(steps: &mut Vec<Step>, path: &str, nested: StepBuilder) -> Option<BuildIssue> {
+    let (step, issue) = nested.finish();
+    steps.push(step);
+    issue.map(|found| found.nested(&format!("{path} {}", steps.len())))
+}
+
+// ── Assertions ──────────────────────────────────────────────────────
+
+/// Builds a `Prove` [`Assertion`].
+#[derive(Debug, Clone)]
+pub struct AssertionBuilder {
+    expr: String,
+    because: Symbol,
+    step: Option<String>,
+}
+
+impl AssertionBuilder {
+    /// Starts an assertion of the Rust boolean expression `expr`.
+    #[must_use]
+    pub fn new(expr: impl Into<String>, because: impl Into<String>) -> Self {
+        Self {
+            expr: expr.into(),
+            because: Symbol::from(because.into()),
+            step: None,
+        }
+    }
+
+    /// Ties the assertion to the `Do` step with the given `id`.
+    #[must_use]
+    pub fn step(mut self, id: impl Into<String>) -> Self {
+        self.step = Some(id.into());
+        self
+    }
+
+    /// Builds the assertion as `Prove` entry `index`, or returns why its
+    /// expression is rejected.
+    pub(super) fn finish(self, index: usize) -> Result<Assertion, String> {
+        let assert_expr = TheoremExpr::for_entry(self.expr, IndexedValidationSection::Prove, index)
+            .map_err(|rejected| rejected.reason)?;
+        Ok(Assertion {
+            assert_expr,
+            because: self.because,
+            step: self.step,
+        })
+    }
+}
@@ -0,0 +1,319 @@
+//! Unit tests for the fluent theorem document builder.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{AssertionBuilder, CallBuilder, KaniBuilder, StepBuilder, TheoremDocBuilder};
+use crate::schema::{
+    ActionSignature, ArgValue, CounterexampleConstraint, ForallDomain, KaniExpectation, KaniSolver,
+    LiteralValue, NumericBound, SchemaError, Symbol, TheoremDoc, TraceLink, emit_theorem_docs,
+    load_theorem_docs,
+};
+
+fn load_one(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("source should load")
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem")
+}
+
+fn deposit_signature() -> ActionSignature {
+    ActionSignature {
+        params: [("amount".to_owned(), "Amount".into())].into(),
+        returns: "Result<u64, crate::LedgerError>".into(),
+    }
+}
+
+fn minimal() -> TheoremDocBuilder {
+    TheoremDocBuilder::new("Minimal", "A minimal theorem")
+        .forall("x", "u8")
+        .prove(AssertionBuilder::new("x <= 255", "u8 is bounded"))
+        .witness("x == 0", "zero is reachable")
+        .kani(KaniBuilder::new(1, KaniExpectation::Success))
+}
+
+fn build_error(builder: TheoremDocBuilder) -> String {
+    builder
+        .build()
+        .expect_err("builder should be rejected")
+        .to_string()
+}
+
+const DEPOSIT: &str = r#"
+Schema: 1
+Theorem: Deposit
+About: Deposits grow the balance
+Tags: [ledger]
+Traces:
+  - { id: REQ-1, system: specs }
+Given:
+  - an empty ledger
+Types:
+  Amount: u64
+Forall:
+  amount: { type: Amount, min: 1, max: 10 }
+  items: { vec: u32, len: { max: 3 } }
+Actions:
+  ledger.deposit:
+    params: { amount: Amount }
+    returns: "Result<u64, crate::LedgerError>"
+Assume:
+  - assume: "amount < 10"
+    because: bounded
+Let:
+  start:
+    must:
+      action: ledger.deposit
+      args: { amount: { ref: amount } }
+Do:
+  - must:
+      action: ledger.deposit
+      args: { amount: { expr: "amount + 1" } }
+      as: after
+    id: deposit
+  - maybe:
+      because: sometimes
+      do:
+        - assert: { expr: "after > 0", because: deposits add }
+      else:
+        - repeat:
+            times: 2
+            do:
+              - expect_err:
+                  action: ledger.deposit
+                  args: { amount: 0 }
+                  error: Zero
+  - foreach:
+      in: items
+      as: item
+      do:
+        - call:
+            action: ledger.deposit
+            args: { amount: { ref: amount } }
+Prove:
+  - assert: "after > amount"
+    because: deposits add
+    step: deposit
+Evidence:
+  kani:
+    unwind: { default: 4, retry: 5 }
+    expect: FAILURE
+    solver: kissat
+    counterexample:
+      amount: { satisfies: "amount > 1" }
+Witness:
+  - cover: "amount == 1"
+    because: reachable
+"#;
+
+fn deposit() -> TheoremDocBuilder {
+    TheoremDocBuilder::new("Deposit", "Deposits grow the balance")
+        .schema(1)
+        .tag("ledger")
+        .trace(TraceLink {
+            id: "REQ-1".to_owned(),
+            system: "specs".to_owned(),
+            url: None,
+        })
+        .given("an empty ledger")
+        .type_alias("Amount", "u64")
+        .forall_domain(
+            "amount",
+            "Amount",
+            ForallDomain::Range {
+                min: Some(NumericBound::Integer(1)),
+                max: Some(NumericBound::Integer(10)),
+            },
+        )
+        .forall_domain("items", "Vec<u32>", ForallDomain::Length { min: 0, max: 3 })
+        .action("ledger.deposit", deposit_signature())
+        .assume("amount < 10", "bounded")
+        .let_must(
+            "start",
+            CallBuilder::new("ledger.deposit").arg_ref("amount", "amount"),
+        )
+        .step(
+            StepBuilder::must(
+                CallBuilder::new("ledger.deposit")
+                    .arg_expr("amount", "amount + 1")
+                    .bind("after"),
+            )
+            .id("deposit"),
+        )
+        .step(
+            StepBuilder::maybe("sometimes")
+                .step(StepBuilder::assert("after > 0", "deposits add"))
+                .else_step(
+                    StepBuilder::repeat(2).step(StepBuilder::expect_err(
+                        CallBuilder::new("ledger.deposit")
+                            .arg("amount", ArgValue::Literal(LiteralValue::Integer(0))),
+                        "Zero",
+                    )),
+                ),
+        )
+        .step(
+            StepBuilder::foreach("items", "item").step(StepBuilder::call(
+                CallBuilder::new("ledger.deposit").arg_ref("amount", "amount"),
+            )),
+        )
+        .prove(AssertionBuilder::new("after > amount", "deposits add").step("deposit"))
+        .kani(
+            KaniBuilder::new(4, KaniExpectation::Failure)
+                .loop_unwind("retry", 5)
+                .solver(KaniSolver::Kissat)
+                .counterexample(
+                    "amount",
+                    CounterexampleConstraint::Satisfies {
+                        satisfies: "amount > 1".to_owned(),
+                    },
+                ),
+        )
+        .witness("amount == 1", "reachable")
+}
+
+#[test]
+fn built_document_equals_the_loaded_yaml() {
+    let built = deposit().build().expect("builder should succeed");
+
+    assert_eq!(built, load_one(DEPOSIT));
+}
+
+#[test]
+fn built_document_survives_emission() {
+    let built = deposit().build().expect("builder should succeed");
+
+    let emitted = emit_theorem_docs(std::slice::from_ref(&built));
+
+    assert_eq!(load_one(&emitted), built);
+}
+
+#[test]
+fn type_aliases_are_expanded_on_build() {
+    let built = deposit().build().expect("builder should succeed");
+
+    assert_eq!(built.forall.get("amount").map(Symbol::as_str), Some("u64"));
+}
+
+#[rstest]
+#[case::theorem_name(TheoremDocBuilder::new("1st", "bad name"), "invalid identifier '1st'")]
+#[case::depends_on(minimal().depends_on("not valid"), "invalid identifier 'not valid'")]
+#[case::forall_var(minimal().forall("fn", "u8"), "invalid identifier 'fn'")]
+fn invalid_names_are_rejected(#[case] builder: TheoremDocBuilder, #[case] expected: &str) {
+    let error = build_error(builder);
+
+    assert!(error.contains(expected), "{error}");
+}
+
+#[rstest]
+#[case::no_proof(
+    TheoremDocBuilder::new("Empty", "nothing to prove")
+        .forall("x", "u8")
+        .witness("x == 0", "reachable")
+        .kani(KaniBuilder::new(1, KaniExpectation::Success)),
+    "Prove section must contain at least one assertion"
+)]
+#[case::no_witness(
+    minimal_without_witness(),
+    "Witness section must contain at least one witness"
+)]
+#[case::bad_expression(
+    minimal().assume("x +", "broken"),
+    "Assume constraint 1: expr"
+)]
+#[case::blank_cover(
+    minimal().witness("  ", "blank"),
+    "Witness 2: cover must be non-empty after trimming"
+)]
+#[case::statement_assertion(
+    minimal().prove(AssertionBuilder::new("{ x }; x", "a statement")),
+    "Prove assertion 2: assert"
+)]
+fn invalid_documents_are_rejected(#[case] builder: TheoremDocBuilder, #[case] expected: &str) {
+    let error = builder.build().expect_err("builder should be rejected");
+
+    assert!(
+        matches!(&error, SchemaError::ValidationFailed { .. }),
+        "{error:?}"
+    );
+    assert!(error.to_string().contains(expected), "{error}");
+}
+
+fn minimal_without_witness() -> TheoremDocBuilder {
+    TheoremDocBuilder::new("NoWitness", "a witness is required")
+        .forall("x", "u8")
+        .prove(AssertionBuilder::new("x <= 255", "u8 is bounded"))
+        .kani(KaniBuilder::new(1, KaniExpectation::Success))
+}
+
+#[rstest]
+#[case::let_ref(
+    minimal().let_call("value", CallBuilder::new("ledger.read").arg_ref("key", "fn")),
+    "Let binding 'value': argument 'key': ref value 'fn' is a Rust reserved keyword"
+)]
+#[case::step_expr(
+    minimal().step(StepBuilder::call(CallBuilder::new("ledger.read").arg_expr("key", "a +"))),
+    "Do step 1: argument 'key'"
+)]
+#[case::nested_ref(
+    minimal().step(StepBuilder::maybe("sometimes").step(StepBuilder::call(
+        CallBuilder::new("ledger.read").arg_ref("key", ""),
+    ))),
+    "Do step 1: argument 'maybe.do step 1: key': ref value must not be empty"
+)]
+fn invalid_arguments_are_rejected(#[case] builder: TheoremDocBuilder, #[case] expected: &str) {
+    let error = build_error(builder);
+
+    assert!(error.contains(expected), "{error}");
+}
+
+#[rstest]
+#[case::id_on_block(
+    minimal().step(StepBuilder::repeat(1).id("loop")),
+    "Do step 1: repeat steps cannot declare an id"
+)]
+#[case::nested_in_call(
+    minimal().step(
+        StepBuilder::call(CallBuilder::new("ledger.read")).step(StepBuilder::assert("x > 0", "why")),
+    ),
+    "Do step 1: call steps cannot contain nested steps"
+)]
+#[case::else_on_repeat(
+    minimal().step(StepBuilder::repeat(1).else_step(StepBuilder::assert("x > 0", "why"))),
+    "Do step 1: repeat steps cannot contain else steps"
+)]
+#[case::nested_misuse(
+    minimal().step(StepBuilder::maybe("sometimes").else_step(StepBuilder::assert("x > 0", "why").id("check"))),
+    "Do step 1: maybe.else step 1: assert steps cannot declare an id"
+)]
+#[case::duplicate_forall(minimal().forall("x", "u16"), "duplicate Forall variable 'x'")]
+#[case::duplicate_argument(
+    minimal().let_call(
+        "value",
+        CallBuilder::new("ledger.read").arg_ref("key", "x").arg_ref("key", "x"),
+    ),
+    "Let binding 'value': duplicate argument 'key'"
+)]
+#[case::duplicate_let(
+    minimal()
+        .let_call("value", CallBuilder::new("ledger.read"))
+        .let_call("value", CallBuilder::new("ledger.read")),
+    "duplicate Let binding 'value'"
+)]
+fn builder_misuse_is_reported_on_build(#[case] builder: TheoremDocBuilder, #[case] expected: &str) {
+    assert_eq!(
+        build_error(builder),
+        format!("validation failed for theorem 'Minimal': {expected}")
+    );
+}
+
+#[test]
+fn first_recorded_mistake_wins() {
+    let error = build_error(
+        minimal()
+            .forall("x", "u16")
+            .step(StepBuilder::repeat(1).id("loop")),
+    );
+
+    assert!(error.contains("duplicate Forall variable 'x'"), "{error}");
+}
@@ -0,0 +1,274 @@
+//! Content-addressed cache of validated theorem documents.
+//!
+//! A [`LoadCache`] maps the [`content_fingerprint`] of a theorem source to
+//! the documents it loaded, so repeated loads of an unchanged file skip
+//! parsing and validation. The cache can be saved to a file, conventionally
+//! [`cache_path`] below Cargo's target directory, and read back by the next
+//! invocation of a command-line run, watcher, or language server.
+//!
+//! Cache files record [`CACHE_SCHEMA_VERSION`] and the `theoremc` version
+//! that wrote them. [`LoadCache::load`] treats a missing, unreadable,
+//! corrupt, or differently versioned file as an empty cache, so a damaged
+//! cache only costs a cold load. [`LoadCache::save`] writes a temporary file
+//! and renames it into place, so readers never see a partly written cache.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use cap_std::{ambient_authority, fs_utf8::Dir as Utf8Dir};
+use serde::{Deserialize, Serialize};
+
+use crate::ledger::content_fingerprint;
+use crate::schema::{SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_source};
+use crate::theorem_file::{
+    TheoremFileLoadError, io_error_code, parse_theorem_file, read_theorem_file,
+};
+
+/// Version of the cache file layout and of the serialized [`TheoremDoc`]
+/// form. Files written with another version are ignored.
+pub const CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// Returns the conventional cache file below a Cargo target directory,
+/// `{target_dir}/theoremc/load-cache.json`.
+///
+/// # Examples
+///
+///     use camino::Utf8Path;
+///     use theoremc_core::cache::cache_path;
+///
+///     assert_eq!(
+///         cache_path(Utf8Path::new("target")),
+///         "target/theoremc/load-cache.json"
+///     );
+#[must_use]
+pub fn cache_path(target_dir: &Utf8Path) -> Utf8PathBuf {
+    target_dir.join("theoremc").join("load-cache.json")
+}
+
+/// Errors raised while saving a cache file.
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    /// The directory holding the cache file could not be created or opened.
+    #[error(
+        "failed to open cache directory '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    OpenDir {
+        /// Directory that failed to open.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: io::Error,
+    },
+
+    /// The cache file could not be written or moved into place.
+    #[error(
+        "failed to write cache '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    Write {
+        /// Cache file path.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: io::Error,
+    },
+
+    /// The cached documents could not be encoded as JSON.
+    #[error("failed to encode theorem cache: {source}")]
+    Encode {
+        /// Underlying encoding failure.
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Validated theorem documents keyed by the fingerprint of their source.
+///
+/// Only sources loaded without `Include` fragments are cached, because a
+/// fingerprint of the source alone cannot tell when a fragment changes.
+/// Documents whose JSON form does not read back identically, such as those
+/// holding non-finite floats, are loaded afresh each time rather than
+/// cached. Documents served from the cache share their `Let` and `Do`
+/// sections with the cached copy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadCache {
+    entries: BTreeMap<String, Vec<TheoremDoc>>,
+}
+
+/// On-disk form of a [`LoadCache`].
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CacheFile<'a> {
+    schema_version: u32,
+    theoremc_version: Cow<'a, str>,
+    entries: Cow<'a, BTreeMap<String, Vec<TheoremDoc>>>,
+}
+
+impl LoadCache {
+    /// Creates an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the cache saved at `path`.
+    ///
+    /// Returns an empty cache when the file is missing, unreadable, not a
+    /// cache file, or was written by another [`CACHE_SCHEMA_VERSION`] or
+    /// `theoremc` version.
+    #[must_use]
+    pub fn load(path: &Utf8Path) -> Self {
+        read_cache_file(path)
+            .and_then(|text| serde_json::from_str::<CacheFile<'_>>(&text).ok())
+            .filter(|file| {
+                file.schema_version == CACHE_SCHEMA_VERSION
+                    && file.theoremc_version == env!("CARGO_PKG_VERSION")
+            })
+            .map(|file| Self {
+                entries: file.entries.into_owned(),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Saves the cache to `path`, creating its parent directories.
+    ///
+    /// The cache is written to a temporary file beside `path` and renamed
+    /// over it, so an interrupted save leaves the previous cache intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheError::OpenDir`] when the parent directory cannot be
+    /// created, [`CacheError::Encode`] when a document cannot be encoded,
+    /// and [`CacheError::Write`] when the file cannot be written.
+    pub fn save(&self, path: &Utf8Path) -> Result<(), CacheError> {
+        let parent = parent_dir(path);
+        let dir = Utf8Dir::create_ambient_dir_all(parent, ambient_authority())
+            .and_then(|()| Utf8Dir::open_ambient_dir(parent, ambient_authority()))
+            .map_err(|source| CacheError::OpenDir {
+                path: parent.to_owned(),
+                source,
+            })?;
+        let contents = serde_json::to_string(&CacheFile {
+            schema_version: CACHE_SCHEMA_VERSION,
+            theoremc_version: Cow::Borrowed(env!("CARGO_PKG_VERSION")),
+            entries: Cow::Borrowed(&self.entries),
+        })
+        .map_err(|source| CacheError::Encode { source })?;
+        let file_name = path.file_name().unwrap_or_default();
+        let temp_name = format!(".{file_name}.tmp");
+        dir.write(&temp_name, contents)
+            .and_then(|()| dir.rename(&temp_name, &dir, file_name))
+            .map_err(|source| CacheError::Write {
+                path: path.to_owned(),
+                source,
+            })
+    }
+
+    /// Returns the number of cached sources.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` when no source is cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the cached documents of a source with these `contents`.
+    #[must_use]
+    pub fn get(&self, contents: &str) -> Option<&[TheoremDoc]> {
+        self.entries
+            .get(&content_fingerprint(contents))
+            .map(Vec::as_slice)
+    }
+
+    /// Loads theorem documents like
+    /// [`load_theorem_docs_with_source`], reusing the cached documents when
+    /// `contents` loaded before.
+    ///
+    /// Failed loads are not cached, so their diagnostics are reported again
+    /// on every call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`SchemaError`] of a source that fails to load.
+    pub fn load_source(
+        &mut self,
+        source: &SourceId,
+        contents: &str,
+    ) -> Result<Vec<TheoremDoc>, SchemaError> {
+        self.get_or_insert_with(contents, || load_theorem_docs_with_source(source, contents))
+    }
+
+    /// Loads the theorem file at `path` like
+    /// [`load_theorem_file`](crate::load_theorem_file), reusing the cached
+    /// documents when the file's contents loaded before.
+    ///
+    /// The file is always read, so edits are picked up without relying on
+    /// modification times.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`load_theorem_file`](crate::load_theorem_file).
+    pub fn load_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+        let (display_path, contents) = read_theorem_file(&path)?;
+        self.get_or_insert_with(&contents, || {
+            parse_theorem_file(path.as_ref(), &display_path, &contents)
+        })
+    }
+
+    fn get_or_insert_with<E>(
+        &mut self,
+        contents: &str,
+        load: impl FnOnce() -> Result<Vec<TheoremDoc>, E>,
+    ) -> Result<Vec<TheoremDoc>, E> {
+        let fingerprint = content_fingerprint(contents);
+        if let Some(docs) = self.entries.get(&fingerprint) {
+            return Ok(docs.clone());
+        }
+        let docs = load()?;
+        if is_self_contained(&docs) && round_trips(&docs) {
+            self.entries.insert(fingerprint, docs.clone());
+        }
+        Ok(docs)
+    }
+}
+
+/// Returns `true` when `docs` merged no `Include` fragments, whose contents
+/// the fingerprint of the including source does not cover.
+fn is_self_contained(docs: &[TheoremDoc]) -> bool {
+    docs.iter().all(|doc| doc.include.is_empty())
+}
+
+/// Returns `true` when `docs` decode from their JSON form unchanged.
+fn round_trips(docs: &[TheoremDoc]) -> bool {
+    serde_json::to_string(docs)
+        .ok()
+        .and_then(|json| serde_json::from_str::<Vec<TheoremDoc>>(&json).ok())
+        .is_some_and(|decoded| decoded == docs)
+}
+
+/// Returns the cache file's contents, or `None` when it cannot be read.
+fn read_cache_file(path: &Utf8Path) -> Option<String> {
+    let dir = Utf8Dir::open_ambient_dir(parent_dir(path), ambient_authority()).ok()?;
+    dir.read_to_string(path.file_name()?).ok()
+}
+
+fn parent_dir(path: &Utf8Path) -> &Utf8Path {
+    path.parent()
+        .filter(|parent| !parent.as_str().is_empty())
+        .unwrap_or_else(|| Utf8Path::new("."))
+}
+
+#[cfg(test)]
+#[path = "cache_tests.rs"]
+mod tests;
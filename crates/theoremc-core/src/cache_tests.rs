@@ -0,0 +1,241 @@
+//! Unit tests for the theorem document load cache.
+
+use std::sync::Arc;
+
+use camino::Utf8PathBuf;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::{CACHE_SCHEMA_VERSION, LoadCache, cache_path};
+use crate::schema::test_fixtures::theorem;
+use crate::schema::{SchemaError, SourceId, TheoremDoc, TheoremValue, load_theorem_docs};
+
+struct Target {
+    _temp_dir: TempDir,
+    dir: Utf8PathBuf,
+}
+
+fn target() -> Target {
+    let temp_dir = TempDir::new().expect("temp directory should be created");
+    let dir = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf())
+        .expect("temp directory should be UTF-8");
+    Target {
+        _temp_dir: temp_dir,
+        dir,
+    }
+}
+
+const KANI_LIMITS: &str = r"
+Theorem: Limits
+About: Kani limits and placeholder backends
+Forall:
+  amount: u64
+  kind: { type: u8, oneof: [1, 2] }
+Prove:
+  - assert: 'amount >= 0'
+    because: unsigned
+Evidence:
+  kani:
+    - name: fast
+      unwind: 2
+      expect: FAILURE
+      timeout: 1m 30s
+      memory_limit: 512MiB
+      counterexample:
+        amount: 0
+        kind: { satisfies: 'kind == 1' }
+    - name: slow
+      unwind: 4
+      expect: SUCCESS
+      solver: kissat
+      stubs:
+        - { original: std::time::SystemTime::now, stub: crate::fixed_now }
+  verus: { mode: spec, bounds: [1, 2.5, true] }
+Witness:
+  - cover: 'amount == 1'
+    because: reachable
+";
+
+#[rstest]
+#[case::full(include_str!("../../../tests/fixtures/valid_full.theorem"))]
+#[case::forall_domains(include_str!("../../../tests/fixtures/valid_forall_domains.theorem"))]
+#[case::forall_optional(include_str!("../../../tests/fixtures/valid_forall_optional.theorem"))]
+#[case::expr_args(include_str!("../../../tests/fixtures/valid_expr_args.theorem"))]
+#[case::repeat(include_str!("../../../tests/fixtures/valid_repeat.theorem"))]
+#[case::foreach(include_str!("../../../tests/fixtures/valid_foreach.theorem"))]
+#[case::expect_err(include_str!("../../../tests/fixtures/valid_expect_err.theorem"))]
+#[case::traces(include_str!("../../../tests/fixtures/valid_traces.theorem"))]
+#[case::kani_limits(KANI_LIMITS)]
+fn documents_survive_a_json_round_trip(#[case] yaml: &str) {
+    let docs = load_theorem_docs(yaml).expect("fixture should load");
+
+    let json = serde_json::to_string(&docs).expect("documents should encode");
+    let decoded: Vec<TheoremDoc> = serde_json::from_str(&json).expect("documents should decode");
+
+    assert_eq!(decoded, docs);
+}
+
+#[test]
+fn repeated_sources_are_served_from_the_cache() {
+    let contents = theorem("Cached", "");
+    let mut cache = LoadCache::new();
+
+    let first = cache
+        .load_source(&SourceId::new("a.theorem"), &contents)
+        .expect("source should load");
+    let second = cache
+        .load_source(&SourceId::new("b.theorem"), &contents)
+        .expect("source should load");
+
+    assert_eq!(first, second);
+    assert!(
+        first
+            .iter()
+            .zip(&second)
+            .all(|(one, other)| Arc::ptr_eq(&one.do_steps, &other.do_steps))
+    );
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get(&contents), Some(first.as_slice()));
+}
+
+#[test]
+fn failed_loads_are_not_cached() {
+    let contents = "Theorem: Broken\nAbout: ''\n";
+    let mut cache = LoadCache::new();
+
+    cache
+        .load_source(&SourceId::new("broken.theorem"), contents)
+        .expect_err("source should fail");
+
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn saved_caches_load_back() {
+    let fixture = target();
+    let path = cache_path(&fixture.dir);
+    let mut cache = LoadCache::new();
+    for yaml in [theorem("First", ""), KANI_LIMITS.to_owned()] {
+        cache
+            .load_source(&SourceId::new("inline"), &yaml)
+            .expect("source should load");
+    }
+
+    cache.save(&path).expect("cache should save");
+    let reloaded = LoadCache::load(&path);
+
+    assert_eq!(reloaded, cache);
+    assert!(!fixture.dir.join("theoremc/.load-cache.json.tmp").exists());
+}
+
+#[test]
+fn file_edits_are_picked_up() {
+    let fixture = target();
+    let path = fixture.dir.join("ledger.theorem");
+    let mut cache = LoadCache::new();
+
+    std::fs::write(&path, theorem("Before", "")).expect("fixture should be written");
+    let before = cache.load_file(&path).expect("file should load");
+    std::fs::write(&path, theorem("After", "")).expect("fixture should be rewritten");
+    let after = cache.load_file(&path).expect("file should load");
+
+    assert_eq!(
+        [before, after].map(|docs| docs.first().map(|doc| doc.theorem.to_string())),
+        [Some("Before".to_owned()), Some("After".to_owned())]
+    );
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn files_with_includes_load_their_fragments_uncached() {
+    let fixture = target();
+    let path = fixture.dir.join("theorems/ledger.theorem");
+    std::fs::write(fixture.dir.join("Cargo.toml"), "").expect("manifest should be written");
+    std::fs::create_dir_all(fixture.dir.join("theorems/shared"))
+        .expect("fixture directory should be created");
+    std::fs::write(
+        fixture.dir.join("theorems/shared/bounds.yaml"),
+        "Forall:\n  amount: u64\n",
+    )
+    .expect("fragment should be written");
+    std::fs::write(
+        &path,
+        theorem("Bounded", "Include: [theorems/shared/bounds.yaml]\n"),
+    )
+    .expect("fixture should be written");
+    let mut cache = LoadCache::new();
+
+    let docs = cache.load_file(&path).expect("file should load");
+
+    assert_eq!(
+        docs.first()
+            .map(|doc| doc.forall.keys().map(AsRef::as_ref).collect::<Vec<&str>>()),
+        Some(vec!["amount"])
+    );
+    assert!(
+        cache.is_empty(),
+        "fragments are not covered by the fingerprint"
+    );
+}
+
+#[rstest]
+#[case::missing(None)]
+#[case::truncated(Some("{\"schema_version\": 1, \"entries\": {".to_owned()))]
+#[case::not_json(Some("\u{0}\u{1}garbage".to_owned()))]
+#[case::other_schema_version(Some(format!(
+    "{{\"schema_version\":{},\"theoremc_version\":\"{}\",\"entries\":{{}}}}",
+    CACHE_SCHEMA_VERSION + 1,
+    env!("CARGO_PKG_VERSION"),
+)))]
+#[case::other_theoremc_version(Some(format!(
+    "{{\"schema_version\":{CACHE_SCHEMA_VERSION},\"theoremc_version\":\"0.0.0-old\",\"entries\":{{}}}}"
+)))]
+#[case::invalid_document(Some(format!(
+    "{{\"schema_version\":{CACHE_SCHEMA_VERSION},\"theoremc_version\":\"{}\",\"entries\":{{\"blake3:00\":[{{\"theorem\":\"fn\"}}]}}}}",
+    env!("CARGO_PKG_VERSION"),
+)))]
+fn unusable_cache_files_read_as_empty(#[case] contents: Option<String>) {
+    let fixture = target();
+    let path = cache_path(&fixture.dir);
+    if let Some(text) = contents {
+        std::fs::create_dir_all(fixture.dir.join("theoremc")).expect("cache dir should exist");
+        std::fs::write(&path, text).expect("cache file should be written");
+    }
+
+    assert!(LoadCache::load(&path).is_empty());
+}
+
+#[test]
+fn saving_replaces_an_unusable_cache_file() {
+    let fixture = target();
+    let path = cache_path(&fixture.dir);
+    std::fs::create_dir_all(fixture.dir.join("theoremc")).expect("cache dir should exist");
+    std::fs::write(&path, "not a cache").expect("cache file should be written");
+    let mut cache = LoadCache::load(&path);
+    cache
+        .load_source(&SourceId::new("inline"), &theorem("Fresh", ""))
+        .expect("source should load");
+
+    cache.save(&path).expect("cache should save");
+
+    assert_eq!(LoadCache::load(&path).len(), 1);
+}
+
+#[test]
+fn documents_without_an_exact_json_form_are_not_cached() {
+    let contents = theorem("NotANumber", "");
+    let mut docs = load_theorem_docs(&contents).expect("source should load");
+    for doc in &mut docs {
+        doc.evidence.verus = Some(TheoremValue::Float(f64::NAN));
+    }
+    let mut cache = LoadCache::new();
+
+    let loaded = cache
+        .get_or_insert_with(&contents, || Ok::<_, SchemaError>(docs))
+        .expect("documents should be returned");
+
+    assert_eq!(loaded.len(), 1);
+
+    assert!(cache.is_empty());
+}
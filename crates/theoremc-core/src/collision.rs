@@ -18,7 +18,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::mangle::mangle_action_name;
-use crate::schema::{LetBinding, SchemaError, Step, TheoremDoc, rust_type};
+use crate::schema::{ForallDomain, LetBinding, SchemaError, Step, Symbol, TheoremDoc, rust_type};
 
 /// Mangles a canonical action name string and returns the identifier.
 fn mangle_to_identifier(name: &str) -> String {
@@ -86,6 +86,44 @@ fn check_action_collisions_with(
     })
 }
 
+/// Checks the actions `doc` references against `seen`, which maps each
+/// mangled identifier to the canonical name that first produced it, and
+/// records the new ones.
+///
+/// This lets a caller that loads documents one at a time detect the same
+/// collisions as [`check_action_collisions`] over the whole set.
+pub(crate) fn check_new_action_collisions(
+    seen: &mut BTreeMap<String, String>,
+    doc: &TheoremDoc,
+) -> Result<(), SchemaError> {
+    let mut occurrences = Vec::new();
+    collect_doc_actions(doc, &mut occurrences);
+    let mut mangled_collisions: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for occurrence in occurrences {
+        let identifier = mangle_to_identifier(occurrence.canonical);
+        match seen.get(&identifier) {
+            Some(earlier) if earlier != occurrence.canonical => {
+                mangled_collisions
+                    .entry(identifier)
+                    .or_insert_with(|| BTreeSet::from([earlier.clone()]))
+                    .insert(occurrence.canonical.to_owned());
+            }
+            Some(_) => {}
+            None => {
+                seen.insert(identifier, occurrence.canonical.to_owned());
+            }
+        }
+    }
+
+    if mangled_collisions.is_empty() {
+        return Ok(());
+    }
+
+    Err(SchemaError::MangledIdentifierCollision {
+        message: format_collision_message(&mangled_collisions),
+    })
+}
+
 // ── Action-name collection ──────────────────────────────────────────
 
 /// A single occurrence of a canonical action name within a theorem.
@@ -128,9 +166,14 @@ pub fn referenced_types(docs: &[TheoremDoc]) -> Vec<&str> {
 fn collect_referenced_type_occurrences(docs: &[TheoremDoc]) -> Vec<&str> {
     let mut out = Vec::new();
     for doc in docs {
-        out.extend(doc.forall.values().map(String::as_str));
+        out.extend(doc.forall.values().map(Symbol::as_str));
+        out.extend(
+            doc.forall_domains
+                .values()
+                .flat_map(ForallDomain::nested_types),
+        );
         for signature in doc.actions.values() {
-            out.extend(signature.params.values().map(String::as_str));
+            out.extend(signature.params.values().map(Symbol::as_str));
             out.push(signature.returns.as_str());
         }
     }
@@ -193,11 +236,21 @@ fn collect_step_actions<'a>(
                     theorem,
                 });
             }
-            Step::Maybe(s) => {
-                for nested in s.maybe.do_steps.iter().rev() {
-                    stack.push(nested);
-                }
+            Step::ExpectErr(e) => {
+                out.push(ActionOccurrence {
+                    canonical: &e.expect_err.action,
+                    theorem,
+                });
+            }
+            Step::Maybe(_) | Step::Repeat(_) | Step::Foreach(_) => {
+                let nested: Vec<&Step> = step
+                    .nested_branches()
+                    .into_iter()
+                    .flat_map(|(_, branch)| branch)
+                    .collect();
+                stack.extend(nested.into_iter().rev());
             }
+            Step::Assert(_) => {}
         }
     }
 }
@@ -33,16 +33,16 @@ fn referenced_types_collects_forall_params_and_returns_in_first_seen_order(
 ) {
     let mut doc = theorem_doc("First", IndexMap::new(), Vec::new(), &boilerplate);
     doc.forall
-        .insert(forall_var("account"), "crate::Account".to_owned());
-    doc.forall.insert(forall_var("limit"), "u64".to_owned());
+        .insert(forall_var("account"), "crate::Account".into());
+    doc.forall.insert(forall_var("limit"), "u64".into());
     doc.actions.insert(
         "account.deposit".to_owned(),
         ActionSignature {
             params: IndexMap::from([
-                ("command".to_owned(), "crate::DepositCommand".to_owned()),
-                ("audit".to_owned(), "crate::AuditRecord".to_owned()),
+                ("command".to_owned(), "crate::DepositCommand".into()),
+                ("audit".to_owned(), "crate::AuditRecord".into()),
             ]),
-            returns: "crate::DepositOutcome".to_owned(),
+            returns: "crate::DepositOutcome".into(),
         },
     );
 
@@ -61,22 +61,20 @@ fn referenced_types_collects_forall_params_and_returns_in_first_seen_order(
 #[rstest]
 fn referenced_types_deduplicate_by_canonical_type_tokens(boilerplate: DocBoilerplate) {
     let mut first = theorem_doc("First", IndexMap::new(), Vec::new(), &boilerplate);
-    first
-        .forall
-        .insert(forall_var("payload"), "Vec<u8>".to_owned());
+    first.forall.insert(forall_var("payload"), "Vec<u8>".into());
     first.actions.insert(
         "payload.write".to_owned(),
         ActionSignature {
-            params: IndexMap::from([("buffer".to_owned(), "Vec <u8>".to_owned())]),
-            returns: "u64".to_owned(),
+            params: IndexMap::from([("buffer".to_owned(), "Vec <u8>".into())]),
+            returns: "u64".into(),
         },
     );
     let mut second = theorem_doc("Second", IndexMap::new(), Vec::new(), &boilerplate);
     second.actions.insert(
         "payload.read".to_owned(),
         ActionSignature {
-            params: IndexMap::from([("buffer".to_owned(), "Vec<u8>".to_owned())]),
-            returns: "u64".to_owned(),
+            params: IndexMap::from([("buffer".to_owned(), "Vec<u8>".into())]),
+            returns: "u64".into(),
         },
     );
 
@@ -116,14 +114,14 @@ mod referenced_types_proptests {
         match position % 3 {
             0 => {
                 doc.forall
-                    .insert(forall_var(&format!("value_{position}")), ty.to_owned());
+                    .insert(forall_var(&format!("value_{position}")), ty.into());
             }
             1 => {
                 doc.actions.insert(
                     action_name,
                     ActionSignature {
-                        params: IndexMap::from([("value".to_owned(), ty.to_owned())]),
-                        returns: ty.to_owned(),
+                        params: IndexMap::from([("value".to_owned(), ty.into())]),
+                        returns: ty.into(),
                     },
                 );
             }
@@ -132,7 +130,7 @@ mod referenced_types_proptests {
                     action_name,
                     ActionSignature {
                         params: IndexMap::new(),
-                        returns: ty.to_owned(),
+                        returns: ty.into(),
                     },
                 );
             }
@@ -197,7 +195,7 @@ mod referenced_types_proptests {
             let mut doc = theorem_doc("Whitespace", IndexMap::new(), Vec::new(), &boilerplate);
             for (position, ty) in variants.iter().enumerate() {
                 doc.forall
-                    .insert(forall_var(&format!("value_{position}")), ty.clone());
+                    .insert(forall_var(&format!("value_{position}")), ty.into());
             }
 
             let docs = [doc];
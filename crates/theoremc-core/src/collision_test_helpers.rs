@@ -2,8 +2,8 @@
 
 use super::{LetBinding, Step, TheoremDoc};
 use crate::schema::{
-    ActionCall, Assertion, Evidence, KaniEvidence, KaniExpectation, LetCall, StepCall, TheoremName,
-    WitnessCheck,
+    ActionCall, Assertion, Evidence, KaniEvidence, KaniExpectation, LetCall, StepCall, TheoremExpr,
+    TheoremName, WitnessCheck,
 };
 use indexmap::IndexMap;
 
@@ -20,22 +20,34 @@ pub(super) struct DocBoilerplate {
 pub(super) fn boilerplate() -> DocBoilerplate {
     DocBoilerplate {
         evidence: Evidence {
-            kani: Some(KaniEvidence {
+            kani: vec![KaniEvidence {
+                name: None,
                 unwind: 1,
+                loop_unwind: IndexMap::new(),
                 expect: KaniExpectation::Success,
                 allow_vacuous: false,
                 vacuity_because: None,
-            }),
+                solver: None,
+                extra_args: Vec::new(),
+                timeout: None,
+                memory_limit: None,
+                stubs: Vec::new(),
+                contracts: Vec::new(),
+                counterexample: IndexMap::new(),
+                playback: false,
+                coverage: false,
+            }],
             verus: None,
             stateright: None,
         },
         assertions: vec![Assertion {
-            assert_expr: "true".to_owned(),
-            because: "trivial".to_owned(),
+            assert_expr: TheoremExpr::new("true").expect("literal expression should be valid"),
+            because: "trivial".into(),
+            step: None,
         }],
         witnesses: vec![WitnessCheck {
-            cover: "true".to_owned(),
-            because: "reachable".to_owned(),
+            cover: TheoremExpr::new("true").expect("literal expression should be valid"),
+            because: "reachable".into(),
         }],
     }
 }
@@ -43,7 +55,7 @@ pub(super) fn boilerplate() -> DocBoilerplate {
 /// Builds an `ActionCall` with the given action name and empty args.
 pub(super) fn action_call(name: &str) -> ActionCall {
     ActionCall {
-        action: name.to_owned(),
+        action: name.into(),
         args: IndexMap::new(),
         as_binding: None,
     }
@@ -61,15 +73,21 @@ pub(super) fn theorem_doc(
         theorem: TheoremName::new(name.to_owned()).expect("valid theorem name"),
         about: "test theorem".to_owned(),
         tags: Vec::new(),
+        traces: Vec::new(),
         given: Vec::new(),
+        depends_on: Vec::new(),
+        include: Vec::new(),
+        types: IndexMap::new(),
         forall: IndexMap::new(),
+        forall_domains: IndexMap::new(),
         actions: IndexMap::new(),
         assume: Vec::new(),
         witness: bp.witnesses.clone(),
-        let_bindings,
-        do_steps,
+        let_bindings: let_bindings.into(),
+        do_steps: do_steps.into(),
         prove: bp.assertions.clone(),
         evidence: bp.evidence.clone(),
+        lints: IndexMap::new(),
     }
 }
 
@@ -98,6 +116,7 @@ pub(super) fn doc_with_do_actions(name: &str, actions: &[&str], bp: &DocBoilerpl
         .map(|a| {
             Step::Call(StepCall {
                 call: action_call(a),
+                id: None,
             })
         })
         .collect();
@@ -34,11 +34,13 @@ fn collect_from_do_steps(boilerplate: DocBoilerplate) {
 fn collect_from_nested_maybe(boilerplate: DocBoilerplate) {
     let inner_step = Step::Must(StepMust {
         must: action_call("inner.action"),
+        id: None,
     });
     let maybe = Step::Maybe(StepMaybe {
         maybe: crate::schema::MaybeBlock {
-            because: "optional branch".to_owned(),
+            because: "optional branch".into(),
             do_steps: vec![inner_step],
+            else_steps: Vec::new(),
         },
     });
     let doc = theorem_doc("T", IndexMap::new(), vec![maybe], &boilerplate);
@@ -59,6 +61,7 @@ fn collect_from_let_and_do_combined(boilerplate: DocBoilerplate) {
     );
     let steps = vec![Step::Call(StepCall {
         call: action_call("account.validate"),
+        id: None,
     })];
     let doc = theorem_doc("T", let_bindings, steps, &boilerplate);
     let mut out = Vec::new();
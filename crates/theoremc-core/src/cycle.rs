@@ -0,0 +1,36 @@
+//! Cycle path rendering shared by the `DependsOn`, `Include`, and `Extends`
+//! checks.
+//!
+//! Each check walks its relationship depth-first while keeping the chain of
+//! entries it is currently inside. When an edge leads back into that chain,
+//! [`cycle_path`] trims the entries that merely lead into the loop, so the
+//! diagnostic names exactly the members of the cycle, such as
+//! `A -> B -> C -> A`.
+
+/// Renders the cycle closed by an edge from the end of `chain` to `closing`.
+///
+/// Returns `None` when `closing` is not on `chain`, meaning the edge does not
+/// close a cycle.
+pub(crate) fn cycle_path<'a, I>(chain: I, closing: &str) -> Option<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let members: Vec<&str> = chain
+        .into_iter()
+        .skip_while(|&member| member != closing)
+        .collect();
+    if members.is_empty() {
+        return None;
+    }
+    Some(
+        members
+            .into_iter()
+            .chain(std::iter::once(closing))
+            .collect::<Vec<_>>()
+            .join(" -> "),
+    )
+}
+
+#[cfg(test)]
+#[path = "cycle_tests.rs"]
+mod tests;
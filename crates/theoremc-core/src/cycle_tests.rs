@@ -0,0 +1,23 @@
+//! Unit tests for cycle path rendering.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::cycle_path;
+
+#[rstest]
+#[case::self_edge(&["A"], "A", Some("A -> A"))]
+#[case::whole_chain(&["A", "B", "C"], "A", Some("A -> B -> C -> A"))]
+#[case::lead_in_trimmed(&["Root", "A", "B"], "A", Some("A -> B -> A"))]
+#[case::not_on_chain(&["A", "B"], "C", None)]
+#[case::empty_chain(&[], "A", None)]
+fn cycle_paths_name_only_cycle_members(
+    #[case] chain: &[&str],
+    #[case] closing: &str,
+    #[case] expected: Option<&str>,
+) {
+    assert_eq!(
+        cycle_path(chain.iter().copied(), closing).as_deref(),
+        expected
+    );
+}
@@ -0,0 +1,189 @@
+//! Theorem dependency graphs built from `DependsOn` declarations.
+//!
+//! A theorem that lists another under `DependsOn` is only worth checking
+//! once its prerequisites hold. [`TheoremGraph`] is the one public view of
+//! those declarations: it resolves them into a deterministic execution
+//! order, answers which theorems depend on one that failed or changed, and
+//! exports the graph.
+//!
+//! The loader checks `DependsOn` with the same rules when a source is loaded,
+//! so graphs built from one loaded source always succeed; building a graph
+//! from several sources re-checks the combined set.
+
+use std::collections::HashMap;
+
+use crate::cycle::cycle_path;
+use crate::schema::{SchemaDiagnosticCode, SchemaError};
+
+#[path = "dependency_graph.rs"]
+mod graph;
+
+pub use graph::{Direction, GraphExporter, NodeIndex, TheoremGraph, TheoremNode};
+
+/// A `DependsOn` entry that cannot be resolved into an acyclic graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DependencyIssue {
+    /// Name of the theorem declaring the offending entry.
+    pub(crate) theorem: String,
+    /// Zero-based position of the offending entry in `DependsOn`.
+    pub(crate) entry: usize,
+    /// Human-readable explanation of the problem.
+    pub(crate) reason: String,
+    /// Diagnostic code classifying the problem.
+    pub(crate) code: SchemaDiagnosticCode,
+}
+
+/// `DependsOn` declarations resolved to node positions, checked to be
+/// unique, known, and acyclic.
+///
+/// This is the shared resolver behind [`TheoremGraph`] and the loader's
+/// `DependsOn` checks; callers outside the crate use [`TheoremGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ResolvedDependencies {
+    /// Direct prerequisite positions of each node, in `DependsOn` order.
+    prerequisites: Vec<Vec<usize>>,
+    /// Every node position with prerequisites first; independent nodes keep
+    /// their given order.
+    order: Vec<usize>,
+}
+
+impl ResolvedDependencies {
+    /// Resolves `(theorem, DependsOn)` pairs given in document order.
+    pub(crate) fn from_nodes(nodes: &[(&str, Vec<&str>)]) -> Result<Self, DependencyIssue> {
+        let mut index = HashMap::with_capacity(nodes.len());
+        for (position, (name, _)) in nodes.iter().enumerate() {
+            if index.insert(*name, position).is_some() {
+                return Err(DependencyIssue {
+                    theorem: (*name).to_owned(),
+                    entry: 0,
+                    reason: format!("theorem '{name}' is declared more than once"),
+                    code: SchemaDiagnosticCode::ValidationFailure,
+                });
+            }
+        }
+
+        let mut prerequisites = Vec::with_capacity(nodes.len());
+        for (name, depends_on) in nodes {
+            let resolved = depends_on
+                .iter()
+                .enumerate()
+                .map(|(entry, dependency)| {
+                    index
+                        .get(dependency)
+                        .copied()
+                        .ok_or_else(|| DependencyIssue {
+                            theorem: (*name).to_owned(),
+                            entry,
+                            reason: format!(
+                                "DependsOn entry '{dependency}' does not name a loaded theorem"
+                            ),
+                            code: SchemaDiagnosticCode::ValidationFailure,
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            prerequisites.push(resolved);
+        }
+
+        let names: Vec<String> = nodes.iter().map(|(name, _)| (*name).to_owned()).collect();
+        let order = TopologicalWalk::new(&names, &prerequisites).run()?;
+        Ok(Self {
+            prerequisites,
+            order,
+        })
+    }
+}
+
+/// Reports `issue` as an unlocated validation failure.
+fn validation_failed(issue: DependencyIssue) -> SchemaError {
+    SchemaError::ValidationFailed {
+        theorem: issue.theorem,
+        reason: issue.reason,
+        diagnostic: None,
+        source: None,
+    }
+}
+
+/// Visit state for one node during the depth-first walk.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Depth-first walk producing a post-order (prerequisites first) and
+/// reporting the first cycle found.
+struct TopologicalWalk<'a> {
+    names: &'a [String],
+    prerequisites: &'a [Vec<usize>],
+    marks: Vec<Mark>,
+    stack: Vec<usize>,
+    order: Vec<usize>,
+}
+
+impl<'a> TopologicalWalk<'a> {
+    fn new(names: &'a [String], prerequisites: &'a [Vec<usize>]) -> Self {
+        Self {
+            names,
+            prerequisites,
+            marks: vec![Mark::Unvisited; names.len()],
+            stack: Vec::new(),
+            order: Vec::with_capacity(names.len()),
+        }
+    }
+
+    fn run(mut self) -> Result<Vec<usize>, DependencyIssue> {
+        for position in 0..self.names.len() {
+            self.visit(position)?;
+        }
+        Ok(self.order)
+    }
+
+    fn visit(&mut self, position: usize) -> Result<(), DependencyIssue> {
+        if self.mark(position) != Mark::Unvisited {
+            return Ok(());
+        }
+        self.set_mark(position, Mark::InProgress);
+        self.stack.push(position);
+        let prerequisites = self
+            .prerequisites
+            .get(position)
+            .map_or(&[][..], Vec::as_slice);
+        for (entry, &prerequisite) in prerequisites.iter().enumerate() {
+            if self.mark(prerequisite) == Mark::InProgress {
+                return Err(self.cycle(position, entry, prerequisite));
+            }
+            self.visit(prerequisite)?;
+        }
+        self.stack.pop();
+        self.set_mark(position, Mark::Done);
+        self.order.push(position);
+        Ok(())
+    }
+
+    fn cycle(&self, position: usize, entry: usize, closing: usize) -> DependencyIssue {
+        let name = |node: usize| self.names.get(node).map_or("", String::as_str);
+        let path = cycle_path(self.stack.iter().map(|&node| name(node)), name(closing))
+            .unwrap_or_default();
+        DependencyIssue {
+            theorem: name(position).to_owned(),
+            entry,
+            reason: format!("dependency cycle detected: {path}"),
+            code: SchemaDiagnosticCode::Cycle,
+        }
+    }
+
+    fn mark(&self, position: usize) -> Mark {
+        self.marks.get(position).copied().unwrap_or(Mark::Done)
+    }
+
+    fn set_mark(&mut self, position: usize, mark: Mark) {
+        if let Some(slot) = self.marks.get_mut(position) {
+            *slot = mark;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "dependency_tests.rs"]
+mod tests;
@@ -0,0 +1,326 @@
+//! Index-based theorem graphs over `DependsOn` edges.
+//!
+//! [`TheoremGraph`] exposes prerequisite relationships through node
+//! indices, in the style of graph libraries such as `petgraph`: an edge runs from a dependent theorem to
+//! each of its prerequisites, so [`Direction::Outgoing`] neighbours are
+//! prerequisites and [`Direction::Incoming`] neighbours are dependents.
+//! Runners order work with [`TheoremGraph::toposort`], impact analysis asks
+//! [`TheoremGraph::reachable`] what depends on a theorem, and
+//! [`TheoremGraph::export`] feeds every node and edge to a
+//! [`GraphExporter`], such as the Graphviz writer behind
+//! [`TheoremGraph::to_dot`].
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use super::{ResolvedDependencies, validation_failed};
+use crate::discover::TheoremCorpus;
+use crate::schema::{SchemaError, TheoremDoc, TheoremName};
+
+/// Identifies a node of a [`TheoremGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeIndex(usize);
+
+impl NodeIndex {
+    /// Returns the zero-based position of the node, in the order the
+    /// theorems were given to the graph.
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Which end of a `DependsOn` edge a neighbour query follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From a theorem to the prerequisites it declares.
+    Outgoing,
+    /// From a theorem to the theorems that declare it as a prerequisite.
+    Incoming,
+}
+
+/// The theorem a [`TheoremGraph`] node stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TheoremNode {
+    /// The theorem name.
+    pub theorem: TheoremName,
+    /// Root-relative path of the declaring file, when built from a corpus.
+    pub path: Option<Utf8PathBuf>,
+}
+
+/// Receives the nodes and edges of a [`TheoremGraph`] from
+/// [`TheoremGraph::export`].
+pub trait GraphExporter {
+    /// Receives one node. Nodes arrive in index order, before any edge.
+    fn node(&mut self, index: NodeIndex, node: &TheoremNode);
+
+    /// Receives the edge from `dependent` to one of its `prerequisite`s.
+    /// Edges arrive grouped by dependent, in `DependsOn` order.
+    fn edge(&mut self, dependent: NodeIndex, prerequisite: NodeIndex);
+}
+
+/// A directed acyclic graph of theorems and their `DependsOn` edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TheoremGraph {
+    nodes: Vec<TheoremNode>,
+    prerequisites: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+    order: Vec<NodeIndex>,
+}
+
+impl TheoremGraph {
+    /// Builds a graph over `docs`, one node per document in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError::ValidationFailed`] when two documents share a
+    /// theorem name, when a `DependsOn` entry names a theorem outside `docs`,
+    /// or when dependencies form a cycle.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::dependency::{Direction, TheoremGraph};
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let theorem = |name: &str, depends_on: &str| format!(
+    ///         "Theorem: {name}\nAbout: example\nDependsOn: [{depends_on}]\n\
+    ///          Prove:\n  - assert: 'true'\n    because: trivial\n\
+    ///          Evidence:\n  kani:\n    unwind: 1\n    expect: SUCCESS\n\
+    ///          Witness:\n  - cover: 'true'\n    because: reachable\n"
+    ///     );
+    ///     let yaml = [theorem("Later", "Base"), theorem("Base", "")].join("---\n");
+    ///     let docs = load_theorem_docs(&yaml).unwrap();
+    ///     let graph = TheoremGraph::new(&docs).unwrap();
+    ///     let base = graph.find_node("Base").unwrap();
+    ///     let impacted = graph.reachable(base, Direction::Incoming);
+    ///     assert_eq!(graph.names(&impacted), ["Later"]);
+    pub fn new(docs: &[TheoremDoc]) -> Result<Self, SchemaError> {
+        Self::build(docs.iter().map(|doc| (None, doc)))
+    }
+
+    /// Builds a graph over every document of `corpus`, in path order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TheoremGraph::new`]; in particular, a
+    /// theorem name declared in more than one file is rejected.
+    pub fn from_corpus(corpus: &TheoremCorpus) -> Result<Self, SchemaError> {
+        Self::build(corpus.docs().map(|(path, doc)| (Some(path), doc)))
+    }
+
+    fn build<'d>(
+        entries: impl Iterator<Item = (Option<&'d Utf8Path>, &'d TheoremDoc)>,
+    ) -> Result<Self, SchemaError> {
+        let docs: Vec<_> = entries.collect();
+        let declared: Vec<(&str, Vec<&str>)> = docs
+            .iter()
+            .map(|(_, doc)| {
+                let prerequisites = doc.depends_on.iter().map(TheoremName::as_str).collect();
+                (doc.theorem.as_str(), prerequisites)
+            })
+            .collect();
+        let resolved = ResolvedDependencies::from_nodes(&declared).map_err(validation_failed)?;
+
+        let mut dependents = vec![Vec::new(); docs.len()];
+        let edges = resolved
+            .prerequisites
+            .iter()
+            .enumerate()
+            .flat_map(|(dependent, direct)| {
+                direct
+                    .iter()
+                    .map(move |&prerequisite| (dependent, prerequisite))
+            });
+        for (dependent, prerequisite) in edges {
+            if let Some(incoming) = dependents.get_mut(prerequisite) {
+                incoming.push(dependent);
+            }
+        }
+        Ok(Self {
+            nodes: docs
+                .iter()
+                .map(|(path, doc)| TheoremNode {
+                    theorem: doc.theorem.clone(),
+                    path: path.map(Utf8Path::to_path_buf),
+                })
+                .collect(),
+            prerequisites: resolved.prerequisites,
+            dependents,
+            order: resolved.order.into_iter().map(NodeIndex).collect(),
+        })
+    }
+
+    /// Returns the number of theorems in the graph.
+    #[must_use]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of `DependsOn` edges in the graph.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.prerequisites.iter().map(Vec::len).sum()
+    }
+
+    /// Returns every node index in order.
+    pub fn node_indices(&self) -> impl Iterator<Item = NodeIndex> {
+        (0..self.nodes.len()).map(NodeIndex)
+    }
+
+    /// Returns the theorem at `index`, or `None` when the graph has no such
+    /// node.
+    #[must_use]
+    pub fn node_weight(&self, index: NodeIndex) -> Option<&TheoremNode> {
+        self.nodes.get(index.0)
+    }
+
+    /// Returns the node of the theorem named `theorem`.
+    #[must_use]
+    pub fn find_node(&self, theorem: &str) -> Option<NodeIndex> {
+        self.nodes
+            .iter()
+            .position(|node| node.theorem.as_str() == theorem)
+            .map(NodeIndex)
+    }
+
+    /// Returns the theorem names of `indices`, skipping unknown nodes.
+    #[must_use]
+    pub fn names(&self, indices: &[NodeIndex]) -> Vec<&str> {
+        indices
+            .iter()
+            .filter_map(|&index| self.node_weight(index))
+            .map(|node| node.theorem.as_str())
+            .collect()
+    }
+
+    /// Returns the direct neighbours of `index` in `direction`: its
+    /// prerequisites in `DependsOn` order when outgoing, and its dependents
+    /// in node order when incoming.
+    pub fn neighbors_directed(
+        &self,
+        index: NodeIndex,
+        direction: Direction,
+    ) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.adjacency(direction)
+            .get(index.0)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .copied()
+            .map(NodeIndex)
+    }
+
+    /// Returns every edge as `(dependent, prerequisite)`, grouped by
+    /// dependent in node order.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeIndex, NodeIndex)> + '_ {
+        self.node_indices().flat_map(move |dependent| {
+            self.neighbors_directed(dependent, Direction::Outgoing)
+                .map(move |prerequisite| (dependent, prerequisite))
+        })
+    }
+
+    /// Returns every node with prerequisites ahead of their dependents.
+    ///
+    /// Independent theorems keep their node order, so this is the order a
+    /// runner checks theorems in.
+    #[must_use]
+    pub fn toposort(&self) -> &[NodeIndex] {
+        &self.order
+    }
+
+    /// Returns every node reachable from `start` in `direction`, excluding
+    /// `start`, in topological order.
+    ///
+    /// Outgoing reachability yields every transitive prerequisite of
+    /// `start`; incoming reachability yields every theorem that depends on
+    /// it directly or transitively, which is what a change to `start` can
+    /// affect.
+    #[must_use]
+    pub fn reachable(&self, start: NodeIndex, direction: Direction) -> Vec<NodeIndex> {
+        let adjacency = self.adjacency(direction);
+        let mut seen = vec![false; self.nodes.len()];
+        let mut pending = adjacency.get(start.0).cloned().unwrap_or_default();
+        while let Some(position) = pending.pop() {
+            match seen.get_mut(position) {
+                Some(flag) if !*flag => *flag = true,
+                _ => continue,
+            }
+            pending.extend(adjacency.get(position).into_iter().flatten());
+        }
+        self.order
+            .iter()
+            .copied()
+            .filter(|&index| index != start && seen.get(index.0).copied().unwrap_or(false))
+            .collect()
+    }
+
+    /// Returns `true` when a path of one or more edges leads from `from` to
+    /// `to`, that is, when `from` depends on `to` directly or transitively.
+    #[must_use]
+    pub fn has_path_connecting(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        self.reachable(from, Direction::Outgoing).contains(&to)
+    }
+
+    /// Feeds every node, then every edge, to `exporter`.
+    pub fn export(&self, exporter: &mut impl GraphExporter) {
+        for (index, node) in self.node_indices().zip(&self.nodes) {
+            exporter.node(index, node);
+        }
+        for (dependent, prerequisite) in self.edges() {
+            exporter.edge(dependent, prerequisite);
+        }
+    }
+
+    /// Renders the graph in Graphviz DOT syntax, with edges pointing from
+    /// dependents to prerequisites.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = DotExporter {
+            graph: self,
+            output: String::from("digraph theorems {\n"),
+        };
+        self.export(&mut dot);
+        dot.output.push_str("}\n");
+        dot.output
+    }
+
+    fn adjacency(&self, direction: Direction) -> &[Vec<usize>] {
+        match direction {
+            Direction::Outgoing => &self.prerequisites,
+            Direction::Incoming => &self.dependents,
+        }
+    }
+}
+
+/// Writes DOT statements naming nodes by theorem name, which is always a
+/// valid quoted DOT identifier.
+struct DotExporter<'g> {
+    graph: &'g TheoremGraph,
+    output: String,
+}
+
+impl DotExporter<'_> {
+    fn name(&self, index: NodeIndex) -> &str {
+        self.graph
+            .node_weight(index)
+            .map_or("", |node| node.theorem.as_str())
+    }
+}
+
+impl GraphExporter for DotExporter<'_> {
+    fn node(&mut self, _index: NodeIndex, node: &TheoremNode) {
+        let line = format!("    \"{}\";\n", node.theorem);
+        self.output.push_str(&line);
+    }
+
+    fn edge(&mut self, dependent: NodeIndex, prerequisite: NodeIndex) {
+        let line = format!(
+            "    \"{}\" -> \"{}\";\n",
+            self.name(dependent),
+            self.name(prerequisite)
+        );
+        self.output.push_str(&line);
+    }
+}
+
+#[cfg(test)]
+#[path = "dependency_graph_tests.rs"]
+mod tests;
@@ -0,0 +1,216 @@
+//! Unit tests for index-based theorem graphs.
+
+use camino::Utf8Path;
+use rstest::rstest;
+
+use super::{Direction, GraphExporter, NodeIndex, TheoremGraph, TheoremNode};
+use crate::discover::{DiscoverOptions, load_theorem_tree_with_provider};
+use crate::file_provider::MemoryFs;
+use crate::schema::test_fixtures;
+use crate::schema::{SchemaError, TheoremDoc, load_theorem_docs};
+
+fn theorem(name: &str, depends_on: &str) -> String {
+    test_fixtures::theorem(name, &format!("DependsOn: [{depends_on}]\n"))
+}
+
+/// `Audit` depends on `Transfer` and `Deposit`, `Transfer` on `Deposit`,
+/// and `Report` stands alone.
+fn ledger() -> Vec<TheoremDoc> {
+    let yaml = [
+        theorem("Audit", "Transfer, Deposit"),
+        theorem("Transfer", "Deposit"),
+        theorem("Deposit", ""),
+        theorem("Report", ""),
+    ]
+    .join("---\n");
+    load_theorem_docs(&yaml).expect("ledger theorems should load")
+}
+
+fn ledger_graph() -> TheoremGraph {
+    TheoremGraph::new(&ledger()).expect("graph should build")
+}
+
+fn node(graph: &TheoremGraph, name: &str) -> NodeIndex {
+    graph.find_node(name).expect("theorem should be a node")
+}
+
+#[test]
+fn nodes_and_edges_follow_document_order() {
+    let graph = ledger_graph();
+    let edges: Vec<(usize, usize)> = graph
+        .edges()
+        .map(|(dependent, prerequisite)| (dependent.index(), prerequisite.index()))
+        .collect();
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(edges, [(0, 1), (0, 2), (1, 2)]);
+    assert_eq!(
+        graph
+            .node_weight(NodeIndex(3))
+            .map(|node| node.theorem.as_str()),
+        Some("Report")
+    );
+    assert_eq!(graph.node_weight(NodeIndex(4)), None);
+}
+
+#[test]
+fn toposort_puts_prerequisites_first() {
+    let graph = ledger_graph();
+
+    assert_eq!(
+        graph.names(graph.toposort()),
+        ["Deposit", "Transfer", "Audit", "Report"]
+    );
+}
+
+#[rstest]
+#[case::prerequisites("Audit", Direction::Outgoing, &["Transfer", "Deposit"])]
+#[case::dependents("Deposit", Direction::Incoming, &["Audit", "Transfer"])]
+#[case::isolated("Report", Direction::Incoming, &[])]
+fn neighbours_follow_the_requested_direction(
+    #[case] theorem: &str,
+    #[case] direction: Direction,
+    #[case] expected: &[&str],
+) {
+    let graph = ledger_graph();
+    let neighbours: Vec<NodeIndex> = graph
+        .neighbors_directed(node(&graph, theorem), direction)
+        .collect();
+
+    assert_eq!(graph.names(&neighbours), expected);
+}
+
+#[rstest]
+#[case::impact_of_a_root("Deposit", Direction::Incoming, &["Transfer", "Audit"])]
+#[case::impact_of_a_middle("Transfer", Direction::Incoming, &["Audit"])]
+#[case::all_prerequisites("Audit", Direction::Outgoing, &["Deposit", "Transfer"])]
+#[case::leaf_prerequisites("Deposit", Direction::Outgoing, &[])]
+fn reachability_is_transitive_and_topologically_ordered(
+    #[case] theorem: &str,
+    #[case] direction: Direction,
+    #[case] expected: &[&str],
+) {
+    let graph = ledger_graph();
+    let reached = graph.reachable(node(&graph, theorem), direction);
+
+    assert_eq!(graph.names(&reached), expected);
+}
+
+#[rstest]
+#[case::direct("Transfer", "Deposit", true)]
+#[case::transitive("Audit", "Deposit", true)]
+#[case::reversed("Deposit", "Audit", false)]
+#[case::unrelated("Report", "Deposit", false)]
+#[case::itself("Deposit", "Deposit", false)]
+fn paths_follow_dependency_edges(#[case] from: &str, #[case] to: &str, #[case] expected: bool) {
+    let graph = ledger_graph();
+
+    assert_eq!(
+        graph.has_path_connecting(node(&graph, from), node(&graph, to)),
+        expected
+    );
+}
+
+#[test]
+fn to_dot_lists_nodes_then_edges() {
+    assert_eq!(
+        ledger_graph().to_dot(),
+        concat!(
+            "digraph theorems {\n",
+            "    \"Audit\";\n",
+            "    \"Transfer\";\n",
+            "    \"Deposit\";\n",
+            "    \"Report\";\n",
+            "    \"Audit\" -> \"Transfer\";\n",
+            "    \"Audit\" -> \"Deposit\";\n",
+            "    \"Transfer\" -> \"Deposit\";\n",
+            "}\n",
+        )
+    );
+}
+
+/// Records the export calls it receives.
+#[derive(Default)]
+struct Recorder {
+    calls: Vec<String>,
+}
+
+impl GraphExporter for Recorder {
+    fn node(&mut self, index: NodeIndex, node: &TheoremNode) {
+        self.calls
+            .push(format!("node {} {}", index.index(), node.theorem));
+    }
+
+    fn edge(&mut self, dependent: NodeIndex, prerequisite: NodeIndex) {
+        self.calls.push(format!(
+            "edge {} {}",
+            dependent.index(),
+            prerequisite.index()
+        ));
+    }
+}
+
+#[test]
+fn exporters_receive_every_node_before_any_edge() {
+    let yaml = [theorem("Later", "Base"), theorem("Base", "")].join("---\n");
+    let docs = load_theorem_docs(&yaml).expect("theorems should load");
+    let mut recorder = Recorder::default();
+
+    TheoremGraph::new(&docs)
+        .expect("graph should build")
+        .export(&mut recorder);
+
+    assert_eq!(recorder.calls, ["node 0 Later", "node 1 Base", "edge 0 1"]);
+}
+
+#[test]
+fn corpus_graphs_record_each_theorem_file() {
+    let mut files = MemoryFs::new();
+    files.insert(
+        "ledger.theorem",
+        [theorem("Transfer", "Deposit"), theorem("Deposit", "")].join("---\n"),
+    );
+    files.insert("report.theorem", theorem("Report", ""));
+    let corpus =
+        load_theorem_tree_with_provider(&files, Utf8Path::new(""), &DiscoverOptions::default())
+            .expect("corpus should load");
+
+    let graph = TheoremGraph::from_corpus(&corpus).expect("graph should build");
+    let paths: Vec<Option<&str>> = graph
+        .node_indices()
+        .filter_map(|index| graph.node_weight(index))
+        .map(|node| node.path.as_deref().map(Utf8Path::as_str))
+        .collect();
+
+    assert_eq!(
+        paths,
+        [
+            Some("ledger.theorem"),
+            Some("ledger.theorem"),
+            Some("report.theorem")
+        ]
+    );
+    assert_eq!(
+        graph.names(graph.toposort()),
+        ["Deposit", "Transfer", "Report"]
+    );
+}
+
+#[test]
+fn theorem_names_shared_across_files_are_rejected() {
+    let mut files = MemoryFs::new();
+    files.insert("a.theorem", theorem("Deposit", ""));
+    files.insert("b.theorem", theorem("Deposit", ""));
+    let corpus =
+        load_theorem_tree_with_provider(&files, Utf8Path::new(""), &DiscoverOptions::default())
+            .expect("corpus should load");
+
+    let error = TheoremGraph::from_corpus(&corpus).expect_err("duplicate names should fail");
+
+    assert!(
+        matches!(error, SchemaError::ValidationFailed { ref reason, .. }
+            if reason == "theorem 'Deposit' is declared more than once"),
+        "unexpected error: {error}"
+    );
+}
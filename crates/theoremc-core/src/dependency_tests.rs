@@ -0,0 +1,128 @@
+//! Unit tests for `DependsOn` resolution.
+
+use rstest::rstest;
+
+use super::{DependencyIssue, ResolvedDependencies, TheoremGraph};
+use crate::schema::test_fixtures;
+use crate::schema::{
+    SchemaDiagnosticCode, SchemaError, SourceId, load_theorem_docs, load_theorem_docs_with_source,
+};
+
+fn theorem(name: &str, depends_on: &str) -> String {
+    test_fixtures::theorem(name, &format!("DependsOn: [{depends_on}]\n"))
+}
+
+fn graph(nodes: &[(&str, &[&str])]) -> Result<ResolvedDependencies, DependencyIssue> {
+    let owned: Vec<(&str, Vec<&str>)> = nodes
+        .iter()
+        .map(|(name, depends_on)| (*name, depends_on.to_vec()))
+        .collect();
+    ResolvedDependencies::from_nodes(&owned)
+}
+
+const LEDGER: &[(&str, &[&str])] = &[
+    ("Audit", &["Transfer", "Deposit"]),
+    ("Transfer", &["Deposit"]),
+    ("Deposit", &[]),
+    ("Report", &[]),
+];
+
+#[test]
+fn prerequisites_run_first_and_independent_theorems_keep_order() {
+    let graph = graph(LEDGER).expect("graph should build");
+
+    assert_eq!(graph.order, [2, 1, 0, 3]);
+    assert_eq!(graph.prerequisites, [vec![1, 2], vec![2], vec![], vec![]]);
+}
+
+#[rstest]
+#[case::unknown(
+    &[("A", &["Missing"][..])],
+    DependencyIssue {
+        theorem: "A".to_owned(),
+        entry: 0,
+        reason: "DependsOn entry 'Missing' does not name a loaded theorem".to_owned(),
+        code: SchemaDiagnosticCode::ValidationFailure,
+    }
+)]
+#[case::self_cycle(
+    &[("A", &["A"][..])],
+    DependencyIssue {
+        theorem: "A".to_owned(),
+        entry: 0,
+        reason: "dependency cycle detected: A -> A".to_owned(),
+        code: SchemaDiagnosticCode::Cycle,
+    }
+)]
+#[case::indirect_cycle(
+    &[("A", &["B"][..]), ("B", &["Base", "C"]), ("C", &["A"]), ("Base", &[])],
+    DependencyIssue {
+        theorem: "C".to_owned(),
+        entry: 0,
+        reason: "dependency cycle detected: A -> B -> C -> A".to_owned(),
+        code: SchemaDiagnosticCode::Cycle,
+    }
+)]
+fn invalid_dependencies_are_reported(
+    #[case] nodes: &[(&str, &[&str])],
+    #[case] expected: DependencyIssue,
+) {
+    assert_eq!(graph(nodes), Err(expected));
+}
+
+#[test]
+fn graphs_build_from_loaded_documents() {
+    let yaml = [theorem("Later", "Base"), theorem("Base", "")].join("---\n");
+    let docs = load_theorem_docs(&yaml).expect("dependencies should load");
+    let graph = TheoremGraph::new(&docs).expect("graph should build");
+
+    assert_eq!(docs.first().map(|doc| doc.depends_on.len()), Some(1));
+    assert_eq!(graph.names(graph.toposort()), ["Base", "Later"]);
+}
+
+#[rstest]
+#[case::unknown(
+    &[theorem("A", "Missing")],
+    "validation failed for theorem 'A': DependsOn entry 'Missing' does not name a loaded theorem",
+    3,
+    SchemaDiagnosticCode::ValidationFailure
+)]
+#[case::cycle(
+    &[theorem("A", "B"), theorem("B", "A")],
+    "validation failed for theorem 'B': dependency cycle detected: A -> B -> A",
+    17,
+    SchemaDiagnosticCode::Cycle
+)]
+fn loader_rejects_invalid_dependencies(
+    #[case] documents: &[String],
+    #[case] expected: &str,
+    #[case] line: usize,
+    #[case] code: SchemaDiagnosticCode,
+) {
+    let error = load_theorem_docs_with_source(
+        &SourceId::new("theorems/deps.theorem"),
+        &documents.join("---\n"),
+    )
+    .expect_err("dependencies should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::ValidationFailed { .. }),
+        "unexpected error: {error}"
+    );
+    assert_eq!(error.to_string(), expected);
+    assert_eq!(diagnostic.location.line, line);
+    assert_eq!(diagnostic.code, code);
+}
+
+#[test]
+fn invalid_dependency_names_are_rejected_at_parse_time() {
+    let message = load_theorem_docs(&theorem("A", "'not valid'"))
+        .expect_err("dependency names must be identifiers")
+        .to_string();
+
+    assert!(
+        message.contains("invalid identifier"),
+        "unexpected error: {message}"
+    );
+}
@@ -0,0 +1,387 @@
+//! Semantic comparison of two versions of a theorem document.
+//!
+//! [`theorem_diff`] reports what a specification change means rather than
+//! which lines moved: obligations added to, removed from, or reworded in
+//! `Assume`, `Prove`, and `Witness`, backends gained or lost, and Kani
+//! harnesses whose unwind bound or expected outcome changed. Review tooling
+//! can render each [`SpecChange`] as a one-line summary.
+
+use std::fmt;
+
+use crate::schema::{Evidence, EvidenceBackend, KaniEvidence, KaniExpectation, TheoremDoc};
+
+/// The sections whose entries are compared one obligation at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObligationSection {
+    /// `Assume` constraints on symbolic inputs.
+    Assume,
+    /// `Prove` assertions.
+    Prove,
+    /// `Witness` non-vacuity covers.
+    Witness,
+}
+
+impl ObligationSection {
+    /// Returns the section key as written in a theorem document.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Assume => "Assume",
+            Self::Prove => "Prove",
+            Self::Witness => "Witness",
+        }
+    }
+}
+
+/// One `Assume`, `Prove`, or `Witness` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Obligation {
+    /// The entry's Rust expression.
+    pub expr: String,
+    /// The entry's justification.
+    pub because: String,
+}
+
+/// One meaningful difference between two versions of a theorem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecChange {
+    /// The new version adds an entry to `section`.
+    Added {
+        /// Section holding the entry.
+        section: ObligationSection,
+        /// The added entry.
+        obligation: Obligation,
+    },
+    /// The new version drops an entry from `section`.
+    Removed {
+        /// Section that held the entry.
+        section: ObligationSection,
+        /// The removed entry.
+        obligation: Obligation,
+    },
+    /// The new version rewrites the expression or justification of an entry.
+    Modified {
+        /// Section holding the entry.
+        section: ObligationSection,
+        /// The entry before the change.
+        old: Obligation,
+        /// The entry after the change.
+        new: Obligation,
+    },
+    /// The new version configures a backend the old one did not.
+    BackendAdded(EvidenceBackend),
+    /// The new version no longer configures a backend.
+    BackendRemoved(EvidenceBackend),
+    /// The new version adds a Kani configuration.
+    HarnessAdded {
+        /// Configuration name, `None` for an unnamed configuration.
+        harness: Option<String>,
+    },
+    /// The new version drops a Kani configuration.
+    HarnessRemoved {
+        /// Configuration name, `None` for an unnamed configuration.
+        harness: Option<String>,
+    },
+    /// A Kani configuration's global `unwind` bound changed.
+    UnwindChanged {
+        /// Configuration name, `None` for an unnamed configuration.
+        harness: Option<String>,
+        /// Bound before the change.
+        old: u32,
+        /// Bound after the change.
+        new: u32,
+    },
+    /// A Kani configuration's expected outcome changed.
+    ExpectationChanged {
+        /// Configuration name, `None` for an unnamed configuration.
+        harness: Option<String>,
+        /// Expectation before the change.
+        old: KaniExpectation,
+        /// Expectation after the change.
+        new: KaniExpectation,
+    },
+}
+
+impl fmt::Display for SpecChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added {
+                section,
+                obligation,
+            } => write!(f, "{} entry added: {}", section.as_str(), obligation.expr),
+            Self::Removed {
+                section,
+                obligation,
+            } => write!(f, "{} entry removed: {}", section.as_str(), obligation.expr),
+            Self::Modified { section, old, new } if old.expr == new.expr => write!(
+                f,
+                "{} entry {} has a new justification",
+                section.as_str(),
+                new.expr
+            ),
+            Self::Modified { section, old, new } => write!(
+                f,
+                "{} entry changed: {} -> {}",
+                section.as_str(),
+                old.expr,
+                new.expr
+            ),
+            Self::BackendAdded(backend) => write!(f, "{} evidence added", backend.as_str()),
+            Self::BackendRemoved(backend) => write!(f, "{} evidence removed", backend.as_str()),
+            Self::HarnessAdded { harness } => {
+                write!(f, "{} added", HarnessLabel(harness.as_deref()))
+            }
+            Self::HarnessRemoved { harness } => {
+                write!(f, "{} removed", HarnessLabel(harness.as_deref()))
+            }
+            Self::UnwindChanged { harness, old, new } => write!(
+                f,
+                "{} unwind changed from {old} to {new}",
+                HarnessLabel(harness.as_deref())
+            ),
+            Self::ExpectationChanged { harness, old, new } => write!(
+                f,
+                "{} expectation changed from {} to {}",
+                HarnessLabel(harness.as_deref()),
+                old.as_str(),
+                new.as_str()
+            ),
+        }
+    }
+}
+
+/// Names a Kani configuration in change summaries.
+struct HarnessLabel<'a>(Option<&'a str>);
+
+impl fmt::Display for HarnessLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(name) => write!(f, "Kani harness '{name}'"),
+            None => f.write_str("Kani harness"),
+        }
+    }
+}
+
+/// The meaningful differences between two versions of a theorem.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TheoremDiff {
+    /// Changes grouped by section in `Assume`, `Prove`, `Witness`, then
+    /// evidence order.
+    pub changes: Vec<SpecChange>,
+}
+
+impl TheoremDiff {
+    /// Returns `true` when the versions mean the same thing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Classifies the changes between the `old` and `new` versions of a theorem.
+///
+/// Within a section, identical entries are unchanged wherever they moved.
+/// A remaining new entry is paired with an old entry that has the same
+/// expression, and then with one that has the same justification, and is
+/// reported as [`SpecChange::Modified`]; unpaired entries are added or
+/// removed. Kani configurations are paired by name.
+///
+/// # Examples
+///
+///     use theoremc_core::diff::{ObligationSection, SpecChange, theorem_diff};
+///     use theoremc_core::schema::load_theorem_docs;
+///
+///     let version = |bound: &str| {
+///         let yaml = format!(
+///             r#"
+///     Theorem: Bounded
+///     About: Values stay below the bound
+///     Forall:
+///       x: u8
+///     Prove:
+///       - assert: "x < {bound}"
+///         because: "values stay below the bound"
+///     Evidence:
+///       kani:
+///         unwind: 1
+///         expect: SUCCESS
+///     Witness:
+///       - cover: "x == 1"
+///         because: "small values are reachable"
+///     "#
+///         );
+///         load_theorem_docs(&yaml).unwrap().remove(0)
+///     };
+///     let diff = theorem_diff(&version("200"), &version("100"));
+///     assert!(matches!(
+///         &diff.changes[..],
+///         [SpecChange::Modified { section: ObligationSection::Prove, .. }]
+///     ));
+///     assert_eq!(
+///         diff.changes[0].to_string(),
+///         "Prove entry changed: x < 200 -> x < 100"
+///     );
+#[must_use]
+pub fn theorem_diff(old: &TheoremDoc, new: &TheoremDoc) -> TheoremDiff {
+    let mut changes = Vec::new();
+    let assume = |doc: &TheoremDoc| {
+        doc.assume
+            .iter()
+            .map(|entry| obligation(entry.expr.as_str(), &entry.because))
+            .collect()
+    };
+    let prove = |doc: &TheoremDoc| {
+        doc.prove
+            .iter()
+            .map(|entry| obligation(entry.assert_expr.as_str(), &entry.because))
+            .collect()
+    };
+    let witness = |doc: &TheoremDoc| {
+        doc.witness
+            .iter()
+            .map(|entry| obligation(entry.cover.as_str(), &entry.because))
+            .collect()
+    };
+    diff_obligations(
+        ObligationSection::Assume,
+        assume(old),
+        assume(new),
+        &mut changes,
+    );
+    diff_obligations(
+        ObligationSection::Prove,
+        prove(old),
+        prove(new),
+        &mut changes,
+    );
+    diff_obligations(
+        ObligationSection::Witness,
+        witness(old),
+        witness(new),
+        &mut changes,
+    );
+    diff_evidence(&old.evidence, &new.evidence, &mut changes);
+    TheoremDiff { changes }
+}
+
+fn obligation(expr: &str, because: &str) -> Obligation {
+    Obligation {
+        expr: expr.to_owned(),
+        because: because.to_owned(),
+    }
+}
+
+fn diff_obligations(
+    section: ObligationSection,
+    old: Vec<Obligation>,
+    new: Vec<Obligation>,
+    changes: &mut Vec<SpecChange>,
+) {
+    let mut unmatched: Vec<Option<Obligation>> = old.into_iter().map(Some).collect();
+    let mut pending: Vec<(Obligation, Option<Obligation>)> = Vec::new();
+    for entry in new {
+        if take(&mut unmatched, |candidate| *candidate == entry).is_none() {
+            pending.push((entry, None));
+        }
+    }
+    for (entry, counterpart) in &mut pending {
+        *counterpart = take(&mut unmatched, |candidate| candidate.expr == entry.expr);
+    }
+    for (entry, counterpart) in pending.iter_mut().filter(|(_, found)| found.is_none()) {
+        *counterpart = take(&mut unmatched, |candidate| {
+            candidate.because == entry.because
+        });
+    }
+
+    changes.extend(
+        pending
+            .into_iter()
+            .map(|(entry, counterpart)| match counterpart {
+                Some(previous) => SpecChange::Modified {
+                    section,
+                    old: previous,
+                    new: entry,
+                },
+                None => SpecChange::Added {
+                    section,
+                    obligation: entry,
+                },
+            }),
+    );
+    changes.extend(
+        unmatched
+            .into_iter()
+            .flatten()
+            .map(|entry| SpecChange::Removed {
+                section,
+                obligation: entry,
+            }),
+    );
+}
+
+/// Takes the first remaining entry of `slots` that `matches` accepts.
+fn take(
+    slots: &mut [Option<Obligation>],
+    matches: impl Fn(&Obligation) -> bool,
+) -> Option<Obligation> {
+    slots
+        .iter_mut()
+        .find(|slot| slot.as_ref().is_some_and(&matches))?
+        .take()
+}
+
+fn diff_evidence(old: &Evidence, new: &Evidence, changes: &mut Vec<SpecChange>) {
+    for backend in [
+        EvidenceBackend::Kani,
+        EvidenceBackend::Verus,
+        EvidenceBackend::Stateright,
+    ] {
+        match (backend.is_configured(old), backend.is_configured(new)) {
+            (false, true) => changes.push(SpecChange::BackendAdded(backend)),
+            (true, false) => changes.push(SpecChange::BackendRemoved(backend)),
+            _ => {}
+        }
+    }
+    if old.kani.is_empty() || new.kani.is_empty() {
+        return;
+    }
+
+    for config in &new.kani {
+        let harness = config.name.clone();
+        let Some(previous) = named(&old.kani, harness.as_deref()) else {
+            changes.push(SpecChange::HarnessAdded { harness });
+            continue;
+        };
+        if previous.unwind != config.unwind {
+            changes.push(SpecChange::UnwindChanged {
+                harness: harness.clone(),
+                old: previous.unwind,
+                new: config.unwind,
+            });
+        }
+        if previous.expect != config.expect {
+            changes.push(SpecChange::ExpectationChanged {
+                harness,
+                old: previous.expect,
+                new: config.expect,
+            });
+        }
+    }
+    changes.extend(
+        old.kani
+            .iter()
+            .filter(|config| named(&new.kani, config.name.as_deref()).is_none())
+            .map(|config| SpecChange::HarnessRemoved {
+                harness: config.name.clone(),
+            }),
+    );
+}
+
+/// Finds the Kani configuration called `name` in `configs`.
+fn named<'a>(configs: &'a [KaniEvidence], name: Option<&str>) -> Option<&'a KaniEvidence> {
+    configs.iter().find(|config| config.name.as_deref() == name)
+}
+
+#[cfg(test)]
+#[path = "diff_tests.rs"]
+mod tests;
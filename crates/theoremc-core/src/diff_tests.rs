@@ -0,0 +1,229 @@
+//! Unit tests for semantic theorem diffs.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{Obligation, ObligationSection, SpecChange, TheoremDiff, theorem_diff};
+use crate::schema::{EvidenceBackend, KaniExpectation, TheoremDoc, load_theorem_docs};
+
+const BASE: &str = concat!(
+    "Theorem: Ledger\n",
+    "About: Balances stay consistent\n",
+    "Forall:\n",
+    "  amount: u32\n",
+    "Assume:\n",
+    "  - expr: 'amount > 0'\n",
+    "    because: deposits are positive\n",
+    "Prove:\n",
+    "  - assert: 'amount < 100'\n",
+    "    because: deposits are bounded\n",
+    "  - assert: 'amount != 7'\n",
+    "    because: seven is reserved\n",
+    "Witness:\n",
+    "  - cover: 'amount == 1'\n",
+    "    because: small deposits are reachable\n",
+);
+
+const KANI: &str = "Evidence:\n  kani:\n    unwind: 2\n    expect: SUCCESS\n";
+
+fn doc(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("theorem should load")
+        .remove(0)
+}
+
+fn obligation(expr: &str, because: &str) -> Obligation {
+    Obligation {
+        expr: expr.to_owned(),
+        because: because.to_owned(),
+    }
+}
+
+fn changes(old: &str, new: &str) -> Vec<SpecChange> {
+    theorem_diff(&doc(old), &doc(new)).changes
+}
+
+#[test]
+fn identical_and_reordered_versions_have_no_changes() {
+    let original = format!("{BASE}{KANI}");
+    let reordered = original.replace(
+        "  - assert: 'amount < 100'\n    because: deposits are bounded\n  - assert: 'amount != 7'\n    because: seven is reserved\n",
+        "  - assert: 'amount != 7'\n    because: seven is reserved\n  - assert: 'amount < 100'\n    because: deposits are bounded\n",
+    );
+
+    assert!(theorem_diff(&doc(&original), &doc(&original)).is_empty());
+    assert_eq!(
+        theorem_diff(&doc(&original), &doc(&reordered)),
+        TheoremDiff::default()
+    );
+}
+
+#[rstest]
+#[case::expression_rewritten(
+    "assert: 'amount < 100'\n    because: deposits are bounded",
+    "assert: 'amount < 50'\n    because: deposits are bounded",
+    SpecChange::Modified {
+        section: ObligationSection::Prove,
+        old: obligation("amount < 100", "deposits are bounded"),
+        new: obligation("amount < 50", "deposits are bounded"),
+    }
+)]
+#[case::justification_rewritten(
+    "because: seven is reserved",
+    "because: seven is kept back",
+    SpecChange::Modified {
+        section: ObligationSection::Prove,
+        old: obligation("amount != 7", "seven is reserved"),
+        new: obligation("amount != 7", "seven is kept back"),
+    }
+)]
+#[case::assumption_rewritten(
+    "expr: 'amount > 0'",
+    "expr: 'amount > 1'",
+    SpecChange::Modified {
+        section: ObligationSection::Assume,
+        old: obligation("amount > 0", "deposits are positive"),
+        new: obligation("amount > 1", "deposits are positive"),
+    }
+)]
+fn rewritten_entries_are_modified(
+    #[case] from: &str,
+    #[case] to: &str,
+    #[case] expected: SpecChange,
+) {
+    let original = format!("{BASE}{KANI}");
+
+    assert_eq!(changes(&original, &original.replace(from, to)), [expected]);
+}
+
+#[test]
+fn unrelated_entries_are_added_and_removed() {
+    let original = format!("{BASE}{KANI}");
+    let revised = original
+        .replace(
+            "  - assert: 'amount != 7'\n    because: seven is reserved\n",
+            "",
+        )
+        .replace(
+            "Witness:\n",
+            "  - assert: 'amount % 2 == 0'\n    because: deposits are even\nWitness:\n",
+        );
+
+    assert_eq!(
+        changes(&original, &revised),
+        [
+            SpecChange::Added {
+                section: ObligationSection::Prove,
+                obligation: obligation("amount % 2 == 0", "deposits are even"),
+            },
+            SpecChange::Removed {
+                section: ObligationSection::Prove,
+                obligation: obligation("amount != 7", "seven is reserved"),
+            },
+        ]
+    );
+}
+
+#[rstest]
+#[case::unwind(
+    "unwind: 2",
+    "unwind: 8",
+    SpecChange::UnwindChanged { harness: None, old: 2, new: 8 }
+)]
+#[case::expectation(
+    "expect: SUCCESS",
+    "expect: FAILURE",
+    SpecChange::ExpectationChanged {
+        harness: None,
+        old: KaniExpectation::Success,
+        new: KaniExpectation::Failure,
+    }
+)]
+fn kani_configuration_changes_are_classified(
+    #[case] from: &str,
+    #[case] to: &str,
+    #[case] expected: SpecChange,
+) {
+    let original = format!("{BASE}{KANI}");
+
+    assert_eq!(changes(&original, &original.replace(from, to)), [expected]);
+}
+
+#[test]
+fn named_harnesses_are_paired_by_name() {
+    let harnesses = |second: &str| {
+        format!(
+            "{BASE}Evidence:\n  kani:\n    - name: shallow\n      unwind: 2\n      expect: SUCCESS\n    - name: {second}\n      unwind: 9\n      expect: SUCCESS\n"
+        )
+    };
+
+    assert_eq!(
+        changes(&harnesses("deep"), &harnesses("deeper")),
+        [
+            SpecChange::HarnessAdded {
+                harness: Some("deeper".to_owned()),
+            },
+            SpecChange::HarnessRemoved {
+                harness: Some("deep".to_owned()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn backend_changes_are_reported() {
+    let kani = format!("{BASE}{KANI}");
+    let verus = format!("{BASE}Evidence:\n  verus: {{}}\n");
+
+    assert_eq!(
+        changes(&kani, &verus),
+        [
+            SpecChange::BackendRemoved(EvidenceBackend::Kani),
+            SpecChange::BackendAdded(EvidenceBackend::Verus),
+        ]
+    );
+}
+
+#[rstest]
+#[case::added(
+    SpecChange::Added {
+        section: ObligationSection::Witness,
+        obligation: obligation("amount == 2", "two is reachable"),
+    },
+    "Witness entry added: amount == 2"
+)]
+#[case::modified(
+    SpecChange::Modified {
+        section: ObligationSection::Prove,
+        old: obligation("amount < 100", "bounded"),
+        new: obligation("amount < 50", "bounded"),
+    },
+    "Prove entry changed: amount < 100 -> amount < 50"
+)]
+#[case::justified(
+    SpecChange::Modified {
+        section: ObligationSection::Assume,
+        old: obligation("amount > 0", "positive"),
+        new: obligation("amount > 0", "strictly positive"),
+    },
+    "Assume entry amount > 0 has a new justification"
+)]
+#[case::backend(
+    SpecChange::BackendAdded(EvidenceBackend::Verus),
+    "verus evidence added"
+)]
+#[case::named_unwind(
+    SpecChange::UnwindChanged { harness: Some("deep".to_owned()), old: 4, new: 8 },
+    "Kani harness 'deep' unwind changed from 4 to 8"
+)]
+#[case::unnamed_expectation(
+    SpecChange::ExpectationChanged {
+        harness: None,
+        old: KaniExpectation::Success,
+        new: KaniExpectation::Failure,
+    },
+    "Kani harness expectation changed from SUCCESS to FAILURE"
+)]
+fn changes_render_as_summaries(#[case] change: SpecChange, #[case] expected: &str) {
+    assert_eq!(change.to_string(), expected);
+}
@@ -0,0 +1,364 @@
+//! Discovery and bulk loading of theorem files below a directory.
+//!
+//! [`find_theorem_files`] walks a directory tree and returns the theorem files
+//! it contains, skipping paths that match ignore patterns. [`load_theorem_tree`]
+//! loads every discovered file into a [`TheoremCorpus`], collecting per-file
+//! load failures instead of stopping at the first one, so a single pass reports
+//! every broken theorem in a project. The `_with_provider` variants read
+//! through a [`FileProvider`], such as an in-memory tree or an overlay of
+//! unsaved editor buffers, instead of the local filesystem.
+//!
+//! Theorem names must be unique across a corpus as well as within a file,
+//! since harnesses and reports name theorems by them. Loading records each
+//! name declared in more than one file as a [`DuplicateTheoremName`] that
+//! locates every declaration.
+//!
+//! A loaded corpus answers impact-analysis queries: which theorems match a
+//! [`TagExpr`], where an action is called, and which theorems quantify over
+//! a given Rust type.
+//!
+//! Ignore patterns use `/`-separated glob syntax: `*` matches any run of
+//! characters within one path component, `?` matches one character, and a
+//! `**` component matches any number of components. A pattern without `/`
+//! matches any single component, so `drafts` skips every directory named
+//! `drafts`; a pattern with `/` must match the whole root-relative path.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::TheoremFileLoadError;
+use crate::file_provider::{CrateIncludes, EntryKind, FileProvider, RealFs, load_with_includes};
+use crate::schema::TheoremDoc;
+
+#[path = "discover_duplicates.rs"]
+mod duplicates;
+#[path = "discover_query.rs"]
+mod query;
+
+pub use crate::tagexpr::{TagExpr, TagExprError};
+pub use duplicates::{DuplicateTheoremName, TheoremDefinition};
+pub use query::{ActionCallSite, CallLocation};
+
+use duplicates::duplicate_names;
+
+/// Which files [`find_theorem_files`] treats as theorem files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoverOptions {
+    /// File extensions, without the leading `.`, that mark theorem files.
+    pub extensions: Vec<String>,
+    /// Glob patterns for root-relative files and directories to skip.
+    pub ignore: Vec<String>,
+}
+
+impl Default for DiscoverOptions {
+    /// Discovers `.theorem` files and ignores nothing.
+    fn default() -> Self {
+        Self {
+            extensions: vec!["theorem".to_owned()],
+            ignore: Vec::new(),
+        }
+    }
+}
+
+/// Filesystem failures while walking a theorem directory tree.
+#[derive(Debug, thiserror::Error)]
+pub enum DiscoverError {
+    /// A directory or directory entry could not be read.
+    #[error("could not {operation} '{path}': {source}")]
+    Io {
+        /// The operation that failed, such as `read directory`.
+        operation: &'static str,
+        /// Path the operation was applied to.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// The theorem documents loaded from a directory tree.
+#[derive(Debug, Default)]
+pub struct TheoremCorpus {
+    /// Documents of each file that loaded, keyed by root-relative path.
+    pub files: BTreeMap<Utf8PathBuf, Vec<TheoremDoc>>,
+    /// Load failures of the remaining files, in path order.
+    pub diagnostics: Vec<TheoremFileLoadError>,
+    /// Theorem names declared in more than one loaded file, in name order.
+    pub duplicates: Vec<DuplicateTheoremName>,
+}
+
+impl TheoremCorpus {
+    /// Returns every loaded document with the path of its file, in path
+    /// order.
+    pub fn docs(&self) -> impl Iterator<Item = (&Utf8Path, &TheoremDoc)> {
+        self.files
+            .iter()
+            .flat_map(|(path, docs)| docs.iter().map(move |doc| (path.as_path(), doc)))
+    }
+
+    /// Returns `true` when every discovered file loaded and no theorem name
+    /// is declared twice.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.diagnostics.is_empty() && self.duplicates.is_empty()
+    }
+}
+
+/// Finds the theorem files below `root`.
+///
+/// Returned paths are relative to `root`, use `/` separators, and are sorted.
+/// Symbolic links are not followed.
+///
+/// # Errors
+///
+/// Returns [`DiscoverError::Io`] when `root` or one of its subdirectories
+/// cannot be opened or read.
+pub fn find_theorem_files(
+    root: &Utf8Path,
+    options: &DiscoverOptions,
+) -> Result<Vec<Utf8PathBuf>, DiscoverError> {
+    find_theorem_files_with_provider(&RealFs, root, options)
+}
+
+/// Finds the theorem files below `root` like [`find_theorem_files`], listing
+/// directories through `provider`.
+///
+/// # Errors
+///
+/// Returns [`DiscoverError::Io`] when `provider` cannot list `root` or one
+/// of its subdirectories.
+pub fn find_theorem_files_with_provider(
+    provider: &(impl FileProvider + ?Sized),
+    root: &Utf8Path,
+    options: &DiscoverOptions,
+) -> Result<Vec<Utf8PathBuf>, DiscoverError> {
+    let walk = Walk {
+        provider,
+        root,
+        options,
+    };
+    let mut files = Vec::new();
+    walk.collect(&Utf8PathBuf::new(), &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Loads every `.theorem` file below `root` into a corpus.
+///
+/// Each file is loaded like [`load_theorem_file`](crate::load_theorem_file):
+/// `Include` paths resolve against the manifest directory of the crate
+/// holding `root`, the nearest directory at or above it that contains a
+/// `Cargo.toml`. Files that fail to load are recorded in
+/// [`TheoremCorpus::diagnostics`].
+///
+/// # Errors
+///
+/// Returns [`DiscoverError`] when the directory tree cannot be walked.
+pub fn load_theorem_tree(root: &Utf8Path) -> Result<TheoremCorpus, DiscoverError> {
+    load_theorem_tree_with_options(root, &DiscoverOptions::default())
+}
+
+/// Loads every theorem file below `root` that `options` selects.
+///
+/// # Errors
+///
+/// Returns [`DiscoverError`] when the directory tree cannot be walked.
+pub fn load_theorem_tree_with_options(
+    root: &Utf8Path,
+    options: &DiscoverOptions,
+) -> Result<TheoremCorpus, DiscoverError> {
+    let files = find_theorem_files(root, options)?;
+    let includes = CrateIncludes::for_dir(root);
+    let mut corpus = load_files(files, |path| {
+        load_with_includes(&RealFs, root, path, &includes)
+    });
+    corpus.duplicates = duplicate_names(&corpus, &RealFs, root);
+    Ok(corpus)
+}
+
+/// Loads every theorem file below `root` that `options` selects, reading
+/// through `provider`.
+///
+/// Each file is loaded like [`load_theorem_tree_with_options`], with the
+/// files, their `Include` fragments, and the `Cargo.toml` that marks the
+/// manifest directory all read through `provider`, so a provider that
+/// overlays unsaved buffers yields the documents an editor shows rather than
+/// those saved on disk.
+///
+/// # Errors
+///
+/// Returns [`DiscoverError`] when the directory tree cannot be walked.
+pub fn load_theorem_tree_with_provider(
+    provider: &(impl FileProvider + ?Sized),
+    root: &Utf8Path,
+    options: &DiscoverOptions,
+) -> Result<TheoremCorpus, DiscoverError> {
+    let files = find_theorem_files_with_provider(provider, root, options)?;
+    let includes = CrateIncludes::enclosing(provider, root);
+    let mut corpus = load_files(files, |path| {
+        load_with_includes(provider, root, path, &includes)
+    });
+    corpus.duplicates = duplicate_names(&corpus, provider, root);
+    Ok(corpus)
+}
+
+/// Loads `files` into a corpus, recording the failures of those that do not
+/// load.
+fn load_files(
+    files: Vec<Utf8PathBuf>,
+    load: impl Fn(&Utf8Path) -> Result<Vec<TheoremDoc>, TheoremFileLoadError>,
+) -> TheoremCorpus {
+    let mut corpus = TheoremCorpus::default();
+    for path in files {
+        match load(&path) {
+            Ok(docs) => {
+                corpus.files.insert(path, docs);
+            }
+            Err(error) => corpus.diagnostics.push(error),
+        }
+    }
+    corpus
+}
+
+/// Loads every theorem file below `root` that `options` selects without
+/// blocking the async runtime.
+///
+/// The walk and every file load run like [`load_theorem_tree_with_options`]
+/// on Tokio's blocking thread pool. Requires the `tokio` feature and a Tokio
+/// runtime.
+///
+/// # Errors
+///
+/// Returns [`DiscoverError`] when the directory tree cannot be walked or the
+/// blocking task cannot complete.
+#[cfg(feature = "tokio")]
+pub async fn load_theorem_tree_async(
+    root: impl Into<Utf8PathBuf>,
+    options: DiscoverOptions,
+) -> Result<TheoremCorpus, DiscoverError> {
+    let root_dir = root.into();
+    let failed_root = root_dir.clone();
+    tokio::task::spawn_blocking(move || load_theorem_tree_with_options(&root_dir, &options))
+        .await
+        .unwrap_or_else(|join_error| {
+            Err(io_err(
+                "walk directory",
+                &failed_root,
+                io::Error::other(join_error),
+            ))
+        })
+}
+
+/// A walk of the directory tree below `root`.
+struct Walk<'a, P: ?Sized> {
+    provider: &'a P,
+    root: &'a Utf8Path,
+    options: &'a DiscoverOptions,
+}
+
+impl<P: FileProvider + ?Sized> Walk<'_, P> {
+    /// Appends the theorem files below the directory at `relative_dir` to
+    /// `files`.
+    fn collect(
+        &self,
+        relative_dir: &Utf8Path,
+        files: &mut Vec<Utf8PathBuf>,
+    ) -> Result<(), DiscoverError> {
+        let directory = self.root.join(relative_dir);
+        let entries = self
+            .provider
+            .read_dir(&directory)
+            .map_err(|source| io_err("open directory", &directory, source))?;
+        for entry in entries {
+            let path = relative_dir.join(&entry.name);
+            if is_ignored(&path, &self.options.ignore) {
+                continue;
+            }
+            match entry.kind {
+                EntryKind::Dir => self.collect(&path, files)?,
+                EntryKind::File if has_extension(&path, &self.options.extensions) => {
+                    files.push(path);
+                }
+                EntryKind::File | EntryKind::Other => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn has_extension(path: &Utf8Path, extensions: &[String]) -> bool {
+    path.extension().is_some_and(|extension| {
+        extensions
+            .iter()
+            .any(|wanted| wanted.trim_start_matches('.') == extension)
+    })
+}
+
+/// Returns `true` when any of `patterns` matches the root-relative `path`.
+fn is_ignored(path: &Utf8Path, patterns: &[String]) -> bool {
+    let components: Vec<&str> = path.iter().collect();
+    patterns.iter().any(|pattern| {
+        if pattern.contains('/') {
+            let segments: Vec<&str> = pattern.split('/').collect();
+            components_match(&segments, &components)
+        } else {
+            components
+                .last()
+                .is_some_and(|name| segment_matches(pattern, name))
+        }
+    })
+}
+
+/// Matches path components against pattern segments, where a `**` segment
+/// matches zero or more components.
+fn components_match(segments: &[&str], components: &[&str]) -> bool {
+    match (segments.split_first(), components.split_first()) {
+        (None, _) => components.is_empty(),
+        (Some((&"**", rest)), _) => {
+            components_match(rest, components)
+                || components
+                    .split_first()
+                    .is_some_and(|(_, tail)| components_match(segments, tail))
+        }
+        (Some((segment, rest)), Some((name, tail))) => {
+            segment_matches(segment, name) && components_match(rest, tail)
+        }
+        (Some(_), None) => false,
+    }
+}
+
+fn segment_matches(segment: &str, name: &str) -> bool {
+    let pattern: Vec<char> = segment.chars().collect();
+    let text: Vec<char> = name.chars().collect();
+    glob_matches(&pattern, &text)
+}
+
+fn glob_matches(pattern: &[char], text: &[char]) -> bool {
+    match (pattern.split_first(), text.split_first()) {
+        (None, _) => text.is_empty(),
+        (Some(('*', rest)), _) => {
+            glob_matches(rest, text)
+                || text
+                    .split_first()
+                    .is_some_and(|(_, tail)| glob_matches(pattern, tail))
+        }
+        (Some((expected, rest)), Some((found, tail))) => {
+            (*expected == '?' || expected == found) && glob_matches(rest, tail)
+        }
+        (Some(_), None) => false,
+    }
+}
+
+fn io_err(operation: &'static str, path: &Utf8Path, source: io::Error) -> DiscoverError {
+    DiscoverError::Io {
+        operation,
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+#[cfg(test)]
+#[path = "discover_tests.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+//! Detection of theorem names declared in more than one file.
+
+use std::collections::BTreeMap;
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use super::TheoremCorpus;
+use crate::file_provider::FileProvider;
+use crate::schema::{SourceId, SourceLocation, theorem_name_locations};
+
+/// A theorem name declared by documents in more than one file.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "theorem '{theorem}' is declared in more than one file: {sites}",
+    sites = render_definitions(definitions)
+)]
+pub struct DuplicateTheoremName {
+    /// The shared name.
+    pub theorem: String,
+    /// Every declaration of the name, in path order.
+    pub definitions: Vec<TheoremDefinition>,
+}
+
+/// One declaration of a theorem name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TheoremDefinition {
+    /// Root-relative path of the declaring file.
+    pub path: Utf8PathBuf,
+    /// Where the name is written, or `None` when the file could not be
+    /// re-read to locate it.
+    pub location: Option<SourceLocation>,
+}
+
+/// Returns the theorem names of `corpus` declared in more than one file,
+/// re-reading the declaring files through `provider` to locate each name.
+pub(super) fn duplicate_names(
+    corpus: &TheoremCorpus,
+    provider: &(impl FileProvider + ?Sized),
+    root: &Utf8Path,
+) -> Vec<DuplicateTheoremName> {
+    let mut declared: BTreeMap<&str, Vec<(&Utf8Path, usize)>> = BTreeMap::new();
+    for (path, docs) in &corpus.files {
+        for (index, doc) in docs.iter().enumerate() {
+            declared
+                .entry(doc.theorem.as_str())
+                .or_default()
+                .push((path, index));
+        }
+    }
+    declared
+        .into_iter()
+        .filter(|(_, sites)| sites.len() > 1)
+        .map(|(theorem, sites)| DuplicateTheoremName {
+            theorem: theorem.to_owned(),
+            definitions: sites
+                .into_iter()
+                .map(|(path, index)| TheoremDefinition {
+                    path: path.to_path_buf(),
+                    location: provider
+                        .read_to_string(&root.join(path))
+                        .ok()
+                        .and_then(|input| {
+                            theorem_name_locations(&SourceId::new(path.as_str()), &input)
+                                .into_iter()
+                                .nth(index)
+                                .flatten()
+                        }),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Renders each declaration as `path:line:column`, or as its path when it
+/// has no location.
+fn render_definitions(definitions: &[TheoremDefinition]) -> String {
+    definitions
+        .iter()
+        .map(|definition| {
+            definition.location.as_ref().map_or_else(
+                || definition.path.to_string(),
+                |location| format!("{}:{}:{}", location.source, location.line, location.column),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,143 @@
+//! Impact-analysis queries over a loaded [`TheoremCorpus`].
+
+use std::fmt;
+
+use camino::Utf8Path;
+
+use super::{TagExpr, TheoremCorpus};
+use crate::schema::rust_type::mentions_type;
+use crate::schema::{
+    ActionCall, LetBinding, Step, StepPath, StepVisitor, Symbol, TheoremDoc, walk_theorem,
+};
+
+/// One call of an action found by [`TheoremCorpus::action_calls`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionCallSite<'a> {
+    /// Root-relative path of the file holding the theorem.
+    pub path: &'a Utf8Path,
+    /// The theorem making the call.
+    pub theorem: &'a TheoremDoc,
+    /// Where in the theorem the call is made.
+    pub location: CallLocation<'a>,
+    /// The call itself.
+    pub call: &'a ActionCall,
+}
+
+/// Where in a theorem an [`ActionCallSite`] is made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallLocation<'a> {
+    /// The `Let` binding of this name.
+    Let(&'a str),
+    /// The `Do` step at this path.
+    Step(StepPath),
+}
+
+impl fmt::Display for CallLocation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Let(name) => write!(f, "Let binding '{name}'"),
+            Self::Step(path) => path.fmt(f),
+        }
+    }
+}
+
+impl TheoremCorpus {
+    /// Returns the documents whose `Tags` satisfy `expr`, in path order.
+    pub fn theorems_tagged<'a>(
+        &'a self,
+        expr: &'a TagExpr,
+    ) -> impl Iterator<Item = (&'a Utf8Path, &'a TheoremDoc)> {
+        self.docs().filter(|(_, doc)| expr.matches_theorem(doc))
+    }
+
+    /// Returns every call of the action named `action` across the corpus,
+    /// in path order and, within a theorem, in execution order.
+    ///
+    /// `Let` bindings and `call`, `must`, and `expect_err` steps are
+    /// searched, including steps nested in `maybe`, `repeat`, and `foreach`
+    /// blocks. Names are compared exactly, as loaded documents hold
+    /// canonical action names.
+    #[must_use]
+    pub fn action_calls(&self, action: &str) -> Vec<ActionCallSite<'_>> {
+        let mut sites = Vec::new();
+        for (path, theorem) in self.docs() {
+            let mut collector = CallCollector {
+                action,
+                location: None,
+                calls: Vec::new(),
+            };
+            walk_theorem(theorem, &mut collector);
+            sites.extend(
+                collector
+                    .calls
+                    .into_iter()
+                    .map(|(location, call)| ActionCallSite {
+                        path,
+                        theorem,
+                        location,
+                        call,
+                    }),
+            );
+        }
+        sites
+    }
+
+    /// Returns the documents with a `Forall` variable whose type mentions
+    /// the Rust type `ty`, in path order.
+    ///
+    /// A variable's type mentions `ty` when it is `ty` or holds it as a
+    /// generic argument, tuple element, or other nested type, so
+    /// `crate::Account` is found in `Vec<crate::Account>`. The field types
+    /// of struct domains are searched too. Types are compared as token
+    /// streams after alias expansion, so spacing does not matter but an
+    /// alias name does not match the type it stands for.
+    pub fn theorems_using_type<'a>(
+        &'a self,
+        ty: &'a str,
+    ) -> impl Iterator<Item = (&'a Utf8Path, &'a TheoremDoc)> {
+        self.docs().filter(|(_, doc)| forall_mentions_type(doc, ty))
+    }
+}
+
+fn forall_mentions_type(doc: &TheoremDoc, ty: &str) -> bool {
+    let field_types = doc
+        .forall_domains
+        .values()
+        .flat_map(|domain| domain.nested_types());
+    doc.forall
+        .values()
+        .map(Symbol::as_str)
+        .chain(field_types)
+        .any(|declared| mentions_type(declared, ty))
+}
+
+/// Collects the calls of one action, tracking the binding or step being
+/// visited.
+struct CallCollector<'doc, 'q> {
+    action: &'q str,
+    location: Option<CallLocation<'doc>>,
+    calls: Vec<(CallLocation<'doc>, &'doc ActionCall)>,
+}
+
+impl<'doc> StepVisitor<'doc> for CallCollector<'doc, '_> {
+    fn visit_let_binding(&mut self, name: &'doc str, _binding: &'doc LetBinding) {
+        self.location = Some(CallLocation::Let(name));
+    }
+
+    fn enter_step(&mut self, _step: &'doc Step, path: &StepPath) {
+        self.location = Some(CallLocation::Step(path.clone()));
+    }
+
+    fn visit_action_call(&mut self, call: &'doc ActionCall) {
+        if call.action != self.action {
+            return;
+        }
+        if let Some(location) = &self.location {
+            self.calls.push((location.clone(), call));
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "discover_query_tests.rs"]
+mod tests;
@@ -0,0 +1,153 @@
+//! Unit tests for impact-analysis queries over a theorem corpus.
+
+use std::collections::BTreeMap;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use crate::discover::{TagExpr, TheoremCorpus};
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+const LEDGER: &str = r#"
+Theorem: Deposit
+About: Deposits grow the balance
+Tags: [ledger, smoke]
+Types:
+  Amount: u64
+Forall:
+  account: crate::Account
+  amount: Amount
+Actions:
+  ledger.open: { returns: u64 }
+  ledger.deposit: { params: { id: u64, amount: u64 }, returns: u64 }
+Let:
+  id: { call: { action: ledger.open, args: {} } }
+Do:
+  - call:
+      action: ledger.deposit
+      args: { id: { ref: id }, amount: { ref: amount } }
+      as: after
+  - maybe:
+      because: deposits may repeat
+      do:
+        - call:
+            action: ledger.deposit
+            args: { id: { ref: id }, amount: 1 }
+Prove:
+  - assert: "after >= amount"
+    because: deposits add
+Evidence:
+  kani: { unwind: 1, expect: SUCCESS }
+Witness:
+  - cover: "amount == 1"
+    because: reachable
+---
+Theorem: Batch
+About: Batches hold accounts
+Tags: [ledger, slow]
+Forall:
+  accounts: { vec: crate::Account, len: { max: 2 } }
+  params: { struct: crate::Params, fields: { owner: "Option<crate::Owner>" } }
+Actions:
+  ledger.open: { returns: u64 }
+Do:
+  - must: { action: ledger.open, args: {} }
+Prove:
+  - assert: "accounts.len() <= 2"
+    because: bounded
+Evidence:
+  kani: { unwind: 3, expect: SUCCESS }
+Witness:
+  - cover: "accounts.is_empty()"
+    because: reachable
+"#;
+
+const BANK: &str = r"
+Theorem: Transfer
+About: Transfers move money
+Tags: [bank]
+Forall:
+  amount: u32
+Prove:
+  - assert: 'amount >= 0'
+    because: unsigned
+Evidence:
+  kani: { unwind: 1, expect: SUCCESS }
+Witness:
+  - cover: 'amount == 0'
+    because: reachable
+";
+
+fn corpus() -> TheoremCorpus {
+    let load = |yaml: &str| load_theorem_docs(yaml).expect("fixture should load");
+    TheoremCorpus {
+        files: BTreeMap::from([
+            (Utf8PathBuf::from("bank.theorem"), load(BANK)),
+            (Utf8PathBuf::from("ledger.theorem"), load(LEDGER)),
+        ]),
+        diagnostics: Vec::new(),
+        duplicates: Vec::new(),
+    }
+}
+
+fn theorem_names<'a>(found: impl Iterator<Item = (&'a Utf8Path, &'a TheoremDoc)>) -> Vec<String> {
+    found.map(|(_, doc)| doc.theorem.to_string()).collect()
+}
+
+#[rstest]
+#[case::single("ledger", &["Deposit", "Batch"])]
+#[case::negated("ledger and not slow", &["Deposit"])]
+#[case::either("bank or slow", &["Transfer", "Batch"])]
+#[case::none("missing", &[])]
+fn tag_queries_select_theorems_in_path_order(#[case] source: &str, #[case] expected: &[&str]) {
+    let corpus = corpus();
+    let expr = TagExpr::parse(source).expect("expression should parse");
+
+    assert_eq!(theorem_names(corpus.theorems_tagged(&expr)), expected);
+}
+
+#[test]
+fn action_calls_are_found_in_bindings_and_nested_steps() {
+    let corpus = corpus();
+
+    let sites: Vec<String> = corpus
+        .action_calls("ledger.deposit")
+        .iter()
+        .map(|site| format!("{} {} {}", site.path, site.theorem.theorem, site.location))
+        .collect();
+
+    assert_eq!(
+        sites,
+        [
+            "ledger.theorem Deposit Do step 1",
+            "ledger.theorem Deposit Do step 2: maybe.do step 1",
+        ]
+    );
+}
+
+#[test]
+fn action_calls_span_theorems_and_binding_kinds() {
+    let corpus = corpus();
+
+    let sites: Vec<String> = corpus
+        .action_calls("ledger.open")
+        .iter()
+        .map(|site| format!("{} {}", site.theorem.theorem, site.location))
+        .collect();
+
+    assert_eq!(sites, ["Deposit Let binding 'id'", "Batch Do step 1"]);
+    assert!(corpus.action_calls("ledger.close").is_empty());
+}
+
+#[rstest]
+#[case::direct_and_nested("crate::Account", &["Deposit", "Batch"])]
+#[case::struct_field("crate :: Owner", &["Batch"])]
+#[case::expanded_alias("u64", &["Deposit"])]
+#[case::alias_name("Amount", &[])]
+#[case::primitive("u32", &["Transfer"])]
+fn type_queries_search_forall_types(#[case] ty: &str, #[case] expected: &[&str]) {
+    let corpus = corpus();
+
+    assert_eq!(theorem_names(corpus.theorems_using_type(ty)), expected);
+}
@@ -0,0 +1,338 @@
+//! Unit tests for theorem file discovery and tree loading.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::{
+    DiscoverOptions, find_theorem_files, is_ignored, load_theorem_tree,
+    load_theorem_tree_with_provider,
+};
+use crate::TheoremFileLoadError;
+use crate::file_provider::{MemoryFs, Overlay};
+use crate::schema::test_fixtures::theorem;
+
+struct Tree {
+    _temp_dir: TempDir,
+    root: Utf8PathBuf,
+}
+
+fn tree(files: &[(&str, &str)]) -> Tree {
+    let temp_dir = TempDir::new().expect("temp directory should be created");
+    let root = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf())
+        .expect("temp directory should be UTF-8");
+    for (path, contents) in files {
+        let file_path = root.join(path);
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent).expect("parent directory should be created");
+        }
+        std::fs::write(&file_path, contents).expect("fixture should be written");
+    }
+    Tree {
+        _temp_dir: temp_dir,
+        root,
+    }
+}
+
+fn paths(found: &[Utf8PathBuf]) -> Vec<&str> {
+    found.iter().map(|path| path.as_str()).collect()
+}
+
+#[test]
+fn finds_theorem_files_recursively_in_sorted_order() {
+    let fixture = tree(&[
+        ("b.theorem", ""),
+        ("nested/deeper/a.theorem", ""),
+        ("nested/notes.md", ""),
+        ("a.theorem", ""),
+    ]);
+
+    let found = find_theorem_files(&fixture.root, &DiscoverOptions::default())
+        .expect("tree should be walked");
+
+    assert_eq!(
+        paths(&found),
+        vec!["a.theorem", "b.theorem", "nested/deeper/a.theorem"]
+    );
+}
+
+#[test]
+fn extension_list_selects_additional_files() {
+    let fixture = tree(&[("a.theorem", ""), ("b.thm", ""), ("c.yaml", "")]);
+    let options = DiscoverOptions {
+        extensions: vec!["theorem".to_owned(), ".thm".to_owned()],
+        ignore: Vec::new(),
+    };
+
+    let found = find_theorem_files(&fixture.root, &options).expect("tree should be walked");
+
+    assert_eq!(paths(&found), vec!["a.theorem", "b.thm"]);
+}
+
+#[test]
+fn ignore_patterns_skip_files_and_directories() {
+    let fixture = tree(&[
+        ("keep.theorem", ""),
+        ("drafts/skip.theorem", ""),
+        ("nested/drafts/skip.theorem", ""),
+        ("nested/old.theorem", ""),
+        ("nested/keep.theorem", ""),
+    ]);
+    let options = DiscoverOptions {
+        ignore: vec!["drafts".to_owned(), "nested/old.*".to_owned()],
+        ..DiscoverOptions::default()
+    };
+
+    let found = find_theorem_files(&fixture.root, &options).expect("tree should be walked");
+
+    assert_eq!(paths(&found), vec!["keep.theorem", "nested/keep.theorem"]);
+}
+
+#[rstest]
+#[case::component_name("drafts", "a/drafts", true)]
+#[case::star_within_component("*.wip.theorem", "a/b.wip.theorem", true)]
+#[case::question_mark("v?.theorem", "v2.theorem", true)]
+#[case::anchored_path("a/b.theorem", "a/b.theorem", true)]
+#[case::anchored_path_elsewhere("a/b.theorem", "c/a/b.theorem", false)]
+#[case::double_star_prefix("**/generated", "a/b/generated", true)]
+#[case::double_star_suffix("vendor/**", "vendor/x/y.theorem", true)]
+#[case::star_stops_at_separator("a/*", "a/b/c.theorem", false)]
+#[case::no_match("drafts", "draft", false)]
+fn ignore_patterns_match_paths(#[case] pattern: &str, #[case] path: &str, #[case] ignored: bool) {
+    assert_eq!(
+        is_ignored(Utf8Path::new(path), &[pattern.to_owned()]),
+        ignored
+    );
+}
+
+#[test]
+fn missing_root_is_an_error() {
+    let fixture = tree(&[]);
+
+    let error = find_theorem_files(&fixture.root.join("absent"), &DiscoverOptions::default())
+        .expect_err("missing root should fail");
+
+    assert!(error.to_string().contains("could not open directory"));
+}
+
+#[test]
+fn tree_loading_keys_documents_by_path() {
+    let fixture = tree(&[
+        ("ledger.theorem", &theorem("Deposit", "")),
+        ("accounts/open.theorem", &theorem("Open", "")),
+    ]);
+
+    let corpus = load_theorem_tree(&fixture.root).expect("tree should be walked");
+
+    assert!(corpus.is_valid());
+    let loaded: Vec<(&str, &str)> = corpus
+        .docs()
+        .map(|(path, doc)| (path.as_str(), doc.theorem.as_str()))
+        .collect();
+    assert_eq!(
+        loaded,
+        vec![
+            ("accounts/open.theorem", "Open"),
+            ("ledger.theorem", "Deposit"),
+        ]
+    );
+}
+
+#[test]
+fn tree_loading_aggregates_failures() {
+    let fixture = tree(&[
+        ("a_empty.theorem", ""),
+        ("b_valid.theorem", &theorem("Valid", "")),
+        ("c_invalid.theorem", "Theorem: Invalid\nAbout: ''\n"),
+    ]);
+
+    let corpus = load_theorem_tree(&fixture.root).expect("tree should be walked");
+
+    assert_eq!(
+        corpus
+            .files
+            .keys()
+            .map(|path| path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["b_valid.theorem"]
+    );
+    assert!(!corpus.is_valid());
+    assert!(matches!(
+        corpus.diagnostics.as_slice(),
+        [
+            TheoremFileLoadError::EmptyTheoremFile { path: empty },
+            TheoremFileLoadError::InvalidTheoremFile { path: invalid, .. },
+        ] if empty == "a_empty.theorem" && invalid == "c_invalid.theorem"
+    ));
+}
+
+#[test]
+fn tree_loading_resolves_includes_against_the_manifest_dir() {
+    let fixture = tree(&[
+        ("Cargo.toml", ""),
+        ("theorems/shared/bounds.yaml", "Forall:\n  amount: u64\n"),
+        (
+            "theorems/ledger/bounded.theorem",
+            &theorem("Bounded", "Include: [theorems/shared/bounds.yaml]\n"),
+        ),
+    ]);
+
+    let corpus =
+        load_theorem_tree(&fixture.root.join("theorems/ledger")).expect("tree should be walked");
+
+    assert!(corpus.is_valid(), "{:?}", corpus.diagnostics);
+    assert_eq!(
+        corpus
+            .docs()
+            .map(|(_, doc)| doc.include.clone())
+            .collect::<Vec<_>>(),
+        vec![vec!["theorems/shared/bounds.yaml".to_owned()]]
+    );
+}
+
+#[test]
+fn provider_trees_resolve_includes_against_the_manifest_dir() {
+    let mut files = MemoryFs::new();
+    files.insert("project/Cargo.toml", "");
+    files.insert(
+        "project/theorems/shared/bounds.yaml",
+        "Forall:\n  amount: u64\n",
+    );
+    files.insert(
+        "project/theorems/bounded.theorem",
+        theorem("Bounded", "Include: [theorems/shared/bounds.yaml]\n"),
+    );
+
+    let corpus = load_theorem_tree_with_provider(
+        &files,
+        Utf8Path::new("project/theorems"),
+        &DiscoverOptions::default(),
+    )
+    .expect("tree should be walked");
+
+    assert!(corpus.is_valid(), "{:?}", corpus.diagnostics);
+    assert_eq!(
+        corpus
+            .files
+            .keys()
+            .map(|path| path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["bounded.theorem"]
+    );
+}
+
+#[test]
+fn provider_trees_load_unsaved_buffers() {
+    let mut disk = MemoryFs::new();
+    disk.insert("project/ledger.theorem", theorem("Saved", ""));
+    disk.insert("project/broken.theorem", "Theorem: Broken\nAbout: ''\n");
+    let mut editor = Overlay::new(disk);
+    editor.set_buffer("project/ledger.theorem", theorem("Unsaved", ""));
+    editor.set_buffer("project/broken.theorem", theorem("Fixed", ""));
+    editor.set_buffer("project/accounts/new.theorem", theorem("Created", ""));
+
+    let corpus = load_theorem_tree_with_provider(
+        &editor,
+        Utf8Path::new("project"),
+        &DiscoverOptions::default(),
+    )
+    .expect("tree should be walked");
+
+    assert!(corpus.is_valid());
+    let loaded: Vec<(&str, &str)> = corpus
+        .docs()
+        .map(|(path, doc)| (path.as_str(), doc.theorem.as_str()))
+        .collect();
+    assert_eq!(
+        loaded,
+        vec![
+            ("accounts/new.theorem", "Created"),
+            ("broken.theorem", "Fixed"),
+            ("ledger.theorem", "Unsaved"),
+        ]
+    );
+}
+
+#[test]
+fn names_declared_in_several_files_are_duplicates() {
+    let fixture = tree(&[
+        ("ledger.theorem", &theorem("Deposit", "")),
+        (
+            "accounts/open.theorem",
+            &format!("{}---\n{}", theorem("Open", ""), theorem("Deposit", "")),
+        ),
+        ("audit.theorem", &theorem("Open", "")),
+    ]);
+
+    let corpus = load_theorem_tree(&fixture.root).expect("tree should be walked");
+
+    assert!(!corpus.is_valid());
+    let rendered: Vec<String> = corpus.duplicates.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "theorem 'Deposit' is declared in more than one file: \
+             accounts/open.theorem:14:10, ledger.theorem:1:10",
+            "theorem 'Open' is declared in more than one file: \
+             accounts/open.theorem:1:10, audit.theorem:1:10",
+        ]
+    );
+}
+
+#[test]
+fn provider_trees_report_duplicate_names() {
+    let mut files = MemoryFs::new();
+    files.insert("project/a.theorem", theorem("Deposit", ""));
+    files.insert("project/b.theorem", theorem("Deposit", ""));
+
+    let corpus = load_theorem_tree_with_provider(
+        &files,
+        Utf8Path::new("project"),
+        &DiscoverOptions::default(),
+    )
+    .expect("tree should be walked");
+
+    let definitions: Vec<(&str, Option<usize>)> = corpus
+        .duplicates
+        .iter()
+        .flat_map(|duplicate| &duplicate.definitions)
+        .map(|definition| {
+            (
+                definition.path.as_str(),
+                definition.location.as_ref().map(|location| location.line),
+            )
+        })
+        .collect();
+    assert_eq!(
+        definitions,
+        vec![("a.theorem", Some(1)), ("b.theorem", Some(1))]
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn async_tree_loading_matches_blocking_loading() {
+    let fixture = tree(&[
+        ("ledger.theorem", &theorem("Deposit", "")),
+        ("drafts/wip.theorem", &theorem("Draft", "")),
+    ]);
+    let options = DiscoverOptions {
+        ignore: vec!["drafts".to_owned()],
+        ..DiscoverOptions::default()
+    };
+
+    let corpus = super::load_theorem_tree_async(fixture.root.clone(), options)
+        .await
+        .expect("tree should be walked");
+
+    assert!(corpus.is_valid());
+    assert_eq!(
+        corpus
+            .files
+            .keys()
+            .map(|path| path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ledger.theorem"]
+    );
+}
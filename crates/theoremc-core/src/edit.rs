@@ -0,0 +1,224 @@
+//! Programmatic edits of `.theorem` sources as minimal text changes.
+//!
+//! Automation that touches one field of a theorem, such as raising an unwind
+//! bound or adding a tag, should not reformat the whole file. A [`Change`]
+//! describes the edit in terms of the theorem document, and [`apply_changes`]
+//! makes it by replacing only the text of the values involved, found through
+//! the locations [`load_spanned_theorem_docs`] records. Comments, layout, and
+//! every other value are left as written. [`plan_change`] returns the same
+//! replacements as [`TextEdit`]s for editors that apply them themselves.
+//!
+//! The edited source is loaded again and compared with the document the
+//! change describes, so an edit that would change anything else is refused.
+//!
+//! # Examples
+//!
+//!     use theoremc_core::edit::{Change, apply_changes};
+//!     use theoremc_core::schema::SourceId;
+//!
+//!     let input = r#"Theorem: Bounded
+//!     About: Stays in range  # checked nightly
+//!     Tags: [smoke]
+//!     Prove:
+//!       - assert: "true"
+//!         because: trivially true
+//!     Evidence:
+//!       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!     "#;
+//!     let changes = [Change::SetUnwind(5), Change::AddTag("nightly".to_owned())];
+//!     let edited = apply_changes(&SourceId::new("bounded.theorem"), input, "Bounded", &changes).unwrap();
+//!     assert_eq!(
+//!         edited,
+//!         r#"Theorem: Bounded
+//!     About: Stays in range  # checked nightly
+//!     Tags: [smoke, nightly]
+//!     Prove:
+//!       - assert: "true"
+//!         because: trivially true
+//!     Evidence:
+//!       kani: { unwind: 5, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!     "#
+//!     );
+
+use std::ops::Range;
+
+use thiserror::Error;
+
+use crate::schema::{
+    KaniExpectation, SchemaError, SourceId, SpannedTheoremDoc, Symbol, TheoremDoc,
+    load_spanned_theorem_docs,
+};
+
+#[path = "edit_plan.rs"]
+mod plan;
+#[path = "edit_text.rs"]
+mod text;
+
+use plan::Target;
+use text::{apply_edits, scalar};
+
+/// A change to one theorem document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// Replaces the `About` summary.
+    SetAbout(String),
+    /// Sets `unwind` in every Kani configuration the theorem writes.
+    SetUnwind(u32),
+    /// Sets `expect` in every Kani configuration the theorem writes.
+    SetExpect(KaniExpectation),
+    /// Adds a tag after the existing ones, unless the theorem has it already.
+    AddTag(String),
+    /// Removes a tag, if the theorem has it.
+    RemoveTag(String),
+}
+
+/// A replacement of a byte range of source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Byte range of the replaced text; empty for an insertion.
+    pub range: Range<usize>,
+    /// Text written in place of the range.
+    pub replacement: String,
+}
+
+/// Errors produced while editing a theorem source.
+#[derive(Debug, Error)]
+pub enum EditError {
+    /// The source does not load as theorem documents.
+    #[error(transparent)]
+    Parse(#[from] SchemaError),
+    /// No theorem document in the source has the requested name.
+    #[error("no theorem named '{theorem}'")]
+    UnknownTheorem {
+        /// The requested theorem name.
+        theorem: String,
+    },
+    /// The value to change is not written in the theorem document itself,
+    /// or is written in a form that cannot be edited in place, such as a
+    /// block scalar.
+    #[error("cannot edit '{path}' of theorem '{theorem}' in place")]
+    Unsupported {
+        /// The theorem being edited.
+        theorem: String,
+        /// Dotted path of the value, such as `Evidence.kani.unwind`.
+        path: String,
+    },
+    /// The edited source would load to a different document than the
+    /// change describes, so the source is left unchanged.
+    #[error("editing theorem '{theorem}' would change more than the requested value")]
+    Changed {
+        /// The theorem being edited.
+        theorem: String,
+    },
+}
+
+/// Applies `changes` in order to the theorem named `theorem` in `input`,
+/// returning the edited source.
+///
+/// # Errors
+///
+/// Returns the errors of [`plan_change`] for the first change that cannot
+/// be made.
+pub fn apply_changes(
+    source: &SourceId,
+    input: &str,
+    theorem: &str,
+    changes: &[Change],
+) -> Result<String, EditError> {
+    changes.iter().try_fold(input.to_owned(), |text, change| {
+        let edits = plan_change(source, &text, theorem, change)?;
+        Ok(apply_edits(&text, &edits))
+    })
+}
+
+/// Returns the text edits that make `change` to the theorem named `theorem`
+/// in `input`, in source order. No edits are returned when the theorem
+/// already reads as the change asks.
+///
+/// # Errors
+///
+/// Returns [`EditError::Parse`] when `input` does not load,
+/// [`EditError::UnknownTheorem`] when no theorem has the name,
+/// [`EditError::Unsupported`] when the value is not written where it can be
+/// edited, and [`EditError::Changed`] when the edited source would not load
+/// to the document the change describes.
+pub fn plan_change(
+    source: &SourceId,
+    input: &str,
+    theorem: &str,
+    change: &Change,
+) -> Result<Vec<TextEdit>, EditError> {
+    let docs = load_spanned_theorem_docs(source, input)?;
+    let spanned = named(&docs, theorem).ok_or_else(|| EditError::UnknownTheorem {
+        theorem: theorem.to_owned(),
+    })?;
+    let mut expected = spanned.doc().clone();
+    if !changed(&mut expected, change) {
+        return Ok(Vec::new());
+    }
+    let target = Target {
+        source,
+        input,
+        theorem,
+        spanned,
+    };
+    let mut edits = match change {
+        Change::SetAbout(about) => vec![target.replace(&["About"], scalar(about))?],
+        Change::SetUnwind(unwind) => target.set_kani("unwind", &unwind.to_string())?,
+        Change::SetExpect(expect) => target.set_kani("expect", expect.as_str())?,
+        Change::AddTag(tag) => vec![target.add_tag(tag)?],
+        Change::RemoveTag(tag) => vec![target.remove_tag(tag)?],
+    };
+    edits.sort_by_key(|edit| edit.range.start);
+    let edited = apply_edits(input, &edits);
+    let reloaded = load_spanned_theorem_docs(source, &edited).ok();
+    let matches = reloaded
+        .as_deref()
+        .and_then(|docs_after| named(docs_after, theorem))
+        .is_some_and(|doc| doc.doc() == &expected);
+    if matches {
+        Ok(edits)
+    } else {
+        Err(EditError::Changed {
+            theorem: theorem.to_owned(),
+        })
+    }
+}
+
+fn named<'d>(docs: &'d [SpannedTheoremDoc], theorem: &str) -> Option<&'d SpannedTheoremDoc> {
+    docs.iter()
+        .find(|doc| doc.doc().theorem.as_str() == theorem)
+}
+
+/// Makes `change` to the loaded document, returning whether it changed.
+fn changed(doc: &mut TheoremDoc, change: &Change) -> bool {
+    let before = doc.clone();
+    match change {
+        Change::SetAbout(about) => about.clone_into(&mut doc.about),
+        Change::SetUnwind(unwind) => doc
+            .evidence
+            .kani
+            .iter_mut()
+            .for_each(|kani| kani.unwind = *unwind),
+        Change::SetExpect(expect) => doc
+            .evidence
+            .kani
+            .iter_mut()
+            .for_each(|kani| kani.expect = *expect),
+        Change::AddTag(tag) => {
+            if !doc.tags.iter().any(|existing| existing == tag) {
+                doc.tags.push(Symbol::from(tag));
+            }
+        }
+        Change::RemoveTag(tag) => {
+            if let Some(index) = doc.tags.iter().position(|existing| existing == tag) {
+                doc.tags.remove(index);
+            }
+        }
+    }
+    *doc != before
+}
+
+#[cfg(test)]
+#[path = "edit_tests.rs"]
+mod tests;
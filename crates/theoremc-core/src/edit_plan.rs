@@ -0,0 +1,223 @@
+//! Locating the text of the values a change rewrites.
+
+use std::ops::Range;
+
+use super::text::{column_of, insertion, line_start, offset, removal, scalar, scalar_length};
+use super::{EditError, TextEdit};
+use crate::format::{is_block_header, section_rank};
+use crate::schema::{
+    RawDocument, SourceId, SourceLocation, SpannedTheoremDoc, canonical_for_alias,
+    deserialize_failure, parse_options,
+};
+
+/// The theorem being edited and the source it was loaded from.
+pub(super) struct Target<'a> {
+    pub(super) source: &'a SourceId,
+    pub(super) input: &'a str,
+    pub(super) theorem: &'a str,
+    pub(super) spanned: &'a SpannedTheoremDoc,
+}
+
+impl Target<'_> {
+    /// Replaces the scalar written at `path` with `text`.
+    pub(super) fn replace(&self, path: &[&str], text: String) -> Result<TextEdit, EditError> {
+        Ok(TextEdit {
+            range: self.scalar(path)?,
+            replacement: text,
+        })
+    }
+
+    /// Sets `key` to `text` in every Kani configuration the theorem writes,
+    /// adding the key to configurations that do not write it.
+    pub(super) fn set_kani(&self, key: &str, text: &str) -> Result<Vec<TextEdit>, EditError> {
+        let configurations: Vec<Vec<String>> = if self.located(&["Evidence", "kani", "0"]).is_some()
+        {
+            (0..self.spanned.doc().evidence.kani.len())
+                .map(|index| vec!["Evidence".to_owned(), "kani".to_owned(), index.to_string()])
+                .collect()
+        } else {
+            vec![vec!["Evidence".to_owned(), "kani".to_owned()]]
+        };
+        configurations
+            .iter()
+            .map(|configuration| {
+                let mut path: Vec<&str> = configuration.iter().map(String::as_str).collect();
+                let mapping = self.located(&path).ok_or_else(|| self.unsupported(&path))?;
+                path.push(key);
+                if self.located(&path).is_some() {
+                    return self.replace(&path, text.to_owned());
+                }
+                Ok(self.insert_entry(mapping, &format!("{key}: {text}")))
+            })
+            .collect()
+    }
+
+    /// Inserts `entry` as the first entry of the mapping starting at
+    /// `mapping`.
+    fn insert_entry(&self, mapping: usize, entry: &str) -> TextEdit {
+        let rest = self.input.get(mapping..).unwrap_or_default();
+        if let Some(inside) = rest.strip_prefix('{') {
+            let padding = inside.len() - inside.trim_start_matches(' ').len();
+            let at = mapping + 1 + padding;
+            let separator = if inside.trim_start().starts_with('}') {
+                ""
+            } else {
+                ", "
+            };
+            return insertion(at, format!("{entry}{separator}"));
+        }
+        let indent = " ".repeat(column_of(self.input, mapping));
+        insertion(mapping, format!("{entry}\n{indent}"))
+    }
+
+    pub(super) fn add_tag(&self, tag: &str) -> Result<TextEdit, EditError> {
+        let Some(tags) = self.located(&["Tags"]) else {
+            return self.insert_tags_section(tag);
+        };
+        let written = self.written_tags();
+        let text = scalar(tag);
+        let Some(last) = written.checked_sub(1) else {
+            return if self
+                .input
+                .get(tags..)
+                .is_some_and(|rest| rest.starts_with('['))
+            {
+                Ok(insertion(tags + 1, text))
+            } else {
+                Err(self.unsupported(&["Tags"]))
+            };
+        };
+        let last_index = last.to_string();
+        let item_path = ["Tags", last_index.as_str()];
+        let item = self.scalar(&item_path)?;
+        if self.in_flow(&item_path) {
+            return Ok(insertion(item.end, format!(", {text}")));
+        }
+        let prefix = self.item_prefix(item.start, &item_path)?;
+        let line_end = self
+            .input
+            .get(item.end..)
+            .and_then(|rest| rest.find('\n'))
+            .map_or(self.input.len(), |newline| item.end + newline);
+        Ok(insertion(line_end, format!("\n{prefix}{text}")))
+    }
+
+    /// Inserts a `Tags` section holding `tag` above the first section that
+    /// follows it in specification order.
+    fn insert_tags_section(&self, tag: &str) -> Result<TextEdit, EditError> {
+        let documents: Vec<RawDocument> =
+            serde_saphyr::from_multiple_with_options(self.input, parse_options())
+                .map_err(|error| deserialize_failure(self.source, self.input, &error))?;
+        let tags_rank = section_rank("Tags");
+        let following = documents
+            .iter()
+            .find_map(|document| match document {
+                RawDocument::Theorem(raw_doc) if raw_doc.theorem.value.as_str() == self.theorem => {
+                    raw_doc.section_keys.iter().find(|key| {
+                        let canonical = canonical_for_alias(&key.value).unwrap_or(&key.value);
+                        section_rank(canonical) > tags_rank
+                    })
+                }
+                _ => None,
+            })
+            .ok_or_else(|| self.unsupported(&["Tags"]))?;
+        let line = usize::try_from(following.referenced.line()).unwrap_or_default();
+        let at = offset(self.input, line, 1).ok_or_else(|| self.unsupported(&["Tags"]))?;
+        Ok(insertion(at, format!("Tags: [{}]\n", scalar(tag))))
+    }
+
+    pub(super) fn remove_tag(&self, tag: &str) -> Result<TextEdit, EditError> {
+        let index = self
+            .spanned
+            .doc()
+            .tags
+            .iter()
+            .position(|existing| existing == tag)
+            .filter(|&index| index < self.written_tags())
+            .ok_or_else(|| self.unsupported(&["Tags"]))?;
+        let item_index = index.to_string();
+        let item_path = ["Tags", item_index.as_str()];
+        let item = self.scalar(&item_path)?;
+        if !self.in_flow(&item_path) {
+            self.item_prefix(item.start, &item_path)?;
+            let start = line_start(self.input, item.start);
+            let end = self
+                .input
+                .get(item.end..)
+                .and_then(|rest| rest.find('\n'))
+                .map_or(self.input.len(), |newline| item.end + newline + 1);
+            return Ok(removal(start..end));
+        }
+        let neighbour = |position: usize| {
+            let neighbour_index = position.to_string();
+            self.scalar(&["Tags", neighbour_index.as_str()])
+        };
+        let range = if index + 1 < self.written_tags() {
+            item.start..neighbour(index + 1)?.start
+        } else if let Some(previous) = index.checked_sub(1) {
+            neighbour(previous)?.end..item.end
+        } else {
+            item
+        };
+        Ok(removal(range))
+    }
+
+    /// Returns the `- ` prefix of the block sequence item starting at
+    /// `start`, with its indentation.
+    fn item_prefix(&self, start: usize, path: &[&str]) -> Result<&str, EditError> {
+        self.input
+            .get(line_start(self.input, start)..start)
+            .filter(|prefix| prefix.trim() == "-" && prefix.ends_with(' '))
+            .ok_or_else(|| self.unsupported(path))
+    }
+
+    /// Returns the number of tags the theorem writes itself.
+    fn written_tags(&self) -> usize {
+        (0..self.spanned.doc().tags.len())
+            .take_while(|position| {
+                let index = position.to_string();
+                self.located(&["Tags", index.as_str()]).is_some()
+            })
+            .count()
+    }
+
+    /// Returns the byte range of the single-line scalar written at `path`.
+    fn scalar(&self, path: &[&str]) -> Result<Range<usize>, EditError> {
+        let start = self.located(path).ok_or_else(|| self.unsupported(path))?;
+        let in_block_scalar = self
+            .input
+            .get(..start)
+            .and_then(|before| before.split_whitespace().next_back())
+            .is_some_and(is_block_header);
+        let length = self
+            .input
+            .get(start..)
+            .filter(|_| !in_block_scalar)
+            .and_then(|rest| scalar_length(rest, self.in_flow(path)))
+            .ok_or_else(|| self.unsupported(path))?;
+        Ok(start..start + length)
+    }
+
+    /// Returns whether the value at `path` is inside a flow collection.
+    fn in_flow(&self, path: &[&str]) -> bool {
+        (0..path.len()).any(|depth| {
+            path.get(..depth)
+                .and_then(|ancestor| self.located(ancestor))
+                .and_then(|at| self.input.get(at..))
+                .is_some_and(|rest| rest.starts_with(['[', '{']))
+        })
+    }
+
+    /// Returns the byte offset of the value written at `path`.
+    fn located(&self, path: &[&str]) -> Option<usize> {
+        let SourceLocation { line, column, .. } = self.spanned.location(path)?;
+        offset(self.input, *line, *column)
+    }
+
+    fn unsupported(&self, path: &[&str]) -> EditError {
+        EditError::Unsupported {
+            theorem: self.theorem.to_owned(),
+            path: path.join("."),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Unit tests for programmatic theorem source edits.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+
+const PROVE: &str = concat!(
+    "Prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+);
+
+fn theorem(head: &str, evidence: &str) -> String {
+    format!("Theorem: T\nAbout: Example\n{head}{PROVE}Evidence:\n{evidence}")
+}
+
+const BLOCK_KANI: &str = concat!(
+    "  kani:\n",
+    "    unwind: 1  # enough for the loop\n",
+    "    expect: SUCCESS\n",
+    "    allow_vacuous: true\n",
+    "    vacuity_because: none\n",
+);
+
+const FLOW_KANI: &str =
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n";
+
+fn apply(input: &str, changes: &[Change]) -> Result<String, EditError> {
+    apply_changes(&SourceId::new("t.theorem"), input, "T", changes)
+}
+
+#[test]
+fn set_unwind_replaces_only_the_value() {
+    let input = theorem("", BLOCK_KANI);
+    let edited = apply(&input, &[Change::SetUnwind(12)]).expect("edit applies");
+
+    assert_eq!(
+        edited,
+        input.replace("unwind: 1  #", "unwind: 12  #"),
+        "comment and layout should be kept"
+    );
+}
+
+#[test]
+fn set_unwind_updates_every_configuration() {
+    let evidence = concat!(
+        "  kani:\n",
+        "    - { name: small, unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+        "    - name: large\n",
+        "      unwind: 8\n",
+        "      expect: SUCCESS\n",
+        "      allow_vacuous: true\n",
+        "      vacuity_because: none\n",
+    );
+    let input = theorem("", evidence);
+    let edited = apply(&input, &[Change::SetUnwind(3)]).expect("edit applies");
+
+    assert_eq!(
+        edited,
+        input
+            .replace("unwind: 1,", "unwind: 3,")
+            .replace("unwind: 8", "unwind: 3")
+    );
+}
+
+#[test]
+fn set_expect_replaces_a_flow_mapping_value() {
+    let input = theorem("", FLOW_KANI);
+    let edited =
+        apply(&input, &[Change::SetExpect(KaniExpectation::Failure)]).expect("edit applies");
+
+    assert_eq!(edited, input.replace("SUCCESS", "FAILURE"));
+}
+
+#[test]
+fn missing_keys_are_added_to_the_mapping() {
+    let defaults =
+        "Defaults:\n  Evidence:\n    kani:\n      unwind: 4\n      expect: SUCCESS\n---\n";
+    let input = format!(
+        "{defaults}{}",
+        theorem(
+            "",
+            "  kani:\n    expect: FAILURE\n    allow_vacuous: true\n    vacuity_because: none\n",
+        )
+    );
+    let edited = apply(&input, &[Change::SetUnwind(6)]).expect("edit applies");
+
+    assert_eq!(
+        edited,
+        input.replace(
+            "    expect: FAILURE\n",
+            "    unwind: 6\n    expect: FAILURE\n"
+        )
+    );
+}
+
+#[rstest]
+#[case::plain("Rounds down", "About: Rounds down\n")]
+#[case::needs_quotes("Holds: always", "About: \"Holds: always\"\n")]
+#[case::boolean_word("true", "About: \"true\"\n")]
+fn set_about_quotes_when_needed(#[case] about: &str, #[case] line: &str) {
+    let input = theorem("", FLOW_KANI);
+    let edited = apply(&input, &[Change::SetAbout(about.to_owned())]).expect("edit applies");
+
+    assert_eq!(edited, input.replace("About: Example\n", line));
+}
+
+#[rstest]
+#[case::flow("Tags: [smoke, slow]\n", "Tags: [smoke, slow, nightly]\n")]
+#[case::empty_flow("Tags: []\n", "Tags: [nightly]\n")]
+#[case::block(
+    "Tags:\n  - smoke  # quick\n  - slow\n",
+    "Tags:\n  - smoke  # quick\n  - slow\n  - nightly\n"
+)]
+#[case::absent("", "Tags: [nightly]\n")]
+fn add_tag_extends_the_written_list(#[case] before: &str, #[case] after: &str) {
+    let input = theorem(before, FLOW_KANI);
+    let edited = apply(&input, &[Change::AddTag("nightly".to_owned())]).expect("edit applies");
+
+    assert_eq!(edited, theorem(after, FLOW_KANI));
+}
+
+#[rstest]
+#[case::flow_first("Tags: [smoke, slow]\n", "smoke", "Tags: [slow]\n")]
+#[case::flow_last("Tags: [smoke, slow]\n", "slow", "Tags: [smoke]\n")]
+#[case::flow_only("Tags: [smoke]\n", "smoke", "Tags: []\n")]
+#[case::block("Tags:\n  - smoke\n  - slow\n", "smoke", "Tags:\n  - slow\n")]
+fn remove_tag_deletes_the_item(#[case] before: &str, #[case] tag: &str, #[case] after: &str) {
+    let input = theorem(before, FLOW_KANI);
+    let edited = apply(&input, &[Change::RemoveTag(tag.to_owned())]).expect("edit applies");
+
+    assert_eq!(edited, theorem(after, FLOW_KANI));
+}
+
+#[test]
+fn changes_already_in_place_make_no_edits() {
+    let input = theorem("Tags: [smoke]\n", FLOW_KANI);
+    let edits = plan_change(
+        &SourceId::new("t.theorem"),
+        &input,
+        "T",
+        &Change::AddTag("smoke".to_owned()),
+    )
+    .expect("plan succeeds");
+
+    assert_eq!(edits, []);
+}
+
+#[test]
+fn plan_change_returns_text_edits() {
+    let input = theorem("", FLOW_KANI);
+    let edits = plan_change(
+        &SourceId::new("t.theorem"),
+        &input,
+        "T",
+        &Change::SetUnwind(5),
+    )
+    .expect("plan succeeds");
+    let start = input.find("unwind: 1").expect("unwind is written") + "unwind: ".len();
+
+    assert_eq!(
+        edits,
+        [TextEdit {
+            range: start..start + 1,
+            replacement: "5".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn unknown_theorems_are_reported() {
+    let input = theorem("", FLOW_KANI);
+    let error = apply_changes(
+        &SourceId::new("t.theorem"),
+        &input,
+        "Missing",
+        &[Change::SetUnwind(2)],
+    )
+    .expect_err("no such theorem");
+
+    assert!(
+        matches!(&error, EditError::UnknownTheorem { theorem } if theorem == "Missing"),
+        "{error}"
+    );
+}
+
+#[test]
+fn block_scalars_are_not_edited() {
+    let input = theorem("", FLOW_KANI).replace("About: Example\n", "About: |\n  Example\n");
+    let error = apply(&input, &[Change::SetAbout("Other".to_owned())]).expect_err("unsupported");
+
+    assert!(
+        matches!(&error, EditError::Unsupported { path, .. } if path == "About"),
+        "{error}"
+    );
+}
+
+#[test]
+fn edits_that_change_inherited_values_are_refused() {
+    let defaults = "Defaults:\n  Tags: [ledger]\n---\n";
+    let input = format!("{defaults}{}", theorem("", FLOW_KANI));
+    let error = apply(&input, &[Change::AddTag("nightly".to_owned())])
+        .expect_err("writing Tags would drop the inherited tag");
+
+    assert!(matches!(error, EditError::Changed { .. }), "{error}");
+}
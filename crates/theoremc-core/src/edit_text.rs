@@ -0,0 +1,122 @@
+//! Text splicing and scalar scanning for in-place edits.
+
+use std::ops::Range;
+
+use super::TextEdit;
+
+/// Applies edits given in source order, last first so earlier ranges stay
+/// valid.
+pub(super) fn apply_edits(input: &str, edits: &[TextEdit]) -> String {
+    let mut text = input.to_owned();
+    for edit in edits.iter().rev() {
+        text.replace_range(edit.range.clone(), &edit.replacement);
+    }
+    text
+}
+
+pub(super) const fn insertion(at: usize, text: String) -> TextEdit {
+    TextEdit {
+        range: at..at,
+        replacement: text,
+    }
+}
+
+pub(super) const fn removal(range: Range<usize>) -> TextEdit {
+    TextEdit {
+        range,
+        replacement: String::new(),
+    }
+}
+
+/// Returns the byte length of the scalar `rest` starts with, without any
+/// comment after it, or `None` for collections, block scalars, and quoted
+/// scalars that continue on another line.
+pub(super) fn scalar_length(rest: &str, in_flow: bool) -> Option<usize> {
+    let first = rest.chars().next()?;
+    let length = match first {
+        '"' => quoted_length(rest, '"')?,
+        '\'' => quoted_length(rest, '\'')?,
+        '[' | '{' | '|' | '>' => return None,
+        _ => {
+            let line = rest.split('\n').next().unwrap_or(rest);
+            let content = line.split(" #").next().unwrap_or(line);
+            let value = if in_flow {
+                content.split([',', ']', '}']).next().unwrap_or(content)
+            } else {
+                content
+            };
+            value.trim_end().len()
+        }
+    };
+    (length > 0).then_some(length)
+}
+
+/// Returns the byte length of the quoted scalar `rest` starts with when it
+/// closes on the same line.
+fn quoted_length(rest: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    let mut characters = rest.char_indices().skip(1).peekable();
+    while let Some((index, character)) = characters.next() {
+        match character {
+            '\n' => return None,
+            '\\' if quote == '"' => escaped = !escaped,
+            '\'' if quote == '\'' && characters.peek().is_some_and(|&(_, next)| next == '\'') => {
+                characters.next();
+            }
+            candidate if candidate == quote && !escaped => return Some(index + 1),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+/// Writes `text` as a plain scalar when YAML reads it back as the same
+/// string, and as a double-quoted scalar otherwise.
+pub(super) fn scalar(text: &str) -> String {
+    let plain = text
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && text.chars().all(|character| {
+            character.is_alphanumeric() || matches!(character, '_' | '-' | '.' | ' ')
+        })
+        && !text.ends_with(' ')
+        && !matches!(
+            text.to_ascii_lowercase().as_str(),
+            "true" | "false" | "null" | "yes" | "no" | "on" | "off" | "y" | "n"
+        )
+        && text.parse::<f64>().is_err();
+    if plain {
+        text.to_owned()
+    } else {
+        serde_json::Value::from(text).to_string()
+    }
+}
+
+/// Returns the byte offset of a 1-indexed line and character column.
+pub(super) fn offset(text: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(line.checked_sub(1)?)
+        .map(str::len)
+        .sum();
+    let line_text = text.get(line_start..)?;
+    line_text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(line_text.len()))
+        .nth(column.checked_sub(1)?)
+        .map(|index| line_start + index)
+}
+
+pub(super) fn line_start(text: &str, at: usize) -> usize {
+    text.get(..at)
+        .and_then(|before| before.rfind('\n'))
+        .map_or(0, |newline| newline + 1)
+}
+
+/// Returns the 0-indexed character column of byte offset `at`.
+pub(super) fn column_of(text: &str, at: usize) -> usize {
+    text.get(line_start(text, at)..at)
+        .map_or(0, |before| before.chars().count())
+}
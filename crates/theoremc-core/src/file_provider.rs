@@ -0,0 +1,394 @@
+//! Pluggable file access for theorem discovery and loading.
+//!
+//! The [`FileProvider`] trait is the only file access used by
+//! [`load_theorem_file_from_provider`](crate::load_theorem_file_from_provider)
+//! and the provider-based functions in [`discover`](crate::discover), so a
+//! language server or test harness can drive the loader without touching
+//! disk. Three providers are included:
+//!
+//! - [`RealFs`] reads the local filesystem.
+//! - [`MemoryFs`] serves files from an in-memory map.
+//! - [`Overlay`] serves unsaved editor buffers in front of another provider.
+//!
+//! In-memory paths are compared after converting `\` to `/` and dropping `.`
+//! components, so `./theorems/a.theorem` and `theorems\a.theorem` name the
+//! same file. Directories exist implicitly whenever a file lies below them.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use cap_std::{ambient_authority, fs_utf8::Dir as Utf8Dir};
+
+use crate::path_format::normalize_path_separators;
+use crate::schema::IncludeResolver;
+use crate::schema::TheoremDoc;
+use crate::theorem_file::{
+    TheoremFileLoadError, io_error_code, is_invalid_theorem_path, load_relative_source, read_file,
+    relative_theorem_path,
+};
+
+#[path = "file_provider_manifest.rs"]
+mod manifest;
+
+pub use manifest::CrateIncludes;
+
+/// Read access to a tree of UTF-8 files.
+pub trait FileProvider {
+    /// Returns the contents of the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error when the file does not exist or cannot be read.
+    fn read_to_string(&self, path: &Utf8Path) -> io::Result<String>;
+
+    /// Returns the entries of the directory at `path`, in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error when the directory does not exist or cannot be
+    /// listed.
+    fn read_dir(&self, path: &Utf8Path) -> io::Result<Vec<DirEntry>>;
+}
+
+/// One entry of a directory listed by [`FileProvider::read_dir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    /// File name of the entry, without its directory.
+    pub name: String,
+    /// What the entry is.
+    pub kind: EntryKind,
+}
+
+/// The kind of a [`DirEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    /// A regular file.
+    File,
+    /// A directory.
+    Dir,
+    /// Anything else, such as a symbolic link, which discovery skips.
+    Other,
+}
+
+/// The local filesystem, accessed with ambient authority.
+///
+/// Symbolic links are listed as [`EntryKind::Other`] rather than followed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RealFs;
+
+impl FileProvider for RealFs {
+    fn read_to_string(&self, path: &Utf8Path) -> io::Result<String> {
+        read_file(path.as_std_path())
+    }
+
+    fn read_dir(&self, path: &Utf8Path) -> io::Result<Vec<DirEntry>> {
+        let directory = Utf8Dir::open_ambient_dir(path, ambient_authority())?;
+        directory
+            .entries()?
+            .map(|entry_result| {
+                let entry = entry_result?;
+                let file_type = entry.file_type()?;
+                let kind = if file_type.is_dir() {
+                    EntryKind::Dir
+                } else if file_type.is_file() {
+                    EntryKind::File
+                } else {
+                    EntryKind::Other
+                };
+                Ok(DirEntry {
+                    name: entry.file_name()?,
+                    kind,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Files held in memory, keyed by path.
+///
+/// # Examples
+///
+///     use camino::Utf8Path;
+///     use theoremc_core::file_provider::{FileProvider, MemoryFs};
+///
+///     let mut files = MemoryFs::new();
+///     files.insert("theorems/ledger.theorem", "Theorem: Deposit\n");
+///
+///     let contents = files.read_to_string(Utf8Path::new("./theorems/ledger.theorem"));
+///     assert_eq!(contents.ok().as_deref(), Some("Theorem: Deposit\n"));
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryFs {
+    files: BTreeMap<Utf8PathBuf, String>,
+}
+
+impl MemoryFs {
+    /// Creates a provider with no files.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `contents` at `path`, returning the contents it replaced.
+    pub fn insert(
+        &mut self,
+        path: impl AsRef<Utf8Path>,
+        contents: impl Into<String>,
+    ) -> Option<String> {
+        self.files
+            .insert(normalized_path(path.as_ref()), contents.into())
+    }
+
+    /// Removes the file at `path`, returning its contents.
+    pub fn remove(&mut self, path: impl AsRef<Utf8Path>) -> Option<String> {
+        self.files.remove(&normalized_path(path.as_ref()))
+    }
+
+    /// Returns the stored contents of the file at `path`.
+    #[must_use]
+    pub fn get(&self, path: impl AsRef<Utf8Path>) -> Option<&str> {
+        self.files
+            .get(&normalized_path(path.as_ref()))
+            .map(String::as_str)
+    }
+}
+
+impl FileProvider for MemoryFs {
+    fn read_to_string(&self, path: &Utf8Path) -> io::Result<String> {
+        self.get(path)
+            .map(str::to_owned)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    fn read_dir(&self, path: &Utf8Path) -> io::Result<Vec<DirEntry>> {
+        let directory = normalized_path(path);
+        let mut entries = BTreeMap::new();
+        for file in self.files.keys() {
+            let Ok(rest) = file.strip_prefix(&directory) else {
+                continue;
+            };
+            let mut components = rest.components();
+            let Some(name) = components.next() else {
+                continue;
+            };
+            let kind = if components.next().is_some() {
+                EntryKind::Dir
+            } else {
+                EntryKind::File
+            };
+            entries.insert(name.as_str().to_owned(), kind);
+        }
+        if entries.is_empty() && !directory.as_str().is_empty() {
+            return Err(io::Error::from(io::ErrorKind::NotFound));
+        }
+        Ok(entries
+            .into_iter()
+            .map(|(name, kind)| DirEntry { name, kind })
+            .collect())
+    }
+}
+
+/// Unsaved editor buffers layered over another provider.
+///
+/// A buffer shadows the file at the same path in the base provider, and a
+/// buffer for a file the base does not have appears in directory listings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overlay<P> {
+    base: P,
+    buffers: MemoryFs,
+}
+
+impl<P: FileProvider> Overlay<P> {
+    /// Creates an overlay with no buffers over `base`.
+    pub fn new(base: P) -> Self {
+        Self {
+            base,
+            buffers: MemoryFs::new(),
+        }
+    }
+
+    /// Returns the provider beneath the buffers.
+    pub const fn base(&self) -> &P {
+        &self.base
+    }
+
+    /// Sets the buffer for `path`, returning the buffer it replaced.
+    pub fn set_buffer(
+        &mut self,
+        path: impl AsRef<Utf8Path>,
+        contents: impl Into<String>,
+    ) -> Option<String> {
+        self.buffers.insert(path, contents)
+    }
+
+    /// Discards the buffer for `path`, so reads reach the base provider
+    /// again, returning the discarded contents.
+    pub fn clear_buffer(&mut self, path: impl AsRef<Utf8Path>) -> Option<String> {
+        self.buffers.remove(path)
+    }
+}
+
+impl<P: FileProvider> FileProvider for Overlay<P> {
+    fn read_to_string(&self, path: &Utf8Path) -> io::Result<String> {
+        self.buffers.get(path).map_or_else(
+            || self.base.read_to_string(path),
+            |buffer| Ok(buffer.to_owned()),
+        )
+    }
+
+    fn read_dir(&self, path: &Utf8Path) -> io::Result<Vec<DirEntry>> {
+        let buffered = self.buffers.read_dir(path).unwrap_or_default();
+        let base_entries = match self.base.read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) if !buffered.is_empty() => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        let mut entries: BTreeMap<String, EntryKind> = base_entries
+            .into_iter()
+            .map(|entry| (entry.name, entry.kind))
+            .collect();
+        for entry in buffered {
+            entries.entry(entry.name).or_insert(entry.kind);
+        }
+        Ok(entries
+            .into_iter()
+            .map(|(name, kind)| DirEntry { name, kind })
+            .collect())
+    }
+}
+
+/// Loads one or more theorem documents from a root-relative theorem file
+/// read through `provider`.
+///
+/// This is
+/// [`load_theorem_file_from_manifest_dir`](crate::load_theorem_file_from_manifest_dir)
+/// with file access delegated to a [`FileProvider`], so editors and tests
+/// can load files held in memory: the theorem file and its `Include`
+/// fragments are read from `root` joined with their relative paths, which
+/// obey the same path rules.
+///
+/// # Errors
+///
+/// Returns [`TheoremFileLoadError::InvalidTheoremPath`] if the theorem path
+/// is absolute, drive-prefixed, or attempts to traverse upward,
+/// [`TheoremFileLoadError::ReadTheoremFile`] if `provider` cannot read the
+/// theorem file, [`TheoremFileLoadError::InvalidTheoremFile`] if schema
+/// parsing or validation fails, and
+/// [`TheoremFileLoadError::EmptyTheoremFile`] if the file contains zero
+/// theorem documents.
+///
+/// # Examples
+///
+///     use camino::Utf8Path;
+///     use theoremc_core::file_provider::MemoryFs;
+///     use theoremc_core::load_theorem_file_from_provider;
+///
+///     let mut files = MemoryFs::new();
+///     files.insert(
+///         "project/theorems/true.theorem",
+///         concat!(
+///             "Theorem: AlwaysTrue\n",
+///             "About: trivially true\n",
+///             "Prove:\n",
+///             "  - assert: 'true'\n",
+///             "    because: trivially true\n",
+///             "Evidence:\n",
+///             "  kani: { unwind: 1, expect: SUCCESS }\n",
+///             "Witness:\n",
+///             "  - cover: 'true'\n",
+///             "    because: always reachable\n",
+///         ),
+///     );
+///
+///     let docs = load_theorem_file_from_provider(
+///         &files,
+///         Utf8Path::new("project"),
+///         Utf8Path::new("theorems/true.theorem"),
+///     )
+///     .expect("file should load");
+///     assert_eq!(docs.len(), 1);
+pub fn load_theorem_file_from_provider(
+    provider: &(impl FileProvider + ?Sized),
+    root: &Utf8Path,
+    theorem_path: &Utf8Path,
+) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    load_with_includes(
+        provider,
+        root,
+        theorem_path,
+        &ProviderIncludes { provider, root },
+    )
+}
+
+/// Loads a root-relative theorem file read through `provider`, reading its
+/// `Include` fragments through `includes`.
+pub(crate) fn load_with_includes(
+    provider: &(impl FileProvider + ?Sized),
+    root: &Utf8Path,
+    theorem_path: &Utf8Path,
+    includes: &dyn IncludeResolver,
+) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    let normalized_theorem_path = relative_theorem_path(theorem_path)?;
+    let theorem_source = provider
+        .read_to_string(&root.join(&normalized_theorem_path))
+        .map_err(|source| TheoremFileLoadError::ReadTheoremFile {
+            path: normalized_theorem_path.clone(),
+            source,
+        })?;
+    load_relative_source(&normalized_theorem_path, &theorem_source, includes)
+}
+
+/// Reads `Include` fragments relative to the consumer crate's manifest
+/// directory.
+pub(crate) struct ManifestIncludes<'a> {
+    pub(crate) root: &'a Utf8Dir,
+}
+
+impl IncludeResolver for ManifestIncludes<'_> {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        let normalized_path = relative_include_path(path)?;
+        self.root
+            .read_to_string(&normalized_path)
+            .map_err(|source| io::Error::new(source.kind(), io_error_code(source.kind())))
+    }
+}
+
+/// Reads `Include` fragments through a [`FileProvider`] relative to `root`.
+struct ProviderIncludes<'a, P: ?Sized> {
+    provider: &'a P,
+    root: &'a Utf8Path,
+}
+
+impl<P: FileProvider + ?Sized> IncludeResolver for ProviderIncludes<'_, P> {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        let normalized_path = relative_include_path(path)?;
+        self.provider
+            .read_to_string(&self.root.join(normalized_path))
+            .map_err(|source| io::Error::new(source.kind(), io_error_code(source.kind())))
+    }
+}
+
+/// Normalizes an `Include` path, rejecting paths that could escape the
+/// root.
+fn relative_include_path(path: &str) -> io::Result<Utf8PathBuf> {
+    let normalized_path = Utf8PathBuf::from(normalize_path_separators(path));
+    if is_invalid_theorem_path(&normalized_path) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "absolute, drive-prefixed, and traversal ('..') paths are not allowed",
+        ));
+    }
+    Ok(normalized_path)
+}
+
+/// Returns `path` with `/` separators and without `.` components.
+fn normalized_path(path: &Utf8Path) -> Utf8PathBuf {
+    Utf8PathBuf::from(normalize_path_separators(path.as_str()))
+        .components()
+        .filter(|component| !matches!(component, Utf8Component::CurDir))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "file_provider_tests.rs"]
+mod tests;
@@ -0,0 +1,121 @@
+//! Resolution of `Include` paths against the enclosing crate's manifest
+//! directory.
+//!
+//! `theorem_file!` reads fragments relative to the consumer crate's manifest
+//! directory. Theorem files loaded by path or from a directory tree find that
+//! directory by walking up to the nearest `Cargo.toml`, so a file includes
+//! the same fragments however it is loaded.
+
+use std::io;
+use std::path::Path;
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use super::{EntryKind, FileProvider, ProviderIncludes, RealFs};
+use crate::schema::IncludeResolver;
+use crate::theorem_file::parent_dir;
+
+/// File whose presence marks a crate's manifest directory.
+const MANIFEST_FILE: &str = "Cargo.toml";
+
+/// Reads `Include` fragments relative to the manifest directory of the crate
+/// holding a theorem file, as [`load_theorem_file`](crate::load_theorem_file)
+/// does.
+///
+/// The manifest directory is the nearest directory at or above the theorem
+/// file that contains a `Cargo.toml`. Fragment paths follow the same rules as
+/// manifest-relative loading, so absolute and `..` paths are rejected. When
+/// no such directory exists, every fragment read fails with
+/// [`io::ErrorKind::NotFound`].
+///
+/// # Examples
+///
+///     use theoremc_core::file_provider::CrateIncludes;
+///
+///     let includes = CrateIncludes::for_file("theorems/ledger.theorem");
+///     let _manifest_dir = includes.manifest_dir();
+#[derive(Debug)]
+pub struct CrateIncludes<'a, P: ?Sized = RealFs> {
+    provider: &'a P,
+    manifest_dir: Option<Utf8PathBuf>,
+}
+
+impl CrateIncludes<'static> {
+    /// Resolves fragments for the theorem file at `path` on the local
+    /// filesystem.
+    #[must_use]
+    pub fn for_file(path: impl AsRef<Path>) -> Self {
+        Self::for_dir(parent_dir(path.as_ref()))
+    }
+
+    /// Resolves fragments for theorem files at or below the local directory
+    /// `dir`.
+    pub(crate) fn for_dir(dir: impl AsRef<Path>) -> Self {
+        let manifest_dir = std::path::absolute(dir)
+            .ok()
+            .and_then(|absolute| Utf8PathBuf::from_path_buf(absolute).ok())
+            .and_then(|start| enclosing_manifest_dir(&RealFs, &start));
+        Self {
+            provider: &RealFs,
+            manifest_dir,
+        }
+    }
+}
+
+impl<'a, P: FileProvider + ?Sized> CrateIncludes<'a, P> {
+    /// Resolves fragments for theorem files at or below `dir`, reading
+    /// through `provider`.
+    pub(crate) fn enclosing(provider: &'a P, dir: &Utf8Path) -> Self {
+        Self {
+            provider,
+            manifest_dir: enclosing_manifest_dir(provider, dir),
+        }
+    }
+
+    /// Returns the manifest directory fragments are read from, or `None`
+    /// when no directory above the theorem file holds a `Cargo.toml`.
+    #[must_use]
+    pub fn manifest_dir(&self) -> Option<&Utf8Path> {
+        self.manifest_dir.as_deref()
+    }
+}
+
+impl<P: FileProvider + ?Sized> IncludeResolver for CrateIncludes<'_, P> {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        let Some(root) = self.manifest_dir.as_deref() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no Cargo.toml encloses the theorem file",
+            ));
+        };
+        ProviderIncludes {
+            provider: self.provider,
+            root,
+        }
+        .read_include(path)
+    }
+}
+
+/// Returns the nearest directory at or above `dir` that holds a
+/// `Cargo.toml`.
+fn enclosing_manifest_dir(
+    provider: &(impl FileProvider + ?Sized),
+    dir: &Utf8Path,
+) -> Option<Utf8PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| {
+            if ancestor.as_str().is_empty() {
+                Utf8Path::new(".")
+            } else {
+                ancestor
+            }
+        })
+        .find(|ancestor| {
+            provider.read_dir(ancestor).is_ok_and(|entries| {
+                entries
+                    .iter()
+                    .any(|entry| entry.kind == EntryKind::File && entry.name == MANIFEST_FILE)
+            })
+        })
+        .map(Utf8Path::to_path_buf)
+}
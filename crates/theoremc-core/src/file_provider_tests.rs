@@ -0,0 +1,205 @@
+//! Unit tests for pluggable file providers.
+
+use std::io;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::{DirEntry, EntryKind, FileProvider, MemoryFs, Overlay, RealFs};
+use crate::schema::test_fixtures::theorem;
+use crate::{TheoremFileLoadError, load_theorem_file_from_provider};
+
+fn listing(provider: &impl FileProvider, path: &str) -> Vec<(String, EntryKind)> {
+    let mut entries: Vec<_> = provider
+        .read_dir(Utf8Path::new(path))
+        .expect("directory should be listed")
+        .into_iter()
+        .map(|DirEntry { name, kind }| (name, kind))
+        .collect();
+    entries.sort_by(|left, right| left.0.cmp(&right.0));
+    entries
+}
+
+fn entry(name: &str, kind: EntryKind) -> (String, EntryKind) {
+    (name.to_owned(), kind)
+}
+
+#[rstest]
+#[case::as_stored("project/a.theorem")]
+#[case::current_dir("./project/./a.theorem")]
+#[case::backslashes(r"project\a.theorem")]
+fn memory_paths_are_normalized(#[case] path: &str) {
+    let mut files = MemoryFs::new();
+    files.insert("project/a.theorem", "contents");
+
+    let contents = files
+        .read_to_string(Utf8Path::new(path))
+        .expect("file should be read");
+
+    assert_eq!(contents, "contents");
+}
+
+#[test]
+fn memory_directories_are_implied_by_their_files() {
+    let mut files = MemoryFs::new();
+    files.insert("project/a.theorem", "");
+    files.insert("project/nested/b.theorem", "");
+    files.insert("other/c.theorem", "");
+
+    assert_eq!(
+        listing(&files, "project"),
+        vec![
+            entry("a.theorem", EntryKind::File),
+            entry("nested", EntryKind::Dir),
+        ]
+    );
+    assert_eq!(
+        listing(&files, "."),
+        vec![
+            entry("other", EntryKind::Dir),
+            entry("project", EntryKind::Dir)
+        ]
+    );
+}
+
+#[rstest]
+#[case::missing_file("project/absent.theorem")]
+#[case::directory("project")]
+fn memory_reads_of_absent_files_are_not_found(#[case] path: &str) {
+    let mut files = MemoryFs::new();
+    files.insert("project/a.theorem", "");
+
+    let error = files
+        .read_to_string(Utf8Path::new(path))
+        .expect_err("read should fail");
+
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn memory_listing_of_a_missing_directory_is_not_found() {
+    let files = MemoryFs::new();
+
+    let error = files
+        .read_dir(Utf8Path::new("absent"))
+        .expect_err("listing should fail");
+
+    assert_eq!(error.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn overlay_buffers_shadow_and_extend_the_base() {
+    let mut base = MemoryFs::new();
+    base.insert("project/saved.theorem", "on disk");
+    base.insert("project/other.theorem", "untouched");
+    let mut overlay = Overlay::new(base);
+    overlay.set_buffer("project/saved.theorem", "unsaved edit");
+    overlay.set_buffer("project/new/draft.theorem", "new buffer");
+
+    let read = |path: &str| {
+        overlay
+            .read_to_string(Utf8Path::new(path))
+            .expect("file should be read")
+    };
+
+    assert_eq!(read("project/saved.theorem"), "unsaved edit");
+    assert_eq!(read("project/other.theorem"), "untouched");
+    assert_eq!(read("project/new/draft.theorem"), "new buffer");
+    assert_eq!(
+        listing(&overlay, "project"),
+        vec![
+            entry("new", EntryKind::Dir),
+            entry("other.theorem", EntryKind::File),
+            entry("saved.theorem", EntryKind::File),
+        ]
+    );
+    assert_eq!(
+        listing(&overlay, "project/new"),
+        vec![entry("draft.theorem", EntryKind::File)]
+    );
+}
+
+#[test]
+fn clearing_a_buffer_restores_the_base_contents() {
+    let mut base = MemoryFs::new();
+    base.insert("a.theorem", "on disk");
+    let mut overlay = Overlay::new(base);
+    overlay.set_buffer("a.theorem", "unsaved edit");
+
+    let cleared = overlay.clear_buffer("a.theorem");
+
+    assert_eq!(cleared.as_deref(), Some("unsaved edit"));
+    assert_eq!(
+        overlay
+            .read_to_string(Utf8Path::new("a.theorem"))
+            .expect("file should be read"),
+        "on disk"
+    );
+}
+
+#[test]
+fn real_fs_reads_and_lists_the_local_filesystem() {
+    let temp_dir = TempDir::new().expect("temp directory should be created");
+    let root = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf())
+        .expect("temp directory should be UTF-8");
+    std::fs::create_dir(root.join("nested")).expect("directory should be created");
+    std::fs::write(root.join("a.theorem"), "on disk").expect("file should be written");
+
+    let contents = RealFs
+        .read_to_string(&root.join("a.theorem"))
+        .expect("file should be read");
+
+    assert_eq!(contents, "on disk");
+    assert_eq!(
+        listing(&RealFs, root.as_str()),
+        vec![
+            entry("a.theorem", EntryKind::File),
+            entry("nested", EntryKind::Dir),
+        ]
+    );
+}
+
+#[test]
+fn provider_loading_resolves_includes_through_the_provider() {
+    let mut files = MemoryFs::new();
+    files.insert(
+        "project/theorems/uses_limits.theorem",
+        theorem("UsesLimits", "Include:\n  - theorems/shared/limits.yaml\n"),
+    );
+    files.insert(
+        "project/theorems/shared/limits.yaml",
+        "Forall:\n  amount: u64\n  limit: u64\n",
+    );
+
+    let docs = load_theorem_file_from_provider(
+        &files,
+        Utf8Path::new("project"),
+        Utf8Path::new("theorems/uses_limits.theorem"),
+    )
+    .expect("file should load");
+
+    assert_eq!(
+        docs.first().map(|doc| doc.include.clone()),
+        Some(vec!["theorems/shared/limits.yaml".to_owned()])
+    );
+}
+
+#[test]
+fn provider_loading_reports_unreadable_and_escaping_paths() {
+    let files = MemoryFs::new();
+    let load = |path: &str| {
+        load_theorem_file_from_provider(&files, Utf8Path::new("project"), Utf8Path::new(path))
+    };
+
+    assert!(matches!(
+        load("absent.theorem"),
+        Err(TheoremFileLoadError::ReadTheoremFile { source, .. })
+            if source.kind() == io::ErrorKind::NotFound
+    ));
+    assert!(matches!(
+        load("../outside.theorem"),
+        Err(TheoremFileLoadError::InvalidTheoremPath { .. })
+    ));
+}
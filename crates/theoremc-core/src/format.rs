@@ -0,0 +1,375 @@
+//! Layout-preserving formatting of `.theorem` sources.
+//!
+//! [`format_source`] rewrites a source into canonical form without
+//! re-serializing it, so comments and the grouping of sections into
+//! blank-line separated blocks survive. In each theorem document, lowercase
+//! section aliases become their `TitleCase` keys and sections are ordered as
+//! in the specification, each carrying the comments above it. Nested blocks
+//! are indented by two spaces per level, with block scalars moved as a
+//! whole, runs of blank lines between sections collapse to one, and trailing
+//! whitespace is removed. The formatted text is parsed again and compared
+//! with the input, so formatting never changes what a source means.
+//!
+//! [`SourceTree`] is the concrete syntax the formatter works on, and can be
+//! used directly by tools that rewrite sections, such as quick fixes.
+//!
+//! # Examples
+//!
+//!     use theoremc_core::format::format_source;
+//!     use theoremc_core::schema::SourceId;
+//!
+//!     let input = r#"theorem: Trivial
+//!     evidence:
+//!         kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!
+//!
+//!     ## Why it holds
+//!     prove:
+//!         - assert: "true"
+//!           because: trivially true
+//!     about: Always holds
+//!     "#;
+//!     let formatted = format_source(&SourceId::new("trivial.theorem"), input).unwrap();
+//!     assert_eq!(
+//!         formatted,
+//!         r#"Theorem: Trivial
+//!     About: Always holds
+//!
+//!     ## Why it holds
+//!     Prove:
+//!       - assert: "true"
+//!         because: trivially true
+//!     Evidence:
+//!       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!     "#
+//!     );
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::schema::{
+    SchemaError, SourceId, canonical_for_alias, deserialize_failure, parse_options,
+};
+
+#[path = "format_tree.rs"]
+mod tree;
+
+pub(crate) use tree::section_rank;
+pub use tree::{Section, SourceDocument, SourceTree};
+
+/// Errors produced while formatting a theorem source.
+#[derive(Debug, Error)]
+pub enum FormatError {
+    /// The source does not parse as YAML documents.
+    #[error(transparent)]
+    Parse(#[from] SchemaError),
+    /// Formatting would change the value of a document, so the source is
+    /// left unchanged.
+    #[error("cannot format document {document} without changing its meaning")]
+    Changed {
+        /// 1-indexed position of the document in the source.
+        document: usize,
+    },
+}
+
+/// Rewrites `input` into canonical form, keeping its comments and section
+/// grouping.
+///
+/// # Errors
+///
+/// Returns [`FormatError::Parse`] when `input` is not valid YAML for a
+/// theorem source, and [`FormatError::Changed`] when the formatted text
+/// would not parse to the same documents.
+pub fn format_source(source: &SourceId, input: &str) -> Result<String, FormatError> {
+    let before = document_values(source, input)?;
+    let mut tree = SourceTree::parse(input);
+    tree.documents_mut()
+        .iter_mut()
+        .for_each(SourceDocument::canonicalize_sections);
+    let formatted = render(&tree);
+    let after = document_values(source, &formatted).map_err(|error| FormatError::Changed {
+        document: error.diagnostic().map_or(1, |diagnostic| {
+            tree::document_at(&formatted, diagnostic.location.line)
+        }),
+    })?;
+    let expected: Vec<Value> = before.into_iter().map(canonical_keys).collect();
+    (0..expected.len().max(after.len()))
+        .find(|&index| expected.get(index) != after.get(index))
+        .map_or(Ok(formatted), |index| {
+            Err(FormatError::Changed {
+                document: index + 1,
+            })
+        })
+}
+
+/// Parses every document of `input` as a plain value.
+fn document_values(source: &SourceId, input: &str) -> Result<Vec<Value>, SchemaError> {
+    serde_saphyr::from_multiple_with_options(input, parse_options())
+        .map_err(|error| deserialize_failure(source, input, &error))
+}
+
+/// Renames the lowercase section aliases of a theorem document's value.
+fn canonical_keys(value: Value) -> Value {
+    match value {
+        Value::Object(entries)
+            if entries.contains_key("theorem") || entries.contains_key("Theorem") =>
+        {
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, entry)| {
+                        let canonical = canonical_for_alias(&key).map_or(key, str::to_owned);
+                        (canonical, entry)
+                    })
+                    .collect(),
+            )
+        }
+        other => other,
+    }
+}
+
+/// Writes the tree in canonical layout.
+fn render(tree: &SourceTree) -> String {
+    let mut lines = Lines::default();
+    for document in tree.documents() {
+        if let Some(marker) = document.marker() {
+            lines.push(marker.trim_end());
+            lines.at_document_start = true;
+        }
+        if document.sections().is_empty() {
+            document
+                .trailing()
+                .iter()
+                .for_each(|line| lines.push(line.trim_end()));
+            continue;
+        }
+        for section in document.sections() {
+            lines.trivia(section.leading());
+            lines.push(section.line().trim_end());
+            lines.body(section.body(), opens_block_scalar(section.line()));
+        }
+        lines.trivia(document.trailing());
+    }
+    lines.finish()
+}
+
+/// Output lines with blank lines between sections collapsed.
+#[derive(Default)]
+struct Lines {
+    text: Vec<String>,
+    /// Whether nothing but a `---` marker has been written for the current
+    /// document, so blank lines are dropped.
+    at_document_start: bool,
+}
+
+impl Lines {
+    fn push(&mut self, line: &str) {
+        self.text.push(line.to_owned());
+        self.at_document_start = false;
+    }
+
+    /// Writes comment and blank lines outside section bodies: comments move
+    /// to the start of the line and runs of blank lines become one.
+    fn trivia(&mut self, lines: &[String]) {
+        for line in lines {
+            let text = line.trim();
+            if !text.is_empty() {
+                self.push(text);
+            } else if !self.at_document_start
+                && self.text.last().is_some_and(|last| !last.is_empty())
+            {
+                self.text.push(String::new());
+            }
+        }
+    }
+
+    fn body(&mut self, body: &[String], in_block_scalar: bool) {
+        let layout = reindent(body, in_block_scalar);
+        self.text.extend(layout);
+        self.at_document_start = false;
+    }
+
+    fn finish(mut self) -> String {
+        while self.text.last().is_some_and(String::is_empty) {
+            self.text.pop();
+        }
+        self.text.into_iter().fold(String::new(), |mut text, line| {
+            text.push_str(&line);
+            text.push('\n');
+            text
+        })
+    }
+}
+
+/// The new indentation of a body line.
+enum Placement {
+    Blank,
+    /// A comment, with its indentation when no line follows it.
+    Comment(usize, String),
+    Indented(usize, String),
+    Verbatim(String),
+}
+
+/// Re-indents a section body by two spaces per nesting level.
+///
+/// Nesting levels are recovered from the original indentation, block scalar
+/// content keeps its indentation relative to the line that opens it, and
+/// comments take the indentation of the line below them.
+fn reindent(body: &[String], in_block_scalar: bool) -> Vec<String> {
+    let mut levels = Levels {
+        stack: vec![(0, 0)],
+        block: in_block_scalar.then_some((0, 0)),
+    };
+    let placements: Vec<Placement> = body.iter().map(|line| levels.place(line)).collect();
+    let mut next_indent = None;
+    let mut lines: Vec<String> = placements
+        .into_iter()
+        .rev()
+        .map(|placement| match placement {
+            Placement::Blank => String::new(),
+            Placement::Comment(fallback, text) => {
+                format!("{}{text}", " ".repeat(next_indent.unwrap_or(fallback)))
+            }
+            Placement::Indented(indent, text) => {
+                next_indent = Some(indent);
+                format!("{}{text}", " ".repeat(indent))
+            }
+            Placement::Verbatim(text) => text,
+        })
+        .collect();
+    lines.reverse();
+    lines
+}
+
+/// The nesting levels open at a body line, as `(original, new)`
+/// indentation pairs.
+struct Levels {
+    stack: Vec<(usize, usize)>,
+    /// The original indentation of the line opening the current block
+    /// scalar, and the shift applied to its content.
+    block: Option<(usize, isize)>,
+}
+
+impl Levels {
+    fn place(&mut self, line: &str) -> Placement {
+        let indent = indentation(line);
+        if let Some((parent, shift)) = self.block {
+            if line.trim().is_empty() || indent > parent {
+                return Placement::Verbatim(shifted(line, shift));
+            }
+            self.block = None;
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return Placement::Blank;
+        }
+        if trimmed.starts_with('#') {
+            return Placement::Comment(self.enclosing(indent), trimmed.to_owned());
+        }
+        let new = self.level(indent);
+        let text = self.items(trimmed, indent, new);
+        if opens_block_scalar(&text) {
+            self.block = Some((indent, signed(new) - signed(indent)));
+        }
+        Placement::Indented(new, text)
+    }
+
+    /// Returns the new indentation of a line indented by `indent`, opening
+    /// a level when it is deeper than the enclosing one.
+    fn level(&mut self, indent: usize) -> usize {
+        while self.stack.last().is_some_and(|&(old, _)| old > indent) {
+            self.stack.pop();
+        }
+        match self.stack.last() {
+            Some(&(old, new)) if old == indent => new,
+            enclosing => {
+                let new = enclosing.map_or(0, |&(_, new)| new + 2);
+                self.stack.push((indent, new));
+                new
+            }
+        }
+    }
+
+    /// Returns the new indentation of the deepest open level a line
+    /// indented by `indent` belongs to, without closing any level.
+    fn enclosing(&self, indent: usize) -> usize {
+        self.stack
+            .iter()
+            .rev()
+            .find(|&&(old, _)| old <= indent)
+            .map_or(0, |&(_, new)| new)
+    }
+
+    /// Writes each leading `- ` of a sequence item with one space, opening a
+    /// level for the content after it.
+    fn items(&mut self, text: &str, indent: usize, new: usize) -> String {
+        let mut prefix = String::new();
+        let (mut rest, mut old, mut current) = (text, indent, new);
+        while let Some(after) = rest
+            .strip_prefix('-')
+            .filter(|after| after.is_empty() || after.starts_with(' '))
+        {
+            let content = after.trim_start();
+            old += 1 + after.len() - content.len();
+            current += 2;
+            prefix.push_str(if content.is_empty() { "-" } else { "- " });
+            if !content.is_empty() && !content.starts_with('#') {
+                self.stack.push((old, current));
+            }
+            rest = content;
+        }
+        prefix + rest
+    }
+}
+
+/// Returns whether `text` ends by opening a literal or folded block scalar.
+fn opens_block_scalar(text: &str) -> bool {
+    let content = text.split(" #").next().unwrap_or(text).trim_end();
+    let (before, header) = content.rsplit_once(' ').unwrap_or(("", content));
+    let node = before.trim_end();
+    is_block_header(header)
+        && (node.is_empty()
+            || node.ends_with([':', '-'])
+            || node
+                .split(' ')
+                .next_back()
+                .is_some_and(|token| token.starts_with('!')))
+}
+
+/// Returns whether `token` is a `|` or `>` block scalar header.
+pub(crate) fn is_block_header(token: &str) -> bool {
+    token.strip_prefix(['|', '>']).is_some_and(|indicators| {
+        indicators.len() <= 2
+            && indicators
+                .chars()
+                .all(|indicator| matches!(indicator, '+' | '-' | '1'..='9'))
+    })
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Moves `line` right by `shift` spaces, or left by removing up to that many
+/// spaces.
+fn shifted(line: &str, shift: isize) -> String {
+    if line.trim().is_empty() {
+        return String::new();
+    }
+    let amount = shift.unsigned_abs();
+    if shift >= 0 {
+        format!("{}{line}", " ".repeat(amount))
+    } else {
+        line.get(amount.min(indentation(line))..)
+            .unwrap_or(line)
+            .to_owned()
+    }
+}
+
+fn signed(value: usize) -> isize {
+    isize::try_from(value).unwrap_or(isize::MAX)
+}
+
+#[cfg(test)]
+#[path = "format_tests.rs"]
+mod tests;
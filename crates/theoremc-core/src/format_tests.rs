@@ -0,0 +1,177 @@
+//! Unit tests for layout-preserving formatting.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::load_theorem_docs;
+
+const EVIDENCE: &str = concat!(
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn format(input: &str) -> String {
+    format_source(&SourceId::new("t.theorem"), input).expect("source should format")
+}
+
+fn theorem(body: &str) -> String {
+    format!("Theorem: T\nAbout: Example\n{body}{EVIDENCE}")
+}
+
+#[test]
+fn sections_are_canonical_and_in_specification_order() {
+    let input = format!(
+        "{EVIDENCE}\n# The claim\nprove:\n  - assert: \"true\"\n    because: trivial\n\
+         about: Example # why\ntheorem: T\n"
+    );
+
+    assert_eq!(
+        format(&input),
+        format!(
+            "Theorem: T\nAbout: Example # why\n\n# The claim\nProve:\n  - assert: \"true\"\n    \
+             because: trivial\n{EVIDENCE}"
+        )
+    );
+}
+
+#[test]
+fn blank_line_runs_collapse_between_sections() {
+    let input = format!(
+        "\n\nTheorem: T\n\n\n\nAbout: Example\nProve:\n  - assert: \"true\"\n    because: trivial\n\n\n{EVIDENCE}\n\n"
+    );
+
+    assert_eq!(
+        format(&input),
+        format!(
+            "Theorem: T\n\nAbout: Example\nProve:\n  - assert: \"true\"\n    because: trivial\n\n{EVIDENCE}"
+        )
+    );
+}
+
+#[rstest]
+#[case::four_spaces(
+    "Prove:\n    - assert: \"true\"\n      because: trivial\n",
+    "Prove:\n  - assert: \"true\"\n    because: trivial\n"
+)]
+#[case::wide_item_marker(
+    "Prove:\n  -   assert: \"true\"\n      because: trivial\n",
+    "Prove:\n  - assert: \"true\"\n    because: trivial\n"
+)]
+#[case::indentless_sequence(
+    "Prove:\n- assert: \"true\"\n  because: trivial\n",
+    "Prove:\n- assert: \"true\"\n  because: trivial\n"
+)]
+#[case::nested_mapping(
+    "Forall:\n      amount: u64\nProve:\n   - assert: amount >= 0\n     because: unsigned\n",
+    "Forall:\n  amount: u64\nProve:\n  - assert: amount >= 0\n    because: unsigned\n"
+)]
+#[case::trailing_whitespace(
+    "Prove:   \n  - assert: \"true\"  \n    because: trivial\t\n",
+    "Prove:\n  - assert: \"true\"\n    because: trivial\n"
+)]
+fn nested_blocks_use_two_space_indentation(#[case] body: &str, #[case] expected: &str) {
+    assert_eq!(format(&theorem(body)), theorem(expected));
+}
+
+#[test]
+fn block_scalars_move_as_a_whole() {
+    let body = concat!(
+        "Prove:\n",
+        "    - assert: \"true\"\n",
+        "      because: |\n",
+        "        first line\n",
+        "\n",
+        "          indented line\n",
+        "    # closing remark\n",
+    );
+    let expected = concat!(
+        "Prove:\n",
+        "  - assert: \"true\"\n",
+        "    because: |\n",
+        "      first line\n",
+        "\n",
+        "        indented line\n",
+        "  # closing remark\n",
+    );
+
+    assert_eq!(format(&theorem(body)), theorem(expected));
+}
+
+#[test]
+fn comments_take_the_indentation_of_the_line_below() {
+    let body = "Forall:\n# the amount\n    amount: u64\n        # the fee\n    fee: u64\n";
+    let expected = "Forall:\n  # the amount\n  amount: u64\n  # the fee\n  fee: u64\n";
+
+    assert_eq!(format(&theorem(body)), theorem(expected));
+}
+
+#[test]
+fn other_documents_keep_their_section_order() {
+    let defaults = "---\nDefaults:\n    tags: [ledger]\n";
+    let suite = "---\nAbout: Ledger\nSuite: ledger\nTheorems: [T]\n";
+    let input = format!(
+        "{defaults}{suite}---\n{}",
+        theorem("Prove: [{ assert: \"true\", because: trivial }]\n")
+    );
+
+    let formatted = format(&input);
+
+    assert!(
+        formatted
+            .starts_with("---\nDefaults:\n  tags: [ledger]\n---\nAbout: Ledger\nSuite: ledger\n"),
+        "{formatted}"
+    );
+}
+
+#[test]
+fn flow_documents_are_left_alone() {
+    let input = "{ Theorem: T, About: Example,\n  Prove: [{ assert: \"true\", because: trivial }],\n  Evidence: { kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none } } }\n";
+
+    assert_eq!(format(input), input);
+}
+
+#[test]
+fn formatting_is_idempotent_and_keeps_meaning() {
+    let input = concat!(
+        "# header\n",
+        "evidence:\n",
+        "    kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+        "prove:\n",
+        "    - assert: \"true\"\n",
+        "      because: >-\n",
+        "        folded\n",
+        "        text\n",
+        "\n",
+        "\n",
+        "theorem: T\n",
+        "about: Example\n",
+    );
+    let once = format(input);
+
+    assert_eq!(format(&once), once);
+    assert_eq!(
+        load_theorem_docs(&once).expect("formatted source should load"),
+        load_theorem_docs(input).expect("source should load")
+    );
+}
+
+#[test]
+fn malformed_sources_are_rejected() {
+    let error = format_source(&SourceId::new("t.theorem"), "Theorem: [unclosed\n")
+        .expect_err("source is malformed");
+
+    assert!(matches!(error, FormatError::Parse(_)), "{error}");
+}
+
+#[rstest]
+#[case::literal("because: |", true)]
+#[case::folded_with_indicators("because: >-2", true)]
+#[case::item("- |", true)]
+#[case::tagged("because: !text |", true)]
+#[case::commented("because: | # note", true)]
+#[case::pipe_in_text("assert: a | b", false)]
+#[case::plain("because: trivial", false)]
+fn block_scalar_headers_are_recognized(#[case] text: &str, #[case] expected: bool) {
+    assert_eq!(opens_block_scalar(text), expected);
+}
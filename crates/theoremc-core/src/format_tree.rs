@@ -0,0 +1,351 @@
+//! Concrete syntax of `.theorem` sources at the level of top-level sections.
+//!
+//! [`SourceTree`] splits a source into its YAML documents and each document
+//! into the top-level sections it declares. Every line is kept, so comments,
+//! blank lines, and the exact text of each section survive and the tree can
+//! be written back unchanged with [`SourceTree::to_source`]. Comments and
+//! blank lines above a section belong to that section and move with it.
+
+use std::ops::Range;
+
+use crate::schema::{SECTION_KEYS, canonical_for_alias};
+
+/// A `.theorem` source split into documents and top-level sections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceTree {
+    documents: Vec<SourceDocument>,
+}
+
+/// One YAML document of a [`SourceTree`].
+///
+/// A document that is not a block mapping of sections, such as a flow
+/// mapping or a document using complex keys, has no sections and keeps all
+/// of its lines in [`trailing`](Self::trailing).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceDocument {
+    marker: Option<String>,
+    sections: Vec<Section>,
+    trailing: Vec<String>,
+}
+
+/// A top-level section: its key line, the indented lines below it, and the
+/// comments and blank lines above it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    leading: Vec<String>,
+    line: String,
+    key: Range<usize>,
+    body: Vec<String>,
+}
+
+impl SourceTree {
+    /// Splits `input` into documents and sections.
+    ///
+    /// Parsing never fails: text the tree does not understand is kept as the
+    /// lines of a document without sections.
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        let mut documents = Vec::new();
+        let mut current = DocumentBuilder::default();
+        for line in input.lines() {
+            if is_document_marker(line) {
+                documents.extend(current.finish());
+                current = DocumentBuilder::starting_at(line);
+            } else {
+                current.push(line);
+            }
+        }
+        documents.extend(current.finish());
+        Self { documents }
+    }
+
+    /// Returns the documents in source order.
+    #[must_use]
+    pub fn documents(&self) -> &[SourceDocument] {
+        &self.documents
+    }
+
+    /// Returns the documents for editing.
+    #[must_use]
+    pub fn documents_mut(&mut self) -> &mut [SourceDocument] {
+        &mut self.documents
+    }
+
+    /// Writes the tree back as source text with `\n` line endings.
+    #[must_use]
+    pub fn to_source(&self) -> String {
+        self.documents.iter().flat_map(SourceDocument::lines).fold(
+            String::new(),
+            |mut text, line| {
+                text.push_str(line);
+                text.push('\n');
+                text
+            },
+        )
+    }
+}
+
+impl SourceDocument {
+    /// Returns the `---` line that starts the document, if it has one.
+    #[must_use]
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+
+    /// Returns the top-level sections in source order.
+    #[must_use]
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Returns the sections for editing or reordering.
+    #[must_use]
+    pub const fn sections_mut(&mut self) -> &mut Vec<Section> {
+        &mut self.sections
+    }
+
+    /// Returns the lines after the last section, or every line of a
+    /// document without sections.
+    #[must_use]
+    pub fn trailing(&self) -> &[String] {
+        &self.trailing
+    }
+
+    /// Returns whether the document declares a theorem, under either
+    /// spelling of the `Theorem` key.
+    #[must_use]
+    pub fn is_theorem(&self) -> bool {
+        self.sections
+            .iter()
+            .any(|section| matches!(section.key(), "Theorem" | "theorem"))
+    }
+
+    /// Spells the sections of a theorem document canonically and puts them
+    /// in specification order.
+    pub(super) fn canonicalize_sections(&mut self) {
+        if !self.is_theorem() {
+            return;
+        }
+        for section in &mut self.sections {
+            if let Some(canonical) = canonical_for_alias(section.key()) {
+                section.set_key(canonical);
+            }
+        }
+        self.sections
+            .sort_by_key(|section| section_rank(section.key()));
+    }
+
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.marker
+            .as_deref()
+            .into_iter()
+            .chain(self.sections.iter().flat_map(Section::lines))
+            .chain(self.trailing.iter().map(String::as_str))
+    }
+}
+
+impl Section {
+    /// Returns the section key, without quotes.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        let key = self.line.get(self.key.clone()).unwrap_or_default();
+        key.strip_prefix(['"', '\''])
+            .and_then(|quoted| quoted.strip_suffix(['"', '\'']))
+            .unwrap_or(key)
+    }
+
+    /// Replaces the section key, leaving the rest of its line unchanged.
+    pub fn set_key(&mut self, key: &str) {
+        self.line.replace_range(self.key.clone(), key);
+        self.key = 0..key.len();
+    }
+
+    /// Returns the comment and blank lines above the section.
+    #[must_use]
+    pub fn leading(&self) -> &[String] {
+        &self.leading
+    }
+
+    /// Returns the line holding the section key.
+    #[must_use]
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Returns the lines below the key line that belong to the section.
+    #[must_use]
+    pub fn body(&self) -> &[String] {
+        &self.body
+    }
+
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.leading
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(self.line.as_str()))
+            .chain(self.body.iter().map(String::as_str))
+    }
+}
+
+/// Collects the lines of one document.
+#[derive(Default)]
+struct DocumentBuilder {
+    document: SourceDocument,
+    /// Comment and blank lines not yet known to belong to a section body or
+    /// to the next section.
+    pending: Vec<String>,
+    /// Whether the document is not a block mapping of sections.
+    opaque: bool,
+}
+
+impl DocumentBuilder {
+    fn starting_at(marker: &str) -> Self {
+        Self {
+            document: SourceDocument {
+                marker: Some(marker.to_owned()),
+                ..SourceDocument::default()
+            },
+            ..Self::default()
+        }
+    }
+
+    fn push(&mut self, line: &str) {
+        if self.opaque {
+            self.document.trailing.push(line.to_owned());
+        } else if is_trivia(line) {
+            self.pending.push(line.to_owned());
+        } else if let Some(key) = section_key(line) {
+            self.document.sections.push(Section {
+                leading: std::mem::take(&mut self.pending),
+                line: line.to_owned(),
+                key,
+                body: Vec::new(),
+            });
+        } else if let Some(section) = self
+            .document
+            .sections
+            .last_mut()
+            .filter(|_| continues_section(line))
+        {
+            section.body.append(&mut self.pending);
+            section.body.push(line.to_owned());
+        } else {
+            self.make_opaque(line);
+        }
+    }
+
+    /// Turns every line collected so far, and `line`, into lines of a
+    /// document without sections.
+    fn make_opaque(&mut self, line: &str) {
+        let lines: Vec<String> = self
+            .document
+            .sections
+            .drain(..)
+            .flat_map(|section| {
+                section
+                    .leading
+                    .into_iter()
+                    .chain(std::iter::once(section.line))
+                    .chain(section.body)
+            })
+            .chain(self.pending.drain(..))
+            .chain(std::iter::once(line.to_owned()))
+            .collect();
+        self.document.trailing = lines;
+        self.opaque = true;
+    }
+
+    /// Returns the document, or nothing for the empty text before a first
+    /// `---` marker.
+    fn finish(mut self) -> Option<SourceDocument> {
+        self.document.trailing.append(&mut self.pending);
+        let empty = self.document.marker.is_none()
+            && self.document.sections.is_empty()
+            && self.document.trailing.is_empty();
+        (!empty).then_some(self.document)
+    }
+}
+
+/// Returns the 1-indexed document of `text` that holds 1-indexed `line`.
+pub(super) fn document_at(text: &str, line: usize) -> usize {
+    let mut lines = text.lines().take(line);
+    let implicit_first = lines
+        .clone()
+        .find(|candidate| !candidate.trim().is_empty() && !candidate.starts_with('#'))
+        .is_some_and(|first| !is_document_marker(first));
+    let markers = lines
+        .by_ref()
+        .filter(|candidate| is_document_marker(candidate))
+        .count();
+    (markers + usize::from(implicit_first)).max(1)
+}
+
+/// Returns the position of `key` in the specification's section order, with
+/// unknown keys last.
+pub(crate) fn section_rank(key: &str) -> usize {
+    SECTION_KEYS
+        .iter()
+        .position(|&(canonical, _)| canonical == key)
+        .unwrap_or(SECTION_KEYS.len())
+}
+
+/// Returns whether `line` is a `---` marker starting a document.
+pub(super) fn is_document_marker(line: &str) -> bool {
+    line.strip_prefix("---")
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+/// Returns whether `line` is blank, a comment written at the start of the
+/// line, or a `...` document end marker.
+fn is_trivia(line: &str) -> bool {
+    line.trim().is_empty() || line.starts_with('#') || line.trim_end() == "..."
+}
+
+/// Returns whether `line` continues the section above it: an indented line,
+/// an indented comment, or an item of a sequence written without
+/// indentation.
+fn continues_section(line: &str) -> bool {
+    line.starts_with([' ', '\t']) || line == "-" || line.starts_with("- ")
+}
+
+/// Returns the byte range of the key when `line` starts a top-level mapping
+/// entry, including the quotes of a quoted key.
+fn section_key(line: &str) -> Option<Range<usize>> {
+    let end = match line.chars().next()? {
+        quote @ ('"' | '\'') => line.get(1..)?.find(quote)? + 2,
+        first if is_indicator(first) || first.is_whitespace() => return None,
+        _ => line
+            .find(": ")
+            .or_else(|| line.strip_suffix(':').map(str::len))?,
+    };
+    let after = line.get(end..)?.trim_start_matches(' ');
+    let key_end = line.get(..end)?.trim_end().len();
+    (after == ":" || after.starts_with(": ")).then_some(0..key_end)
+}
+
+/// Returns whether `character` cannot start a plain YAML scalar key.
+const fn is_indicator(character: char) -> bool {
+    matches!(
+        character,
+        '-' | '?'
+            | ':'
+            | ','
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '#'
+            | '&'
+            | '*'
+            | '!'
+            | '|'
+            | '>'
+            | '%'
+            | '@'
+            | '`'
+    )
+}
+
+#[cfg(test)]
+#[path = "format_tree_tests.rs"]
+mod tests;
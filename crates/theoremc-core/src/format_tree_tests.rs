@@ -0,0 +1,121 @@
+//! Unit tests for the section-level concrete syntax tree.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+
+const SOURCE: &str = concat!(
+    "# Ledger theorems\n",
+    "Defaults:\n",
+    "  tags: [ledger]\n",
+    "---\n",
+    "theorem: Deposit # inline\n",
+    "\n",
+    "# Inputs\n",
+    "Forall:\n",
+    "  amount: u64\n",
+    "\n",
+    "  # still the body\n",
+    "  other: u8\n",
+    "Tags:\n",
+    "- smoke\n",
+    "\"About\": Deposits add up\n",
+    "# trailing\n",
+);
+
+fn keys(document: &SourceDocument) -> Vec<&str> {
+    document.sections().iter().map(Section::key).collect()
+}
+
+#[test]
+fn writes_the_source_back_unchanged() {
+    assert_eq!(SourceTree::parse(SOURCE).to_source(), SOURCE);
+}
+
+#[test]
+fn splits_documents_and_sections() {
+    let tree = SourceTree::parse(SOURCE);
+    let [defaults, theorem] = tree.documents() else {
+        panic!("two documents expected");
+    };
+
+    assert_eq!(keys(defaults), ["Defaults"]);
+    assert_eq!(keys(theorem), ["theorem", "Forall", "Tags", "About"]);
+    assert_eq!(theorem.marker(), Some("---"));
+    assert!(theorem.is_theorem());
+    assert!(!defaults.is_theorem());
+    assert_eq!(theorem.trailing(), ["# trailing"]);
+}
+
+#[test]
+fn comments_above_a_section_lead_it() {
+    let tree = SourceTree::parse(SOURCE);
+    let forall = tree
+        .documents()
+        .iter()
+        .flat_map(SourceDocument::sections)
+        .find(|section| section.key() == "Forall")
+        .expect("Forall section");
+
+    assert_eq!(forall.leading(), ["", "# Inputs"]);
+    assert_eq!(
+        forall.body(),
+        ["  amount: u64", "", "  # still the body", "  other: u8"]
+    );
+}
+
+#[test]
+fn keys_are_replaced_in_place() {
+    let mut tree = SourceTree::parse("\"about\" : Example # note\n");
+    let section = tree
+        .documents_mut()
+        .first_mut()
+        .and_then(|document| document.sections_mut().first_mut())
+        .expect("one section");
+    section.set_key("About");
+
+    assert_eq!(section.key(), "About");
+    assert_eq!(tree.to_source(), "About : Example # note\n");
+}
+
+#[rstest]
+#[case::flow_mapping("{ Theorem: T,\n  About: x }\n")]
+#[case::complex_key("? Theorem\n: T\n")]
+#[case::scalar("just text\n")]
+fn other_documents_keep_their_lines(#[case] input: &str) {
+    let tree = SourceTree::parse(input);
+    let [document] = tree.documents() else {
+        panic!("one document expected");
+    };
+
+    assert!(document.sections().is_empty());
+    assert_eq!(tree.to_source(), input);
+}
+
+#[rstest]
+#[case::plain("Theorem: T", Some(0..7))]
+#[case::no_value("Prove:", Some(0..5))]
+#[case::spaced("Theorem : T", Some(0..7))]
+#[case::double_quoted("\"Theorem\": T", Some(0..9))]
+#[case::single_quoted("'Theorem': T", Some(0..9))]
+#[case::no_colon("Theorem", None)]
+#[case::url_like("http://example", None)]
+#[case::sequence("- item", None)]
+#[case::indented("  key: value", None)]
+fn section_keys_are_recognized(#[case] line: &str, #[case] expected: Option<Range<usize>>) {
+    assert_eq!(section_key(line), expected);
+}
+
+#[rstest]
+#[case::implicit_first("a: 1\n---\nb: 2\n", 1, 1)]
+#[case::after_marker("a: 1\n---\nb: 2\n", 3, 2)]
+#[case::explicit_first("---\na: 1\n---\nb: 2\n", 2, 1)]
+#[case::leading_comment("# c\n---\na: 1\n", 3, 1)]
+fn errors_name_the_document_holding_their_line(
+    #[case] text: &str,
+    #[case] line: usize,
+    #[case] expected: usize,
+) {
+    assert_eq!(document_at(text, line), expected);
+}
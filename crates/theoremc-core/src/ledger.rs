@@ -0,0 +1,194 @@
+//! Append-only evidence ledger of verification runs.
+//!
+//! Each run of a theorem harness is recorded as one JSON object per line of
+//! a ledger file: the theorem, a fingerprint of the theorem source it ran
+//! against, the backend, the outcome, how long it took, and the toolchain
+//! versions involved. Lines are only ever appended, so the file is an
+//! auditable history of what was proven, when, and against which version of
+//! the specification.
+
+use std::io::Write;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use cap_std::{ambient_authority, fs::OpenOptions, fs_utf8::Dir as Utf8Dir};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::theorem_file::io_error_code;
+
+/// One recorded verification run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LedgerEntry {
+    /// Theorem key of the verified theorem, `{path}#{Theorem}`.
+    pub theorem: String,
+    /// [`content_fingerprint`] of the theorem file the run verified.
+    pub fingerprint: String,
+    /// Backend that ran the proof, such as `kani`.
+    pub backend: String,
+    /// Reported outcome, such as `SUCCESS`.
+    pub outcome: String,
+    /// Wall-clock duration of the run in milliseconds.
+    pub duration_ms: u64,
+    /// When the run finished, in seconds since the Unix epoch.
+    pub recorded_at: u64,
+    /// Tool versions keyed by tool name, such as `rustc` or `kani`.
+    #[serde(default)]
+    pub toolchain: IndexMap<String, String>,
+}
+
+/// Errors raised while reading or appending to a ledger file.
+#[derive(Debug, thiserror::Error)]
+pub enum LedgerError {
+    /// The directory holding the ledger file could not be opened.
+    #[error(
+        "failed to open ledger directory '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    OpenDir {
+        /// Directory that failed to open.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The ledger file could not be read or appended to.
+    #[error(
+        "failed to access ledger '{path}': {code}",
+        code = io_error_code(source.kind())
+    )]
+    Access {
+        /// Ledger file path.
+        path: Utf8PathBuf,
+        /// Underlying IO failure.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A ledger line is not a valid entry.
+    #[error("ledger '{path}' line {line}: {source}")]
+    InvalidEntry {
+        /// Ledger file path.
+        path: Utf8PathBuf,
+        /// One-based line number of the entry.
+        line: usize,
+        /// Underlying decoding failure.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// An entry could not be encoded as JSON.
+    #[error("failed to encode ledger entry: {source}")]
+    Encode {
+        /// Underlying encoding failure.
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// A JSON Lines ledger file.
+#[derive(Debug)]
+pub struct Ledger {
+    dir: Utf8Dir,
+    file: Utf8PathBuf,
+    path: Utf8PathBuf,
+}
+
+impl Ledger {
+    /// Opens the ledger at `path`. The file itself is created by the first
+    /// [`append`](Self::append), so a ledger with no runs reads as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LedgerError::OpenDir`] when the directory holding `path`
+    /// cannot be opened.
+    pub fn open(path: &Utf8Path) -> Result<Self, LedgerError> {
+        let parent = path
+            .parent()
+            .filter(|parent| !parent.as_str().is_empty())
+            .unwrap_or_else(|| Utf8Path::new("."));
+        let dir = Utf8Dir::open_ambient_dir(parent, ambient_authority()).map_err(|source| {
+            LedgerError::OpenDir {
+                path: parent.to_owned(),
+                source,
+            }
+        })?;
+        Ok(Self {
+            dir,
+            file: Utf8PathBuf::from(path.file_name().unwrap_or_default()),
+            path: path.to_owned(),
+        })
+    }
+
+    /// Appends `entry` as one line at the end of the ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LedgerError::Access`] when the ledger cannot be written.
+    pub fn append(&self, entry: &LedgerEntry) -> Result<(), LedgerError> {
+        let mut line =
+            serde_json::to_string(entry).map_err(|source| LedgerError::Encode { source })?;
+        line.push('\n');
+        let mut options = OpenOptions::new();
+        options.append(true).create(true);
+        self.dir
+            .open_with(&self.file, &options)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|source| self.access_error(source))
+    }
+
+    /// Reads every entry, oldest first. Blank lines are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LedgerError::Access`] when the ledger exists but cannot be
+    /// read, and [`LedgerError::InvalidEntry`] for a line that is not an
+    /// entry.
+    pub fn entries(&self) -> Result<Vec<LedgerEntry>, LedgerError> {
+        if !self.dir.exists(&self.file) {
+            return Ok(Vec::new());
+        }
+        let text = self
+            .dir
+            .read_to_string(&self.file)
+            .map_err(|source| self.access_error(source))?;
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|source| LedgerError::InvalidEntry {
+                    path: self.path.clone(),
+                    line: index + 1,
+                    source,
+                })
+            })
+            .collect()
+    }
+
+    fn access_error(&self, source: std::io::Error) -> LedgerError {
+        LedgerError::Access {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
+
+/// Returns the fingerprint of a theorem file's contents: `blake3:` followed
+/// by the full lowercase hex digest.
+///
+/// # Examples
+///
+///     use theoremc_core::ledger::content_fingerprint;
+///
+///     let fingerprint = content_fingerprint("Theorem: Deposit\n");
+///     assert!(fingerprint.starts_with("blake3:"));
+///     assert_eq!(fingerprint.len(), "blake3:".len() + 64);
+#[must_use]
+pub fn content_fingerprint(contents: &str) -> String {
+    format!("blake3:{}", blake3::hash(contents.as_bytes()).to_hex())
+}
+
+#[cfg(test)]
+#[path = "ledger_tests.rs"]
+mod tests;
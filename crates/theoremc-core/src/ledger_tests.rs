@@ -0,0 +1,128 @@
+//! Unit tests for the evidence ledger.
+
+use camino::Utf8PathBuf;
+use indexmap::IndexMap;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::{Ledger, LedgerEntry, LedgerError, content_fingerprint};
+
+struct TempLedger {
+    _temp_dir: TempDir,
+    path: Utf8PathBuf,
+}
+
+fn temp_ledger() -> TempLedger {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let dir = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf())
+        .expect("temp path should be UTF-8");
+    TempLedger {
+        _temp_dir: temp_dir,
+        path: dir.join("evidence.jsonl"),
+    }
+}
+
+fn entry(outcome: &str, recorded_at: u64) -> LedgerEntry {
+    LedgerEntry {
+        theorem: "theorems/ledger.theorem#Deposit".to_owned(),
+        fingerprint: content_fingerprint("Theorem: Deposit\n"),
+        backend: "kani".to_owned(),
+        outcome: outcome.to_owned(),
+        duration_ms: 1250,
+        recorded_at,
+        toolchain: IndexMap::from([
+            ("rustc".to_owned(), "1.88.0".to_owned()),
+            ("kani".to_owned(), "0.56.0".to_owned()),
+        ]),
+    }
+}
+
+#[test]
+fn appended_entries_read_back_in_order() {
+    let temp = temp_ledger();
+    let ledger = Ledger::open(&temp.path).expect("ledger should open");
+    let first = entry("FAILURE", 1_700_000_000);
+    let second = entry("SUCCESS", 1_700_000_600);
+
+    ledger.append(&first).expect("first entry should append");
+    ledger.append(&second).expect("second entry should append");
+
+    assert_eq!(
+        ledger.entries().expect("ledger should read"),
+        vec![first, second]
+    );
+}
+
+#[test]
+fn each_entry_is_one_json_line() {
+    let temp = temp_ledger();
+    let ledger = Ledger::open(&temp.path).expect("ledger should open");
+    ledger
+        .append(&entry("SUCCESS", 1_700_000_000))
+        .expect("entry should append");
+
+    let text = std::fs::read_to_string(&temp.path).expect("ledger file should exist");
+
+    assert_eq!(
+        text,
+        format!(
+            concat!(
+                "{{\"theorem\":\"theorems/ledger.theorem#Deposit\",",
+                "\"fingerprint\":\"{}\",\"backend\":\"kani\",\"outcome\":\"SUCCESS\",",
+                "\"duration_ms\":1250,\"recorded_at\":1700000000,",
+                "\"toolchain\":{{\"rustc\":\"1.88.0\",\"kani\":\"0.56.0\"}}}}\n",
+            ),
+            content_fingerprint("Theorem: Deposit\n"),
+        )
+    );
+}
+
+#[test]
+fn missing_ledger_reads_as_empty() {
+    let temp = temp_ledger();
+    let ledger = Ledger::open(&temp.path).expect("ledger should open");
+
+    assert_eq!(ledger.entries().expect("ledger should read"), vec![]);
+}
+
+#[test]
+fn invalid_line_is_reported_with_its_number() {
+    let temp = temp_ledger();
+    std::fs::write(&temp.path, "\n{\"theorem\": 1}\n").expect("ledger should be written");
+    let ledger = Ledger::open(&temp.path).expect("ledger should open");
+
+    let error = ledger.entries().expect_err("line 2 is not an entry");
+
+    assert!(
+        matches!(error, LedgerError::InvalidEntry { line: 2, .. }),
+        "{error}"
+    );
+}
+
+#[test]
+fn missing_directory_is_reported() {
+    let temp = temp_ledger();
+    let path = temp.path.join("missing/evidence.jsonl");
+
+    let error = Ledger::open(&path).expect_err("directory does not exist");
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "failed to open ledger directory '{}': io:NotFound",
+            temp.path.join("missing")
+        )
+    );
+}
+
+#[test]
+fn fingerprint_tracks_content() {
+    assert_eq!(
+        content_fingerprint("Theorem: Deposit\n"),
+        content_fingerprint("Theorem: Deposit\n")
+    );
+    assert_ne!(
+        content_fingerprint("Theorem: Deposit\n"),
+        content_fingerprint("Theorem: Withdraw\n")
+    );
+}
@@ -3,18 +3,76 @@
 //! This crate owns the shared logic consumed by the public facade crate and by
 //! proc-macro expansion.
 
+/// Action manifests declaring the Rust signatures theorems may call.
+pub mod actions;
+
+/// Fluent construction of validated theorem documents.
+pub mod builder;
+
+/// Content-addressed caching of validated theorem documents.
+pub mod cache;
+
 /// Mangled-identifier collision detection across loaded theorem documents.
 pub mod collision;
 
+mod cycle;
+
+/// Theorem dependency graphs built from `DependsOn` declarations.
+pub mod dependency;
+
+/// Semantic comparison of theorem document versions.
+pub mod diff;
+
+/// Discovery and bulk loading of theorem files below a directory.
+pub mod discover;
+
+/// Minimal textual edits of theorem sources.
+pub mod edit;
+
+/// Layout-preserving formatting of theorem sources.
+pub mod format;
+
+/// Pluggable file access for theorem discovery and loading.
+pub mod file_provider;
+
+/// Append-only history of verification runs.
+pub mod ledger;
+
+/// Lints for theorem documents that load but are likely mistakes.
+pub mod lint;
+
 /// Action name mangling for deterministic, injective resolution.
 pub mod mangle;
 
+/// Migration of theorem documents and sources to the latest schema version.
+pub mod migrate;
+
 /// Path formatting helpers shared by compile-time tooling.
 pub mod path_format;
 
+/// Boolean tag expressions that select theorems by their `Tags`.
+pub mod tagexpr;
+
+/// Reporting views such as requirement traceability matrices.
+pub mod report;
+
+/// Verification results parsed from proof backend output.
+pub mod results;
+
 /// Schema types for `.theorem` document deserialization and validation.
 pub mod schema;
 
 mod theorem_file;
 
-pub use theorem_file::{TheoremFileLoadError, load_theorem_file_from_manifest_dir};
+/// Re-exports used by `theoremc-macros` expansions; not a stable API.
+#[doc(hidden)]
+pub mod __private {
+    pub use inventory;
+}
+
+pub use file_provider::load_theorem_file_from_provider;
+#[cfg(feature = "tokio")]
+pub use theorem_file::load_theorem_file_async;
+pub use theorem_file::{
+    TheoremFileLoadError, load_theorem_file, load_theorem_file_from_manifest_dir, read_theorem_file,
+};
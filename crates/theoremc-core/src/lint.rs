@@ -0,0 +1,248 @@
+//! Lints for theorem documents that load but are likely mistakes.
+//!
+//! Loading rejects documents that cannot be compiled into harnesses. Lints
+//! report documents that compile but probably do not say what their author
+//! meant, such as a `Forall` variable that no expression reads. Each finding
+//! is a [`LintDiagnostic`] located at the value it concerns, with a
+//! suggested fix when there is an obvious one.
+//!
+//! [`lint_source`] loads a source and lints every theorem in it, and
+//! [`lint_theorem`] lints one document loaded with
+//! [`load_spanned_theorem_docs`](crate::schema::load_spanned_theorem_docs).
+//! A [`LintConfig`] sets each lint to allow, warn, or deny, and each
+//! theorem's `Lints` section refines it. A [`LintRegistry`] holds the lints
+//! to run, and accepts rules defined outside this crate through the
+//! [`Lint`] trait.
+//!
+//! # Examples
+//!
+//!     use theoremc_core::lint::{LintCode, lint_source};
+//!     use theoremc_core::schema::SourceId;
+//!
+//!     let yaml = r#"Theorem: Unused
+//!     About: Declares a variable it never reads
+//!     Forall:
+//!       x: u8
+//!       y: u8
+//!     Prove:
+//!       - assert: "y.checked_add(0) == Some(y)"
+//!         because: adding zero never overflows
+//!     Evidence:
+//!       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!     "#;
+//!     let lints = lint_source(&SourceId::new("unused.theorem"), yaml).unwrap();
+//!     assert_eq!(lints.len(), 1);
+//!     assert_eq!(lints[0].code, LintCode::UnusedForall);
+//!     assert_eq!(lints[0].location.as_ref().map(|location| location.line), Some(4));
+
+use crate::schema::{SchemaError, SourceId, SourceLocation, SpannedTheoremDoc};
+
+#[path = "lint_because.rs"]
+mod because;
+#[path = "lint_config.rs"]
+mod config;
+#[path = "lint_constant.rs"]
+mod constant;
+#[path = "lint_duplicate.rs"]
+mod duplicate;
+#[path = "lint_registry.rs"]
+mod registry;
+#[path = "lint_tags.rs"]
+mod tags;
+#[path = "lint_unused.rs"]
+mod unused;
+#[path = "lint_unwind.rs"]
+mod unwind;
+#[path = "lint_witness.rs"]
+mod witness;
+
+pub use because::DEFAULT_PLACEHOLDER_PATTERNS;
+pub use config::{DEFAULT_UNWIND_CEILING, LintConfig, LintConfigError, LintLevel};
+pub use registry::{Lint, LintContext, LintRegistry};
+
+/// Stable identifiers of the lints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintCode {
+    /// A `Forall` variable no expression, argument, or derived variable in
+    /// use reads.
+    UnusedForall,
+    /// A `Let` binding nothing after it reads.
+    UnusedLet,
+    /// A `Do` step's `as` binding nothing after it reads.
+    UnusedAs,
+    /// A `Prove` assertion that holds whatever the inputs are, such as a
+    /// constant or a comparison of an operand with itself.
+    ConstantAssertion,
+    /// An `Assume`, `Prove`, or `Witness` entry that repeats an earlier
+    /// entry of the same section.
+    DuplicateCheck,
+    /// A `Witness` whose `cover` expression the assumptions already force.
+    AssumedWitness,
+    /// A Kani `unwind` below what the `repeat` loops need, or above the
+    /// configured ceiling.
+    UnwindBound,
+    /// A `because` field that is a placeholder such as `TODO`, a single
+    /// word, or a restatement of its expression.
+    PlaceholderBecause,
+    /// A tag that is not kebab-case, or that matches none of the configured
+    /// allowed tag patterns.
+    TagStyle,
+    /// A configured required tag pattern that the theorem's tags match
+    /// other than exactly once.
+    RequiredTag,
+    /// A lint registered through [`LintRegistry::register`], identified by
+    /// its name.
+    Custom(&'static str),
+}
+
+impl LintCode {
+    /// Every built-in lint, in the order their findings are reported.
+    pub const ALL: [Self; 10] = [
+        Self::UnusedForall,
+        Self::UnusedLet,
+        Self::UnusedAs,
+        Self::ConstantAssertion,
+        Self::DuplicateCheck,
+        Self::AssumedWitness,
+        Self::UnwindBound,
+        Self::PlaceholderBecause,
+        Self::TagStyle,
+        Self::RequiredTag,
+    ];
+
+    /// Returns the built-in lint named `name`, or `None` when there is
+    /// none.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|code| code.as_str() == name)
+    }
+
+    /// Returns the stable, machine-readable lint name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::UnusedForall => "unused_forall",
+            Self::UnusedLet => "unused_let",
+            Self::UnusedAs => "unused_as",
+            Self::ConstantAssertion => "constant_assertion",
+            Self::DuplicateCheck => "duplicate_check",
+            Self::AssumedWitness => "assumed_witness",
+            Self::UnwindBound => "unwind_bound",
+            Self::PlaceholderBecause => "placeholder_because",
+            Self::TagStyle => "tag_style",
+            Self::RequiredTag => "required_tag",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+/// Errors raised while linting a source.
+#[derive(Debug, thiserror::Error)]
+pub enum LintError {
+    /// The source did not load.
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+    /// A `Lints` section names an unknown lint or level.
+    #[error(transparent)]
+    Config(#[from] LintConfigError),
+}
+
+/// One lint finding in a theorem document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    /// The lint that raised the finding.
+    pub code: LintCode,
+    /// The configured level of the lint. Lints report `Warn`, and the
+    /// runner replaces it with the level the configuration sets.
+    pub level: LintLevel,
+    /// The theorem the finding concerns.
+    pub theorem: String,
+    /// Where the value the finding concerns is written, or `None` when the
+    /// document inherits it.
+    pub location: Option<SourceLocation>,
+    /// Human-readable description of the finding.
+    pub message: String,
+    /// A suggested fix, such as removing the unused declaration.
+    pub suggestion: Option<String>,
+}
+
+impl LintDiagnostic {
+    /// Renders the finding on one line, as `level | code |
+    /// source:line:column | message | help: suggestion`, omitting the
+    /// location and suggestion when there are none.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut fields = vec![
+            self.level.as_str().to_owned(),
+            self.code.as_str().to_owned(),
+        ];
+        fields.extend(
+            self.location.as_ref().map(|location| {
+                format!("{}:{}:{}", location.source, location.line, location.column)
+            }),
+        );
+        fields.push(self.message.clone());
+        fields.extend(
+            self.suggestion
+                .as_ref()
+                .map(|suggestion| format!("help: {suggestion}")),
+        );
+        fields.join(" | ")
+    }
+}
+
+/// Runs every built-in lint over one theorem document at the levels its
+/// `Lints` section sets.
+///
+/// # Errors
+///
+/// Returns [`LintConfigError`] when the `Lints` section names an unknown
+/// lint or level.
+pub fn lint_theorem(doc: &SpannedTheoremDoc) -> Result<Vec<LintDiagnostic>, LintConfigError> {
+    lint_theorem_with_config(doc, &LintConfig::default())
+}
+
+/// Runs every built-in lint over one theorem document at the levels of
+/// `config` refined by the document's `Lints` section, dropping the
+/// findings of allowed lints.
+///
+/// # Errors
+///
+/// Returns [`LintConfigError`] when the `Lints` section names an unknown
+/// lint or level.
+pub fn lint_theorem_with_config(
+    doc: &SpannedTheoremDoc,
+    config: &LintConfig,
+) -> Result<Vec<LintDiagnostic>, LintConfigError> {
+    LintRegistry::default().lint_theorem(doc, config)
+}
+
+/// Loads the theorem documents of `input` and lints each, returning the
+/// findings in document order.
+///
+/// # Errors
+///
+/// Returns [`LintError::Schema`] with the errors of
+/// [`load_spanned_theorem_docs`](crate::schema::load_spanned_theorem_docs)
+/// when the source does not load, and [`LintError::Config`] when a `Lints`
+/// section names an unknown lint or level.
+pub fn lint_source(source: &SourceId, input: &str) -> Result<Vec<LintDiagnostic>, LintError> {
+    lint_source_with_config(source, input, &LintConfig::default())
+}
+
+/// Loads and lints `input` like [`lint_source`], starting from `config`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`lint_source`].
+pub fn lint_source_with_config(
+    source: &SourceId,
+    input: &str,
+    config: &LintConfig,
+) -> Result<Vec<LintDiagnostic>, LintError> {
+    LintRegistry::default().lint_source(source, input, config)
+}
+
+#[cfg(test)]
+#[path = "lint_tests.rs"]
+mod tests;
@@ -0,0 +1,244 @@
+//! Lints for `because` text that does not justify its check.
+
+use regex::Regex;
+use toml::Spanned;
+use toml::de::DeValue;
+
+use super::config::{invalid, strings};
+use super::{LintCode, LintConfig, LintConfigError, LintDiagnostic, LintLevel};
+use crate::schema::{SpannedTheoremDoc, Step};
+
+/// The regular expressions `placeholder_because` matches `because` text
+/// against, unless the configuration sets others: a `TODO`-style marker
+/// anywhere, or text without a letter or digit.
+pub const DEFAULT_PLACEHOLDER_PATTERNS: [&str; 2] = [r"(?i)\b(todo|fixme|tbd|xxx+)\b", r"^[\W_]*$"];
+
+impl LintConfig {
+    /// Sets the regular expressions `placeholder_because` matches `because`
+    /// text against, replacing [`DEFAULT_PLACEHOLDER_PATTERNS`].
+    #[must_use]
+    pub fn with_placeholder_patterns(mut self, patterns: Vec<Regex>) -> Self {
+        self.placeholder_patterns = Patterns(patterns);
+        self
+    }
+
+    /// Returns the regular expressions `placeholder_because` matches
+    /// `because` text against.
+    #[must_use]
+    pub fn placeholder_patterns(&self) -> &[Regex] {
+        &self.placeholder_patterns.0
+    }
+}
+
+/// Reports each `because` field of an `Assume`, `Prove`, or `Witness`
+/// entry, inline `assert` step, or `maybe` block that matches a configured
+/// placeholder pattern, such as `TODO`, is a single word, or only restates
+/// the expression it justifies. The justification is what reviewers audit,
+/// so a placeholder leaves the check unexplained.
+pub(super) fn placeholder_because(
+    spanned: &SpannedTheoremDoc,
+    config: &LintConfig,
+) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let mut justifications = Vec::new();
+    let sections = [
+        (
+            "Assume",
+            "Assume assumption",
+            doc.assume
+                .iter()
+                .map(|entry| (entry.expr.as_str(), entry.because.as_str()))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "Prove",
+            "Prove assertion",
+            doc.prove
+                .iter()
+                .map(|entry| (entry.assert_expr.as_str(), entry.because.as_str()))
+                .collect(),
+        ),
+        (
+            "Witness",
+            "Witness",
+            doc.witness
+                .iter()
+                .map(|entry| (entry.cover.as_str(), entry.because.as_str()))
+                .collect(),
+        ),
+    ];
+    for (section, noun, entries) in sections {
+        justifications.extend(
+            entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, (expr, because))| Justification {
+                    label: format!("{noun} {}", index + 1),
+                    path: vec![section.to_owned(), index.to_string()],
+                    expr: Some(expr),
+                    because,
+                }),
+        );
+    }
+    step_justifications(&doc.do_steps, &Block::top_level(), &mut justifications);
+    justifications
+        .into_iter()
+        .filter_map(|justification| {
+            let reason = placeholder_reason(&justification, config)?;
+            let mut path: Vec<&str> = justification.path.iter().map(String::as_str).collect();
+            path.push("because");
+            Some(LintDiagnostic {
+                code: LintCode::PlaceholderBecause,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location: spanned.location(&path).cloned(),
+                message: format!(
+                    "{} has because text '{}', which {reason}",
+                    justification.label,
+                    justification.because.trim()
+                ),
+                suggestion: Some("explain why the check is needed or why it holds".to_owned()),
+            })
+        })
+        .collect()
+}
+
+/// One `because` field and the expression it justifies, if any.
+struct Justification<'d> {
+    label: String,
+    path: Vec<String>,
+    expr: Option<&'d str>,
+    because: &'d str,
+}
+
+/// Describes why `justification` is a placeholder, or returns `None` when
+/// it may be a real explanation.
+fn placeholder_reason(justification: &Justification<'_>, config: &LintConfig) -> Option<String> {
+    let because = justification.because.trim();
+    if justification
+        .expr
+        .is_some_and(|expr| without_spaces(expr) == without_spaces(because))
+    {
+        return Some("only restates the expression".to_owned());
+    }
+    if let Some(pattern) = config
+        .placeholder_patterns()
+        .iter()
+        .find(|pattern| pattern.is_match(because))
+    {
+        return Some(format!(
+            "matches the placeholder pattern '{}'",
+            pattern.as_str()
+        ));
+    }
+    (because.split_whitespace().count() <= 1).then(|| "is a single word".to_owned())
+}
+
+fn without_spaces(text: &str) -> String {
+    text.chars()
+        .filter(|character| !character.is_whitespace())
+        .collect()
+}
+
+/// The `Do` block being walked: its label, as in loader messages, and its
+/// source path.
+struct Block {
+    label: String,
+    path: Vec<String>,
+}
+
+impl Block {
+    fn top_level() -> Self {
+        Self {
+            label: "Do step".to_owned(),
+            path: vec!["Do".to_owned()],
+        }
+    }
+}
+
+/// Collects the `because` fields of the inline `assert` steps and `maybe`
+/// blocks in `steps` and their nested blocks.
+fn step_justifications<'d>(steps: &'d [Step], block: &Block, found: &mut Vec<Justification<'d>>) {
+    for (index, step) in steps.iter().enumerate() {
+        let label = format!("{} {}", block.label, index + 1);
+        let mut path = block.path.clone();
+        path.extend([index.to_string(), step.keyword().to_owned()]);
+        let justified = match step {
+            Step::Assert(step_assert) => Some((
+                Some(step_assert.assert.expr.as_str()),
+                step_assert.assert.because.as_str(),
+            )),
+            Step::Maybe(step_maybe) => Some((None, step_maybe.maybe.because.as_str())),
+            Step::Call(_)
+            | Step::Must(_)
+            | Step::ExpectErr(_)
+            | Step::Repeat(_)
+            | Step::Foreach(_) => None,
+        };
+        if let Some((expr, because)) = justified {
+            found.push(Justification {
+                label: label.clone(),
+                path: path.clone(),
+                expr,
+                because,
+            });
+        }
+        for (branch, nested) in step.nested_branches() {
+            let mut nested_path = path.clone();
+            nested_path.push(branch.to_owned());
+            let nested_block = Block {
+                label: format!("{label}: {}.{branch} step", step.keyword()),
+                path: nested_path,
+            };
+            step_justifications(nested, &nested_block, found);
+        }
+    }
+}
+
+/// Compiled regular expressions, equal when their sources are.
+#[derive(Debug, Clone)]
+pub(super) struct Patterns(pub(super) Vec<Regex>);
+
+impl PartialEq for Patterns {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .map(Regex::as_str)
+            .eq(other.0.iter().map(Regex::as_str))
+    }
+}
+
+impl Eq for Patterns {}
+
+impl Default for Patterns {
+    fn default() -> Self {
+        Self(
+            DEFAULT_PLACEHOLDER_PATTERNS
+                .iter()
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect(),
+        )
+    }
+}
+
+/// Compiles the `placeholder_patterns` lint option.
+pub(super) fn placeholder_patterns(
+    entry: &Spanned<DeValue<'_>>,
+    at: &str,
+) -> Result<Vec<Regex>, LintConfigError> {
+    strings("placeholder_patterns", entry, at)?
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern).map_err(|error| {
+                invalid(
+                    at,
+                    format!("placeholder pattern '{pattern}' is not a regular expression: {error}"),
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "lint_because_tests.rs"]
+mod tests;
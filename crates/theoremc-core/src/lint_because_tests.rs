@@ -0,0 +1,133 @@
+//! Unit tests for the placeholder because lint.
+
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn lints_with(because: &str, config: &LintConfig) -> Vec<LintDiagnostic> {
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "Forall:\n",
+            "  x: u8\n",
+            "Prove:\n",
+            "  - assert: \"x.checked_add(0) == Some(x)\"\n",
+            "    because: \"{because}\"\n",
+            "Evidence:\n",
+            "  kani: {{ unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }}\n",
+        ),
+        because = because,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(|doc| placeholder_because(doc, config))
+        .collect()
+}
+
+fn lints(because: &str) -> Vec<LintDiagnostic> {
+    lints_with(because, &LintConfig::default())
+}
+
+#[rstest]
+#[case::todo(
+    "TODO",
+    "matches the placeholder pattern '(?i)\\b(todo|fixme|tbd|xxx+)\\b'"
+)]
+#[case::todo_with_text(
+    "fixme: explain this later",
+    "matches the placeholder pattern '(?i)\\b(todo|fixme|tbd|xxx+)\\b'"
+)]
+#[case::punctuation("? ?", "matches the placeholder pattern '^[\\W_]*$'")]
+#[case::single_word("arithmetic", "is a single word")]
+#[case::restated("x.checked_add(0)  ==  Some(x)", "only restates the expression")]
+fn placeholder_because_text_is_reported(#[case] because: &str, #[case] reason: &str) {
+    let messages: Vec<String> = lints(because)
+        .into_iter()
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        [format!(
+            "Prove assertion 1 has because text '{because}', which {reason}"
+        )]
+    );
+}
+
+#[rstest]
+#[case::sentence("adding zero never overflows")]
+#[case::mentions_a_word_containing_todo("the mastodon handle stays valid")]
+fn explanations_are_not_reported(#[case] because: &str) {
+    assert_eq!(lints(because), []);
+}
+
+#[test]
+fn findings_are_located_at_the_because_value() {
+    let locations: Vec<(usize, usize)> = lints("TBD")
+        .iter()
+        .filter_map(|lint| lint.location.as_ref())
+        .map(|location| (location.line, location.column))
+        .collect();
+
+    assert_eq!(locations, [(7, 14)]);
+}
+
+#[test]
+fn configured_patterns_replace_the_defaults() {
+    let pattern = Regex::new("^see (above|below)$").expect("pattern should compile");
+    let config = LintConfig::default().with_placeholder_patterns(vec![pattern]);
+
+    assert_eq!(lints_with("see above", &config).len(), 1);
+    assert_eq!(lints_with("TODO check overflow", &config), []);
+}
+
+#[test]
+fn steps_and_other_sections_are_checked() {
+    let input = concat!(
+        "Theorem: T\n",
+        "About: Example\n",
+        "Forall:\n",
+        "  x: u8\n",
+        "Assume:\n",
+        "  - expr: \"x < 10\"\n",
+        "    because: x < 10\n",
+        "Do:\n",
+        "  - maybe:\n",
+        "      because: TODO\n",
+        "      do:\n",
+        "        - assert:\n",
+        "            expr: \"x < 10\"\n",
+        "            because: holds\n",
+        "Prove:\n",
+        "  - assert: \"x < 10\"\n",
+        "    because: the assumption bounds x\n",
+        "Evidence:\n",
+        "  kani: { unwind: 2, expect: SUCCESS }\n",
+        "Witness:\n",
+        "  - cover: \"x == 3\"\n",
+        "    because: xxx\n",
+    );
+    let messages: Vec<String> = load_spanned_theorem_docs(&SourceId::new("t.theorem"), input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(|doc| placeholder_because(doc, &LintConfig::default()))
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        [
+            "Assume assumption 1 has because text 'x < 10', which only restates the expression",
+            "Witness 1 has because text 'xxx', which matches the placeholder pattern \
+             '(?i)\\b(todo|fixme|tbd|xxx+)\\b'",
+            "Do step 1 has because text 'TODO', which matches the placeholder pattern \
+             '(?i)\\b(todo|fixme|tbd|xxx+)\\b'",
+            "Do step 1: maybe.do step 1 has because text 'holds', which is a single word",
+        ]
+    );
+}
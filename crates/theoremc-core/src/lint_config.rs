@@ -0,0 +1,367 @@
+//! Per-lint levels deciding which findings are reported.
+//!
+//! A configuration starts from the `[lints]` and `[lint_options]` tables of
+//! a `theoremc.toml` file, read by [`LintConfig::from_toml`], and each
+//! theorem refines it with its `Lints` section, which already holds the
+//! levels of its file's `Defaults` document:
+//!
+//! ```toml
+//! [lints]
+//! constant_assertion = "allow"
+//! unused_forall = "deny"
+//!
+//! [lint_options]
+//! unwind_ceiling = 64
+//! placeholder_patterns = ["(?i)\\b(todo|fixme|tbd)\\b", "(?i)^see (above|below)$"]
+//! allowed_tags = ["component:*", "team-*", "smoke"]
+//! required_tags = ["component:*"]
+//! ```
+
+use std::collections::BTreeMap;
+
+use toml::Spanned;
+use toml::de::{DeTable, DeValue};
+
+use super::because::{Patterns, placeholder_patterns};
+use super::{LintCode, LintRegistry};
+use crate::schema::{SourceId, SpannedTheoremDoc, position};
+
+/// The Kani `unwind` above which `unwind_bound` reports a theorem, unless
+/// the configuration sets another ceiling.
+pub const DEFAULT_UNWIND_CEILING: u32 = 128;
+
+/// How the findings of one lint are treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LintLevel {
+    /// Findings are dropped.
+    Allow,
+    /// Findings are reported as warnings.
+    #[default]
+    Warn,
+    /// Findings are reported as errors, failing `theoremc lint`.
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the level as written in configuration, such as `warn`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+
+    /// Returns the level written as `name`, or `None` when there is none.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::Allow, Self::Warn, Self::Deny]
+            .into_iter()
+            .find(|level| level.as_str() == name)
+    }
+}
+
+/// Errors raised while reading lint levels and options.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LintConfigError {
+    /// The configuration file is not well-formed TOML.
+    #[error("{at}: TOML syntax error: {message}")]
+    Syntax {
+        /// Where the error was found.
+        at: String,
+        /// The parser's description of the error.
+        message: String,
+    },
+    /// A lint name matches no lint.
+    #[error("{at}: unknown lint '{name}'")]
+    UnknownLint {
+        /// Where the name is written.
+        at: String,
+        /// The unknown name.
+        name: String,
+    },
+    /// A level is not `allow`, `warn`, or `deny`.
+    #[error("{at}: lint '{lint}' has unknown level '{level}'; expected allow, warn, or deny")]
+    UnknownLevel {
+        /// Where the level is written.
+        at: String,
+        /// The lint the level was given for.
+        lint: String,
+        /// The unknown level.
+        level: String,
+    },
+    /// A lint was registered under the name of another lint.
+    #[error("a lint named '{name}' is already registered")]
+    DuplicateLint {
+        /// The name both lints use.
+        name: String,
+    },
+    /// A configuration entry is not of the expected shape.
+    #[error("{at}: {message}")]
+    Invalid {
+        /// Where the entry is written.
+        at: String,
+        /// What is wrong with the entry.
+        message: String,
+    },
+}
+
+/// The level of each lint and the thresholds lints compare against; lints
+/// without an explicit level warn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintConfig {
+    levels: BTreeMap<LintCode, LintLevel>,
+    unwind_ceiling: u32,
+    pub(super) placeholder_patterns: Patterns,
+    pub(super) allowed_tags: Vec<String>,
+    pub(super) required_tags: Vec<String>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            levels: BTreeMap::new(),
+            unwind_ceiling: DEFAULT_UNWIND_CEILING,
+            placeholder_patterns: Patterns::default(),
+            allowed_tags: Vec::new(),
+            required_tags: Vec::new(),
+        }
+    }
+}
+
+impl LintConfig {
+    /// Reads the `[lints]` and `[lint_options]` tables of a `theoremc.toml`
+    /// file. Other tables are left to the tools that own them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintConfigError`] when `input` is not well-formed TOML,
+    /// names an unknown lint, level, or option, or gives an option a value
+    /// of the wrong type.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::lint::{LintCode, LintConfig, LintLevel};
+    ///     use theoremc_core::schema::SourceId;
+    ///
+    ///     let toml = "[lints]\nunused_let = \"deny\"\n";
+    ///     let config = LintConfig::from_toml(&SourceId::new("theoremc.toml"), toml).unwrap();
+    ///     assert_eq!(config.level(LintCode::UnusedLet), LintLevel::Deny);
+    pub fn from_toml(source: &SourceId, input: &str) -> Result<Self, LintConfigError> {
+        Self::read_toml(source, input, &LintRegistry::default())
+    }
+
+    /// Reads a `theoremc.toml` file like [`LintConfig::from_toml`],
+    /// accepting the names of the lints in `registry`.
+    pub(super) fn read_toml(
+        source: &SourceId,
+        input: &str,
+        registry: &LintRegistry,
+    ) -> Result<Self, LintConfigError> {
+        let toml = TomlSource {
+            source,
+            input,
+            registry,
+        };
+        let table = DeTable::parse(input).map_err(|error| LintConfigError::Syntax {
+            at: toml.at(error.span().map_or(0, |span| span.start)),
+            message: error.message().to_owned(),
+        })?;
+        let mut config = Self::default();
+        for (key, value) in table.get_ref() {
+            let section = key.get_ref().as_ref();
+            if !matches!(section, "lints" | "lint_options") {
+                continue;
+            }
+            let DeValue::Table(entries) = value.get_ref() else {
+                return Err(LintConfigError::Invalid {
+                    at: toml.at(value.span().start),
+                    message: format!("[{section}] must be a table"),
+                });
+            };
+            config.read_section(section, entries, &toml)?;
+        }
+        Ok(config)
+    }
+
+    /// Applies the entries of the `[lints]` or `[lint_options]` table.
+    fn read_section(
+        &mut self,
+        section: &str,
+        entries: &DeTable<'_>,
+        toml: &TomlSource<'_>,
+    ) -> Result<(), LintConfigError> {
+        for (name, entry) in entries {
+            let (lint, entry_at) = (name.get_ref().as_ref(), toml.at(name.span().start));
+            if section == "lints" {
+                self.levels.insert(
+                    lint_code(toml.registry, lint, &entry_at)?,
+                    toml_level(lint, entry, &entry_at)?,
+                );
+            } else {
+                self.read_option(lint, entry, &entry_at)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies one entry of the `[lint_options]` table.
+    fn read_option(
+        &mut self,
+        option: &str,
+        entry: &Spanned<DeValue<'_>>,
+        at: &str,
+    ) -> Result<(), LintConfigError> {
+        match option {
+            "unwind_ceiling" => self.unwind_ceiling = unwind_ceiling(entry, at)?,
+            "placeholder_patterns" => {
+                self.placeholder_patterns = Patterns(placeholder_patterns(entry, at)?);
+            }
+            "allowed_tags" => self.allowed_tags = strings(option, entry, at)?,
+            "required_tags" => self.required_tags = strings(option, entry, at)?,
+            _ => return Err(invalid(at, format!("unknown lint option '{option}'"))),
+        }
+        Ok(())
+    }
+
+    /// Sets the level of `code`, replacing any earlier level.
+    #[must_use]
+    pub fn with_level(mut self, code: LintCode, level: LintLevel) -> Self {
+        self.levels.insert(code, level);
+        self
+    }
+
+    /// Returns the level of `code`.
+    #[must_use]
+    pub fn level(&self, code: LintCode) -> LintLevel {
+        self.levels.get(&code).copied().unwrap_or_default()
+    }
+
+    /// Sets the Kani `unwind` above which `unwind_bound` reports a theorem.
+    #[must_use]
+    pub const fn with_unwind_ceiling(mut self, ceiling: u32) -> Self {
+        self.unwind_ceiling = ceiling;
+        self
+    }
+
+    /// Returns the Kani `unwind` above which `unwind_bound` reports a
+    /// theorem.
+    #[must_use]
+    pub const fn unwind_ceiling(&self) -> u32 {
+        self.unwind_ceiling
+    }
+
+    /// Returns this configuration refined by the `Lints` section of `doc`,
+    /// whose levels win, or an error located at the first entry naming a
+    /// lint outside `registry` or an unknown level.
+    pub(super) fn for_theorem(
+        &self,
+        doc: &SpannedTheoremDoc,
+        registry: &LintRegistry,
+    ) -> Result<Self, LintConfigError> {
+        let theorem = doc.doc();
+        let mut config = self.clone();
+        for (name, level) in &theorem.lints {
+            let at = doc.location(&["Lints", name.as_str()]).map_or_else(
+                || format!("theorem '{}'", theorem.theorem.as_str()),
+                |location| format!("{}:{}:{}", location.source, location.line, location.column),
+            );
+            let code = lint_code(registry, name, &at)?;
+            let parsed =
+                LintLevel::from_name(level).ok_or_else(|| unknown_level(name, level, &at))?;
+            config.levels.insert(code, parsed);
+        }
+        Ok(config)
+    }
+}
+
+/// A `theoremc.toml` file being read, and the lints it may name.
+struct TomlSource<'a> {
+    source: &'a SourceId,
+    input: &'a str,
+    registry: &'a LintRegistry,
+}
+
+impl TomlSource<'_> {
+    /// Describes the position of byte `offset` as `source:line:column`.
+    fn at(&self, offset: usize) -> String {
+        let (line, column) = position(self.input, offset);
+        format!("{}:{line}:{column}", self.source.as_str())
+    }
+}
+
+fn lint_code(registry: &LintRegistry, name: &str, at: &str) -> Result<LintCode, LintConfigError> {
+    registry
+        .code(name)
+        .ok_or_else(|| LintConfigError::UnknownLint {
+            at: at.to_owned(),
+            name: name.to_owned(),
+        })
+}
+
+fn unknown_level(lint: &str, level: &str, at: &str) -> LintConfigError {
+    LintConfigError::UnknownLevel {
+        at: at.to_owned(),
+        lint: lint.to_owned(),
+        level: level.to_owned(),
+    }
+}
+
+fn toml_level(
+    lint: &str,
+    entry: &Spanned<DeValue<'_>>,
+    at: &str,
+) -> Result<LintLevel, LintConfigError> {
+    match entry.get_ref() {
+        DeValue::String(level) => {
+            LintLevel::from_name(level).ok_or_else(|| unknown_level(lint, level, at))
+        }
+        _ => Err(LintConfigError::Invalid {
+            at: at.to_owned(),
+            message: format!("the level of lint '{lint}' must be a string"),
+        }),
+    }
+}
+
+pub(super) fn invalid(at: &str, message: String) -> LintConfigError {
+    LintConfigError::Invalid {
+        at: at.to_owned(),
+        message,
+    }
+}
+
+fn unwind_ceiling(entry: &Spanned<DeValue<'_>>, at: &str) -> Result<u32, LintConfigError> {
+    match entry.get_ref() {
+        DeValue::Integer(integer) => u32::from_str_radix(integer.as_str(), integer.radix())
+            .map_err(|_| {
+                invalid(
+                    at,
+                    "unwind_ceiling must be a non-negative 32-bit integer".to_owned(),
+                )
+            }),
+        _ => Err(invalid(at, "unwind_ceiling must be an integer".to_owned())),
+    }
+}
+
+pub(super) fn strings(
+    option: &str,
+    entry: &Spanned<DeValue<'_>>,
+    at: &str,
+) -> Result<Vec<String>, LintConfigError> {
+    let not_strings = || invalid(at, format!("{option} must be an array of strings"));
+    let DeValue::Array(items) = entry.get_ref() else {
+        return Err(not_strings());
+    };
+    items
+        .iter()
+        .map(|item| match item.get_ref() {
+            DeValue::String(text) => Ok(text.to_string()),
+            _ => Err(not_strings()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "lint_config_tests.rs"]
+mod tests;
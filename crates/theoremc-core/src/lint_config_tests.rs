@@ -0,0 +1,147 @@
+//! Unit tests for lint configuration.
+
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use rstest::rstest;
+
+use super::super::DEFAULT_PLACEHOLDER_PATTERNS;
+use super::*;
+
+fn from_toml(input: &str) -> Result<LintConfig, LintConfigError> {
+    LintConfig::from_toml(&SourceId::new("theoremc.toml"), input)
+}
+
+#[test]
+fn levels_and_options_are_read() {
+    let config = from_toml(concat!(
+        "[package]\n",
+        "name = \"ignored\"\n",
+        "\n",
+        "[lints]\n",
+        "unused_let = \"allow\"\n",
+        "constant_assertion = \"deny\"\n",
+        "\n",
+        "[lint_options]\n",
+        "unwind_ceiling = 64\n",
+    ))
+    .expect("configuration should load");
+
+    assert_eq!(
+        config,
+        LintConfig::default()
+            .with_level(LintCode::UnusedLet, LintLevel::Allow)
+            .with_level(LintCode::ConstantAssertion, LintLevel::Deny)
+            .with_unwind_ceiling(64)
+    );
+}
+
+#[rstest]
+#[case::unknown_lint(
+    "[lints]\nunused = \"allow\"\n",
+    "theoremc.toml:2:1: unknown lint 'unused'"
+)]
+#[case::unknown_level(
+    "[lints]\nunused_let = \"forbid\"\n",
+    "theoremc.toml:2:1: lint 'unused_let' has unknown level 'forbid'; expected allow, warn, or deny"
+)]
+#[case::level_not_a_string(
+    "[lints]\nunused_let = 1\n",
+    "theoremc.toml:2:1: the level of lint 'unused_let' must be a string"
+)]
+#[case::unknown_option(
+    "[lint_options]\nceiling = 4\n",
+    "theoremc.toml:2:1: unknown lint option 'ceiling'"
+)]
+#[case::negative_ceiling(
+    "[lint_options]\nunwind_ceiling = -1\n",
+    "theoremc.toml:2:1: unwind_ceiling must be a non-negative 32-bit integer"
+)]
+#[case::tags_not_strings(
+    "[lint_options]\nrequired_tags = [true]\n",
+    "theoremc.toml:2:1: required_tags must be an array of strings"
+)]
+#[case::patterns_not_an_array(
+    "[lint_options]\nplaceholder_patterns = \"todo\"\n",
+    "theoremc.toml:2:1: placeholder_patterns must be an array of strings"
+)]
+#[case::pattern_not_a_string(
+    "[lint_options]\nplaceholder_patterns = [1]\n",
+    "theoremc.toml:2:1: placeholder_patterns must be an array of strings"
+)]
+#[case::lints_not_a_table("lints = 1\n", "theoremc.toml:1:9: [lints] must be a table")]
+fn invalid_configuration_is_rejected(#[case] input: &str, #[case] message: &str) {
+    let error = from_toml(input).expect_err("configuration should be rejected");
+
+    assert_eq!(error.to_string(), message);
+}
+
+#[test]
+fn malformed_toml_is_a_syntax_error() {
+    let error = from_toml("[lints\n").expect_err("malformed TOML should fail");
+
+    assert!(matches!(error, LintConfigError::Syntax { .. }), "{error}");
+}
+
+#[rstest]
+#[case::allow("allow", Some(LintLevel::Allow))]
+#[case::warn("warn", Some(LintLevel::Warn))]
+#[case::deny("deny", Some(LintLevel::Deny))]
+#[case::unknown("error", None)]
+fn levels_are_named(#[case] name: &str, #[case] level: Option<LintLevel>) {
+    assert_eq!(LintLevel::from_name(name), level);
+}
+
+#[test]
+fn placeholder_patterns_are_read() {
+    let config = from_toml("[lint_options]\nplaceholder_patterns = [\"^n/a$\", \"(?i)wip\"]\n")
+        .expect("configuration should load");
+    let patterns: Vec<&str> = config
+        .placeholder_patterns()
+        .iter()
+        .map(Regex::as_str)
+        .collect();
+
+    assert_eq!(patterns, ["^n/a$", "(?i)wip"]);
+}
+
+#[test]
+fn invalid_placeholder_patterns_are_rejected() {
+    let error = from_toml("[lint_options]\nplaceholder_patterns = [\"(todo\"]\n")
+        .expect_err("the pattern does not compile");
+
+    assert!(
+        error.to_string().starts_with(
+            "theoremc.toml:2:1: placeholder pattern '(todo' is not a regular expression"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn default_placeholder_patterns_compile() {
+    let config = LintConfig::default();
+    let patterns: Vec<&str> = config
+        .placeholder_patterns()
+        .iter()
+        .map(Regex::as_str)
+        .collect();
+
+    assert_eq!(patterns, DEFAULT_PLACEHOLDER_PATTERNS);
+}
+
+#[test]
+fn tag_patterns_are_read() {
+    let config = from_toml(concat!(
+        "[lint_options]\n",
+        "allowed_tags = [\"component:*\", \"smoke\"]\n",
+        "required_tags = [\"component:*\"]\n",
+    ))
+    .expect("configuration should load");
+
+    assert_eq!(
+        config,
+        LintConfig::default()
+            .with_allowed_tags(vec!["component:*".to_owned(), "smoke".to_owned()])
+            .with_required_tags(vec!["component:*".to_owned()])
+    );
+}
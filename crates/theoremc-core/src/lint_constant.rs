@@ -0,0 +1,112 @@
+//! Lints for assertions that hold whatever the theorem's inputs are.
+
+use quote::ToTokens;
+use syn::visit::{self, Visit};
+use syn::{BinOp, Expr, ExprCall, ExprMacro, ExprMethodCall};
+
+use super::{LintCode, LintDiagnostic, LintLevel};
+use crate::schema::{SpannedTheoremDoc, TheoremDoc, referenced_variables};
+
+/// Reports each `Prove` assertion that is a constant expression, such as
+/// `true` or `1 == 1`, or that compares an operand with itself using `==`,
+/// `<=`, or `>=`. Such an assertion passes without checking anything.
+pub(super) fn constant_assertions(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    doc.prove
+        .iter()
+        .enumerate()
+        .filter_map(|(index, assertion)| {
+            let reason = trivial_reason(doc, assertion.assert_expr.as_str().trim())?;
+            let position = index.to_string();
+            Some(LintDiagnostic {
+                code: LintCode::ConstantAssertion,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location: spanned
+                    .location(&["Prove", position.as_str(), "assert"])
+                    .cloned(),
+                message: format!(
+                    "Prove assertion {} {reason}, so it holds whatever the inputs are",
+                    index + 1
+                ),
+                suggestion: Some(
+                    "assert a property of the theorem's inputs, or allow constant_assertion \
+                     for a deliberate smoke theorem"
+                        .to_owned(),
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Describes why `expr` holds trivially, or returns `None` when it may not.
+fn trivial_reason(doc: &TheoremDoc, expr: &str) -> Option<&'static str> {
+    let parsed = syn::parse_str::<Expr>(expr).ok()?;
+    let assertion = without_parens(&parsed);
+    if referenced_variables(expr).is_empty() && !has_calls(assertion) {
+        return Some("is a constant expression");
+    }
+    if let Expr::Binary(binary) = assertion
+        && matches!(binary.op, BinOp::Eq(_) | BinOp::Le(_) | BinOp::Ge(_))
+        && tokens(&binary.left) == tokens(&binary.right)
+        && !has_calls(&binary.left)
+        && !reads_float(doc, &binary.left)
+    {
+        return Some("compares identical operands");
+    }
+    None
+}
+
+/// Strips the parentheses and invisible groups around `expr`.
+pub(super) fn without_parens(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => without_parens(&paren.expr),
+        Expr::Group(group) => without_parens(&group.expr),
+        other => other,
+    }
+}
+
+fn tokens(expr: &Expr) -> String {
+    without_parens(expr).to_token_stream().to_string()
+}
+
+/// Returns whether `expr` calls a function, method, or macro, whose result
+/// may differ between evaluations.
+fn has_calls(expr: &Expr) -> bool {
+    let mut calls = Calls(false);
+    calls.visit_expr(expr);
+    calls.0
+}
+
+/// Returns whether `expr` reads a floating-point `Forall` variable, which
+/// is not equal to itself when it is NaN.
+fn reads_float(doc: &TheoremDoc, expr: &Expr) -> bool {
+    referenced_variables(&tokens(expr)).iter().any(|name| {
+        doc.forall
+            .get(name.as_str())
+            .is_some_and(|rust_type| matches!(rust_type.trim(), "f32" | "f64"))
+    })
+}
+
+struct Calls(bool);
+
+impl<'ast> Visit<'ast> for Calls {
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        self.0 = true;
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.0 = true;
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_macro(&mut self, mac: &'ast ExprMacro) {
+        self.0 = true;
+        visit::visit_expr_macro(self, mac);
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_constant_tests.rs"]
+mod tests;
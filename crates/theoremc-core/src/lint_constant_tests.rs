@@ -0,0 +1,102 @@
+//! Unit tests for the constant assertion lint.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn reasons(forall: &str, assertion: &str) -> Vec<String> {
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "Forall:\n",
+            "{forall}",
+            "Prove:\n",
+            "  - assert: '{assertion}'\n",
+            "    because: checked\n",
+            "Evidence:\n",
+            "  kani: {{ unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }}\n",
+        ),
+        forall = forall,
+        assertion = assertion,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(constant_assertions)
+        .map(|lint| lint.message)
+        .collect()
+}
+
+#[rstest]
+#[case::literal("true")]
+#[case::arithmetic("1 + 1 == 2")]
+#[case::parenthesized("(u8::MAX > 0)")]
+fn constant_expressions_are_reported(#[case] assertion: &str) {
+    assert_eq!(
+        reasons("  x: u8\n", assertion),
+        ["Prove assertion 1 is a constant expression, so it holds whatever the inputs are"]
+    );
+}
+
+#[rstest]
+#[case::equal("x == x")]
+#[case::less_or_equal("(x / 2) <= (x / 2)")]
+#[case::greater_or_equal("x >= (x)")]
+fn identical_operands_are_reported(#[case] assertion: &str) {
+    assert_eq!(
+        reasons("  x: u8\n", assertion),
+        ["Prove assertion 1 compares identical operands, so it holds whatever the inputs are"]
+    );
+}
+
+#[test]
+fn operands_with_calls_are_not_reported() {
+    assert_eq!(
+        reasons("  x: u8\n", "x.count_ones() == x.count_ones()"),
+        Vec::<String>::new()
+    );
+}
+
+#[rstest]
+#[case::reads_a_variable("x > 0")]
+#[case::different_operands("x == x + 0 * x")]
+#[case::strict_comparison("x < x")]
+#[case::calls_a_function("is_ready()")]
+fn meaningful_assertions_are_not_reported(#[case] assertion: &str) {
+    assert_eq!(reasons("  x: u8\n", assertion), Vec::<String>::new());
+}
+
+#[test]
+fn float_self_comparison_is_not_reported() {
+    assert_eq!(reasons("  x: f32\n", "x == x"), Vec::<String>::new());
+}
+
+#[test]
+fn findings_are_located_at_the_assertion() {
+    let input = concat!(
+        "Theorem: T\n",
+        "About: Example\n",
+        "Prove:\n",
+        "  - assert: \"true\"\n",
+        "    because: smoke test\n",
+        "Evidence:\n",
+        "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+    );
+    let lints: Vec<LintDiagnostic> = load_spanned_theorem_docs(&SourceId::new("t.theorem"), input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(constant_assertions)
+        .collect();
+    let located: Vec<(LintCode, usize, usize)> = lints
+        .iter()
+        .filter_map(|lint| {
+            let location = lint.location.as_ref()?;
+            Some((lint.code, location.line, location.column))
+        })
+        .collect();
+
+    assert_eq!(located, [(LintCode::ConstantAssertion, 4, 13)]);
+}
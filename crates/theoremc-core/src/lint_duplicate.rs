@@ -0,0 +1,103 @@
+//! Lints for checks a section states more than once.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use quote::ToTokens;
+use syn::Expr;
+
+use super::constant::without_parens;
+use super::{LintCode, LintDiagnostic, LintLevel};
+use crate::schema::{SourceLocation, SpannedTheoremDoc, TheoremExpr};
+
+/// Reports each `Assume`, `Prove`, or `Witness` entry whose expression
+/// repeats an earlier entry of the same section once spacing and redundant
+/// outer parentheses are ignored. A repeated check usually marks a
+/// copy-paste where the copy should have said something different.
+pub(super) fn duplicate_checks(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let sections: [(&str, &str, &str, Vec<&TheoremExpr>); 3] = [
+        (
+            "Assume",
+            "expr",
+            "assumption",
+            doc.assume
+                .iter()
+                .map(|assumption| &assumption.expr)
+                .collect(),
+        ),
+        (
+            "Prove",
+            "assert",
+            "assertion",
+            doc.prove
+                .iter()
+                .map(|assertion| &assertion.assert_expr)
+                .collect(),
+        ),
+        (
+            "Witness",
+            "cover",
+            "witness",
+            doc.witness.iter().map(|witness| &witness.cover).collect(),
+        ),
+    ];
+    sections
+        .iter()
+        .flat_map(|(section, key, noun, exprs)| {
+            let locate = |index: usize| {
+                let position = index.to_string();
+                spanned
+                    .location(&[*section, position.as_str(), key])
+                    .cloned()
+            };
+            let mut first_seen: HashMap<String, usize> = HashMap::new();
+            let mut found = Vec::new();
+            for (index, expr) in exprs.iter().enumerate() {
+                let first = match first_seen.entry(normalized(expr)) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(index);
+                        continue;
+                    }
+                };
+                found.push(LintDiagnostic {
+                    code: LintCode::DuplicateCheck,
+                    level: LintLevel::Warn,
+                    theorem: doc.theorem.as_str().to_owned(),
+                    location: locate(index),
+                    message: format!(
+                        "{section} {noun} {} repeats {section} {noun} {}{}",
+                        index + 1,
+                        first + 1,
+                        locate(first).as_ref().map_or_else(String::new, at),
+                    ),
+                    suggestion: Some(format!(
+                        "remove the repeated {noun}, or correct it to the check it was meant to be"
+                    )),
+                });
+            }
+            found
+        })
+        .collect()
+}
+
+/// Returns the expression re-printed from its tokens without outer
+/// parentheses, or trimmed when it does not parse.
+fn normalized(expr: &TheoremExpr) -> String {
+    syn::parse_str::<Expr>(expr.as_str().trim()).map_or_else(
+        |_| expr.as_str().trim().to_owned(),
+        |parsed| without_parens(&parsed).to_token_stream().to_string(),
+    )
+}
+
+fn at(location: &SourceLocation) -> String {
+    format!(
+        " at {}:{}:{}",
+        location.source, location.line, location.column
+    )
+}
+
+#[cfg(test)]
+#[path = "lint_duplicate_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+//! Unit tests for the duplicate check lint.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn lints(checks: &str) -> Vec<LintDiagnostic> {
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "Forall:\n",
+            "  x: u8\n",
+            "  y: u8\n",
+            "{checks}",
+            "Evidence:\n",
+            "  kani: {{ unwind: 1, expect: SUCCESS }}\n",
+        ),
+        checks = checks,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(duplicate_checks)
+        .collect()
+}
+
+const WITNESS: &str = "Witness:\n  - cover: \"x == 1\"\n    because: reachable\n";
+
+#[rstest]
+#[case::identical("x > y", "x > y")]
+#[case::spacing("x>y", "x  >  y")]
+#[case::parentheses("x > y", "(x > y)")]
+fn repeated_assertions_are_reported(#[case] first: &str, #[case] second: &str) {
+    let checks = format!(
+        concat!(
+            "Prove:\n",
+            "  - assert: \"{first}\"\n",
+            "    because: first\n",
+            "  - assert: \"{second}\"\n",
+            "    because: second\n",
+            "{witness}",
+        ),
+        first = first,
+        second = second,
+        witness = WITNESS,
+    );
+    let messages: Vec<String> = lints(&checks)
+        .into_iter()
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        ["Prove assertion 2 repeats Prove assertion 1 at t.theorem:7:13"]
+    );
+}
+
+#[test]
+fn each_section_is_checked_and_located() {
+    let checks = concat!(
+        "Assume:\n",
+        "  - expr: \"x < 10\"\n",
+        "    because: bounded\n",
+        "  - expr: \"x < 10\"\n",
+        "    because: bounded again\n",
+        "Prove:\n",
+        "  - assert: \"x < 10\"\n",
+        "    because: restated across sections\n",
+        "Witness:\n",
+        "  - cover: \"y == 1\"\n",
+        "    because: reachable\n",
+        "  - cover: \"y==1\"\n",
+        "    because: reachable again\n",
+    );
+    let located: Vec<(LintCode, usize, usize)> = lints(checks)
+        .iter()
+        .filter_map(|lint| {
+            let location = lint.location.as_ref()?;
+            Some((lint.code, location.line, location.column))
+        })
+        .collect();
+
+    assert_eq!(
+        located,
+        [
+            (LintCode::DuplicateCheck, 9, 11),
+            (LintCode::DuplicateCheck, 17, 12),
+        ]
+    );
+}
+
+#[test]
+fn distinct_checks_are_not_reported() {
+    let prove = concat!(
+        "Prove:\n",
+        "  - assert: \"x > y\"\n",
+        "    because: first\n",
+        "  - assert: \"y > x\"\n",
+        "    because: second\n",
+    );
+
+    assert_eq!(lints(&format!("{prove}{WITNESS}")), []);
+}
@@ -0,0 +1,274 @@
+//! The set of lints a run applies, including lints defined outside this
+//! crate.
+
+use super::{
+    LintCode, LintConfig, LintConfigError, LintDiagnostic, LintError, LintLevel, because, constant,
+    duplicate, tags, unused, unwind, witness,
+};
+use crate::schema::{
+    SourceId, SourceLocation, SpannedTheoremDoc, TheoremDoc, load_spanned_theorem_docs,
+};
+
+/// A rule checked against every theorem document.
+///
+/// Findings carry [`LintCode::Custom`] with the lint's name, and are
+/// reported at the level the configuration sets for that name.
+///
+/// # Examples
+///
+///     use theoremc_core::lint::{
+///         Lint, LintCode, LintConfig, LintContext, LintDiagnostic, LintLevel, LintRegistry,
+///     };
+///     use theoremc_core::schema::{SourceId, TheoremDoc};
+///
+///     /// Requires every theorem to name its owning team in a tag.
+///     struct OwnerTag;
+///
+///     impl Lint for OwnerTag {
+///         fn name(&self) -> &'static str {
+///             "owner_tag"
+///         }
+///
+///         fn check(&self, doc: &TheoremDoc, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+///             if doc.tags.iter().any(|tag| tag.starts_with("team-")) {
+///                 return Vec::new();
+///             }
+///             vec![LintDiagnostic {
+///                 code: LintCode::Custom(self.name()),
+///                 level: LintLevel::Warn,
+///                 theorem: doc.theorem.as_str().to_owned(),
+///                 location: ctx.location(&["Theorem"]),
+///                 message: "theorem has no team- tag".to_owned(),
+///                 suggestion: Some("add a tag such as team-ledger".to_owned()),
+///             }]
+///         }
+///     }
+///
+///     let yaml = r#"Theorem: Untagged
+///     About: Has no owner
+///     Prove:
+///       - assert: "1 + 1 == 2"
+///         because: one plus one is two
+///     Evidence:
+///       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+///     Lints:
+///       constant_assertion: allow
+///     "#;
+///     let mut registry = LintRegistry::default();
+///     registry.register(OwnerTag).unwrap();
+///     let lints = registry
+///         .lint_source(&SourceId::new("t.theorem"), yaml, &LintConfig::default())
+///         .unwrap();
+///     assert_eq!(lints.len(), 1);
+///     assert_eq!(lints[0].code.as_str(), "owner_tag");
+pub trait Lint: Send + Sync {
+    /// Returns the lint's name, used in `theoremc.toml`, `Lints` sections,
+    /// and rendered findings. Names are unique within a registry.
+    fn name(&self) -> &'static str;
+
+    /// Returns the findings of the lint in `doc`.
+    fn check(&self, doc: &TheoremDoc, ctx: &LintContext<'_>) -> Vec<LintDiagnostic>;
+}
+
+/// What a lint may consult besides the document it checks.
+#[derive(Debug, Clone, Copy)]
+pub struct LintContext<'a> {
+    spanned: &'a SpannedTheoremDoc,
+    config: &'a LintConfig,
+}
+
+impl<'a> LintContext<'a> {
+    /// Returns where the value at `path` is written, as described by
+    /// [`SpannedTheoremDoc::location`], or `None` when it is inherited.
+    #[must_use]
+    pub fn location(&self, path: &[&str]) -> Option<SourceLocation> {
+        self.spanned.location(path).cloned()
+    }
+
+    /// Returns the document with the locations of its values.
+    #[must_use]
+    pub const fn spanned(&self) -> &'a SpannedTheoremDoc {
+        self.spanned
+    }
+
+    /// Returns the configuration in force for the document, including its
+    /// `Lints` section.
+    #[must_use]
+    pub const fn config(&self) -> &'a LintConfig {
+        self.config
+    }
+}
+
+/// The lints a run applies, in the order their findings are reported.
+///
+/// The default registry holds the built-in lints; [`LintRegistry::register`]
+/// adds more, so a tool built on this crate can run organization-specific
+/// rules alongside them.
+pub struct LintRegistry {
+    lints: Vec<Box<dyn Lint>>,
+}
+
+impl Default for LintRegistry {
+    fn default() -> Self {
+        let builtins = [
+            Builtin(LintCode::UnusedForall, |ctx| {
+                unused::unused_forall(ctx.spanned)
+            }),
+            Builtin(LintCode::UnusedLet, |ctx| unused::unused_let(ctx.spanned)),
+            Builtin(LintCode::UnusedAs, |ctx| unused::unused_as(ctx.spanned)),
+            Builtin(LintCode::ConstantAssertion, |ctx| {
+                constant::constant_assertions(ctx.spanned)
+            }),
+            Builtin(LintCode::DuplicateCheck, |ctx| {
+                duplicate::duplicate_checks(ctx.spanned)
+            }),
+            Builtin(LintCode::AssumedWitness, |ctx| {
+                witness::assumed_witnesses(ctx.spanned)
+            }),
+            Builtin(LintCode::UnwindBound, |ctx| {
+                unwind::unwind_bounds(ctx.spanned, ctx.config)
+            }),
+            Builtin(LintCode::PlaceholderBecause, |ctx| {
+                because::placeholder_because(ctx.spanned, ctx.config)
+            }),
+            Builtin(LintCode::TagStyle, |ctx| {
+                tags::tag_styles(ctx.spanned, ctx.config)
+            }),
+            Builtin(LintCode::RequiredTag, |ctx| {
+                tags::required_tags(ctx.spanned, ctx.config)
+            }),
+        ];
+        Self {
+            lints: builtins
+                .into_iter()
+                .map(|builtin| Box::new(builtin) as Box<dyn Lint>)
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Debug for LintRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl LintRegistry {
+    /// Returns a registry holding no lints.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self { lints: Vec::new() }
+    }
+
+    /// Adds `lint`, whose findings follow those of the lints already
+    /// registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintConfigError::DuplicateLint`] when a registered lint
+    /// already uses the name.
+    pub fn register(&mut self, lint: impl Lint + 'static) -> Result<(), LintConfigError> {
+        if self.code(lint.name()).is_some() {
+            return Err(LintConfigError::DuplicateLint {
+                name: lint.name().to_owned(),
+            });
+        }
+        self.lints.push(Box::new(lint));
+        Ok(())
+    }
+
+    /// Returns the names of the registered lints, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.lints.iter().map(|lint| lint.name())
+    }
+
+    /// Returns the code of the registered lint named `name`.
+    #[must_use]
+    pub fn code(&self, name: &str) -> Option<LintCode> {
+        self.names()
+            .find(|registered| *registered == name)
+            .map(|registered| {
+                LintCode::from_name(registered).unwrap_or(LintCode::Custom(registered))
+            })
+    }
+
+    /// Reads a `theoremc.toml` file like [`LintConfig::from_toml`],
+    /// accepting the names of the registered lints.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LintConfig::from_toml`].
+    pub fn config_from_toml(
+        &self,
+        source: &SourceId,
+        input: &str,
+    ) -> Result<LintConfig, LintConfigError> {
+        LintConfig::read_toml(source, input, self)
+    }
+
+    /// Runs every registered lint over one theorem document at the levels
+    /// of `config` refined by the document's `Lints` section, dropping the
+    /// findings of allowed lints.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LintConfigError`] when the `Lints` section names a lint
+    /// that is not registered, or an unknown level.
+    pub fn lint_theorem(
+        &self,
+        doc: &SpannedTheoremDoc,
+        config: &LintConfig,
+    ) -> Result<Vec<LintDiagnostic>, LintConfigError> {
+        let theorem_config = config.for_theorem(doc, self)?;
+        let ctx = LintContext {
+            spanned: doc,
+            config: &theorem_config,
+        };
+        Ok(self
+            .lints
+            .iter()
+            .flat_map(|lint| lint.check(doc.doc(), &ctx))
+            .filter_map(|finding| match theorem_config.level(finding.code) {
+                LintLevel::Allow => None,
+                level => Some(LintDiagnostic { level, ..finding }),
+            })
+            .collect())
+    }
+
+    /// Loads the theorem documents of `input` and lints each with the
+    /// registered lints, returning the findings in document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`lint_source`](super::lint_source).
+    pub fn lint_source(
+        &self,
+        source: &SourceId,
+        input: &str,
+        config: &LintConfig,
+    ) -> Result<Vec<LintDiagnostic>, LintError> {
+        let docs = load_spanned_theorem_docs(source, input)?;
+        let mut findings = Vec::new();
+        for doc in &docs {
+            findings.extend(self.lint_theorem(doc, config)?);
+        }
+        Ok(findings)
+    }
+}
+
+/// A built-in lint and the function that checks it.
+struct Builtin(LintCode, fn(&LintContext<'_>) -> Vec<LintDiagnostic>);
+
+impl Lint for Builtin {
+    fn name(&self) -> &'static str {
+        self.0.as_str()
+    }
+
+    fn check(&self, _doc: &TheoremDoc, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        (self.1)(ctx)
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_registry_tests.rs"]
+mod tests;
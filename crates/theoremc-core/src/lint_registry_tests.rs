@@ -0,0 +1,165 @@
+//! Unit tests for the lint registry and custom lints.
+
+use pretty_assertions::assert_eq;
+
+use super::*;
+
+const TAGGED: &str = concat!(
+    "Theorem: T\n",
+    "About: Example\n",
+    "Tags: [ledger]\n",
+    "Prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+/// Reports every theorem, located at its `Tags` section.
+struct EveryTheorem;
+
+impl Lint for EveryTheorem {
+    fn name(&self) -> &'static str {
+        "every_theorem"
+    }
+
+    fn check(&self, doc: &TheoremDoc, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        vec![LintDiagnostic {
+            code: LintCode::Custom(self.name()),
+            level: LintLevel::Warn,
+            theorem: doc.theorem.as_str().to_owned(),
+            location: ctx.location(&["Tags"]),
+            message: format!("theorem has {} tags", doc.tags.len()),
+            suggestion: None,
+        }]
+    }
+}
+
+/// A custom lint reusing the name of a built-in lint.
+struct Impostor;
+
+impl Lint for Impostor {
+    fn name(&self) -> &'static str {
+        "unused_let"
+    }
+
+    fn check(&self, _doc: &TheoremDoc, _ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        Vec::new()
+    }
+}
+
+fn custom_registry() -> LintRegistry {
+    let mut registry = LintRegistry::empty();
+    registry
+        .register(EveryTheorem)
+        .expect("the name should be free");
+    registry
+}
+
+#[test]
+fn default_registry_holds_the_built_in_lints_in_order() {
+    let names: Vec<&str> = LintRegistry::default().names().collect();
+    let builtins: Vec<&str> = LintCode::ALL.iter().map(|code| code.as_str()).collect();
+
+    assert_eq!(names, builtins);
+}
+
+#[test]
+fn custom_lints_run_after_the_built_in_lints() {
+    let mut registry = LintRegistry::default();
+    registry
+        .register(EveryTheorem)
+        .expect("the name should be free");
+    let lints = registry
+        .lint_source(&SourceId::new("t.theorem"), TAGGED, &LintConfig::default())
+        .expect("source should load");
+    let codes: Vec<LintCode> = lints.iter().map(|lint| lint.code).collect();
+
+    assert_eq!(
+        codes,
+        [
+            LintCode::ConstantAssertion,
+            LintCode::Custom("every_theorem")
+        ]
+    );
+    assert_eq!(
+        lints.last().map(LintDiagnostic::render).as_deref(),
+        Some("warn | every_theorem | t.theorem:3:7 | theorem has 1 tags")
+    );
+}
+
+#[test]
+fn lints_sections_set_the_level_of_custom_lints() {
+    let input = format!("{TAGGED}Lints:\n  every_theorem: deny\n");
+    let lints = custom_registry()
+        .lint_source(&SourceId::new("t.theorem"), &input, &LintConfig::default())
+        .expect("source should load");
+    let levels: Vec<LintLevel> = lints.iter().map(|lint| lint.level).collect();
+
+    assert_eq!(levels, [LintLevel::Deny]);
+}
+
+#[test]
+fn configuration_files_set_the_level_of_custom_lints() {
+    let registry = custom_registry();
+    let config = registry
+        .config_from_toml(
+            &SourceId::new("theoremc.toml"),
+            "[lints]\nevery_theorem = \"allow\"\n",
+        )
+        .expect("configuration should parse");
+    let lints = registry
+        .lint_source(&SourceId::new("t.theorem"), TAGGED, &config)
+        .expect("source should load");
+
+    assert_eq!(lints, []);
+}
+
+#[test]
+fn unregistered_custom_lints_are_unknown() {
+    let error = LintRegistry::default()
+        .config_from_toml(
+            &SourceId::new("theoremc.toml"),
+            "[lints]\nevery_theorem = \"allow\"\n",
+        )
+        .expect_err("the lint is not registered");
+
+    assert!(
+        matches!(&error, LintConfigError::UnknownLint { name, .. } if name == "every_theorem"),
+        "{error}"
+    );
+}
+
+#[test]
+fn registering_a_taken_name_is_an_error() {
+    let mut registry = custom_registry();
+    let custom = registry
+        .register(EveryTheorem)
+        .expect_err("the name is taken");
+    let builtin = LintRegistry::default()
+        .register(Impostor)
+        .expect_err("the name is taken");
+
+    assert_eq!(
+        [custom.to_string(), builtin.to_string()],
+        [
+            "a lint named 'every_theorem' is already registered",
+            "a lint named 'unused_let' is already registered",
+        ]
+    );
+}
+
+#[test]
+fn registered_names_resolve_to_codes() {
+    let registry = custom_registry();
+
+    assert_eq!(
+        registry.code("every_theorem"),
+        Some(LintCode::Custom("every_theorem"))
+    );
+    assert_eq!(registry.code("unused_let"), None);
+    assert_eq!(
+        LintRegistry::default().code("unused_let"),
+        Some(LintCode::UnusedLet)
+    );
+}
@@ -0,0 +1,188 @@
+//! Lints for tag conventions that corpora slicing runs by tag rely on.
+
+use super::{LintCode, LintConfig, LintDiagnostic, LintLevel};
+use crate::schema::{SourceLocation, SpannedTheoremDoc};
+
+impl LintConfig {
+    /// Sets the tag patterns `tag_style` accepts. A pattern ending in `*`
+    /// matches the tags starting with the text before it, and any other
+    /// pattern matches only itself. With no patterns, every kebab-case tag
+    /// is accepted.
+    #[must_use]
+    pub fn with_allowed_tags(mut self, patterns: Vec<String>) -> Self {
+        self.allowed_tags = patterns;
+        self
+    }
+
+    /// Returns the tag patterns `tag_style` accepts.
+    #[must_use]
+    pub fn allowed_tags(&self) -> &[String] {
+        &self.allowed_tags
+    }
+
+    /// Sets the tag patterns, written as for
+    /// [`LintConfig::with_allowed_tags`], that `required_tag` expects each
+    /// theorem's tags to match exactly once.
+    #[must_use]
+    pub fn with_required_tags(mut self, patterns: Vec<String>) -> Self {
+        self.required_tags = patterns;
+        self
+    }
+
+    /// Returns the tag patterns `required_tag` expects each theorem's tags
+    /// to match exactly once.
+    #[must_use]
+    pub fn required_tags(&self) -> &[String] {
+        &self.required_tags
+    }
+}
+
+/// Reports each tag that is not kebab-case, with `:` separating the
+/// segments of a namespaced tag such as `component:ledger`, or that
+/// matches none of the configured allowed tag patterns.
+pub(super) fn tag_styles(spanned: &SpannedTheoremDoc, config: &LintConfig) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let allowed = config.allowed_tags();
+    doc.tags
+        .iter()
+        .enumerate()
+        .filter_map(|(index, tag)| {
+            let (message, suggestion) = if !is_kebab_case(tag) {
+                (
+                    format!("tag '{tag}' is not kebab-case"),
+                    format!("rename it to '{}'", kebab_case(tag)),
+                )
+            } else if !allowed.is_empty() && !allowed.iter().any(|pattern| matches(pattern, tag)) {
+                (
+                    format!("tag '{tag}' matches no allowed tag pattern"),
+                    format!("use a tag matching one of {}", allowed.join(", ")),
+                )
+            } else {
+                return None;
+            };
+            let position = index.to_string();
+            Some(LintDiagnostic {
+                code: LintCode::TagStyle,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location: spanned.location(&["Tags", position.as_str()]).cloned(),
+                message,
+                suggestion: Some(suggestion),
+            })
+        })
+        .collect()
+}
+
+/// Reports each configured required tag pattern that the theorem's tags
+/// match other than exactly once.
+pub(super) fn required_tags(
+    spanned: &SpannedTheoremDoc,
+    config: &LintConfig,
+) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    config
+        .required_tags()
+        .iter()
+        .filter_map(|pattern| {
+            let matching: Vec<usize> = doc
+                .tags
+                .iter()
+                .enumerate()
+                .filter(|(_, tag)| matches(pattern, tag))
+                .map(|(index, _)| index)
+                .collect();
+            let (message, location) = match matching.as_slice() {
+                [_] => return None,
+                [] => (
+                    format!("theorem has no tag matching '{pattern}'"),
+                    tags_location(spanned),
+                ),
+                [_, second, ..] => {
+                    let position = second.to_string();
+                    (
+                        format!(
+                            "theorem has {} tags matching '{pattern}', but needs exactly one",
+                            matching.len()
+                        ),
+                        spanned
+                            .location(&["Tags", position.as_str()])
+                            .cloned()
+                            .or_else(|| tags_location(spanned)),
+                    )
+                }
+            };
+            Some(LintDiagnostic {
+                code: LintCode::RequiredTag,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location,
+                message,
+                suggestion: Some(format!("tag the theorem with exactly one '{pattern}' tag")),
+            })
+        })
+        .collect()
+}
+
+/// Returns whether `tag` matches `pattern`, which matches tags starting
+/// with its text before a trailing `*`, or otherwise only itself.
+fn matches(pattern: &str, tag: &str) -> bool {
+    pattern
+        .strip_suffix('*')
+        .map_or(tag == pattern, |prefix| tag.starts_with(prefix))
+}
+
+/// Returns where the theorem's tags are written, or the theorem name when
+/// it declares none of its own.
+fn tags_location(spanned: &SpannedTheoremDoc) -> Option<SourceLocation> {
+    spanned
+        .location(&["Tags"])
+        .or_else(|| spanned.location(&["Theorem"]))
+        .cloned()
+}
+
+/// Returns whether every `:`-separated segment of `tag` is lower-case
+/// words of ASCII letters and digits joined by single hyphens.
+fn is_kebab_case(tag: &str) -> bool {
+    tag.split(':').all(|segment| {
+        segment.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|character| character.is_ascii_lowercase() || character.is_ascii_digit())
+        })
+    })
+}
+
+/// Suggests a kebab-case spelling of `tag`.
+fn kebab_case(tag: &str) -> String {
+    tag.split(':')
+        .map(|segment| {
+            segment
+                .split(|character: char| !character.is_ascii_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(split_camel_case)
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Lowercases `word`, hyphenating wherever a lowercase letter is followed by
+/// an uppercase one, so `FooBar` becomes `foo-bar`.
+fn split_camel_case(word: &str) -> String {
+    let mut kebab = String::with_capacity(word.len());
+    let mut previous_lowercase = false;
+    for character in word.chars() {
+        if previous_lowercase && character.is_ascii_uppercase() {
+            kebab.push('-');
+        }
+        previous_lowercase = character.is_ascii_lowercase();
+        kebab.push(character.to_ascii_lowercase());
+    }
+    kebab
+}
+
+#[cfg(test)]
+#[path = "lint_tags_tests.rs"]
+mod tests;
@@ -0,0 +1,128 @@
+//! Unit tests for the tag lints.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn docs(tags: &str) -> Vec<SpannedTheoremDoc> {
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "Tags: {tags}\n",
+            "Prove:\n",
+            "  - assert: \"1 + 1 == 2\"\n",
+            "    because: arithmetic holds\n",
+            "Evidence:\n",
+            "  kani: {{ unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }}\n",
+        ),
+        tags = tags,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input).expect("theorem should load")
+}
+
+fn messages(lints: Vec<LintDiagnostic>) -> Vec<String> {
+    lints.into_iter().map(|lint| lint.message).collect()
+}
+
+fn style_lints(tags: &str, config: &LintConfig) -> Vec<LintDiagnostic> {
+    docs(tags)
+        .iter()
+        .flat_map(|doc| tag_styles(doc, config))
+        .collect()
+}
+
+fn required_lints(tags: &str, config: &LintConfig) -> Vec<LintDiagnostic> {
+    docs(tags)
+        .iter()
+        .flat_map(|doc| required_tags(doc, config))
+        .collect()
+}
+
+#[rstest]
+#[case::upper_case("Ledger", "ledger")]
+#[case::underscores("fast_path", "fast-path")]
+#[case::namespaced("component:Ledger_Core", "component:ledger-core")]
+#[case::doubled_hyphen("slow--path", "slow-path")]
+#[case::mixed_case("FooBar", "foo-bar")]
+#[case::namespaced_mixed_case("component:LedgerCore_v2", "component:ledger-core-v2")]
+fn tags_that_are_not_kebab_case_are_reported(#[case] tag: &str, #[case] suggestion: &str) {
+    let lints = style_lints(&format!("[smoke, \"{tag}\"]"), &LintConfig::default());
+
+    assert_eq!(
+        lints
+            .iter()
+            .map(|lint| (lint.message.as_str(), lint.suggestion.as_deref()))
+            .collect::<Vec<_>>(),
+        [(
+            format!("tag '{tag}' is not kebab-case").as_str(),
+            Some(format!("rename it to '{suggestion}'").as_str())
+        )]
+    );
+    assert_eq!(
+        lints
+            .first()
+            .and_then(|lint| lint.location.as_ref())
+            .map(|location| (location.line, location.column)),
+        Some((3, 15))
+    );
+}
+
+#[test]
+fn kebab_case_tags_pass_without_allowed_patterns() {
+    assert_eq!(
+        style_lints("[smoke, component:ledger, team-42]", &LintConfig::default()),
+        []
+    );
+}
+
+#[test]
+fn tags_outside_the_allowed_patterns_are_reported() {
+    let config =
+        LintConfig::default().with_allowed_tags(vec!["component:*".to_owned(), "smoke".to_owned()]);
+
+    assert_eq!(
+        messages(style_lints(
+            "[smoke, component:ledger, slow, smoke-test]",
+            &config
+        )),
+        [
+            "tag 'slow' matches no allowed tag pattern",
+            "tag 'smoke-test' matches no allowed tag pattern",
+        ]
+    );
+}
+
+#[rstest]
+#[case::exactly_one("[smoke, component:ledger]", &[])]
+#[case::missing("[smoke]", &["theorem has no tag matching 'component:*'"])]
+#[case::no_tags("[]", &["theorem has no tag matching 'component:*'"])]
+#[case::several(
+    "[component:ledger, component:audit]",
+    &["theorem has 2 tags matching 'component:*', but needs exactly one"]
+)]
+fn required_tags_must_match_exactly_once(#[case] tags: &str, #[case] expected: &[&str]) {
+    let config = LintConfig::default().with_required_tags(vec!["component:*".to_owned()]);
+
+    assert_eq!(messages(required_lints(tags, &config)), expected);
+}
+
+#[test]
+fn repeated_required_tags_are_located_at_the_second_match() {
+    let config = LintConfig::default().with_required_tags(vec!["component:*".to_owned()]);
+    let locations: Vec<(usize, usize)> =
+        required_lints("[component:ledger, component:audit]", &config)
+            .iter()
+            .filter_map(|lint| lint.location.as_ref())
+            .map(|location| (location.line, location.column))
+            .collect();
+
+    assert_eq!(locations, [(3, 26)]);
+}
+
+#[test]
+fn no_tags_are_required_by_default() {
+    assert_eq!(required_lints("[]", &LintConfig::default()), []);
+}
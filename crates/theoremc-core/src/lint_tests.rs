@@ -0,0 +1,127 @@
+//! Unit tests for lint reporting.
+
+use pretty_assertions::assert_eq;
+
+use super::*;
+
+const UNUSED: &str = concat!(
+    "Theorem: T\n",
+    "About: Example\n",
+    "Forall:\n",
+    "  x: u8\n",
+    "Prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+#[test]
+fn lint_source_locates_findings() {
+    let config = LintConfig::default().with_level(LintCode::ConstantAssertion, LintLevel::Allow);
+    let lints = lint_source_with_config(&SourceId::new("t.theorem"), UNUSED, &config)
+        .expect("source should load");
+    let rendered: Vec<String> = lints.iter().map(LintDiagnostic::render).collect();
+
+    assert_eq!(
+        rendered,
+        [concat!(
+            "warn | unused_forall | t.theorem:4:6 | Forall variable 'x' is never referenced; ",
+            "it enlarges the state space Kani explores for no benefit ",
+            "| help: remove 'x' from Forall",
+        )]
+    );
+}
+
+#[test]
+fn findings_without_a_location_render_without_one() {
+    let lint = LintDiagnostic {
+        code: LintCode::UnusedForall,
+        level: LintLevel::Warn,
+        theorem: "T".to_owned(),
+        location: None,
+        message: "unused".to_owned(),
+        suggestion: None,
+    };
+
+    assert_eq!(lint.render(), "warn | unused_forall | unused");
+}
+
+#[test]
+fn sources_that_do_not_load_are_errors() {
+    let error = lint_source(&SourceId::new("t.theorem"), "Theorem: [")
+        .expect_err("malformed YAML should fail");
+
+    assert!(
+        matches!(&error, LintError::Schema(schema) if schema.diagnostic().is_some()),
+        "{error}"
+    );
+}
+
+#[test]
+fn findings_of_every_lint_are_reported_by_default() {
+    let lints = lint_source(&SourceId::new("t.theorem"), UNUSED).expect("source should load");
+    let codes: Vec<LintCode> = lints.iter().map(|lint| lint.code).collect();
+
+    assert_eq!(codes, [LintCode::UnusedForall, LintCode::ConstantAssertion]);
+}
+
+#[test]
+fn config_levels_default_to_warn() {
+    let config = LintConfig::default().with_level(LintCode::UnusedLet, LintLevel::Allow);
+
+    assert_eq!(config.level(LintCode::UnusedLet), LintLevel::Allow);
+    assert_eq!(config.level(LintCode::UnusedForall), LintLevel::Warn);
+}
+
+#[test]
+fn lints_sections_refine_the_configuration() {
+    let input = format!("{UNUSED}Lints:\n  unused_forall: deny\n  constant_assertion: allow\n");
+    let lints = lint_source(&SourceId::new("t.theorem"), &input).expect("source should load");
+    let levels: Vec<(LintCode, LintLevel)> =
+        lints.iter().map(|lint| (lint.code, lint.level)).collect();
+
+    assert_eq!(levels, [(LintCode::UnusedForall, LintLevel::Deny)]);
+}
+
+#[test]
+fn theorem_levels_override_inherited_and_configured_levels() {
+    let input = format!(
+        concat!(
+            "Defaults:\n",
+            "  Lints: {{ unused_forall: allow, constant_assertion: deny }}\n",
+            "---\n",
+            "{}",
+            "Lints:\n",
+            "  constant_assertion: warn\n",
+        ),
+        UNUSED
+    );
+    let config = LintConfig::default().with_level(LintCode::UnusedForall, LintLevel::Deny);
+    let lints = lint_source_with_config(&SourceId::new("t.theorem"), &input, &config)
+        .expect("source should load");
+    let levels: Vec<(LintCode, LintLevel)> =
+        lints.iter().map(|lint| (lint.code, lint.level)).collect();
+
+    assert_eq!(levels, [(LintCode::ConstantAssertion, LintLevel::Warn)]);
+}
+
+#[test]
+fn unknown_lints_in_lints_sections_are_errors() {
+    let input = format!("{UNUSED}Lints:\n  unused_forrall: allow\n");
+    let error =
+        lint_source(&SourceId::new("t.theorem"), &input).expect_err("unknown lint should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "t.theorem:11:19: unknown lint 'unused_forrall'"
+    );
+}
+
+#[test]
+fn lint_names_round_trip() {
+    for code in LintCode::ALL {
+        assert_eq!(LintCode::from_name(code.as_str()), Some(code));
+    }
+    assert_eq!(LintCode::from_name("unused"), None);
+}
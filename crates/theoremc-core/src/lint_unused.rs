@@ -0,0 +1,165 @@
+//! Lints for declarations nothing reads.
+
+use std::collections::HashSet;
+
+use super::{LintCode, LintDiagnostic, LintLevel};
+use crate::schema::{
+    ActionCall, SpannedTheoremDoc, Step, StepPath, StepVisitor, TheoremDoc, arg_variables,
+    referenced_variables, walk_theorem,
+};
+
+/// Reports each `Forall` variable the theorem never reads. Every value of
+/// such a variable is explored by Kani without affecting the result.
+pub(super) fn unused_forall(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let used = forall_uses(doc);
+    doc.forall
+        .keys()
+        .filter(|name| !used.contains(name.as_str()))
+        .map(|name| LintDiagnostic {
+            code: LintCode::UnusedForall,
+            level: LintLevel::Warn,
+            theorem: doc.theorem.as_str().to_owned(),
+            location: spanned.location(&["Forall", name.as_str()]).cloned(),
+            message: format!(
+                "Forall variable '{name}' is never referenced; \
+                 it enlarges the state space Kani explores for no benefit"
+            ),
+            suggestion: Some(format!("remove '{name}' from Forall")),
+        })
+        .collect()
+}
+
+/// Reports each `Let` binding nothing after it reads.
+pub(super) fn unused_let(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let used: HashSet<String> = referenced_names(doc).into_iter().collect();
+    doc.let_bindings
+        .keys()
+        .filter(|name| !used.contains(name.as_str()))
+        .map(|name| LintDiagnostic {
+            code: LintCode::UnusedLet,
+            level: LintLevel::Warn,
+            theorem: doc.theorem.as_str().to_owned(),
+            location: spanned.location(&["Let", name.as_str()]).cloned(),
+            message: format!("Let binding '{name}' is never referenced"),
+            suggestion: Some(format!(
+                "remove '{name}', or make its call a Do step if only its effect matters"
+            )),
+        })
+        .collect()
+}
+
+/// Reports each `as` binding of a `Do` step that no later step, argument,
+/// or expression reads.
+pub(super) fn unused_as(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let used: HashSet<String> = referenced_names(doc).into_iter().collect();
+    let mut bindings = Vec::new();
+    as_bindings(&doc.do_steps, &["Do".to_owned()], &mut bindings);
+    bindings
+        .into_iter()
+        .filter(|(_, name)| !used.contains(*name))
+        .map(|(path, name)| {
+            let segments: Vec<&str> = path.iter().map(String::as_str).collect();
+            LintDiagnostic {
+                code: LintCode::UnusedAs,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location: spanned.location(&segments).cloned(),
+                message: format!("as binding '{name}' is never referenced"),
+                suggestion: Some(format!("remove `as: {name}` from the step")),
+            }
+        })
+        .collect()
+}
+
+/// Collects the `as` binding of each step in `steps` and their nested
+/// blocks, with the source path of the `as` value.
+fn as_bindings<'d>(steps: &'d [Step], path: &[String], found: &mut Vec<(Vec<String>, &'d str)>) {
+    for (index, step) in steps.iter().enumerate() {
+        let mut step_path = path.to_vec();
+        step_path.extend([index.to_string(), step.keyword().to_owned()]);
+        if let Some(binding) = step.as_binding() {
+            let mut binding_path = step_path.clone();
+            binding_path.push("as".to_owned());
+            found.push((binding_path, binding));
+        }
+        for (branch, nested) in step.nested_branches() {
+            let mut nested_path = step_path.clone();
+            nested_path.push(branch.to_owned());
+            as_bindings(nested, &nested_path, found);
+        }
+    }
+}
+
+/// Returns the `Forall` variables the theorem reads, counting the variables
+/// a derived variable's `from` expression reads when the derived variable
+/// is itself read.
+fn forall_uses(doc: &TheoremDoc) -> HashSet<String> {
+    let mut pending = referenced_names(doc);
+    let mut used = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !doc.forall.contains_key(name.as_str()) || !used.insert(name.clone()) {
+            continue;
+        }
+        let derived = doc
+            .forall_domains
+            .get(name.as_str())
+            .and_then(|domain| domain.derived_expr());
+        pending.extend(derived.map_or_else(Vec::new, |expr| referenced_variables(expr.trim())));
+    }
+    used
+}
+
+/// Returns the free variables of every `Assume`, `Prove`, and `Witness`
+/// expression, action argument, inline `assert` step, and `foreach`
+/// sequence.
+fn referenced_names(doc: &TheoremDoc) -> Vec<String> {
+    let mut references = References::default();
+    walk_theorem(doc, &mut references);
+    let expressions = doc
+        .assume
+        .iter()
+        .map(|assumption| assumption.expr.as_str())
+        .chain(
+            doc.prove
+                .iter()
+                .map(|assertion| assertion.assert_expr.as_str()),
+        )
+        .chain(doc.witness.iter().map(|witness| witness.cover.as_str()));
+    let mut names = references.0;
+    names.extend(expressions.flat_map(|expr| referenced_variables(expr.trim())));
+    names
+}
+
+/// The names read by action arguments, inline `assert` steps, and the
+/// sequences `foreach` steps iterate.
+#[derive(Default)]
+struct References(Vec<String>);
+
+impl<'doc> StepVisitor<'doc> for References {
+    fn enter_step(&mut self, step: &'doc Step, _path: &StepPath) {
+        match step {
+            Step::Assert(step_assert) => self
+                .0
+                .extend(referenced_variables(step_assert.assert.expr.trim())),
+            Step::Foreach(step_foreach) => {
+                self.0.push(step_foreach.foreach.source.trim().to_owned());
+            }
+            Step::Call(_)
+            | Step::Must(_)
+            | Step::ExpectErr(_)
+            | Step::Maybe(_)
+            | Step::Repeat(_) => {}
+        }
+    }
+
+    fn visit_action_call(&mut self, call: &'doc ActionCall) {
+        self.0.extend(call.args.values().flat_map(arg_variables));
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_unused_tests.rs"]
+mod tests;
@@ -0,0 +1,211 @@
+//! Unit tests for the unused declaration lints.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn theorem(body: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "{body}",
+            "Evidence:\n",
+            "  kani: {{ unwind: 4, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }}\n",
+        ),
+        body = body,
+    )
+}
+
+fn unused_names(input: &str) -> Vec<String> {
+    let docs =
+        load_spanned_theorem_docs(&SourceId::new("t.theorem"), input).expect("theorem should load");
+    docs.iter()
+        .flat_map(unused_forall)
+        .map(|lint| {
+            lint.message
+                .split('\'')
+                .nth(1)
+                .expect("message names the variable")
+                .to_owned()
+        })
+        .collect()
+}
+
+const PROVE_TRUE: &str = "Prove:\n  - assert: \"true\"\n    because: trivially true\n";
+
+#[rstest]
+#[case::assume("Forall:\n  x: u8\nAssume:\n  - expr: \"x > 0\"\n    because: positive\n")]
+#[case::witness("Forall:\n  x: u8\nWitness:\n  - cover: \"x == 1\"\n    because: reachable\n")]
+#[case::let_argument(concat!(
+    "Forall:\n  x: u8\n",
+    "Actions:\n  counter.new: { params: { start: u8 }, returns: u8 }\n",
+    "Let:\n  counter: { call: { action: counter.new, args: { start: { ref: x } } } }\n",
+))]
+#[case::nested_step_expression(concat!(
+    "Forall:\n  x: u8\n",
+    "Actions:\n  counter.bump: { params: { by: u8 } }\n",
+    "Do:\n",
+    "  - repeat:\n",
+    "      times: 2\n",
+    "      do:\n",
+    "        - call: { action: counter.bump, args: { by: { expr: \"x + 1\" } } }\n",
+))]
+#[case::foreach_source(concat!(
+    "Forall:\n  items: { vec: u8, len: { max: 2 } }\n",
+    "Do:\n",
+    "  - foreach:\n",
+    "      in: items\n",
+    "      as: item\n",
+    "      do:\n",
+    "        - assert: { expr: \"item >= 0\", because: unsigned }\n",
+))]
+fn referenced_variables_are_used(#[case] body: &str) {
+    let input = theorem(&format!("{body}{PROVE_TRUE}"));
+
+    assert_eq!(unused_names(&input), Vec::<String>::new());
+}
+
+#[test]
+fn unreferenced_variables_are_reported_in_declaration_order() {
+    let input = theorem(concat!(
+        "Forall:\n  a: u8\n  b: u8\n  c: u8\n",
+        "Prove:\n  - assert: \"b == b\"\n    because: reflexive\n",
+    ));
+
+    assert_eq!(unused_names(&input), ["a", "c"]);
+}
+
+#[test]
+fn closure_parameters_do_not_count_as_uses() {
+    let input = theorem(concat!(
+        "Forall:\n  x: u8\n",
+        "Prove:\n  - assert: \"(|x: u8| x == x)(0)\"\n    because: reflexive\n",
+    ));
+
+    assert_eq!(unused_names(&input), ["x"]);
+}
+
+#[rstest]
+#[case::derived_variable_read("total == total", Vec::<&str>::new())]
+#[case::derived_variable_unread("true", vec!["a", "b", "total"])]
+fn derived_variables_use_their_inputs_only_when_read(
+    #[case] assertion: &str,
+    #[case] expected: Vec<&str>,
+) {
+    let input = theorem(&format!(
+        concat!(
+            "Forall:\n",
+            "  a: u8\n",
+            "  b: u8\n",
+            "  total: {{ type: u16, from: \"u16::from(a) + u16::from(b)\" }}\n",
+            "Prove:\n  - assert: \"{assertion}\"\n    because: checked\n",
+        ),
+        assertion = assertion,
+    ));
+
+    assert_eq!(unused_names(&input), expected);
+}
+
+const BINDINGS: &str = concat!(
+    "Actions:\n",
+    "  ledger.open: { returns: u64 }\n",
+    "  ledger.close: { params: { id: u64 } }\n",
+    "Let:\n",
+    "  kept: { call: { action: ledger.open, args: {} } }\n",
+    "  spare: { call: { action: ledger.open, args: {} } }\n",
+    "Do:\n",
+    "  - call: { action: ledger.close, args: { id: { ref: kept } } }\n",
+    "  - maybe:\n",
+    "      because: opening again is optional\n",
+    "      do:\n",
+    "        - call: { action: ledger.open, args: {}, as: reopened }\n",
+    "  - call: { action: ledger.open, args: {}, as: checked }\n",
+    "Prove:\n",
+    "  - assert: \"checked >= 0\"\n",
+    "    because: unsigned\n",
+);
+
+fn lints(input: &str, lint: fn(&SpannedTheoremDoc) -> Vec<LintDiagnostic>) -> Vec<LintDiagnostic> {
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(lint)
+        .collect()
+}
+
+#[test]
+fn unused_let_bindings_are_located_at_the_binding() {
+    let found = lints(&theorem(BINDINGS), unused_let);
+    let summary: Vec<(LintCode, usize, &str)> = found
+        .iter()
+        .map(|lint| {
+            let location = lint.location.as_ref().expect("binding is written");
+            (lint.code, location.line, lint.message.as_str())
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        [(
+            LintCode::UnusedLet,
+            8,
+            "Let binding 'spare' is never referenced"
+        )]
+    );
+    assert!(
+        found
+            .first()
+            .and_then(|lint| lint.suggestion.as_deref())
+            .is_some_and(|suggestion| suggestion.starts_with("remove 'spare'"))
+    );
+}
+
+#[test]
+fn unused_as_bindings_are_found_in_nested_steps() {
+    let found = lints(&theorem(BINDINGS), unused_as);
+    let summary: Vec<(usize, usize, &str)> = found
+        .iter()
+        .map(|lint| {
+            let location = lint.location.as_ref().expect("binding is written");
+            (location.line, location.column, lint.message.as_str())
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        [(14, 54, "as binding 'reopened' is never referenced")]
+    );
+    assert_eq!(
+        found.first().and_then(|lint| lint.suggestion.as_deref()),
+        Some("remove `as: reopened` from the step")
+    );
+}
+
+#[rstest]
+#[case::later_let("  second: { call: { action: ledger.close, args: { id: { ref: first } } } }\n")]
+#[case::witness("")]
+fn bindings_read_anywhere_later_are_used(#[case] second: &str) {
+    let input = theorem(&format!(
+        concat!(
+            "Actions:\n",
+            "  ledger.open: {{ returns: u64 }}\n",
+            "  ledger.close: {{ params: {{ id: u64 }} }}\n",
+            "Let:\n",
+            "  first: {{ call: {{ action: ledger.open, args: {{}} }} }}\n",
+            "{second}",
+            "Prove:\n  - assert: \"true\"\n    because: trivially true\n",
+            "Witness:\n  - cover: \"first == 0\"\n    because: reachable\n",
+        ),
+        second = second,
+    ));
+    let found: Vec<String> = lints(&input, unused_let)
+        .into_iter()
+        .map(|lint| lint.message)
+        .filter(|message| message.contains("'first'"))
+        .collect();
+
+    assert_eq!(found, Vec::<String>::new());
+}
@@ -0,0 +1,61 @@
+//! Lints for Kani unwind bounds that do not fit the theorem's steps.
+
+use super::{LintCode, LintConfig, LintDiagnostic, LintLevel};
+use crate::schema::{SpannedTheoremDoc, Step};
+
+/// Reports each Kani configuration whose `unwind` is below what the theorem's
+/// `repeat` loops need, or above the configured ceiling. The low case applies
+/// [`Step::required_unwind`], the rule loading enforces, so the lint and
+/// validation cannot disagree; the high case slows every proof for little
+/// gain.
+pub(super) fn unwind_bounds(
+    spanned: &SpannedTheoremDoc,
+    config: &LintConfig,
+) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let expected = Step::required_unwind(&doc.do_steps);
+    let ceiling = config.unwind_ceiling();
+    doc.evidence
+        .kani
+        .iter()
+        .enumerate()
+        .filter_map(|(index, kani)| {
+            let (message, suggestion) = if u64::from(kani.unwind) < expected {
+                (
+                    format!(
+                        "Kani unwind {} is below the {expected} that the repeat loops need",
+                        kani.unwind
+                    ),
+                    format!("raise unwind to at least {expected}"),
+                )
+            } else if kani.unwind > ceiling {
+                (
+                    format!(
+                        "Kani unwind {} is above the ceiling of {ceiling}",
+                        kani.unwind
+                    ),
+                    format!("lower unwind to at most {ceiling}, or raise the ceiling"),
+                )
+            } else {
+                return None;
+            };
+            let position = index.to_string();
+            let location = spanned
+                .location(&["Evidence", "kani", position.as_str(), "unwind"])
+                .or_else(|| spanned.location(&["Evidence", "kani", "unwind"]))
+                .cloned();
+            Some(LintDiagnostic {
+                code: LintCode::UnwindBound,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location,
+                message,
+                suggestion: Some(suggestion),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "lint_unwind_tests.rs"]
+mod tests;
@@ -0,0 +1,95 @@
+//! Unit tests for the unwind bound lint.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+const STEPS: &str = concat!(
+    "Actions:\n  account.deposit: { params: { amount: u8 } }\n",
+    "Do:\n",
+    "  - call: { action: account.deposit, args: { amount: 1 } }\n",
+    "  - repeat:\n",
+    "      times: 3\n",
+    "      do:\n",
+    "        - call: { action: account.deposit, args: { amount: 1 } }\n",
+);
+
+fn lints(steps: &str, kani: &str, config: &LintConfig) -> Vec<LintDiagnostic> {
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "{steps}",
+            "Prove:\n",
+            "  - assert: \"true\"\n",
+            "    because: checked\n",
+            "Evidence:\n",
+            "{kani}",
+        ),
+        steps = steps,
+        kani = kani,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(|doc| unwind_bounds(doc, config))
+        .collect()
+}
+
+fn kani(unwind: u32) -> String {
+    format!(
+        "  kani: {{ unwind: {unwind}, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }}\n"
+    )
+}
+
+#[rstest]
+#[case::one_more_than_the_repeat_count(4, &[])]
+#[case::more_than_enough(6, &[])]
+#[case::above_the_ceiling(200, &["Kani unwind 200 is above the ceiling of 128"])]
+fn unwind_is_compared_with_the_loops_and_the_ceiling(
+    #[case] unwind: u32,
+    #[case] expected: &[&str],
+) {
+    let messages: Vec<String> = lints(STEPS, &kani(unwind), &LintConfig::default())
+        .into_iter()
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(messages, expected);
+}
+
+#[test]
+fn the_ceiling_is_configurable() {
+    let config = LintConfig::default().with_unwind_ceiling(4);
+    let messages: Vec<String> = lints("", &kani(5), &config)
+        .into_iter()
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(messages, ["Kani unwind 5 is above the ceiling of 4"]);
+}
+
+#[test]
+fn every_configuration_is_checked_and_located() {
+    let configurations = concat!(
+        "  kani:\n",
+        "    - { name: quick, unwind: 4, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+        "    - name: thorough\n",
+        "      unwind: 8\n",
+        "      expect: SUCCESS\n",
+        "      allow_vacuous: true\n",
+        "      vacuity_because: none\n",
+    );
+    let config = LintConfig::default().with_unwind_ceiling(6);
+    let located: Vec<(usize, usize)> = lints(STEPS, configurations, &config)
+        .iter()
+        .filter_map(|lint| {
+            let location = lint.location.as_ref()?;
+            Some((location.line, location.column))
+        })
+        .collect();
+
+    assert_eq!(located, [(18, 15)]);
+}
@@ -0,0 +1,75 @@
+//! Lints for witnesses that cannot fail to be reached.
+
+use std::collections::HashSet;
+
+use quote::ToTokens;
+use syn::{BinOp, Expr};
+
+use super::constant::without_parens;
+use super::{LintCode, LintDiagnostic, LintLevel};
+use crate::schema::{SpannedTheoremDoc, TheoremExpr};
+
+/// Reports each `Witness` whose `cover` expression restates the
+/// assumptions: every `&&` operand of the cover is, once normalized, an
+/// assumption or an `&&` operand of one. Assumptions constrain every
+/// explored input, so such a witness is always reached and does not show
+/// that the proof is non-vacuous.
+pub(super) fn assumed_witnesses(spanned: &SpannedTheoremDoc) -> Vec<LintDiagnostic> {
+    let doc = spanned.doc();
+    let assumed: HashSet<String> = doc
+        .assume
+        .iter()
+        .flat_map(|assumption| conjuncts(&assumption.expr))
+        .collect();
+    doc.witness
+        .iter()
+        .enumerate()
+        .filter(|(_, witness)| {
+            let cover = conjuncts(&witness.cover);
+            !cover.is_empty() && cover.iter().all(|conjunct| assumed.contains(conjunct))
+        })
+        .map(|(index, _)| {
+            let position = index.to_string();
+            LintDiagnostic {
+                code: LintCode::AssumedWitness,
+                level: LintLevel::Warn,
+                theorem: doc.theorem.as_str().to_owned(),
+                location: spanned
+                    .location(&["Witness", position.as_str(), "cover"])
+                    .cloned(),
+                message: format!(
+                    "Witness {} only restates Assume, so every explored input reaches it",
+                    index + 1
+                ),
+                suggestion: Some(
+                    "cover a case the assumptions allow but do not force, such as a boundary value"
+                        .to_owned(),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Returns the normalized `&&` operands of `expr`, or nothing when it does
+/// not parse.
+fn conjuncts(expr: &TheoremExpr) -> Vec<String> {
+    let mut found = Vec::new();
+    if let Ok(parsed) = syn::parse_str::<Expr>(expr.as_str().trim()) {
+        collect_conjuncts(&parsed, &mut found);
+    }
+    found
+}
+
+fn collect_conjuncts(expr: &Expr, found: &mut Vec<String>) {
+    match without_parens(expr) {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+            collect_conjuncts(&binary.left, found);
+            collect_conjuncts(&binary.right, found);
+        }
+        operand => found.push(operand.to_token_stream().to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_witness_tests.rs"]
+mod tests;
@@ -0,0 +1,85 @@
+//! Unit tests for the assumed witness lint.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SourceId, load_spanned_theorem_docs};
+
+fn lints(assumptions: &[&str], cover: &str) -> Vec<LintDiagnostic> {
+    let assume = assumptions
+        .iter()
+        .map(|assumption| format!("  - expr: \"{assumption}\"\n    because: bounded\n"))
+        .collect::<Vec<_>>()
+        .concat();
+    let input = format!(
+        concat!(
+            "Theorem: T\n",
+            "About: Example\n",
+            "Forall:\n",
+            "  x: u8\n",
+            "  y: u8\n",
+            "Assume:\n",
+            "{assume}",
+            "Prove:\n",
+            "  - assert: \"x.checked_add(y).is_some()\"\n",
+            "    because: bounded\n",
+            "Evidence:\n",
+            "  kani: {{ unwind: 1, expect: SUCCESS }}\n",
+            "Witness:\n",
+            "  - cover: \"{cover}\"\n",
+            "    because: reachable\n",
+        ),
+        assume = assume,
+        cover = cover,
+    );
+    load_spanned_theorem_docs(&SourceId::new("t.theorem"), &input)
+        .expect("theorem should load")
+        .iter()
+        .flat_map(assumed_witnesses)
+        .collect()
+}
+
+#[rstest]
+#[case::identical(&["x < 100"], "x < 100")]
+#[case::spacing(&["x < 100"], "(x<100)")]
+#[case::part_of_an_assumption(&["x < 100 && y < 100"], "y < 100")]
+#[case::several_assumptions(&["x < 100", "y < 100"], "y < 100 && x < 100")]
+fn witnesses_implied_by_assumptions_are_reported(
+    #[case] assumptions: &[&str],
+    #[case] cover: &str,
+) {
+    let messages: Vec<String> = lints(assumptions, cover)
+        .into_iter()
+        .map(|lint| lint.message)
+        .collect();
+
+    assert_eq!(
+        messages,
+        ["Witness 1 only restates Assume, so every explored input reaches it"]
+    );
+}
+
+#[rstest]
+#[case::narrower(&["x < 100"], "x == 99")]
+#[case::adds_a_condition(&["x < 100"], "x < 100 && y == 0")]
+#[case::either_assumption(&["x < 100 || y < 100"], "x < 100")]
+fn witnesses_the_assumptions_do_not_force_are_not_reported(
+    #[case] assumptions: &[&str],
+    #[case] cover: &str,
+) {
+    assert_eq!(lints(assumptions, cover), []);
+}
+
+#[test]
+fn findings_are_located_at_the_cover() {
+    let located: Vec<(usize, usize)> = lints(&["x < 100"], "x < 100")
+        .iter()
+        .filter_map(|lint| {
+            let location = lint.location.as_ref()?;
+            Some((location.line, location.column))
+        })
+        .collect();
+
+    assert_eq!(located, [(15, 12)]);
+}
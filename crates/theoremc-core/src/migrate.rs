@@ -0,0 +1,233 @@
+//! Migration of theorem documents to the latest `Schema` version.
+//!
+//! [`to_latest`] upgrades a loaded [`TheoremDoc`], and [`migrate_source`]
+//! rewrites `.theorem` source text in place: it declares the latest
+//! `Schema` version and replaces lowercase section aliases with their
+//! canonical `TitleCase` keys, leaving comments, layout, `Include`, `Use`,
+//! and `UseFixtures` entries, and `Defaults`, `Suite`, `Shared`, and
+//! `Fixtures` documents untouched.
+//! [`fix_section_aliases`] makes only the key rewrite, fixing the
+//! deprecation warnings raised under
+//! [`AliasPolicy::Warn`](crate::schema::AliasPolicy::Warn) without changing
+//! the declared version.
+//!
+//! # Examples
+//!
+//!     use theoremc_core::migrate::migrate_source;
+//!     use theoremc_core::schema::{LATEST_SCHEMA_VERSION, SourceId, load_theorem_docs};
+//!
+//!     let v1 = r#"theorem: Trivial
+//!     about: Always holds
+//!     prove:
+//!       - assert: "true"
+//!         because: trivially true
+//!     evidence:
+//!       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+//!     "#;
+//!     let v2 = migrate_source(&SourceId::new("trivial.theorem"), v1).unwrap();
+//!     assert!(v2.starts_with("Schema: 2\nTheorem: Trivial\nAbout: Always holds\n"));
+//!
+//!     let docs = load_theorem_docs(&v2).unwrap();
+//!     assert_eq!(docs[0].schema_version(), LATEST_SCHEMA_VERSION);
+
+use serde_saphyr::{Location, Spanned};
+use thiserror::Error;
+
+use crate::schema::{
+    LATEST_SCHEMA_VERSION, RawDocument, SchemaError, SourceId, TheoremDoc, canonical_for_alias,
+    deserialize_failure, parse_options,
+};
+
+/// Errors produced while rewriting a theorem source.
+#[derive(Debug, Error)]
+pub enum MigrateError {
+    /// The source does not parse as theorem documents.
+    #[error(transparent)]
+    Parse(#[from] SchemaError),
+    /// A key or value the rewrite needs to change is not where the parser
+    /// reported it, so the source is left unchanged.
+    #[error("cannot rewrite '{expected}' at line {line}, column {column}")]
+    Rewrite {
+        /// Text the rewrite expected to find.
+        expected: String,
+        /// 1-indexed line number.
+        line: u64,
+        /// 1-indexed column number.
+        column: u64,
+    },
+}
+
+/// Upgrades a loaded document to [`LATEST_SCHEMA_VERSION`].
+///
+/// Loaded documents share one in-memory shape across versions, so only the
+/// declared version changes; [`emit_theorem_docs`](crate::schema::emit_theorem_docs)
+/// then writes the latest layout.
+#[must_use]
+pub const fn to_latest(mut doc: TheoremDoc) -> TheoremDoc {
+    doc.schema = Some(LATEST_SCHEMA_VERSION);
+    doc
+}
+
+/// Rewrites every theorem document in `input` to the latest `Schema`
+/// layout, returning the source unchanged when it is already current.
+///
+/// # Errors
+///
+/// Returns [`MigrateError::Parse`] when `input` does not parse, and
+/// [`MigrateError::Rewrite`] when a located key or value cannot be found in
+/// the text.
+pub fn migrate_source(source: &SourceId, input: &str) -> Result<String, MigrateError> {
+    rewrite_theorems(source, input, theorem_edits)
+}
+
+/// Rewrites every lowercase section key alias in the theorem documents of
+/// `input` to its canonical `TitleCase` spelling, leaving everything else,
+/// including the declared `Schema` version, unchanged.
+///
+/// # Errors
+///
+/// Returns the same errors as [`migrate_source`].
+///
+/// # Examples
+///
+///     use theoremc_core::migrate::fix_section_aliases;
+///     use theoremc_core::schema::SourceId;
+///
+///     let v1 = r#"theorem: Trivial
+///     About: Always holds
+///     prove:
+///       - assert: "true"
+///         because: trivially true
+///     Evidence:
+///       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+///     "#;
+///     let fixed = fix_section_aliases(&SourceId::new("trivial.theorem"), v1).unwrap();
+///     assert!(fixed.starts_with("Theorem: Trivial\nAbout: Always holds\nProve:\n"));
+pub fn fix_section_aliases(source: &SourceId, input: &str) -> Result<String, MigrateError> {
+    rewrite_theorems(source, input, |_, keys| alias_edits(keys))
+}
+
+/// Applies the edits `edits_for` returns for each theorem document's
+/// declared version and top-level keys.
+fn rewrite_theorems(
+    source: &SourceId,
+    input: &str,
+    edits_for: impl Fn(Option<&Spanned<u32>>, &[Spanned<String>]) -> Vec<Edit>,
+) -> Result<String, MigrateError> {
+    let documents: Vec<RawDocument> =
+        serde_saphyr::from_multiple_with_options(input, parse_options())
+            .map_err(|error| deserialize_failure(source, input, &error))?;
+    let mut edits: Vec<Edit> = documents
+        .iter()
+        .filter_map(|document| match document {
+            RawDocument::Theorem(raw_doc) => Some(raw_doc),
+            _ => None,
+        })
+        .flat_map(|raw_doc| edits_for(raw_doc.schema.as_ref(), &raw_doc.section_keys))
+        .collect();
+    // Later edits first, so earlier positions stay valid.
+    edits.sort_by_key(|edit| std::cmp::Reverse((edit.at.line(), edit.at.column())));
+    edits
+        .into_iter()
+        .try_fold(input.to_owned(), |text, edit| edit.apply(text))
+}
+
+/// Edits that bring one theorem document to the latest layout.
+fn theorem_edits(schema: Option<&Spanned<u32>>, keys: &[Spanned<String>]) -> Vec<Edit> {
+    if schema.is_some_and(|version| version.value >= LATEST_SCHEMA_VERSION) {
+        return Vec::new();
+    }
+    let mut edits = alias_edits(keys);
+    let latest = LATEST_SCHEMA_VERSION.to_string();
+    match (schema, keys.first()) {
+        (Some(version), _) => edits.push(Edit {
+            at: version.referenced,
+            old: version.value.to_string(),
+            new: latest,
+        }),
+        (None, Some(first_key)) => edits.push(Edit {
+            at: first_key.referenced,
+            old: String::new(),
+            new: format!("Schema: {latest}"),
+        }),
+        (None, None) => {}
+    }
+    edits
+}
+
+/// Edits that replace each lowercase section alias with its canonical key.
+fn alias_edits(keys: &[Spanned<String>]) -> Vec<Edit> {
+    keys.iter()
+        .filter_map(|key| {
+            canonical_for_alias(&key.value).map(|canonical| Edit {
+                at: key.referenced,
+                old: key.value.clone(),
+                new: canonical.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Replaces `old` with `new` at a parser location. An empty `old` inserts
+/// `new` as a mapping entry ahead of the key at that location.
+struct Edit {
+    at: Location,
+    old: String,
+    new: String,
+}
+
+impl Edit {
+    fn apply(self, mut text: String) -> Result<String, MigrateError> {
+        let start = self.offset(&text).ok_or_else(|| self.rewrite_error())?;
+        let (line_start, rest) = text
+            .split_at_checked(start)
+            .ok_or_else(|| self.rewrite_error())?;
+        if self.old.is_empty() {
+            let indent = line_start.rsplit('\n').next().unwrap_or_default();
+            let entry = if indent.trim().is_empty() {
+                format!("{}\n{indent}", self.new)
+            } else {
+                format!("{}, ", self.new)
+            };
+            text.insert_str(start, &entry);
+            return Ok(text);
+        }
+        // Quoted keys are located at their opening quote.
+        let quoted = usize::from(rest.starts_with(['"', '\'']));
+        let old_start = start + quoted;
+        if !text
+            .get(old_start..)
+            .is_some_and(|tail| tail.starts_with(&self.old))
+        {
+            return Err(self.rewrite_error());
+        }
+        text.replace_range(old_start..old_start + self.old.len(), &self.new);
+        Ok(text)
+    }
+
+    /// Returns the byte offset of the edit's line and character column.
+    fn offset(&self, text: &str) -> Option<usize> {
+        let line = usize::try_from(self.at.line()).ok()?.checked_sub(1)?;
+        let column = usize::try_from(self.at.column()).ok()?.checked_sub(1)?;
+        let line_start: usize = text.split_inclusive('\n').take(line).map(str::len).sum();
+        let line_text = text.get(line_start..)?;
+        line_text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(line_text.len()))
+            .nth(column)
+            .map(|index| line_start + index)
+    }
+
+    fn rewrite_error(&self) -> MigrateError {
+        MigrateError::Rewrite {
+            expected: self.old.clone(),
+            line: self.at.line(),
+            column: self.at.column(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "migrate_tests.rs"]
+mod tests;
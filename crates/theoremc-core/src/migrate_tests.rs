@@ -0,0 +1,113 @@
+//! Unit tests for schema migration.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{MigrateError, fix_section_aliases, migrate_source, to_latest};
+use crate::schema::{LATEST_SCHEMA_VERSION, SourceId, load_theorem_docs};
+
+const EVIDENCE: &str =
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n";
+
+fn migrate(input: &str) -> String {
+    migrate_source(&SourceId::new("t.theorem"), input).expect("source should migrate")
+}
+
+fn theorem(keys: [&str; 4], schema: &str) -> String {
+    let [theorem, about, prove, evidence] = keys;
+    format!(
+        "{schema}{theorem}: T\n{about}: Example # keep me\n{prove}:\n  - assert: \"true\"\n    \
+         because: trivial\n{evidence}:\n{EVIDENCE}"
+    )
+}
+
+#[rstest]
+#[case::omitted_version(theorem(["theorem", "about", "prove", "evidence"], ""))]
+#[case::version_one(theorem(["theorem", "about", "prove", "evidence"], "Schema: 1\n"))]
+#[case::lowercase_schema(theorem(["theorem", "about", "prove", "evidence"], "schema: 1\n"))]
+#[case::canonical_keys(theorem(["Theorem", "About", "Prove", "Evidence"], ""))]
+fn version_one_sources_are_rewritten(#[case] input: String) {
+    let expected = theorem(["Theorem", "About", "Prove", "Evidence"], "Schema: 2\n");
+
+    assert_eq!(migrate(&input), expected);
+}
+
+#[test]
+fn current_sources_are_unchanged() {
+    let input = theorem(["Theorem", "About", "Prove", "Evidence"], "Schema: 2\n");
+
+    assert_eq!(migrate(&input), input);
+}
+
+#[test]
+fn every_theorem_document_is_migrated() {
+    let first = theorem(["theorem", "About", "prove", "Evidence"], "");
+    let second =
+        theorem(["Theorem", "about", "Prove", "evidence"], "").replacen(": T\n", ": U\n", 1);
+    let input = format!("Defaults:\n  tags: [shared]\n---\n{first}---\n  # indented\n{second}");
+
+    let migrated = migrate(&input);
+
+    let canonical = theorem(["Theorem", "About", "Prove", "Evidence"], "Schema: 2\n");
+    let renamed = canonical.replacen(": T\n", ": U\n", 1);
+    assert_eq!(
+        migrated,
+        format!("Defaults:\n  tags: [shared]\n---\n{canonical}---\n  # indented\n{renamed}")
+    );
+    let docs = load_theorem_docs(&migrated).expect("migrated source should load");
+    assert!(
+        docs.iter()
+            .all(|doc| doc.schema_version() == LATEST_SCHEMA_VERSION)
+    );
+}
+
+#[test]
+fn flow_and_quoted_keys_are_rewritten() {
+    let input = format!(
+        "{{ 'theorem': T, about: Example, prove: [{{ assert: 'true', because: trivial }}], \
+         evidence: {{ {} }} }}\n",
+        EVIDENCE.trim()
+    );
+
+    let migrated = migrate(&input);
+
+    assert!(
+        migrated.starts_with("{ Schema: 2, 'Theorem': T, About: Example, Prove: ["),
+        "{migrated}"
+    );
+    assert!(load_theorem_docs(&migrated).is_ok(), "{migrated}");
+}
+
+#[test]
+fn unparseable_sources_are_reported() {
+    let result = migrate_source(&SourceId::new("t.theorem"), "Theorem: [unclosed\n");
+
+    assert!(matches!(result, Err(MigrateError::Parse(_))));
+}
+
+#[test]
+fn loaded_documents_move_to_the_latest_version() {
+    let docs = load_theorem_docs(&theorem(["Theorem", "About", "Prove", "Evidence"], ""))
+        .expect("source should load");
+    let doc = docs
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem");
+
+    assert_eq!(to_latest(doc).schema, Some(LATEST_SCHEMA_VERSION));
+}
+
+#[rstest]
+#[case::omitted_version("")]
+#[case::version_one("Schema: 1\n")]
+fn fixing_aliases_keeps_the_declared_version(#[case] schema: &str) {
+    let input = theorem(["theorem", "About", "prove", "evidence"], schema);
+
+    let fixed =
+        fix_section_aliases(&SourceId::new("t.theorem"), &input).expect("source should be fixed");
+
+    assert_eq!(
+        fixed,
+        theorem(["Theorem", "About", "Prove", "Evidence"], schema)
+    );
+}
@@ -0,0 +1,106 @@
+//! Reporting views over loaded theorem documents.
+//!
+//! Reports are derived from validated [`TheoremDoc`](crate::schema::TheoremDoc)
+//! values and rendered deterministically, so the same corpus always produces
+//! byte-identical output suitable for audits and review diffs.
+
+#[path = "report_coverage.rs"]
+pub mod coverage;
+#[path = "report_html.rs"]
+pub mod html;
+#[path = "report_junit.rs"]
+pub mod junit;
+#[path = "report_markdown.rs"]
+pub mod markdown;
+#[path = "report_traceability.rs"]
+pub mod traceability;
+
+use crate::results::kani::{ExpectationMismatch, HarnessResult, check_expectations};
+use crate::schema::TheoremDoc;
+
+/// Verification status of one theorem, judged by Kani results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TheoremStatus {
+    /// Every Kani harness reported its expected outcome.
+    Passed,
+    /// Some harnesses disagreed with their expectations, in document and
+    /// configuration order.
+    Failed(Vec<ExpectationMismatch>),
+    /// The theorem has no Kani evidence, so nothing was verified.
+    NotRun,
+}
+
+impl TheoremStatus {
+    /// Judges `doc`, loaded from `theorem_path`, by the Kani `results`.
+    #[must_use]
+    pub fn of(theorem_path: &str, doc: &TheoremDoc, results: &[HarnessResult]) -> Self {
+        if doc.evidence.kani.is_empty() {
+            return Self::NotRun;
+        }
+        let mismatches = check_expectations(theorem_path, std::slice::from_ref(doc), results);
+        if mismatches.is_empty() {
+            Self::Passed
+        } else {
+            Self::Failed(mismatches)
+        }
+    }
+
+    /// Returns the status as shown to readers: `passed`, `failed`, or
+    /// `not run`.
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed(_) => "failed",
+            Self::NotRun => "not run",
+        }
+    }
+}
+
+/// Counts theorems by status, as in `3 theorems: 1 passed, 1 failed, 1 not
+/// run`.
+fn status_summary<'a>(statuses: impl Iterator<Item = &'a TheoremStatus>) -> String {
+    let (mut passed, mut failed, mut not_run) = (0, 0, 0);
+    for status in statuses {
+        match status {
+            TheoremStatus::Passed => passed += 1,
+            TheoremStatus::Failed(_) => failed += 1,
+            TheoremStatus::NotRun => not_run += 1,
+        }
+    }
+    format!(
+        "{} theorems: {passed} passed, {failed} failed, {not_run} not run",
+        passed + failed + not_run
+    )
+}
+
+/// Appends one CSV record, quoting fields that contain separators, quotes, or
+/// line breaks.
+fn write_csv_record(csv: &mut String, fields: &[&str]) {
+    let record: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+    csv.push_str(&record.join(","));
+    csv.push('\n');
+}
+
+/// Escapes text for XML or HTML attribute values and character data.
+fn escape_markup(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_owned(),
+            '<' => "&lt;".to_owned(),
+            '>' => "&gt;".to_owned(),
+            '"' => "&quot;".to_owned(),
+            '\'' => "&apos;".to_owned(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
@@ -0,0 +1,333 @@
+//! Per-assertion reachability recorded from Kani's coverage mode.
+//!
+//! A theorem whose Kani evidence sets `coverage: true` is verified in Kani's
+//! coverage mode, which reports whether each `Prove` assertion was reached.
+//! [`CoverageReport`] holds one row per such assertion so vacuity analysis
+//! can rely on observed reachability instead of declared `Witness` covers
+//! alone: an assertion that is never reached holds only vacuously.
+//!
+//! [`CoverageReport::from_kani_results`] derives the same rows from an
+//! ordinary Kani run, together with the outcome of every `Witness` cover, by
+//! matching each check's description against the declared expressions.
+
+use super::write_csv_record;
+use crate::results::kani::{CheckResult, CheckStatus, HarnessResult, expected_harnesses};
+use crate::schema::TheoremDoc;
+
+/// Coverage status Kani reports for an assertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reachability {
+    /// Every path to the assertion was reached.
+    Reached,
+    /// The assertion was reached on some paths only.
+    PartiallyReached,
+    /// The assertion was never reached.
+    Unreached,
+}
+
+impl Reachability {
+    /// Returns the status as rendered in reports.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Reached => "reached",
+            Self::PartiallyReached => "partial",
+            Self::Unreached => "unreached",
+        }
+    }
+}
+
+/// Reachability of one `Prove` assertion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionCoverage {
+    /// Name of the theorem declaring the assertion.
+    pub theorem: String,
+    /// Zero-based position of the assertion in `Prove`.
+    pub index: usize,
+    /// The asserted expression.
+    pub assertion: String,
+    /// Status reported by Kani, or `None` until a result is recorded.
+    pub reachability: Option<Reachability>,
+}
+
+/// Outcome of one `Witness` cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessCoverage {
+    /// Name of the theorem declaring the witness.
+    pub theorem: String,
+    /// Zero-based position of the witness in `Witness`.
+    pub index: usize,
+    /// The cover expression.
+    pub cover: String,
+    /// Status Kani reported for the cover, or `None` when no result matched
+    /// it. Across several harnesses, a cover satisfied by any of them is
+    /// [`CheckStatus::Satisfied`].
+    pub status: Option<CheckStatus>,
+}
+
+/// Per-assertion reachability for theorems verified in coverage mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// One row per `Prove` assertion, in load and declaration order.
+    pub assertions: Vec<AssertionCoverage>,
+    /// One row per `Witness` cover, in load and declaration order; filled
+    /// only by [`from_kani_results`](Self::from_kani_results).
+    pub witnesses: Vec<WitnessCoverage>,
+}
+
+impl CoverageReport {
+    /// Builds a report with one unrecorded row per `Prove` assertion of every
+    /// theorem with at least one Kani configuration in coverage mode.
+    #[must_use]
+    pub fn from_docs<'a>(docs: impl IntoIterator<Item = &'a TheoremDoc>) -> Self {
+        let assertions = docs
+            .into_iter()
+            .filter(|doc| doc.evidence.kani.iter().any(|kani| kani.coverage))
+            .flat_map(|doc| {
+                doc.prove
+                    .iter()
+                    .enumerate()
+                    .map(|(index, prove)| AssertionCoverage {
+                        theorem: doc.theorem.as_str().to_owned(),
+                        index,
+                        assertion: prove.assert_expr.as_str().trim().to_owned(),
+                        reachability: None,
+                    })
+            })
+            .collect();
+        Self {
+            assertions,
+            witnesses: Vec::new(),
+        }
+    }
+
+    /// Builds a report from the per-check output of a Kani run over `docs`,
+    /// loaded from `theorem_path`, with one row per `Prove` assertion and
+    /// `Witness` cover of every theorem with Kani evidence.
+    ///
+    /// A check matches an assertion when its description is `assertion
+    /// failed: <expr>`, and a cover when it is `cover condition: <expr>`,
+    /// ignoring whitespace. An assertion some harness reached and another
+    /// did not is partially reached. Rows no check matched stay unrecorded.
+    #[must_use]
+    pub fn from_kani_results(
+        theorem_path: &str,
+        docs: &[TheoremDoc],
+        results: &[HarnessResult],
+    ) -> Self {
+        let mut report = Self::default();
+        for doc in docs.iter().filter(|doc| !doc.evidence.kani.is_empty()) {
+            let harnesses = expected_harnesses(theorem_path, doc);
+            let checks: Vec<&CheckResult> = results
+                .iter()
+                .filter(|result| {
+                    harnesses
+                        .iter()
+                        .any(|(harness, _)| result.identifier() == harness)
+                })
+                .flat_map(|result| &result.checks)
+                .collect();
+            report.add_theorem(doc, &checks);
+        }
+        report
+    }
+
+    fn add_theorem(&mut self, doc: &TheoremDoc, checks: &[&CheckResult]) {
+        let theorem = doc.theorem.as_str();
+        self.assertions
+            .extend(
+                doc.prove
+                    .iter()
+                    .enumerate()
+                    .map(|(index, prove)| AssertionCoverage {
+                        theorem: theorem.to_owned(),
+                        index,
+                        assertion: prove.assert_expr.as_str().trim().to_owned(),
+                        reachability: assertion_reachability(prove.assert_expr.as_str(), checks),
+                    }),
+            );
+        self.witnesses
+            .extend(
+                doc.witness
+                    .iter()
+                    .enumerate()
+                    .map(|(index, witness)| WitnessCoverage {
+                        theorem: theorem.to_owned(),
+                        index,
+                        cover: witness.cover.as_str().trim().to_owned(),
+                        status: cover_status(witness.cover.as_str(), checks),
+                    }),
+            );
+    }
+
+    /// Records the status Kani reported for assertion `index` of `theorem`.
+    ///
+    /// Returns `false` when the report has no such assertion.
+    pub fn record(&mut self, theorem: &str, index: usize, reachability: Reachability) -> bool {
+        let row = self
+            .assertions
+            .iter_mut()
+            .find(|row| row.theorem == theorem && row.index == index);
+        row.map(|found| found.reachability = Some(reachability))
+            .is_some()
+    }
+
+    /// Returns the assertions Kani never reached, which hold only vacuously.
+    pub fn vacuous_assertions(&self) -> impl Iterator<Item = &AssertionCoverage> {
+        self.assertions
+            .iter()
+            .filter(|row| row.reachability == Some(Reachability::Unreached))
+    }
+
+    /// Returns the witnesses Kani reported but never satisfied.
+    pub fn unsatisfied_witnesses(&self) -> impl Iterator<Item = &WitnessCoverage> {
+        self.witnesses.iter().filter(|row| is_unsatisfied(row))
+    }
+
+    /// Renders a plain-text summary for terminal output: one line per
+    /// theorem counting reached assertions and satisfied witnesses, followed
+    /// by an indented line for each unreached assertion and unsatisfied
+    /// witness.
+    #[must_use]
+    pub fn to_summary(&self) -> String {
+        let mut theorems: Vec<&str> = Vec::new();
+        for theorem in self
+            .assertions
+            .iter()
+            .map(|row| row.theorem.as_str())
+            .chain(self.witnesses.iter().map(|row| row.theorem.as_str()))
+        {
+            if !theorems.contains(&theorem) {
+                theorems.push(theorem);
+            }
+        }
+        theorems
+            .into_iter()
+            .map(|theorem| self.theorem_summary(theorem))
+            .collect::<Vec<_>>()
+            .concat()
+    }
+
+    fn theorem_summary(&self, theorem: &str) -> String {
+        let assertions: Vec<&AssertionCoverage> = self
+            .assertions
+            .iter()
+            .filter(|row| row.theorem == theorem)
+            .collect();
+        let witnesses: Vec<&WitnessCoverage> = self
+            .witnesses
+            .iter()
+            .filter(|row| row.theorem == theorem)
+            .collect();
+        let reached = assertions
+            .iter()
+            .filter(|row| row.reachability == Some(Reachability::Reached))
+            .count();
+        let satisfied = witnesses
+            .iter()
+            .filter(|row| row.status == Some(CheckStatus::Satisfied))
+            .count();
+        let header = format!(
+            "{theorem}: {reached}/{} assertions reached, {satisfied}/{} witnesses satisfied\n",
+            assertions.len(),
+            witnesses.len()
+        );
+        let gaps = assertions
+            .iter()
+            .filter_map(|row| match row.reachability {
+                Some(status @ (Reachability::Unreached | Reachability::PartiallyReached)) => {
+                    Some(format!(
+                        "  {} assertion {}: {}\n",
+                        status.as_str(),
+                        row.index + 1,
+                        row.assertion
+                    ))
+                }
+                _ => None,
+            })
+            .chain(
+                witnesses
+                    .iter()
+                    .filter(|row| is_unsatisfied(row))
+                    .map(|row| format!("  unsatisfied witness {}: {}\n", row.index + 1, row.cover)),
+            );
+        std::iter::once(header)
+            .chain(gaps)
+            .collect::<Vec<_>>()
+            .concat()
+    }
+
+    /// Renders the report as CSV with a `theorem,assertion,expr,reachability`
+    /// header. Assertions are numbered from one, and rows without a recorded
+    /// result show `unknown`.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("theorem,assertion,expr,reachability\n");
+        for row in &self.assertions {
+            let number = (row.index + 1).to_string();
+            let status = row.reachability.map_or("unknown", Reachability::as_str);
+            write_csv_record(&mut csv, &[&row.theorem, &number, &row.assertion, status]);
+        }
+        csv
+    }
+}
+
+fn is_unsatisfied(row: &WitnessCoverage) -> bool {
+    row.status
+        .is_some_and(|status| status != CheckStatus::Satisfied)
+}
+
+/// Combines the statuses of every non-cover check describing `expr`.
+fn assertion_reachability(expr: &str, checks: &[&CheckResult]) -> Option<Reachability> {
+    let (mut reached, mut unreached) = (false, false);
+    for check in checks
+        .iter()
+        .filter(|check| !check.is_cover() && describes(check, "assertion failed: ", expr))
+    {
+        match check.status {
+            CheckStatus::Success | CheckStatus::Failure => reached = true,
+            CheckStatus::Unreachable => unreached = true,
+            _ => {}
+        }
+    }
+    match (reached, unreached) {
+        (true, true) => Some(Reachability::PartiallyReached),
+        (true, false) => Some(Reachability::Reached),
+        (false, true) => Some(Reachability::Unreached),
+        (false, false) => None,
+    }
+}
+
+/// Returns the best status of any cover check describing `expr`.
+fn cover_status(expr: &str, checks: &[&CheckResult]) -> Option<CheckStatus> {
+    let statuses: Vec<CheckStatus> = checks
+        .iter()
+        .filter(|check| check.is_cover() && describes(check, "cover condition: ", expr))
+        .map(|check| check.status)
+        .collect();
+    [
+        CheckStatus::Satisfied,
+        CheckStatus::Unsatisfiable,
+        CheckStatus::Unreachable,
+        CheckStatus::Undetermined,
+    ]
+    .into_iter()
+    .find(|status| statuses.contains(status))
+}
+
+/// Returns whether `check` is described as `prefix` followed by `expr`,
+/// ignoring whitespace, which Kani normalizes when printing expressions.
+fn describes(check: &CheckResult, prefix: &str, expr: &str) -> bool {
+    check
+        .description
+        .strip_prefix(prefix)
+        .is_some_and(|described| without_whitespace(described) == without_whitespace(expr))
+}
+
+fn without_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+#[path = "report_coverage_tests.rs"]
+mod tests;
@@ -0,0 +1,258 @@
+//! Unit tests for coverage-mode reachability reports.
+
+use super::{AssertionCoverage, CoverageReport, Reachability};
+use crate::mangle::mangle_theorem_harness;
+use crate::results::kani::{CheckResult, CheckStatus, HarnessResult};
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+fn theorem(name: &str, coverage: bool) -> String {
+    format!(
+        concat!(
+            "Theorem: {name}\n",
+            "About: coverage example\n",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "  - assert: '\"a,b\".len() == 3'\n",
+            "    because: the literal has three characters\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "    coverage: {coverage}\n",
+            "Witness:\n",
+            "  - cover: 'true'\n",
+            "    because: always reachable\n",
+        ),
+        name = name,
+        coverage = coverage,
+    )
+}
+
+fn corpus() -> Vec<TheoremDoc> {
+    let yaml = [theorem("Covered", true), theorem("Plain", false)].join("---\n");
+    load_theorem_docs(&yaml).expect("coverage theorems should load")
+}
+
+fn row(index: usize, assertion: &str, reachability: Option<Reachability>) -> AssertionCoverage {
+    AssertionCoverage {
+        theorem: "Covered".to_owned(),
+        index,
+        assertion: assertion.to_owned(),
+        reachability,
+    }
+}
+
+#[test]
+fn report_lists_assertions_of_coverage_mode_theorems() {
+    let report = CoverageReport::from_docs(&corpus());
+
+    assert_eq!(
+        report.assertions,
+        [row(0, "true", None), row(1, "\"a,b\".len() == 3", None)]
+    );
+}
+
+#[test]
+fn recorded_results_identify_vacuous_assertions() {
+    let mut report = CoverageReport::from_docs(&corpus());
+
+    assert!(report.record("Covered", 0, Reachability::Reached));
+    assert!(report.record("Covered", 1, Reachability::Unreached));
+    assert!(!report.record("Plain", 0, Reachability::Reached));
+    assert!(!report.record("Covered", 2, Reachability::Reached));
+
+    let vacuous: Vec<_> = report.vacuous_assertions().collect();
+    assert_eq!(
+        vacuous,
+        [&row(1, "\"a,b\".len() == 3", Some(Reachability::Unreached))]
+    );
+}
+
+#[test]
+fn report_renders_as_csv() {
+    let mut report = CoverageReport::from_docs(&corpus());
+    report.record("Covered", 0, Reachability::PartiallyReached);
+
+    assert_eq!(
+        report.to_csv(),
+        concat!(
+            "theorem,assertion,expr,reachability\n",
+            "Covered,1,true,partial\n",
+            "Covered,2,\"\"\"a,b\"\".len() == 3\",unknown\n",
+        )
+    );
+}
+
+#[test]
+fn corpus_without_coverage_renders_only_the_header() {
+    let report =
+        CoverageReport::from_docs(&load_theorem_docs(&theorem("Plain", false)).expect("load"));
+
+    assert_eq!(report, CoverageReport::default());
+    assert_eq!(report.to_csv(), "theorem,assertion,expr,reachability\n");
+}
+
+fn check(property: &str, status: CheckStatus, description: &str) -> CheckResult {
+    CheckResult {
+        property: property.to_owned(),
+        status,
+        description: description.to_owned(),
+        location: None,
+    }
+}
+
+fn kani_result(theorem: &str, suffix: &str, checks: Vec<CheckResult>) -> HarnessResult {
+    HarnessResult {
+        harness: format!(
+            "{}{suffix}",
+            mangle_theorem_harness(THEOREM_PATH, theorem).identifier()
+        ),
+        checks,
+        verified: true,
+        counterexample: None,
+        verification_time: None,
+    }
+}
+
+fn kani_corpus() -> Vec<TheoremDoc> {
+    load_theorem_docs(concat!(
+        "Theorem: Deposit\n",
+        "About: deposits grow the balance\n",
+        "Forall:\n",
+        "  balance: u32\n",
+        "  amount: u32\n",
+        "Prove:\n",
+        "  - assert: 'balance>=amount'\n",
+        "    because: deposits never shrink the balance\n",
+        "  - assert: 'amount > 0'\n",
+        "    because: zero deposits are rejected\n",
+        "Evidence:\n",
+        "  kani:\n",
+        "    - { name: small, unwind: 1, expect: SUCCESS }\n",
+        "    - { name: large, unwind: 4, expect: SUCCESS }\n",
+        "Witness:\n",
+        "  - cover: 'amount > 0'\n",
+        "    because: a positive deposit is possible\n",
+        "  - cover: 'amount == u32::MAX'\n",
+        "    because: the largest deposit is possible\n",
+    ))
+    .expect("theorem should load")
+}
+
+fn kani_report() -> CoverageReport {
+    let results = vec![
+        kani_result(
+            "Deposit",
+            "__small",
+            vec![
+                check(
+                    "d.assertion.1",
+                    CheckStatus::Success,
+                    "assertion failed: balance >= amount",
+                ),
+                check(
+                    "d.assertion.2",
+                    CheckStatus::Unreachable,
+                    "assertion failed: amount > 0",
+                ),
+                check(
+                    "d.cover.1",
+                    CheckStatus::Unsatisfiable,
+                    "cover condition: amount > 0",
+                ),
+                check(
+                    "d.cover.2",
+                    CheckStatus::Unsatisfiable,
+                    "cover condition: amount == u32::MAX",
+                ),
+            ],
+        ),
+        kani_result(
+            "Deposit",
+            "__large",
+            vec![
+                check(
+                    "d.assertion.2",
+                    CheckStatus::Success,
+                    "assertion failed: amount > 0",
+                ),
+                check(
+                    "d.cover.1",
+                    CheckStatus::Satisfied,
+                    "cover condition: amount > 0",
+                ),
+            ],
+        ),
+        kani_result(
+            "Other",
+            "",
+            vec![check(
+                "o.cover.1",
+                CheckStatus::Satisfied,
+                "cover condition: amount == u32::MAX",
+            )],
+        ),
+    ];
+    CoverageReport::from_kani_results(THEOREM_PATH, &kani_corpus(), &results)
+}
+
+#[test]
+fn kani_checks_are_matched_to_assertions_and_witnesses() {
+    let report = kani_report();
+
+    assert_eq!(
+        report
+            .assertions
+            .iter()
+            .map(|row| (row.assertion.as_str(), row.reachability))
+            .collect::<Vec<_>>(),
+        [
+            ("balance>=amount", Some(Reachability::Reached)),
+            ("amount > 0", Some(Reachability::PartiallyReached)),
+        ]
+    );
+    assert_eq!(
+        report
+            .witnesses
+            .iter()
+            .map(|row| (row.cover.as_str(), row.status))
+            .collect::<Vec<_>>(),
+        [
+            ("amount > 0", Some(CheckStatus::Satisfied)),
+            ("amount == u32::MAX", Some(CheckStatus::Unsatisfiable)),
+        ]
+    );
+    assert_eq!(report.unsatisfied_witnesses().count(), 1);
+}
+
+#[test]
+fn kani_coverage_renders_as_a_summary() {
+    assert_eq!(
+        kani_report().to_summary(),
+        concat!(
+            "Deposit: 1/2 assertions reached, 1/2 witnesses satisfied\n",
+            "  partial assertion 2: amount > 0\n",
+            "  unsatisfied witness 2: amount == u32::MAX\n",
+        )
+    );
+}
+
+#[test]
+fn unmatched_checks_leave_rows_unrecorded() {
+    let report = CoverageReport::from_kani_results(THEOREM_PATH, &kani_corpus(), &[]);
+
+    assert!(
+        report
+            .assertions
+            .iter()
+            .all(|row| row.reachability.is_none())
+    );
+    assert!(report.witnesses.iter().all(|row| row.status.is_none()));
+    assert_eq!(
+        report.to_summary(),
+        "Deposit: 0/2 assertions reached, 0/2 witnesses satisfied\n"
+    );
+}
@@ -0,0 +1,235 @@
+//! Static HTML evidence reports for reviewers who do not read Rust.
+//!
+//! [`HtmlReport`] lists every theorem of a run with a status badge, its
+//! `About` prose and tags, the `because` justification of each assumption,
+//! assertion, and witness, and a link back to the line declaring it. The
+//! page is self-contained, so it can be archived as a CI artefact or served
+//! as is.
+
+use super::{TheoremStatus, escape_markup, status_summary};
+use crate::results::kani::HarnessResult;
+use crate::schema::{Assertion, Assumption, Symbol, TheoremDoc, WitnessCheck};
+
+const STYLE: &str = concat!(
+    "body{font-family:sans-serif;max-width:60em;margin:auto;padding:1em}",
+    ".theorem{border-top:1px solid #ccc;padding:.5em 0}",
+    ".badge{border-radius:.3em;padding:0 .4em;font-size:.8em;color:#fff}",
+    ".passed{background:#2e7d32}.failed{background:#c62828}",
+    ".not-run{background:#757575}",
+    ".tags li{display:inline;margin-right:.5em}dt code{font-size:1.1em}",
+);
+
+/// A loaded theorem file to add to a report.
+#[derive(Debug, Clone, Copy)]
+pub struct TheoremSource<'a> {
+    /// Theorem file path, relative to the report's source base.
+    pub path: &'a str,
+    /// Text of the file, used to locate each theorem's declaration.
+    pub text: &'a str,
+    /// Theorems loaded from the file.
+    pub docs: &'a [TheoremDoc],
+}
+
+/// One theorem as shown in the report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlTheorem {
+    /// Theorem name.
+    pub theorem: String,
+    /// One-based line of the theorem's `Theorem` key, when found in the
+    /// source.
+    pub line: Option<usize>,
+    /// The theorem's `About` prose.
+    pub about: String,
+    /// The theorem's tags.
+    pub tags: Vec<Symbol>,
+    /// Assumptions with their justifications.
+    pub assume: Vec<Assumption>,
+    /// Assertions with their justifications.
+    pub prove: Vec<Assertion>,
+    /// Witnesses with their justifications.
+    pub witness: Vec<WitnessCheck>,
+    /// Verification status judged by the run's Kani results.
+    pub status: TheoremStatus,
+}
+
+/// The theorems of one theorem file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtmlFile {
+    /// Theorem file path.
+    pub path: String,
+    /// One entry per theorem, in document order.
+    pub theorems: Vec<HtmlTheorem>,
+}
+
+/// The verification state of one run, for rendering as a static HTML page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HtmlReport {
+    /// Prefix joined to each theorem file path to link to its source, such
+    /// as a repository browser URL ending in `/`. Empty links relative to
+    /// the report.
+    pub source_base: String,
+    /// One entry per theorem file, in the order added.
+    pub files: Vec<HtmlFile>,
+}
+
+impl HtmlReport {
+    /// Creates an empty report linking sources under `source_base`.
+    #[must_use]
+    pub fn new(source_base: &str) -> Self {
+        Self {
+            source_base: source_base.to_owned(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Adds the theorems of `source`, judging each by the Kani `results`.
+    pub fn add_file(&mut self, source: &TheoremSource<'_>, results: &[HarnessResult]) {
+        let theorems = source
+            .docs
+            .iter()
+            .map(|doc| HtmlTheorem {
+                theorem: doc.theorem.as_str().to_owned(),
+                line: theorem_line(source.text, doc.theorem.as_str()),
+                about: doc.about.clone(),
+                tags: doc.tags.clone(),
+                assume: doc.assume.clone(),
+                prove: doc.prove.clone(),
+                witness: doc.witness.clone(),
+                status: TheoremStatus::of(source.path, doc, results),
+            })
+            .collect();
+        self.files.push(HtmlFile {
+            path: source.path.to_owned(),
+            theorems,
+        });
+    }
+
+    /// Renders the report as a self-contained HTML document.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut html = format!(
+            concat!(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n",
+                "<title>Theorem verification report</title>\n<style>{}</style>\n",
+                "</head>\n<body>\n<h1>Theorem verification report</h1>\n",
+                "<p class=\"summary\">{}</p>\n",
+            ),
+            STYLE,
+            status_summary(
+                self.files
+                    .iter()
+                    .flat_map(|file| &file.theorems)
+                    .map(|theorem| &theorem.status)
+            )
+        );
+        for file in &self.files {
+            html.push_str("<section>\n<h2>");
+            html.push_str(&escape_markup(&file.path));
+            html.push_str("</h2>\n");
+            for theorem in &file.theorems {
+                self.write_theorem(&mut html, &file.path, theorem);
+            }
+            html.push_str("</section>\n");
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    fn write_theorem(&self, html: &mut String, path: &str, theorem: &HtmlTheorem) {
+        let label = theorem.status.label();
+        html.push_str("<article class=\"theorem\">\n<h3>");
+        html.push_str(&escape_markup(&theorem.theorem));
+        html.push_str(" <span class=\"badge ");
+        html.push_str(&label.replace(' ', "-"));
+        html.push_str("\">");
+        html.push_str(label);
+        html.push_str("</span></h3>\n");
+        html.push_str(&self.source_link(path, theorem.line));
+        html.push_str("<p>");
+        html.push_str(&escape_markup(&theorem.about));
+        html.push_str("</p>\n");
+        if !theorem.tags.is_empty() {
+            html.push_str(&list("tags", theorem.tags.iter().map(Symbol::as_str)));
+        }
+        if let TheoremStatus::Failed(mismatches) = &theorem.status {
+            let messages: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+            html.push_str(&list("mismatches", messages.iter().map(String::as_str)));
+        }
+        write_justifications(html, theorem);
+        html.push_str("</article>\n");
+    }
+
+    fn source_link(&self, path: &str, line: Option<usize>) -> String {
+        let (anchor, text) = line.map_or_else(
+            || (String::new(), path.to_owned()),
+            |number| (format!("#L{number}"), format!("{path}:{number}")),
+        );
+        format!(
+            "<p class=\"source\"><a href=\"{}\">{}</a></p>\n",
+            escape_markup(&format!("{}{path}{anchor}", self.source_base)),
+            escape_markup(&text)
+        )
+    }
+}
+
+/// Renders `items` as a `<ul>` with the given class.
+fn list<'a>(class: &str, items: impl Iterator<Item = &'a str>) -> String {
+    let entries: Vec<String> = items
+        .map(|item| format!("<li>{}</li>\n", escape_markup(item)))
+        .collect();
+    format!("<ul class=\"{class}\">\n{}</ul>\n", entries.concat())
+}
+
+/// Lists each assumption, assertion, and witness with its `because` prose.
+fn write_justifications(html: &mut String, theorem: &HtmlTheorem) {
+    let entries = theorem
+        .assume
+        .iter()
+        .map(|assumption| {
+            (
+                "Assume",
+                assumption.expr.as_str(),
+                assumption.because.as_str(),
+            )
+        })
+        .chain(theorem.prove.iter().map(|assertion| {
+            (
+                "Prove",
+                assertion.assert_expr.as_str(),
+                assertion.because.as_str(),
+            )
+        }))
+        .chain(
+            theorem
+                .witness
+                .iter()
+                .map(|witness| ("Witness", witness.cover.as_str(), witness.because.as_str())),
+        );
+    html.push_str("<dl>\n");
+    for (kind, expr, because) in entries {
+        html.push_str("<dt>");
+        html.push_str(kind);
+        html.push_str(" <code>");
+        html.push_str(&escape_markup(expr));
+        html.push_str("</code></dt>\n<dd>");
+        html.push_str(&escape_markup(because));
+        html.push_str("</dd>\n");
+    }
+    html.push_str("</dl>\n");
+}
+
+/// Finds the one-based line declaring theorem `name` in `source`.
+fn theorem_line(source: &str, name: &str) -> Option<usize> {
+    source
+        .lines()
+        .position(|line| {
+            line.strip_prefix("Theorem:")
+                .or_else(|| line.strip_prefix("theorem:"))
+                .is_some_and(|value| value.trim().trim_matches(['"', '\'']) == name)
+        })
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+#[path = "report_html_tests.rs"]
+mod tests;
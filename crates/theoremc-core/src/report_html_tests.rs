@@ -0,0 +1,138 @@
+//! Unit tests for HTML evidence reports.
+
+use pretty_assertions::assert_eq;
+
+use super::{HtmlReport, TheoremSource};
+use crate::mangle::mangle_theorem_harness;
+use crate::report::TheoremStatus;
+use crate::results::kani::HarnessResult;
+use crate::schema::load_theorem_docs;
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+const SOURCE: &str = concat!(
+    "Theorem: Deposit\n",
+    "About: deposits <never> overdraw\n",
+    "Tags: [ledger, money]\n",
+    "Assume:\n",
+    "  - expr: 'true'\n",
+    "    because: amounts are bounded\n",
+    "Prove:\n",
+    "  - assert: '1 < 2'\n",
+    "    because: one is smaller & simpler\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS }\n",
+    "Witness:\n",
+    "  - cover: 'true'\n",
+    "    because: always reachable\n",
+    "---\n",
+    "Theorem: 'Audit'\n",
+    "About: audited later\n",
+    "Prove:\n",
+    "  - assert: 'true'\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  verus: {}\n",
+    "Witness:\n",
+    "  - cover: 'true'\n",
+    "    because: always reachable\n",
+);
+
+fn report(results: &[HarnessResult]) -> HtmlReport {
+    let docs = load_theorem_docs(SOURCE).expect("source should load");
+    let mut report = HtmlReport::new("https://example.com/blob/main/");
+    report.add_file(
+        &TheoremSource {
+            path: THEOREM_PATH,
+            text: SOURCE,
+            docs: &docs,
+        },
+        results,
+    );
+    report
+}
+
+fn passing_result() -> HarnessResult {
+    HarnessResult {
+        harness: mangle_theorem_harness(THEOREM_PATH, "Deposit")
+            .identifier()
+            .to_owned(),
+        checks: vec![],
+        verified: true,
+        counterexample: None,
+        verification_time: None,
+    }
+}
+
+#[test]
+fn records_lines_and_statuses() {
+    let file = report(&[passing_result()])
+        .files
+        .into_iter()
+        .next()
+        .expect("one file");
+
+    assert_eq!(
+        file.theorems
+            .iter()
+            .map(|theorem| (theorem.theorem.as_str(), theorem.line, &theorem.status))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Deposit", Some(1), &TheoremStatus::Passed),
+            ("Audit", Some(16), &TheoremStatus::NotRun),
+        ]
+    );
+}
+
+#[test]
+fn renders_prose_tags_badges_and_links() {
+    let html = report(&[passing_result()]).to_html();
+
+    for fragment in [
+        "<p class=\"summary\">2 theorems: 1 passed, 0 failed, 1 not run</p>",
+        "<h3>Deposit <span class=\"badge passed\">passed</span></h3>",
+        concat!(
+            "<p class=\"source\"><a href=\"https://example.com/blob/main/",
+            "theorems/ledger.theorem#L1\">theorems/ledger.theorem:1</a></p>",
+        ),
+        "<p>deposits &lt;never&gt; overdraw</p>",
+        "<ul class=\"tags\">\n<li>ledger</li>\n<li>money</li>\n</ul>",
+        "<dt>Assume <code>true</code></dt>\n<dd>amounts are bounded</dd>",
+        "<dt>Prove <code>1 &lt; 2</code></dt>\n<dd>one is smaller &amp; simpler</dd>",
+        "<dt>Witness <code>true</code></dt>\n<dd>always reachable</dd>",
+        "<h3>Audit <span class=\"badge not-run\">not run</span></h3>",
+    ] {
+        assert!(html.contains(fragment), "missing {fragment} in:\n{html}");
+    }
+}
+
+#[test]
+fn failed_theorems_list_their_mismatches() {
+    let html = report(&[]).to_html();
+    let harness = mangle_theorem_harness(THEOREM_PATH, "Deposit");
+
+    assert!(html.contains("<h3>Deposit <span class=\"badge failed\">failed</span></h3>"));
+    assert!(html.contains(&format!(
+        "<ul class=\"mismatches\">\n<li>theorem &apos;Deposit&apos;: no Kani result for \
+         harness &apos;{}&apos;</li>\n</ul>",
+        harness.identifier()
+    )));
+}
+
+#[test]
+fn unlocated_theorems_link_to_the_file() {
+    let docs = load_theorem_docs(SOURCE).expect("source should load");
+    let mut report = HtmlReport::default();
+    report.add_file(
+        &TheoremSource {
+            path: THEOREM_PATH,
+            text: "",
+            docs: &docs,
+        },
+        &[],
+    );
+
+    assert!(report.to_html().contains(
+        "<p class=\"source\"><a href=\"theorems/ledger.theorem\">theorems/ledger.theorem</a></p>"
+    ));
+}
@@ -0,0 +1,176 @@
+//! JUnit XML export of theorem verification outcomes.
+//!
+//! [`JunitReport`] holds one test suite per theorem file and one test case
+//! per theorem, so CI dashboards that already read JUnit XML can show which
+//! theorems hold. A theorem fails when any of its Kani harnesses disagrees
+//! with its `expect` outcome; the failure carries the mismatch and the checks
+//! Kani reported as failed.
+
+use super::{TheoremStatus, escape_markup};
+use crate::results::kani::{CheckStatus, ExpectationMismatch, HarnessResult};
+use crate::schema::TheoremDoc;
+
+/// Verdict recorded for one theorem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JunitOutcome {
+    /// Every harness reported its expected outcome.
+    Passed,
+    /// Some harness disagreed with its expectation.
+    Failed {
+        /// First mismatch, used as the failure message.
+        message: String,
+        /// Every mismatch, followed by the failed checks of the harnesses
+        /// involved.
+        details: String,
+    },
+    /// The theorem has no Kani evidence, so nothing was verified.
+    Skipped,
+}
+
+/// One theorem's test case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunitCase {
+    /// Theorem name.
+    pub theorem: String,
+    /// The theorem's verdict.
+    pub outcome: JunitOutcome,
+}
+
+/// The test cases of one theorem file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunitSuite {
+    /// Theorem file path, used as the suite name and case class name.
+    pub path: String,
+    /// One case per theorem, in document order.
+    pub cases: Vec<JunitCase>,
+}
+
+/// Theorem outcomes for export as JUnit XML.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JunitReport {
+    /// One suite per theorem file, in the order added.
+    pub suites: Vec<JunitSuite>,
+}
+
+impl JunitReport {
+    /// Adds a suite for the theorem file at `theorem_path`, judging each of
+    /// its `docs` by the Kani `results`.
+    pub fn add_file(&mut self, theorem_path: &str, docs: &[TheoremDoc], results: &[HarnessResult]) {
+        let cases = docs
+            .iter()
+            .map(|doc| JunitCase {
+                theorem: doc.theorem.as_str().to_owned(),
+                outcome: theorem_outcome(theorem_path, doc, results),
+            })
+            .collect();
+        self.suites.push(JunitSuite {
+            path: theorem_path.to_owned(),
+            cases,
+        });
+    }
+
+    /// Renders the report as a JUnit XML `testsuites` document.
+    #[must_use]
+    pub fn to_xml(&self) -> String {
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"theoremc\"{}>\n",
+            count_attributes(self.suites.iter().flat_map(|suite| &suite.cases))
+        );
+        for suite in &self.suites {
+            write_suite(&mut xml, suite);
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+fn theorem_outcome(
+    theorem_path: &str,
+    doc: &TheoremDoc,
+    results: &[HarnessResult],
+) -> JunitOutcome {
+    match TheoremStatus::of(theorem_path, doc, results) {
+        TheoremStatus::Passed => JunitOutcome::Passed,
+        TheoremStatus::NotRun => JunitOutcome::Skipped,
+        TheoremStatus::Failed(mismatches) => JunitOutcome::Failed {
+            message: mismatches
+                .first()
+                .map_or_else(String::new, ToString::to_string),
+            details: mismatches
+                .iter()
+                .map(|mismatch| mismatch_details(mismatch, results))
+                .collect::<Vec<_>>()
+                .concat(),
+        },
+    }
+}
+
+/// Describes `mismatch`, then lists the failed checks of its harness.
+fn mismatch_details(mismatch: &ExpectationMismatch, results: &[HarnessResult]) -> String {
+    let harness = match mismatch {
+        ExpectationMismatch::MissingResult { harness, .. }
+        | ExpectationMismatch::UnexpectedOutcome { harness, .. }
+        | ExpectationMismatch::VacuousSuccess { harness, .. } => harness,
+    };
+    let checks = results
+        .iter()
+        .filter(|result| result.identifier() == harness)
+        .flat_map(|result| &result.checks)
+        .filter(|check| check.status == CheckStatus::Failure)
+        .map(|check| {
+            let location = check
+                .location
+                .as_ref()
+                .map_or_else(String::new, |location| format!(" at {location}"));
+            format!("  {}: {}{location}\n", check.property, check.description)
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    format!("{mismatch}\n{checks}")
+}
+
+/// Renders the `tests`, `failures`, and `skipped` attributes for `cases`.
+fn count_attributes<'a>(cases: impl Iterator<Item = &'a JunitCase>) -> String {
+    let (tests, failures, skipped) = cases.fold((0, 0, 0), |(tests, failures, skipped), case| {
+        match case.outcome {
+            JunitOutcome::Passed => (tests + 1, failures, skipped),
+            JunitOutcome::Failed { .. } => (tests + 1, failures + 1, skipped),
+            JunitOutcome::Skipped => (tests + 1, failures, skipped + 1),
+        }
+    });
+    format!(" tests=\"{tests}\" failures=\"{failures}\" skipped=\"{skipped}\"")
+}
+
+fn write_suite(xml: &mut String, suite: &JunitSuite) {
+    let path = escape_markup(&suite.path);
+    xml.push_str("  <testsuite name=\"");
+    xml.push_str(&path);
+    xml.push('"');
+    xml.push_str(&count_attributes(suite.cases.iter()));
+    xml.push_str(">\n");
+    for case in &suite.cases {
+        xml.push_str("    <testcase classname=\"");
+        xml.push_str(&path);
+        xml.push_str("\" name=\"");
+        xml.push_str(&escape_markup(&case.theorem));
+        xml.push('"');
+        match &case.outcome {
+            JunitOutcome::Passed => xml.push_str("/>\n"),
+            JunitOutcome::Failed { message, details } => {
+                xml.push_str(">\n      <failure message=\"");
+                xml.push_str(&escape_markup(message));
+                xml.push_str("\" type=\"ExpectationMismatch\">");
+                xml.push_str(&escape_markup(details));
+                xml.push_str("</failure>\n    </testcase>\n");
+            }
+            JunitOutcome::Skipped => {
+                xml.push_str(">\n      <skipped message=\"no Kani evidence\"/>\n    </testcase>\n");
+            }
+        }
+    }
+    xml.push_str("  </testsuite>\n");
+}
+
+#[cfg(test)]
+#[path = "report_junit_tests.rs"]
+mod tests;
@@ -0,0 +1,145 @@
+//! Unit tests for JUnit XML export.
+
+use pretty_assertions::assert_eq;
+
+use super::{JunitOutcome, JunitReport};
+use crate::mangle::mangle_theorem_harness;
+use crate::results::kani::{CheckResult, CheckStatus, HarnessResult};
+use crate::schema::test_fixtures::theorem_without_evidence;
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+fn theorem(name: &str, evidence: &str) -> String {
+    format!(
+        "{}Evidence:\n{evidence}",
+        theorem_without_evidence(name, "")
+    )
+}
+
+fn corpus() -> Vec<TheoremDoc> {
+    let kani = "  kani: { unwind: 1, expect: SUCCESS }\n";
+    load_theorem_docs(&format!(
+        "{}---\n{}---\n{}",
+        theorem("Deposit", kani),
+        theorem("Balance", kani),
+        theorem("Audit", "  verus: {}\n"),
+    ))
+    .expect("corpus should load")
+}
+
+fn result(theorem: &str, verified: bool, checks: Vec<CheckResult>) -> HarnessResult {
+    HarnessResult {
+        harness: format!(
+            "theorem_ledger::{}",
+            mangle_theorem_harness(THEOREM_PATH, theorem).identifier()
+        ),
+        checks,
+        verified,
+        counterexample: None,
+        verification_time: None,
+    }
+}
+
+fn failed_check() -> CheckResult {
+    CheckResult {
+        property: "deposit.assertion.1".to_owned(),
+        status: CheckStatus::Failure,
+        description: "assertion failed: balance >= amount & amount > 0".to_owned(),
+        location: Some("src/ledger.rs:12:5 in function deposit".to_owned()),
+    }
+}
+
+fn report() -> JunitReport {
+    let results = vec![
+        result("Deposit", false, vec![failed_check()]),
+        result("Balance", true, vec![]),
+    ];
+    let mut report = JunitReport::default();
+    report.add_file(THEOREM_PATH, &corpus(), &results);
+    report
+}
+
+#[test]
+fn each_theorem_is_one_case() {
+    let suite = report().suites.into_iter().next().expect("one suite");
+
+    assert_eq!(suite.path, THEOREM_PATH);
+    assert_eq!(
+        suite
+            .cases
+            .iter()
+            .map(|case| case.theorem.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Deposit", "Balance", "Audit"]
+    );
+    assert!(matches!(
+        suite.cases.get(1).map(|case| &case.outcome),
+        Some(JunitOutcome::Passed)
+    ));
+    assert!(matches!(
+        suite.cases.get(2).map(|case| &case.outcome),
+        Some(JunitOutcome::Skipped)
+    ));
+}
+
+#[test]
+fn renders_failures_with_the_diagnostic() {
+    let mangled = mangle_theorem_harness(THEOREM_PATH, "Deposit");
+    let harness = mangled.identifier();
+
+    assert_eq!(
+        report().to_xml(),
+        format!(
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<testsuites name=\"theoremc\" tests=\"3\" failures=\"1\" skipped=\"1\">\n",
+                "  <testsuite name=\"theorems/ledger.theorem\" tests=\"3\" failures=\"1\" ",
+                "skipped=\"1\">\n",
+                "    <testcase classname=\"theorems/ledger.theorem\" name=\"Deposit\">\n",
+                "      <failure message=\"theorem &apos;Deposit&apos;: harness ",
+                "&apos;{harness}&apos; expected SUCCESS, but Kani reported FAILURE\" ",
+                "type=\"ExpectationMismatch\">theorem &apos;Deposit&apos;: harness ",
+                "&apos;{harness}&apos; expected SUCCESS, but Kani reported FAILURE\n",
+                "  deposit.assertion.1: assertion failed: balance &gt;= amount &amp; ",
+                "amount &gt; 0 at src/ledger.rs:12:5 in function deposit\n",
+                "</failure>\n",
+                "    </testcase>\n",
+                "    <testcase classname=\"theorems/ledger.theorem\" name=\"Balance\"/>\n",
+                "    <testcase classname=\"theorems/ledger.theorem\" name=\"Audit\">\n",
+                "      <skipped message=\"no Kani evidence\"/>\n",
+                "    </testcase>\n",
+                "  </testsuite>\n",
+                "</testsuites>\n",
+            ),
+            harness = harness,
+        )
+    );
+}
+
+#[test]
+fn missing_results_fail_the_theorem() {
+    let mut report = JunitReport::default();
+    report.add_file(THEOREM_PATH, &corpus(), &[]);
+
+    let failures = report
+        .suites
+        .iter()
+        .flat_map(|suite| &suite.cases)
+        .filter(|case| matches!(case.outcome, JunitOutcome::Failed { .. }))
+        .count();
+
+    assert_eq!(failures, 2);
+}
+
+#[test]
+fn empty_report_renders_an_empty_document() {
+    assert_eq!(
+        JunitReport::default().to_xml(),
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<testsuites name=\"theoremc\" tests=\"0\" failures=\"0\" skipped=\"0\">\n",
+            "</testsuites>\n",
+        )
+    );
+}
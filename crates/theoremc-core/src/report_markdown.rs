@@ -0,0 +1,139 @@
+//! Compact Markdown summaries of a verification run.
+//!
+//! [`MarkdownSummary`] renders one table row per theorem, giving its backend,
+//! the outcome its evidence expects, the outcome Kani reported, and how long
+//! verification took. The table is small enough to paste into a pull request
+//! description or post from a CI bot.
+
+use std::time::Duration;
+
+use super::{TheoremStatus, status_summary};
+use crate::results::kani::{HarnessResult, expected_harnesses};
+use crate::schema::TheoremDoc;
+
+/// One theorem's row of the summary table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownRow {
+    /// Theorem key, `{path}#{Theorem}`.
+    pub theorem: String,
+    /// Backend named by the theorem's evidence, such as `kani`.
+    pub backend: String,
+    /// Distinct outcomes the theorem's harnesses expect, in configuration
+    /// order.
+    pub expected: Vec<String>,
+    /// Distinct outcomes Kani reported for those harnesses, with `missing`
+    /// for a harness without a result.
+    pub actual: Vec<String>,
+    /// Total reported verification time of the theorem's harnesses.
+    pub duration: Option<Duration>,
+    /// Verification status judged by the run's Kani results.
+    pub status: TheoremStatus,
+}
+
+/// A per-run summary table of theorem outcomes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownSummary {
+    /// One row per theorem, in the order added.
+    pub rows: Vec<MarkdownRow>,
+}
+
+impl MarkdownSummary {
+    /// Adds a row for each of `docs`, loaded from `theorem_path`, judged by
+    /// the Kani `results`.
+    pub fn add_file(&mut self, theorem_path: &str, docs: &[TheoremDoc], results: &[HarnessResult]) {
+        self.rows.extend(
+            docs.iter()
+                .map(|doc| summary_row(theorem_path, doc, results)),
+        );
+    }
+
+    /// Renders the summary line and table as GitHub-flavoured Markdown.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            concat!(
+                "**{}**\n\n",
+                "| Theorem | Backend | Expected | Actual | Duration | Status |\n",
+                "| --- | --- | --- | --- | ---: | --- |\n",
+            ),
+            status_summary(self.rows.iter().map(|row| &row.status))
+        );
+        for row in &self.rows {
+            let cells = [
+                cell(&row.theorem),
+                cell(&row.backend),
+                joined(&row.expected),
+                joined(&row.actual),
+                row.duration.map_or_else(
+                    || "—".to_owned(),
+                    |time| format!("{:.2}s", time.as_secs_f64()),
+                ),
+                row.status.label().to_owned(),
+            ];
+            markdown.push_str("| ");
+            markdown.push_str(&cells.join(" | "));
+            markdown.push_str(" |\n");
+        }
+        markdown
+    }
+}
+
+fn summary_row(theorem_path: &str, doc: &TheoremDoc, results: &[HarnessResult]) -> MarkdownRow {
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+    let mut duration = None;
+    for (harness, kani) in expected_harnesses(theorem_path, doc) {
+        push_distinct(&mut expected, kani.expect.as_str());
+        let result = results.iter().find(|result| result.identifier() == harness);
+        push_distinct(
+            &mut actual,
+            result.map_or("missing", |found| found.outcome().as_str()),
+        );
+        if let Some(time) = result.and_then(|found| found.verification_time) {
+            duration = Some(duration.unwrap_or(Duration::ZERO) + time);
+        }
+    }
+    MarkdownRow {
+        theorem: format!("{theorem_path}#{}", doc.theorem.as_str()),
+        backend: backend(doc).to_owned(),
+        expected,
+        actual,
+        duration,
+        status: TheoremStatus::of(theorem_path, doc, results),
+    }
+}
+
+/// Names the first backend the theorem's evidence configures.
+const fn backend(doc: &TheoremDoc) -> &'static str {
+    if !doc.evidence.kani.is_empty() {
+        "kani"
+    } else if doc.evidence.verus.is_some() {
+        "verus"
+    } else {
+        "stateright"
+    }
+}
+
+fn push_distinct(values: &mut Vec<String>, value: &str) {
+    if !values.iter().any(|existing| existing == value) {
+        values.push(value.to_owned());
+    }
+}
+
+fn joined(values: &[String]) -> String {
+    if values.is_empty() {
+        "—".to_owned()
+    } else {
+        cell(&values.join(", "))
+    }
+}
+
+/// Escapes text for a table cell, where `|` would end the cell and line
+/// breaks would end the row.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+#[path = "report_markdown_tests.rs"]
+mod tests;
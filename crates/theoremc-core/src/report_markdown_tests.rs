@@ -0,0 +1,102 @@
+//! Unit tests for Markdown run summaries.
+
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+
+use super::MarkdownSummary;
+use crate::mangle::mangle_theorem_harness;
+use crate::results::kani::{CheckResult, CheckStatus, HarnessResult};
+use crate::schema::load_theorem_docs;
+use crate::schema::test_fixtures::theorem_without_evidence;
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+fn theorem(name: &str, evidence: &str) -> String {
+    format!(
+        "{}Evidence:\n{evidence}",
+        theorem_without_evidence(name, "")
+    )
+}
+
+fn result(harness: String, verified: bool, millis: u64) -> HarnessResult {
+    HarnessResult {
+        harness,
+        checks: vec![CheckResult {
+            property: "h.assertion.1".to_owned(),
+            status: if verified {
+                CheckStatus::Success
+            } else {
+                CheckStatus::Failure
+            },
+            description: String::new(),
+            location: None,
+        }],
+        verified,
+        counterexample: None,
+        verification_time: Some(Duration::from_millis(millis)),
+    }
+}
+
+fn summary() -> MarkdownSummary {
+    let docs = load_theorem_docs(&format!(
+        "{}---\n{}---\n{}",
+        theorem(
+            "Deposit",
+            concat!(
+                "  kani:\n",
+                "    - { name: fast, unwind: 1, expect: SUCCESS }\n",
+                "    - { name: slow, unwind: 4, expect: SUCCESS }\n",
+            ),
+        ),
+        theorem("Overdraw", "  kani: { unwind: 1, expect: FAILURE }\n"),
+        theorem("Audit", "  verus: {}\n"),
+    ))
+    .expect("theorems should load");
+    let deposit = mangle_theorem_harness(THEOREM_PATH, "Deposit");
+    let overdraw = mangle_theorem_harness(THEOREM_PATH, "Overdraw");
+    let results = vec![
+        result(format!("{}__fast", deposit.identifier()), true, 420),
+        result(format!("{}__slow", deposit.identifier()), false, 1_580),
+        result(overdraw.identifier().to_owned(), false, 50),
+    ];
+    let mut summary = MarkdownSummary::default();
+    summary.add_file(THEOREM_PATH, &docs, &results);
+    summary
+}
+
+#[test]
+fn renders_one_row_per_theorem() {
+    assert_eq!(
+        summary().to_markdown(),
+        concat!(
+            "**3 theorems: 1 passed, 1 failed, 1 not run**\n\n",
+            "| Theorem | Backend | Expected | Actual | Duration | Status |\n",
+            "| --- | --- | --- | --- | ---: | --- |\n",
+            "| theorems/ledger.theorem#Deposit | kani | SUCCESS | SUCCESS, FAILURE | 2.00s ",
+            "| failed |\n",
+            "| theorems/ledger.theorem#Overdraw | kani | FAILURE | FAILURE | 0.05s | passed |\n",
+            "| theorems/ledger.theorem#Audit | verus | — | — | — | not run |\n",
+        )
+    );
+}
+
+#[test]
+fn harnesses_without_results_are_missing() {
+    let docs = load_theorem_docs(&theorem(
+        "Deposit",
+        "  kani: { unwind: 1, expect: SUCCESS }\n",
+    ))
+    .expect("theorem should load");
+    let mut summary = MarkdownSummary::default();
+    summary.add_file(THEOREM_PATH, &docs, &[]);
+
+    let row = summary.rows.first().expect("one row");
+    assert_eq!(row.actual, vec!["missing".to_owned()]);
+    assert_eq!(row.duration, None);
+}
+
+#[test]
+fn cells_escape_table_syntax() {
+    assert_eq!(super::cell("a|b\nc"), "a\\|b c");
+}
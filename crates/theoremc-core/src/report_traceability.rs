@@ -0,0 +1,132 @@
+//! Theorem-to-requirement traceability matrices built from `Traces`.
+//!
+//! [`TraceabilityMatrix`] inverts each theorem's `Traces` entries into one row
+//! per requirement listing the theorems that provide evidence for it, plus
+//! the theorems that trace to no requirement at all.
+
+use std::collections::BTreeMap;
+
+use super::write_csv_record;
+use crate::schema::TheoremDoc;
+
+/// One requirement and the theorems that trace to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementRow {
+    /// Requirements system that owns `id`.
+    pub system: String,
+    /// Requirement identifier.
+    pub id: String,
+    /// First link to the requirement declared by any tracing theorem.
+    pub url: Option<String>,
+    /// Names of the theorems tracing to this requirement, in load order.
+    pub theorems: Vec<String>,
+}
+
+impl RequirementRow {
+    /// Adds `theorem` to the row once, keeping the first link seen.
+    fn record(&mut self, theorem: &str, url: Option<&String>) {
+        if self.url.is_none() {
+            self.url = url.cloned();
+        }
+        if !self.theorems.iter().any(|name| name == theorem) {
+            self.theorems.push(theorem.to_owned());
+        }
+    }
+}
+
+/// Requirements and the theorems providing evidence for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceabilityMatrix {
+    /// One row per requirement, ordered by system and then identifier.
+    pub rows: Vec<RequirementRow>,
+    /// Names of theorems without any `Traces` entry, in load order.
+    pub untraced: Vec<String>,
+}
+
+impl TraceabilityMatrix {
+    /// Builds the matrix for `docs`.
+    ///
+    /// Requirement identifiers and systems are compared after trimming.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::report::traceability::TraceabilityMatrix;
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let yaml = r#"
+    ///     Theorem: DepositIsPositive
+    ///     About: Deposits are positive
+    ///     Traces:
+    ///       - id: REQ-7
+    ///         system: ledger-spec
+    ///     Prove:
+    ///       - assert: "true"
+    ///         because: "trivially true"
+    ///     Evidence:
+    ///       kani:
+    ///         unwind: 1
+    ///         expect: SUCCESS
+    ///     Witness:
+    ///       - cover: "true"
+    ///         because: "always reachable"
+    ///     "#;
+    ///     let docs = load_theorem_docs(yaml).unwrap();
+    ///     let matrix = TraceabilityMatrix::from_docs(&docs);
+    ///     assert_eq!(matrix.rows[0].theorems, ["DepositIsPositive"]);
+    #[must_use]
+    pub fn from_docs<'a>(docs: impl IntoIterator<Item = &'a TheoremDoc>) -> Self {
+        let mut rows: BTreeMap<(String, String), RequirementRow> = BTreeMap::new();
+        let mut untraced = Vec::new();
+        for doc in docs {
+            let theorem = doc.theorem.as_str();
+            if doc.traces.is_empty() {
+                untraced.push(theorem.to_owned());
+            }
+            for trace in &doc.traces {
+                let system = trace.system.trim().to_owned();
+                let id = trace.id.trim().to_owned();
+                let row =
+                    rows.entry((system.clone(), id.clone()))
+                        .or_insert_with(|| RequirementRow {
+                            system,
+                            id,
+                            url: None,
+                            theorems: Vec::new(),
+                        });
+                row.record(theorem, trace.url.as_ref());
+            }
+        }
+        Self {
+            rows: rows.into_values().collect(),
+            untraced,
+        }
+    }
+
+    /// Renders the matrix as CSV with a `system,id,url,theorems` header.
+    ///
+    /// Theorem names within a row are separated by `;`. Untraced theorems are
+    /// listed with empty requirement columns after every requirement row.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("system,id,url,theorems\n");
+        for row in &self.rows {
+            write_csv_record(
+                &mut csv,
+                &[
+                    &row.system,
+                    &row.id,
+                    row.url.as_deref().unwrap_or_default(),
+                    &row.theorems.join(";"),
+                ],
+            );
+        }
+        for theorem in &self.untraced {
+            write_csv_record(&mut csv, &["", "", "", theorem]);
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+#[path = "report_traceability_tests.rs"]
+mod tests;
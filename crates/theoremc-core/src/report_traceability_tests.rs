@@ -0,0 +1,83 @@
+//! Unit tests for requirement traceability matrices.
+
+use super::{RequirementRow, TraceabilityMatrix};
+use crate::schema::test_fixtures::theorem;
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+fn corpus() -> Vec<TheoremDoc> {
+    let yaml = [
+        theorem(
+            "Deposit",
+            concat!(
+                "Traces:\n",
+                "  - { id: REQ-2, system: ledger }\n",
+                "  - { id: SEC-1, system: audit, url: 'https://example.com/sec-1' }\n",
+            ),
+        ),
+        theorem("Report", ""),
+        theorem(
+            "Transfer",
+            "Traces:\n  - { id: REQ-2, system: ledger, url: 'https://example.com/req-2' }\n",
+        ),
+    ]
+    .join("---\n");
+    load_theorem_docs(&yaml).expect("traced theorems should load")
+}
+
+fn row(system: &str, id: &str, url: Option<&str>, theorems: &[&str]) -> RequirementRow {
+    RequirementRow {
+        system: system.to_owned(),
+        id: id.to_owned(),
+        url: url.map(str::to_owned),
+        theorems: theorems.iter().map(|name| (*name).to_owned()).collect(),
+    }
+}
+
+#[test]
+fn matrix_groups_theorems_by_requirement() {
+    let matrix = TraceabilityMatrix::from_docs(&corpus());
+
+    assert_eq!(
+        matrix.rows,
+        [
+            row(
+                "audit",
+                "SEC-1",
+                Some("https://example.com/sec-1"),
+                &["Deposit"]
+            ),
+            row(
+                "ledger",
+                "REQ-2",
+                Some("https://example.com/req-2"),
+                &["Deposit", "Transfer"]
+            ),
+        ]
+    );
+    assert_eq!(matrix.untraced, ["Report"]);
+}
+
+#[test]
+fn matrix_renders_as_csv() {
+    let mut matrix = TraceabilityMatrix::from_docs(&corpus());
+    matrix.untraced.push("Needs, \"quotes\"".to_owned());
+
+    assert_eq!(
+        matrix.to_csv(),
+        concat!(
+            "system,id,url,theorems\n",
+            "audit,SEC-1,https://example.com/sec-1,Deposit\n",
+            "ledger,REQ-2,https://example.com/req-2,Deposit;Transfer\n",
+            ",,,Report\n",
+            ",,,\"Needs, \"\"quotes\"\"\"\n",
+        )
+    );
+}
+
+#[test]
+fn empty_corpus_renders_only_the_header() {
+    let matrix = TraceabilityMatrix::from_docs(&[]);
+
+    assert_eq!(matrix, TraceabilityMatrix::default());
+    assert_eq!(matrix.to_csv(), "system,id,url,theorems\n");
+}
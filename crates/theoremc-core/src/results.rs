@@ -0,0 +1,13 @@
+//! Verification results reported by proof backends.
+//!
+//! Backends run outside `theoremc`, so their results arrive as tool output.
+//! Each backend module parses that output into structured results and checks
+//! them against the outcome every theorem's evidence expects. [`diff`]
+//! compares a run with a baseline run to surface regressions.
+
+#[path = "results_diff.rs"]
+mod diff;
+#[path = "results_kani.rs"]
+pub mod kani;
+
+pub use diff::{ResultChange, TheoremChange, TheoremRun, diff};
@@ -0,0 +1,152 @@
+//! Comparison of a verification run against a baseline run.
+//!
+//! A [`TheoremRun`] condenses one theorem's Kani results into whether its
+//! expectations held and how long its harnesses took. Runs serialize to JSON,
+//! so a previous run can be kept as a baseline file, and [`diff`] classifies
+//! every theorem whose verdict or duration changed since that baseline.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::kani::{HarnessResult, check_expectations, expected_harnesses};
+use crate::schema::TheoremDoc;
+
+/// One theorem's verdict in a verification run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TheoremRun {
+    /// Theorem key, `{path}#{Theorem}`.
+    pub theorem: String,
+    /// Whether every harness of the theorem reported its expected outcome.
+    pub passed: bool,
+    /// Total reported verification time of the theorem's harnesses, in
+    /// milliseconds.
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+impl TheoremRun {
+    /// Condenses the Kani `results` for `docs`, loaded from `theorem_path`,
+    /// into one run per theorem with Kani evidence, in document order.
+    #[must_use]
+    pub fn from_kani_results(
+        theorem_path: &str,
+        docs: &[TheoremDoc],
+        results: &[HarnessResult],
+    ) -> Vec<Self> {
+        docs.iter()
+            .filter(|doc| !doc.evidence.kani.is_empty())
+            .map(|doc| Self {
+                theorem: format!("{theorem_path}#{}", doc.theorem.as_str()),
+                passed: check_expectations(theorem_path, std::slice::from_ref(doc), results)
+                    .is_empty(),
+                duration_ms: total_time(theorem_path, doc, results)
+                    .map(|time| u64::try_from(time.as_millis()).unwrap_or(u64::MAX)),
+            })
+            .collect()
+    }
+}
+
+/// How a theorem's verdict or duration changed since the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultChange {
+    /// The theorem fails now but passed in, or was absent from, the
+    /// baseline.
+    NewlyFailing,
+    /// The theorem passes now but failed in the baseline.
+    NewlyPassing,
+    /// The theorem failed in both runs.
+    StillFailing,
+    /// The theorem passed in both runs, but its duration moved by more than
+    /// the threshold.
+    DurationChanged {
+        /// Baseline duration.
+        previous: Duration,
+        /// Current duration.
+        current: Duration,
+    },
+}
+
+/// A theorem whose result changed since the baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TheoremChange {
+    /// Theorem key, `{path}#{Theorem}`.
+    pub theorem: String,
+    /// How the result changed.
+    pub change: ResultChange,
+}
+
+/// Classifies every theorem of the `current` run against the `previous`
+/// baseline, in `current` order.
+///
+/// Theorems that passed in both runs are reported only when both durations
+/// are known and differ by more than `threshold`. Theorems missing from the
+/// current run are ignored.
+///
+/// # Examples
+///
+///     use std::time::Duration;
+///
+///     use theoremc_core::results::{ResultChange, TheoremRun, diff};
+///
+///     let run = |passed| TheoremRun {
+///         theorem: "theorems/ledger.theorem#Deposit".to_owned(),
+///         passed,
+///         duration_ms: Some(400),
+///     };
+///     let changes = diff(&[run(true)], &[run(false)], Duration::from_secs(1));
+///     assert_eq!(changes[0].change, ResultChange::NewlyFailing);
+#[must_use]
+pub fn diff(
+    previous: &[TheoremRun],
+    current: &[TheoremRun],
+    threshold: Duration,
+) -> Vec<TheoremChange> {
+    current
+        .iter()
+        .filter_map(|run| {
+            let baseline = previous.iter().find(|old| old.theorem == run.theorem);
+            classify(baseline, run, threshold).map(|change| TheoremChange {
+                theorem: run.theorem.clone(),
+                change,
+            })
+        })
+        .collect()
+}
+
+fn classify(
+    baseline: Option<&TheoremRun>,
+    run: &TheoremRun,
+    threshold: Duration,
+) -> Option<ResultChange> {
+    let was_passing = baseline.is_none_or(|old| old.passed);
+    match (was_passing, run.passed) {
+        (true, false) => Some(ResultChange::NewlyFailing),
+        (false, true) => Some(ResultChange::NewlyPassing),
+        (false, false) => Some(ResultChange::StillFailing),
+        (true, true) => {
+            let previous = Duration::from_millis(baseline?.duration_ms?);
+            let current = Duration::from_millis(run.duration_ms?);
+            (previous.abs_diff(current) > threshold)
+                .then_some(ResultChange::DurationChanged { previous, current })
+        }
+    }
+}
+
+/// Sums the reported verification times of the theorem's harnesses.
+fn total_time(theorem_path: &str, doc: &TheoremDoc, results: &[HarnessResult]) -> Option<Duration> {
+    expected_harnesses(theorem_path, doc)
+        .iter()
+        .filter_map(|(harness, _)| {
+            results
+                .iter()
+                .find(|result| result.identifier() == harness)
+                .and_then(|result| result.verification_time)
+        })
+        .reduce(|total, time| total + time)
+}
+
+#[cfg(test)]
+#[path = "results_diff_tests.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+//! Unit tests for baseline comparison of verification runs.
+
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{ResultChange, TheoremChange, TheoremRun, diff};
+use crate::mangle::mangle_theorem_harness;
+use crate::results::kani::HarnessResult;
+use crate::schema::load_theorem_docs;
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+fn run(theorem: &str, passed: bool, duration_ms: Option<u64>) -> TheoremRun {
+    TheoremRun {
+        theorem: format!("{THEOREM_PATH}#{theorem}"),
+        passed,
+        duration_ms,
+    }
+}
+
+fn change(theorem: &str, change: ResultChange) -> TheoremChange {
+    TheoremChange {
+        theorem: format!("{THEOREM_PATH}#{theorem}"),
+        change,
+    }
+}
+
+#[test]
+fn classifies_verdict_changes_in_current_order() {
+    let previous = vec![
+        run("Deposit", true, Some(100)),
+        run("Withdraw", false, Some(100)),
+        run("Transfer", false, Some(100)),
+        run("Removed", false, None),
+    ];
+    let current = vec![
+        run("Transfer", false, Some(100)),
+        run("Withdraw", true, Some(100)),
+        run("Deposit", false, Some(100)),
+        run("Added", false, None),
+        run("AddedPassing", true, None),
+    ];
+
+    assert_eq!(
+        diff(&previous, &current, Duration::from_secs(1)),
+        vec![
+            change("Transfer", ResultChange::StillFailing),
+            change("Withdraw", ResultChange::NewlyPassing),
+            change("Deposit", ResultChange::NewlyFailing),
+            change("Added", ResultChange::NewlyFailing),
+        ]
+    );
+}
+
+#[rstest]
+#[case::slower(Some(1_000), Some(2_500), true)]
+#[case::faster(Some(2_500), Some(1_000), true)]
+#[case::within_threshold(Some(1_000), Some(1_900), false)]
+#[case::unknown_baseline(None, Some(2_500), false)]
+#[case::unknown_current(Some(1_000), None, false)]
+fn duration_changes_beyond_the_threshold_are_reported(
+    #[case] previous: Option<u64>,
+    #[case] current: Option<u64>,
+    #[case] reported: bool,
+) {
+    let changes = diff(
+        &[run("Deposit", true, previous)],
+        &[run("Deposit", true, current)],
+        Duration::from_secs(1),
+    );
+
+    let expected = previous
+        .zip(current)
+        .filter(|_| reported)
+        .map(|(old, new)| {
+            change(
+                "Deposit",
+                ResultChange::DurationChanged {
+                    previous: Duration::from_millis(old),
+                    current: Duration::from_millis(new),
+                },
+            )
+        });
+    assert_eq!(changes, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn runs_condense_kani_results() {
+    let docs = load_theorem_docs(concat!(
+        "Theorem: Deposit\n",
+        "About: deposits never overdraw\n",
+        "Prove:\n",
+        "  - assert: 'true'\n",
+        "    because: trivially true\n",
+        "Evidence:\n",
+        "  kani:\n",
+        "    - { name: small, unwind: 1, expect: SUCCESS }\n",
+        "    - { name: large, unwind: 4, expect: SUCCESS }\n",
+        "Witness:\n",
+        "  - cover: 'true'\n",
+        "    because: always reachable\n",
+    ))
+    .expect("theorem should load");
+    let harness = mangle_theorem_harness(THEOREM_PATH, "Deposit");
+    let results: Vec<HarnessResult> = [("__small", 400), ("__large", 1_100)]
+        .into_iter()
+        .map(|(suffix, millis)| HarnessResult {
+            harness: format!("{}{suffix}", harness.identifier()),
+            checks: vec![],
+            verified: true,
+            counterexample: None,
+            verification_time: Some(Duration::from_millis(millis)),
+        })
+        .collect();
+
+    assert_eq!(
+        TheoremRun::from_kani_results(THEOREM_PATH, &docs, &results),
+        vec![run("Deposit", true, Some(1_500))]
+    );
+}
+
+#[test]
+fn runs_round_trip_through_json() {
+    let runs = vec![
+        run("Deposit", true, Some(1_500)),
+        run("Withdraw", false, None),
+    ];
+
+    let json = serde_json::to_string(&runs).expect("runs should encode");
+    let decoded: Vec<TheoremRun> = serde_json::from_str(&json).expect("runs should decode");
+
+    assert_eq!(decoded, runs);
+}
@@ -0,0 +1,392 @@
+//! Kani results parsed from `cargo kani` output.
+//!
+//! [`parse_kani_output`] reads the textual report `cargo kani` prints, in its
+//! default or terse format, into one [`HarnessResult`] per harness: the status
+//! of every check, including `kani::cover!` properties, the overall verdict,
+//! and the counterexample values of any concrete playback test.
+//! [`check_expectations`] then compares those results with the `expect`
+//! outcome each theorem's Kani evidence declares, and
+//! [`find_vacuous_harnesses`] flags harnesses that succeeded only because no
+//! `Witness` cover was satisfiable.
+//!
+//! Only the textual report is read. `cargo kani` has no stable JSON results
+//! format, so machine-readable output is not parsed; the roadmap tracks
+//! adding it once one exists.
+
+#[path = "results_kani_expect.rs"]
+mod expect;
+
+use std::time::Duration;
+
+use crate::schema::KaniExpectation;
+
+pub(crate) use expect::expected_harnesses;
+pub use expect::{ExpectationMismatch, VacuousHarness, check_expectations, find_vacuous_harnesses};
+
+/// Status Kani reports for one check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The property holds.
+    Success,
+    /// The property fails on some execution.
+    Failure,
+    /// No execution reaches the property.
+    Unreachable,
+    /// Kani could not decide the property.
+    Undetermined,
+    /// A cover property is reached on some execution.
+    Satisfied,
+    /// A cover property is reachable but its condition never holds.
+    Unsatisfiable,
+}
+
+impl CheckStatus {
+    fn parse(status: &str) -> Option<Self> {
+        match status {
+            "SUCCESS" => Some(Self::Success),
+            "FAILURE" => Some(Self::Failure),
+            "UNREACHABLE" => Some(Self::Unreachable),
+            "UNDETERMINED" => Some(Self::Undetermined),
+            "SATISFIED" => Some(Self::Satisfied),
+            "UNSATISFIABLE" => Some(Self::Unsatisfiable),
+            _ => None,
+        }
+    }
+}
+
+/// One check of a harness, such as an assertion or a cover property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Kani's property name, such as `verify_deposit.assertion.1`.
+    pub property: String,
+    /// Reported status.
+    pub status: CheckStatus,
+    /// Description of the property, such as `assertion failed: x > 0`.
+    pub description: String,
+    /// Source location, when Kani reports one.
+    pub location: Option<String>,
+}
+
+impl CheckResult {
+    /// Returns whether the check is a `kani::cover!` property.
+    #[must_use]
+    pub fn is_cover(&self) -> bool {
+        self.property.split('.').any(|segment| segment == "cover")
+            || matches!(
+                self.status,
+                CheckStatus::Satisfied | CheckStatus::Unsatisfiable
+            )
+    }
+}
+
+/// The result of verifying one harness.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarnessResult {
+    /// Harness path as Kani prints it, such as `theorem_ledger::deposit`.
+    pub harness: String,
+    /// Every reported check, in report order; empty for terse output.
+    pub checks: Vec<CheckResult>,
+    /// Whether Kani reported `VERIFICATION:- SUCCESSFUL`.
+    pub verified: bool,
+    /// Values Kani chose for the harness's `kani::any()` calls, in call
+    /// order, when a concrete playback test was printed.
+    pub counterexample: Option<Vec<String>>,
+    /// How long Kani took to verify the harness, when it reported a
+    /// `Verification Time`.
+    pub verification_time: Option<Duration>,
+}
+
+impl HarnessResult {
+    /// Returns the harness's identifier: the last segment of its path.
+    #[must_use]
+    pub fn identifier(&self) -> &str {
+        self.harness
+            .rsplit("::")
+            .next()
+            .unwrap_or(self.harness.as_str())
+    }
+
+    /// Iterates over the harness's `kani::cover!` results.
+    pub fn covers(&self) -> impl Iterator<Item = &CheckResult> {
+        self.checks.iter().filter(|check| check.is_cover())
+    }
+
+    /// Returns the outcome the results amount to, in `expect` terms.
+    ///
+    /// A failed check is `FAILURE`. Otherwise an undecided check is
+    /// `UNDETERMINED`, and any other failed verdict, as in terse output, is
+    /// `FAILURE`. A harness none of whose checks, apart from covers, is
+    /// reachable is `UNREACHABLE`.
+    #[must_use]
+    pub fn outcome(&self) -> KaniExpectation {
+        let statuses = self
+            .checks
+            .iter()
+            .filter(|check| !check.is_cover())
+            .map(|check| check.status)
+            .collect::<Vec<_>>();
+        if statuses.contains(&CheckStatus::Failure) {
+            KaniExpectation::Failure
+        } else if statuses.contains(&CheckStatus::Undetermined) {
+            KaniExpectation::Undetermined
+        } else if !self.verified {
+            KaniExpectation::Failure
+        } else if !statuses.is_empty()
+            && statuses
+                .iter()
+                .all(|status| *status == CheckStatus::Unreachable)
+        {
+            KaniExpectation::Unreachable
+        } else {
+            KaniExpectation::Success
+        }
+    }
+}
+
+/// Errors raised while parsing `cargo kani` output.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KaniOutputError {
+    /// A check reported a status Kani does not define.
+    #[error("line {line}: unknown check status '{status}'")]
+    UnknownStatus {
+        /// One-based line number of the status.
+        line: usize,
+        /// The status as printed.
+        status: String,
+    },
+
+    /// A check detail appeared before any `Check` heading.
+    #[error("line {line}: check detail outside a check")]
+    OrphanDetail {
+        /// One-based line number of the detail.
+        line: usize,
+    },
+
+    /// A check was reported without a status.
+    #[error("harness '{harness}': check '{property}' has no status")]
+    MissingStatus {
+        /// Harness reporting the check.
+        harness: String,
+        /// Property name of the check.
+        property: String,
+    },
+
+    /// The output ended before a harness reported its verdict.
+    #[error("harness '{harness}' has no verification verdict")]
+    MissingVerdict {
+        /// Harness without a verdict.
+        harness: String,
+    },
+}
+
+/// Parses the textual output of `cargo kani` into one result per harness, in
+/// verification order.
+///
+/// # Errors
+///
+/// Returns [`KaniOutputError`] when a check has an unknown or missing status,
+/// or a harness has no `VERIFICATION:-` verdict, as when the output was cut
+/// short.
+///
+/// # Examples
+///
+///     use theoremc_core::results::kani::{CheckStatus, parse_kani_output};
+///
+///     let results = parse_kani_output(concat!(
+///         "Checking harness ledger::deposit...\n",
+///         "RESULTS:\n",
+///         "Check 1: deposit.assertion.1\n",
+///         "\t - Status: SUCCESS\n",
+///         "\t - Description: \"assertion failed: balance >= amount\"\n",
+///         "VERIFICATION:- SUCCESSFUL\n",
+///     ))
+///     .unwrap();
+///     assert_eq!(results[0].identifier(), "deposit");
+///     assert_eq!(results[0].checks[0].status, CheckStatus::Success);
+pub fn parse_kani_output(output: &str) -> Result<Vec<HarnessResult>, KaniOutputError> {
+    let mut parser = Parser::default();
+    for (index, line) in output.lines().enumerate() {
+        parser.line(index + 1, line)?;
+    }
+    parser.finish()
+}
+
+/// Line-by-line parser state.
+#[derive(Default)]
+struct Parser {
+    finished: Vec<HarnessResult>,
+    current: Option<(HarnessResult, bool)>,
+    check: Option<PendingCheck>,
+    playback: Option<(String, Vec<String>)>,
+}
+
+/// A check whose details are still being read.
+struct PendingCheck {
+    property: String,
+    status: Option<CheckStatus>,
+    description: String,
+    location: Option<String>,
+}
+
+impl Parser {
+    fn line(&mut self, number: usize, line: &str) -> Result<(), KaniOutputError> {
+        let trimmed = line.trim();
+        if let Some(harness) = trimmed
+            .strip_prefix("Checking harness ")
+            .map(|rest| rest.trim_end_matches("..."))
+        {
+            self.finish_harness()?;
+            self.current = Some((harness_result(harness), false));
+        } else if let Some(property) = check_heading(trimmed) {
+            self.finish_check()?;
+            self.check = Some(PendingCheck {
+                property: property.to_owned(),
+                status: None,
+                description: String::new(),
+                location: None,
+            });
+        } else if let Some(detail) = trimmed.strip_prefix("- ") {
+            self.detail(number, detail)?;
+        } else if let Some(verdict) = trimmed.strip_prefix("VERIFICATION:-") {
+            self.finish_check()?;
+            if let Some((harness, reported)) = &mut self.current {
+                harness.verified = verdict.trim() == "SUCCESSFUL";
+                *reported = true;
+            }
+        } else if let Some(seconds) = trimmed.strip_prefix("Verification Time: ") {
+            self.record_time(seconds);
+        } else {
+            self.playback_line(trimmed);
+        }
+        Ok(())
+    }
+
+    fn detail(&mut self, number: usize, detail: &str) -> Result<(), KaniOutputError> {
+        let Some((key @ ("Status" | "Description" | "Location"), value)) = detail.split_once(": ")
+        else {
+            return Ok(());
+        };
+        let check = self
+            .check
+            .as_mut()
+            .ok_or(KaniOutputError::OrphanDetail { line: number })?;
+        match key {
+            "Status" => {
+                let status =
+                    CheckStatus::parse(value).ok_or_else(|| KaniOutputError::UnknownStatus {
+                        line: number,
+                        status: value.to_owned(),
+                    })?;
+                check.status = Some(status);
+            }
+            "Description" => value.trim_matches('"').clone_into(&mut check.description),
+            "Location" => check.location = Some(value.to_owned()),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Collects the value comments of a concrete playback test, which Kani
+    /// prints as `// <value>` above each byte vector.
+    fn playback_line(&mut self, trimmed: &str) {
+        if let Some(harness) = trimmed
+            .strip_prefix("Concrete playback unit test for `")
+            .and_then(|rest| rest.strip_suffix("`:"))
+        {
+            self.playback = Some((harness.to_owned(), Vec::new()));
+        } else if trimmed.contains("concrete_playback_run") {
+            if let Some((harness, values)) = self.playback.take() {
+                self.record_counterexample(&harness, values);
+            }
+        } else if let Some((_, values)) = &mut self.playback
+            && let Some(value) = trimmed.strip_prefix("// ")
+        {
+            values.push(value.to_owned());
+        }
+    }
+
+    /// Records a `Verification Time` such as `0.42s` against the current
+    /// harness, ignoring times Kani did not print in seconds.
+    fn record_time(&mut self, seconds: &str) {
+        let time = seconds
+            .strip_suffix('s')
+            .and_then(|value| value.parse::<f64>().ok())
+            .and_then(|value| Duration::try_from_secs_f64(value).ok());
+        if let Some((harness, _)) = &mut self.current {
+            harness.verification_time = time;
+        }
+    }
+
+    fn record_counterexample(&mut self, harness: &str, values: Vec<String>) {
+        let current = self.current.as_mut().map(|(result, _)| result);
+        if let Some(result) = current
+            .into_iter()
+            .chain(self.finished.iter_mut().rev())
+            .find(|result| result.harness == harness || result.identifier() == harness)
+        {
+            result.counterexample = Some(values);
+        }
+    }
+
+    fn finish_check(&mut self) -> Result<(), KaniOutputError> {
+        let Some(check) = self.check.take() else {
+            return Ok(());
+        };
+        let Some((harness, _)) = &mut self.current else {
+            return Ok(());
+        };
+        let status = check.status.ok_or_else(|| KaniOutputError::MissingStatus {
+            harness: harness.harness.clone(),
+            property: check.property.clone(),
+        })?;
+        harness.checks.push(CheckResult {
+            property: check.property,
+            status,
+            description: check.description,
+            location: check.location,
+        });
+        Ok(())
+    }
+
+    fn finish_harness(&mut self) -> Result<(), KaniOutputError> {
+        self.finish_check()?;
+        match self.current.take() {
+            Some((harness, true)) => self.finished.push(harness),
+            Some((harness, false)) => {
+                return Err(KaniOutputError::MissingVerdict {
+                    harness: harness.harness,
+                });
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<HarnessResult>, KaniOutputError> {
+        self.finish_harness()?;
+        Ok(self.finished)
+    }
+}
+
+fn harness_result(harness: &str) -> HarnessResult {
+    HarnessResult {
+        harness: harness.to_owned(),
+        checks: Vec::new(),
+        verified: false,
+        counterexample: None,
+        verification_time: None,
+    }
+}
+
+/// Returns the property of a `Check <n>: <property>` heading.
+fn check_heading(trimmed: &str) -> Option<&str> {
+    let (number, property) = trimmed.strip_prefix("Check ")?.split_once(": ")?;
+    number
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then_some(property.trim())
+}
+
+#[cfg(test)]
+#[path = "results_kani_tests.rs"]
+mod tests;
@@ -0,0 +1,226 @@
+//! Comparison of Kani results with each theorem's declared expectation.
+//!
+//! Every Kani configuration of a theorem yields one harness, or one per
+//! verified contract, named as the generated code names it: the theorem's
+//! harness identifier, a `__<name>` suffix for a named configuration, and a
+//! `__contract_<n>` suffix per contract. Each such harness must have a result
+//! whose outcome is the configuration's `expect`.
+//!
+//! A harness that succeeds while Kani reports every one of its `Witness`
+//! covers as `UNSATISFIABLE` has proven its assertions of no execution at
+//! all. Such vacuous success fails the expectation unless the configuration
+//! sets `allow_vacuous`.
+
+use super::{CheckStatus, HarnessResult};
+use crate::mangle::mangle_theorem_harness;
+use crate::schema::{KaniEvidence, KaniExpectation, TheoremDoc};
+
+/// A theorem harness whose Kani result disagrees with its evidence.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ExpectationMismatch {
+    /// Kani reported no result for the harness.
+    #[error("theorem '{theorem}': no Kani result for harness '{harness}'")]
+    MissingResult {
+        /// Theorem whose harness has no result.
+        theorem: String,
+        /// Harness identifier.
+        harness: String,
+    },
+
+    /// The harness's outcome differs from its declared `expect`.
+    #[error(
+        "theorem '{theorem}': harness '{harness}' expected {expected}, but Kani reported {actual}",
+        expected = expected.as_str(),
+        actual = actual.as_str()
+    )]
+    UnexpectedOutcome {
+        /// Theorem whose harness disagreed.
+        theorem: String,
+        /// Harness identifier.
+        harness: String,
+        /// Outcome the evidence declares.
+        expected: KaniExpectation,
+        /// Outcome Kani reported.
+        actual: KaniExpectation,
+    },
+
+    /// The harness succeeded, but only vacuously, and its configuration does
+    /// not set `allow_vacuous`.
+    #[error(
+        "theorem '{theorem}': harness '{harness}' passed vacuously: every Witness cover was \
+         UNSATISFIABLE"
+    )]
+    VacuousSuccess {
+        /// Theorem whose harness passed vacuously.
+        theorem: String,
+        /// Harness identifier.
+        harness: String,
+    },
+}
+
+/// A harness whose success is vacuous because Kani reported every one of its
+/// `Witness` covers as `UNSATISFIABLE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VacuousHarness {
+    /// Theorem declaring the harness.
+    pub theorem: String,
+    /// Harness identifier.
+    pub harness: String,
+    /// Whether the configuration sets `allow_vacuous`, making the finding a
+    /// warning rather than a failure.
+    pub allowed: bool,
+}
+
+/// Checks every Kani harness of `docs`, loaded from `theorem_path`, against
+/// `results`, returning one mismatch per disagreeing harness in document and
+/// configuration order. A vacuous success counts as a mismatch unless the
+/// configuration sets `allow_vacuous`.
+///
+/// Results are matched by harness identifier, so harnesses Kani prints with
+/// a module path still match. Results for harnesses of other files are
+/// ignored.
+///
+/// # Examples
+///
+///     use theoremc_core::results::kani::{check_expectations, parse_kani_output};
+///     use theoremc_core::schema::load_theorem_docs;
+///
+///     let docs = load_theorem_docs(concat!(
+///         "Theorem: Trivial\n",
+///         "About: nothing can go wrong\n",
+///         "Prove:\n",
+///         "  - assert: 'true'\n",
+///         "    because: trivially true\n",
+///         "Evidence:\n",
+///         "  kani: { unwind: 1, expect: SUCCESS }\n",
+///         "Witness:\n",
+///         "  - cover: 'true'\n",
+///         "    because: always reachable\n",
+///     ))
+///     .unwrap();
+///     let results = parse_kani_output("").unwrap();
+///     let mismatches = check_expectations("theorems/trivial.theorem", &docs, &results);
+///     assert_eq!(mismatches.len(), 1);
+#[must_use]
+pub fn check_expectations(
+    theorem_path: &str,
+    docs: &[TheoremDoc],
+    results: &[HarnessResult],
+) -> Vec<ExpectationMismatch> {
+    docs.iter()
+        .flat_map(|doc| {
+            expected_harnesses(theorem_path, doc)
+                .into_iter()
+                .filter_map(|(harness, kani)| {
+                    harness_mismatch(doc.theorem.as_str(), harness, kani, results)
+                })
+        })
+        .collect()
+}
+
+/// Finds the harnesses of `docs`, loaded from `theorem_path`, that succeeded
+/// as expected while Kani reported every one of their covers as
+/// `UNSATISFIABLE`, in document and configuration order.
+///
+/// Harnesses whose configuration sets `allow_vacuous` are included with
+/// [`VacuousHarness::allowed`] set, so callers can still warn about them;
+/// the others also fail [`check_expectations`].
+#[must_use]
+pub fn find_vacuous_harnesses(
+    theorem_path: &str,
+    docs: &[TheoremDoc],
+    results: &[HarnessResult],
+) -> Vec<VacuousHarness> {
+    docs.iter()
+        .filter(|doc| !doc.witness.is_empty())
+        .flat_map(|doc| {
+            expected_harnesses(theorem_path, doc)
+                .into_iter()
+                .filter(|(harness, _)| {
+                    find_result(harness, results).is_some_and(is_vacuous_success)
+                })
+                .map(|(harness, kani)| VacuousHarness {
+                    theorem: doc.theorem.as_str().to_owned(),
+                    harness,
+                    allowed: kani.allow_vacuous,
+                })
+        })
+        .collect()
+}
+
+/// Returns whether `result` succeeded with covers, all of them
+/// unsatisfiable.
+fn is_vacuous_success(result: &HarnessResult) -> bool {
+    result.outcome() == KaniExpectation::Success
+        && result.covers().next().is_some()
+        && result
+            .covers()
+            .all(|cover| cover.status == CheckStatus::Unsatisfiable)
+}
+
+fn find_result<'a>(harness: &str, results: &'a [HarnessResult]) -> Option<&'a HarnessResult> {
+    results.iter().find(|result| result.identifier() == harness)
+}
+
+/// Names every Kani harness generated for `doc`, loaded from
+/// `theorem_path`, with its configuration, in configuration order.
+pub(crate) fn expected_harnesses<'a>(
+    theorem_path: &str,
+    doc: &'a TheoremDoc,
+) -> Vec<(String, &'a KaniEvidence)> {
+    let base = mangle_theorem_harness(theorem_path, doc.theorem.as_str());
+    doc.evidence
+        .kani
+        .iter()
+        .flat_map(|kani| {
+            harness_identifiers(base.identifier(), kani)
+                .into_iter()
+                .map(move |harness| (harness, kani))
+        })
+        .collect()
+}
+
+/// Compares the result for `harness`, if any, with the outcome `kani`
+/// expects.
+fn harness_mismatch(
+    theorem: &str,
+    harness: String,
+    kani: &KaniEvidence,
+    results: &[HarnessResult],
+) -> Option<ExpectationMismatch> {
+    let Some(result) = find_result(&harness, results) else {
+        return Some(ExpectationMismatch::MissingResult {
+            theorem: theorem.to_owned(),
+            harness,
+        });
+    };
+    let actual = result.outcome();
+    if actual != kani.expect {
+        return Some(ExpectationMismatch::UnexpectedOutcome {
+            theorem: theorem.to_owned(),
+            harness,
+            expected: kani.expect,
+            actual,
+        });
+    }
+    (!kani.allow_vacuous && is_vacuous_success(result)).then(|| {
+        ExpectationMismatch::VacuousSuccess {
+            theorem: theorem.to_owned(),
+            harness,
+        }
+    })
+}
+
+/// Names the harnesses generated for one Kani configuration.
+fn harness_identifiers(base: &str, kani: &KaniEvidence) -> Vec<String> {
+    let configured = kani
+        .name
+        .as_ref()
+        .map_or_else(|| base.to_owned(), |name| format!("{base}__{name}"));
+    if kani.contracts.is_empty() {
+        return vec![configured];
+    }
+    (1..=kani.contracts.len())
+        .map(|index| format!("{configured}__contract_{index}"))
+        .collect()
+}
@@ -0,0 +1,336 @@
+//! Unit tests for Kani output parsing and expectation checks.
+
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{
+    CheckResult, CheckStatus, ExpectationMismatch, HarnessResult, KaniOutputError, VacuousHarness,
+    check_expectations, find_vacuous_harnesses, parse_kani_output,
+};
+use crate::mangle::mangle_theorem_harness;
+use crate::schema::{KaniExpectation, TheoremDoc, load_theorem_docs};
+
+const THEOREM_PATH: &str = "theorems/ledger.theorem";
+
+const OUTPUT: &str = concat!(
+    "Kani Rust Verifier 0.56.0 (cargo plugin)\n",
+    "Checking harness theorem_ledger::deposit...\n",
+    "\n",
+    "RESULTS:\n",
+    "Check 1: deposit.assertion.1\n",
+    "\t - Status: FAILURE\n",
+    "\t - Description: \"assertion failed: balance >= amount\"\n",
+    "\t - Location: src/ledger.rs:12:5 in function deposit\n",
+    "\n",
+    "Check 2: deposit.cover.1\n",
+    "\t - Status: SATISFIED\n",
+    "\t - Description: \"cover condition: amount > 0\"\n",
+    "\n",
+    "SUMMARY:\n",
+    " ** 1 of 1 failed\n",
+    "Failed Checks: assertion failed: balance >= amount\n",
+    " - File: \"src/ledger.rs\", line 12, in deposit\n",
+    "\n",
+    "VERIFICATION:- FAILED\n",
+    "Concrete playback unit test for `theorem_ledger::deposit`:\n",
+    "```\n",
+    "#[test]\n",
+    "fn kani_concrete_playback_deposit_1() {\n",
+    "    let concrete_vals: Vec<Vec<u8>> = vec![\n",
+    "        // 4294967295\n",
+    "        vec![255, 255, 255, 255],\n",
+    "        // 0\n",
+    "        vec![0, 0, 0, 0],\n",
+    "    ];\n",
+    "    kani::concrete_playback_run(concrete_vals, deposit);\n",
+    "}\n",
+    "```\n",
+    "Verification Time: 0.42s\n",
+    "\n",
+    "Checking harness theorem_ledger::balance...\n",
+    "VERIFICATION:- SUCCESSFUL\n",
+    "Complete - 1 successfully verified harnesses, 1 failures, 2 total.\n",
+);
+
+fn result(harness: &str, verified: bool, statuses: &[(&str, CheckStatus)]) -> HarnessResult {
+    HarnessResult {
+        harness: harness.to_owned(),
+        checks: statuses
+            .iter()
+            .map(|(property, status)| CheckResult {
+                property: (*property).to_owned(),
+                status: *status,
+                description: String::new(),
+                location: None,
+            })
+            .collect(),
+        verified,
+        counterexample: None,
+        verification_time: None,
+    }
+}
+
+fn ledger_docs(evidence: &str) -> Vec<TheoremDoc> {
+    load_theorem_docs(&format!(
+        concat!(
+            "Theorem: Deposit\n",
+            "About: deposits never overdraw\n",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "{evidence}",
+            "Witness:\n",
+            "  - cover: 'true'\n",
+            "    because: always reachable\n",
+        ),
+        evidence = evidence,
+    ))
+    .expect("theorem should load")
+}
+
+fn harness(suffix: &str) -> String {
+    format!(
+        "{}{suffix}",
+        mangle_theorem_harness(THEOREM_PATH, "Deposit").identifier()
+    )
+}
+
+#[test]
+fn parses_checks_verdicts_and_counterexamples() {
+    let results = parse_kani_output(OUTPUT).expect("output should parse");
+
+    assert_eq!(
+        results,
+        vec![
+            HarnessResult {
+                harness: "theorem_ledger::deposit".to_owned(),
+                checks: vec![
+                    CheckResult {
+                        property: "deposit.assertion.1".to_owned(),
+                        status: CheckStatus::Failure,
+                        description: "assertion failed: balance >= amount".to_owned(),
+                        location: Some("src/ledger.rs:12:5 in function deposit".to_owned()),
+                    },
+                    CheckResult {
+                        property: "deposit.cover.1".to_owned(),
+                        status: CheckStatus::Satisfied,
+                        description: "cover condition: amount > 0".to_owned(),
+                        location: None,
+                    },
+                ],
+                verified: false,
+                counterexample: Some(vec!["4294967295".to_owned(), "0".to_owned()]),
+                verification_time: Some(Duration::from_millis(420)),
+            },
+            result("theorem_ledger::balance", true, &[]),
+        ]
+    );
+    let deposit = results.first().expect("deposit result");
+    assert_eq!(deposit.identifier(), "deposit");
+    assert_eq!(deposit.covers().count(), 1);
+}
+
+#[rstest]
+#[case::unknown_status(
+    "Checking harness h...\nCheck 1: h.assertion.1\n\t - Status: MAYBE\nVERIFICATION:- FAILED\n",
+    KaniOutputError::UnknownStatus { line: 3, status: "MAYBE".to_owned() }
+)]
+#[case::missing_status(
+    "Checking harness h...\nCheck 1: h.assertion.1\nVERIFICATION:- FAILED\n",
+    KaniOutputError::MissingStatus { harness: "h".to_owned(), property: "h.assertion.1".to_owned() }
+)]
+#[case::orphan_detail(
+    "Checking harness h...\n\t - Status: SUCCESS\n",
+    KaniOutputError::OrphanDetail { line: 2 }
+)]
+#[case::truncated_output(
+    "Checking harness h...\nCheck 1: h.assertion.1\n\t - Status: SUCCESS\n",
+    KaniOutputError::MissingVerdict { harness: "h".to_owned() }
+)]
+fn malformed_output_is_rejected(#[case] output: &str, #[case] expected: KaniOutputError) {
+    assert_eq!(parse_kani_output(output), Err(expected));
+}
+
+#[rstest]
+#[case::all_checks_pass(true, &[("h.assertion.1", CheckStatus::Success)], KaniExpectation::Success)]
+#[case::failed_check(false, &[("h.assertion.1", CheckStatus::Failure)], KaniExpectation::Failure)]
+#[case::terse_failure(false, &[], KaniExpectation::Failure)]
+#[case::undecided_check(
+    false,
+    &[("h.assertion.1", CheckStatus::Success), ("h.assertion.2", CheckStatus::Undetermined)],
+    KaniExpectation::Undetermined
+)]
+#[case::unreachable_checks(
+    true,
+    &[("h.assertion.1", CheckStatus::Unreachable), ("h.cover.1", CheckStatus::Unsatisfiable)],
+    KaniExpectation::Unreachable
+)]
+#[case::partly_unreachable(
+    true,
+    &[("h.assertion.1", CheckStatus::Unreachable), ("h.assertion.2", CheckStatus::Success)],
+    KaniExpectation::Success
+)]
+fn outcome_summarizes_checks(
+    #[case] verified: bool,
+    #[case] statuses: &[(&str, CheckStatus)],
+    #[case] expected: KaniExpectation,
+) {
+    assert_eq!(result("h", verified, statuses).outcome(), expected);
+}
+
+#[test]
+fn matching_results_have_no_mismatches() {
+    let docs = ledger_docs("    unwind: 1\n    expect: FAILURE\n");
+    let results = vec![result(
+        &format!("theorem_ledger::{}", harness("")),
+        false,
+        &[],
+    )];
+
+    assert_eq!(check_expectations(THEOREM_PATH, &docs, &results), vec![]);
+}
+
+#[test]
+fn mismatched_and_missing_harnesses_are_reported() {
+    let docs = ledger_docs(concat!(
+        "    - name: fast\n",
+        "      unwind: 1\n",
+        "      expect: SUCCESS\n",
+        "    - name: contract\n",
+        "      unwind: 1\n",
+        "      expect: SUCCESS\n",
+        "      contracts: [ledger::deposit, ledger::withdraw]\n",
+    ));
+    let results = vec![
+        result(&harness("__fast"), false, &[]),
+        result(&harness("__contract__contract_1"), true, &[]),
+    ];
+
+    assert_eq!(
+        check_expectations(THEOREM_PATH, &docs, &results),
+        vec![
+            ExpectationMismatch::UnexpectedOutcome {
+                theorem: "Deposit".to_owned(),
+                harness: harness("__fast"),
+                expected: KaniExpectation::Success,
+                actual: KaniExpectation::Failure,
+            },
+            ExpectationMismatch::MissingResult {
+                theorem: "Deposit".to_owned(),
+                harness: harness("__contract__contract_2"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn mismatch_messages_name_theorem_harness_and_outcomes() {
+    let mismatch = ExpectationMismatch::UnexpectedOutcome {
+        theorem: "Deposit".to_owned(),
+        harness: "theorem__deposit".to_owned(),
+        expected: KaniExpectation::Success,
+        actual: KaniExpectation::Failure,
+    };
+
+    assert_eq!(
+        mismatch.to_string(),
+        "theorem 'Deposit': harness 'theorem__deposit' expected SUCCESS, but Kani reported FAILURE"
+    );
+}
+
+fn vacuous_result(suffix: &str, cover: CheckStatus) -> HarnessResult {
+    result(
+        &harness(suffix),
+        true,
+        &[
+            ("h.assertion.1", CheckStatus::Success),
+            ("h.cover.1", cover),
+            ("h.cover.2", CheckStatus::Unsatisfiable),
+        ],
+    )
+}
+
+const VACUITY_EVIDENCE: &str = concat!(
+    "    - name: strict\n",
+    "      unwind: 1\n",
+    "      expect: SUCCESS\n",
+    "    - name: lenient\n",
+    "      unwind: 1\n",
+    "      expect: SUCCESS\n",
+    "      allow_vacuous: true\n",
+    "      vacuity_because: the witness is aspirational\n",
+    "    - name: covered\n",
+    "      unwind: 1\n",
+    "      expect: SUCCESS\n",
+);
+
+fn vacuity_results() -> Vec<HarnessResult> {
+    vec![
+        vacuous_result("__strict", CheckStatus::Unsatisfiable),
+        vacuous_result("__lenient", CheckStatus::Unsatisfiable),
+        vacuous_result("__covered", CheckStatus::Satisfied),
+    ]
+}
+
+#[test]
+fn vacuous_harnesses_are_flagged_with_their_policy() {
+    let docs = ledger_docs(VACUITY_EVIDENCE);
+
+    assert_eq!(
+        find_vacuous_harnesses(THEOREM_PATH, &docs, &vacuity_results()),
+        vec![
+            VacuousHarness {
+                theorem: "Deposit".to_owned(),
+                harness: harness("__strict"),
+                allowed: false,
+            },
+            VacuousHarness {
+                theorem: "Deposit".to_owned(),
+                harness: harness("__lenient"),
+                allowed: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn vacuous_success_fails_unless_allowed() {
+    let docs = ledger_docs(VACUITY_EVIDENCE);
+
+    let mismatches = check_expectations(THEOREM_PATH, &docs, &vacuity_results());
+
+    assert_eq!(
+        mismatches,
+        vec![ExpectationMismatch::VacuousSuccess {
+            theorem: "Deposit".to_owned(),
+            harness: harness("__strict"),
+        }]
+    );
+    assert_eq!(
+        mismatches.first().map(ToString::to_string),
+        Some(format!(
+            "theorem 'Deposit': harness '{}' passed vacuously: every Witness cover was \
+             UNSATISFIABLE",
+            harness("__strict")
+        ))
+    );
+}
+
+#[test]
+fn harnesses_without_covers_are_not_vacuous() {
+    let docs = ledger_docs("    unwind: 1\n    expect: SUCCESS\n");
+    let results = vec![result(
+        &harness(""),
+        true,
+        &[("h.assertion.1", CheckStatus::Success)],
+    )];
+
+    assert_eq!(
+        find_vacuous_harnesses(THEOREM_PATH, &docs, &results),
+        vec![]
+    );
+}
@@ -5,151 +5,40 @@
 //! decoding. Plain YAML scalars become [`Literal`](ArgValue::Literal)
 //! variants, explicit `{ ref: <Identifier> }` maps become
 //! [`Reference`](ArgValue::Reference) variants, explicit
-//! `{ literal: <String> }` maps also become `Literal` variants, and
+//! `{ literal: <String> }` maps also become `Literal` variants,
+//! `{ expr: <String> }` maps become [`Expr`](ArgValue::Expr) variants, and
 //! other composite forms are preserved as raw values for future
 //! lowering steps (`TFS-5`, `ADR-3`, `DES-5`).
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
+use super::expr::validate_rust_expr;
 use super::identifier::{is_rust_reserved_keyword, is_valid_ascii_identifier_pattern};
 use super::value::TheoremValue;
 
+#[path = "arg_value_error.rs"]
+mod error;
+
+pub use error::ArgDecodeError;
+
 /// The sentinel YAML map key that identifies a variable reference.
 const REF_KEY: &str = "ref";
 
 /// The sentinel YAML map key that identifies an explicit string literal.
 const LITERAL_KEY: &str = "literal";
 
+/// The sentinel YAML map key that identifies a computed Rust expression.
+const EXPR_KEY: &str = "expr";
+
 /// Discriminates recognized sentinel map keys for dispatch.
 enum SentinelKind {
     /// The `{ ref: <Identifier> }` sentinel.
     Ref,
     /// The `{ literal: <String> }` sentinel.
     Literal,
-}
-
-/// Errors produced when decoding a raw [`TheoremValue`] into an
-/// [`ArgValue`].
-///
-/// Each variant carries the parameter name (`param`) for diagnostic
-/// context. Variants derive `PartialEq` and `Eq` so callers and tests
-/// can match on specific error conditions.
-#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum ArgDecodeError {
-    /// The `{ ref: "" }` target was an empty string.
-    #[error("argument '{param}': ref value must not be empty")]
-    EmptyRefTarget {
-        /// Argument parameter name.
-        param: String,
-    },
-
-    /// The `{ ref: <name> }` target is not a valid ASCII identifier.
-    #[error(
-        "argument '{param}': ref value '{name}' is not a valid \
-         identifier (must match ^[A-Za-z_][A-Za-z0-9_]*$)"
-    )]
-    InvalidIdentifier {
-        /// Argument parameter name.
-        param: String,
-        /// The invalid identifier value.
-        name: String,
-    },
-
-    /// The `{ ref: <name> }` target is a Rust reserved keyword.
-    #[error("argument '{param}': ref value '{name}' is a Rust reserved keyword")]
-    ReservedKeyword {
-        /// Argument parameter name.
-        param: String,
-        /// The keyword value.
-        name: String,
-    },
-
-    /// The `ref` value is not a string (e.g. an integer or boolean).
-    #[error(
-        "argument '{param}': ref value must be a string identifier, \
-         not {kind}"
-    )]
-    NonStringRefTarget {
-        /// Argument parameter name.
-        param: String,
-        /// Human-readable kind label (e.g. "an integer").
-        kind: &'static str,
-    },
-
-    /// The `literal` value is not a string (e.g. an integer or boolean).
-    #[error(
-        "argument '{param}': literal value must be a string, \
-         not {kind}"
-    )]
-    NonStringLiteralValue {
-        /// Argument parameter name.
-        param: String,
-        /// Human-readable kind label (e.g. "an integer").
-        kind: &'static str,
-    },
-}
-
-impl ArgDecodeError {
-    /// Returns the argument breadcrumb associated with this decode failure.
-    #[must_use]
-    pub(crate) fn param(&self) -> &str {
-        match self {
-            Self::EmptyRefTarget { param }
-            | Self::InvalidIdentifier { param, .. }
-            | Self::ReservedKeyword { param, .. }
-            | Self::NonStringRefTarget { param, .. }
-            | Self::NonStringLiteralValue { param, .. } => param,
-        }
-    }
-
-    /// Returns this error with `prefix` prepended to its parameter breadcrumb.
-    ///
-    /// This is used when argument decoding happens inside nested structures,
-    /// such as a `maybe.do` step, so diagnostics identify the failing argument
-    /// and its enclosing path.
-    ///
-    /// # Examples
-    ///
-    ///     use theoremc_core::schema::ArgDecodeError;
-    ///
-    ///     let error = ArgDecodeError::EmptyRefTarget {
-    ///         param: "name".into(),
-    ///     }
-    ///     .with_param_prefix("maybe.do step 1");
-    ///
-    ///     assert_eq!(
-    ///         error.to_string(),
-    ///         "argument 'maybe.do step 1: name': ref value must not be empty"
-    ///     );
-    #[must_use]
-    pub fn with_param_prefix(self, prefix: &str) -> Self {
-        match self {
-            Self::EmptyRefTarget { param } => Self::EmptyRefTarget {
-                param: prefixed_param(prefix, &param),
-            },
-            Self::InvalidIdentifier { param, name } => Self::InvalidIdentifier {
-                param: prefixed_param(prefix, &param),
-                name,
-            },
-            Self::ReservedKeyword { param, name } => Self::ReservedKeyword {
-                param: prefixed_param(prefix, &param),
-                name,
-            },
-            Self::NonStringRefTarget { param, kind } => Self::NonStringRefTarget {
-                param: prefixed_param(prefix, &param),
-                kind,
-            },
-            Self::NonStringLiteralValue { param, kind } => Self::NonStringLiteralValue {
-                param: prefixed_param(prefix, &param),
-                kind,
-            },
-        }
-    }
-}
-
-fn prefixed_param(prefix: &str, param: &str) -> String {
-    format!("{prefix}: {param}")
+    /// The `{ expr: <String> }` sentinel.
+    Expr,
 }
 
 /// A semantically decoded action-call argument value.
@@ -168,12 +57,14 @@ fn prefixed_param(prefix: &str, param: &str) -> String {
 ///
 ///     let lit = ArgValue::Literal(LiteralValue::String("hello".into()));
 ///     let reference = ArgValue::Reference("graph".into());
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ArgValue {
     /// A scalar literal value (bool, integer, float, or string).
     Literal(LiteralValue),
     /// An explicit variable reference via `{ ref: <Identifier> }`.
     Reference(String),
+    /// A Rust expression via `{ expr: <String> }`, emitted verbatim.
+    Expr(String),
     /// A YAML sequence not yet lowered (future: `vec![...]` synthesis).
     RawSequence(Vec<TheoremValue>),
     /// A YAML map not yet lowered (future: struct-literal synthesis).
@@ -193,7 +84,7 @@ pub enum ArgValue {
 ///     let n = LiteralValue::Integer(42);
 ///     let b = LiteralValue::Bool(true);
 ///     let f = LiteralValue::Float(3.14);
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LiteralValue {
     /// A boolean literal (`true` / `false`).
     Bool(bool),
@@ -247,6 +138,9 @@ impl<'a> ParamName<'a> {
 ///   `ArgValue::Literal(LiteralValue::String(s))`
 /// - `TheoremValue::Mapping(m)` with exactly one key `"literal"` whose
 ///   value is not a string → `Err(...)` with an actionable message
+/// - `TheoremValue::Mapping(m)` with exactly one key `"expr"` whose
+///   value is a string that parses as a single Rust expression →
+///   `ArgValue::Expr(source)`
 /// - `TheoremValue::Mapping(m)` (any other map) →
 ///   `ArgValue::RawMap(m)` (preserved for future lowering)
 ///
@@ -258,7 +152,9 @@ impl<'a> ParamName<'a> {
 /// Returns [`ArgDecodeError`] when a `{ ref: ... }` wrapper contains
 /// an invalid target: empty string, non-identifier pattern, Rust
 /// reserved keyword, or non-string value. Also returns an error when
-/// a `{ literal: ... }` wrapper contains a non-string value.
+/// a `{ literal: ... }` wrapper contains a non-string value, or when
+/// an `{ expr: ... }` wrapper is not a string holding a single Rust
+/// expression.
 ///
 /// # Examples
 ///
@@ -281,8 +177,8 @@ pub fn decode_arg_value(
     }
 }
 
-/// Decodes a YAML mapping into a sentinel wrapper (`Reference` or
-/// `Literal`) if the map has exactly one recognized sentinel key, or
+/// Decodes a YAML mapping into a sentinel wrapper (`Reference`,
+/// `Literal`, or `Expr`) if the map has exactly one recognized sentinel key, or
 /// a `RawMap` for all other maps (struct literal candidates).
 fn decode_mapping(
     param_name: ParamName<'_>,
@@ -301,6 +197,7 @@ fn decode_mapping(
     match kind {
         SentinelKind::Ref => decode_ref_target(param_name, value),
         SentinelKind::Literal => decode_literal_target(param_name, value),
+        SentinelKind::Expr => decode_expr_target(param_name, value),
     }
 }
 
@@ -316,6 +213,7 @@ fn classify_sentinel(map: &IndexMap<String, TheoremValue>) -> Option<SentinelKin
     match key.as_str() {
         REF_KEY => Some(SentinelKind::Ref),
         LITERAL_KEY => Some(SentinelKind::Literal),
+        EXPR_KEY => Some(SentinelKind::Expr),
         _ => None,
     }
 }
@@ -328,7 +226,7 @@ fn decode_ref_target(
     let TheoremValue::String(name) = value else {
         return Err(ArgDecodeError::NonStringRefTarget {
             param: param_name.as_str().to_owned(),
-            kind: non_string_kind(&value),
+            kind: value.kind(),
         });
     };
 
@@ -367,23 +265,32 @@ fn decode_literal_target(
     let TheoremValue::String(s) = value else {
         return Err(ArgDecodeError::NonStringLiteralValue {
             param: param_name.as_str().to_owned(),
-            kind: non_string_kind(&value),
+            kind: value.kind(),
         });
     };
     Ok(ArgValue::Literal(LiteralValue::String(s)))
 }
 
-/// Returns a human-readable kind label for non-string `TheoremValue`
-/// variants, used in error messages.
-const fn non_string_kind(value: &TheoremValue) -> &'static str {
-    match value {
-        TheoremValue::Bool(_) => "a boolean",
-        TheoremValue::Integer(_) => "an integer",
-        TheoremValue::Float(_) => "a float",
-        TheoremValue::String(_) => "a string",
-        TheoremValue::Sequence(_) => "a sequence",
-        TheoremValue::Mapping(_) => "a mapping",
-    }
+/// Validates the `expr` wrapper value and produces an `ArgValue::Expr`.
+///
+/// The source is trimmed and must parse as a single Rust expression,
+/// using the same rules as `Assume`, `Prove`, and `Witness` expressions.
+fn decode_expr_target(
+    param_name: ParamName<'_>,
+    value: TheoremValue,
+) -> Result<ArgValue, ArgDecodeError> {
+    let TheoremValue::String(source) = value else {
+        return Err(ArgDecodeError::NonStringExprValue {
+            param: param_name.as_str().to_owned(),
+            kind: value.kind(),
+        });
+    };
+    let trimmed = source.trim();
+    validate_rust_expr(trimmed).map_err(|reason| ArgDecodeError::InvalidExpr {
+        param: param_name.as_str().to_owned(),
+        reason,
+    })?;
+    Ok(ArgValue::Expr(trimmed.to_owned()))
 }
 
 #[cfg(test)]
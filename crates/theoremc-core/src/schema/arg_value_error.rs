@@ -0,0 +1,206 @@
+//! Errors raised while decoding action-call argument values.
+
+/// Errors produced when decoding a raw
+/// [`TheoremValue`](crate::schema::TheoremValue) into an
+/// [`ArgValue`](super::ArgValue).
+///
+/// Each variant carries the parameter name (`param`) for diagnostic
+/// context. Variants derive `PartialEq` and `Eq` so callers and tests
+/// can match on specific error conditions.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArgDecodeError {
+    /// The `{ ref: "" }` target was an empty string.
+    #[error("argument '{param}': ref value must not be empty")]
+    EmptyRefTarget {
+        /// Argument parameter name.
+        param: String,
+    },
+
+    /// The `{ ref: <name> }` target is not a valid ASCII identifier.
+    #[error(
+        "argument '{param}': ref value '{name}' is not a valid \
+         identifier (must match ^[A-Za-z_][A-Za-z0-9_]*$)"
+    )]
+    InvalidIdentifier {
+        /// Argument parameter name.
+        param: String,
+        /// The invalid identifier value.
+        name: String,
+    },
+
+    /// The `{ ref: <name> }` target is a Rust reserved keyword.
+    #[error("argument '{param}': ref value '{name}' is a Rust reserved keyword")]
+    ReservedKeyword {
+        /// Argument parameter name.
+        param: String,
+        /// The keyword value.
+        name: String,
+    },
+
+    /// The `ref` value is not a string (e.g. an integer or boolean).
+    #[error(
+        "argument '{param}': ref value must be a string identifier, \
+         not {kind}"
+    )]
+    NonStringRefTarget {
+        /// Argument parameter name.
+        param: String,
+        /// Human-readable kind label (e.g. "an integer").
+        kind: &'static str,
+    },
+
+    /// The `literal` value is not a string (e.g. an integer or boolean).
+    #[error(
+        "argument '{param}': literal value must be a string, \
+         not {kind}"
+    )]
+    NonStringLiteralValue {
+        /// Argument parameter name.
+        param: String,
+        /// Human-readable kind label (e.g. "an integer").
+        kind: &'static str,
+    },
+
+    /// The `expr` value is not a string (e.g. an integer or mapping).
+    #[error(
+        "argument '{param}': expr value must be a string holding a Rust \
+         expression, not {kind}"
+    )]
+    NonStringExprValue {
+        /// Argument parameter name.
+        param: String,
+        /// Human-readable kind label (e.g. "an integer").
+        kind: &'static str,
+    },
+
+    /// The `expr` value is not a single valid Rust expression.
+    #[error("argument '{param}': expr {reason}")]
+    InvalidExpr {
+        /// Argument parameter name.
+        param: String,
+        /// Parser or shape failure reported by expression validation.
+        reason: String,
+    },
+
+    /// Positional `args` were given for an action with no `Actions`
+    /// signature to name them.
+    #[error(
+        "argument '{param}': positional args for '{action}' need an \
+         Actions signature to name each parameter"
+    )]
+    PositionalArgsWithoutSignature {
+        /// Argument breadcrumb (the `args` key).
+        param: String,
+        /// Canonical action name.
+        action: String,
+    },
+
+    /// The number of positional `args` differs from the number of
+    /// parameters in the action's `Actions` signature.
+    #[error(
+        "argument '{param}': '{action}' takes {expected} parameter(s), \
+         but {found} positional args were given"
+    )]
+    PositionalArgCount {
+        /// Argument breadcrumb (the `args` key).
+        param: String,
+        /// Canonical action name.
+        action: String,
+        /// Parameter count of the signature.
+        expected: usize,
+        /// Number of positional values given.
+        found: usize,
+    },
+}
+
+impl ArgDecodeError {
+    /// Returns the argument breadcrumb associated with this decode failure.
+    #[must_use]
+    pub(crate) fn param(&self) -> &str {
+        match self {
+            Self::EmptyRefTarget { param }
+            | Self::InvalidIdentifier { param, .. }
+            | Self::ReservedKeyword { param, .. }
+            | Self::NonStringRefTarget { param, .. }
+            | Self::NonStringLiteralValue { param, .. }
+            | Self::NonStringExprValue { param, .. }
+            | Self::InvalidExpr { param, .. }
+            | Self::PositionalArgsWithoutSignature { param, .. }
+            | Self::PositionalArgCount { param, .. } => param,
+        }
+    }
+
+    /// Returns this error with `prefix` prepended to its parameter breadcrumb.
+    ///
+    /// This is used when argument decoding happens inside nested structures,
+    /// such as a `maybe.do` step, so diagnostics identify the failing argument
+    /// and its enclosing path.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::ArgDecodeError;
+    ///
+    ///     let error = ArgDecodeError::EmptyRefTarget {
+    ///         param: "name".into(),
+    ///     }
+    ///     .with_param_prefix("maybe.do step 1");
+    ///
+    ///     assert_eq!(
+    ///         error.to_string(),
+    ///         "argument 'maybe.do step 1: name': ref value must not be empty"
+    ///     );
+    #[must_use]
+    pub fn with_param_prefix(self, prefix: &str) -> Self {
+        match self {
+            Self::EmptyRefTarget { param } => Self::EmptyRefTarget {
+                param: prefixed_param(prefix, &param),
+            },
+            Self::InvalidIdentifier { param, name } => Self::InvalidIdentifier {
+                param: prefixed_param(prefix, &param),
+                name,
+            },
+            Self::ReservedKeyword { param, name } => Self::ReservedKeyword {
+                param: prefixed_param(prefix, &param),
+                name,
+            },
+            Self::NonStringRefTarget { param, kind } => Self::NonStringRefTarget {
+                param: prefixed_param(prefix, &param),
+                kind,
+            },
+            Self::NonStringLiteralValue { param, kind } => Self::NonStringLiteralValue {
+                param: prefixed_param(prefix, &param),
+                kind,
+            },
+            Self::NonStringExprValue { param, kind } => Self::NonStringExprValue {
+                param: prefixed_param(prefix, &param),
+                kind,
+            },
+            Self::InvalidExpr { param, reason } => Self::InvalidExpr {
+                param: prefixed_param(prefix, &param),
+                reason,
+            },
+            Self::PositionalArgsWithoutSignature { param, action } => {
+                Self::PositionalArgsWithoutSignature {
+                    param: prefixed_param(prefix, &param),
+                    action,
+                }
+            }
+            Self::PositionalArgCount {
+                param,
+                action,
+                expected,
+                found,
+            } => Self::PositionalArgCount {
+                param: prefixed_param(prefix, &param),
+                action,
+                expected,
+                found,
+            },
+        }
+    }
+}
+
+fn prefixed_param(prefix: &str, param: &str) -> String {
+    format!("{prefix}: {param}")
+}
@@ -201,6 +201,51 @@ fn multi_key_map_with_literal_is_raw_map() {
     assert_eq!(result.expect("should decode"), ArgValue::RawMap(map));
 }
 
+// ── Expression wrapper decoding ─────────────────────────────────────
+
+#[rstest]
+#[case::arithmetic("a + b", "a + b")]
+#[case::method_call("  receipt.total()  ", "receipt.total()")]
+#[case::macro_call("vec![amount; 2]", "vec![amount; 2]")]
+fn valid_expr_wrapper_decodes_as_expr(#[case] input: &str, #[case] expected: &str) {
+    let map = IndexMap::from([("expr".to_owned(), TheoremValue::String(input.to_owned()))]);
+    let result = decode_arg_value(ParamName::new("param"), TheoremValue::Mapping(map));
+    assert_eq!(
+        result.expect("should decode"),
+        ArgValue::Expr(expected.to_owned())
+    );
+}
+
+#[rstest]
+#[case::invalid_syntax("a +", "expr is not a valid Rust expression")]
+#[case::block("{ let x = 1; x }", "expr must be a single expression")]
+#[case::empty("  ", "expr is not a valid Rust expression")]
+fn invalid_expr_wrapper_is_rejected(#[case] input: &str, #[case] expected_fragment: &str) {
+    let map = IndexMap::from([("expr".to_owned(), TheoremValue::String(input.to_owned()))]);
+    let err = decode_arg_value(ParamName::new("param"), TheoremValue::Mapping(map))
+        .expect_err("should fail");
+    assert!(matches!(err, ArgDecodeError::InvalidExpr { ref param, .. } if param == "param"));
+    let msg = err.to_string();
+    assert!(
+        msg.contains(expected_fragment),
+        "expected display to mention '{expected_fragment}', got: {msg}"
+    );
+}
+
+#[test]
+fn expr_with_non_string_value_is_rejected() {
+    let map = IndexMap::from([("expr".to_owned(), TheoremValue::Integer(3))]);
+    let err = decode_arg_value(ParamName::new("param"), TheoremValue::Mapping(map))
+        .expect_err("should fail");
+    assert_eq!(
+        err,
+        ArgDecodeError::NonStringExprValue {
+            param: "param".into(),
+            kind: "an integer",
+        }
+    );
+}
+
 // ── Error message includes parameter name ───────────────────────────
 
 #[rstest]
@@ -293,6 +338,16 @@ fn error_message_includes_param_name(
         kind: "a boolean",
     },
 )]
+#[case::invalid_expr(
+    ArgDecodeError::InvalidExpr {
+        param: "name".into(),
+        reason: "must be a single expression".into(),
+    },
+    ArgDecodeError::InvalidExpr {
+        param: "maybe.do step 2: name".into(),
+        reason: "must be a single expression".into(),
+    },
+)]
 fn with_param_prefix_updates_every_error_variant(
     #[case] error: ArgDecodeError,
     #[case] expected: ArgDecodeError,
@@ -0,0 +1,73 @@
+//! File-level `Defaults` documents.
+//!
+//! A `.theorem` source may open with a `Defaults` document whose `Tags`,
+//! `Evidence`, and `Lints` values are inherited by every theorem document
+//! that follows.
+//! Defaults are merged into each raw theorem before decoding and validation,
+//! so inherited values are checked exactly as if they had been written in the
+//! theorem itself.
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_saphyr::Spanned;
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::raw::RawTheoremDoc;
+use super::raw_evidence::{RawEvidence, inherit_evidence};
+use super::source_id::SourceId;
+use super::symbol::Symbol;
+
+/// Values a `Defaults` document supplies to the theorems in its file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawDefaults {
+    #[serde(rename = "Tags", alias = "tags", default)]
+    pub(crate) tags: Option<Vec<Symbol>>,
+    #[serde(rename = "Evidence", alias = "evidence", default)]
+    pub(crate) evidence: Option<RawEvidence>,
+    #[serde(rename = "Lints", alias = "lints", default)]
+    pub(crate) lints: IndexMap<String, String>,
+}
+
+impl RawDefaults {
+    /// Fills values `raw_doc` leaves unset.
+    ///
+    /// `Tags` are inherited only when the theorem declares none. `Evidence`
+    /// is merged field by field, so a theorem may override one Kani setting
+    /// and inherit the rest. `Lints` are merged lint by lint, with the
+    /// inherited levels first and the theorem's own levels winning.
+    pub(crate) fn apply_to(&self, raw_doc: &mut RawTheoremDoc) {
+        if raw_doc.tags.is_none() {
+            raw_doc.tags.clone_from(&self.tags);
+        }
+        inherit_evidence(&mut raw_doc.evidence, self.evidence.as_ref());
+        if !self.lints.is_empty() {
+            let own = std::mem::replace(&mut raw_doc.lints, self.lints.clone());
+            raw_doc.lints.extend(own);
+        }
+    }
+}
+
+/// Builds the error reported for a `Defaults` document that is not first in
+/// its source.
+pub(crate) fn misplaced_defaults(
+    source: &SourceId,
+    defaults: &Spanned<RawDefaults>,
+) -> SchemaError {
+    let message = "a Defaults document must be the first document in the file".to_owned();
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ParseFailure,
+        source,
+        message.clone(),
+        defaults.referenced,
+    );
+    SchemaError::Deserialize {
+        message,
+        diagnostic: Some(diagnostic),
+    }
+}
+
+#[cfg(test)]
+#[path = "defaults_tests.rs"]
+mod tests;
@@ -0,0 +1,242 @@
+//! Unit tests for file-level `Defaults` documents.
+use std::time::Duration;
+
+use rstest::rstest;
+
+use crate::schema::test_fixtures::theorem_without_evidence;
+use crate::schema::{
+    KaniExpectation, KaniSolver, KaniTimeout, MemoryLimit, SchemaError, TheoremDoc,
+    load_theorem_docs,
+};
+
+const DEFAULTS: &str = concat!(
+    "Defaults:\n",
+    "  Tags: [ledger]\n",
+    "  Evidence:\n",
+    "    kani:\n",
+    "      unwind: 4\n",
+    "      expect: SUCCESS\n",
+);
+
+fn load(documents: &[&str]) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs(&documents.join("---\n"))
+}
+
+fn kani_settings(doc: &TheoremDoc) -> (u32, KaniExpectation) {
+    let kani = doc.evidence.kani.first().expect("kani evidence expected");
+    (kani.unwind, kani.expect)
+}
+
+#[test]
+fn theorems_inherit_tags_and_evidence() {
+    let docs = load(&[
+        DEFAULTS,
+        &theorem_without_evidence("First", ""),
+        &theorem_without_evidence("Second", ""),
+    ])
+    .expect("defaults should apply");
+
+    assert_eq!(docs.len(), 2);
+    for doc in &docs {
+        assert_eq!(doc.tags, ["ledger"]);
+        assert_eq!(kani_settings(doc), (4, KaniExpectation::Success));
+    }
+}
+
+#[rstest]
+#[case::unwind_only(
+    "Evidence:\n  kani:\n    unwind: 9\n",
+    (9, KaniExpectation::Success)
+)]
+#[case::expect_only(
+    "Evidence:\n  kani:\n    expect: FAILURE\n",
+    (4, KaniExpectation::Failure)
+)]
+fn theorem_evidence_overrides_individual_fields(
+    #[case] evidence: &str,
+    #[case] expected: (u32, KaniExpectation),
+) {
+    let docs = load(&[DEFAULTS, &theorem_without_evidence("Override", evidence)])
+        .expect("override should load");
+    let doc = docs.first().expect("one theorem document");
+
+    assert_eq!(kani_settings(doc), expected);
+}
+
+#[test]
+fn theorems_inherit_solver_and_extra_args_unless_overridden() {
+    let defaults = DEFAULTS.replacen(
+        "      expect: SUCCESS\n",
+        "      expect: SUCCESS\n      solver: kissat\n      extra_args: [--verbose]\n",
+        1,
+    );
+    let docs = load(&[
+        &defaults,
+        &theorem_without_evidence("Inherited", ""),
+        &theorem_without_evidence("Quiet", "Evidence:\n  kani:\n    extra_args: []\n"),
+    ])
+    .expect("defaults should apply");
+    let settings: Vec<_> = docs
+        .iter()
+        .map(|doc| {
+            let kani = doc.evidence.kani.first().expect("kani evidence expected");
+            (kani.solver, kani.extra_args.clone())
+        })
+        .collect();
+
+    assert_eq!(
+        settings,
+        [
+            (Some(KaniSolver::Kissat), vec!["--verbose".to_owned()]),
+            (Some(KaniSolver::Kissat), Vec::new()),
+        ]
+    );
+}
+
+#[test]
+fn theorems_inherit_resource_limits_unless_overridden() {
+    let defaults = DEFAULTS.replacen(
+        "      expect: SUCCESS\n",
+        "      expect: SUCCESS\n      timeout: 10m\n      memory_limit: 8GiB\n",
+        1,
+    );
+    let docs = load(&[
+        &defaults,
+        &theorem_without_evidence("Inherited", ""),
+        &theorem_without_evidence("Quick", "Evidence:\n  kani:\n    timeout: 30s\n"),
+    ])
+    .expect("defaults should apply");
+    let limits: Vec<_> = docs
+        .iter()
+        .map(|doc| {
+            let kani = doc.evidence.kani.first().expect("kani evidence expected");
+            (
+                kani.timeout.map(KaniTimeout::as_duration),
+                kani.memory_limit.map(MemoryLimit::bytes),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        limits,
+        [
+            (Some(Duration::from_secs(600)), Some(8 << 30)),
+            (Some(Duration::from_secs(30)), Some(8 << 30)),
+        ]
+    );
+}
+
+#[test]
+fn theorem_tags_replace_default_tags() {
+    let docs = load(&[
+        DEFAULTS,
+        &theorem_without_evidence("Tagged", "Tags: [slow, nightly]\n"),
+    ])
+    .expect("tags override should load");
+    let doc = docs.first().expect("one theorem document");
+
+    assert_eq!(doc.tags, ["slow", "nightly"]);
+}
+
+#[test]
+fn theorem_lint_levels_are_merged_over_default_levels() {
+    let defaults = format!("{DEFAULTS}  Lints: {{ unused_let: allow, unused_as: deny }}\n");
+    let own = theorem_without_evidence(
+        "Own",
+        "Lints:\n  unused_as: warn\n  duplicate_check: deny\n",
+    );
+    let docs = load(&[&defaults, &own]).expect("defaults should apply");
+    let doc = docs.first().expect("one theorem expected");
+    let levels: Vec<(&str, &str)> = doc
+        .lints
+        .iter()
+        .map(|(lint, level)| (lint.as_str(), level.as_str()))
+        .collect();
+
+    assert_eq!(
+        levels,
+        [
+            ("unused_let", "allow"),
+            ("unused_as", "warn"),
+            ("duplicate_check", "deny"),
+        ]
+    );
+}
+
+#[test]
+fn inherited_evidence_is_validated() {
+    let defaults = "Defaults:\n  evidence:\n    kani:\n      unwind: 0\n      expect: SUCCESS\n";
+    let error = load(&[defaults, &theorem_without_evidence("ZeroUnwind", "")])
+        .expect_err("inherited zero unwind should be rejected");
+
+    assert!(
+        matches!(error, SchemaError::ValidationFailed { .. }),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn missing_required_kani_fields_are_reported() {
+    let defaults = "Defaults:\n  Evidence:\n    kani:\n      expect: SUCCESS\n";
+    let message = load(&[defaults, &theorem_without_evidence("NoUnwind", "")])
+        .expect_err("unwind is still required")
+        .to_string();
+
+    assert!(
+        message.contains("Evidence.kani.unwind is required"),
+        "unexpected error: {message}"
+    );
+}
+
+#[rstest]
+#[case::not_first(
+    &[theorem_without_evidence("First", "Evidence:\n  kani:\n    unwind: 1\n    expect: SUCCESS\n"), DEFAULTS.to_owned()],
+    "a Defaults document must be the first document in the file"
+)]
+#[case::extra_key(
+    &[format!("{DEFAULTS}Theorem: Mixed\n")],
+    "a Defaults document must contain only the `Defaults` key"
+)]
+#[case::unknown_default(
+    &["Defaults:\n  About: not inheritable\n".to_owned()],
+    "unknown field `About`"
+)]
+fn invalid_defaults_documents_are_rejected(#[case] documents: &[String], #[case] expected: &str) {
+    let borrowed: Vec<&str> = documents.iter().map(String::as_str).collect();
+    let message = load(&borrowed)
+        .expect_err("defaults document should be rejected")
+        .to_string();
+
+    assert!(message.contains(expected), "unexpected error: {message}");
+}
+
+#[test]
+fn configuration_lists_inherit_a_single_default_configuration() {
+    let defaults = DEFAULTS.replacen(
+        "      expect: SUCCESS\n",
+        "      expect: SUCCESS\n      solver: minisat\n",
+        1,
+    );
+    let evidence = concat!(
+        "Evidence:\n",
+        "  kani:\n",
+        "    - name: quick\n",
+        "    - name: deep\n",
+        "      unwind: 32\n",
+    );
+    let docs = load(&[&defaults, &theorem_without_evidence("Matrix", evidence)])
+        .expect("defaults should apply");
+    let configs: Vec<_> = docs
+        .iter()
+        .flat_map(|doc| &doc.evidence.kani)
+        .map(|kani| (kani.name.as_deref(), kani.unwind, kani.solver))
+        .collect();
+
+    assert_eq!(
+        configs,
+        [
+            (Some("quick"), 4, Some(KaniSolver::Minisat)),
+            (Some("deep"), 32, Some(KaniSolver::Minisat)),
+        ]
+    );
+}
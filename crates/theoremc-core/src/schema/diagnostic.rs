@@ -13,6 +13,23 @@ pub enum SchemaDiagnosticCode {
     ParseFailure,
     /// Post-deserialization semantic validation failure.
     ValidationFailure,
+    /// `DependsOn`, `Include`, or `Extends` entries form a cycle.
+    Cycle,
+    /// The document declares a `Schema` version this loader does not
+    /// support.
+    UnsupportedSchemaVersion,
+    /// A top-level section key uses a deprecated lowercase alias. Reported
+    /// as a warning.
+    DeprecatedAlias,
+    /// A top-level key the document does not declare was skipped. Reported
+    /// as a warning.
+    UnknownKey,
+    /// Kani evidence expects `UNREACHABLE` while declaring witnesses.
+    KaniUnreachableWithWitness,
+    /// Kani evidence stubs a function whose contract it verifies.
+    KaniStubbedContract,
+    /// Kani evidence enables playback for a possibly vacuous expected failure.
+    KaniVacuousPlayback,
 }
 
 impl SchemaDiagnosticCode {
@@ -22,6 +39,13 @@ impl SchemaDiagnosticCode {
         match self {
             Self::ParseFailure => "schema.parse_failure",
             Self::ValidationFailure => "schema.validation_failure",
+            Self::Cycle => "schema.cycle",
+            Self::UnsupportedSchemaVersion => "schema.unsupported_version",
+            Self::DeprecatedAlias => "schema.deprecated_alias",
+            Self::UnknownKey => "schema.unknown_key",
+            Self::KaniUnreachableWithWitness => "schema.kani.unreachable_with_witness",
+            Self::KaniStubbedContract => "schema.kani.stubbed_contract",
+            Self::KaniVacuousPlayback => "schema.kani.vacuous_playback",
         }
     }
 }
@@ -64,7 +88,10 @@ impl SchemaDiagnostic {
     }
 }
 
-fn location_for_source(source: &SourceId, location: serde_saphyr::Location) -> SourceLocation {
+pub(super) fn location_for_source(
+    source: &SourceId,
+    location: serde_saphyr::Location,
+) -> SourceLocation {
     let line = usize::try_from(location.line()).ok().unwrap_or(usize::MAX);
     let column = usize::try_from(location.column())
         .ok()
@@ -0,0 +1,311 @@
+//! Canonical YAML emission for loaded theorem documents.
+//!
+//! [`emit_theorem_docs`] writes documents back in the shape the loader
+//! reads: `TitleCase` top-level keys in specification order, map entries in
+//! their loaded order, and plain values in place of the decoded forms
+//! (`{ ref: name }` for references, `{ expr: ... }` for computed arguments,
+//! and structured mappings for `Forall` domains). Borrowed view structs
+//! carry the YAML layout, so the serde form of [`TheoremDoc`] itself stays
+//! free to serve as the cache format.
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_saphyr::SerializerOptions;
+
+use super::forall::{ForallChoice, ForallDomain, NumericBound};
+use super::newtypes::{ForallVar, TheoremName};
+use super::symbol::Symbol;
+use super::types::{ActionSignature, Assertion, Assumption, TheoremDoc, TraceLink, WitnessCheck};
+
+#[path = "emit_evidence.rs"]
+mod evidence;
+#[path = "emit_steps.rs"]
+mod steps;
+
+use evidence::EvidenceView;
+use steps::{LetView, StepView, steps};
+
+/// Writes `docs` as a multi-document YAML stream that loads back into
+/// equal documents.
+///
+/// Keys use their canonical `TitleCase` or lowercase spelling, empty optional
+/// sections are omitted, and `Forall`, `Types`, `Actions`, `Let`, and
+/// argument maps keep their loaded order. `Include` and `Use` are not
+/// written, because their fragments are already merged into the document;
+/// a document loaded with includes therefore reloads with an empty
+/// [`TheoremDoc::include`].
+///
+/// # Examples
+///
+///     use theoremc_core::schema::{emit_theorem_docs, load_theorem_docs};
+///
+///     let yaml = r#"
+///     theorem: Trivial
+///     about: Always holds
+///     prove:
+///       - assert: "true"
+///         because: trivially true
+///     evidence:
+///       kani: { unwind: 1, expect: SUCCESS }
+///     witness:
+///       - cover: "true"
+///         because: always reachable
+///     "#;
+///     let docs = load_theorem_docs(yaml).unwrap();
+///
+///     let emitted = emit_theorem_docs(&docs);
+///     assert!(emitted.starts_with("Theorem: Trivial\nAbout: Always holds\n"));
+///     assert_eq!(load_theorem_docs(&emitted).unwrap(), docs);
+#[must_use]
+pub fn emit_theorem_docs(docs: &[TheoremDoc]) -> String {
+    let views: Vec<DocView<'_>> = docs.iter().map(DocView::new).collect();
+    let options = SerializerOptions {
+        compact_list_indent: false,
+        ..SerializerOptions::default()
+    };
+    // Every view has string keys and no impl raises a custom error, and
+    // writing to a `String` cannot fail, so serialization always succeeds.
+    serde_saphyr::to_string_multiple_with_options(&views, options).unwrap_or_default()
+}
+
+#[expect(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "serde passes fields to `skip_serializing_if` by reference"
+)]
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+// ── Top-level document ──────────────────────────────────────────────
+
+#[derive(Serialize)]
+struct DocView<'a> {
+    #[serde(rename = "Schema", skip_serializing_if = "Option::is_none")]
+    schema: Option<u32>,
+    #[serde(rename = "Theorem")]
+    theorem: &'a TheoremName,
+    #[serde(rename = "About")]
+    about: &'a str,
+    #[serde(rename = "Tags", skip_serializing_if = "<[_]>::is_empty")]
+    tags: &'a [Symbol],
+    #[serde(rename = "Traces", skip_serializing_if = "Vec::is_empty")]
+    traces: Vec<TraceView<'a>>,
+    #[serde(rename = "Given", skip_serializing_if = "<[_]>::is_empty")]
+    given: &'a [String],
+    #[serde(rename = "DependsOn", skip_serializing_if = "<[_]>::is_empty")]
+    depends_on: &'a [TheoremName],
+    #[serde(rename = "Types", skip_serializing_if = "IndexMap::is_empty")]
+    types: &'a IndexMap<String, String>,
+    #[serde(rename = "Forall", skip_serializing_if = "IndexMap::is_empty")]
+    forall: IndexMap<&'a ForallVar, ForallView<'a>>,
+    #[serde(rename = "Assume", skip_serializing_if = "Vec::is_empty")]
+    assume: Vec<AssumeView<'a>>,
+    #[serde(rename = "Witness", skip_serializing_if = "<[_]>::is_empty")]
+    witness: &'a [WitnessCheck],
+    #[serde(rename = "Let", skip_serializing_if = "IndexMap::is_empty")]
+    let_bindings: IndexMap<&'a str, LetView<'a>>,
+    #[serde(rename = "Do", skip_serializing_if = "Vec::is_empty")]
+    do_steps: Vec<StepView<'a>>,
+    #[serde(rename = "Actions", skip_serializing_if = "IndexMap::is_empty")]
+    actions: &'a IndexMap<String, ActionSignature>,
+    #[serde(rename = "Prove")]
+    prove: Vec<ProveView<'a>>,
+    #[serde(rename = "Evidence")]
+    evidence: EvidenceView<'a>,
+    #[serde(rename = "Lints", skip_serializing_if = "IndexMap::is_empty")]
+    lints: &'a IndexMap<String, String>,
+}
+
+impl<'a> DocView<'a> {
+    fn new(doc: &'a TheoremDoc) -> Self {
+        Self {
+            schema: doc.schema,
+            theorem: &doc.theorem,
+            about: &doc.about,
+            tags: &doc.tags,
+            traces: doc.traces.iter().map(TraceView::new).collect(),
+            given: &doc.given,
+            depends_on: &doc.depends_on,
+            types: &doc.types,
+            forall: doc
+                .forall
+                .iter()
+                .map(|(var, ty)| (var, ForallView::new(ty, doc.forall_domains.get(var))))
+                .collect(),
+            assume: doc.assume.iter().map(AssumeView::new).collect(),
+            witness: &doc.witness,
+            let_bindings: doc
+                .let_bindings
+                .iter()
+                .map(|(name, binding)| (name.as_str(), LetView::new(binding)))
+                .collect(),
+            do_steps: steps(&doc.do_steps),
+            actions: &doc.actions,
+            prove: doc.prove.iter().map(ProveView::new).collect(),
+            evidence: EvidenceView::new(&doc.evidence),
+            lints: &doc.lints,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TraceView<'a> {
+    id: &'a str,
+    system: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'a str>,
+}
+
+impl<'a> TraceView<'a> {
+    fn new(trace: &'a TraceLink) -> Self {
+        Self {
+            id: &trace.id,
+            system: &trace.system,
+            url: trace.url.as_deref(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AssumeView<'a> {
+    assume: &'a str,
+    because: &'a str,
+}
+
+impl<'a> AssumeView<'a> {
+    fn new(assumption: &'a Assumption) -> Self {
+        Self {
+            assume: assumption.expr.as_str(),
+            because: &assumption.because,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ProveView<'a> {
+    assert: &'a str,
+    because: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    step: Option<&'a str>,
+}
+
+impl<'a> ProveView<'a> {
+    fn new(assertion: &'a Assertion) -> Self {
+        Self {
+            assert: assertion.assert_expr.as_str(),
+            because: &assertion.because,
+            step: assertion.step.as_deref(),
+        }
+    }
+}
+
+// ── Forall ──────────────────────────────────────────────────────────
+
+/// A `Forall` entry: the bare type, or a mapping when a domain is declared.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ForallView<'a> {
+    Type(&'a str),
+    Domain(DomainView<'a>),
+}
+
+impl<'a> ForallView<'a> {
+    fn new(ty: &'a str, domain: Option<&'a ForallDomain>) -> Self {
+        match domain {
+            None => Self::Type(ty),
+            Some(ForallDomain::Optional {
+                ty: value_ty,
+                inner,
+            }) => Self::Domain(DomainView {
+                optional: true,
+                ..DomainView::new(value_ty, inner.as_deref())
+            }),
+            Some(value_domain) => Self::Domain(DomainView::new(ty, Some(value_domain))),
+        }
+    }
+}
+
+#[derive(Default, Serialize)]
+struct DomainView<'a> {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    ty: Option<&'a str>,
+    #[serde(rename = "struct", skip_serializing_if = "Option::is_none")]
+    struct_ty: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vec: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min: Option<NumericBound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<NumericBound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oneof: Option<&'a [ForallChoice]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fields: Option<&'a IndexMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    len: Option<LenView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<&'a str>,
+    #[serde(skip_serializing_if = "is_false")]
+    optional: bool,
+}
+
+#[derive(Serialize)]
+struct LenView {
+    min: u32,
+    max: u32,
+}
+
+impl<'a> DomainView<'a> {
+    /// Returns the mapping declaring a value of type `ty` within `domain`.
+    /// Optional domains never nest, so a nested one is written as its type.
+    fn new(ty: &'a str, domain: Option<&'a ForallDomain>) -> Self {
+        let typed = Self {
+            ty: Some(ty),
+            ..Self::default()
+        };
+        match domain {
+            Some(ForallDomain::Range { min, max }) => Self {
+                min: *min,
+                max: *max,
+                ..typed
+            },
+            Some(ForallDomain::OneOf(choices)) => Self {
+                oneof: Some(choices),
+                ..typed
+            },
+            Some(ForallDomain::Struct { fields }) => Self {
+                struct_ty: Some(ty),
+                fields: Some(fields),
+                ..Self::default()
+            },
+            Some(ForallDomain::Length { min, max }) => Self {
+                vec: Some(vec_element(ty)),
+                len: Some(LenView {
+                    min: *min,
+                    max: *max,
+                }),
+                ..Self::default()
+            },
+            Some(ForallDomain::Derived { expr }) => Self {
+                from: Some(expr),
+                ..typed
+            },
+            Some(ForallDomain::Optional { .. }) | None => typed,
+        }
+    }
+}
+
+/// Returns the element type of a `Vec<element>` type string, or the whole
+/// string when it does not have that form.
+fn vec_element(ty: &str) -> &str {
+    ty.trim()
+        .strip_prefix("Vec")
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix('<'))
+        .and_then(|rest| rest.strip_suffix('>'))
+        .map_or(ty, str::trim)
+}
+
+#[cfg(test)]
+#[path = "emit_tests.rs"]
+mod tests;
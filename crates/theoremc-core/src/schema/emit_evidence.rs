@@ -0,0 +1,114 @@
+//! Canonical YAML views of the `Evidence` section.
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::schema::kani_limits::{KaniTimeout, MemoryLimit};
+use crate::schema::types::{
+    CounterexampleConstraint, Evidence, KaniEvidence, KaniExpectation, KaniSolver, KaniStub,
+};
+use crate::schema::value::TheoremValue;
+
+#[derive(Serialize)]
+pub(super) struct EvidenceView<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kani: Option<KaniView<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verus: Option<&'a TheoremValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stateright: Option<&'a TheoremValue>,
+}
+
+impl<'a> EvidenceView<'a> {
+    pub(super) fn new(evidence: &'a Evidence) -> Self {
+        let configs: Vec<_> = evidence.kani.iter().map(KaniConfigView::new).collect();
+        let kani = match <[_; 1]>::try_from(configs) {
+            Ok([config]) => Some(KaniView::One(config)),
+            Err(none) if none.is_empty() => None,
+            Err(several) => Some(KaniView::Many(several)),
+        };
+        Self {
+            kani,
+            verus: evidence.verus.as_ref(),
+            stateright: evidence.stateright.as_ref(),
+        }
+    }
+}
+
+/// `Evidence.kani`: one mapping, or a list when there are several.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum KaniView<'a> {
+    One(KaniConfigView<'a>),
+    Many(Vec<KaniConfigView<'a>>),
+}
+
+#[derive(Serialize)]
+struct KaniConfigView<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    unwind: UnwindView<'a>,
+    expect: KaniExpectation,
+    #[serde(skip_serializing_if = "super::is_false")]
+    allow_vacuous: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vacuity_because: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solver: Option<KaniSolver>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    extra_args: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<&'a KaniTimeout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory_limit: Option<&'a MemoryLimit>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    stubs: &'a [KaniStub],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    contracts: &'a [String],
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    counterexample: &'a IndexMap<String, CounterexampleConstraint>,
+    #[serde(skip_serializing_if = "super::is_false")]
+    playback: bool,
+    #[serde(skip_serializing_if = "super::is_false")]
+    coverage: bool,
+}
+
+impl<'a> KaniConfigView<'a> {
+    fn new(kani: &'a KaniEvidence) -> Self {
+        Self {
+            name: kani.name.as_deref(),
+            unwind: UnwindView(kani),
+            expect: kani.expect,
+            allow_vacuous: kani.allow_vacuous,
+            vacuity_because: kani.vacuity_because.as_deref(),
+            solver: kani.solver,
+            extra_args: &kani.extra_args,
+            timeout: kani.timeout.as_ref(),
+            memory_limit: kani.memory_limit.as_ref(),
+            stubs: &kani.stubs,
+            contracts: &kani.contracts,
+            counterexample: &kani.counterexample,
+            playback: kani.playback,
+            coverage: kani.coverage,
+        }
+    }
+}
+
+/// Kani `unwind`: the global bound, or a mapping with a `default` entry
+/// when per-loop bounds are declared.
+struct UnwindView<'a>(&'a KaniEvidence);
+
+impl Serialize for UnwindView<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.loop_unwind.is_empty() {
+            return serializer.serialize_u32(self.0.unwind);
+        }
+        let default = std::iter::once(("default", self.0.unwind));
+        let loops = self
+            .0
+            .loop_unwind
+            .iter()
+            .map(|(label, bound)| (label.as_str(), *bound));
+        serializer.collect_map(default.chain(loops))
+    }
+}
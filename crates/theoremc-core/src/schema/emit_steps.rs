@@ -0,0 +1,179 @@
+//! Canonical YAML views of `Let` bindings and `Do` steps.
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::schema::arg_value::{ArgValue, LiteralValue};
+use crate::schema::types::{ActionCall, InlineAssert, LetBinding, Step};
+
+#[derive(Serialize)]
+pub(super) enum LetView<'a> {
+    #[serde(rename = "call")]
+    Call(CallView<'a>),
+    #[serde(rename = "must")]
+    Must(CallView<'a>),
+}
+
+impl<'a> LetView<'a> {
+    pub(super) fn new(binding: &'a LetBinding) -> Self {
+        match binding {
+            LetBinding::Call(let_call) => Self::Call(CallView::new(&let_call.call)),
+            LetBinding::Must(let_must) => Self::Must(CallView::new(&let_must.must)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(super) struct CallView<'a> {
+    action: &'a str,
+    args: IndexMap<&'a str, ArgView<'a>>,
+    #[serde(rename = "as", skip_serializing_if = "Option::is_none")]
+    as_binding: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+impl<'a> CallView<'a> {
+    fn new(call: &'a ActionCall) -> Self {
+        Self {
+            action: &call.action,
+            args: call
+                .args
+                .iter()
+                .map(|(name, value)| (name.as_str(), ArgView(value)))
+                .collect(),
+            as_binding: call.as_binding.as_deref(),
+            error: None,
+        }
+    }
+}
+
+/// An argument value in the form that decodes back to it.
+struct ArgView<'a>(&'a ArgValue);
+
+impl Serialize for ArgView<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            ArgValue::Literal(LiteralValue::Bool(value)) => serializer.serialize_bool(*value),
+            ArgValue::Literal(LiteralValue::Integer(value)) => serializer.serialize_i64(*value),
+            ArgValue::Literal(LiteralValue::Float(value)) => serializer.serialize_f64(*value),
+            ArgValue::Literal(LiteralValue::String(value)) => serializer.serialize_str(value),
+            ArgValue::Reference(name) => single_entry(serializer, "ref", name),
+            ArgValue::Expr(source) => single_entry(serializer, "expr", source),
+            ArgValue::RawSequence(values) => values.serialize(serializer),
+            ArgValue::RawMap(entries) => entries.serialize(serializer),
+        }
+    }
+}
+
+fn single_entry<S: serde::Serializer>(
+    serializer: S,
+    key: &str,
+    value: &str,
+) -> Result<S::Ok, S::Error> {
+    IndexMap::from([(key, value)]).serialize(serializer)
+}
+
+pub(super) fn steps(steps: &[Step]) -> Vec<StepView<'_>> {
+    steps.iter().map(StepView::new).collect()
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub(super) enum StepView<'a> {
+    Call {
+        call: CallView<'a>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<&'a str>,
+    },
+    Must {
+        must: CallView<'a>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<&'a str>,
+    },
+    ExpectErr {
+        expect_err: CallView<'a>,
+    },
+    Maybe {
+        maybe: MaybeView<'a>,
+    },
+    Repeat {
+        repeat: RepeatView<'a>,
+    },
+    Foreach {
+        foreach: ForeachView<'a>,
+    },
+    Assert {
+        assert: &'a InlineAssert,
+    },
+}
+
+impl<'a> StepView<'a> {
+    fn new(step: &'a Step) -> Self {
+        match step {
+            Step::Call(step_call) => Self::Call {
+                call: CallView::new(&step_call.call),
+                id: step_call.id.as_deref(),
+            },
+            Step::Must(step_must) => Self::Must {
+                must: CallView::new(&step_must.must),
+                id: step_must.id.as_deref(),
+            },
+            Step::ExpectErr(step_err) => Self::ExpectErr {
+                expect_err: CallView {
+                    error: Some(&step_err.error),
+                    ..CallView::new(&step_err.expect_err)
+                },
+            },
+            Step::Maybe(step_maybe) => Self::Maybe {
+                maybe: MaybeView {
+                    because: &step_maybe.maybe.because,
+                    do_steps: steps(&step_maybe.maybe.do_steps),
+                    else_steps: steps(&step_maybe.maybe.else_steps),
+                },
+            },
+            Step::Repeat(step_repeat) => Self::Repeat {
+                repeat: RepeatView {
+                    times: step_repeat.repeat.times,
+                    do_steps: steps(&step_repeat.repeat.do_steps),
+                },
+            },
+            Step::Foreach(step_foreach) => Self::Foreach {
+                foreach: ForeachView {
+                    source: &step_foreach.foreach.source,
+                    as_binding: &step_foreach.foreach.as_binding,
+                    do_steps: steps(&step_foreach.foreach.do_steps),
+                },
+            },
+            Step::Assert(step_assert) => Self::Assert {
+                assert: &step_assert.assert,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(super) struct MaybeView<'a> {
+    because: &'a str,
+    #[serde(rename = "do")]
+    do_steps: Vec<StepView<'a>>,
+    #[serde(rename = "else", skip_serializing_if = "Vec::is_empty")]
+    else_steps: Vec<StepView<'a>>,
+}
+
+#[derive(Serialize)]
+pub(super) struct RepeatView<'a> {
+    times: u32,
+    #[serde(rename = "do")]
+    do_steps: Vec<StepView<'a>>,
+}
+
+#[derive(Serialize)]
+pub(super) struct ForeachView<'a> {
+    #[serde(rename = "in")]
+    source: &'a str,
+    #[serde(rename = "as")]
+    as_binding: &'a str,
+    #[serde(rename = "do")]
+    do_steps: Vec<StepView<'a>>,
+}
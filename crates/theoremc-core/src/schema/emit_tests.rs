@@ -0,0 +1,197 @@
+//! Unit tests for canonical YAML emission.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::emit_theorem_docs;
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+fn load(yaml: &str) -> Vec<TheoremDoc> {
+    load_theorem_docs(yaml).expect("source should load")
+}
+
+fn reload(docs: &[TheoremDoc]) -> Vec<TheoremDoc> {
+    let emitted = emit_theorem_docs(docs);
+    load_theorem_docs(&emitted)
+        .unwrap_or_else(|error| panic!("emitted YAML should load: {error}\n{emitted}"))
+}
+
+const EVERY_FEATURE: &str = r#"
+Schema: 1
+Theorem: EveryFeature
+About: "Uses: every emitted form"
+Tags: [ledger, "true"]
+Traces:
+  - { id: REQ-1, system: specs }
+  - { id: REQ-2, system: specs, url: "https://example.com/req-2" }
+Given:
+  - "multi-line\nnarrative"
+Types:
+  Amount: u64
+Forall:
+  amount: { type: Amount, min: 1, max: 10 }
+  ratio: { type: f64, min: 0.5 }
+  choice: { type: u8, oneof: [1, 2] }
+  items: { vec: u32, len: { min: 1, max: 3 } }
+  maybe_items: { vec: u32, len: { max: 2 }, optional: true }
+  maybe_amount: { type: u64, max: 5, optional: true }
+  total: { type: u64, from: "amount + 1" }
+  plain: bool
+Actions:
+  ledger.deposit:
+    params: { amount: u64, memo: String, flag: bool }
+    returns: "Result<u64, crate::LedgerError>"
+Assume:
+  - assume: "amount < 10"
+    because: bounded
+Let:
+  start:
+    must:
+      action: ledger.deposit
+      args: { amount: { ref: amount }, memo: "null", flag: false }
+Do:
+  - call:
+      action: ledger.deposit
+      args: { amount: { expr: "amount + 1" }, memo: "42", flag: true }
+      as: after
+    id: first
+  - maybe:
+      because: sometimes
+      do:
+        - assert: { expr: "after > 0", because: deposits add }
+      else:
+        - repeat:
+            times: 2
+            do:
+              - expect_err:
+                  action: ledger.deposit
+                  args: { amount: 0, memo: "", flag: true }
+                  error: Zero
+  - foreach:
+      in: items
+      as: item
+      do:
+        - must:
+            action: ledger.deposit
+            args: { amount: { ref: item }, memo: { literal: "x" }, flag: true }
+Prove:
+  - assert: "after > amount"
+    because: deposits add
+    step: first
+Evidence:
+  kani:
+    - name: fast
+      unwind: { default: 4, retry: 5 }
+      expect: SUCCESS
+      solver: kissat
+      extra_args: [--verbose, "--output-format=terse"]
+      timeout: 90s
+      memory_limit: 1GiB
+      playback: true
+    - name: vacuous
+      unwind: 4
+      expect: SUCCESS
+      allow_vacuous: true
+      vacuity_because: exploratory
+      coverage: true
+  verus: { mode: spec }
+Witness:
+  - cover: "amount == 1"
+    because: reachable
+Lints:
+  unused_let: allow
+"#;
+
+#[rstest]
+#[case::aliases_and_must(include_str!("../../../../tests/fixtures/valid_aliases_and_must.theorem"))]
+#[case::arg_literal_wrapper(include_str!("../../../../tests/fixtures/valid_arg_literal_wrapper.theorem"))]
+#[case::arg_mixed_scalars(include_str!("../../../../tests/fixtures/valid_arg_mixed_scalars.theorem"))]
+#[case::arg_ref(include_str!("../../../../tests/fixtures/valid_arg_ref.theorem"))]
+#[case::arg_string_literal(include_str!("../../../../tests/fixtures/valid_arg_string_literal.theorem"))]
+#[case::assert_step(include_str!("../../../../tests/fixtures/valid_assert_step.theorem"))]
+#[case::defaults(include_str!("../../../../tests/fixtures/valid_defaults.theorem"))]
+#[case::depends_on(include_str!("../../../../tests/fixtures/valid_depends_on.theorem"))]
+#[case::expect_err(include_str!("../../../../tests/fixtures/valid_expect_err.theorem"))]
+#[case::expr_args(include_str!("../../../../tests/fixtures/valid_expr_args.theorem"))]
+#[case::forall_derived(include_str!("../../../../tests/fixtures/valid_forall_derived.theorem"))]
+#[case::forall_domains(include_str!("../../../../tests/fixtures/valid_forall_domains.theorem"))]
+#[case::forall_oneof(include_str!("../../../../tests/fixtures/valid_forall_oneof.theorem"))]
+#[case::forall_optional(include_str!("../../../../tests/fixtures/valid_forall_optional.theorem"))]
+#[case::forall_struct(include_str!("../../../../tests/fixtures/valid_forall_struct.theorem"))]
+#[case::forall_vec(include_str!("../../../../tests/fixtures/valid_forall_vec.theorem"))]
+#[case::foreach(include_str!("../../../../tests/fixtures/valid_foreach.theorem"))]
+#[case::full(include_str!("../../../../tests/fixtures/valid_full.theorem"))]
+#[case::lowercase(include_str!("../../../../tests/fixtures/valid_lowercase.theorem"))]
+#[case::maybe_else(include_str!("../../../../tests/fixtures/valid_maybe_else.theorem"))]
+#[case::minimal(include_str!("../../../../tests/fixtures/valid_minimal.theorem"))]
+#[case::multi(include_str!("../../../../tests/fixtures/valid_multi.theorem"))]
+#[case::nested_maybe(include_str!("../../../../tests/fixtures/valid_nested_maybe.theorem"))]
+#[case::positional_args(include_str!("../../../../tests/fixtures/valid_positional_args.theorem"))]
+#[case::repeat(include_str!("../../../../tests/fixtures/valid_repeat.theorem"))]
+#[case::shared_action(include_str!("../../../../tests/fixtures/valid_shared_action_across_theorems.theorem"))]
+#[case::shared_inputs(include_str!("../../../../tests/fixtures/valid_shared_inputs.theorem"))]
+#[case::step_ids(include_str!("../../../../tests/fixtures/valid_step_ids.theorem"))]
+#[case::suite(include_str!("../../../../tests/fixtures/valid_suite.theorem"))]
+#[case::traces(include_str!("../../../../tests/fixtures/valid_traces.theorem"))]
+#[case::type_aliases(include_str!("../../../../tests/fixtures/valid_type_aliases.theorem"))]
+#[case::vacuous(include_str!("../../../../tests/fixtures/valid_vacuous.theorem"))]
+#[case::every_feature(EVERY_FEATURE)]
+fn emitted_documents_load_back_unchanged(#[case] yaml: &str) {
+    let docs = load(yaml);
+
+    assert_eq!(reload(&docs), docs);
+}
+
+#[test]
+fn emission_is_idempotent() {
+    let emitted = emit_theorem_docs(&load(EVERY_FEATURE));
+
+    assert_eq!(emit_theorem_docs(&load(&emitted)), emitted);
+}
+
+#[test]
+fn top_level_keys_are_title_case_in_specification_order() {
+    let emitted = emit_theorem_docs(&load(EVERY_FEATURE));
+    let keys: Vec<&str> = emitted
+        .lines()
+        .filter(|line| line.starts_with(|first: char| first.is_ascii_uppercase()))
+        .filter_map(|line| line.split_once(':').map(|(key, _)| key))
+        .collect();
+
+    assert_eq!(
+        keys,
+        [
+            "Schema", "Theorem", "About", "Tags", "Traces", "Given", "Types", "Forall", "Assume",
+            "Witness", "Let", "Do", "Actions", "Prove", "Evidence", "Lints",
+        ]
+    );
+}
+
+#[test]
+fn documents_are_separated_by_markers_and_keep_their_order() {
+    let docs = load(include_str!(
+        "../../../../tests/fixtures/valid_multi.theorem"
+    ));
+
+    let emitted = emit_theorem_docs(&docs);
+
+    assert_eq!(
+        emitted.matches("\n---\n").count() + 1,
+        docs.len(),
+        "{emitted}"
+    );
+    assert_eq!(
+        reload(&docs)
+            .iter()
+            .map(|doc| doc.theorem.to_string())
+            .collect::<Vec<_>>(),
+        docs.iter()
+            .map(|doc| doc.theorem.to_string())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn no_documents_emit_nothing() {
+    assert_eq!(emit_theorem_docs(&[]), "");
+}
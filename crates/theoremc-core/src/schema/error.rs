@@ -1,6 +1,7 @@
 //! Error types for `.theorem` schema deserialization and validation.
 
-use super::diagnostic::SchemaDiagnostic;
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode};
+use super::source_format::SourceFormat;
 
 fn format_duplicate_theorem_key_collisions(collisions: &[SchemaDiagnostic]) -> String {
     collisions
@@ -23,6 +24,17 @@ pub enum SchemaError {
         diagnostic: Option<SchemaDiagnostic>,
     },
 
+    /// A JSON or TOML source is not well-formed.
+    #[error("{format} syntax error: {message}")]
+    Syntax {
+        /// Format the source was read as.
+        format: SourceFormat,
+        /// Parser error message.
+        message: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<SchemaDiagnostic>,
+    },
+
     /// A theorem identifier failed lexical or keyword validation.
     #[error("invalid identifier '{identifier}': {reason}")]
     InvalidIdentifier {
@@ -55,6 +67,102 @@ pub enum SchemaError {
         source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
     },
 
+    /// An `Include` directive could not be resolved or merged.
+    #[error("include '{include}' failed for theorem '{theorem}': {reason}")]
+    IncludeFailed {
+        /// The theorem name whose includes failed to resolve.
+        theorem: String,
+        /// The include path that failed.
+        include: String,
+        /// A human-readable explanation of the failure.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A `Suite` document is malformed or names theorems it cannot group.
+    #[error("invalid suite '{suite}': {reason}")]
+    InvalidSuite {
+        /// The suite name as declared.
+        suite: String,
+        /// A human-readable explanation of the violation.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A `Shared` document is malformed.
+    #[error("invalid shared block '{shared}': {reason}")]
+    InvalidShared {
+        /// The shared block name as declared.
+        shared: String,
+        /// A human-readable explanation of the violation.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A theorem's `Extends` base could not be resolved or merged.
+    #[error("extending theorem '{base}' failed for theorem '{theorem}': {reason}")]
+    ExtendsFailed {
+        /// The theorem name whose `Extends` entry failed to merge.
+        theorem: String,
+        /// The base theorem name the entry refers to.
+        base: String,
+        /// A human-readable explanation of the failure.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A `Fixtures` document is malformed.
+    #[error("invalid fixture '{fixture}': {reason}")]
+    InvalidFixture {
+        /// The fixture name as declared.
+        fixture: String,
+        /// A human-readable explanation of the violation.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A theorem's `UseFixtures` entry could not be resolved or merged.
+    #[error("use of fixture '{fixture}' failed for theorem '{theorem}': {reason}")]
+    UseFixtureFailed {
+        /// The theorem name whose `UseFixtures` entries failed to merge.
+        theorem: String,
+        /// The fixture name the failing entry refers to.
+        fixture: String,
+        /// A human-readable explanation of the failure.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// A theorem's `Use` entry could not be resolved or merged.
+    #[error("use of shared block '{shared}' failed for theorem '{theorem}': {reason}")]
+    UseFailed {
+        /// The theorem name whose `Use` entries failed to merge.
+        theorem: String,
+        /// The shared block name the failing entry refers to.
+        shared: String,
+        /// A human-readable explanation of the failure.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
+    /// An action manifest entry is malformed.
+    #[error("invalid action manifest entry '{action}': {reason}")]
+    InvalidActionManifest {
+        /// The canonical action name as declared.
+        action: String,
+        /// A human-readable explanation of the violation.
+        reason: String,
+        /// Optional structured diagnostic payload.
+        diagnostic: Option<Box<SchemaDiagnostic>>,
+    },
+
     /// Two or more different canonical action names produce the same
     /// mangled Rust identifier.
     #[error("mangled identifier collision: {message}")]
@@ -87,10 +195,48 @@ impl SchemaError {
     #[must_use]
     pub fn diagnostic(&self) -> Option<&SchemaDiagnostic> {
         match self {
-            Self::Deserialize { diagnostic, .. } | Self::DuplicateTheoremKey { diagnostic, .. } => {
-                diagnostic.as_ref()
-            }
-            Self::ValidationFailed { diagnostic, .. } => diagnostic.as_deref(),
+            Self::Deserialize { diagnostic, .. }
+            | Self::Syntax { diagnostic, .. }
+            | Self::DuplicateTheoremKey { diagnostic, .. } => diagnostic.as_ref(),
+            Self::ValidationFailed { diagnostic, .. }
+            | Self::IncludeFailed { diagnostic, .. }
+            | Self::InvalidSuite { diagnostic, .. }
+            | Self::InvalidShared { diagnostic, .. }
+            | Self::InvalidFixture { diagnostic, .. }
+            | Self::ExtendsFailed { diagnostic, .. }
+            | Self::UseFixtureFailed { diagnostic, .. }
+            | Self::InvalidActionManifest { diagnostic, .. }
+            | Self::UseFailed { diagnostic, .. } => diagnostic.as_deref(),
+            Self::InvalidIdentifier { .. }
+            | Self::InvalidActionName { .. }
+            | Self::MangledIdentifierCollision { .. } => None,
+        }
+    }
+
+    /// Reclassifies the structured diagnostic as a
+    /// [`SchemaDiagnosticCode::Cycle`], for failures that report a cycle.
+    pub(crate) fn into_cycle(mut self) -> Self {
+        if let Some(diagnostic) = self.diagnostic_mut() {
+            diagnostic.code = SchemaDiagnosticCode::Cycle;
+        }
+        self
+    }
+
+    /// Returns the structured diagnostic payload for in-place adjustment.
+    pub(crate) fn diagnostic_mut(&mut self) -> Option<&mut SchemaDiagnostic> {
+        match self {
+            Self::Deserialize { diagnostic, .. }
+            | Self::Syntax { diagnostic, .. }
+            | Self::DuplicateTheoremKey { diagnostic, .. } => diagnostic.as_mut(),
+            Self::ValidationFailed { diagnostic, .. }
+            | Self::IncludeFailed { diagnostic, .. }
+            | Self::InvalidSuite { diagnostic, .. }
+            | Self::InvalidShared { diagnostic, .. }
+            | Self::InvalidFixture { diagnostic, .. }
+            | Self::ExtendsFailed { diagnostic, .. }
+            | Self::UseFixtureFailed { diagnostic, .. }
+            | Self::InvalidActionManifest { diagnostic, .. }
+            | Self::UseFailed { diagnostic, .. } => diagnostic.as_deref_mut(),
             Self::InvalidIdentifier { .. }
             | Self::InvalidActionName { .. }
             | Self::MangledIdentifierCollision { .. } => None,
@@ -4,7 +4,13 @@
 //! `syn::Expr` and rejects statement-like forms (blocks, loops,
 //! assignments, and flow-control constructs) that are not single
 //! expressions. It is called from the post-deserialization validation
-//! pipeline in `validate.rs`.
+//! pipeline in `validate.rs`. [`free_identifiers`] lists the plain names an
+//! expression refers to, so callers can resolve them against declared
+//! variables.
+
+use std::str::FromStr;
+
+use proc_macro2::{TokenStream, TokenTree};
 
 /// Validates that `input` is a syntactically valid Rust expression and
 /// is not a statement-like form (block, loop, assignment, or
@@ -35,6 +41,49 @@ pub(crate) fn validate_rust_expr(input: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Returns the distinct unqualified identifiers in `input`, in first-seen
+/// order.
+///
+/// Identifiers are taken from the token stream, skipping field and method
+/// names after `.`, path segments after `::`, path heads and field labels
+/// followed by `:`, macro names followed by `!`, and lifetimes. Keywords are
+/// not filtered out. Input that does not tokenize yields no identifiers.
+pub(crate) fn free_identifiers(input: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    if let Ok(tokens) = TokenStream::from_str(input) {
+        collect_free_identifiers(tokens, &mut found);
+    }
+    found
+}
+
+fn collect_free_identifiers(tokens: TokenStream, found: &mut Vec<String>) {
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    for (index, tree) in trees.iter().enumerate() {
+        match tree {
+            TokenTree::Ident(ident) if is_free_ident(&trees, index) => {
+                let name = ident.to_string();
+                if !found.contains(&name) {
+                    found.push(name);
+                }
+            }
+            TokenTree::Group(group) => collect_free_identifiers(group.stream(), found),
+            _ => {}
+        }
+    }
+}
+
+fn is_free_ident(trees: &[TokenTree], index: usize) -> bool {
+    let before = |offset| index.checked_sub(offset).and_then(|at| trees.get(at));
+    let after = trees.get(index + 1);
+    let qualified = is_punct(before(1), &['.', '\''])
+        || (is_punct(before(1), &[':']) && is_punct(before(2), &[':']));
+    !qualified && !is_punct(after, &[':', '!'])
+}
+
+fn is_punct(tree: Option<&TokenTree>, chars: &[char]) -> bool {
+    matches!(tree, Some(TokenTree::Punct(punct)) if chars.contains(&punct.as_char()))
+}
+
 /// Returns `true` if the given `syn::Expr` variant is a statement-like
 /// form that is disallowed in theorem expressions.
 ///
@@ -98,7 +147,7 @@ mod tests {
 
     use rstest::rstest;
 
-    use super::validate_rust_expr;
+    use super::{free_identifiers, validate_rust_expr};
 
     // ── Happy path: valid single expressions ─────────────────────
 
@@ -180,4 +229,17 @@ mod tests {
             reason = reason
         );
     }
+
+    // ── Free identifier collection ───────────────────────────────
+
+    #[rstest]
+    #[case::arithmetic("a + b * a", &["a", "b"][..])]
+    #[case::method_and_field("account.balance() + fee.amount", &["account", "fee"][..])]
+    #[case::paths("u64::MAX - crate::limits::CAP - x", &["x"][..])]
+    #[case::nested_call("min(a, (b + c))", &["min", "a", "b", "c"][..])]
+    #[case::macro_and_label("vec![n; 2].len() + Point { x: y }.x", &["n", "Point", "y"][..])]
+    #[case::unparseable("\"unterminated", &[][..])]
+    fn free_identifiers_skip_qualified_names(#[case] input: &str, #[case] expected: &[&str]) {
+        assert_eq!(free_identifiers(input), expected);
+    }
 }
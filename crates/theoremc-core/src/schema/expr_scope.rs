@@ -0,0 +1,167 @@
+//! Variable references in theorem expressions.
+//!
+//! [`referenced_variables`] walks a parsed expression with `syn::visit` and
+//! reports the single-segment value paths it reads that are not bound inside
+//! the expression itself. Closure parameters, `match` arm patterns, `if let`
+//! patterns, `for` patterns, and `let` statements in nested blocks bind names
+//! for the code they scope over. Call targets such as `min(a, b)` name
+//! functions rather than variables, and names that do not start with a
+//! lower-case letter or underscore are constants, unit structs, or enum
+//! variants by Rust convention, so neither is reported.
+
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+
+/// Returns the distinct variables `input` reads, in first-seen order.
+///
+/// Macro arguments are inspected when they parse as a comma-separated list
+/// of expressions. Input that does not parse as an expression yields no
+/// variables.
+pub(crate) fn referenced_variables(input: &str) -> Vec<String> {
+    let Ok(expr) = syn::parse_str::<syn::Expr>(input) else {
+        return Vec::new();
+    };
+    let mut scope = ScopeVisitor::default();
+    scope.visit_expr(&expr);
+    scope.referenced
+}
+
+/// Names bound so far, innermost last, and the free variables found.
+#[derive(Default)]
+struct ScopeVisitor {
+    bound: Vec<String>,
+    referenced: Vec<String>,
+}
+
+impl ScopeVisitor {
+    fn bind(&mut self, pat: &syn::Pat) {
+        PatternBindings(&mut self.bound).visit_pat(pat);
+    }
+
+    /// Runs `body` with the names bound by `pats` in scope.
+    fn scoped<'p>(
+        &mut self,
+        pats: impl IntoIterator<Item = &'p syn::Pat>,
+        body: impl FnOnce(&mut Self),
+    ) {
+        let depth = self.bound.len();
+        for pat in pats {
+            self.bind(pat);
+        }
+        body(self);
+        self.bound.truncate(depth);
+    }
+}
+
+impl<'ast> Visit<'ast> for ScopeVisitor {
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        let Some(ident) = node.path.get_ident().filter(|_| node.qself.is_none()) else {
+            return;
+        };
+        let name = ident.to_string();
+        if is_variable_name(&name)
+            && !self.bound.contains(&name)
+            && !self.referenced.contains(&name)
+        {
+            self.referenced.push(name);
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        let names_function = matches!(
+            &*node.func,
+            syn::Expr::Path(path) if path.qself.is_none() && path.path.get_ident().is_some()
+        );
+        if !names_function {
+            self.visit_expr(&node.func);
+        }
+        for arg in &node.args {
+            self.visit_expr(arg);
+        }
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast syn::ExprClosure) {
+        self.scoped(&node.inputs, |scope| scope.visit_expr(&node.body));
+    }
+
+    fn visit_arm(&mut self, node: &'ast syn::Arm) {
+        self.scoped([&node.pat], |scope| {
+            if let Some((_, guard)) = &node.guard {
+                scope.visit_expr(guard);
+            }
+            scope.visit_expr(&node.body);
+        });
+    }
+
+    fn visit_expr_if(&mut self, node: &'ast syn::ExprIf) {
+        // Patterns in `if let` conditions bind for the `then` branch only.
+        self.scoped([], |scope| {
+            scope.visit_expr(&node.cond);
+            scope.visit_block(&node.then_branch);
+        });
+        if let Some((_, else_branch)) = &node.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_let(&mut self, node: &'ast syn::ExprLet) {
+        self.visit_expr(&node.expr);
+        self.bind(&node.pat);
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast syn::ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.scoped([&*node.pat], |scope| scope.visit_block(&node.body));
+    }
+
+    fn visit_block(&mut self, node: &'ast syn::Block) {
+        self.scoped([], |scope| {
+            for stmt in &node.stmts {
+                scope.visit_stmt(stmt);
+            }
+        });
+    }
+
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        if let Some(init) = &node.init {
+            self.visit_expr(&init.expr);
+            if let Some((_, diverge)) = &init.diverge {
+                self.visit_expr(diverge);
+            }
+        }
+        self.bind(&node.pat);
+    }
+
+    fn visit_item(&mut self, _node: &'ast syn::Item) {}
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        let parser = Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated;
+        if let Ok(args) = node.parse_body_with(parser) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+/// Collects the identifiers a pattern binds.
+struct PatternBindings<'a>(&'a mut Vec<String>);
+
+impl<'ast> Visit<'ast> for PatternBindings<'_> {
+    fn visit_pat_ident(&mut self, node: &'ast syn::PatIdent) {
+        self.0.push(node.ident.to_string());
+        visit::visit_pat_ident(self, node);
+    }
+}
+
+fn is_variable_name(name: &str) -> bool {
+    name != "self"
+        && name
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_ascii_lowercase() || first == '_')
+}
+
+#[cfg(test)]
+#[path = "expr_scope_tests.rs"]
+mod tests;
@@ -0,0 +1,39 @@
+//! Unit tests for variable references in theorem expressions.
+
+use rstest::rstest;
+
+use super::referenced_variables;
+
+#[rstest]
+#[case::arithmetic("a + b * a", &["a", "b"][..])]
+#[case::method_and_field("account.balance() + fee.amount", &["account", "fee"][..])]
+#[case::paths_and_constants("u64::MAX - crate::limits::CAP - MAX_FEE - x", &["x"][..])]
+#[case::function_calls("min(a, (b + c)) + u64::from(d)", &["a", "b", "c", "d"][..])]
+#[case::variants_and_literals("op == Op::Deposit || flag == None || true", &["op", "flag"][..])]
+#[case::struct_shorthand("Point { x, y: z } == origin", &["x", "z", "origin"][..])]
+#[case::macro_arguments("assert_eq!(total, limit + 1)", &["total", "limit"][..])]
+#[case::opaque_macro("vec![n; 2].is_empty()", &[][..])]
+#[case::unparseable("a +", &[][..])]
+fn references_exclude_functions_and_constants(#[case] input: &str, #[case] expected: &[&str]) {
+    assert_eq!(referenced_variables(input), expected);
+}
+
+#[rstest]
+#[case::closure("limit.map_or(true, |cap| cap <= ceiling)", &["limit", "ceiling"][..])]
+#[case::match_arm(
+    "match memo { Some(text) if text.len() > max => false, _ => other }",
+    &["memo", "max", "other"][..]
+)]
+#[case::if_let(
+    "if let Some(cap) = limit { cap > 0 } else { cap_default }",
+    &["limit", "cap_default"][..]
+)]
+#[case::binding_scope_ends("items.iter().all(|item| item > 0) && item", &["items", "item"][..])]
+#[case::nested_block_let("if ready { let x = base + 1; x > 2 } else { false }", &["ready", "base"][..])]
+#[case::for_loop("if ready { for i in 0..n { check(i); } true } else { false }", &["ready", "n"][..])]
+fn bindings_inside_the_expression_are_not_references(
+    #[case] input: &str,
+    #[case] expected: &[&str],
+) {
+    assert_eq!(referenced_variables(input), expected);
+}
@@ -0,0 +1,339 @@
+//! Structured `Forall` domains.
+//!
+//! A `Forall` entry is either a bare Rust type string or a mapping that pairs
+//! the type with value constraints, such as `{ type: u64, min: 1, max: 100 }`
+//! or `{ type: crate::Op, oneof: [Deposit, Withdraw] }`, or that describes a
+//! struct built field by field, such as
+//! `{ struct: crate::Params, fields: { balance: u64 } }`, a bounded vector
+//! such as `{ vec: u32, len: { max: 8 } }`, or that derives the value from
+//! earlier variables, such as `{ type: u64, from: "a + b" }`. Any entry except
+//! a derived one may add `optional: true` to wrap its value in `Option`.
+//! [`ForallDomain`] records those constraints and lowers them to Rust
+//! expressions: ranges
+//! become boolean expressions the Kani backend emits as `kani::assume` calls
+//! straight after binding the symbolic value, finite sets become the
+//! candidate values of a nondeterministic selection, struct fields each
+//! receive their own symbolic value, vectors are generated up to their
+//! maximum length, derived values are computed in declaration order, and
+//! optional values are nondeterministically `None` or `Some` of an in-domain
+//! value.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+#[path = "forall_check.rs"]
+mod check;
+#[path = "forall_de.rs"]
+mod de;
+
+pub(crate) use check::{ForallDomainField, check_domain, check_unwind};
+pub(crate) use de::ScalarBound;
+
+/// Value constraints declared by a structured `Forall` entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ForallDomain {
+    /// An inclusive numeric range. At least one bound is present.
+    Range {
+        /// Smallest permitted value.
+        min: Option<NumericBound>,
+        /// Largest permitted value.
+        max: Option<NumericBound>,
+    },
+    /// A finite set of permitted values, in declaration order.
+    OneOf(Vec<ForallChoice>),
+    /// A struct whose fields are each bound to an arbitrary value.
+    Struct {
+        /// Field names mapped to their Rust types, in declaration order.
+        fields: IndexMap<String, String>,
+    },
+    /// A `Vec` of arbitrary elements with an inclusive length range.
+    Length {
+        /// Shortest permitted length.
+        min: u32,
+        /// Longest permitted length, which bounds the generated vector.
+        max: u32,
+    },
+    /// A value computed from earlier `Forall` variables rather than chosen
+    /// symbolically.
+    Derived {
+        /// The Rust expression computing the value.
+        expr: String,
+    },
+    /// An `Option` that is either `None` or `Some` of a value drawn from the
+    /// wrapped domain.
+    Optional {
+        /// The Rust type of the wrapped value.
+        ty: String,
+        /// Constraints on the wrapped value, or `None` when it is arbitrary.
+        inner: Option<Box<Self>>,
+    },
+}
+
+impl ForallDomain {
+    /// Returns the Rust boolean expressions over `var` that confine the
+    /// symbolic value to this domain, in declaration order.
+    ///
+    /// Finite sets are selected rather than assumed, so they yield none; see
+    /// [`ForallDomain::choice_exprs`]. Struct and derived domains constrain
+    /// nothing, and length domains constrain only a non-zero minimum length, because the
+    /// maximum bounds the generated vector itself. Optional domains constrain
+    /// the wrapped value before it is wrapped; see
+    /// [`ForallDomain::optional_inner`].
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::{ForallDomain, NumericBound};
+    ///
+    ///     let domain = ForallDomain::Range {
+    ///         min: Some(NumericBound::Integer(1)),
+    ///         max: Some(NumericBound::Integer(100)),
+    ///     };
+    ///     assert_eq!(domain.constraints("amount"), ["amount >= 1", "amount <= 100"]);
+    #[must_use]
+    pub fn constraints(&self, var: &str) -> Vec<String> {
+        match self {
+            Self::Range { min, max } => min
+                .iter()
+                .map(|bound| format!("{var} >= {bound}"))
+                .chain(max.iter().map(|bound| format!("{var} <= {bound}")))
+                .collect(),
+            Self::Length { min, .. } if *min > 0 => vec![format!("{var}.len() >= {min}")],
+            Self::OneOf(_)
+            | Self::Struct { .. }
+            | Self::Length { .. }
+            | Self::Derived { .. }
+            | Self::Optional { .. } => Vec::new(),
+        }
+    }
+
+    /// Returns one Rust expression per permitted value of a variable of type
+    /// `ty`, or `None` when the domain narrows an arbitrary value instead of
+    /// selecting from a finite set.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::{ForallChoice, ForallDomain};
+    ///
+    ///     let domain = ForallDomain::OneOf(vec![
+    ///         ForallChoice::Path("Deposit".to_owned()),
+    ///         ForallChoice::Path("crate::Op::Withdraw".to_owned()),
+    ///     ]);
+    ///     assert_eq!(
+    ///         domain.choice_exprs("crate::Op"),
+    ///         Some(vec![
+    ///             "<crate::Op>::Deposit".to_owned(),
+    ///             "crate::Op::Withdraw".to_owned(),
+    ///         ])
+    ///     );
+    #[must_use]
+    pub fn choice_exprs(&self, ty: &str) -> Option<Vec<String>> {
+        match self {
+            Self::Range { .. }
+            | Self::Struct { .. }
+            | Self::Length { .. }
+            | Self::Derived { .. }
+            | Self::Optional { .. } => None,
+            Self::OneOf(choices) => Some(choices.iter().map(|choice| choice.to_expr(ty)).collect()),
+        }
+    }
+
+    /// Returns the declared fields of a struct domain, or `None` for other
+    /// domains.
+    #[must_use]
+    pub const fn struct_fields(&self) -> Option<&IndexMap<String, String>> {
+        match self {
+            Self::Struct { fields } => Some(fields),
+            Self::Range { .. }
+            | Self::OneOf(_)
+            | Self::Length { .. }
+            | Self::Derived { .. }
+            | Self::Optional { .. } => None,
+        }
+    }
+
+    /// Returns the longest permitted length of a length domain, or `None`
+    /// for other domains.
+    #[must_use]
+    pub const fn max_len(&self) -> Option<u32> {
+        match self {
+            Self::Length { max, .. } => Some(*max),
+            Self::Range { .. }
+            | Self::OneOf(_)
+            | Self::Struct { .. }
+            | Self::Derived { .. }
+            | Self::Optional { .. } => None,
+        }
+    }
+
+    /// Returns the expression of a derived variable, or `None` for other
+    /// domains.
+    #[must_use]
+    pub fn derived_expr(&self) -> Option<&str> {
+        match self {
+            Self::Derived { expr } => Some(expr),
+            Self::Range { .. }
+            | Self::OneOf(_)
+            | Self::Struct { .. }
+            | Self::Length { .. }
+            | Self::Optional { .. } => None,
+        }
+    }
+
+    /// Returns the wrapped type and domain of an optional value, or `None`
+    /// for other domains.
+    #[must_use]
+    pub fn optional_inner(&self) -> Option<(&str, Option<&Self>)> {
+        match self {
+            Self::Optional { ty, inner } => Some((ty, inner.as_deref())),
+            Self::Range { .. }
+            | Self::OneOf(_)
+            | Self::Struct { .. }
+            | Self::Length { .. }
+            | Self::Derived { .. } => None,
+        }
+    }
+
+    /// Returns the Rust types the domain names beyond the entry's own type:
+    /// struct field types, including those of an optional struct.
+    #[must_use]
+    pub fn nested_types(&self) -> Vec<&str> {
+        match self {
+            Self::Struct { fields } => fields.values().map(String::as_str).collect(),
+            Self::Optional { inner, .. } => {
+                inner.as_deref().map_or_else(Vec::new, Self::nested_types)
+            }
+            Self::Range { .. } | Self::OneOf(_) | Self::Length { .. } | Self::Derived { .. } => {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns every type string the domain stores, for alias expansion.
+    pub(crate) fn type_strings_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            Self::Struct { fields } => fields.values_mut().collect(),
+            Self::Optional { ty, inner } => {
+                let mut types = vec![ty];
+                if let Some(wrapped) = inner {
+                    types.extend(wrapped.type_strings_mut());
+                }
+                types
+            }
+            Self::Range { .. } | Self::OneOf(_) | Self::Length { .. } | Self::Derived { .. } => {
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// One permitted value of a `oneof` domain.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForallChoice {
+    /// A numeric literal.
+    Number(NumericBound),
+    /// A boolean literal.
+    Bool(bool),
+    /// A Rust path. A single segment such as `Deposit` names an associated
+    /// item of the entry's type, typically an enum variant; longer paths are
+    /// used as written.
+    Path(String),
+}
+
+impl ForallChoice {
+    /// Renders the choice as a Rust expression for a variable of type `ty`.
+    #[must_use]
+    pub fn to_expr(&self, ty: &str) -> String {
+        match self {
+            Self::Path(path) if !path.contains("::") => format!("<{}>::{}", ty.trim(), path.trim()),
+            Self::Path(path) => path.trim().to_owned(),
+            Self::Number(value) => value.to_string(),
+            Self::Bool(value) => value.to_string(),
+        }
+    }
+}
+
+/// Renders the choice as written in the theorem file, for diagnostics.
+impl fmt::Display for ForallChoice {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(formatter, "{value}"),
+            Self::Bool(value) => write!(formatter, "{value}"),
+            Self::Path(path) => write!(formatter, "'{path}'"),
+        }
+    }
+}
+
+/// A numeric literal bounding a `Forall` range or listed in a `oneof` set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericBound {
+    /// An integer literal that fits `i128`.
+    Integer(i128),
+    /// An integer literal above `i128::MAX`, which only `u128` admits.
+    Unsigned(u128),
+    /// A floating-point literal.
+    Float(f64),
+}
+
+impl NumericBound {
+    /// Returns `true` when this bound is greater than `other`, comparing
+    /// integer pairs exactly.
+    fn exceeds(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Integer(low), Self::Integer(high)) => low > high,
+            (Self::Unsigned(low), Self::Unsigned(high)) => low > high,
+            (Self::Unsigned(_), Self::Integer(_)) => true,
+            (Self::Integer(_), Self::Unsigned(_)) => false,
+            _ => self.as_f64() > other.as_f64(),
+        }
+    }
+
+    const fn as_f64(self) -> f64 {
+        match self {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "mixed integer and float bounds are only ordered, never round-tripped"
+            )]
+            Self::Integer(value) => value as f64,
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "mixed integer and float bounds are only ordered, never round-tripped"
+            )]
+            Self::Unsigned(value) => value as f64,
+            Self::Float(value) => value,
+        }
+    }
+}
+
+/// Renders the bound as a Rust literal without a type suffix, so it takes
+/// the type of the variable it is compared against.
+impl fmt::Display for NumericBound {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(formatter, "{value}"),
+            Self::Unsigned(value) => write!(formatter, "{value}"),
+            Self::Float(value) => write!(formatter, "{value:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "forall_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+#[path = "forall_struct_tests.rs"]
+mod struct_tests;
+
+#[cfg(test)]
+#[path = "forall_vec_tests.rs"]
+mod vec_tests;
+
+#[cfg(test)]
+#[path = "forall_derive_tests.rs"]
+mod derive_tests;
+
+#[cfg(test)]
+#[path = "forall_optional_tests.rs"]
+mod optional_tests;
@@ -0,0 +1,272 @@
+//! Compatibility checks between `Forall` domains and their declared types.
+
+use indexmap::IndexMap;
+
+use super::{ForallChoice, ForallDomain, NumericBound};
+use crate::schema::expr::validate_rust_expr;
+use crate::schema::identifier::validate_identifier;
+use crate::schema::rust_type;
+
+/// Which part of a structured `Forall` entry a domain check rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ForallDomainField {
+    /// The entry's `type`.
+    Type,
+    /// The entry's `min` bound.
+    Min,
+    /// The entry's `max` bound.
+    Max,
+    /// The entry's `oneof` list as a whole.
+    OneOf,
+    /// One value in the entry's `oneof` list, by zero-based position.
+    Choice(usize),
+    /// The entry's `fields` mapping as a whole.
+    Fields,
+    /// One entry in the `fields` mapping, by zero-based position.
+    Field(usize),
+    /// The `max` of the entry's `len` mapping.
+    LenMax,
+    /// The entry's `from` expression.
+    From,
+}
+
+/// A domain that does not fit its declared type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DomainMismatch {
+    pub(crate) field: ForallDomainField,
+    pub(crate) reason: String,
+}
+
+impl DomainMismatch {
+    const fn new(field: ForallDomainField, reason: String) -> Self {
+        Self { field, reason }
+    }
+}
+
+/// Checks that `domain` can constrain a value of the Rust type `ty`.
+///
+/// Range bounds require a primitive numeric type; integer types accept only
+/// integer bounds within their range, float types accept finite bounds
+/// within their range, and `min` must not exceed `max`. A `oneof` set must
+/// be non-empty, free of duplicates, and list only values that fit the type.
+/// A struct domain needs a plain type path and at least one field, each with
+/// an identifier name and a valid Rust type. A length range must not be
+/// inverted, and a derived value's `from` must be a single Rust expression.
+/// An optional value's wrapped domain must fit the wrapped type.
+pub(crate) fn check_domain(domain: &ForallDomain, ty: &str) -> Result<(), DomainMismatch> {
+    match domain {
+        ForallDomain::Range { min, max } => check_range(*min, *max, ty),
+        ForallDomain::OneOf(choices) => check_choices(choices, ty),
+        ForallDomain::Struct { fields } => check_struct(fields, ty),
+        ForallDomain::Length { min, max } if min > max => Err(DomainMismatch::new(
+            ForallDomainField::LenMax,
+            format!("len min {min} must not exceed len max {max}"),
+        )),
+        ForallDomain::Length { .. } => Ok(()),
+        ForallDomain::Derived { expr } => validate_rust_expr(expr.trim()).map_err(|reason| {
+            DomainMismatch::new(ForallDomainField::From, format!("from {reason}"))
+        }),
+        ForallDomain::Optional {
+            ty: wrapped_ty,
+            inner,
+        } => inner
+            .as_deref()
+            .map_or(Ok(()), |wrapped| check_domain(wrapped, wrapped_ty)),
+    }
+}
+
+/// Checks that a Kani `unwind` bound can iterate over every element of a
+/// bounded vector. A loop over `max` elements needs `max + 1` unwindings,
+/// including the final exit check.
+pub(crate) fn check_unwind(domain: &ForallDomain, unwind: u32) -> Result<(), DomainMismatch> {
+    if let Some((_, Some(wrapped))) = domain.optional_inner() {
+        return check_unwind(wrapped, unwind);
+    }
+    match domain.max_len() {
+        Some(max) if unwind <= max => Err(DomainMismatch::new(
+            ForallDomainField::LenMax,
+            format!(
+                "len max {max} needs a Kani unwind of at least {}, but unwind is {unwind}",
+                u64::from(max) + 1
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_range(
+    min: Option<NumericBound>,
+    max: Option<NumericBound>,
+    ty: &str,
+) -> Result<(), DomainMismatch> {
+    let Some(numeric) = NumericType::parse(ty) else {
+        return Err(DomainMismatch::new(
+            ForallDomainField::Type,
+            format!("type '{ty}' does not support min/max bounds; use a primitive numeric type"),
+        ));
+    };
+    for (field, label, bound) in [
+        (ForallDomainField::Min, "min", min),
+        (ForallDomainField::Max, "max", max),
+    ] {
+        if let Some(value) = bound {
+            numeric
+                .admits(value)
+                .map_err(|reason| DomainMismatch::new(field, format!("{label} {reason}")))?;
+        }
+    }
+    if let (Some(low), Some(high)) = (min, max)
+        && low.exceeds(high)
+    {
+        return Err(DomainMismatch::new(
+            ForallDomainField::Max,
+            format!("min {low} must not exceed max {high}"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_choices(choices: &[ForallChoice], ty: &str) -> Result<(), DomainMismatch> {
+    if choices.is_empty() {
+        return Err(DomainMismatch::new(
+            ForallDomainField::OneOf,
+            "oneof must list at least one value".to_owned(),
+        ));
+    }
+    for (index, choice) in choices.iter().enumerate() {
+        let position = index + 1;
+        let mismatch =
+            |reason: String| DomainMismatch::new(ForallDomainField::Choice(index), reason);
+        check_choice(choice, ty)
+            .map_err(|reason| mismatch(format!("oneof value {position}: {reason}")))?;
+        if choices
+            .get(..index)
+            .is_some_and(|earlier| earlier.contains(choice))
+        {
+            return Err(mismatch(format!(
+                "oneof value {position}: {choice} is listed more than once"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that one `oneof` value can inhabit the Rust type `ty`.
+fn check_choice(choice: &ForallChoice, ty: &str) -> Result<(), String> {
+    match choice {
+        ForallChoice::Number(value) => NumericType::parse(ty)
+            .ok_or_else(|| format!("{value} is a number, but type '{ty}' is not numeric"))?
+            .admits(*value),
+        ForallChoice::Bool(value) if ty.trim() != "bool" => {
+            Err(format!("{value} is a boolean, but the type is '{ty}'"))
+        }
+        ForallChoice::Bool(_) => Ok(()),
+        ForallChoice::Path(path) => syn::parse_str::<syn::Path>(path)
+            .map(|_| ())
+            .map_err(|error| format!("{choice} is not a valid Rust path: {error}")),
+    }
+}
+
+fn check_struct(fields: &IndexMap<String, String>, ty: &str) -> Result<(), DomainMismatch> {
+    if !syn::parse_str::<syn::TypePath>(ty).is_ok_and(|path| path.qself.is_none()) {
+        return Err(DomainMismatch::new(
+            ForallDomainField::Type,
+            format!("struct '{ty}' must be a Rust path naming a struct type"),
+        ));
+    }
+    if fields.is_empty() {
+        return Err(DomainMismatch::new(
+            ForallDomainField::Fields,
+            "fields must list at least one field".to_owned(),
+        ));
+    }
+    for (index, (name, field_ty)) in fields.iter().enumerate() {
+        check_field(name, field_ty).map_err(|reason| {
+            DomainMismatch::new(
+                ForallDomainField::Field(index),
+                format!("field '{name}': {reason}"),
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks one struct field's name and type.
+fn check_field(name: &str, ty: &str) -> Result<(), String> {
+    validate_identifier(name).map_err(|error| format!("name {error}"))?;
+    match rust_type::parse_with_free_named_lifetime(ty) {
+        Err(error) => Err(format!("type is not a valid Rust type: {error}")),
+        Ok(Some(lifetime)) => Err(format!(
+            "type contains a free named lifetime parameter '{lifetime}'; use an owned type"
+        )),
+        Ok(None) => Ok(()),
+    }
+}
+
+/// Primitive numeric types that accept range bounds and numeric choices.
+#[derive(Debug, Clone, Copy)]
+enum NumericType {
+    Integer {
+        name: &'static str,
+        low: i128,
+        high: u128,
+    },
+    Float {
+        name: &'static str,
+        max: f64,
+    },
+}
+
+impl NumericType {
+    fn parse(ty: &str) -> Option<Self> {
+        let integer = |name, low, high| Some(Self::Integer { name, low, high });
+        match ty.trim() {
+            "u8" => integer("u8", 0, u8::MAX.into()),
+            "u16" => integer("u16", 0, u16::MAX.into()),
+            "u32" => integer("u32", 0, u32::MAX.into()),
+            "u64" => integer("u64", 0, u64::MAX.into()),
+            // `usize` and `isize` are checked against their 64-bit range, the
+            // widest width Kani targets.
+            "usize" => integer("usize", 0, u64::MAX.into()),
+            "u128" => integer("u128", 0, u128::MAX),
+            "i8" => integer("i8", i8::MIN.into(), i8::MAX.unsigned_abs().into()),
+            "i16" => integer("i16", i16::MIN.into(), i16::MAX.unsigned_abs().into()),
+            "i32" => integer("i32", i32::MIN.into(), i32::MAX.unsigned_abs().into()),
+            "i64" => integer("i64", i64::MIN.into(), i64::MAX.unsigned_abs().into()),
+            "isize" => integer("isize", i64::MIN.into(), i64::MAX.unsigned_abs().into()),
+            "i128" => integer("i128", i128::MIN, i128::MAX.unsigned_abs()),
+            "f32" => Some(Self::Float {
+                name: "f32",
+                max: f64::from(f32::MAX),
+            }),
+            "f64" => Some(Self::Float {
+                name: "f64",
+                max: f64::MAX,
+            }),
+            _ => None,
+        }
+    }
+
+    fn admits(self, bound: NumericBound) -> Result<(), String> {
+        match (self, bound) {
+            (Self::Integer { name, low, high }, NumericBound::Integer(value))
+                if value < low || u128::try_from(value).is_ok_and(|magnitude| magnitude > high) =>
+            {
+                Err(format!("{bound} is out of range for {name}"))
+            }
+            (Self::Integer { name, high, .. }, NumericBound::Unsigned(value)) if value > high => {
+                Err(format!("{bound} is out of range for {name}"))
+            }
+            (Self::Integer { name, .. }, NumericBound::Float(value)) => Err(format!(
+                "{value:?} is not an integer, but the type is {name}"
+            )),
+            (Self::Float { .. }, NumericBound::Float(value)) if !value.is_finite() => {
+                Err("must be a finite number".to_owned())
+            }
+            (Self::Float { name, max }, _) if bound.as_f64().abs() > max => {
+                Err(format!("{bound} is out of range for {name}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
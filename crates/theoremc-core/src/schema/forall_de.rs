@@ -0,0 +1,194 @@
+//! (De)serialization of `Forall` bounds and `oneof` choices as plain scalars.
+//!
+//! Theorem files read `min` and `max` through [`ScalarBound`], which takes
+//! the bound's text rather than a number the YAML parser has already typed,
+//! because integers outside the 64-bit range would otherwise reach the
+//! visitor as rounded floats.
+
+use std::fmt;
+
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::{ForallChoice, NumericBound};
+
+impl Serialize for NumericBound {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Integer(value) => serializer.serialize_i128(*value),
+            Self::Unsigned(value) => serializer.serialize_u128(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NumericBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NumericBoundVisitor)
+    }
+}
+
+/// A `min` or `max` bound read from its scalar text, so integers keep
+/// their exact value up to the `i128` and `u128` limits.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ScalarBound(pub(crate) NumericBound);
+
+impl<'de> Deserialize<'de> for ScalarBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(NumericBoundVisitor).map(Self)
+    }
+}
+
+struct NumericBoundVisitor;
+
+impl Visitor<'_> for NumericBoundVisitor {
+    type Value = NumericBound;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an integer or floating-point number")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(NumericBound::Integer(i128::from(v)))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(NumericBound::Integer(i128::from(v)))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(NumericBound::Integer(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(i128::try_from(v).map_or(NumericBound::Unsigned(v), NumericBound::Integer))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(NumericBound::Float(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let text = v.trim();
+        match parse_integer(text) {
+            Some(Ok(bound)) => Ok(bound),
+            Some(Err(())) => Err(E::custom(format!(
+                "integer {text} is out of range for a Forall bound"
+            ))),
+            None => parse_float(text)
+                .map(NumericBound::Float)
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+/// Reads an integer as the YAML loader does: decimal digits, or `0x`, `0o`,
+/// or `0b` digits, with an optional sign and `_` separators. Returns `None`
+/// when `text` is not an integer and `Some(Err(()))` when it overflows
+/// `i128` or `u128`.
+fn parse_integer(text: &str) -> Option<Result<NumericBound, ()>> {
+    let (negative, magnitude) = text.strip_prefix('-').map_or_else(
+        || (false, text.strip_prefix('+').unwrap_or(text)),
+        |rest| (true, rest),
+    );
+    let (radix, written) = [("0x", 16), ("0o", 8), ("0b", 2)]
+        .into_iter()
+        .find_map(|(prefix, radix)| {
+            let rest = magnitude.get(prefix.len()..)?;
+            magnitude
+                .get(..prefix.len())
+                .filter(|written| written.eq_ignore_ascii_case(prefix))
+                .map(|_| (radix, rest))
+        })
+        .unwrap_or((10, magnitude));
+    let digits: String = written.chars().filter(|&digit| digit != '_').collect();
+    if digits.is_empty() || !digits.chars().all(|digit| digit.is_digit(radix)) {
+        return None;
+    }
+    let Ok(value) = u128::from_str_radix(&digits, radix) else {
+        return Some(Err(()));
+    };
+    Some(if negative {
+        0_i128
+            .checked_sub_unsigned(value)
+            .map(NumericBound::Integer)
+            .ok_or(())
+    } else {
+        Ok(i128::try_from(value).map_or(NumericBound::Unsigned(value), NumericBound::Integer))
+    })
+}
+
+/// Reads a decimal floating-point literal such as `0.5`, `-1.5e3`, or `.25`.
+fn parse_float(text: &str) -> Option<f64> {
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let is_decimal = unsigned.starts_with(|first: char| first.is_ascii_digit() || first == '.')
+        && unsigned
+            .chars()
+            .all(|part| part.is_ascii_digit() || matches!(part, '.' | 'e' | 'E' | '-' | '+'));
+    is_decimal.then(|| text.parse().ok()).flatten()
+}
+
+impl Serialize for ForallChoice {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Number(value) => value.serialize(serializer),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Path(path) => serializer.serialize_str(path),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ForallChoice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ForallChoiceVisitor)
+    }
+}
+
+/// Accepts the numeric scalars [`NumericBoundVisitor`] does, plus booleans
+/// and path strings.
+struct ForallChoiceVisitor;
+
+impl Visitor<'_> for ForallChoiceVisitor {
+    type Value = ForallChoice;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a number, boolean, or Rust path")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(ForallChoice::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        NumericBoundVisitor.visit_i64(v).map(ForallChoice::Number)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        NumericBoundVisitor.visit_u64(v).map(ForallChoice::Number)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        NumericBoundVisitor.visit_i128(v).map(ForallChoice::Number)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        NumericBoundVisitor.visit_u128(v).map(ForallChoice::Number)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        NumericBoundVisitor.visit_f64(v).map(ForallChoice::Number)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(ForallChoice::Path(v.to_owned()))
+    }
+}
@@ -0,0 +1,122 @@
+//! Unit tests for derived `Forall` variables.
+
+use rstest::rstest;
+
+use super::tests::{theorem_with_forall, var};
+use super::{ForallDomain, ForallDomainField, check_domain};
+use crate::schema::{Symbol, load_theorem_docs};
+
+fn derived(expr: &str) -> ForallDomain {
+    ForallDomain::Derived {
+        expr: expr.to_owned(),
+    }
+}
+
+#[test]
+fn derived_domains_expose_their_expression_only() {
+    let domain = derived("a + b");
+    assert_eq!(domain.derived_expr(), Some("a + b"));
+    assert!(domain.constraints("total").is_empty());
+    assert_eq!(domain.choice_exprs("u64"), None);
+    assert_eq!(domain.max_len(), None);
+}
+
+#[rstest]
+#[case::statement("{ a }", "from must be a single expression")]
+#[case::unparseable("a +", "from is not a valid Rust expression")]
+fn derived_expressions_use_the_expression_checker(
+    #[case] expr: &str,
+    #[case] expected_fragment: &str,
+) {
+    let mismatch = check_domain(&derived(expr), "u64").expect_err("expression should fail");
+    assert_eq!(mismatch.field, ForallDomainField::From);
+    assert!(
+        mismatch.reason.contains(expected_fragment),
+        "expected '{expected_fragment}' in '{}'",
+        mismatch.reason
+    );
+}
+
+#[test]
+fn derived_entries_load_after_their_inputs() {
+    let yaml = theorem_with_forall(concat!(
+        "  a: u32\n",
+        "  b: u32\n",
+        "  total: { type: u64, from: \"u64::from(a) + u64::from(b)\" }\n",
+    ));
+    let docs = load_theorem_docs(&yaml).expect("derived Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.get(&var("total")).map(Symbol::as_str),
+        Some("u64")
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("total")),
+        Some(&derived("u64::from(a) + u64::from(b)"))
+    );
+}
+
+#[rstest]
+#[case::forward_reference(
+    "  total:\n    type: u64\n    from: a + b\n  a: u64\n  b: u64\n",
+    "Forall entry 'total': from refers to 'a', which is declared after it",
+    8
+)]
+#[case::self_reference(
+    "  a: u64\n  total:\n    type: u64\n    from: total + a\n",
+    "Forall entry 'total': from refers to itself",
+    9
+)]
+#[case::invalid_expression(
+    "  a: u64\n  total:\n    type: u64\n    from: 'a +'\n",
+    "Forall entry 'total': from is not a valid Rust expression",
+    9
+)]
+fn invalid_derivations_point_at_from(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let error =
+        load_theorem_docs(&theorem_with_forall(forall)).expect_err("derivation should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::field_access(concat!(
+    "  limits: crate::Limits\n",
+    "  cap: { type: u64, from: \"limits.later\" }\n",
+    "  later: u64\n",
+))]
+#[case::path_segment(concat!(
+    "  cap: { type: u64, from: \"crate::later()\" }\n",
+    "  later: u64\n",
+))]
+fn qualified_names_are_not_forall_references(#[case] forall: &str) {
+    load_theorem_docs(&theorem_with_forall(forall))
+        .expect("qualified names should not resolve to Forall variables");
+}
+
+#[rstest]
+#[case::with_bounds(
+    "  x: { type: u8, from: \"1\", max: 3 }\n",
+    "`from` cannot be combined with `min`, `max`, or `oneof`"
+)]
+#[case::with_struct(
+    "  x: { struct: P, fields: { a: u8 }, from: \"p\" }\n",
+    "`from` requires `type`"
+)]
+fn malformed_derivations_are_parse_errors(#[case] forall: &str, #[case] expected_fragment: &str) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+}
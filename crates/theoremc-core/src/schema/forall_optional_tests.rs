@@ -0,0 +1,126 @@
+//! Unit tests for optional `Forall` values.
+
+use indexmap::IndexMap;
+use rstest::rstest;
+
+use super::tests::{theorem_with_forall, var};
+use super::{ForallDomain, NumericBound};
+use crate::schema::{Symbol, load_theorem_docs};
+
+fn optional(ty: &str, inner: Option<ForallDomain>) -> ForallDomain {
+    ForallDomain::Optional {
+        ty: ty.to_owned(),
+        inner: inner.map(Box::new),
+    }
+}
+
+#[test]
+fn optional_domains_expose_their_wrapped_type_and_domain() {
+    let range = ForallDomain::Range {
+        min: None,
+        max: Some(NumericBound::Integer(10)),
+    };
+    let domain = optional("u64", Some(range.clone()));
+
+    assert_eq!(domain.optional_inner(), Some(("u64", Some(&range))));
+    assert!(domain.constraints("limit").is_empty());
+    assert_eq!(domain.choice_exprs("Option<u64>"), None);
+    assert_eq!(domain.max_len(), None);
+    assert_eq!(range.optional_inner(), None);
+}
+
+#[test]
+fn optional_structs_report_their_field_types() {
+    let fields = IndexMap::from([("frozen".to_owned(), "bool".to_owned())]);
+    let domain = optional("crate::Params", Some(ForallDomain::Struct { fields }));
+
+    assert_eq!(domain.nested_types(), ["bool"]);
+}
+
+#[test]
+fn optional_entries_wrap_the_type_in_option() {
+    let yaml = theorem_with_forall(concat!(
+        "  memo: { type: u32, optional: true }\n",
+        "  limit: { type: Amount, max: 10, optional: true }\n",
+        "  plain: { type: u8, optional: false }\n",
+    ));
+    let docs = load_theorem_docs(&yaml).expect("optional Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.get(&var("memo")).map(Symbol::as_str),
+        Some("Option<u32>")
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("memo")),
+        Some(&optional("u32", None))
+    );
+    assert_eq!(
+        doc.forall.get(&var("limit")).map(Symbol::as_str),
+        Some("Option < u64 >")
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("limit")),
+        Some(&optional(
+            "u64",
+            Some(ForallDomain::Range {
+                min: None,
+                max: Some(NumericBound::Integer(10)),
+            })
+        ))
+    );
+    assert_eq!(
+        doc.forall.get(&var("plain")).map(Symbol::as_str),
+        Some("u8")
+    );
+    assert_eq!(doc.forall_domains.get(&var("plain")), None);
+}
+
+#[test]
+fn optional_vectors_wrap_the_vector_type() {
+    let yaml = theorem_with_forall("  xs: { vec: u8, len: { max: 0 }, optional: true }\n");
+    let docs = load_theorem_docs(&yaml).expect("optional vec should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.get(&var("xs")).map(Symbol::as_str),
+        Some("Option<Vec<u8>>")
+    );
+}
+
+#[rstest]
+#[case::wrapped_bound(
+    "  limit: { type: u8, max: 300, optional: true }\n",
+    "Forall entry 'limit': max 300 is out of range for u8"
+)]
+#[case::wrapped_vector(
+    "  xs: { vec: u8, len: { max: 4 }, optional: true }\n",
+    "Forall entry 'xs': len max 4 needs a Kani unwind of at least 5"
+)]
+fn wrapped_domains_are_validated(#[case] forall: &str, #[case] expected_fragment: &str) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+    assert_eq!(diagnostic.location.line, 6);
+}
+
+#[rstest]
+#[case::with_from(
+    "  x: { type: u8, from: \"1\", optional: true }\n",
+    "`optional` cannot be combined with `from`"
+)]
+#[case::not_a_bool("  x: { type: u8, optional: maybe }\n", "optional")]
+fn malformed_optional_entries_are_parse_errors(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+}
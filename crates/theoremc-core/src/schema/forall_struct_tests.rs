@@ -0,0 +1,171 @@
+//! Unit tests for struct-shaped `Forall` variables.
+
+use indexmap::IndexMap;
+use rstest::rstest;
+
+use super::check::DomainMismatch;
+use super::tests::{theorem_with_forall, var};
+use super::{ForallDomain, ForallDomainField, check_domain};
+use crate::collision::referenced_types;
+use crate::schema::{Symbol, load_theorem_docs};
+
+fn struct_domain(fields: &[(&str, &str)]) -> ForallDomain {
+    ForallDomain::Struct {
+        fields: fields
+            .iter()
+            .map(|(name, ty)| ((*name).to_owned(), (*ty).to_owned()))
+            .collect::<IndexMap<_, _>>(),
+    }
+}
+
+#[rstest]
+#[case::plain("crate::AccountParams", &[("balance", "u64"), ("frozen", "bool")])]
+#[case::generic("crate::Pair<u8>", &[("left", "u8"), ("right", "u8")])]
+#[case::owned_field("Params", &[("owner", "String")])]
+fn compatible_struct_domains_are_accepted(#[case] ty: &str, #[case] fields: &[(&str, &str)]) {
+    let domain = struct_domain(fields);
+    assert_eq!(check_domain(&domain, ty), Ok(()));
+    assert!(domain.constraints("x").is_empty());
+    assert_eq!(domain.choice_exprs(ty), None);
+}
+
+#[rstest]
+#[case::tuple_type(
+    "(u8, u8)",
+    &[("left", "u8")],
+    ForallDomainField::Type,
+    "struct '(u8, u8)' must be a Rust path naming a struct type"
+)]
+#[case::qualified_self(
+    "<T as Trait>::Params",
+    &[("left", "u8")],
+    ForallDomainField::Type,
+    "must be a Rust path naming a struct type"
+)]
+#[case::no_fields("Params", &[], ForallDomainField::Fields, "fields must list at least one field")]
+#[case::keyword_field(
+    "Params",
+    &[("balance", "u64"), ("type", "u8")],
+    ForallDomainField::Field(1),
+    "field 'type': name"
+)]
+#[case::invalid_field_type(
+    "Params",
+    &[("balance", "Vec<u64")],
+    ForallDomainField::Field(0),
+    "field 'balance': type is not a valid Rust type"
+)]
+#[case::free_lifetime(
+    "Params",
+    &[("name", "&'a str")],
+    ForallDomainField::Field(0),
+    "field 'name': type contains a free named lifetime parameter 'a"
+)]
+fn incompatible_struct_domains_are_rejected(
+    #[case] ty: &str,
+    #[case] fields: &[(&str, &str)],
+    #[case] field: ForallDomainField,
+    #[case] expected_fragment: &str,
+) {
+    let Err(DomainMismatch {
+        field: actual_field,
+        reason,
+    }) = check_domain(&struct_domain(fields), ty)
+    else {
+        panic!("struct domain should be rejected for {ty}");
+    };
+    assert_eq!(actual_field, field);
+    assert!(
+        reason.contains(expected_fragment),
+        "expected '{expected_fragment}' in '{reason}'"
+    );
+}
+
+#[test]
+fn struct_entries_load_with_alias_expanded_fields() {
+    let yaml = theorem_with_forall(concat!(
+        "  params:\n",
+        "    struct: crate::AccountParams\n",
+        "    fields: { balance: Amount, frozen: bool }\n",
+    ));
+    let docs = load_theorem_docs(&yaml).expect("struct Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.get(&var("params")).map(Symbol::as_str),
+        Some("crate::AccountParams")
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("params")),
+        Some(&struct_domain(&[("balance", "u64"), ("frozen", "bool")]))
+    );
+    assert_eq!(
+        referenced_types(&docs),
+        ["crate::AccountParams", "u64", "bool"]
+    );
+}
+
+#[rstest]
+#[case::bad_field_type(
+    "  params:\n    struct: Params\n    fields:\n      ok: u8\n      bad: 'Vec<u8'\n",
+    "Forall entry 'params': field 'bad': type is not a valid Rust type",
+    10
+)]
+#[case::empty_fields(
+    "  params:\n    struct: Params\n    fields: {}\n",
+    "Forall entry 'params': fields must list at least one field",
+    8
+)]
+#[case::non_path_struct(
+    "  params:\n    struct: '[u8; 4]'\n    fields: { a: u8 }\n",
+    "Forall entry 'params': struct '[u8; 4]' must be a Rust path",
+    7
+)]
+fn invalid_struct_entries_point_at_the_offending_field(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("struct should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::both_kinds(
+    "  x: { type: u8, struct: Params, fields: { a: u8 } }\n",
+    "only one of `type`, `struct`, and `vec` may be set"
+)]
+#[case::neither_kind("  x: { optional: true }\n", "needs `type`, `struct`, or `vec`")]
+#[case::bounds_without_type("  x: { min: 1 }\n", "`min` and `max` require `type`")]
+#[case::oneof_without_type(
+    "  op: { oneof: [Deposit, Withdraw, Transfer] }\n",
+    "`oneof` requires `type` naming the type of its values"
+)]
+#[case::from_without_type("  x: { from: \"1\" }\n", "`from` requires `type`")]
+#[case::fields_without_struct("  x: { fields: { a: u8 } }\n", "`fields` requires `struct`")]
+#[case::len_without_vec("  x: { len: { max: 2 } }\n", "`len` requires `vec`")]
+#[case::struct_without_fields("  x: { struct: Params }\n", "`struct` requires `fields`")]
+#[case::fields_with_type(
+    "  x: { type: Params, fields: { a: u8 } }\n",
+    "`fields` requires `struct` instead of `type`"
+)]
+#[case::struct_with_bounds(
+    "  x: { struct: Params, fields: { a: u8 }, max: 3 }\n",
+    "`struct` cannot be combined with `min`, `max`, `oneof`, or `len`"
+)]
+fn malformed_struct_entries_are_parse_errors(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+}
@@ -0,0 +1,368 @@
+//! Unit tests for structured `Forall` domains.
+
+use rstest::rstest;
+
+use super::check::DomainMismatch;
+use super::{ForallChoice, ForallDomain, ForallDomainField, NumericBound, check_domain};
+use crate::schema::{ForallVar, Symbol, load_theorem_docs};
+
+fn range(min: Option<NumericBound>, max: Option<NumericBound>) -> ForallDomain {
+    ForallDomain::Range { min, max }
+}
+
+#[rstest]
+#[case::both(
+    range(Some(NumericBound::Integer(-5)), Some(NumericBound::Integer(5))),
+    &["x >= -5", "x <= 5"][..]
+)]
+#[case::min_only(range(Some(NumericBound::Integer(1)), None), &["x >= 1"][..])]
+#[case::float_max(range(None, Some(NumericBound::Float(1.0))), &["x <= 1.0"][..])]
+fn range_lowers_to_inclusive_comparisons(#[case] domain: ForallDomain, #[case] expected: &[&str]) {
+    assert_eq!(domain.constraints("x"), expected);
+}
+
+#[rstest]
+#[case::unsigned(
+    "u64",
+    range(Some(NumericBound::Integer(1)), Some(NumericBound::Integer(100)))
+)]
+#[case::signed("i8", range(Some(NumericBound::Integer(-128)), Some(NumericBound::Integer(127))))]
+#[case::equal_bounds(
+    "u8",
+    range(Some(NumericBound::Integer(3)), Some(NumericBound::Integer(3)))
+)]
+#[case::float_with_integer_bound(
+    "f64",
+    range(Some(NumericBound::Integer(0)), Some(NumericBound::Float(0.5)))
+)]
+#[case::u128_upper("u128", range(None, Some(NumericBound::Unsigned(u128::MAX))))]
+#[case::i128_lower("i128", range(Some(NumericBound::Integer(i128::MIN)), None))]
+#[case::f32_upper("f32", range(None, Some(NumericBound::Float(3.0e38))))]
+fn compatible_domains_are_accepted(#[case] ty: &str, #[case] domain: ForallDomain) {
+    assert_eq!(check_domain(&domain, ty), Ok(()));
+}
+
+#[rstest]
+#[case::non_numeric_type(
+    "String",
+    range(Some(NumericBound::Integer(0)), None),
+    ForallDomainField::Type,
+    "type 'String' does not support min/max bounds"
+)]
+#[case::negative_unsigned(
+    "u32",
+    range(Some(NumericBound::Integer(-1)), None),
+    ForallDomainField::Min,
+    "min -1 is out of range for u32"
+)]
+#[case::overflowing_max(
+    "u8",
+    range(None, Some(NumericBound::Integer(256))),
+    ForallDomainField::Max,
+    "max 256 is out of range for u8"
+)]
+#[case::float_bound_on_integer(
+    "i32",
+    range(Some(NumericBound::Float(0.5)), None),
+    ForallDomainField::Min,
+    "min 0.5 is not an integer, but the type is i32"
+)]
+#[case::infinite_float(
+    "f32",
+    range(None, Some(NumericBound::Float(f64::INFINITY))),
+    ForallDomainField::Max,
+    "max must be a finite number"
+)]
+#[case::float_beyond_f32(
+    "f32",
+    range(Some(NumericBound::Float(1e40)), None),
+    ForallDomainField::Min,
+    "min 1e40 is out of range for f32"
+)]
+#[case::integer_beyond_f32(
+    "f32",
+    range(None, Some(NumericBound::Unsigned(u128::MAX))),
+    ForallDomainField::Max,
+    "max 340282366920938463463374607431768211455 is out of range for f32"
+)]
+#[case::unsigned_beyond_i128(
+    "i128",
+    range(None, Some(NumericBound::Unsigned(1 << 127))),
+    ForallDomainField::Max,
+    "max 170141183460469231731687303715884105728 is out of range for i128"
+)]
+#[case::inverted(
+    "u64",
+    range(Some(NumericBound::Integer(10)), Some(NumericBound::Integer(1))),
+    ForallDomainField::Max,
+    "min 10 must not exceed max 1"
+)]
+fn incompatible_domains_are_rejected(
+    #[case] ty: &str,
+    #[case] domain: ForallDomain,
+    #[case] field: ForallDomainField,
+    #[case] expected_fragment: &str,
+) {
+    let Err(DomainMismatch {
+        field: actual_field,
+        reason,
+    }) = check_domain(&domain, ty)
+    else {
+        panic!("domain should be rejected for {ty}");
+    };
+    assert_eq!(actual_field, field);
+    assert!(
+        reason.contains(expected_fragment),
+        "expected '{expected_fragment}' in '{reason}'"
+    );
+}
+
+fn path(name: &str) -> ForallChoice {
+    ForallChoice::Path(name.to_owned())
+}
+
+fn oneof(choices: &[ForallChoice]) -> ForallDomain {
+    ForallDomain::OneOf(choices.to_vec())
+}
+
+#[rstest]
+#[case::variants("Op", oneof(&[path("Deposit"), path("Withdraw")]))]
+#[case::qualified("crate::Op", oneof(&[path("crate::Op::Deposit")]))]
+#[case::integers("u8", oneof(&[ForallChoice::Number(NumericBound::Integer(0)), ForallChoice::Number(NumericBound::Integer(255))]))]
+#[case::booleans("bool", oneof(&[ForallChoice::Bool(false), ForallChoice::Bool(true)]))]
+fn compatible_oneof_domains_are_accepted(#[case] ty: &str, #[case] domain: ForallDomain) {
+    assert_eq!(check_domain(&domain, ty), Ok(()));
+    assert!(domain.constraints("x").is_empty());
+}
+
+#[rstest]
+#[case::empty("Op", oneof(&[]), ForallDomainField::OneOf, "oneof must list at least one value")]
+#[case::duplicate(
+    "Op",
+    oneof(&[path("Deposit"), path("Withdraw"), path("Deposit")]),
+    ForallDomainField::Choice(2),
+    "oneof value 3: 'Deposit' is listed more than once"
+)]
+#[case::out_of_range(
+    "u8",
+    oneof(&[ForallChoice::Number(NumericBound::Integer(256))]),
+    ForallDomainField::Choice(0),
+    "oneof value 1: 256 is out of range for u8"
+)]
+#[case::number_for_enum(
+    "Op",
+    oneof(&[path("Deposit"), ForallChoice::Number(NumericBound::Integer(1))]),
+    ForallDomainField::Choice(1),
+    "oneof value 2: 1 is a number, but type 'Op' is not numeric"
+)]
+#[case::bool_for_integer(
+    "u8",
+    oneof(&[ForallChoice::Bool(true)]),
+    ForallDomainField::Choice(0),
+    "oneof value 1: true is a boolean, but the type is 'u8'"
+)]
+#[case::invalid_path(
+    "Op",
+    oneof(&[path("not a path")]),
+    ForallDomainField::Choice(0),
+    "oneof value 1: 'not a path' is not a valid Rust path"
+)]
+fn incompatible_oneof_domains_are_rejected(
+    #[case] ty: &str,
+    #[case] domain: ForallDomain,
+    #[case] field: ForallDomainField,
+    #[case] expected_fragment: &str,
+) {
+    let Err(DomainMismatch {
+        field: actual_field,
+        reason,
+    }) = check_domain(&domain, ty)
+    else {
+        panic!("oneof domain should be rejected for {ty}");
+    };
+    assert_eq!(actual_field, field);
+    assert!(
+        reason.contains(expected_fragment),
+        "expected '{expected_fragment}' in '{reason}'"
+    );
+}
+
+#[rstest]
+#[case::variant("Deposit", "crate::Op", "<crate::Op>::Deposit")]
+#[case::qualified("crate::Op::Deposit", "crate::Op", "crate::Op::Deposit")]
+#[case::float("0.5", "f64", "0.5")]
+fn oneof_choices_lower_to_expressions(
+    #[case] choice: &str,
+    #[case] ty: &str,
+    #[case] expected: &str,
+) {
+    let parsed = choice.parse::<f64>().map_or_else(
+        |_| path(choice),
+        |value| ForallChoice::Number(NumericBound::Float(value)),
+    );
+    assert_eq!(parsed.to_expr(ty), expected);
+}
+
+pub(super) fn theorem_with_forall(forall: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: Bounded\n",
+            "About: bounded inputs\n",
+            "Types:\n  Amount: u64\n",
+            "Forall:\n{forall}",
+            "Prove:\n  - assert: 'true'\n    because: trivially true\n",
+            "Evidence:\n  kani:\n    unwind: 1\n    expect: SUCCESS\n",
+            "Witness:\n  - cover: 'true'\n    because: always reachable\n",
+        ),
+        forall = forall,
+    )
+}
+
+pub(super) fn var(name: &str) -> ForallVar {
+    ForallVar::new(name.to_owned()).expect("valid Forall variable")
+}
+
+#[test]
+fn structured_entries_load_type_and_domain() {
+    let yaml = theorem_with_forall(concat!(
+        "  amount: { type: Amount, min: 1, max: 100 }\n",
+        "  ratio: { type: f64, max: 0.5 }\n",
+        "  plain: { type: bool }\n",
+        "  flag: bool\n",
+    ));
+    let docs = load_theorem_docs(&yaml).expect("structured Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.values().map(Symbol::as_str).collect::<Vec<_>>(),
+        ["u64", "f64", "bool", "bool"]
+    );
+    assert_eq!(doc.forall_domains.len(), 2);
+    assert_eq!(
+        doc.forall_domains.get(&var("amount")),
+        Some(&range(
+            Some(NumericBound::Integer(1)),
+            Some(NumericBound::Integer(100))
+        ))
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("ratio")),
+        Some(&range(None, Some(NumericBound::Float(0.5))))
+    );
+}
+
+#[rstest]
+#[case::i128_limits(
+    "{ type: i128, min: -170141183460469231731687303715884105728, \
+     max: 170141183460469231731687303715884105727 }",
+    range(
+        Some(NumericBound::Integer(i128::MIN)),
+        Some(NumericBound::Integer(i128::MAX))
+    )
+)]
+#[case::u128_limit(
+    "{ type: u128, max: 340282366920938463463374607431768211455 }",
+    range(None, Some(NumericBound::Unsigned(u128::MAX)))
+)]
+#[case::radix_and_separators(
+    "{ type: u32, min: 0x_ff, max: 1_000_000 }",
+    range(
+        Some(NumericBound::Integer(255)),
+        Some(NumericBound::Integer(1_000_000))
+    )
+)]
+#[case::float_forms("{ type: f64, min: -.5, max: 1.5e3 }",
+    range(Some(NumericBound::Float(-0.5)), Some(NumericBound::Float(1500.0)))
+)]
+fn bounds_load_exactly_as_written(#[case] entry: &str, #[case] expected: ForallDomain) {
+    let yaml = theorem_with_forall(&format!("  x: {entry}\n"));
+    let docs = load_theorem_docs(&yaml).expect("bounded Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(doc.forall_domains.get(&var("x")), Some(&expected));
+}
+
+#[test]
+fn oneof_entries_load_choices_in_order() {
+    let yaml = theorem_with_forall("  op: { type: crate::Op, oneof: [Deposit, Withdraw] }\n");
+    let docs = load_theorem_docs(&yaml).expect("oneof Forall should load");
+    let doc = docs.first().expect("one document");
+    assert_eq!(
+        doc.forall_domains.get(&var("op")),
+        Some(&oneof(&[path("Deposit"), path("Withdraw")]))
+    );
+}
+
+#[rstest]
+#[case::inverted(
+    "  amount:\n    type: u64\n    min: 9\n    max: 3\n",
+    "Forall entry 'amount': min 9 must not exceed max 3",
+    9
+)]
+#[case::non_numeric(
+    "  name:\n    type: String\n    min: 0\n",
+    "Forall entry 'name': type 'String' does not support min/max bounds",
+    7
+)]
+#[case::aliased_overflow(
+    "  flag: bool\n  amount:\n    type: Amount\n    min: -1\n",
+    "Forall entry 'amount': min -1 is out of range for u64",
+    9
+)]
+#[case::duplicate_choice(
+    "  op:\n    type: u8\n    oneof:\n      - 1\n      - 2\n      - 1\n",
+    "Forall entry 'op': oneof value 3: 1 is listed more than once",
+    11
+)]
+#[case::mismatched_choice(
+    "  op:\n    type: crate::Op\n    oneof: [Deposit, Withdraw, 7]\n",
+    "Forall entry 'op': oneof value 3: 7 is a number, but type 'crate::Op' is not numeric",
+    8
+)]
+#[case::empty_oneof(
+    "  op:\n    type: u8\n    oneof: []\n",
+    "Forall entry 'op': oneof must list at least one value",
+    8
+)]
+#[case::f32_overflow(
+    "  ratio:\n    type: f32\n    min: 1e40\n",
+    "Forall entry 'ratio': min 1e40 is out of range for f32",
+    8
+)]
+fn invalid_domains_point_at_the_offending_field(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("domain should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::unknown_key("  x: { type: u8, step: 2 }\n", "unknown field `step`")]
+#[case::non_numeric_bound("  x: { type: u8, min: low }\n", "integer or floating-point number")]
+#[case::overflowing_bound(
+    "  x: { type: i128, min: -170141183460469231731687303715884105729 }\n",
+    "integer -170141183460469231731687303715884105729 is out of range for a Forall bound"
+)]
+#[case::oneof_with_bounds(
+    "  x: { type: u8, min: 1, oneof: [1, 2] }\n",
+    "`oneof` cannot be combined with `min` or `max`"
+)]
+#[case::non_scalar_choice("  x: { type: u8, oneof: [[1]] }\n", "number, boolean, or Rust path")]
+fn malformed_structured_entries_are_parse_errors(
+    #[case] forall: &str,
+    #[case] expected_fragment: &str,
+) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+}
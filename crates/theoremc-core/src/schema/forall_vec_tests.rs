@@ -0,0 +1,128 @@
+//! Unit tests for length-bounded `vec` entries in `Forall`.
+
+use rstest::rstest;
+
+use super::check::DomainMismatch;
+use super::tests::{theorem_with_forall, var};
+use super::{ForallDomain, ForallDomainField, check_domain, check_unwind};
+use crate::schema::{Symbol, load_theorem_docs};
+
+#[rstest]
+#[case::empty_allowed(ForallDomain::Length { min: 0, max: 8 }, &[][..])]
+#[case::non_empty(ForallDomain::Length { min: 2, max: 8 }, &["xs.len() >= 2"][..])]
+fn length_domains_assume_only_a_minimum(#[case] domain: ForallDomain, #[case] expected: &[&str]) {
+    assert_eq!(domain.constraints("xs"), expected);
+    assert_eq!(domain.max_len(), Some(8));
+    assert_eq!(domain.choice_exprs("Vec<u32>"), None);
+}
+
+#[rstest]
+#[case::exact(ForallDomain::Length { min: 3, max: 3 }, 4, Ok(()))]
+#[case::inverted(
+    ForallDomain::Length { min: 4, max: 3 },
+    10,
+    Err("len min 4 must not exceed len max 3")
+)]
+#[case::unwind_too_small(
+    ForallDomain::Length { min: 0, max: 8 },
+    8,
+    Err("len max 8 needs a Kani unwind of at least 9, but unwind is 8")
+)]
+#[case::unwind_sufficient(ForallDomain::Length { min: 0, max: 8 }, 9, Ok(()))]
+fn length_domains_respect_order_and_unwind(
+    #[case] domain: ForallDomain,
+    #[case] unwind: u32,
+    #[case] expected: Result<(), &str>,
+) {
+    let actual = check_domain(&domain, "Vec<u32>").and_then(|()| check_unwind(&domain, unwind));
+    assert_eq!(
+        actual,
+        expected.map_err(|reason| DomainMismatch {
+            field: ForallDomainField::LenMax,
+            reason: reason.to_owned(),
+        })
+    );
+}
+
+fn with_unwind(forall: &str, unwind: u32) -> String {
+    theorem_with_forall(forall).replace("unwind: 1", &format!("unwind: {unwind}"))
+}
+
+#[test]
+fn vec_entries_load_as_vec_types() {
+    let yaml = with_unwind(
+        concat!(
+            "  xs: { vec: u32, len: { min: 1, max: 8 } }\n",
+            "  amounts: { vec: Amount, len: { max: 2 } }\n",
+        ),
+        9,
+    );
+    let docs = load_theorem_docs(&yaml).expect("vec Forall should load");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.forall.values().map(Symbol::as_str).collect::<Vec<_>>(),
+        ["Vec<u32>", "Vec < u64 >"]
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("xs")),
+        Some(&ForallDomain::Length { min: 1, max: 8 })
+    );
+    assert_eq!(
+        doc.forall_domains.get(&var("amounts")),
+        Some(&ForallDomain::Length { min: 0, max: 2 })
+    );
+}
+
+#[rstest]
+#[case::unwind_too_small(
+    "  xs:\n    vec: u8\n    len: { max: 4 }\n",
+    1,
+    "Forall entry 'xs': len max 4 needs a Kani unwind of at least 5, but unwind is 1",
+    8
+)]
+#[case::inverted(
+    "  xs:\n    vec: u8\n    len:\n      min: 3\n      max: 2\n",
+    9,
+    "Forall entry 'xs': len min 3 must not exceed len max 2",
+    10
+)]
+fn invalid_vec_entries_point_at_len_max(
+    #[case] forall: &str,
+    #[case] unwind: u32,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let error = load_theorem_docs(&with_unwind(forall, unwind)).expect_err("vec should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::missing_len("  xs: { vec: u8 }\n", "`vec` requires `len`")]
+#[case::missing_max("  xs: { vec: u8, len: { min: 1 } }\n", "missing field `max`")]
+#[case::negative_max("  xs: { vec: u8, len: { max: -1 } }\n", "max")]
+#[case::len_with_type(
+    "  xs: { type: u8, len: { max: 1 } }\n",
+    "`len` requires `vec` instead of `type`"
+)]
+#[case::vec_with_oneof(
+    "  xs: { vec: u8, len: { max: 1 }, oneof: [1] }\n",
+    "`vec` cannot be combined with `min`, `max`, `oneof`, or `fields`"
+)]
+#[case::vec_and_type(
+    "  xs: { vec: u8, type: u8, len: { max: 1 } }\n",
+    "only one of `type`, `struct`, and `vec` may be set"
+)]
+fn malformed_vec_entries_are_parse_errors(#[case] forall: &str, #[case] expected_fragment: &str) {
+    let error = load_theorem_docs(&theorem_with_forall(forall)).expect_err("entry should fail");
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected '{expected_fragment}' in '{error}'"
+    );
+}
@@ -0,0 +1,295 @@
+//! `Include` directive resolution for shared theorem fragments.
+//!
+//! A theorem document may list other YAML files under `Include`. Each fragment
+//! may declare `Forall`, `Assume`, and `Let` sections, plus further `Include`
+//! entries of its own. Fragments are merged depth-first in declaration order,
+//! ahead of the theorem's own entries, before conversion and semantic
+//! validation. Each fragment is merged at most once per theorem, include
+//! cycles are rejected with the files that form them, and merged entries keep
+//! their origin so diagnostics can point back at the fragment.
+
+use std::collections::BTreeSet;
+use std::io;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_saphyr::{Location, Spanned};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic, first_line};
+use super::error::SchemaError;
+use super::newtypes::ForallVar;
+use super::raw::RawTheoremDoc;
+use super::raw_action::RawLetBinding;
+use super::raw_forall::RawForallEntry;
+use super::raw_obligation::RawAssumption;
+use super::source_id::SourceId;
+use super::validation_reason::ValidationReasonKind;
+use super::yaml_anchor::{ReuseFailure, parse_options};
+use crate::cycle::cycle_path;
+
+#[path = "include_merge.rs"]
+mod merge;
+
+use merge::FragmentMerge;
+
+/// Reads the shared fragments named by `Include` directives.
+///
+/// Include paths are passed through exactly as written in the theorem file;
+/// implementations decide how they map onto storage.
+/// [`load_theorem_file_from_manifest_dir`](crate::load_theorem_file_from_manifest_dir)
+/// resolves them relative to the consuming crate's manifest directory.
+///
+/// # Examples
+///
+///     use std::collections::HashMap;
+///     use std::io;
+///
+///     use theoremc_core::schema::IncludeResolver;
+///
+///     struct InMemory(HashMap<String, String>);
+///
+///     impl IncludeResolver for InMemory {
+///         fn read_include(&self, path: &str) -> io::Result<String> {
+///             self.0
+///                 .get(path)
+///                 .cloned()
+///                 .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+///         }
+///     }
+pub trait IncludeResolver {
+    /// Returns the UTF-8 contents of the fragment at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an IO error when the fragment cannot be located or read.
+    fn read_include(&self, path: &str) -> io::Result<String>;
+}
+
+/// Resolver used by string-based loaders, which have nowhere to read
+/// fragments from.
+pub(crate) struct NoIncludes;
+
+impl IncludeResolver for NoIncludes {
+    fn read_include(&self, _path: &str) -> io::Result<String> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "this loader has no include resolver",
+        ))
+    }
+}
+
+/// Raw shared fragment loaded through an `Include` directive.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawIncludeFragment {
+    #[serde(rename = "Include", alias = "include", default)]
+    include: Vec<Spanned<String>>,
+    #[serde(rename = "Forall", alias = "forall", default)]
+    forall: IndexMap<ForallVar, RawForallEntry>,
+    #[serde(rename = "Assume", alias = "assume", default)]
+    assume: Vec<RawAssumption>,
+    #[serde(rename = "Let", alias = "let", default)]
+    let_bindings: IndexMap<String, RawLetBinding>,
+}
+
+/// Origin of an entry merged from an included fragment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IncludeOrigin {
+    /// Fragment path the entry was declared in.
+    pub(crate) path: SourceId,
+    /// Location of the theorem's own `Include` entry that pulled it in.
+    pub(crate) include_location: Location,
+}
+
+/// Origins of the entries merged into one theorem document.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct IncludeProvenance {
+    fragments: Vec<String>,
+    forall: Vec<IncludeOrigin>,
+    assume: Vec<IncludeOrigin>,
+    let_bindings: IndexMap<String, IncludeOrigin>,
+}
+
+impl IncludeProvenance {
+    /// Consumes the provenance and returns every merged fragment path in
+    /// merge order.
+    pub(crate) fn into_fragment_paths(self) -> Vec<String> {
+        self.fragments
+    }
+
+    /// Returns the fragment origin of the entry a validation reason names.
+    pub(crate) fn origin_for_reason(&self, reason: ValidationReasonKind) -> Option<&IncludeOrigin> {
+        match reason {
+            ValidationReasonKind::ForallDomain { index, .. } => self.forall.get(index),
+            ValidationReasonKind::Assume { index, .. } => self.assume.get(index),
+            _ => None,
+        }
+    }
+
+    /// Returns the fragment origin of an included `Let` binding.
+    pub(crate) fn let_binding_origin(&self, name: &str) -> Option<&IncludeOrigin> {
+        self.let_bindings.get(name)
+    }
+}
+
+/// Resolves every fragment reachable from `raw_doc`'s `Include` entries and
+/// merges them into `raw_doc`.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::IncludeFailed`] when a fragment cannot be read or
+/// parsed, when includes form a cycle, or when merged `Forall` or `Let`
+/// sections declare the same name twice.
+pub(crate) fn resolve_includes(
+    source: &SourceId,
+    raw_doc: &mut RawTheoremDoc,
+    resolver: &dyn IncludeResolver,
+) -> Result<IncludeProvenance, SchemaError> {
+    if raw_doc.include.is_empty() {
+        return Ok(IncludeProvenance::default());
+    }
+    let mut walk = IncludeWalk {
+        resolver,
+        theorem: raw_doc.theorem.value.to_string(),
+        chain: vec![source.as_str().to_owned()],
+        visited: BTreeSet::new(),
+        loaded: Vec::new(),
+    };
+    for entry in &raw_doc.include {
+        walk.visit(source, entry, entry.referenced)?;
+    }
+    let IncludeWalk {
+        theorem, loaded, ..
+    } = walk;
+    let merge = FragmentMerge {
+        source,
+        theorem: &theorem,
+        theorem_location: raw_doc.theorem_location(),
+    };
+    merge.apply(raw_doc, loaded)
+}
+
+/// A fragment read during the include walk, in merge order.
+struct LoadedFragment {
+    origin: IncludeOrigin,
+    fragment: RawIncludeFragment,
+}
+
+/// Depth-first include traversal state for one theorem document.
+struct IncludeWalk<'a> {
+    resolver: &'a dyn IncludeResolver,
+    theorem: String,
+    chain: Vec<String>,
+    visited: BTreeSet<String>,
+    loaded: Vec<LoadedFragment>,
+}
+
+impl IncludeWalk<'_> {
+    fn visit(
+        &mut self,
+        parent: &SourceId,
+        entry: &Spanned<String>,
+        root_location: Location,
+    ) -> Result<(), SchemaError> {
+        let path = entry.value.trim();
+        if path.is_empty() {
+            return Err(self.entry_failure(parent, entry, "Include entry must be non-empty"));
+        }
+        if let Some(cycle) = cycle_path(self.chain.iter().map(String::as_str), path) {
+            let reason = format!("include cycle detected: {cycle}");
+            return Err(self.entry_failure(parent, entry, &reason).into_cycle());
+        }
+        if !self.visited.insert(path.to_owned()) {
+            return Ok(());
+        }
+
+        let text = self.resolver.read_include(path).map_err(|error| {
+            self.entry_failure(parent, entry, &format!("failed to read include: {error}"))
+        })?;
+        let fragment: RawIncludeFragment =
+            serde_saphyr::from_str_with_options(&text, parse_options()).map_err(|error| {
+                ReuseFailure::of(&error, &text).map_or_else(
+                    || self.parse_failure(parent, entry, &error),
+                    |reuse| self.reuse_failure(entry, &reuse),
+                )
+            })?;
+        let fragment_source = SourceId::new(path);
+
+        self.chain.push(path.to_owned());
+        for nested in &fragment.include {
+            self.visit(&fragment_source, nested, root_location)?;
+        }
+        self.chain.pop();
+
+        self.loaded.push(LoadedFragment {
+            origin: IncludeOrigin {
+                path: fragment_source,
+                include_location: root_location,
+            },
+            fragment,
+        });
+        Ok(())
+    }
+
+    fn entry_failure(
+        &self,
+        parent: &SourceId,
+        entry: &Spanned<String>,
+        reason: &str,
+    ) -> SchemaError {
+        let diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ValidationFailure,
+            parent,
+            reason.to_owned(),
+            entry.referenced,
+        );
+        SchemaError::IncludeFailed {
+            theorem: self.theorem.clone(),
+            include: entry.value.trim().to_owned(),
+            reason: reason.to_owned(),
+            diagnostic: Some(Box::new(diagnostic)),
+        }
+    }
+
+    /// Reports a fragment parse failure at the fragment's own location when
+    /// the parser supplies one, and at the including entry otherwise.
+    fn parse_failure(
+        &self,
+        parent: &SourceId,
+        entry: &Spanned<String>,
+        error: &serde_saphyr::Error,
+    ) -> SchemaError {
+        let fragment = SourceId::new(entry.value.trim());
+        let reason = error.to_string();
+        let (diagnostic_source, location) = error
+            .location()
+            .map_or((parent, entry.referenced), |location| (&fragment, location));
+        let diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ParseFailure,
+            diagnostic_source,
+            first_line(&reason),
+            location,
+        );
+        SchemaError::IncludeFailed {
+            theorem: self.theorem.clone(),
+            include: fragment.as_str().to_owned(),
+            reason,
+            diagnostic: Some(Box::new(diagnostic)),
+        }
+    }
+
+    fn reuse_failure(&self, entry: &Spanned<String>, reuse: &ReuseFailure) -> SchemaError {
+        let fragment = SourceId::new(entry.value.trim());
+        let diagnostic = reuse.diagnostic(&fragment);
+        SchemaError::IncludeFailed {
+            theorem: self.theorem.clone(),
+            include: fragment.as_str().to_owned(),
+            reason: reuse.message(),
+            diagnostic: Some(Box::new(diagnostic)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "include_tests.rs"]
+mod tests;
@@ -0,0 +1,149 @@
+//! Merging of resolved `Include` fragments into a theorem document.
+//!
+//! Fragments merge in include-walk order ahead of the theorem's own entries.
+//! `Forall` and `Let` names must be unique across every merged source, while
+//! `Assume` entries simply concatenate.
+
+use std::hash::Hash;
+
+use indexmap::IndexMap;
+use serde_saphyr::Location;
+
+use super::{IncludeOrigin, IncludeProvenance, LoadedFragment};
+use crate::schema::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use crate::schema::error::SchemaError;
+use crate::schema::raw::RawTheoremDoc;
+use crate::schema::source_id::SourceId;
+
+/// Merges loaded fragments into a theorem document, detecting name clashes.
+pub(super) struct FragmentMerge<'a> {
+    pub(super) source: &'a SourceId,
+    pub(super) theorem: &'a str,
+    pub(super) theorem_location: Location,
+}
+
+impl FragmentMerge<'_> {
+    pub(super) fn apply(
+        &self,
+        raw_doc: &mut RawTheoremDoc,
+        loaded: Vec<LoadedFragment>,
+    ) -> Result<IncludeProvenance, SchemaError> {
+        let mut provenance = IncludeProvenance::default();
+        let mut forall = SectionMerge::new("Forall");
+        let mut let_bindings = SectionMerge::new("Let");
+        let mut assume = Vec::new();
+
+        for LoadedFragment { origin, fragment } in loaded {
+            for (name, ty) in fragment.forall {
+                provenance.forall.push(origin.clone());
+                forall
+                    .insert(name, ty, Some(&origin))
+                    .map_err(|clash| self.conflict(&clash))?;
+            }
+            for (name, binding) in fragment.let_bindings {
+                provenance.let_bindings.insert(name.clone(), origin.clone());
+                let_bindings
+                    .insert(name, binding, Some(&origin))
+                    .map_err(|clash| self.conflict(&clash))?;
+            }
+            provenance
+                .assume
+                .extend(fragment.assume.iter().map(|_| origin.clone()));
+            assume.extend(fragment.assume);
+            provenance.fragments.push(origin.path.as_str().to_owned());
+        }
+
+        for (name, ty) in std::mem::take(&mut raw_doc.forall) {
+            forall
+                .insert(name, ty, None)
+                .map_err(|clash| self.conflict(&clash))?;
+        }
+        for (name, binding) in std::mem::take(&mut raw_doc.let_bindings) {
+            let_bindings
+                .insert(name, binding, None)
+                .map_err(|clash| self.conflict(&clash))?;
+        }
+        assume.append(&mut raw_doc.assume);
+
+        raw_doc.forall = forall.entries;
+        raw_doc.let_bindings = let_bindings.entries;
+        raw_doc.assume = assume;
+        Ok(provenance)
+    }
+
+    /// Names the source that declared an entry; `None` is the theorem itself.
+    fn owner<'o>(&'o self, origin: Option<&'o IncludeOrigin>) -> &'o str {
+        origin.map_or(self.source.as_str(), |included| included.path.as_str())
+    }
+
+    fn conflict(&self, conflict: &MergeConflict) -> SchemaError {
+        let reason = format!(
+            "{} entry '{}' is declared by both '{}' and '{}'",
+            conflict.section,
+            conflict.name,
+            self.owner(conflict.first.as_ref()),
+            self.owner(conflict.second.as_ref()),
+        );
+        // The theorem's own entries merge last, so blame the include entry
+        // that introduced the clash.
+        let blamed = conflict.second.as_ref().or(conflict.first.as_ref());
+        let diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ValidationFailure,
+            self.source,
+            reason.clone(),
+            blamed.map_or(self.theorem_location, |origin| origin.include_location),
+        );
+        SchemaError::IncludeFailed {
+            theorem: self.theorem.to_owned(),
+            include: self.owner(blamed).to_owned(),
+            reason,
+            diagnostic: Some(Box::new(diagnostic)),
+        }
+    }
+}
+
+/// Two sources that declare the same name in one merged section.
+struct MergeConflict {
+    section: &'static str,
+    name: String,
+    first: Option<IncludeOrigin>,
+    second: Option<IncludeOrigin>,
+}
+
+/// Insertion-ordered section merge that remembers which source declared
+/// each name. `None` owners are the theorem document itself.
+struct SectionMerge<K, V> {
+    section: &'static str,
+    entries: IndexMap<K, V>,
+    owners: IndexMap<String, Option<IncludeOrigin>>,
+}
+
+impl<K: Hash + Eq + AsRef<str>, V> SectionMerge<K, V> {
+    fn new(section: &'static str) -> Self {
+        Self {
+            section,
+            entries: IndexMap::new(),
+            owners: IndexMap::new(),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        key: K,
+        value: V,
+        owner: Option<&IncludeOrigin>,
+    ) -> Result<(), Box<MergeConflict>> {
+        let name = key.as_ref().to_owned();
+        if let Some(first) = self.owners.get(&name) {
+            return Err(Box::new(MergeConflict {
+                section: self.section,
+                name,
+                first: first.clone(),
+                second: owner.cloned(),
+            }));
+        }
+        self.owners.insert(name, owner.cloned());
+        self.entries.insert(key, value);
+        Ok(())
+    }
+}
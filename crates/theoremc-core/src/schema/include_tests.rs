@@ -0,0 +1,300 @@
+//! Unit tests for `Include` fragment resolution and merging.
+
+use std::collections::HashMap;
+use std::io;
+
+use rstest::rstest;
+
+use super::IncludeResolver;
+use crate::schema::{
+    SchemaDiagnosticCode, SchemaError, SourceId, TheoremDoc, load_theorem_docs,
+    load_theorem_docs_with_includes,
+};
+
+const THEOREM_SOURCE: &str = "theorems/account.theorem";
+
+struct InMemoryIncludes(HashMap<&'static str, &'static str>);
+
+impl InMemoryIncludes {
+    fn new(fragments: &[(&'static str, &'static str)]) -> Self {
+        Self(fragments.iter().copied().collect())
+    }
+}
+
+impl IncludeResolver for InMemoryIncludes {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        self.0
+            .get(path)
+            .map(|text| (*text).to_owned())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "not found"))
+    }
+}
+
+fn theorem_with_includes(includes: &str, body: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: Account\n",
+            "About: uses shared fragments\n",
+            "Include:\n{includes}",
+            "{body}",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "Witness:\n",
+            "  - cover: 'true'\n",
+            "    because: always reachable\n",
+        ),
+        includes = includes,
+        body = body,
+    )
+}
+
+fn load_with(
+    yaml: &str,
+    fragments: &[(&'static str, &'static str)],
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_with_includes(
+        &SourceId::new(THEOREM_SOURCE),
+        yaml,
+        &InMemoryIncludes::new(fragments),
+    )
+}
+
+fn load_error(yaml: &str, fragments: &[(&'static str, &'static str)]) -> SchemaError {
+    load_with(yaml, fragments).expect_err("includes should be rejected")
+}
+
+const LIMITS: (&str, &str) = (
+    "shared/limits.yaml",
+    "Forall:\n  limit: u64\nAssume:\n  - expr: 'limit > 0'\n    because: limits are positive\n",
+);
+
+const AMOUNTS: (&str, &str) = (
+    "shared/amounts.yaml",
+    concat!(
+        "Include:\n  - shared/limits.yaml\n",
+        "Forall:\n  amount: u64\n",
+        "Assume:\n  - expr: 'amount <= limit'\n    because: amounts respect the limit\n",
+    ),
+);
+
+#[test]
+fn fragments_merge_depth_first_ahead_of_local_entries() {
+    let yaml = theorem_with_includes(
+        "  - shared/amounts.yaml\n",
+        "Forall:\n  fee: u64\nAssume:\n  - expr: 'fee < amount'\n    because: fees are small\n",
+    );
+    let docs = load_with(&yaml, &[LIMITS, AMOUNTS]).expect("includes should merge");
+    let doc = docs.first().expect("one theorem document");
+
+    assert_eq!(doc.include, ["shared/limits.yaml", "shared/amounts.yaml"]);
+    assert_eq!(
+        doc.forall.keys().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        ["limit", "amount", "fee"]
+    );
+    assert_eq!(
+        doc.assume
+            .iter()
+            .map(|assumption| assumption.expr.as_str())
+            .collect::<Vec<_>>(),
+        ["limit > 0", "amount <= limit", "fee < amount"]
+    );
+}
+
+#[test]
+fn shared_fragments_are_merged_once() {
+    let yaml = theorem_with_includes("  - shared/amounts.yaml\n  - shared/limits.yaml\n", "");
+    let docs = load_with(&yaml, &[LIMITS, AMOUNTS]).expect("diamond includes should merge");
+    let doc = docs.first().expect("one theorem document");
+
+    assert_eq!(doc.include, ["shared/limits.yaml", "shared/amounts.yaml"]);
+    assert_eq!(doc.assume.len(), 2);
+}
+
+#[test]
+fn included_let_bindings_are_merged() {
+    let yaml = theorem_with_includes(
+        "  - shared/setup.yaml\n",
+        "Actions:\n  account.open:\n    params: {}\n    returns: u64\n",
+    );
+    let setup = (
+        "shared/setup.yaml",
+        "Let:\n  account:\n    call:\n      action: account.open\n      args: {}\n",
+    );
+    let docs = load_with(&yaml, &[setup]).expect("let fragment should merge");
+    let doc = docs.first().expect("one theorem document");
+
+    assert!(doc.let_bindings.contains_key("account"));
+}
+
+#[rstest]
+#[case::self_cycle(
+    &[("shared/a.yaml", "Include:\n  - shared/a.yaml\n")],
+    "include cycle detected: shared/a.yaml -> shared/a.yaml"
+)]
+#[case::indirect_cycle(
+    &[
+        ("shared/a.yaml", "Include:\n  - shared/b.yaml\n"),
+        ("shared/b.yaml", "Include:\n  - theorems/account.theorem\n"),
+    ],
+    "include cycle detected: theorems/account.theorem -> shared/a.yaml -> shared/b.yaml -> theorems/account.theorem"
+)]
+#[case::missing(&[], "include 'shared/a.yaml' failed for theorem 'Account': failed to read include: not found")]
+#[case::duplicate_forall(
+    &[
+        ("shared/a.yaml", "Include:\n  - shared/b.yaml\nForall:\n  x: u8\n"),
+        ("shared/b.yaml", "Forall:\n  x: u16\n"),
+    ],
+    "Forall entry 'x' is declared by both 'shared/b.yaml' and 'shared/a.yaml'"
+)]
+fn invalid_includes_are_rejected(
+    #[case] fragments: &[(&'static str, &'static str)],
+    #[case] expected: &str,
+) {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let message = load_error(&yaml, fragments).to_string();
+    assert!(message.contains(expected), "unexpected error: {message}");
+}
+
+#[test]
+fn include_cycles_name_only_the_fragments_in_them() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let fragments = [
+        ("shared/a.yaml", "Include:\n  - shared/b.yaml\n"),
+        ("shared/b.yaml", "Include:\n  - shared/c.yaml\n"),
+        ("shared/c.yaml", "Include:\n  - shared/b.yaml\n"),
+    ];
+    let error = load_error(&yaml, &fragments);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(
+            &error,
+            SchemaError::IncludeFailed { reason, .. }
+                if reason == "include cycle detected: shared/b.yaml -> shared/c.yaml -> shared/b.yaml"
+        ),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.code, SchemaDiagnosticCode::Cycle);
+    assert_eq!(diagnostic.location.source, "shared/c.yaml");
+    assert_eq!(diagnostic.location.line, 2);
+}
+
+#[test]
+fn local_entries_may_not_redeclare_included_names() {
+    let yaml = theorem_with_includes("  - shared/limits.yaml\n", "Forall:\n  limit: u32\n");
+    let error = load_error(&yaml, &[LIMITS]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        diagnostic.message.contains(
+            "Forall entry 'limit' is declared by both 'shared/limits.yaml' and 'theorems/account.theorem'"
+        ),
+        "unexpected diagnostic: {}",
+        diagnostic.message
+    );
+    assert_eq!(diagnostic.location.source, THEOREM_SOURCE);
+    assert_eq!(diagnostic.location.line, 4);
+}
+
+#[test]
+fn fragment_parse_failures_point_into_the_fragment() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let error = load_error(&yaml, &[("shared/a.yaml", "Forall:\n  x: u8\nProve: []\n")]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(matches!(error, SchemaError::IncludeFailed { .. }));
+    assert_eq!(diagnostic.location.source, "shared/a.yaml");
+}
+
+#[test]
+fn fragment_anchors_are_rejected_at_the_anchor() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let fragment = ("shared/a.yaml", "Forall:\n  x: &width u8\n  y: *width\n");
+    let error = load_error(&yaml, &[fragment]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(error.to_string().contains("YAML anchors are not supported"));
+    assert_eq!(diagnostic.location.source, "shared/a.yaml");
+    assert_eq!(
+        (diagnostic.location.line, diagnostic.location.column),
+        (2, 6)
+    );
+}
+
+#[test]
+fn included_assumption_failures_name_the_fragment() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let fragment = (
+        "shared/a.yaml",
+        "Forall:\n  x: u8\nAssume:\n  - expr: 'x > 0'\n    because: ''\n",
+    );
+    let error = load_error(&yaml, &[fragment]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(
+            "Assume constraint 1: because must be non-empty after trimming (included from 'shared/a.yaml')"
+        ),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, "shared/a.yaml");
+    assert_eq!(diagnostic.location.line, 5);
+}
+
+#[test]
+fn included_forall_domain_failures_name_the_fragment() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let fragment = (
+        "shared/a.yaml",
+        "Forall:\n  x:\n    type: u8\n    min: 300\n",
+    );
+    let error = load_error(&yaml, &[fragment]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(
+            "Forall entry 'x': min 300 is out of range for u8 (included from 'shared/a.yaml')"
+        ),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, "shared/a.yaml");
+    assert_eq!(diagnostic.location.line, 4);
+}
+
+#[test]
+fn included_let_decode_failures_point_at_the_include_entry() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let fragment = (
+        "shared/a.yaml",
+        "Let:\n  x:\n    call:\n      action: account.open\n      args:\n        target: { ref: 'not valid' }\n",
+    );
+    let error = load_error(&yaml, &[fragment]);
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        diagnostic
+            .message
+            .contains("(included from 'shared/a.yaml')"),
+        "unexpected diagnostic: {}",
+        diagnostic.message
+    );
+    assert_eq!(diagnostic.location.source, THEOREM_SOURCE);
+    assert_eq!(diagnostic.location.line, 4);
+}
+
+#[test]
+fn loaders_without_a_resolver_reject_includes() {
+    let yaml = theorem_with_includes("  - shared/a.yaml\n", "");
+    let message = load_theorem_docs(&yaml)
+        .expect_err("inline loading has no include resolver")
+        .to_string();
+    assert!(
+        message.contains("this loader has no include resolver"),
+        "unexpected error: {message}"
+    );
+}
@@ -0,0 +1,326 @@
+//! JSON Schema export for `.theorem` documents.
+//!
+//! [`export_json_schema`] describes every document kind a `.theorem` source
+//! may hold, so editors running a YAML language server can offer completion
+//! and flag structural mistakes while a theorem is written. Section keys are
+//! taken from the loader's table of canonical keys and lowercase aliases, and
+//! enumerated values from the schema types, so the export follows the loader
+//! as sections and variants are added.
+//!
+//! The schema checks shape only. Identifier rules, expression syntax, name
+//! resolution, and the other semantic checks of `TFS-1` still run when a
+//! document is loaded.
+
+use serde_json::{Map, Value, json};
+
+use super::section_key::SECTION_KEYS;
+use super::types::{KaniExpectation, KaniSolver, LATEST_SCHEMA_VERSION};
+
+#[path = "json_schema_evidence.rs"]
+mod evidence;
+#[path = "json_schema_sections.rs"]
+mod sections;
+
+use evidence::{evidence, kani_evidence};
+use sections::{
+    action_call, action_signature, args, assertion, assumption, described_expression, forall_entry,
+    let_binding, step, trace_link,
+};
+
+/// JSON Schema dialect of the exported schema.
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Every `Evidence.kani.expect` value.
+const EXPECTATIONS: [KaniExpectation; 4] = [
+    KaniExpectation::Success,
+    KaniExpectation::Failure,
+    KaniExpectation::Unreachable,
+    KaniExpectation::Undetermined,
+];
+
+/// Every `Evidence.kani.solver` value.
+const SOLVERS: [KaniSolver; 3] = [KaniSolver::Cadical, KaniSolver::Kissat, KaniSolver::Minisat];
+
+/// Theorem sections a document must declare.
+const REQUIRED_SECTIONS: [&str; 3] = ["Theorem", "About", "Prove"];
+
+/// One top-level section of a document kind.
+struct Section {
+    canonical: &'static str,
+    alias: &'static str,
+    required: bool,
+    schema: Value,
+}
+
+impl Section {
+    const fn new(canonical: &'static str, alias: &'static str, schema: Value) -> Self {
+        Self {
+            canonical,
+            alias,
+            required: false,
+            schema,
+        }
+    }
+
+    const fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+}
+
+/// Returns a JSON Schema (draft 2020-12) describing one YAML document of a
+/// `.theorem` source.
+///
+/// A document validates when it is a theorem, `Suite`, `Shared`, `Fixtures`,
+/// or `Defaults` document. Lowercase section aliases are accepted and marked
+/// deprecated, and the untagged `Do` step and `Let` binding forms are
+/// described as `oneOf` alternatives keyed by their keyword.
+///
+/// # Examples
+///
+/// ```
+/// use theoremc_core::schema::export_json_schema;
+///
+/// let schema = export_json_schema();
+/// let expectations = &schema["$defs"]["kani_expectation"]["enum"];
+/// assert_eq!(expectations[0], "SUCCESS");
+/// ```
+#[must_use]
+pub fn export_json_schema() -> Value {
+    json!({
+        "$schema": DIALECT,
+        "title": "theoremc .theorem document",
+        "description": "One YAML document of a .theorem source (TFS-1).",
+        "oneOf": [
+            reference("theorem_document"),
+            reference("suite_document"),
+            reference("shared_document"),
+            reference("fixtures_document"),
+            reference("defaults_document"),
+        ],
+        "$defs": definitions(),
+    })
+}
+
+/// Returns the named definitions the document schemas refer to.
+fn definitions() -> Value {
+    json!({
+        "theorem_document": theorem_document(),
+        "suite_document": suite_document(),
+        "shared_document": shared_document(),
+        "fixtures_document": fixtures_document(),
+        "defaults_document": defaults_document(),
+        "trace_link": trace_link(),
+        "forall_entry": forall_entry(),
+        "action_signature": action_signature(),
+        "assumption": assumption(),
+        "witness": described_expression("cover"),
+        "assertion": assertion(),
+        "action_call": action_call(),
+        "args": args(),
+        "let_binding": let_binding(),
+        "step": step(),
+        "evidence": evidence(),
+        "kani_evidence": kani_evidence(),
+        "kani_expectation": names(
+            "Expected Kani verification outcome.",
+            &EXPECTATIONS.map(KaniExpectation::as_str),
+        ),
+        "kani_solver": names("SAT solver Kani runs.", &SOLVERS.map(KaniSolver::as_str)),
+    })
+}
+
+/// Describes a theorem document from the loader's section key table.
+fn theorem_document() -> Value {
+    let sections = SECTION_KEYS.map(|(canonical, alias)| {
+        let section = Section::new(canonical, alias, theorem_section(canonical));
+        if REQUIRED_SECTIONS.contains(&canonical) {
+            section.required()
+        } else {
+            section
+        }
+    });
+    document(&sections)
+}
+
+/// Returns the schema of the theorem section spelled `canonical`.
+fn theorem_section(canonical: &str) -> Value {
+    match canonical {
+        "Schema" => json!({
+            "type": "integer",
+            "minimum": 1,
+            "maximum": LATEST_SCHEMA_VERSION,
+            "description": "Schema version; version 2 rejects lowercase section aliases.",
+        }),
+        "Theorem" | "Extends" => identifier(),
+        "About" | "Given" => text(),
+        "Tags" | "DependsOn" | "Include" | "Use" | "UseFixtures" => list(&text()),
+        "Traces" => list(&reference("trace_link")),
+        "Types" => mapping(&text()),
+        "Forall" => mapping(&reference("forall_entry")),
+        "Actions" => mapping(&reference("action_signature")),
+        "Assume" => list(&reference("assumption")),
+        "Witness" => list(&reference("witness")),
+        "Let" => mapping(&reference("let_binding")),
+        "Do" => list(&reference("step")),
+        "Prove" => json!({ "type": "array", "minItems": 1, "items": reference("assertion") }),
+        "Evidence" => reference("evidence"),
+        "Lints" => lint_levels(),
+        _ => json!({}),
+    }
+}
+
+/// Describes a `Suite` document.
+fn suite_document() -> Value {
+    document(&[
+        Section::new("Suite", "suite", identifier()).required(),
+        Section::new("About", "about", text()).required(),
+        Section::new("Tags", "tags", list(&text())),
+        Section::new("Theorems", "theorems", list(&identifier())).required(),
+        Section::new("Evidence", "evidence", reference("evidence")),
+        Section::new("Requires", "requires", suite_requirements()),
+    ])
+}
+
+/// Describes a `Suite` document's `Requires` policies.
+fn suite_requirements() -> Value {
+    let backends = names("An evidence backend.", &["kani", "verus", "stateright"]);
+    let count = json!({ "type": "integer", "minimum": 0 });
+    let min_expect = json!({
+        "type": "object",
+        "propertyNames": reference("kani_expectation"),
+        "additionalProperties": count,
+    });
+    record(
+        json!({ "backends": list(&backends), "min_expect": min_expect }),
+        &[],
+    )
+}
+
+/// Describes a `Shared` document.
+fn shared_document() -> Value {
+    document(&[
+        Section::new("Shared", "shared", identifier()).required(),
+        Section::new("About", "about", text()).required(),
+        Section::new("Forall", "forall", mapping(&reference("forall_entry"))),
+        Section::new("Assume", "assume", list(&reference("assumption"))),
+    ])
+}
+
+/// Describes a `Fixtures` document.
+fn fixtures_document() -> Value {
+    document(&[
+        Section::new("Fixtures", "fixtures", identifier()).required(),
+        Section::new("About", "about", text()).required(),
+        Section::new("Let", "let", mapping(&reference("let_binding"))).required(),
+    ])
+}
+
+/// Describes a file-level `Defaults` document.
+fn defaults_document() -> Value {
+    document(&[Section::new(
+        "Defaults",
+        "defaults",
+        document(&[
+            Section::new("Tags", "tags", list(&text())),
+            Section::new("Evidence", "evidence", reference("evidence")),
+            Section::new("Lints", "lints", lint_levels()),
+        ]),
+    )
+    .required()])
+}
+
+/// Describes a `Lints` mapping of lint names to levels.
+fn lint_levels() -> Value {
+    mapping(&json!({ "enum": ["allow", "warn", "deny"] }))
+}
+
+/// Describes a mapping of `sections`, each accepted under its canonical key
+/// or its deprecated lowercase alias. A required section may use either
+/// spelling.
+fn document(sections: &[Section]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for section in sections {
+        properties.insert(section.canonical.to_owned(), section.schema.clone());
+        properties.insert(section.alias.to_owned(), deprecated(&section.schema));
+        if section.required {
+            required.push(json!({
+                "anyOf": [
+                    { "required": [section.canonical] },
+                    { "required": [section.alias] },
+                ],
+            }));
+        }
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+        "allOf": required,
+    })
+}
+
+/// Marks `schema` as deprecated.
+fn deprecated(schema: &Value) -> Value {
+    let mut marked = schema.as_object().cloned().unwrap_or_default();
+    marked.insert("deprecated".to_owned(), Value::Bool(true));
+    Value::Object(marked)
+}
+
+/// Describes a string drawn from `values`.
+fn names(description: &str, values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values, "description": description })
+}
+
+/// Describes a mapping with exactly the key `keyword`, holding `schema`.
+fn keyed(keyword: &str, schema: Value) -> Value {
+    let mut properties = Map::new();
+    properties.insert(keyword.to_owned(), schema);
+    record(Value::Object(properties), &[keyword])
+}
+
+/// Describes a mapping with fixed `properties`, of which `required` must
+/// be present.
+fn record(properties: Value, required: &[&str]) -> Value {
+    let mut schema = Map::new();
+    schema.insert("type".to_owned(), json!("object"));
+    schema.insert("properties".to_owned(), properties);
+    schema.insert("required".to_owned(), json!(required));
+    schema.insert("additionalProperties".to_owned(), Value::Bool(false));
+    Value::Object(schema)
+}
+
+/// Describes a mapping from names to `values`.
+fn mapping(values: &Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+/// Describes a sequence of `items`.
+fn list(items: &Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+/// Refers to the definition `name`.
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+/// Describes a string.
+fn text() -> Value {
+    json!({ "type": "string" })
+}
+
+/// Describes a Rust identifier.
+fn identifier() -> Value {
+    json!({ "type": "string", "pattern": "^[A-Za-z_][A-Za-z0-9_]*$" })
+}
+
+/// Describes a non-negative count.
+fn count() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+#[cfg(test)]
+#[path = "json_schema_tests.rs"]
+mod tests;
@@ -0,0 +1,57 @@
+//! JSON Schema definitions of the `Evidence` section.
+
+use serde_json::{Value, json};
+
+use super::{identifier, list, mapping, record, reference, text};
+
+/// Describes the `Evidence` section.
+pub(super) fn evidence() -> Value {
+    let configs = json!({ "type": "array", "minItems": 1, "items": reference("kani_evidence") });
+    record(
+        json!({
+            "kani": { "oneOf": [reference("kani_evidence"), configs] },
+            "verus": {},
+            "stateright": {},
+        }),
+        &[],
+    )
+}
+
+/// Describes one Kani harness configuration.
+pub(super) fn kani_evidence() -> Value {
+    let bound = json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX });
+    let per_loop = json!({
+        "type": "object",
+        "additionalProperties": bound,
+        "required": ["default"],
+    });
+    let stub = record(
+        json!({ "original": text(), "stub": text() }),
+        &["original", "stub"],
+    );
+    let constraint = json!({
+        "oneOf": [
+            record(json!({ "satisfies": text() }), &["satisfies"]),
+            { "type": ["string", "number", "boolean"] },
+        ],
+    });
+    record(
+        json!({
+            "name": identifier(),
+            "unwind": { "oneOf": [bound, per_loop] },
+            "expect": reference("kani_expectation"),
+            "allow_vacuous": { "type": "boolean" },
+            "vacuity_because": text(),
+            "solver": reference("kani_solver"),
+            "extra_args": list(&text()),
+            "timeout": { "type": "string", "description": "A duration such as '90s' or '1h 30m'." },
+            "memory_limit": { "type": "string", "description": "A size such as '512MiB' or '4GB'." },
+            "stubs": list(&stub),
+            "contracts": list(&text()),
+            "counterexample": mapping(&constraint),
+            "playback": { "type": "boolean" },
+            "coverage": { "type": "boolean" },
+        }),
+        &[],
+    )
+}
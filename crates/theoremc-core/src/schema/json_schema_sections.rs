@@ -0,0 +1,144 @@
+//! JSON Schema definitions of theorem section entries and `Do` steps.
+
+use serde_json::{Map, Value, json};
+
+use super::{count, identifier, keyed, list, mapping, record, reference, text};
+
+/// Describes a `Traces` entry.
+pub(super) fn trace_link() -> Value {
+    record(
+        json!({ "id": text(), "system": text(), "url": text() }),
+        &["id", "system"],
+    )
+}
+
+/// Describes a `Forall` value: a bare Rust type or a domain mapping.
+pub(super) fn forall_entry() -> Value {
+    let choice = json!({ "type": ["number", "boolean", "string"] });
+    let domain = record(
+        json!({
+            "type": text(),
+            "struct": text(),
+            "vec": text(),
+            "min": { "type": "number" },
+            "max": { "type": "number" },
+            "oneof": { "type": "array", "minItems": 1, "items": choice },
+            "fields": mapping(&text()),
+            "len": record(
+                json!({ "min": count(), "max": count() }),
+                &["max"],
+            ),
+            "from": text(),
+            "optional": { "type": "boolean" },
+        }),
+        &[],
+    );
+    let shapes = json!([
+        { "required": ["type"] },
+        { "required": ["struct"] },
+        { "required": ["vec"] },
+    ]);
+    json!({
+        "oneOf": [
+            { "type": "string", "description": "A Rust type." },
+            { "allOf": [domain, { "oneOf": shapes }] },
+        ],
+    })
+}
+
+/// Describes an `Actions` signature.
+pub(super) fn action_signature() -> Value {
+    record(
+        json!({ "params": mapping(&text()), "returns": text() }),
+        &[],
+    )
+}
+
+/// Describes an `Assume` entry, whose expression key is `assume` or `expr`.
+pub(super) fn assumption() -> Value {
+    let entry = record(
+        json!({ "assume": text(), "expr": text(), "because": text() }),
+        &["because"],
+    );
+    json!({
+        "allOf": [
+            entry,
+            { "oneOf": [{ "required": ["assume"] }, { "required": ["expr"] }] },
+        ],
+    })
+}
+
+/// Describes a `Prove` entry.
+pub(super) fn assertion() -> Value {
+    record(
+        json!({ "assert": text(), "because": text(), "step": identifier() }),
+        &["assert", "because"],
+    )
+}
+
+/// Describes an expression entry keyed by `keyword` with a justification.
+pub(super) fn described_expression(keyword: &str) -> Value {
+    let mut properties = Map::new();
+    properties.insert(keyword.to_owned(), text());
+    properties.insert("because".to_owned(), text());
+    record(Value::Object(properties), &[keyword, "because"])
+}
+
+/// Describes an action invocation.
+pub(super) fn action_call() -> Value {
+    record(
+        json!({ "action": text(), "args": reference("args"), "as": identifier() }),
+        &["action", "args"],
+    )
+}
+
+/// Describes action arguments, keyed by parameter or in signature order.
+pub(super) fn args() -> Value {
+    json!({
+        "oneOf": [
+            { "type": "object", "description": "Arguments keyed by parameter name." },
+            { "type": "array", "description": "Arguments in signature parameter order." },
+        ],
+    })
+}
+
+/// Describes a `Let` binding: a `call` or a `must`.
+pub(super) fn let_binding() -> Value {
+    json!({
+        "oneOf": [
+            record(json!({ "call": reference("action_call") }), &["call"]),
+            record(json!({ "must": reference("action_call") }), &["must"]),
+        ],
+    })
+}
+
+/// Describes a `Do` step, one alternative per step keyword.
+pub(super) fn step() -> Value {
+    let steps = list(&reference("step"));
+    json!({
+        "oneOf": [
+            record(json!({ "call": reference("action_call"), "id": identifier() }), &["call"]),
+            record(json!({ "must": reference("action_call"), "id": identifier() }), &["must"]),
+            keyed("expect_err", record(
+                json!({ "action": text(), "args": reference("args"), "error": identifier() }),
+                &["action", "args", "error"],
+            )),
+            keyed("maybe", record(
+                json!({ "because": text(), "do": steps, "else": steps }),
+                &["because", "do"],
+            )),
+            keyed("repeat", record(
+                json!({ "times": { "type": "integer", "minimum": 1 }, "do": steps }),
+                &["times", "do"],
+            )),
+            keyed("foreach", record(
+                json!({ "in": identifier(), "as": identifier(), "do": steps }),
+                &["in", "as", "do"],
+            )),
+            keyed("assert", record(
+                json!({ "expr": text(), "because": text() }),
+                &["expr", "because"],
+            )),
+        ],
+    })
+}
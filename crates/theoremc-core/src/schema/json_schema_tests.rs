@@ -0,0 +1,140 @@
+//! Unit tests for JSON Schema export.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+use super::*;
+
+fn definition(name: &str) -> Value {
+    export_json_schema()
+        .pointer(&format!("/$defs/{name}"))
+        .cloned()
+        .unwrap_or_else(|| panic!("missing definition {name}"))
+}
+
+fn references(value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::Object(entries) => {
+            if let Some(Value::String(target)) = entries.get("$ref") {
+                found.push(target.clone());
+            }
+            entries.values().for_each(|child| references(child, found));
+        }
+        Value::Array(items) => items.iter().for_each(|child| references(child, found)),
+        _ => {}
+    }
+}
+
+#[test]
+fn every_reference_resolves() {
+    let schema = export_json_schema();
+    let mut targets = Vec::new();
+    references(&schema, &mut targets);
+
+    assert!(!targets.is_empty());
+    for target in targets {
+        let pointer = target.trim_start_matches('#');
+        assert!(schema.pointer(pointer).is_some(), "dangling {target}");
+    }
+}
+
+#[test]
+fn theorem_document_lists_every_section_spelling() {
+    let theorem = definition("theorem_document");
+
+    for (canonical, alias) in SECTION_KEYS {
+        assert!(
+            theorem
+                .pointer(&format!("/properties/{canonical}"))
+                .is_some(),
+            "{canonical}"
+        );
+        assert_eq!(
+            theorem.pointer(&format!("/properties/{alias}/deprecated")),
+            Some(&json!(true))
+        );
+    }
+    assert_eq!(theorem.get("additionalProperties"), Some(&json!(false)));
+}
+
+#[rstest]
+#[case::theorem("theorem_document", &["Theorem", "About", "Prove"])]
+#[case::suite("suite_document", &["Suite", "About", "Theorems"])]
+#[case::shared("shared_document", &["Shared", "About"])]
+#[case::fixtures("fixtures_document", &["Fixtures", "About", "Let"])]
+#[case::defaults("defaults_document", &["Defaults"])]
+fn required_sections_accept_either_spelling(#[case] name: &str, #[case] expected: &[&str]) {
+    let document = definition(name);
+    let required: Vec<Value> = expected
+        .iter()
+        .map(|canonical| {
+            let alias = canonical.to_lowercase();
+            json!({ "anyOf": [{ "required": [canonical] }, { "required": [alias] }] })
+        })
+        .collect();
+
+    assert_eq!(document.get("allOf"), Some(&Value::Array(required)));
+}
+
+#[test]
+fn steps_are_keyed_by_their_keyword() {
+    let step = definition("step");
+    let keywords: Vec<Value> = step
+        .get("oneOf")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|alternative| alternative.pointer("/required/0").cloned())
+        .collect();
+
+    assert_eq!(
+        keywords,
+        [
+            "call",
+            "must",
+            "expect_err",
+            "maybe",
+            "repeat",
+            "foreach",
+            "assert"
+        ]
+        .map(Value::from)
+    );
+}
+
+#[test]
+fn let_bindings_are_calls_or_musts() {
+    let binding = definition("let_binding");
+
+    assert_eq!(binding.pointer("/oneOf/0/required"), Some(&json!(["call"])));
+    assert_eq!(binding.pointer("/oneOf/1/required"), Some(&json!(["must"])));
+}
+
+#[test]
+fn kani_expectations_match_the_schema_type() {
+    let expectation = definition("kani_expectation");
+    let names: Vec<&str> = expectation
+        .get("enum")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    assert_eq!(names, ["SUCCESS", "FAILURE", "UNREACHABLE", "UNDETERMINED"]);
+    for name in names {
+        let parsed: KaniExpectation = serde_saphyr::from_str(name).expect("expectation parses");
+        assert_eq!(parsed.as_str(), name);
+    }
+}
+
+#[test]
+fn schema_version_is_bounded_by_the_latest() {
+    let theorem = definition("theorem_document");
+
+    assert_eq!(
+        theorem.pointer("/properties/Schema/maximum"),
+        Some(&json!(LATEST_SCHEMA_VERSION))
+    );
+}
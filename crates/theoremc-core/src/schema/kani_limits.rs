@@ -0,0 +1,276 @@
+//! Resource limits for a single Kani harness run.
+//!
+//! `Evidence.kani.timeout` uses humantime-style durations such as `90s`,
+//! `5m`, or `1h 30m`; `Evidence.kani.memory_limit` uses byte sizes such as
+//! `512MiB` or `4GB`. Both are parsed when the document is deserialized, so a
+//! malformed limit fails loading with a message naming the field and
+//! downstream code only sees validated values.
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::de;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Duration units accepted by [`KaniTimeout`] with their scale in
+/// milliseconds.
+const DURATION_UNITS: &[(&str, u64)] = &[
+    ("ms", 1),
+    ("s", 1_000),
+    ("m", 60_000),
+    ("h", 3_600_000),
+    ("d", 86_400_000),
+];
+
+/// Byte-size units accepted by [`MemoryLimit`] with their scale in bytes,
+/// matched case-insensitively.
+const SIZE_UNITS: &[(&str, u64)] = &[
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("tb", 1_000_000_000_000),
+    ("kib", 1 << 10),
+    ("mib", 1 << 20),
+    ("gib", 1 << 30),
+    ("tib", 1 << 40),
+];
+
+/// Reasons a Kani resource limit fails to parse.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum KaniLimitError {
+    /// The limit is not a number followed by a recognized unit.
+    #[error("invalid {field} '{value}': expected {expected}")]
+    Malformed {
+        /// The evidence field being parsed.
+        field: &'static str,
+        /// The rejected input.
+        value: String,
+        /// Description of the accepted form.
+        expected: &'static str,
+    },
+    /// The limit parses but is zero.
+    #[error("invalid {field} '{value}': must be greater than zero")]
+    Zero {
+        /// The evidence field being parsed.
+        field: &'static str,
+        /// The rejected input.
+        value: String,
+    },
+    /// The limit does not fit in 64 bits of its base unit.
+    #[error("invalid {field} '{value}': value is too large")]
+    Overflow {
+        /// The evidence field being parsed.
+        field: &'static str,
+        /// The rejected input.
+        value: String,
+    },
+}
+
+// ── KaniTimeout ────────────────────────────────────────────────────
+
+/// Wall-clock limit for verifying one harness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KaniTimeout(Duration);
+
+impl KaniTimeout {
+    const FIELD: &'static str = "timeout";
+    const EXPECTED: &'static str = "a duration such as '90s', '5m', or '1h 30m' \
+                                    (units: ms, s, m, h, d)";
+
+    /// Parses a humantime-style duration made of one or more `<n><unit>`
+    /// terms, optionally separated by whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KaniLimitError`] when the input is malformed, zero, or
+    /// overflows a millisecond count.
+    pub fn parse(value: &str) -> Result<Self, KaniLimitError> {
+        let millis = parse_terms(value, Self::FIELD, Self::EXPECTED)?;
+        Ok(Self(Duration::from_millis(millis)))
+    }
+
+    /// Returns the limit as a [`Duration`].
+    #[must_use]
+    pub const fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl fmt::Display for KaniTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}ms", self.0.as_millis())
+    }
+}
+
+/// Serializes the limit in its [`Display`](fmt::Display) form, which
+/// [`KaniTimeout::parse`] accepts.
+impl Serialize for KaniTimeout {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for KaniTimeout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(de::Error::custom)
+    }
+}
+
+// ── MemoryLimit ────────────────────────────────────────────────────
+
+/// Memory ceiling for verifying one harness, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLimit(u64);
+
+impl MemoryLimit {
+    const FIELD: &'static str = "memory_limit";
+    const EXPECTED: &'static str = "a size such as '512MiB' or '4GB' \
+                                    (units: B, KB, MB, GB, TB, KiB, MiB, GiB, TiB)";
+
+    /// Parses a byte size written as `<n><unit>`, with decimal (`KB`, `MB`,
+    /// ...) or binary (`KiB`, `MiB`, ...) units in any letter case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KaniLimitError`] when the input is malformed, zero, or
+    /// overflows a byte count.
+    pub fn parse(value: &str) -> Result<Self, KaniLimitError> {
+        let malformed = || KaniLimitError::Malformed {
+            field: Self::FIELD,
+            value: value.to_owned(),
+            expected: Self::EXPECTED,
+        };
+        let trimmed = value.trim();
+        let digits = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(malformed)?;
+        let (number, unit) = trimmed.split_at(digits);
+        let scale = SIZE_UNITS
+            .iter()
+            .find(|&&(name, _)| unit.trim_start().eq_ignore_ascii_case(name))
+            .map(|&(_, scale)| scale)
+            .ok_or_else(malformed)?;
+        let bytes = scaled(number, scale, Self::FIELD, value)?.ok_or_else(malformed)?;
+        if bytes == 0 {
+            return Err(KaniLimitError::Zero {
+                field: Self::FIELD,
+                value: value.to_owned(),
+            });
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Returns the limit in bytes.
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for MemoryLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+/// Serializes the limit in its [`Display`](fmt::Display) form, which
+/// [`MemoryLimit::parse`] accepts.
+impl Serialize for MemoryLimit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemoryLimit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(de::Error::custom)
+    }
+}
+
+// ── Parsing helpers ────────────────────────────────────────────────
+
+/// Sums the `<n><unit>` terms of a duration into milliseconds.
+fn parse_terms(
+    value: &str,
+    field: &'static str,
+    expected: &'static str,
+) -> Result<u64, KaniLimitError> {
+    let malformed = || KaniLimitError::Malformed {
+        field,
+        value: value.to_owned(),
+        expected,
+    };
+    let mut rest = value.trim();
+    if rest.is_empty() {
+        return Err(malformed());
+    }
+    let mut total: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(malformed)?;
+        let (number, tail) = rest.split_at(digits);
+        let (unit, scale) = duration_unit(tail).ok_or_else(malformed)?;
+        let term = scaled(number, scale, field, value)?.ok_or_else(malformed)?;
+        total = total
+            .checked_add(term)
+            .ok_or_else(|| overflow(field, value))?;
+        rest = tail.get(unit.len()..).unwrap_or_default().trim_start();
+    }
+    if total == 0 {
+        return Err(KaniLimitError::Zero {
+            field,
+            value: value.to_owned(),
+        });
+    }
+    Ok(total)
+}
+
+/// Returns the longest duration unit that starts `input`.
+fn duration_unit(input: &str) -> Option<(&'static str, u64)> {
+    DURATION_UNITS
+        .iter()
+        .filter(|&&(name, _)| input.starts_with(name))
+        .max_by_key(|&&(name, _)| name.len())
+        .copied()
+}
+
+/// Multiplies the decimal `number` by `scale`, returning `None` when
+/// `number` has no digits.
+fn scaled(
+    number: &str,
+    scale: u64,
+    field: &'static str,
+    value: &str,
+) -> Result<Option<u64>, KaniLimitError> {
+    if number.is_empty() {
+        return Ok(None);
+    }
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(scale))
+        .map(Some)
+        .ok_or_else(|| overflow(field, value))
+}
+
+fn overflow(field: &'static str, value: &str) -> KaniLimitError {
+    KaniLimitError::Overflow {
+        field,
+        value: value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+#[path = "kani_limits_tests.rs"]
+mod tests;
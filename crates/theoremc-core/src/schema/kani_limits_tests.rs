@@ -0,0 +1,87 @@
+//! Unit tests for Kani resource-limit parsing.
+
+use std::time::Duration;
+
+use rstest::rstest;
+
+use super::{KaniLimitError, KaniTimeout, MemoryLimit};
+
+#[rstest]
+#[case::millis("250ms", 250)]
+#[case::seconds("90s", 90_000)]
+#[case::minutes("5m", 300_000)]
+#[case::compound("1h30m", 5_400_000)]
+#[case::spaced("1h 30m 15s", 5_415_000)]
+#[case::days("1d", 86_400_000)]
+#[case::padded("  10s  ", 10_000)]
+fn timeouts_parse_humantime_terms(#[case] input: &str, #[case] millis: u64) {
+    let timeout = KaniTimeout::parse(input).expect("timeout should parse");
+    assert_eq!(timeout.as_duration(), Duration::from_millis(millis));
+}
+
+#[rstest]
+#[case::bare_number("30")]
+#[case::empty("")]
+#[case::unknown_unit("5 minutes")]
+#[case::missing_number("s")]
+#[case::fraction("1.5h")]
+#[case::negative("-5s")]
+fn malformed_timeouts_are_rejected(#[case] input: &str) {
+    assert!(matches!(
+        KaniTimeout::parse(input),
+        Err(KaniLimitError::Malformed {
+            field: "timeout",
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[case::bytes("4096B", 4096)]
+#[case::decimal("4GB", 4_000_000_000)]
+#[case::binary("512MiB", 512 << 20)]
+#[case::lowercase("2gib", 2 << 30)]
+#[case::spaced("64 KiB", 64 << 10)]
+fn memory_limits_parse_sizes(#[case] input: &str, #[case] bytes: u64) {
+    let limit = MemoryLimit::parse(input).expect("memory limit should parse");
+    assert_eq!(limit.bytes(), bytes);
+}
+
+#[rstest]
+#[case::bare_number("1024")]
+#[case::unknown_unit("4 gigabytes")]
+#[case::missing_number("MiB")]
+#[case::fraction("1.5GiB")]
+fn malformed_memory_limits_are_rejected(#[case] input: &str) {
+    assert!(matches!(
+        MemoryLimit::parse(input),
+        Err(KaniLimitError::Malformed {
+            field: "memory_limit",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn zero_limits_are_rejected() {
+    assert!(matches!(
+        KaniTimeout::parse("0s"),
+        Err(KaniLimitError::Zero { .. })
+    ));
+    assert!(matches!(
+        MemoryLimit::parse("0MiB"),
+        Err(KaniLimitError::Zero { .. })
+    ));
+}
+
+#[test]
+fn overflowing_limits_are_rejected() {
+    assert!(matches!(
+        KaniTimeout::parse("99999999999999999999d"),
+        Err(KaniLimitError::Overflow { .. })
+    ));
+    assert!(matches!(
+        MemoryLimit::parse("20000000TiB"),
+        Err(KaniLimitError::Overflow { .. })
+    ));
+}
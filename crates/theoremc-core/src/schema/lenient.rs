@@ -0,0 +1,379 @@
+//! Skipping of undeclared keys at any depth of a theorem source.
+//!
+//! Under [`UnknownKeyPolicy::Warn`](super::UnknownKeyPolicy::Warn) and
+//! [`UnknownKeyPolicy::Ignore`](super::UnknownKeyPolicy::Ignore) the loader
+//! parses inside [`collect_skipped_keys`] and wraps section deserializers in
+//! [`Lenient`]. Every mapping a struct reads through a [`Lenient`]
+//! deserializer drops the keys the struct does not declare, so strict
+//! structs nested in `Evidence`, `Do` steps, and the other sections accept
+//! keys written for a newer schema. The skipped keys are recorded per
+//! thread rather than threaded through serde, which lets hand-written
+//! visitors that read a mapping themselves, such as the one for
+//! `Evidence.kani`, wrap it in [`Lenient`] too. Outside
+//! [`collect_skipped_keys`], [`Lenient`] changes nothing.
+
+use std::cell::RefCell;
+use std::fmt;
+
+use serde::Deserialize;
+use serde::de::value::StringDeserializer;
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde_saphyr::Spanned;
+
+thread_local! {
+    /// Keys skipped by the innermost [`collect_skipped_keys`] call, or
+    /// `None` when undeclared keys are rejected.
+    static SKIPPED: RefCell<Option<Vec<Spanned<String>>>> = const { RefCell::new(None) };
+}
+
+/// Runs `parse` with undeclared keys skipped, returning its result with the
+/// keys skipped in source order.
+pub(crate) fn collect_skipped_keys<T>(parse: impl FnOnce() -> T) -> (T, Vec<Spanned<String>>) {
+    let guard = RestoreOnDrop(SKIPPED.replace(Some(Vec::new())));
+    let parsed = parse();
+    let skipped = SKIPPED.take().unwrap_or_default();
+    drop(guard);
+    (parsed, skipped)
+}
+
+/// Returns whether undeclared keys are currently skipped.
+pub(crate) fn is_skipping() -> bool {
+    SKIPPED.with_borrow(Option::is_some)
+}
+
+/// Records `key` as skipped.
+pub(crate) fn record_skipped(key: Spanned<String>) {
+    SKIPPED.with_borrow_mut(|skipped| {
+        if let Some(keys) = skipped {
+            keys.push(key);
+        }
+    });
+}
+
+/// Reinstates the enclosing sink, even when parsing panics.
+struct RestoreOnDrop(Option<Vec<Spanned<String>>>);
+
+impl Drop for RestoreOnDrop {
+    fn drop(&mut self) {
+        SKIPPED.set(self.0.take());
+    }
+}
+
+/// A deserializer whose structs, at any depth, skip keys they do not
+/// declare while [`collect_skipped_keys`] is running.
+pub(crate) struct Lenient<D>(pub(crate) D);
+
+/// A seed that deserializes its value through [`Lenient`].
+pub(crate) struct LenientSeed<S>(pub(crate) S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for LenientSeed<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(Lenient(deserializer))
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                self.0.$method($($arg,)* LenientVisitor { inner: visitor, fields: None })
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Lenient<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any() deserialize_bool() deserialize_i8() deserialize_i16()
+        deserialize_i32() deserialize_i64() deserialize_i128() deserialize_u8()
+        deserialize_u16() deserialize_u32() deserialize_u64() deserialize_u128()
+        deserialize_f32() deserialize_f64() deserialize_char() deserialize_str()
+        deserialize_string() deserialize_bytes() deserialize_byte_buf()
+        deserialize_option() deserialize_unit() deserialize_seq() deserialize_map()
+        deserialize_identifier()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let lenient = LenientVisitor {
+            inner: visitor,
+            fields: Some(fields),
+        };
+        self.0.deserialize_struct(name, fields, lenient)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+/// A visitor that hands the mappings, sequences, and nested values it is
+/// given back to `inner` through [`Lenient`] wrappers. `fields` are the
+/// keys a struct declares, when `inner` reads one.
+struct LenientVisitor<V> {
+    inner: V,
+    fields: Option<&'static [&'static str]>,
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E: serde::de::Error>(self, value: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for LenientVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool) visit_i8(i8) visit_i16(i16) visit_i32(i32) visit_i64(i64)
+        visit_i128(i128) visit_u8(u8) visit_u16(u16) visit_u32(u32) visit_u64(u64)
+        visit_u128(u128) visit_f32(f32) visit_f64(f64) visit_char(char)
+        visit_str(&str) visit_borrowed_str(&'de str) visit_string(String)
+        visit_bytes(&[u8]) visit_borrowed_bytes(&'de [u8]) visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(Lenient(deserializer))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner.visit_newtype_struct(Lenient(deserializer))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(LenientSeq(seq))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(LenientMap {
+            map,
+            fields: self.fields,
+        })
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(LenientEnum(data))
+    }
+}
+
+struct LenientSeq<A>(A);
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for LenientSeq<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0.next_element_seed(LenientSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.0.size_hint()
+    }
+}
+
+/// A mapping that skips keys outside `fields` while keys are being skipped.
+struct LenientMap<A> {
+    map: A,
+    fields: Option<&'static [&'static str]>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for LenientMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(fields) = self.fields.filter(|_| is_skipping()) else {
+            return self.map.next_key_seed(LenientSeed(seed));
+        };
+        while let Some(key) = self.map.next_key_seed(SpannedKey)? {
+            if fields.contains(&key.value.as_str()) {
+                let declared: StringDeserializer<A::Error> = key.value.into_deserializer();
+                return seed.deserialize(declared).map(Some);
+            }
+            record_skipped(key);
+            self.map.next_value::<IgnoredAny>()?;
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.map.next_value_seed(LenientSeed(seed))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+struct LenientEnum<A>(A);
+
+impl<'de, A: EnumAccess<'de>> EnumAccess<'de> for LenientEnum<A> {
+    type Error = A::Error;
+    type Variant = LenientVariant<A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let (variant, access) = self.0.variant_seed(seed)?;
+        Ok((variant, LenientVariant(access)))
+    }
+}
+
+struct LenientVariant<A>(A);
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for LenientVariant<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.0.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        self.0.newtype_variant_seed(LenientSeed(seed))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let lenient = LenientVisitor {
+            inner: visitor,
+            fields: None,
+        };
+        self.0.tuple_variant(len, lenient)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let lenient = LenientVisitor {
+            inner: visitor,
+            fields: Some(fields),
+        };
+        self.0.struct_variant(fields, lenient)
+    }
+}
+
+/// Reads a mapping key with its location. The location maps inside a
+/// [`Spanned`] value are synthesized rather than parsed, so their keys have
+/// no location of their own and read as
+/// [`Location::UNKNOWN`](serde_saphyr::Location::UNKNOWN).
+struct SpannedKey;
+
+impl<'de> DeserializeSeed<'de> for SpannedKey {
+    type Value = Spanned<String>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        Spanned::deserialize(PlainKeyFallback(deserializer))
+    }
+}
+
+/// A key deserializer that offers a plain string key to [`Spanned`] as an
+/// unlocated value.
+struct PlainKeyFallback<D>(D);
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for PlainKeyFallback<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .deserialize_newtype_struct(name, PlainKeyVisitor(visitor))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple tuple_struct map struct
+        enum identifier ignored_any
+    }
+}
+
+struct PlainKeyVisitor<V>(V);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for PlainKeyVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.0.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let plain: StringDeserializer<E> = value.to_owned().into_deserializer();
+        self.0.visit_newtype_struct(plain)
+    }
+}
+
+#[cfg(test)]
+#[path = "lenient_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+//! Unit tests for skipping undeclared keys.
+
+use std::panic;
+
+use pretty_assertions::assert_eq;
+use serde::Deserialize;
+
+use super::*;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct Inner {
+    depth: u32,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct Outer {
+    inner: Vec<Inner>,
+}
+
+const NESTED: &str = "inner:\n  - depth: 1\n    width: 2\nlabel: x\n";
+
+/// Reads an [`Outer`] through [`Lenient`].
+struct LenientOuter(Outer);
+
+impl<'de> Deserialize<'de> for LenientOuter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Outer::deserialize(Lenient(deserializer)).map(Self)
+    }
+}
+
+fn parse_lenient(yaml: &str) -> Result<Outer, serde_saphyr::Error> {
+    serde_saphyr::from_str::<LenientOuter>(yaml).map(|parsed| parsed.0)
+}
+
+#[test]
+fn skipped_keys_are_collected_at_every_depth() {
+    let (parsed, skipped) = collect_skipped_keys(|| parse_lenient(NESTED));
+
+    assert_eq!(
+        parsed.expect("undeclared keys should be skipped"),
+        Outer {
+            inner: vec![Inner { depth: 1 }],
+        }
+    );
+    let keys: Vec<(&str, u64)> = skipped
+        .iter()
+        .map(|key| (key.value.as_str(), key.referenced.line()))
+        .collect();
+    assert_eq!(keys, [("width", 3), ("label", 4)]);
+}
+
+#[test]
+fn lenient_deserializers_stay_strict_outside_collection() {
+    let error = parse_lenient(NESTED).expect_err("undeclared keys should be rejected");
+
+    assert!(
+        error.to_string().contains("unknown field `width`"),
+        "{error}"
+    );
+}
+
+#[test]
+fn collection_ends_when_parsing_panics() {
+    let outcome = panic::catch_unwind(|| collect_skipped_keys(|| panic!("parser bug")));
+
+    assert!(outcome.is_err());
+    assert!(!is_skipping());
+}
@@ -0,0 +1,47 @@
+//! Options that tune how strictly theorem sources are loaded.
+
+/// Loader settings for
+/// [`load_theorem_docs_with_options`](super::load_theorem_docs_with_options).
+///
+/// The default loads sources exactly like
+/// [`load_theorem_docs_with_includes`](super::load_theorem_docs_with_includes).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// How lowercase aliases of top-level section keys, such as `prove`
+    /// for `Prove`, are treated in documents that do not already require
+    /// canonical keys.
+    pub aliases: AliasPolicy,
+    /// How keys a document does not declare, such as sections or fields
+    /// added by a newer schema, are treated.
+    pub unknown_keys: UnknownKeyPolicy,
+}
+
+/// How the loader treats lowercase section key aliases.
+///
+/// Documents declaring `Schema` version 2 or later reject aliases whatever
+/// the policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AliasPolicy {
+    /// Accept aliases silently.
+    #[default]
+    Allow,
+    /// Accept aliases, reporting each as a deprecation warning.
+    Warn,
+    /// Reject the first alias as a validation failure.
+    Deny,
+}
+
+/// How the loader treats keys that a theorem, `Suite`, `Shared`, or
+/// `Fixtures` document does not declare, whether at the top level or nested
+/// inside a section or a `Defaults` document, such as a field of a `Do` step
+/// or a Kani configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownKeyPolicy {
+    /// Fail loading at the first unknown key.
+    #[default]
+    Deny,
+    /// Skip unknown keys, reporting each as a warning.
+    Warn,
+    /// Skip unknown keys silently.
+    Ignore,
+}
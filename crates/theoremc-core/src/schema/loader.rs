@@ -5,20 +5,29 @@
 //! identifiers at deserialization time (via `TheoremName` / `ForallVar`
 //! newtypes) and enforcing structural constraints post-deserialization.
 
-use std::collections::BTreeMap;
+use crate::actions::{ActionManifest, check_action_calls};
+
+use serde_saphyr::Spanned;
 
-use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, create_diagnostic, first_line};
 use super::error::SchemaError;
-use super::loader_decode_location::locate_decode_failure;
-use super::loader_message::{ErrorMessage, FieldName};
-use super::raw::{RawDocDecodeError, RawTheoremDoc};
+use super::include::{IncludeResolver, NoIncludes, resolve_includes};
+use super::load_options::LoadOptions;
+use super::loader_context::DocumentContext;
+use super::loader_dependency::check_theorem_dependencies;
+use super::loader_duplicate::check_duplicate_theorem_keys;
+use super::loader_options::{LoadedTheoremDocs, parse_documents, source_warnings};
+use super::raw::RawTheoremDoc;
+use super::raw_document::{RawDocument, SourceDocuments, split_documents};
+use super::raw_suite::RawSuite;
+use super::section_key::check_canonical_keys;
 use super::source_id::SourceId;
+use super::suite_policy::check_suite_requirements;
+use super::type_alias::expand_type_aliases;
 use super::types::TheoremDoc;
 use super::validate::validate_theorem_doc;
-use super::validation_reason::ValidationFailure;
 
 /// Synthetic source identifier used by [`load_theorem_docs`].
-const INLINE_SOURCE: &str = "<inline>";
+pub(super) const INLINE_SOURCE: &str = "<inline>";
 
 /// Loads one or more theorem documents from a YAML string.
 ///
@@ -32,8 +41,9 @@ const INLINE_SOURCE: &str = "<inline>";
 /// non-empty `About`, non-empty
 /// `Prove`, at-least-one Evidence backend, positive Kani `unwind`,
 /// non-blank string fields, non-empty `Witness` when Kani
-/// `allow_vacuous` is false, and `vacuity_because` when
-/// `allow_vacuous` is true.
+/// `allow_vacuous` is false, `vacuity_because` when
+/// `allow_vacuous` is true, and acyclic `DependsOn` entries that name
+/// theorems in the same source.
 ///
 /// # Errors
 ///
@@ -51,6 +61,8 @@ const INLINE_SOURCE: &str = "<inline>";
 ///     let yaml = r#"
 ///     Theorem: MyTheorem
 ///     About: A simple example
+///     Forall:
+///       x: i32
 ///     Prove:
 ///       - assert: "x > 0"
 ///         because: "x is positive"
@@ -73,293 +85,160 @@ pub fn load_theorem_docs(input: &str) -> Result<Vec<TheoremDoc>, SchemaError> {
 ///
 /// This function behaves like [`load_theorem_docs`] but associates parser and
 /// validator diagnostics with `source` in structured diagnostic payloads.
+/// Documents that declare `Include` entries are rejected because there is no
+/// resolver to read the fragments; use [`load_theorem_docs_with_includes`]
+/// instead.
 ///
 /// # Errors
 ///
 /// Returns [`SchemaError::Deserialize`] when YAML parsing or deserialization
 /// fails, [`SchemaError::ValidationFailed`] when semantic validation fails,
+/// [`SchemaError::IncludeFailed`] when a document declares `Include` entries,
 /// and [`SchemaError::DuplicateTheoremKey`] when the same source declares a
 /// duplicate literal theorem key `{P}#{T}`.
 pub fn load_theorem_docs_with_source(
     source: &SourceId,
     input: &str,
 ) -> Result<Vec<TheoremDoc>, SchemaError> {
-    let raw_docs: Vec<RawTheoremDoc> = serde_saphyr::from_multiple(input).map_err(|error| {
-        let message = error.to_string();
-        let diagnostic = build_parse_diagnostic(source, input, &error, ErrorMessage::new(&message));
-        SchemaError::Deserialize {
-            message,
-            diagnostic,
-        }
-    })?;
-    check_duplicate_theorem_keys(source, &raw_docs)?;
-
-    let mut docs = Vec::with_capacity(raw_docs.len());
-    for raw_doc in &raw_docs {
-        let doc = raw_doc.to_theorem_doc().map_err(|decode_err| {
-            attach_decode_failure_diagnostic(decode_err, source, input, raw_doc)
-        })?;
-        validate_theorem_doc(&doc)
-            .map_err(|failure| attach_validation_failure_diagnostic(failure, source, raw_doc))?;
-        docs.push(doc);
-    }
-
-    crate::collision::check_action_collisions(&docs)?;
-
-    Ok(docs)
-}
-
-#[derive(Debug, Clone, Copy)]
-struct DuplicateTheoremLocation {
-    location: serde_saphyr::Location,
-    line: usize,
-    column: usize,
-}
-
-#[derive(Debug, Clone)]
-struct DuplicateTheoremCollision {
-    first: DuplicateTheoremLocation,
-    duplicates: Vec<DuplicateTheoremLocation>,
+    load_theorem_docs_with_includes(source, input, &NoIncludes)
 }
 
-fn build_duplicate_theorem_key_error(
-    source: &SourceId,
-    theorem: &str,
-    first_collision: &DuplicateTheoremCollision,
-    collisions: &BTreeMap<&str, DuplicateTheoremCollision>,
-) -> SchemaError {
-    let theorem_key = crate::mangle::theorem_key(source.as_str(), theorem);
-    let first_diagnostic = create_diagnostic(
-        SchemaDiagnosticCode::ValidationFailure,
-        source,
-        format_duplicate_theorem_key_summary(source, theorem, first_collision),
-        first_collision
-            .duplicates
-            .first()
-            .copied()
-            .unwrap_or(first_collision.first)
-            .location,
-    );
-    let mut collision_diagnostics = Vec::with_capacity(collisions.len());
-    collision_diagnostics.push(first_diagnostic.clone());
-    collision_diagnostics.extend(collisions.iter().skip(1).map(
-        |(other_theorem, other_collision)| {
-            create_diagnostic(
-                SchemaDiagnosticCode::ValidationFailure,
-                source,
-                format_duplicate_theorem_key_summary(source, other_theorem, other_collision),
-                other_collision
-                    .duplicates
-                    .first()
-                    .copied()
-                    .unwrap_or(other_collision.first)
-                    .location,
-            )
-        },
-    ));
-    SchemaError::DuplicateTheoremKey {
-        theorem_key,
-        collisions: collision_diagnostics,
-        diagnostic: Some(first_diagnostic),
-    }
-}
-
-fn check_duplicate_theorem_keys(
-    source: &SourceId,
-    raw_docs: &[RawTheoremDoc],
-) -> Result<(), SchemaError> {
-    let mut first_seen: BTreeMap<&str, DuplicateTheoremLocation> = BTreeMap::new();
-    let mut collisions: BTreeMap<&str, DuplicateTheoremCollision> = BTreeMap::new();
-
-    for raw_doc in raw_docs {
-        let theorem = raw_doc.theorem.value.as_str();
-        let location = raw_doc.theorem_location();
-        let duplicate = DuplicateTheoremLocation {
-            location,
-            line: usize::try_from(location.line()).ok().unwrap_or(usize::MAX),
-            column: usize::try_from(location.column())
-                .ok()
-                .unwrap_or(usize::MAX),
-        };
-
-        if let Some(first) = first_seen.get(theorem) {
-            collisions
-                .entry(theorem)
-                .and_modify(|collision| collision.duplicates.push(duplicate))
-                .or_insert_with(|| DuplicateTheoremCollision {
-                    first: *first,
-                    duplicates: vec![duplicate],
-                });
-        } else {
-            first_seen.insert(theorem, duplicate);
-        }
-    }
-
-    collisions
-        .first_key_value()
-        .map_or(Ok(()), |(theorem, first_collision)| {
-            Err(build_duplicate_theorem_key_error(
-                source,
-                theorem,
-                first_collision,
-                &collisions,
-            ))
-        })
-}
-
-fn format_duplicate_theorem_key_summary(
-    source: &SourceId,
-    theorem: &str,
-    collision: &DuplicateTheoremCollision,
-) -> String {
-    let theorem_key = crate::mangle::theorem_key(source.as_str(), theorem);
-    let mut locations = Vec::with_capacity(collision.duplicates.len() + 1);
-    locations.push(render_duplicate_location(source, collision.first));
-    locations.extend(
-        collision
-            .duplicates
-            .iter()
-            .copied()
-            .map(|location| render_duplicate_location(source, location)),
-    );
-
-    format!(
-        "duplicate theorem key '{theorem_key}' appears at {}",
-        locations.join(", "),
-    )
-}
-
-fn render_duplicate_location(source: &SourceId, location: DuplicateTheoremLocation) -> String {
-    format!("{}:{}:{}", source.as_str(), location.line, location.column,)
-}
-
-fn attach_decode_failure_diagnostic(
-    error: RawDocDecodeError,
+/// Loads theorem documents from YAML, reading `Include` fragments through
+/// `resolver`.
+///
+/// A leading `Defaults` document supplies `Tags` and `Evidence` values that
+/// the following theorem documents inherit unless they override them, and a
+/// `Suite` document supplies default `Evidence` to the theorems it lists.
+/// `Suite` documents are otherwise skipped; use
+/// [`load_suites_with_includes`](super::load_suites_with_includes) to keep
+/// the grouping.
+/// Each document's fragments are merged depth-first ahead of the document's
+/// own `Forall`, `Assume`, and `Let` entries before semantic validation. A
+/// fragment reached twice through different includes is merged once.
+/// Diagnostics for merged `Assume` and `Let` entries name the fragment they
+/// came from.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_theorem_docs_with_source`], plus
+/// [`SchemaError::IncludeFailed`] when a fragment cannot be read or parsed,
+/// when includes form a cycle, or when merged sections declare the same name
+/// twice. A `Defaults` document that is not the first document in `input` is
+/// reported as [`SchemaError::Deserialize`], and a malformed `Suite` document
+/// as [`SchemaError::InvalidSuite`].
+pub fn load_theorem_docs_with_includes(
     source: &SourceId,
     input: &str,
-    raw_doc: &RawTheoremDoc,
-) -> SchemaError {
-    let reason = error.to_string();
-    let mut diagnostic = create_diagnostic(
-        SchemaDiagnosticCode::ValidationFailure,
-        source,
-        reason.clone(),
-        raw_doc.theorem_location(),
-    );
-    if let Some((line, column)) = locate_decode_failure(input, raw_doc, &error) {
-        diagnostic.location.line = line;
-        diagnostic.location.column = column;
-    }
-
-    SchemaError::ValidationFailed {
-        theorem: raw_doc.theorem.value.to_string(),
-        reason,
-        diagnostic: Some(Box::new(diagnostic)),
-        source: Some(Box::new(error)),
-    }
-}
-
-fn attach_validation_failure_diagnostic(
-    failure: ValidationFailure,
-    source: &SourceId,
-    raw_doc: &RawTheoremDoc,
-) -> SchemaError {
-    let location = failure.reason_kind().map_or_else(
-        || raw_doc.theorem_location(),
-        |reason| raw_doc.location_for_validation_reason(reason),
-    );
-    let diagnostic = create_diagnostic(
-        SchemaDiagnosticCode::ValidationFailure,
+    resolver: &dyn IncludeResolver,
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    let load = SourceLoad {
         source,
-        failure.reason().to_owned(),
-        location,
-    );
-    failure.into_schema_error(Some(diagnostic))
+        input,
+        resolver,
+        manifest: None,
+        options: &LoadOptions::default(),
+    };
+    load_source_documents(&load).map(|(_, loaded)| loaded.docs)
 }
 
-fn build_parse_diagnostic(
+/// Loads theorem documents like [`load_theorem_docs_with_includes`], then
+/// checks every `Let` and `Do` action call against `manifest`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_theorem_docs_with_includes`], plus
+/// [`SchemaError::ValidationFailed`] when a call names an action the
+/// manifest does not declare, omits a declared parameter, passes an
+/// undeclared one, or uses `must` on an action declared infallible. The
+/// diagnostic points at the call's `action` key.
+pub fn load_theorem_docs_with_manifest(
     source: &SourceId,
     input: &str,
-    error: &serde_saphyr::Error,
-    message: ErrorMessage<'_>,
-) -> Option<SchemaDiagnostic> {
-    let location = error.location()?;
-    let mut diagnostic = create_diagnostic(
-        SchemaDiagnosticCode::ParseFailure,
+    resolver: &dyn IncludeResolver,
+    manifest: &ActionManifest,
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    let load = SourceLoad {
         source,
-        first_line(message.as_str()),
-        location,
-    );
-
-    // `serde_saphyr` may report unknown-field deserialization failures at
-    // document-start (1:1). Re-anchor to the offending key when possible.
-    if should_reanchor_unknown_field(&diagnostic)
-        && let Some((line, column)) = locate_unknown_field(input, message)
-    {
-        diagnostic.location.line = line;
-        diagnostic.location.column = column;
-    }
-
-    Some(diagnostic)
-}
-
-const fn should_reanchor_unknown_field(diagnostic: &SchemaDiagnostic) -> bool {
-    diagnostic.location.line == 1 && diagnostic.location.column == 1
+        input,
+        resolver,
+        manifest: Some(manifest),
+        options: &LoadOptions::default(),
+    };
+    load_source_documents(&load).map(|(_, loaded)| loaded.docs)
 }
 
-fn locate_unknown_field(input: &str, message: ErrorMessage<'_>) -> Option<(usize, usize)> {
-    let field = unknown_field_name(message)?;
-
-    for (line_index, line) in input.lines().enumerate() {
-        if let Some(column) = mapping_key_column(line, field) {
-            return Some((line_index + 1, column));
-        }
-    }
-
-    None
+/// Loads every document in `load.input`, returning the raw `Suite`
+/// documents alongside the validated theorem documents and their warnings.
+/// When `load.manifest` is given, action calls are also checked against it.
+pub(crate) fn load_source_documents(
+    load: &SourceLoad<'_>,
+) -> Result<(Vec<RawSuite>, LoadedTheoremDocs), SchemaError> {
+    let (documents, unknown_keys) =
+        parse_documents(load.source, load.input, load.options.unknown_keys)?;
+    load_parsed_documents(load, documents, &unknown_keys)
 }
 
-fn unknown_field_name(message: ErrorMessage<'_>) -> Option<FieldName<'_>> {
-    let (_, tail) = message.as_str().split_once("unknown field `")?;
-    let (field, _) = tail.split_once('`')?;
-    Some(FieldName::new(field))
-}
-
-fn mapping_key_column(line: &str, field: FieldName<'_>) -> Option<usize> {
-    let trimmed = line.trim_start();
-    if trimmed.is_empty() || trimmed.starts_with('#') {
-        return None;
-    }
-
-    if is_mapping_key_for_field(trimmed, field) {
-        let leading = line.len() - trimmed.len();
-        return Some(leading + 1);
-    }
+/// Loads already parsed `documents` like [`load_source_documents`], given
+/// the top-level keys parsing skipped.
+pub(super) fn load_parsed_documents(
+    load: &SourceLoad<'_>,
+    documents: Vec<RawDocument>,
+    unknown_keys: &[Spanned<String>],
+) -> Result<(Vec<RawSuite>, LoadedTheoremDocs), SchemaError> {
+    let source = load.source;
+    let SourceDocuments {
+        suites,
+        theorems: raw_docs,
+    } = split_documents(source, documents)?;
+    check_duplicate_theorem_keys(source, &raw_docs)?;
+    check_theorem_dependencies(source, &raw_docs)?;
 
-    None
-}
+    let warnings = source_warnings(load, &raw_docs, unknown_keys);
+    let docs = raw_docs
+        .into_iter()
+        .map(|raw_doc| load.theorem_doc(raw_doc))
+        .collect::<Result<Vec<_>, _>>()?;
 
-fn is_plain_mapping_key(line: &str, field: FieldName<'_>) -> bool {
-    line.strip_prefix(field.as_str())
-        .is_some_and(|tail| tail.starts_with(':'))
-}
+    check_suite_requirements(source, &suites, &docs)?;
+    crate::collision::check_action_collisions(&docs)?;
 
-fn is_single_quoted_mapping_key(line: &str, field: FieldName<'_>) -> bool {
-    line.strip_prefix('\'')
-        .and_then(|tail| tail.strip_prefix(field.as_str()))
-        .is_some_and(|tail| tail.starts_with("':"))
+    Ok((suites, LoadedTheoremDocs { docs, warnings }))
 }
 
-fn is_double_quoted_mapping_key(line: &str, field: FieldName<'_>) -> bool {
-    line.strip_prefix('"')
-        .and_then(|tail| tail.strip_prefix(field.as_str()))
-        .is_some_and(|tail| tail.starts_with("\":"))
+/// The source a theorem document came from and how to finish loading it.
+pub(super) struct SourceLoad<'a> {
+    pub(super) source: &'a SourceId,
+    pub(super) input: &'a str,
+    pub(super) resolver: &'a dyn IncludeResolver,
+    pub(super) manifest: Option<&'a ActionManifest>,
+    pub(super) options: &'a LoadOptions,
 }
 
-fn is_mapping_key_for_field(line: &str, field: FieldName<'_>) -> bool {
-    is_plain_mapping_key(line, field)
-        || is_single_quoted_mapping_key(line, field)
-        || is_double_quoted_mapping_key(line, field)
+impl SourceLoad<'_> {
+    /// Merges `raw_doc`'s includes, then decodes and validates it. When a
+    /// manifest is given, action calls are also checked against it.
+    pub(super) fn theorem_doc(
+        &self,
+        mut raw_doc: RawTheoremDoc,
+    ) -> Result<TheoremDoc, SchemaError> {
+        check_canonical_keys(self.source, &raw_doc, self.options.aliases)?;
+        let provenance = resolve_includes(self.source, &mut raw_doc, self.resolver)?;
+        let converted = raw_doc.take_theorem_doc();
+        let context = DocumentContext {
+            source: self.source,
+            input: self.input,
+            raw_doc: &raw_doc,
+            provenance: &provenance,
+        };
+        let mut doc = converted.map_err(|decode_err| context.decode_failure(decode_err))?;
+        expand_type_aliases(&mut doc);
+        validate_theorem_doc(&doc).map_err(|failure| context.validation_failure(failure))?;
+        if let Some(declared) = self.manifest {
+            check_action_calls(&doc, declared).map_err(|failure| context.call_failure(failure))?;
+        }
+        doc.include = provenance.into_fragment_paths();
+        Ok(doc)
+    }
 }
 
 #[cfg(test)]
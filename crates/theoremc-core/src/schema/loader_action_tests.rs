@@ -10,6 +10,9 @@ fn action_signatures_parse_with_ordered_params_and_default_return() {
     let yaml = r"
 Theorem: HasActions
 About: Declares action signatures
+Forall:
+  account: crate::account::Account
+  amount: u64
 Actions:
   account.deposit:
     params:
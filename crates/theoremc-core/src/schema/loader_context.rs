@@ -0,0 +1,129 @@
+//! Diagnostics for failures found while decoding and checking one loaded
+//! theorem document.
+//!
+//! Failures in included or fixture-supplied entries point at the `Include`
+//! entry or fixture that brought them in, since their own spans belong to
+//! another source.
+
+use crate::actions::{CallFailure, CallSite};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::include::IncludeProvenance;
+use super::loader_decode_location::{locate_call_site, locate_decode_failure};
+use super::raw::{RawDocDecodeError, RawTheoremDoc};
+use super::source_id::SourceId;
+use super::validation_reason::{ValidationFailure, ValidationReasonKind};
+
+/// Source text, raw spans, and include provenance for one loaded document.
+pub(super) struct DocumentContext<'a> {
+    pub(super) source: &'a SourceId,
+    pub(super) input: &'a str,
+    pub(super) raw_doc: &'a RawTheoremDoc,
+    pub(super) provenance: &'a IncludeProvenance,
+}
+
+impl DocumentContext<'_> {
+    pub(super) fn decode_failure(&self, failure: RawDocDecodeError) -> SchemaError {
+        let error = match failure {
+            RawDocDecodeError::Expression(rejected) => {
+                return self.validation_failure(ValidationFailure::named(
+                    self.raw_doc.theorem.value.to_string(),
+                    rejected.reason,
+                    Some(rejected.kind),
+                ));
+            }
+            other => other,
+        };
+        // Included bindings have no span in this source, so point at the
+        // include entry that brought them in. Fixture bindings point at the
+        // fixture that declares them.
+        let name = error.let_binding_name();
+        let included = name.and_then(|binding| self.provenance.let_binding_origin(binding));
+        let fixture = name.and_then(|binding| self.raw_doc.fixture_origins.get(binding));
+        let reason = match (included, fixture) {
+            (Some(origin), _) => format!("{error} (included from '{}')", origin.path.as_str()),
+            (None, Some(origin)) => format!("{error} (from fixture '{}')", origin.fixture),
+            (None, None) => error.to_string(),
+        };
+        let location = match (included, fixture) {
+            (Some(origin), _) => origin.include_location,
+            (None, Some(origin)) => origin.location,
+            (None, None) => self.raw_doc.theorem_location(),
+        };
+        let mut diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ValidationFailure,
+            self.source,
+            reason.clone(),
+            location,
+        );
+        if included.is_none()
+            && fixture.is_none()
+            && let Some((line, column)) = locate_decode_failure(self.input, self.raw_doc, &error)
+        {
+            diagnostic.location.line = line;
+            diagnostic.location.column = column;
+        }
+
+        SchemaError::ValidationFailed {
+            theorem: self.raw_doc.theorem.value.to_string(),
+            reason,
+            diagnostic: Some(Box::new(diagnostic)),
+            source: Some(Box::new(error)),
+        }
+    }
+
+    pub(super) fn validation_failure(&self, mut failure: ValidationFailure) -> SchemaError {
+        let location = failure.reason_kind().map_or_else(
+            || self.raw_doc.theorem_location(),
+            |reason| self.raw_doc.location_for_validation_reason(reason),
+        );
+        // Spans of included entries point into the fragment, not this source.
+        let origin = failure
+            .reason_kind()
+            .and_then(|reason| self.provenance.origin_for_reason(reason));
+        if let Some(included) = origin {
+            failure.mark_included_from(&included.path);
+        }
+        let code = failure.reason_kind().map_or(
+            SchemaDiagnosticCode::ValidationFailure,
+            ValidationReasonKind::diagnostic_code,
+        );
+        let diagnostic = create_diagnostic(
+            code,
+            origin.map_or(self.source, |included| &included.path),
+            failure.reason().to_owned(),
+            location,
+        );
+        failure.into_schema_error(Some(diagnostic))
+    }
+
+    pub(super) fn call_failure(&self, failure: CallFailure) -> SchemaError {
+        let fixture = match &failure.site {
+            CallSite::Let(name) => self.raw_doc.fixture_origins.get(name),
+            CallSite::Do(_) | CallSite::Prove(_) => None,
+        };
+        let reason = match fixture {
+            Some(origin) => format!("{} (from fixture '{}')", failure.reason, origin.fixture),
+            None => failure.reason,
+        };
+        let mut diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ValidationFailure,
+            self.source,
+            reason.clone(),
+            fixture.map_or_else(|| self.raw_doc.theorem_location(), |origin| origin.location),
+        );
+        if fixture.is_none()
+            && let Some((line, column)) = locate_call_site(self.input, self.raw_doc, &failure.site)
+        {
+            diagnostic.location.line = line;
+            diagnostic.location.column = column;
+        }
+        SchemaError::ValidationFailed {
+            theorem: self.raw_doc.theorem.value.to_string(),
+            reason,
+            diagnostic: Some(Box::new(diagnostic)),
+            source: None,
+        }
+    }
+}
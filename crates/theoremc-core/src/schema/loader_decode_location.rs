@@ -20,6 +20,8 @@ impl<'a> YamlKey<'a> {
 }
 
 use super::raw::{RawDocDecodeError, RawTheoremDoc};
+use super::validation_reason::{IndexedValidationField, ValidationReasonKind};
+use crate::actions::CallSite;
 
 pub(crate) fn locate_decode_failure(
     input: &str,
@@ -27,8 +29,7 @@ pub(crate) fn locate_decode_failure(
     error: &RawDocDecodeError,
 ) -> Option<(usize, usize)> {
     let argument_name = terminal_argument_name(YamlKey::new(error.param()));
-    let start_line = usize::try_from(raw_doc.theorem_location().line()).ok()?;
-    let start_index = start_line.saturating_sub(1);
+    let start_index = document_start_index(raw_doc)?;
 
     if let Some(binding_name) = error.let_binding_name() {
         return locate_let_binding_argument(
@@ -45,6 +46,40 @@ pub(crate) fn locate_decode_failure(
     None
 }
 
+/// Locates the `action` key of a call rejected by the action manifest, or
+/// the `assert` expression of a rejected `Prove` entry.
+pub(crate) fn locate_call_site(
+    input: &str,
+    raw_doc: &RawTheoremDoc,
+    site: &CallSite,
+) -> Option<(usize, usize)> {
+    let start_index = document_start_index(raw_doc)?;
+    let action = YamlKey::new("action");
+    match site {
+        CallSite::Let(name) => {
+            locate_let_binding_argument(input, start_index, YamlKey::new(name), action)
+        }
+        CallSite::Do(step_index) => {
+            locate_do_step_argument(input, start_index, *step_index, action)
+        }
+        CallSite::Prove(index) => {
+            let location = raw_doc.location_for_validation_reason(ValidationReasonKind::Prove {
+                index: *index,
+                field: IndexedValidationField::Value,
+            });
+            Some((
+                usize::try_from(location.line()).ok()?,
+                usize::try_from(location.column()).ok()?,
+            ))
+        }
+    }
+}
+
+fn document_start_index(raw_doc: &RawTheoremDoc) -> Option<usize> {
+    let start_line = usize::try_from(raw_doc.theorem_location().line()).ok()?;
+    Some(start_line.saturating_sub(1))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum BindingLineOutcome {
     Found(usize, usize),
@@ -139,6 +174,7 @@ fn locate_do_step_argument(
     let mut is_in_do = false;
     let mut current_step = 0;
     let mut step_indent = None;
+    let mut list_indent = None;
 
     for (index, line) in document_lines(input, start_index) {
         if !is_in_do {
@@ -148,6 +184,9 @@ fn locate_do_step_argument(
         if is_top_level_section(line) {
             break;
         }
+        if step_indent.is_none() && is_nested_list_item(line, &mut list_indent) {
+            continue;
+        }
 
         let scope = update_step_scope(line, step_index, &mut current_step, &mut step_indent);
         if scope == StepScope::PastSelected {
@@ -186,7 +225,8 @@ fn update_step_scope(
     current_step: &mut usize,
     selected_indent: &mut Option<usize>,
 ) -> StepScope {
-    if !is_list_item(line) {
+    let is_nested_in_selected = selected_indent.is_some_and(|indent| indent_width(line) > indent);
+    if !is_list_item(line) || is_nested_in_selected {
         return selected_indent.map_or(StepScope::OutsideSelected, |_| StepScope::InsideSelected);
     }
 
@@ -201,6 +241,17 @@ fn update_step_scope(
     }
 }
 
+/// Returns `true` for a list item indented deeper than the `Do` list, such as
+/// a step nested in a `maybe` block, recording the `Do` list indent from the
+/// first item seen.
+fn is_nested_list_item(line: &str, list_indent: &mut Option<usize>) -> bool {
+    if !is_list_item(line) {
+        return false;
+    }
+    let indent = indent_width(line);
+    indent > *list_indent.get_or_insert(indent)
+}
+
 fn document_lines(input: &str, start_index: usize) -> impl Iterator<Item = (usize, &str)> {
     input
         .lines()
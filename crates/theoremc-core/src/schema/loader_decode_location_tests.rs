@@ -176,3 +176,22 @@ fn step_scope_tracks_selected_and_subsequent_list_items() {
         StepScope::PastSelected
     );
 }
+
+#[test]
+fn do_step_scan_counts_only_top_level_steps() {
+    let input = concat!(
+        "Theorem: Example\n",
+        "Do:\n",
+        "  - maybe:\n",
+        "      because: nested steps\n",
+        "      do:\n",
+        "        - call:\n",
+        "            action: account.read\n",
+        "  - must:\n",
+        "      action: account.write\n",
+    );
+    let action = YamlKey::new("action");
+
+    assert_eq!(locate_do_step_argument(input, 0, 1, action), Some((7, 13)));
+    assert_eq!(locate_do_step_argument(input, 0, 2, action), Some((9, 7)));
+}
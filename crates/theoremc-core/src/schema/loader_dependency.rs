@@ -0,0 +1,50 @@
+//! `DependsOn` checks for theorem documents loaded from one source.
+
+use serde_saphyr::Location;
+
+use super::diagnostic::create_diagnostic;
+use super::error::SchemaError;
+use super::raw::RawTheoremDoc;
+use super::source_id::SourceId;
+use crate::dependency::ResolvedDependencies;
+
+/// Checks that every `DependsOn` entry names a theorem in `raw_docs` and that
+/// the declared dependencies are acyclic.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::ValidationFailed`] pointing at the offending
+/// `DependsOn` entry, with a [`SchemaDiagnosticCode::Cycle`] diagnostic when
+/// the dependencies form a cycle.
+pub(crate) fn check_theorem_dependencies(
+    source: &SourceId,
+    raw_docs: &[RawTheoremDoc],
+) -> Result<(), SchemaError> {
+    let nodes: Vec<(&str, Vec<&str>)> = raw_docs
+        .iter()
+        .map(|raw_doc| {
+            let depends_on = raw_doc
+                .depends_on
+                .iter()
+                .map(|dependency| dependency.value.as_str())
+                .collect();
+            (raw_doc.theorem.value.as_str(), depends_on)
+        })
+        .collect();
+    let Err(issue) = ResolvedDependencies::from_nodes(&nodes) else {
+        return Ok(());
+    };
+
+    let location = raw_docs
+        .iter()
+        .find(|raw_doc| raw_doc.theorem.value.as_str() == issue.theorem)
+        .and_then(|raw_doc| raw_doc.depends_on.get(issue.entry))
+        .map_or(Location::UNKNOWN, |dependency| dependency.referenced);
+    let diagnostic = create_diagnostic(issue.code, source, issue.reason.clone(), location);
+    Err(SchemaError::ValidationFailed {
+        theorem: issue.theorem,
+        reason: issue.reason,
+        diagnostic: Some(Box::new(diagnostic)),
+        source: None,
+    })
+}
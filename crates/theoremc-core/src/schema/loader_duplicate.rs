@@ -0,0 +1,166 @@
+//! Duplicate theorem-key detection for multi-document sources.
+//!
+//! Every document in one source shares the same path component `P`, so two
+//! documents declaring the same theorem name `T` would collide on the theorem
+//! key `{P}#{T}`. Collisions are reported with every duplicate site in
+//! deterministic theorem-key order.
+
+use std::collections::BTreeMap;
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::raw::RawTheoremDoc;
+use super::source_id::SourceId;
+
+#[derive(Debug, Clone, Copy)]
+struct DuplicateTheoremLocation {
+    location: serde_saphyr::Location,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone)]
+struct DuplicateTheoremCollision {
+    first: DuplicateTheoremLocation,
+    duplicates: Vec<DuplicateTheoremLocation>,
+}
+
+fn build_duplicate_theorem_key_error(
+    source: &SourceId,
+    theorem: &str,
+    first_collision: &DuplicateTheoremCollision,
+    collisions: &BTreeMap<&str, DuplicateTheoremCollision>,
+) -> SchemaError {
+    let theorem_key = crate::mangle::theorem_key(source.as_str(), theorem);
+    let first_diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        format_duplicate_theorem_key_summary(source, theorem, first_collision),
+        first_collision
+            .duplicates
+            .first()
+            .copied()
+            .unwrap_or(first_collision.first)
+            .location,
+    );
+    let mut collision_diagnostics = Vec::with_capacity(collisions.len());
+    collision_diagnostics.push(first_diagnostic.clone());
+    collision_diagnostics.extend(collisions.iter().skip(1).map(
+        |(other_theorem, other_collision)| {
+            create_diagnostic(
+                SchemaDiagnosticCode::ValidationFailure,
+                source,
+                format_duplicate_theorem_key_summary(source, other_theorem, other_collision),
+                other_collision
+                    .duplicates
+                    .first()
+                    .copied()
+                    .unwrap_or(other_collision.first)
+                    .location,
+            )
+        },
+    ));
+    SchemaError::DuplicateTheoremKey {
+        theorem_key,
+        collisions: collision_diagnostics,
+        diagnostic: Some(first_diagnostic),
+    }
+}
+
+/// Builds the error for a theorem `theorem` first declared at `first` and
+/// declared again at `duplicate`. Each location is paired with the number of
+/// lines before the document it was parsed from, which the rendered
+/// positions add; the diagnostic stays at the duplicate's own location.
+pub(super) fn duplicate_theorem_key(
+    source: &SourceId,
+    theorem: &str,
+    first: (serde_saphyr::Location, usize),
+    duplicate: (serde_saphyr::Location, usize),
+) -> SchemaError {
+    let collision = DuplicateTheoremCollision {
+        first: duplicate_location(first.0).below(first.1),
+        duplicates: vec![duplicate_location(duplicate.0).below(duplicate.1)],
+    };
+    let collisions = BTreeMap::from([(theorem, collision.clone())]);
+    build_duplicate_theorem_key_error(source, theorem, &collision, &collisions)
+}
+
+impl DuplicateTheoremLocation {
+    /// Moves the rendered position `lines` lines further down.
+    const fn below(mut self, lines: usize) -> Self {
+        self.line = self.line.saturating_add(lines);
+        self
+    }
+}
+
+fn duplicate_location(location: serde_saphyr::Location) -> DuplicateTheoremLocation {
+    DuplicateTheoremLocation {
+        location,
+        line: usize::try_from(location.line()).ok().unwrap_or(usize::MAX),
+        column: usize::try_from(location.column())
+            .ok()
+            .unwrap_or(usize::MAX),
+    }
+}
+
+pub(super) fn check_duplicate_theorem_keys(
+    source: &SourceId,
+    raw_docs: &[RawTheoremDoc],
+) -> Result<(), SchemaError> {
+    let mut first_seen: BTreeMap<&str, DuplicateTheoremLocation> = BTreeMap::new();
+    let mut collisions: BTreeMap<&str, DuplicateTheoremCollision> = BTreeMap::new();
+
+    for raw_doc in raw_docs {
+        let theorem = raw_doc.theorem.value.as_str();
+        let duplicate = duplicate_location(raw_doc.theorem_location());
+
+        if let Some(first) = first_seen.get(theorem) {
+            collisions
+                .entry(theorem)
+                .and_modify(|collision| collision.duplicates.push(duplicate))
+                .or_insert_with(|| DuplicateTheoremCollision {
+                    first: *first,
+                    duplicates: vec![duplicate],
+                });
+        } else {
+            first_seen.insert(theorem, duplicate);
+        }
+    }
+
+    collisions
+        .first_key_value()
+        .map_or(Ok(()), |(theorem, first_collision)| {
+            Err(build_duplicate_theorem_key_error(
+                source,
+                theorem,
+                first_collision,
+                &collisions,
+            ))
+        })
+}
+
+fn format_duplicate_theorem_key_summary(
+    source: &SourceId,
+    theorem: &str,
+    collision: &DuplicateTheoremCollision,
+) -> String {
+    let theorem_key = crate::mangle::theorem_key(source.as_str(), theorem);
+    let mut locations = Vec::with_capacity(collision.duplicates.len() + 1);
+    locations.push(render_duplicate_location(source, collision.first));
+    locations.extend(
+        collision
+            .duplicates
+            .iter()
+            .copied()
+            .map(|location| render_duplicate_location(source, location)),
+    );
+
+    format!(
+        "duplicate theorem key '{theorem_key}' appears at {}",
+        locations.join(", "),
+    )
+}
+
+fn render_duplicate_location(source: &SourceId, location: DuplicateTheoremLocation) -> String {
+    format!("{}:{}:{}", source.as_str(), location.line, location.column,)
+}
@@ -0,0 +1,88 @@
+//! JSON front-end for theorem documents.
+//!
+//! JSON is a subset of YAML 1.2, so a well-formed JSON source is read by the
+//! YAML loader itself and every schema and validation diagnostic points into
+//! the JSON text as written. The source is first checked by a JSON parser, so
+//! malformed input is reported as a JSON syntax error rather than as a YAML
+//! one.
+
+use serde::de::IgnoredAny;
+
+use super::error::SchemaError;
+use super::include::NoIncludes;
+use super::load_options::LoadOptions;
+use super::loader::{SourceLoad, load_parsed_documents};
+use super::loader_parse_failure::deserialize_failure;
+use super::raw_document::RawDocument;
+use super::source_format::{SourceFormat, syntax_error};
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+use super::yaml_anchor::parse_options;
+
+/// Loads theorem documents from a JSON source.
+///
+/// The source is one document object, or an array of document objects read
+/// like the documents of a YAML stream, so `Defaults`, `Suite`, `Shared`, and
+/// `Fixtures` documents may accompany the theorems. Documents use the same keys and
+/// checks as YAML documents.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::Syntax`] when `input` is not well-formed JSON, and
+/// otherwise the same errors as
+/// [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source),
+/// located in the JSON text.
+///
+/// # Examples
+///
+/// ```
+/// use theoremc_core::schema::{SourceId, load_theorem_docs_json};
+///
+/// let json = r#"{
+///   "Theorem": "T",
+///   "About": "Example",
+///   "Prove": [{ "assert": "true", "because": "trivially true" }],
+///   "Evidence": {
+///     "kani": { "unwind": 1, "expect": "SUCCESS", "allow_vacuous": true, "vacuity_because": "none" }
+///   }
+/// }"#;
+/// let docs = load_theorem_docs_json(&SourceId::new("t.theorem.json"), json).unwrap();
+/// assert_eq!(docs[0].theorem.as_str(), "T");
+/// ```
+pub fn load_theorem_docs_json(
+    source: &SourceId,
+    input: &str,
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    serde_json::from_str::<IgnoredAny>(input).map_err(|error| {
+        let position = (error.line(), error.column());
+        syntax_error(SourceFormat::Json, source, error.to_string(), position)
+    })?;
+    let documents = parse_documents(source, input)?;
+    let load = SourceLoad {
+        source,
+        input,
+        resolver: &NoIncludes,
+        manifest: None,
+        options: &LoadOptions::default(),
+    };
+    load_parsed_documents(&load, documents, &[]).map(|(_, loaded)| loaded.docs)
+}
+
+/// Parses a document object, or an array of them, as YAML.
+pub(super) fn parse_documents(
+    source: &SourceId,
+    input: &str,
+) -> Result<Vec<RawDocument>, SchemaError> {
+    let failure = |error: serde_saphyr::Error| deserialize_failure(source, input, &error);
+    if input.trim_start().starts_with('[') {
+        serde_saphyr::from_str_with_options(input, parse_options()).map_err(failure)
+    } else {
+        serde_saphyr::from_str_with_options(input, parse_options())
+            .map(|document| vec![document])
+            .map_err(failure)
+    }
+}
+
+#[cfg(test)]
+#[path = "loader_json_tests.rs"]
+mod tests;
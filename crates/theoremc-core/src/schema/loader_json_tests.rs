@@ -0,0 +1,97 @@
+//! Unit tests for the JSON front-end.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SchemaDiagnosticCode, Symbol};
+
+const THEOREM: &str = concat!(
+    "{\n",
+    "  \"Theorem\": \"T\",\n",
+    "  \"About\": \"Example\",\n",
+    "  \"Prove\": [{ \"assert\": \"true\", \"because\": \"trivially true\" }],\n",
+    "  \"Evidence\": {\n",
+    "    \"kani\": { \"unwind\": 1, \"expect\": \"SUCCESS\", \"allow_vacuous\": true, \"vacuity_because\": \"none\" }\n",
+    "  }\n",
+    "}\n",
+);
+
+fn load(input: &str) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_json(&SourceId::new("t.theorem.json"), input)
+}
+
+fn located(error: &SchemaError) -> (SchemaDiagnosticCode, usize, usize) {
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.location.source, "t.theorem.json");
+    (
+        diagnostic.code,
+        diagnostic.location.line,
+        diagnostic.location.column,
+    )
+}
+
+#[test]
+fn loads_a_theorem_object() {
+    let docs = load(THEOREM).expect("JSON theorem should load");
+    let names: Vec<&str> = docs.iter().map(|doc| doc.theorem.as_str()).collect();
+
+    assert_eq!(names, ["T"]);
+}
+
+#[test]
+fn loads_an_array_of_documents() {
+    let input = format!(
+        "[\n  {{ \"Defaults\": {{ \"Tags\": [\"smoke\"] }} }},\n  {}]\n",
+        THEOREM.trim_end()
+    );
+    let docs = load(&input).expect("JSON documents should load");
+
+    assert_eq!(docs.len(), 1);
+    assert_eq!(
+        docs.first().map(|doc| doc.tags.clone()),
+        Some(vec![Symbol::new("smoke")])
+    );
+}
+
+#[rstest]
+#[case::trailing_comma("{ \"Theorem\": \"T\", }", (1, 19))]
+#[case::unterminated_string("{\n  \"Theorem\": \"T\n}", (2, 15))]
+fn syntax_errors_are_reported_as_json(#[case] input: &str, #[case] position: (usize, usize)) {
+    let error = load(input).expect_err("JSON is malformed");
+
+    assert!(matches!(
+        error,
+        SchemaError::Syntax {
+            format: SourceFormat::Json,
+            ..
+        }
+    ));
+    assert!(
+        error.to_string().starts_with("JSON syntax error: "),
+        "{error}"
+    );
+    let (code, line, column) = located(&error);
+    assert_eq!(
+        (code, (line, column)),
+        (SchemaDiagnosticCode::ParseFailure, position)
+    );
+}
+
+#[test]
+fn schema_errors_are_located_in_the_json_text() {
+    let input = THEOREM.replace("\"About\"", "\"Abuot\"");
+    let error = load(&input).expect_err("unknown section should be rejected");
+    let (code, line, _) = located(&error);
+
+    assert_eq!((code, line), (SchemaDiagnosticCode::ParseFailure, 3));
+}
+
+#[test]
+fn validation_errors_are_located_in_the_json_text() {
+    let input = THEOREM.replace("\"trivially true\"", "\"  \"");
+    let error = load(&input).expect_err("blank reason should be rejected");
+    let (code, line, _) = located(&error);
+
+    assert_eq!((code, line), (SchemaDiagnosticCode::ValidationFailure, 4));
+}
@@ -0,0 +1,147 @@
+//! Theorem loading tuned by [`LoadOptions`].
+//!
+//! [`load_theorem_docs_with_options`] parses sources leniently when the
+//! options skip unknown keys, and collects the non-fatal diagnostics the
+//! options ask for: deprecated section key aliases and skipped keys.
+
+use serde_saphyr::Spanned;
+
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::include::IncludeResolver;
+use super::load_options::{LoadOptions, UnknownKeyPolicy};
+use super::loader::{SourceLoad, load_source_documents};
+use super::loader_parse_failure::deserialize_failure;
+use super::raw::RawTheoremDoc;
+use super::raw_document::{LenientDocument, RawDocument};
+use super::section_key::alias_warnings;
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+use super::yaml_anchor::parse_options;
+
+/// Theorem documents loaded by [`load_theorem_docs_with_options`], with the
+/// warnings raised while loading them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadedTheoremDocs {
+    /// Validated theorem documents in source order.
+    pub docs: Vec<TheoremDoc>,
+    /// Non-fatal diagnostics, such as deprecated section key aliases, in
+    /// source order.
+    pub warnings: Vec<SchemaDiagnostic>,
+}
+
+/// Loads theorem documents like [`load_theorem_docs_with_includes`](super::load_theorem_docs_with_includes),
+/// applying `options`.
+///
+/// With [`AliasPolicy::Warn`](super::AliasPolicy::Warn), each lowercase
+/// section key alias is reported as a
+/// [`SchemaDiagnosticCode::DeprecatedAlias`] warning located at the key;
+/// [`migrate::fix_section_aliases`](crate::migrate::fix_section_aliases)
+/// rewrites them in place. With [`UnknownKeyPolicy::Warn`] or
+/// [`UnknownKeyPolicy::Ignore`], keys a document does not declare are
+/// skipped at any depth, so sources written for a newer schema still load;
+/// `Warn` reports each as a [`SchemaDiagnosticCode::UnknownKey`] warning.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_theorem_docs_with_includes`](super::load_theorem_docs_with_includes), plus
+/// [`SchemaError::ValidationFailed`] at the first alias when `options`
+/// denies aliases.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::{
+///         AliasPolicy, IncludeResolver, LoadOptions, SourceId, load_theorem_docs_with_options,
+///     };
+///
+///     struct NoFragments;
+///
+///     impl IncludeResolver for NoFragments {
+///         fn read_include(&self, _path: &str) -> std::io::Result<String> {
+///             Err(std::io::ErrorKind::NotFound.into())
+///         }
+///     }
+///
+///     let yaml = r#"
+///     Theorem: T
+///     About: Example
+///     prove:
+///       - assert: "true"
+///         because: trivially true
+///     Evidence:
+///       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+///     "#;
+///     let options = LoadOptions {
+///         aliases: AliasPolicy::Warn,
+///         ..LoadOptions::default()
+///     };
+///     let loaded =
+///         load_theorem_docs_with_options(&SourceId::new("t.theorem"), yaml, &NoFragments, &options)
+///             .unwrap();
+///     assert_eq!(loaded.docs.len(), 1);
+///     assert_eq!(loaded.warnings[0].location.line, 4);
+pub fn load_theorem_docs_with_options(
+    source: &SourceId,
+    input: &str,
+    resolver: &dyn IncludeResolver,
+    options: &LoadOptions,
+) -> Result<LoadedTheoremDocs, SchemaError> {
+    let load = SourceLoad {
+        source,
+        input,
+        resolver,
+        manifest: None,
+        options,
+    };
+    load_source_documents(&load).map(|(_, loaded)| loaded)
+}
+
+/// Parses every document in `input`, skipping undeclared top-level keys
+/// unless `unknown_keys` denies them, and returns the keys it skipped.
+pub(super) fn parse_documents(
+    source: &SourceId,
+    input: &str,
+    unknown_keys: UnknownKeyPolicy,
+) -> Result<(Vec<RawDocument>, Vec<Spanned<String>>), SchemaError> {
+    let failure = |error: serde_saphyr::Error| deserialize_failure(source, input, &error);
+    if unknown_keys == UnknownKeyPolicy::Deny {
+        let documents =
+            serde_saphyr::from_multiple_with_options(input, parse_options()).map_err(failure)?;
+        return Ok((documents, Vec::new()));
+    }
+    let lenient: Vec<LenientDocument> =
+        serde_saphyr::from_multiple_with_options(input, parse_options()).map_err(failure)?;
+    let (documents, skipped): (Vec<_>, Vec<_>) = lenient
+        .into_iter()
+        .map(|parsed| (parsed.document, parsed.unknown_keys))
+        .unzip();
+    Ok((documents, skipped.concat()))
+}
+
+/// Collects the warnings `load.options` asks for, in source order.
+pub(super) fn source_warnings(
+    load: &SourceLoad<'_>,
+    raw_docs: &[RawTheoremDoc],
+    unknown_keys: &[Spanned<String>],
+) -> Vec<SchemaDiagnostic> {
+    let mut warnings: Vec<SchemaDiagnostic> = raw_docs
+        .iter()
+        .flat_map(|raw_doc| alias_warnings(load.source, raw_doc, load.options.aliases))
+        .collect();
+    if load.options.unknown_keys == UnknownKeyPolicy::Warn {
+        warnings.extend(unknown_keys.iter().map(|key| {
+            create_diagnostic(
+                SchemaDiagnosticCode::UnknownKey,
+                load.source,
+                format!("unknown key '{}' is ignored", key.value),
+                key.referenced,
+            )
+        }));
+    }
+    warnings.sort_by_key(|warning| (warning.location.line, warning.location.column));
+    warnings
+}
+
+#[cfg(test)]
+#[path = "loader_options_tests.rs"]
+mod tests;
@@ -0,0 +1,267 @@
+//! Unit tests for loader options controlling section key aliases and
+//! unknown keys.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::include::NoIncludes;
+use crate::schema::{AliasPolicy, UnknownKeyPolicy};
+
+const ALIASED: &str = concat!(
+    "theorem: T\n",
+    "About: Example\n",
+    "prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn load_with(input: &str, aliases: AliasPolicy) -> Result<LoadedTheoremDocs, SchemaError> {
+    load_with_options(
+        input,
+        &LoadOptions {
+            aliases,
+            ..LoadOptions::default()
+        },
+    )
+}
+
+fn load_with_options(input: &str, options: &LoadOptions) -> Result<LoadedTheoremDocs, SchemaError> {
+    load_theorem_docs_with_options(&SourceId::new("t.theorem"), input, &NoIncludes, options)
+}
+
+fn load_unknown(
+    input: &str,
+    unknown_keys: UnknownKeyPolicy,
+) -> Result<LoadedTheoremDocs, SchemaError> {
+    load_with_options(
+        input,
+        &LoadOptions {
+            unknown_keys,
+            ..LoadOptions::default()
+        },
+    )
+}
+
+#[test]
+fn aliases_are_allowed_silently_by_default() {
+    let loaded = load_with(ALIASED, AliasPolicy::default()).expect("source should load");
+
+    assert_eq!(loaded.docs.len(), 1);
+    assert!(loaded.warnings.is_empty());
+}
+
+#[test]
+fn warn_reports_each_alias_at_its_key() {
+    let loaded = load_with(ALIASED, AliasPolicy::Warn).expect("source should load");
+
+    let rendered: Vec<String> = loaded
+        .warnings
+        .iter()
+        .map(SchemaDiagnostic::render)
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "schema.deprecated_alias | t.theorem:1:1 | section key 'theorem' is deprecated; write \
+             'Theorem' instead",
+            "schema.deprecated_alias | t.theorem:3:1 | section key 'prove' is deprecated; write \
+             'Prove' instead",
+        ]
+    );
+    assert_eq!(loaded.docs.len(), 1);
+}
+
+#[test]
+fn deny_rejects_the_first_alias() {
+    let result = load_with(ALIASED, AliasPolicy::Deny);
+
+    let Err(SchemaError::ValidationFailed {
+        reason, diagnostic, ..
+    }) = result
+    else {
+        panic!("expected a validation failure, got {result:?}");
+    };
+    assert_eq!(
+        reason,
+        "this loader requires canonical section keys; write 'Theorem' instead of 'theorem'"
+    );
+    let location = diagnostic.map(|failure| failure.location);
+    assert_eq!(location.map(|at| (at.line, at.column)), Some((1, 1)));
+}
+
+#[rstest]
+#[case::allow(AliasPolicy::Allow)]
+#[case::warn(AliasPolicy::Warn)]
+#[case::deny(AliasPolicy::Deny)]
+fn canonical_sources_load_under_every_policy(#[case] aliases: AliasPolicy) {
+    let canonical = ALIASED
+        .replace("theorem:", "Theorem:")
+        .replace("prove:", "Prove:");
+
+    let loaded = load_with(&canonical, aliases).expect("source should load");
+
+    assert!(loaded.warnings.is_empty());
+}
+
+#[test]
+fn schema_version_two_rejects_aliases_whatever_the_policy() {
+    let input = format!("Schema: 2\n{ALIASED}");
+
+    let result = load_with(&input, AliasPolicy::Warn);
+
+    assert!(
+        matches!(&result, Err(SchemaError::ValidationFailed { reason, .. })
+            if reason.starts_with("Schema version 2 requires canonical section keys")),
+        "{result:?}"
+    );
+}
+
+const FUTURE: &str = concat!(
+    "Horizon: 3\n",
+    "Theorem: T\n",
+    "About: Example\n",
+    "Prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "Outlook:\n",
+    "  depth: 2\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+#[test]
+fn unknown_keys_are_denied_by_default() {
+    let result = load_with_options(FUTURE, &LoadOptions::default());
+
+    assert!(
+        matches!(&result, Err(SchemaError::Deserialize { message, .. })
+            if message.contains("unknown field `Horizon`")),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn warn_skips_unknown_keys_and_reports_each() {
+    let loaded = load_unknown(FUTURE, UnknownKeyPolicy::Warn).expect("source should load");
+
+    let rendered: Vec<String> = loaded
+        .warnings
+        .iter()
+        .map(SchemaDiagnostic::render)
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "schema.unknown_key | t.theorem:1:1 | unknown key 'Horizon' is ignored",
+            "schema.unknown_key | t.theorem:7:1 | unknown key 'Outlook' is ignored",
+        ]
+    );
+    let doc = loaded.docs.first().expect("source should hold a theorem");
+    assert_eq!(doc.theorem.as_str(), "T");
+    assert_eq!(doc.prove.len(), 1);
+}
+
+#[test]
+fn ignore_skips_unknown_keys_silently() {
+    let loaded = load_unknown(FUTURE, UnknownKeyPolicy::Ignore).expect("source should load");
+
+    assert_eq!(loaded.docs.len(), 1);
+    assert!(loaded.warnings.is_empty());
+}
+
+/// Returns [`FUTURE`] without its unknown keys.
+fn canonical() -> String {
+    FUTURE
+        .replace("Horizon: 3\n", "")
+        .replace("Outlook:\n  depth: 2\n", "")
+}
+
+#[test]
+fn unknown_keys_in_suite_documents_are_skipped() {
+    let input = format!(
+        "Suite: S\nAbout: Grouped\nOwner: team\nTheorems: [T]\n---\n{}",
+        canonical()
+    );
+
+    let loaded = load_unknown(&input, UnknownKeyPolicy::Warn).expect("source should load");
+
+    let keys: Vec<(&str, usize)> = loaded
+        .warnings
+        .iter()
+        .map(|warning| (warning.message.as_str(), warning.location.line))
+        .collect();
+    assert_eq!(keys, [("unknown key 'Owner' is ignored", 3)]);
+}
+
+#[rstest]
+#[case::prove_entry(
+    "    because: trivially true\n",
+    "    because: trivially true\n    weight: 2\n",
+    "weight",
+    6
+)]
+#[case::evidence("Evidence:\n", "Evidence:\n  lean: { depth: 1 }\n", "lean", 7)]
+#[case::kani(
+    "vacuity_because: none }",
+    "vacuity_because: none, retries: 2 }",
+    "retries",
+    7
+)]
+#[case::do_step(
+    "Prove:\n",
+    concat!(
+        "Actions:\n  ledger.deposit: { params: {} }\n",
+        "Do:\n  - call: { action: ledger.deposit, args: {} }\n    retries: 3\n",
+        "Prove:\n",
+    ),
+    "retries",
+    7
+)]
+fn nested_unknown_keys_follow_the_policy(
+    #[case] anchor: &str,
+    #[case] replacement: &str,
+    #[case] key: &str,
+    #[case] line: usize,
+) {
+    let input = canonical().replacen(anchor, replacement, 1);
+
+    let denied = load_unknown(&input, UnknownKeyPolicy::Deny);
+    let warned = load_unknown(&input, UnknownKeyPolicy::Warn).expect("source should load");
+
+    assert!(
+        matches!(&denied, Err(SchemaError::Deserialize { message, .. })
+            if message.contains(&format!("unknown field `{key}`"))),
+        "{denied:?}"
+    );
+    let warnings: Vec<(&str, usize)> = warned
+        .warnings
+        .iter()
+        .map(|warning| (warning.message.as_str(), warning.location.line))
+        .collect();
+    assert_eq!(
+        warnings,
+        [(format!("unknown key '{key}' is ignored").as_str(), line)]
+    );
+    assert_eq!(warned.docs.len(), 1);
+}
+
+#[test]
+fn warnings_are_reported_in_source_order() {
+    let input = FUTURE.replace("Prove:", "prove:");
+    let options = LoadOptions {
+        aliases: AliasPolicy::Warn,
+        unknown_keys: UnknownKeyPolicy::Warn,
+    };
+
+    let loaded = load_with_options(&input, &options).expect("source should load");
+
+    let lines: Vec<usize> = loaded
+        .warnings
+        .iter()
+        .map(|warning| warning.location.line)
+        .collect();
+    assert_eq!(lines, [1, 4, 7]);
+}
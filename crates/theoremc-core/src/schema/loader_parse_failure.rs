@@ -0,0 +1,117 @@
+//! Diagnostics for YAML parse and schema-mismatch failures.
+//!
+//! `serde_saphyr` may report an unknown field at the start of the document,
+//! so the diagnostic is re-anchored at the offending key when the source
+//! spells it on a line of its own.
+
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, create_diagnostic, first_line};
+use super::error::SchemaError;
+use super::loader_message::{ErrorMessage, FieldName};
+use super::source_id::SourceId;
+use super::yaml_anchor::ReuseFailure;
+
+/// Converts a YAML parse or schema-mismatch error into
+/// [`SchemaError::Deserialize`] with a diagnostic anchored in `input`.
+pub(crate) fn deserialize_failure(
+    source: &SourceId,
+    input: &str,
+    error: &serde_saphyr::Error,
+) -> SchemaError {
+    if let Some(reuse) = ReuseFailure::of(error, input) {
+        return SchemaError::Deserialize {
+            message: reuse.message(),
+            diagnostic: Some(reuse.diagnostic(source)),
+        };
+    }
+    let message = error.to_string();
+    let diagnostic = build_parse_diagnostic(source, input, error, ErrorMessage::new(&message));
+    SchemaError::Deserialize {
+        message,
+        diagnostic,
+    }
+}
+
+fn build_parse_diagnostic(
+    source: &SourceId,
+    input: &str,
+    error: &serde_saphyr::Error,
+    message: ErrorMessage<'_>,
+) -> Option<SchemaDiagnostic> {
+    let location = error.location()?;
+    let mut diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ParseFailure,
+        source,
+        first_line(message.as_str()),
+        location,
+    );
+
+    // `serde_saphyr` may report unknown-field deserialization failures at
+    // document-start (1:1). Re-anchor to the offending key when possible.
+    if should_reanchor_unknown_field(&diagnostic)
+        && let Some((line, column)) = locate_unknown_field(input, message)
+    {
+        diagnostic.location.line = line;
+        diagnostic.location.column = column;
+    }
+
+    Some(diagnostic)
+}
+
+const fn should_reanchor_unknown_field(diagnostic: &SchemaDiagnostic) -> bool {
+    diagnostic.location.line == 1 && diagnostic.location.column == 1
+}
+
+fn locate_unknown_field(input: &str, message: ErrorMessage<'_>) -> Option<(usize, usize)> {
+    let field = unknown_field_name(message)?;
+
+    for (line_index, line) in input.lines().enumerate() {
+        if let Some(column) = mapping_key_column(line, field) {
+            return Some((line_index + 1, column));
+        }
+    }
+
+    None
+}
+
+fn unknown_field_name(message: ErrorMessage<'_>) -> Option<FieldName<'_>> {
+    let (_, tail) = message.as_str().split_once("unknown field `")?;
+    let (field, _) = tail.split_once('`')?;
+    Some(FieldName::new(field))
+}
+
+fn mapping_key_column(line: &str, field: FieldName<'_>) -> Option<usize> {
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return None;
+    }
+
+    if is_mapping_key_for_field(trimmed, field) {
+        let leading = line.len() - trimmed.len();
+        return Some(leading + 1);
+    }
+
+    None
+}
+
+fn is_plain_mapping_key(line: &str, field: FieldName<'_>) -> bool {
+    line.strip_prefix(field.as_str())
+        .is_some_and(|tail| tail.starts_with(':'))
+}
+
+fn is_single_quoted_mapping_key(line: &str, field: FieldName<'_>) -> bool {
+    line.strip_prefix('\'')
+        .and_then(|tail| tail.strip_prefix(field.as_str()))
+        .is_some_and(|tail| tail.starts_with("':"))
+}
+
+fn is_double_quoted_mapping_key(line: &str, field: FieldName<'_>) -> bool {
+    line.strip_prefix('"')
+        .and_then(|tail| tail.strip_prefix(field.as_str()))
+        .is_some_and(|tail| tail.starts_with("\":"))
+}
+
+fn is_mapping_key_for_field(line: &str, field: FieldName<'_>) -> bool {
+    is_plain_mapping_key(line, field)
+        || is_single_quoted_mapping_key(line, field)
+        || is_double_quoted_mapping_key(line, field)
+}
@@ -0,0 +1,234 @@
+//! RON front-end for theorem documents.
+//!
+//! RON suits generators written in Rust, which can emit a theorem as a
+//! struct literal instead of assembling YAML strings. A RON source holds one
+//! document written with the same field names as a YAML document, or a list
+//! of documents read like the documents of a YAML stream. The parsed value
+//! is rendered as JSON text that the YAML loader reads, so RON documents
+//! share the schema, validation, and diagnostics of YAML documents.
+//!
+//! RON values carry no positions once parsed, so each field is located by
+//! scanning the source for its name in order; values are reported at the
+//! field that holds them.
+
+use ron::Value;
+use ron::value::{Map, Number};
+
+use super::error::SchemaError;
+use super::rendered_source::{Position, RenderedSource, position, quoted};
+use super::source_format::{SourceFormat, syntax_error};
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+
+/// Loads theorem documents from a RON source.
+///
+/// A document is a struct, optionally named, or a map whose fields use the
+/// keys of a YAML document. Enumerated values such as Kani expectations are
+/// written as strings, `Some(value)` reads as `value`, and `None` and `()`
+/// read as null.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::Syntax`] when `input` is not well-formed RON, and
+/// otherwise the same errors as
+/// [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source),
+/// located at the RON field they concern.
+///
+/// # Examples
+///
+/// ```
+/// use theoremc_core::schema::{SourceId, load_theorem_docs_ron};
+///
+/// let ron = r#"TheoremDoc(
+///     Theorem: "T",
+///     About: "Example",
+///     Prove: [(assert: "true", because: "trivially true")],
+///     Evidence: (
+///         kani: (unwind: 1, expect: "SUCCESS", allow_vacuous: true, vacuity_because: "none"),
+///     ),
+/// )"#;
+/// let docs = load_theorem_docs_ron(&SourceId::new("t.theorem.ron"), ron).unwrap();
+/// assert_eq!(docs[0].theorem.as_str(), "T");
+/// ```
+pub fn load_theorem_docs_ron(
+    source: &SourceId,
+    input: &str,
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    let value: Value = ron::from_str(input).map_err(|error| {
+        let start = (error.span.start.line, error.span.start.col);
+        syntax_error(SourceFormat::Ron, source, error.code.to_string(), start)
+    })?;
+    let mut rendering = Rendering {
+        input,
+        cursor: 0,
+        rendered: RenderedSource::default(),
+    };
+    rendering.value("", &value, (1, 1));
+    rendering.rendered.load(source)
+}
+
+/// The rendering of a RON source as JSON text.
+struct Rendering<'i> {
+    input: &'i str,
+    /// The byte offset field names are next searched from.
+    cursor: usize,
+    rendered: RenderedSource,
+}
+
+impl Rendering<'_> {
+    /// Renders `value` after `prefix`, which holds its key when it has one.
+    fn value(&mut self, prefix: &str, value: &Value, origin: Position) {
+        match value {
+            Value::Map(map) => self.map(prefix, map, origin),
+            Value::Seq(items) => self.seq(prefix, items, origin),
+            Value::Option(Some(inner)) => self.value(prefix, inner, origin),
+            Value::Option(None) | Value::Unit => {
+                self.rendered.line(&format!("{prefix}null"), origin);
+            }
+            scalar => self
+                .rendered
+                .line(&format!("{prefix}{}", scalar_text(scalar)), origin),
+        }
+    }
+
+    fn map(&mut self, prefix: &str, map: &Map, origin: Position) {
+        self.rendered.line(&format!("{prefix}{{"), origin);
+        for (index, (key, value)) in map.iter().enumerate() {
+            if index > 0 {
+                self.rendered.separate();
+            }
+            let name = key_text(key);
+            let located = self.locate(&name).unwrap_or(origin);
+            self.value(&format!("{}: ", quoted(&name)), value, located);
+        }
+        self.rendered.line("}", origin);
+    }
+
+    fn seq(&mut self, prefix: &str, items: &[Value], origin: Position) {
+        self.rendered.line(&format!("{prefix}["), origin);
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.rendered.separate();
+            }
+            self.value("", item, origin);
+        }
+        self.rendered.line("]", origin);
+    }
+
+    /// Finds the next field or map key named `name` after the cursor and
+    /// moves the cursor past it.
+    fn locate(&mut self, name: &str) -> Option<Position> {
+        let offset = find_key(self.input, self.cursor, name)?;
+        self.cursor = offset + token(self.input.get(offset..)?).0;
+        Some(position(self.input, offset))
+    }
+}
+
+/// Returns the text of a map key or field name.
+fn key_text(key: &Value) -> String {
+    match key {
+        Value::String(text) => text.clone(),
+        Value::Char(character) => character.to_string(),
+        other => scalar_text(other),
+    }
+}
+
+/// Renders a scalar as JSON, or YAML for special floats.
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::Bool(flag) => flag.to_string(),
+        Value::Char(character) => quoted(&character.to_string()),
+        Value::String(text) => quoted(text),
+        Value::Bytes(bytes) => quoted(&String::from_utf8_lossy(bytes)),
+        Value::Number(number) => number_text(number),
+        _ => "null".to_owned(),
+    }
+}
+
+fn number_text(number: &Number) -> String {
+    let float = match number {
+        Number::F32(float) => f64::from(float.get()),
+        Number::F64(float) => float.get(),
+        integer => return serde_json::to_string(integer).unwrap_or_default(),
+    };
+    if float.is_nan() {
+        ".nan".to_owned()
+    } else if float.is_infinite() {
+        if float > 0.0 { ".inf" } else { "-.inf" }.to_owned()
+    } else {
+        format!("{float:?}")
+    }
+}
+
+/// Returns the byte offset of the first field name or quoted map key equal
+/// to `name` at or after `from`, skipping string literals and comments.
+fn find_key(input: &str, from: usize, name: &str) -> Option<usize> {
+    let mut offset = from;
+    while let Some(rest) = input.get(offset..).filter(|rest| !rest.is_empty()) {
+        let (length, text) = token(rest);
+        let after = rest.get(length..).unwrap_or_default();
+        if text == Some(name) && after.trim_start().starts_with(':') {
+            return Some(offset);
+        }
+        offset += length;
+    }
+    None
+}
+
+/// Returns the byte length of the token `rest` starts with, and its text
+/// when it is an identifier or a string that could name a key.
+fn token(rest: &str) -> (usize, Option<&str>) {
+    if rest.starts_with("//") {
+        return (
+            rest.find('\n').map_or(rest.len(), |newline| newline + 1),
+            None,
+        );
+    }
+    if rest.starts_with("/*") {
+        return (rest.find("*/").map_or(rest.len(), |end| end + 2), None);
+    }
+    if rest.starts_with('"') {
+        let length = string_length(rest);
+        return (length, rest.get(1..length.saturating_sub(1)));
+    }
+    if let Some(length) = raw_string_length(rest) {
+        return (length, None);
+    }
+    let word = rest
+        .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .unwrap_or(rest.len());
+    if word > 0 {
+        return (word, rest.get(..word));
+    }
+    (rest.chars().next().map_or(1, char::len_utf8), None)
+}
+
+/// Returns the byte length of the quoted string `rest` starts with.
+fn string_length(rest: &str) -> usize {
+    let mut escaped = false;
+    for (index, character) in rest.char_indices().skip(1) {
+        match character {
+            '"' if !escaped => return index + 1,
+            '\\' => escaped = !escaped,
+            _ => escaped = false,
+        }
+    }
+    rest.len()
+}
+
+/// Returns the byte length of the raw string, such as `r#"..."#`, that
+/// `rest` starts with.
+fn raw_string_length(rest: &str) -> Option<usize> {
+    let hashes = rest.strip_prefix('r')?;
+    let count = hashes.len() - hashes.trim_start_matches('#').len();
+    let body = hashes.get(count..)?.strip_prefix('"')?;
+    let closing = format!("\"{}", "#".repeat(count));
+    let end = body
+        .find(&closing)
+        .map_or(body.len(), |end| end + closing.len());
+    Some(rest.len() - body.len() + end)
+}
+
+#[cfg(test)]
+#[path = "loader_ron_tests.rs"]
+mod tests;
@@ -0,0 +1,148 @@
+//! Unit tests for the RON front-end.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{ForallVar, SchemaDiagnosticCode, Symbol};
+
+const EVIDENCE: &str = concat!(
+    "    Evidence: (\n",
+    "        kani: (unwind: 1, expect: \"SUCCESS\", allow_vacuous: true, vacuity_because: \"none\"),\n",
+    "    ),\n",
+);
+
+fn theorem(body: &str) -> String {
+    format!("(\n    Theorem: \"T\",\n    About: \"Example\",\n{body}{EVIDENCE})\n")
+}
+
+fn load(input: &str) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_ron(&SourceId::new("t.theorem.ron"), input)
+}
+
+fn located(error: &SchemaError) -> (SchemaDiagnosticCode, usize, usize) {
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.location.source, "t.theorem.ron");
+    (
+        diagnostic.code,
+        diagnostic.location.line,
+        diagnostic.location.column,
+    )
+}
+
+const PROVE: &str = "    Prove: [(assert: \"true\", because: \"trivially true\")],\n";
+
+#[test]
+fn loads_a_theorem_struct() {
+    let input = theorem(concat!(
+        "    Tags: [\"smoke\"],\n",
+        "    Forall: {\"zeta\": \"u8\", \"alpha\": \"u8\"},\n",
+        "    Prove: [(assert: \"zeta == alpha || true\", because: \"trivially true\")],\n",
+    ));
+    let docs = load(&input).expect("RON theorem should load");
+    let doc = docs.first().expect("one document");
+    let names: Vec<&str> = doc.forall.keys().map(ForallVar::as_str).collect();
+
+    assert_eq!(doc.theorem.as_str(), "T");
+    assert_eq!(doc.tags, ["smoke"]);
+    assert_eq!(names, ["zeta", "alpha"]);
+}
+
+#[test]
+fn loads_a_list_of_documents() {
+    let input = format!(
+        "[\n    (Defaults: (Tags: [\"smoke\"])),\n    TheoremDoc{}\n]\n",
+        theorem(PROVE).trim_end()
+    );
+    let docs = load(&input).expect("RON documents should load");
+
+    assert_eq!(docs.len(), 1);
+    assert_eq!(
+        docs.first().map(|doc| doc.tags.clone()),
+        Some(vec![Symbol::new("smoke")])
+    );
+}
+
+#[test]
+fn options_and_comments_are_accepted() {
+    let input = theorem(concat!(
+        "    // Generated by the spec exporter.\n",
+        "    Schema: Some(1),\n",
+        "    /* Reason: \"no\" */\n",
+        "    Prove: [(assert: \"true\", because: \"About: trivially\")],\n",
+    ));
+    let docs = load(&input).expect("RON theorem should load");
+
+    assert_eq!(docs.len(), 1);
+}
+
+#[test]
+fn syntax_errors_are_reported_as_ron() {
+    let error = load("(\n    Theorem: \"T\",\n    About: ,\n)").expect_err("RON is malformed");
+
+    assert!(matches!(
+        error,
+        SchemaError::Syntax {
+            format: SourceFormat::Ron,
+            ..
+        }
+    ));
+    assert!(
+        error.to_string().starts_with("RON syntax error: "),
+        "{error}"
+    );
+    assert_eq!(located(&error), (SchemaDiagnosticCode::ParseFailure, 3, 11));
+}
+
+#[test]
+fn unknown_fields_are_located_at_the_ron_field() {
+    let input = theorem(concat!(
+        "    Prove: [(\n",
+        "        assert: \"true\",\n",
+        "        because: \"trivially true\",\n",
+        "        becuase: \"typo\",\n",
+        "    )],\n",
+    ));
+    let error = load(&input).expect_err("unknown field should be rejected");
+
+    assert_eq!(located(&error), (SchemaDiagnosticCode::ParseFailure, 7, 9));
+    assert!(error.to_string().contains("line 7 column 9: "), "{error}");
+}
+
+#[test]
+fn validation_errors_are_located_in_the_ron_source() {
+    let input = theorem("    Prove: [(assert: \"true\", because: \"  \")],\n");
+    let error = load(&input).expect_err("blank reason should be rejected");
+    let (code, line, _) = located(&error);
+
+    assert_eq!((code, line), (SchemaDiagnosticCode::ValidationFailure, 4));
+}
+
+#[rstest]
+#[case::identifier("(About: 1)", "About", Some(1))]
+#[case::quoted_key("{\"About\": 1}", "About", Some(1))]
+#[case::skips_strings("(a: \"About: x\", About: 1)", "About", Some(16))]
+#[case::skips_raw_strings("(a: r#\"About: \"x\"\"#, About: 1)", "About", Some(21))]
+#[case::skips_line_comments("(// About: x\nAbout: 1)", "About", Some(13))]
+#[case::skips_block_comments("(/* About: */ About: 1)", "About", Some(14))]
+#[case::needs_a_colon("(a: About, About: 1)", "About", Some(11))]
+#[case::missing("(a: 1)", "About", None)]
+fn keys_are_found_outside_strings_and_comments(
+    #[case] input: &str,
+    #[case] name: &str,
+    #[case] expected: Option<usize>,
+) {
+    assert_eq!(find_key(input, 0, name), expected);
+}
+
+#[rstest]
+#[case::integer(Number::U8(3), "3")]
+#[case::negative(Number::I64(-3), "-3")]
+#[case::float(Number::from(1.5_f64), "1.5")]
+#[case::whole_float(Number::from(3.0_f64), "3.0")]
+#[case::infinity(Number::from(f64::INFINITY), ".inf")]
+#[case::negative_infinity(Number::from(f64::NEG_INFINITY), "-.inf")]
+#[case::not_a_number(Number::from(f64::NAN), ".nan")]
+fn numbers_use_yaml_spellings(#[case] number: Number, #[case] expected: &str) {
+    assert_eq!(number_text(&number), expected);
+}
@@ -0,0 +1,389 @@
+//! Lazy, document-at-a-time loading of multi-document `.theorem` sources.
+//!
+//! [`iter_theorem_docs`] splits its input at `---` document markers, keeping
+//! any `%` directives with the document they precede, as the iterator
+//! advances and parses, decodes, and validates one document per
+//! step, so only the current document's raw form is held in memory and a
+//! caller that stops early never parses the rest of the input. Each document
+//! is parsed on its own and its diagnostics are then moved down by the lines
+//! before it, so no line is read twice.
+//!
+//! Checks that span documents run incrementally: a theorem's `DependsOn`
+//! entries must name theorems declared earlier in the source, and duplicate
+//! theorem keys and mangled action collisions are reported at the document
+//! that introduces them. `Suite`, `Shared`, and `Fixtures` documents can
+//! affect theorems on either side of them, so they are rejected here, as are
+//! `Use`, `UseFixtures`, and `Extends` entries; load such sources with
+//! [`load_theorem_docs`](super::load_theorem_docs).
+
+use std::collections::BTreeMap;
+
+use serde_saphyr::Location;
+
+use super::defaults::{RawDefaults, misplaced_defaults};
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::include::NoIncludes;
+use super::load_options::LoadOptions;
+use super::loader::{INLINE_SOURCE, SourceLoad};
+use super::loader_duplicate::duplicate_theorem_key;
+use super::loader_parse_failure::deserialize_failure;
+use super::raw::RawTheoremDoc;
+use super::raw_document::RawDocument;
+use super::raw_extends::apply_extends;
+use super::raw_fixtures::apply_fixtures;
+use super::raw_shared::apply_shared;
+use super::rendered_source::positioned_reason;
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+use super::yaml_anchor::parse_options;
+use crate::collision::check_new_action_collisions;
+
+/// Lazily loads theorem documents from a YAML string.
+///
+/// Yields the same documents as [`load_theorem_docs`](super::load_theorem_docs)
+/// for sources without `Suite`, `Shared`, or `Fixtures` documents, without
+/// `Extends` entries, and without forward `DependsOn` references, one per
+/// call to `next`. A leading `Defaults`
+/// document is applied to every theorem that follows it. The iterator ends
+/// after yielding its first error.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::iter_theorem_docs;
+///
+///     let yaml = concat!(
+///         "Theorem: First\n",
+///         "About: The first theorem\n",
+///         "Prove:\n",
+///         "  - assert: 'true'\n",
+///         "    because: trivially true\n",
+///         "Evidence:\n",
+///         "  kani: { unwind: 1, expect: SUCCESS }\n",
+///         "Witness:\n",
+///         "  - cover: 'true'\n",
+///         "    because: always reachable\n",
+///         "---\n",
+///         "Theorem: Second\n",
+///         "About: ''\n",
+///     );
+///     let mut docs = iter_theorem_docs(yaml);
+///     let first = docs.next().expect("one document").expect("valid document");
+///     assert_eq!(first.theorem, "First");
+pub fn iter_theorem_docs(input: &str) -> impl Iterator<Item = Result<TheoremDoc, SchemaError>> {
+    iter_theorem_docs_with_source(&SourceId::new(INLINE_SOURCE), input)
+}
+
+/// Lazily loads theorem documents like [`iter_theorem_docs`], recording
+/// diagnostics against an explicit source identifier.
+///
+/// Documents that declare `Include` entries are rejected, as with
+/// [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source).
+pub fn iter_theorem_docs_with_source<'a>(
+    source: &SourceId,
+    input: &'a str,
+) -> impl Iterator<Item = Result<TheoremDoc, SchemaError>> + use<'a> {
+    TheoremDocStream {
+        source: source.clone(),
+        chunks: DocumentChunks {
+            rest: input.strip_prefix('\u{FEFF}').unwrap_or(input),
+            line: 0,
+        },
+        defaults: None,
+        documents: 0,
+        theorems: BTreeMap::new(),
+        actions: BTreeMap::new(),
+        failed: false,
+    }
+}
+
+/// Iterator state behind [`iter_theorem_docs_with_source`].
+struct TheoremDocStream<'a> {
+    source: SourceId,
+    chunks: DocumentChunks<'a>,
+    defaults: Option<RawDefaults>,
+    documents: usize,
+    /// Theorems loaded so far, with the location of their `Theorem` key and
+    /// the number of lines before the document that declares them.
+    theorems: BTreeMap<String, (Location, usize)>,
+    /// Mangled action identifiers seen so far, with their canonical names.
+    actions: BTreeMap<String, String>,
+    failed: bool,
+}
+
+impl Iterator for TheoremDocStream<'_> {
+    type Item = Result<TheoremDoc, SchemaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            let chunk = self.chunks.next()?;
+            match self.load(chunk) {
+                Ok(None) => {}
+                Ok(Some(doc)) => return Some(Ok(doc)),
+                Err(error) => {
+                    self.failed = true;
+                    return Some(Err(relocate(error, chunk.line)));
+                }
+            }
+        }
+    }
+}
+
+impl TheoremDocStream<'_> {
+    /// Loads one document, returning `None` for blank and `Defaults`
+    /// documents. Errors are located within the chunk.
+    fn load(&mut self, chunk: DocumentChunk<'_>) -> Result<Option<TheoremDoc>, SchemaError> {
+        let Some(document) = self.parse(chunk)? else {
+            return Ok(None);
+        };
+        let first = self.documents == 0;
+        self.documents += 1;
+        match document {
+            RawDocument::Defaults(spanned) if first => {
+                self.defaults = Some(spanned.value);
+                Ok(None)
+            }
+            RawDocument::Defaults(spanned) => Err(misplaced_defaults(&self.source, &spanned)),
+            RawDocument::Suite(suite) => Err(SchemaError::InvalidSuite {
+                suite: suite.name.value.clone(),
+                reason: WHOLE_SOURCE_REASON.to_owned(),
+                diagnostic: Some(Box::new(
+                    self.whole_source_diagnostic(suite.name.referenced),
+                )),
+            }),
+            RawDocument::Shared(block) => Err(SchemaError::InvalidShared {
+                shared: block.name.value.clone(),
+                reason: WHOLE_SOURCE_REASON.to_owned(),
+                diagnostic: Some(Box::new(
+                    self.whole_source_diagnostic(block.name.referenced),
+                )),
+            }),
+            RawDocument::Fixtures(fixture) => Err(SchemaError::InvalidFixture {
+                fixture: fixture.name.value.clone(),
+                reason: WHOLE_SOURCE_REASON.to_owned(),
+                diagnostic: Some(Box::new(
+                    self.whole_source_diagnostic(fixture.name.referenced),
+                )),
+            }),
+            RawDocument::Theorem(raw_doc) => self.theorem(*raw_doc, chunk).map(Some),
+        }
+    }
+
+    /// Parses the chunk's document, if it has one, on its own, so each
+    /// document is read once however far into the input it starts.
+    fn parse(&self, chunk: DocumentChunk<'_>) -> Result<Option<RawDocument>, SchemaError> {
+        if is_blank_document(chunk.text) {
+            return Ok(None);
+        }
+        serde_saphyr::from_str_with_options(chunk.text, parse_options())
+            .map(Some)
+            .map_err(|error| deserialize_failure(&self.source, chunk.text, &error))
+    }
+
+    /// Loads the theorem of `chunk`'s document.
+    fn theorem(
+        &mut self,
+        mut raw_doc: RawTheoremDoc,
+        chunk: DocumentChunk<'_>,
+    ) -> Result<TheoremDoc, SchemaError> {
+        apply_shared(&self.source, &[], &mut raw_doc)?;
+        apply_fixtures(&self.source, &[], &mut raw_doc)?;
+        apply_extends(&self.source, std::slice::from_mut(&mut raw_doc))?;
+        if let Some(file_defaults) = &self.defaults {
+            file_defaults.apply_to(&mut raw_doc);
+        }
+        self.check_declared_order(&raw_doc, chunk.line)?;
+        let load = SourceLoad {
+            source: &self.source,
+            input: chunk.text,
+            resolver: &NoIncludes,
+            manifest: None,
+            options: &LoadOptions::default(),
+        };
+        let doc = load.theorem_doc(raw_doc)?;
+        check_new_action_collisions(&mut self.actions, &doc)?;
+        Ok(doc)
+    }
+
+    /// Rejects a theorem key seen before and `DependsOn` entries that do not
+    /// name an earlier theorem, then records the theorem declared after
+    /// `line` lines of the input.
+    fn check_declared_order(
+        &mut self,
+        raw_doc: &RawTheoremDoc,
+        line: usize,
+    ) -> Result<(), SchemaError> {
+        let theorem = raw_doc.theorem.value.as_str();
+        if let Some(first) = self.theorems.get(theorem) {
+            return Err(duplicate_theorem_key(
+                &self.source,
+                theorem,
+                *first,
+                (raw_doc.theorem_location(), line),
+            ));
+        }
+        if let Some(dependency) = raw_doc
+            .depends_on
+            .iter()
+            .find(|dependency| !self.theorems.contains_key(dependency.value.as_str()))
+        {
+            let reason = format!(
+                "DependsOn entry '{}' does not name a theorem declared earlier in the source",
+                dependency.value.as_str()
+            );
+            return Err(SchemaError::ValidationFailed {
+                theorem: theorem.to_owned(),
+                diagnostic: Some(Box::new(create_diagnostic(
+                    SchemaDiagnosticCode::ValidationFailure,
+                    &self.source,
+                    reason.clone(),
+                    dependency.referenced,
+                ))),
+                reason,
+                source: None,
+            });
+        }
+        self.theorems
+            .insert(theorem.to_owned(), (raw_doc.theorem_location(), line));
+        Ok(())
+    }
+
+    fn whole_source_diagnostic(&self, location: Location) -> super::SchemaDiagnostic {
+        create_diagnostic(
+            SchemaDiagnosticCode::ValidationFailure,
+            &self.source,
+            WHOLE_SOURCE_REASON.to_owned(),
+            location,
+        )
+    }
+}
+
+/// Moves the diagnostics of an error raised while loading a document from
+/// the document's own lines to those of the whole input, `lines` further
+/// down, rewriting the position a parser message starts with.
+fn relocate(mut error: SchemaError, lines: usize) -> SchemaError {
+    if lines == 0 {
+        return error;
+    }
+    if let SchemaError::DuplicateTheoremKey { collisions, .. } = &mut error {
+        for collision in collisions {
+            collision.location.line = collision.location.line.saturating_add(lines);
+        }
+    }
+    let Some(diagnostic) = error.diagnostic_mut() else {
+        return error;
+    };
+    let location = &mut diagnostic.location;
+    location.line = location.line.saturating_add(lines);
+    if let Some(reason) = positioned_reason(&diagnostic.message) {
+        diagnostic.message = format!(
+            "line {} column {}: {reason}",
+            location.line, location.column
+        );
+    }
+    let relocated = diagnostic.message.clone();
+    if let SchemaError::Deserialize { message, .. } = &mut error {
+        *message = relocated;
+    }
+    error
+}
+
+const WHOLE_SOURCE_REASON: &str =
+    "Suite, Shared, and Fixtures documents need the whole source; load it with load_theorem_docs";
+
+/// One document's text and the zero-based line it starts on.
+#[derive(Debug, Clone, Copy)]
+struct DocumentChunk<'a> {
+    line: usize,
+    text: &'a str,
+}
+
+/// Splits a source into document chunks before each `---` marker line that
+/// starts a new document.
+///
+/// Directive lines such as `%YAML 1.2` belong to the document whose `---`
+/// follows them, so a chunk ends before the directives rather than at the
+/// marker, and a chunk that holds only directives, comments, and blank lines
+/// so far takes the next marker as its own start.
+struct DocumentChunks<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Iterator for DocumentChunks<'a> {
+    type Item = DocumentChunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (length, lines) = chunk_end(self.rest);
+        let (text, rest) = self.rest.split_at_checked(length)?;
+        let chunk = DocumentChunk {
+            line: self.line,
+            text,
+        };
+        self.rest = rest;
+        self.line += lines;
+        Some(chunk)
+    }
+}
+
+/// Returns the byte length and line count of the first document chunk of
+/// `rest`.
+fn chunk_end(rest: &str) -> (usize, usize) {
+    let mut end = (0, 0);
+    let mut started = false;
+    let mut directives_at = None;
+    for line in rest.split_inclusive('\n') {
+        if is_document_marker(line, "---") {
+            if started {
+                return directives_at.unwrap_or(end);
+            }
+            started = true;
+            directives_at = None;
+        } else if line.starts_with('%') {
+            directives_at = directives_at.or(Some(end));
+        } else if !is_blank_line(line) {
+            started = true;
+            directives_at = None;
+        }
+        end = (end.0 + line.len(), end.1 + 1);
+    }
+    end
+}
+
+/// Returns `true` when `line` starts with the `marker` at column one.
+fn is_document_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|after| after.is_empty() || after.starts_with(char::is_whitespace))
+}
+
+/// Returns `true` when `line` holds nothing but a `...` end marker, a
+/// comment, or whitespace.
+fn is_blank_line(line: &str) -> bool {
+    let content = if is_document_marker(line, "...") {
+        line.get(3..).unwrap_or_default()
+    } else {
+        line
+    }
+    .trim();
+    content.is_empty() || content.starts_with('#')
+}
+
+/// Returns `true` when a chunk holds only markers, directives, comments, and
+/// blank lines.
+fn is_blank_document(text: &str) -> bool {
+    text.lines().all(|line| {
+        line.starts_with('%')
+            || is_blank_line(line)
+            || is_document_marker(line, "---") && is_blank_line(line.get(3..).unwrap_or_default())
+    })
+}
+
+#[cfg(test)]
+#[path = "loader_stream_tests.rs"]
+mod tests;
@@ -0,0 +1,252 @@
+//! Unit tests for lazy theorem document loading.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{iter_theorem_docs, iter_theorem_docs_with_source};
+use crate::schema::test_fixtures::theorem_without_evidence;
+use crate::schema::{SchemaError, SourceId, load_theorem_docs, load_theorem_docs_with_source};
+
+const KANI: &str = "Evidence:\n  kani: { unwind: 1, expect: SUCCESS }\n";
+
+fn names(docs: &[crate::schema::TheoremDoc]) -> Vec<&str> {
+    docs.iter().map(|doc| doc.theorem.as_str()).collect()
+}
+
+#[test]
+fn yields_the_documents_the_batch_loader_returns() {
+    let input = format!(
+        "---\nDefaults:\n  Tags: [ledger]\n  Evidence:\n    kani: {{ unwind: 2, expect: SUCCESS }}\n---\n{}---\n{}...\n",
+        theorem_without_evidence("First", ""),
+        theorem_without_evidence("Second", "DependsOn: [First]\n"),
+    );
+
+    let streamed = iter_theorem_docs(&input)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("every document should load");
+
+    let loaded = load_theorem_docs(&input).expect("batch load should succeed");
+    assert_eq!(names(&streamed), vec!["First", "Second"]);
+    assert_eq!(streamed, loaded);
+}
+
+#[rstest]
+#[case::leading_directive("%YAML 1.2\n---\n{first}---\n{second}")]
+#[case::directive_after_end_marker("{first}...\n%YAML 1.2\n---\n{second}")]
+#[case::directives_and_comments(
+    "# ledger\n%YAML 1.2\n%TAG ! tag:example.com,2026:\n---\n{first}---\n{second}"
+)]
+#[case::end_marker_before_start("{first}...\n---\n{second}")]
+fn directives_stay_with_the_document_they_precede(#[case] template: &str) {
+    let input = template
+        .replace("{first}", &theorem_without_evidence("First", KANI))
+        .replace("{second}", &theorem_without_evidence("Second", KANI));
+
+    let streamed = iter_theorem_docs(&input).collect::<Result<Vec<_>, _>>();
+    let loaded = load_theorem_docs(&input);
+
+    assert_eq!(
+        streamed.map_err(|error| error.to_string()),
+        loaded.map_err(|error| error.to_string())
+    );
+    assert_eq!(iter_theorem_docs(&input).flatten().count(), 2, "{input}");
+}
+
+#[test]
+fn stops_parsing_once_the_caller_stops() {
+    let input = format!(
+        "{}---\nTheorem: [unclosed\n",
+        theorem_without_evidence("First", KANI)
+    );
+
+    let first = iter_theorem_docs(&input)
+        .next()
+        .expect("one document")
+        .expect("first document should load");
+
+    assert_eq!(first.theorem, "First");
+}
+
+#[test]
+fn ends_after_the_first_error() {
+    let input = format!(
+        "{}---\n{}---\n{}",
+        theorem_without_evidence("First", KANI),
+        theorem_without_evidence("Second", ""),
+        theorem_without_evidence("Third", KANI),
+    );
+
+    let results: Vec<_> = iter_theorem_docs(&input).collect();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.first().is_some_and(Result::is_ok));
+    assert!(
+        results
+            .get(1)
+            .is_some_and(|result| matches!(result, Err(SchemaError::ValidationFailed { .. })))
+    );
+}
+
+#[rstest]
+#[case::validation(theorem_without_evidence("Second", &format!("Assume:\n  - expr: '  '\n    because: blank\n{KANI}")), 15, 11)]
+#[case::parse("Theorem: Second\nAbout: [unclosed\n".to_owned(), 13, 8)]
+fn diagnostics_use_positions_in_the_whole_source(
+    #[case] second: String,
+    #[case] line: usize,
+    #[case] column: usize,
+) {
+    let source = SourceId::new("theorems/stream.theorem");
+    let input = format!("{}---\n{second}", theorem_without_evidence("First", KANI));
+
+    let error = iter_theorem_docs_with_source(&source, &input)
+        .find_map(Result::err)
+        .expect("second document should fail");
+
+    let batch_error =
+        load_theorem_docs_with_source(&source, &input).expect_err("batch load should fail");
+    let diagnostic = error.diagnostic().expect("error should carry a diagnostic");
+    let batch_diagnostic = batch_error
+        .diagnostic()
+        .expect("batch error should carry a diagnostic");
+    assert_eq!(diagnostic.code, batch_diagnostic.code);
+    assert_eq!(diagnostic.location, batch_diagnostic.location);
+    assert_eq!(diagnostic.location.source, "theorems/stream.theorem");
+    assert_eq!(
+        (diagnostic.location.line, diagnostic.location.column),
+        (line, column)
+    );
+}
+
+#[test]
+fn duplicate_theorem_keys_match_the_batch_error() {
+    let source = SourceId::new("theorems/duplicate.theorem");
+    let input = format!(
+        "{}---\n{}",
+        theorem_without_evidence("Twice", KANI),
+        theorem_without_evidence("Twice", KANI)
+    );
+
+    let error = iter_theorem_docs_with_source(&source, &input)
+        .find_map(Result::err)
+        .expect("duplicate should fail");
+
+    let batch_error =
+        load_theorem_docs_with_source(&source, &input).expect_err("batch load should fail");
+    assert!(matches!(error, SchemaError::DuplicateTheoremKey { .. }));
+    assert_eq!(error.to_string(), batch_error.to_string());
+}
+
+#[test]
+fn dependencies_must_name_earlier_theorems() {
+    let input = format!(
+        "{}---\n{}",
+        theorem_without_evidence("First", &format!("DependsOn: [Second]\n{KANI}")),
+        theorem_without_evidence("Second", KANI),
+    );
+
+    let error = iter_theorem_docs(&input)
+        .find_map(Result::err)
+        .expect("forward dependency should fail");
+
+    assert_eq!(
+        error.to_string(),
+        concat!(
+            "validation failed for theorem 'First': DependsOn entry 'Second' does not ",
+            "name a theorem declared earlier in the source",
+        )
+    );
+}
+
+#[rstest]
+#[case::suite("Suite: Ledger\nAbout: ledger theorems\nTheorems: [First]\n")]
+#[case::shared("Shared: Accounts\nAbout: shared inputs\nForall:\n  x: u8\n")]
+#[case::fixtures("Fixtures: Open\nAbout: opens\nLet:\n  a: { call: { action: open, args: {} } }\n")]
+fn whole_source_documents_are_rejected(#[case] document: &str) {
+    let input = format!("{}---\n{document}", theorem_without_evidence("First", KANI));
+
+    let error = iter_theorem_docs(&input)
+        .find_map(Result::err)
+        .expect("document should be rejected");
+
+    assert!(error.to_string().ends_with(concat!(
+        "Suite, Shared, and Fixtures documents need the whole source; ",
+        "load it with load_theorem_docs",
+    )));
+}
+
+#[test]
+fn blank_documents_are_skipped() {
+    let input = format!(
+        "# leading comment\n---\n{}---\n# nothing here\n--- # marker comment\n\n",
+        theorem_without_evidence("Only", KANI)
+    );
+
+    let docs = iter_theorem_docs(&input)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("blank documents should be skipped");
+
+    assert_eq!(names(&docs), vec!["Only"]);
+}
+
+#[rstest]
+#[case::parse("Theorem: Late\nAbout: [unclosed\n")]
+#[case::validation(&theorem_without_evidence(
+    "Late",
+    &format!("Assume:\n  - expr: '  '\n    because: blank\n{KANI}")
+))]
+fn late_documents_report_lines_in_the_whole_source(#[case] late: &str) {
+    let source = SourceId::new("theorems/late.theorem");
+    let mut input: String = (0..50)
+        .map(|index| theorem_without_evidence(&format!("Early{index}"), KANI) + "---\n")
+        .collect();
+    input.push_str(late);
+
+    let error = iter_theorem_docs_with_source(&source, &input)
+        .find_map(Result::err)
+        .expect("late document should fail");
+
+    let batch_error =
+        load_theorem_docs_with_source(&source, &input).expect_err("batch load should fail");
+    let diagnostic = error.diagnostic().expect("error should carry a diagnostic");
+    assert_eq!(
+        Some(&diagnostic.location),
+        batch_error.diagnostic().map(|batch| &batch.location)
+    );
+    assert!(diagnostic.location.line > 500, "{diagnostic:?}");
+    assert!(
+        !diagnostic.message.starts_with("line ")
+            || diagnostic
+                .message
+                .starts_with(&format!("line {} ", diagnostic.location.line)),
+        "{diagnostic:?}"
+    );
+}
+
+/// Returns the quickest of three runs streaming `count` documents.
+fn quickest_stream(count: usize) -> std::time::Duration {
+    let input: String = (0..count)
+        .map(|index| theorem_without_evidence(&format!("T{index}"), KANI) + "---\n")
+        .collect();
+    (0..3)
+        .map(|_| {
+            let started = std::time::Instant::now();
+            assert_eq!(iter_theorem_docs(&input).flatten().count(), count);
+            started.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+#[test]
+fn streaming_cost_grows_linearly_with_document_count() {
+    let small = quickest_stream(200);
+    let large = quickest_stream(1600);
+
+    // Eight times the documents take about eight times as long, while
+    // re-reading every earlier line for each document takes over twenty
+    // times as long at these sizes.
+    assert!(
+        large < small * 16,
+        "1600 documents took {large:?}, 200 took {small:?}"
+    );
+}
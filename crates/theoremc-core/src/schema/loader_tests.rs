@@ -236,6 +236,8 @@ fn accept_assume_field_alias() {
     let yaml = r"
 Theorem: AssumeAlias
 About: Assumption alias key should parse
+Forall:
+  x: i32
 Assume:
   - assume: 'x > 0'
     because: positive input domain
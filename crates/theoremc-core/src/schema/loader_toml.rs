@@ -0,0 +1,144 @@
+//! TOML front-end for theorem documents.
+//!
+//! A TOML source holds one document written with the same keys as a YAML
+//! document. The parsed table is rendered as JSON text that the YAML loader
+//! reads, with each line located at the TOML key or value it came from, so
+//! TOML documents share the schema, validation, and diagnostics of YAML
+//! documents.
+
+use toml::Spanned;
+use toml::de::{DeTable, DeValue};
+
+use super::error::SchemaError;
+use super::rendered_source::{Position, RenderedSource, position, quoted};
+use super::source_format::{SourceFormat, syntax_error};
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+
+/// Loads the theorem document of a TOML source.
+///
+/// The top-level table is one document, read like a YAML document with the
+/// same keys, so a theorem is written with `Theorem`, `About`, `[[Prove]]`,
+/// and so on.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::Syntax`] when `input` is not well-formed TOML, and
+/// otherwise the same errors as
+/// [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source),
+/// located at the TOML key or value they concern.
+///
+/// # Examples
+///
+/// ```
+/// use theoremc_core::schema::{SourceId, load_theorem_docs_toml};
+///
+/// let toml = r#"
+/// Theorem = "T"
+/// About = "Example"
+///
+/// [[Prove]]
+/// assert = "true"
+/// because = "trivially true"
+///
+/// [Evidence.kani]
+/// unwind = 1
+/// expect = "SUCCESS"
+/// allow_vacuous = true
+/// vacuity_because = "none"
+/// "#;
+/// let docs = load_theorem_docs_toml(&SourceId::new("t.theorem.toml"), toml).unwrap();
+/// assert_eq!(docs[0].theorem.as_str(), "T");
+/// ```
+pub fn load_theorem_docs_toml(
+    source: &SourceId,
+    input: &str,
+) -> Result<Vec<TheoremDoc>, SchemaError> {
+    let table = DeTable::parse(input).map_err(|error| {
+        let offset = error.span().map_or(0, |span| span.start);
+        let message = error.message().to_owned();
+        syntax_error(SourceFormat::Toml, source, message, position(input, offset))
+    })?;
+    let mut rendering = Rendering {
+        input,
+        rendered: RenderedSource::default(),
+    };
+    rendering.table("", table.get_ref(), (1, 1));
+    rendering.rendered.load(source)
+}
+
+/// The rendering of a TOML source as JSON text.
+struct Rendering<'i> {
+    input: &'i str,
+    rendered: RenderedSource,
+}
+
+impl Rendering<'_> {
+    /// Renders `value` after `prefix`, which holds its key when it has one.
+    fn value(&mut self, prefix: &str, value: &DeValue<'_>, origin: Position) {
+        match value {
+            DeValue::Table(table) => self.table(prefix, table, origin),
+            DeValue::Array(items) => self.array(prefix, items, origin),
+            DeValue::String(text) => self
+                .rendered
+                .line(&format!("{prefix}{}", quoted(text)), origin),
+            DeValue::Integer(integer) => {
+                let decimal = i128::from_str_radix(integer.as_str(), integer.radix())
+                    .map_or_else(|_| integer.as_str().to_owned(), |number| number.to_string());
+                self.rendered.line(&format!("{prefix}{decimal}"), origin);
+            }
+            DeValue::Float(float) => {
+                self.rendered
+                    .line(&format!("{prefix}{}", yaml_float(float.as_str())), origin);
+            }
+            DeValue::Boolean(flag) => self.rendered.line(&format!("{prefix}{flag}"), origin),
+            DeValue::Datetime(datetime) => {
+                self.rendered.line(
+                    &format!("{prefix}{}", quoted(&datetime.to_string())),
+                    origin,
+                );
+            }
+        }
+    }
+
+    fn table(&mut self, prefix: &str, table: &DeTable<'_>, origin: Position) {
+        self.rendered.line(&format!("{prefix}{{"), origin);
+        for (index, (key, value)) in table.iter().enumerate() {
+            if index > 0 {
+                self.rendered.separate();
+            }
+            let key_prefix = format!("{}: ", quoted(key.get_ref()));
+            self.value(&key_prefix, value.get_ref(), self.position(key));
+        }
+        self.rendered.line("}", origin);
+    }
+
+    fn array(&mut self, prefix: &str, items: &[Spanned<DeValue<'_>>], origin: Position) {
+        self.rendered.line(&format!("{prefix}["), origin);
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.rendered.separate();
+            }
+            self.value("", item.get_ref(), self.position(item));
+        }
+        self.rendered.line("]", origin);
+    }
+
+    fn position<T>(&self, spanned: &Spanned<T>) -> Position {
+        position(self.input, spanned.span().start)
+    }
+}
+
+/// Spells a TOML float as a YAML float, whose special values differ.
+fn yaml_float(float: &str) -> &str {
+    match float {
+        "inf" | "+inf" => ".inf",
+        "-inf" => "-.inf",
+        "nan" | "+nan" | "-nan" => ".nan",
+        finite => finite,
+    }
+}
+
+#[cfg(test)]
+#[path = "loader_toml_tests.rs"]
+mod tests;
@@ -0,0 +1,153 @@
+//! Unit tests for the TOML front-end.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{ForallVar, SchemaDiagnosticCode};
+
+const EVIDENCE: &str = concat!(
+    "[Evidence.kani]\n",
+    "unwind = 1\n",
+    "expect = \"SUCCESS\"\n",
+    "allow_vacuous = true\n",
+    "vacuity_because = \"none\"\n",
+);
+
+fn theorem(body: &str) -> String {
+    format!("Theorem = \"T\"\nAbout = \"Example\"\n{body}{EVIDENCE}")
+}
+
+fn load(input: &str) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_toml(&SourceId::new("t.theorem.toml"), input)
+}
+
+fn located(error: &SchemaError) -> (SchemaDiagnosticCode, usize, usize) {
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.location.source, "t.theorem.toml");
+    (
+        diagnostic.code,
+        diagnostic.location.line,
+        diagnostic.location.column,
+    )
+}
+
+#[test]
+fn loads_a_theorem_table() {
+    let input = theorem(concat!(
+        "Tags = [\"smoke\"]\n",
+        "\n",
+        "[Forall]\n",
+        "x = \"u8\"\n",
+        "\n",
+        "[[Prove]]\n",
+        "assert = \"x == x\"\n",
+        "because = \"equality is reflexive\"\n",
+        "\n",
+    ));
+    let docs = load(&input).expect("TOML theorem should load");
+
+    assert_eq!(docs.len(), 1);
+    let doc = docs.first().expect("one document");
+    assert_eq!(doc.theorem.as_str(), "T");
+    assert_eq!(doc.tags, ["smoke"]);
+    assert_eq!(doc.prove.len(), 1);
+}
+
+#[test]
+fn keeps_table_order() {
+    let input = theorem(concat!(
+        "[Forall]\n",
+        "zeta = \"u8\"\n",
+        "alpha = \"u8\"\n",
+        "\n",
+        "[[Prove]]\n",
+        "assert = \"zeta == alpha || true\"\n",
+        "because = \"trivially true\"\n",
+        "\n",
+    ));
+    let docs = load(&input).expect("TOML theorem should load");
+    let names: Vec<&str> = docs
+        .iter()
+        .flat_map(|doc| doc.forall.keys())
+        .map(ForallVar::as_str)
+        .collect();
+
+    assert_eq!(names, ["zeta", "alpha"]);
+}
+
+#[rstest]
+#[case::hexadecimal("0x10", "16")]
+#[case::octal("0o17", "15")]
+#[case::binary("0b101", "5")]
+#[case::separated("1_000", "1000")]
+fn integers_are_written_in_decimal(#[case] literal: &str, #[case] decimal: &str) {
+    let input = format!("unwind = {literal}\n");
+    let table = DeTable::parse(&input).expect("TOML parses");
+    let mut rendering = Rendering {
+        input: &input,
+        rendered: RenderedSource::default(),
+    };
+    rendering.table("", table.get_ref(), (1, 1));
+
+    assert_eq!(
+        rendering.rendered.text,
+        format!("{{\n\"unwind\": {decimal}\n}}\n")
+    );
+    assert_eq!(rendering.rendered.origins, [(1, 1), (1, 1), (1, 1)]);
+}
+
+#[test]
+fn syntax_errors_are_reported_as_toml() {
+    let error = load("Theorem = \"T\"\nAbout = \n").expect_err("TOML is malformed");
+
+    assert!(matches!(
+        error,
+        SchemaError::Syntax {
+            format: SourceFormat::Toml,
+            ..
+        }
+    ));
+    assert_eq!(located(&error), (SchemaDiagnosticCode::ParseFailure, 2, 9));
+}
+
+#[test]
+fn unknown_keys_are_located_at_the_toml_key() {
+    let input = theorem(concat!(
+        "[[Prove]]\n",
+        "assert = \"true\"\n",
+        "because = \"trivially true\"\n",
+        "becuase = \"typo\"\n",
+        "\n",
+    ));
+    let error = load(&input).expect_err("unknown key should be rejected");
+    let (code, line, column) = located(&error);
+
+    assert_eq!(code, SchemaDiagnosticCode::ParseFailure);
+    assert_eq!((line, column), (6, 1));
+    assert!(error.to_string().contains("line 6 column 1: "), "{error}");
+}
+
+#[test]
+fn validation_errors_are_located_in_the_toml_source() {
+    let input = theorem(concat!(
+        "[[Prove]]\n",
+        "assert = \"true\"\n",
+        "because = \"  \"\n",
+        "\n",
+    ));
+    let error = load(&input).expect_err("blank reason should be rejected");
+    let (code, line, _) = located(&error);
+
+    assert_eq!(code, SchemaDiagnosticCode::ValidationFailure);
+    assert!((3..=5).contains(&line), "{error}");
+}
+
+#[rstest]
+#[case::infinity("inf", ".inf")]
+#[case::negative_infinity("-inf", "-.inf")]
+#[case::not_a_number("nan", ".nan")]
+#[case::finite("1.5e3", "1.5e3")]
+fn floats_use_yaml_spellings(#[case] toml: &str, #[case] yaml: &str) {
+    assert_eq!(yaml_float(toml), yaml);
+}
@@ -7,40 +7,120 @@
 
 mod action_name;
 pub mod arg_value;
+mod defaults;
 mod diagnostic;
+mod emit;
 mod error;
 mod expr;
+mod expr_scope;
+mod forall;
 mod identifier;
+mod include;
+mod json_schema;
+mod kani_limits;
+mod lenient;
+mod load_options;
 mod loader;
+mod loader_context;
 mod loader_decode_location;
+mod loader_dependency;
+mod loader_duplicate;
+mod loader_json;
 mod loader_message;
+mod loader_options;
+mod loader_parse_failure;
+mod loader_ron;
+mod loader_stream;
+mod loader_toml;
 mod newtypes;
+mod normalize;
+mod pretty;
 mod raw;
 mod raw_action;
+mod raw_document;
+mod raw_evidence;
+mod raw_extends;
+mod raw_fixtures;
+mod raw_forall;
+mod raw_kani;
+mod raw_obligation;
+mod raw_shared;
+mod raw_step_entry;
+mod raw_suite;
+mod rendered_source;
 pub(crate) mod rust_type;
+mod section_key;
+mod source_format;
 mod source_id;
+mod spanned;
 mod step;
+mod suite;
+mod suite_policy;
+mod symbol;
 #[cfg(test)]
 mod test_support;
+mod theorem_expr;
+mod type_alias;
 mod types;
 mod validate;
 mod validation_reason;
 mod value;
+mod visit;
+mod yaml_anchor;
 
 #[cfg(any(test, feature = "test-support"))]
 #[doc(hidden)]
 pub mod test_fixtures;
 
+pub(crate) use action_name::validate_canonical_action_name;
 pub use arg_value::{ArgDecodeError, ArgValue, LiteralValue};
+pub(crate) use diagnostic::create_diagnostic;
 pub use diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, SourceLocation};
+pub use emit::emit_theorem_docs;
 pub use error::SchemaError;
+pub(crate) use expr_scope::referenced_variables;
+pub use forall::{ForallChoice, ForallDomain, NumericBound};
 pub use identifier::validate_identifier;
-pub use loader::{load_theorem_docs, load_theorem_docs_with_source};
+pub use include::IncludeResolver;
+pub use json_schema::export_json_schema;
+pub use kani_limits::{KaniLimitError, KaniTimeout, MemoryLimit};
+pub use load_options::{AliasPolicy, LoadOptions, UnknownKeyPolicy};
+pub use loader::{
+    load_theorem_docs, load_theorem_docs_with_includes, load_theorem_docs_with_manifest,
+    load_theorem_docs_with_source,
+};
+pub use loader_json::load_theorem_docs_json;
+pub use loader_options::{LoadedTheoremDocs, load_theorem_docs_with_options};
+pub(crate) use loader_parse_failure::deserialize_failure;
+pub use loader_ron::load_theorem_docs_ron;
+pub use loader_stream::{iter_theorem_docs, iter_theorem_docs_with_source};
+pub use loader_toml::load_theorem_docs_toml;
 pub use newtypes::{ForallVar, TheoremName};
+pub(crate) use raw_document::RawDocument;
+pub(crate) use rendered_source::position;
+pub(crate) use section_key::{SECTION_KEYS, canonical_for_alias};
+pub use source_format::SourceFormat;
 pub use source_id::SourceId;
+pub(crate) use spanned::theorem_name_locations;
+pub use spanned::{
+    SpannedTheoremDoc, load_spanned_theorem_docs, load_spanned_theorem_docs_with_includes,
+};
+pub use suite::{LoadedSuites, TheoremSuite, load_suites, load_suites_with_includes};
+pub use suite_policy::{EvidenceBackend, SuiteRequirements};
+pub use symbol::Symbol;
+pub use theorem_expr::{TheoremExpr, TheoremExprError};
+pub(crate) use type_alias::expand_type_aliases;
 pub use types::{
-    ActionCall, ActionSignature, Assertion, Assumption, Evidence, KaniEvidence, KaniExpectation,
-    LetBinding, LetCall, LetMust, MaybeBlock, Step, StepCall, StepMaybe, StepMust, TheoremDoc,
-    WitnessCheck,
+    ActionCall, ActionSignature, Assertion, Assumption, CounterexampleConstraint, Evidence,
+    ForeachBlock, InlineAssert, KaniEvidence, KaniExpectation, KaniSolver, KaniStub,
+    LATEST_SCHEMA_VERSION, LetBinding, LetCall, LetMust, MaybeBlock, RepeatBlock,
+    SUPPORTED_SCHEMA_VERSIONS, Step, StepAssert, StepCall, StepExpectErr, StepForeach, StepMaybe,
+    StepMust, StepRepeat, TheoremDoc, TraceLink, WitnessCheck,
 };
-pub use value::TheoremValue;
+pub(crate) use validate::{arg_variables, validate_theorem_doc};
+pub(crate) use validation_reason::IndexedValidationSection;
+#[cfg(feature = "json")]
+pub use value::JsonValueError;
+pub use value::{TheoremValue, ValueConversionError};
+pub use visit::{StepPath, StepVisitor, walk_steps, walk_theorem};
+pub(crate) use yaml_anchor::parse_options;
@@ -8,8 +8,8 @@ use std::borrow::Borrow;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
-use serde::Deserialize;
 use serde::de;
+use serde::{Deserialize, Serialize, Serializer};
 
 use super::identifier::validate_identifier;
 
@@ -66,6 +66,12 @@ impl fmt::Display for TheoremName {
     }
 }
 
+impl Serialize for TheoremName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 impl<'de> Deserialize<'de> for TheoremName {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -136,6 +142,12 @@ impl fmt::Display for ForallVar {
     }
 }
 
+impl Serialize for ForallVar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 impl<'de> Deserialize<'de> for ForallVar {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
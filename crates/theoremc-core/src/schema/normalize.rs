@@ -0,0 +1,228 @@
+//! Canonical normalization of loaded theorem documents.
+//!
+//! Documents that differ only in presentation, such as padding around prose,
+//! spacing inside Rust expressions and types, or the key spelling of their
+//! source, normalize to equal documents. [`TheoremDoc::canonical_hash`]
+//! fingerprints the canonical YAML of the normalized form, so such documents
+//! also hash equally.
+
+use std::sync::Arc;
+
+use quote::ToTokens;
+
+use super::arg_value::ArgValue;
+use super::emit::emit_theorem_docs;
+use super::forall::ForallDomain;
+use super::rust_type::canonical_token_stream;
+use super::theorem_expr::TheoremExpr;
+use super::types::{
+    ActionCall, ActionSignature, CounterexampleConstraint, KaniEvidence, LetBinding, Step,
+    TheoremDoc,
+};
+use crate::ledger::content_fingerprint;
+
+impl TheoremDoc {
+    /// Returns the document in canonical form.
+    ///
+    /// Prose such as `About`, `Tags`, `Given`, trace links, and `because`
+    /// justifications is trimmed. Rust expressions and types are re-printed
+    /// from their `syn` token streams, so `amount>=1` and `amount >= 1`, or
+    /// `Vec<u8>` and `Vec <u8>`, become the same string; strings that do not
+    /// parse are only trimmed. Names, identifiers, and literal argument
+    /// values are kept as loaded. Key casing needs no work, because a loaded
+    /// document no longer records how its keys were spelled.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let yaml = r#"
+    ///     Theorem: Spacing
+    ///     About: "  Padded prose  "
+    ///     Forall:
+    ///       amount: u64
+    ///     Prove:
+    ///       - assert: "amount>=0"
+    ///         because: unsigned
+    ///     Evidence:
+    ///       kani: { unwind: 1, expect: SUCCESS }
+    ///     Witness:
+    ///       - cover: "amount==1"
+    ///         because: reachable
+    ///     "#;
+    ///     let docs = load_theorem_docs(yaml).unwrap();
+    ///
+    ///     let normalized = docs[0].normalize();
+    ///     assert_eq!(normalized.about, "Padded prose");
+    ///     assert_eq!(normalized.prove[0].assert_expr, "amount >= 0");
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let mut doc = self.clone();
+        doc.normalize_prose();
+        doc.normalize_types();
+        doc.normalize_checks();
+        for binding in Arc::make_mut(&mut doc.let_bindings).values_mut() {
+            normalize_call(match binding {
+                LetBinding::Call(let_call) => &mut let_call.call,
+                LetBinding::Must(let_must) => &mut let_must.must,
+            });
+        }
+        normalize_steps(Arc::make_mut(&mut doc.do_steps).as_mut_slice());
+        doc.evidence.kani.iter_mut().for_each(normalize_kani);
+        doc
+    }
+
+    /// Returns a fingerprint of the normalized document: `blake3:` followed
+    /// by the lowercase hex digest of its canonical YAML.
+    ///
+    /// Documents that normalize equal hash equally, and the hash is stable
+    /// across runs and platforms.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let source = |assertion: &str| {
+    ///         format!(
+    ///             "Theorem: Hashed\nAbout: example\nForall: {{ x: u8 }}\n\
+    ///              Prove: [{{ assert: '{assertion}', because: bounded }}]\n\
+    ///              Evidence: {{ kani: {{ unwind: 1, expect: SUCCESS }} }}\n\
+    ///              Witness: [{{ cover: 'x == 0', because: reachable }}]\n"
+    ///         )
+    ///     };
+    ///     let tight = load_theorem_docs(&source("x<=255")).unwrap();
+    ///     let spaced = load_theorem_docs(&source("x <= 255")).unwrap();
+    ///
+    ///     assert_eq!(tight[0].canonical_hash(), spaced[0].canonical_hash());
+    #[must_use]
+    pub fn canonical_hash(&self) -> String {
+        content_fingerprint(&emit_theorem_docs(std::slice::from_ref(&self.normalize())))
+    }
+
+    fn normalize_prose(&mut self) {
+        trim(&mut self.about);
+        self.tags.iter_mut().for_each(trim);
+        self.given.iter_mut().for_each(trim);
+        for trace in &mut self.traces {
+            trim(&mut trace.id);
+            trim(&mut trace.system);
+            trace.url.iter_mut().for_each(trim);
+        }
+    }
+
+    fn normalize_types(&mut self) {
+        self.types.values_mut().for_each(normalize_type);
+        self.forall.values_mut().for_each(normalize_type);
+        self.forall_domains.values_mut().for_each(normalize_domain);
+        self.actions.values_mut().for_each(normalize_signature);
+    }
+
+    fn normalize_checks(&mut self) {
+        for assumption in &mut self.assume {
+            normalize_theorem_expr(&mut assumption.expr);
+            trim(&mut assumption.because);
+        }
+        for witness in &mut self.witness {
+            normalize_theorem_expr(&mut witness.cover);
+            trim(&mut witness.because);
+        }
+        for assertion in &mut self.prove {
+            normalize_theorem_expr(&mut assertion.assert_expr);
+            trim(&mut assertion.because);
+        }
+    }
+}
+
+fn trim<T: AsRef<str> + From<String>>(text: &mut T) {
+    let trimmed = text.as_ref().trim();
+    if trimmed.len() != text.as_ref().len() {
+        *text = T::from(trimmed.to_owned());
+    }
+}
+
+/// Re-prints a Rust expression from its tokens, or trims it when it does
+/// not parse.
+fn normalize_expr(expr: &mut String) {
+    match syn::parse_str::<syn::Expr>(expr.trim()) {
+        Ok(parsed) => *expr = parsed.to_token_stream().to_string(),
+        Err(_) => trim(expr),
+    }
+}
+
+/// Re-prints a theorem expression from its tokens through the validating
+/// constructor, keeping it unchanged should the re-printed form not parse.
+fn normalize_theorem_expr(expr: &mut TheoremExpr) {
+    if let Ok(parsed) = expr.to_syn_expr()
+        && let Ok(printed) = TheoremExpr::new(parsed.to_token_stream().to_string())
+    {
+        *expr = printed;
+    }
+}
+
+/// Re-prints a Rust type from its tokens, or trims it when it does not
+/// parse.
+fn normalize_type<T: AsRef<str> + From<String>>(ty: &mut T) {
+    match canonical_token_stream(ty.as_ref()) {
+        Some(canonical) => *ty = T::from(canonical),
+        None => trim(ty),
+    }
+}
+
+fn normalize_domain(domain: &mut ForallDomain) {
+    domain
+        .type_strings_mut()
+        .into_iter()
+        .for_each(normalize_type);
+    if let ForallDomain::Derived { expr } = domain {
+        normalize_expr(expr);
+    }
+}
+
+fn normalize_signature(signature: &mut ActionSignature) {
+    signature.params.values_mut().for_each(normalize_type);
+    normalize_type(&mut signature.returns);
+}
+
+fn normalize_call(call: &mut ActionCall) {
+    for value in call.args.values_mut() {
+        if let ArgValue::Expr(expr) = value {
+            normalize_expr(expr);
+        }
+    }
+}
+
+fn normalize_steps(steps: &mut [Step]) {
+    steps.iter_mut().for_each(normalize_step);
+}
+
+fn normalize_step(step: &mut Step) {
+    match step {
+        Step::Call(step_call) => normalize_call(&mut step_call.call),
+        Step::Must(step_must) => normalize_call(&mut step_must.must),
+        Step::ExpectErr(step_err) => normalize_call(&mut step_err.expect_err),
+        Step::Maybe(step_maybe) => {
+            trim(&mut step_maybe.maybe.because);
+            normalize_steps(&mut step_maybe.maybe.do_steps);
+            normalize_steps(&mut step_maybe.maybe.else_steps);
+        }
+        Step::Repeat(step_repeat) => normalize_steps(&mut step_repeat.repeat.do_steps),
+        Step::Foreach(step_foreach) => normalize_steps(&mut step_foreach.foreach.do_steps),
+        Step::Assert(step_assert) => {
+            normalize_expr(&mut step_assert.assert.expr);
+            trim(&mut step_assert.assert.because);
+        }
+    }
+}
+
+fn normalize_kani(kani: &mut KaniEvidence) {
+    kani.vacuity_because.iter_mut().for_each(trim);
+    for constraint in kani.counterexample.values_mut() {
+        if let CounterexampleConstraint::Satisfies { satisfies } = constraint {
+            normalize_expr(satisfies);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "normalize_tests.rs"]
+mod tests;
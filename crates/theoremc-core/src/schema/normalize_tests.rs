@@ -0,0 +1,172 @@
+//! Unit tests for canonical document normalization and hashing.
+
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use crate::schema::{ArgValue, LetBinding, Step, Symbol, TheoremDoc, load_theorem_docs};
+
+fn load_one(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("source should load")
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem")
+}
+
+const TIGHT: &str = r#"
+Theorem: Deposit
+About: Deposits grow the balance
+Tags: [ledger]
+Forall:
+  amount: { type: "Vec<u8>", optional: true }
+  limit: { type: u64, from: "amount.map_or(0,|a|a.len() as u64)" }
+Actions:
+  ledger.deposit:
+    params: { amount: "Option<Vec<u8>>" }
+    returns: "Result<u64,crate::Error>"
+Assume:
+  - assume: "limit<=10"
+    because: bounded
+Let:
+  before:
+    must:
+      action: ledger.deposit
+      args: { amount: { expr: "amount.clone()" } }
+Do:
+  - maybe:
+      because: sometimes
+      do:
+        - assert: { expr: "before>=0", because: unsigned }
+Prove:
+  - assert: "before<=u64::MAX"
+    because: fits
+Evidence:
+  kani:
+    unwind: 11
+    expect: FAILURE
+    counterexample:
+      limit: { satisfies: "limit>5" }
+Witness:
+  - cover: "limit==1"
+    because: reachable
+"#;
+
+const SPACED: &str = r#"
+theorem: Deposit
+about: "  Deposits grow the balance "
+tags: [" ledger "]
+forall:
+  amount: { type: "Vec < u8 >", optional: true }
+  limit: { type: u64, from: "amount.map_or(0, |a| a.len() as u64)" }
+actions:
+  ledger.deposit:
+    params: { amount: "Option< Vec<u8> >" }
+    returns: "Result<u64, crate::Error>"
+assume:
+  - expr: "limit <= 10"
+    because: " bounded"
+let:
+  before:
+    must:
+      action: ledger.deposit
+      args: { amount: { expr: "amount . clone ()" } }
+do:
+  - maybe:
+      because: "sometimes  "
+      do:
+        - assert: { expr: "before >= 0", because: unsigned }
+prove:
+  - assert: "before <= u64 :: MAX"
+    because: fits
+evidence:
+  kani:
+    unwind: 11
+    expect: FAILURE
+    counterexample:
+      limit: { satisfies: "limit > 5" }
+witness:
+  - cover: "limit == 1"
+    because: "reachable "
+"#;
+
+#[test]
+fn presentation_differences_normalize_equal() {
+    let tight = load_one(TIGHT);
+    let spaced = load_one(SPACED);
+
+    assert_ne!(tight, spaced);
+    assert_eq!(tight.normalize(), spaced.normalize());
+    assert_eq!(tight.canonical_hash(), spaced.canonical_hash());
+}
+
+#[test]
+fn normalization_re_prints_expressions_and_types() {
+    let doc = load_one(SPACED).normalize();
+
+    assert_eq!(doc.about, "Deposits grow the balance");
+    assert_eq!(doc.tags, ["ledger"]);
+    assert_eq!(
+        doc.forall.values().map(Symbol::as_str).collect::<Vec<_>>(),
+        ["Option < Vec < u8 > >", "u64"]
+    );
+    assert_eq!(
+        doc.assume.first().map(|a| a.expr.as_str()),
+        Some("limit <= 10")
+    );
+    assert_eq!(
+        doc.prove.first().map(|a| a.assert_expr.as_str()),
+        Some("before <= u64 :: MAX")
+    );
+    let Some(LetBinding::Must(binding)) = doc.let_bindings.get("before") else {
+        panic!("binding should be a must call");
+    };
+    assert_eq!(
+        binding.must.args.get("amount"),
+        Some(&ArgValue::Expr("amount . clone ()".to_owned()))
+    );
+    let Some(Step::Maybe(step)) = doc.do_steps.first() else {
+        panic!("step should be a maybe block");
+    };
+    assert_eq!(step.maybe.because, "sometimes");
+}
+
+#[test]
+fn normalization_is_idempotent() {
+    let once = load_one(SPACED).normalize();
+
+    assert_eq!(once.normalize(), once);
+}
+
+#[test]
+fn clones_share_let_and_do_until_normalization_rewrites_them() {
+    let doc = load_one(TIGHT);
+    let snapshot = doc.clone();
+    let normalized = doc.normalize();
+
+    assert!(Arc::ptr_eq(&doc.let_bindings, &snapshot.let_bindings));
+    assert!(Arc::ptr_eq(&doc.do_steps, &snapshot.do_steps));
+    assert!(!Arc::ptr_eq(&doc.do_steps, &normalized.do_steps));
+    assert_eq!(doc, snapshot);
+    assert_ne!(doc.let_bindings, normalized.let_bindings);
+}
+
+#[rstest]
+#[case::assertion("before<=u64::MAX", "before<u64::MAX")]
+#[case::type_string("Result<u64,crate::Error>", "Result<u32,crate::Error>")]
+#[case::prose("Deposits grow the balance", "Deposits shrink the balance")]
+fn semantic_differences_hash_differently(#[case] original: &str, #[case] changed: &str) {
+    let doc = load_one(TIGHT);
+    let other = load_one(&TIGHT.replacen(original, changed, 1));
+
+    assert_ne!(doc.canonical_hash(), other.canonical_hash());
+}
+
+#[test]
+fn canonical_hashes_are_blake3_fingerprints() {
+    let hash = load_one(TIGHT).canonical_hash();
+
+    assert!(hash.starts_with("blake3:"));
+    assert_eq!(hash.len(), "blake3:".len() + 64);
+}
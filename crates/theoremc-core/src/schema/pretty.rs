@@ -0,0 +1,354 @@
+//! Human-readable outlines of theorem documents.
+//!
+//! The [`fmt::Display`] implementation for [`TheoremDoc`] renders an
+//! indented outline for CLI `show` output and debugging: the theorem and
+//! its intent, its inputs, numbered `Do` steps with their nested blocks,
+//! and every obligation with its `because` text. Unlike
+//! [`emit_theorem_docs`](super::emit_theorem_docs), the outline is not meant
+//! to be loaded back.
+
+use std::fmt::{self, Write as _};
+
+use indexmap::IndexMap;
+
+use super::arg_value::{ArgValue, LiteralValue};
+use super::newtypes::TheoremName;
+use super::types::{ActionCall, Evidence, KaniEvidence, LetBinding, Step, TheoremDoc};
+use super::value::TheoremValue;
+
+/// Indentation added for each level of the outline.
+const INDENT: usize = 2;
+
+impl TheoremDoc {
+    /// Renders the document as the indented outline shown by its
+    /// [`fmt::Display`] implementation.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let yaml = r#"
+    ///     Theorem: Bounded
+    ///     About: Bytes stay in range
+    ///     Forall:
+    ///       x: u8
+    ///     Prove:
+    ///       - assert: "x <= 255"
+    ///         because: u8 is bounded
+    ///     Evidence:
+    ///       kani: { unwind: 1, expect: SUCCESS }
+    ///     Witness:
+    ///       - cover: "x == 0"
+    ///         because: zero is reachable
+    ///     "#;
+    ///     let docs = load_theorem_docs(yaml).unwrap();
+    ///
+    ///     let outline = docs[0].to_pretty_string();
+    ///     assert!(outline.starts_with("Theorem Bounded\n  About: Bytes stay in range\n"));
+    ///     assert!(outline.contains("    - x <= 255\n      because: u8 is bounded"));
+    #[must_use]
+    pub fn to_pretty_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for TheoremDoc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = Outline { f, started: false };
+        out.line(0, format_args!("Theorem {}", self.theorem))?;
+        out.line(INDENT, format_args!("About: {}", self.about))?;
+        write_header(&mut out, self)?;
+        write_inputs(&mut out, self)?;
+        write_steps_section(&mut out, self)?;
+        write_obligations(&mut out, self)?;
+        write_evidence(&mut out, &self.evidence)
+    }
+}
+
+/// Writes outline lines, separating them with newlines so the outline has
+/// no trailing newline.
+struct Outline<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    started: bool,
+}
+
+impl Outline<'_, '_> {
+    fn line(&mut self, indent: usize, text: fmt::Arguments<'_>) -> fmt::Result {
+        if self.started {
+            self.f.write_char('\n')?;
+        }
+        self.started = true;
+        write!(self.f, "{:indent$}{text}", "")
+    }
+
+    /// Writes a section heading followed by one `- ` item per entry.
+    fn list<T: fmt::Display>(
+        &mut self,
+        heading: &str,
+        entries: impl IntoIterator<Item = T>,
+    ) -> fmt::Result {
+        let mut items = entries.into_iter().peekable();
+        if items.peek().is_none() {
+            return Ok(());
+        }
+        self.line(INDENT, format_args!("{heading}:"))?;
+        items.try_for_each(|item| self.line(2 * INDENT, format_args!("- {item}")))
+    }
+
+    /// Writes an item followed by its `because` justification.
+    fn justified(&mut self, item: fmt::Arguments<'_>, because: &str) -> fmt::Result {
+        self.line(2 * INDENT, format_args!("- {item}"))?;
+        self.line(3 * INDENT, format_args!("because: {because}"))
+    }
+}
+
+fn write_header(out: &mut Outline<'_, '_>, doc: &TheoremDoc) -> fmt::Result {
+    if !doc.tags.is_empty() {
+        out.line(INDENT, format_args!("Tags: {}", doc.tags.join(", ")))?;
+    }
+    if !doc.depends_on.is_empty() {
+        let names: Vec<_> = doc.depends_on.iter().map(TheoremName::as_str).collect();
+        out.line(INDENT, format_args!("Depends on: {}", names.join(", ")))?;
+    }
+    out.list(
+        "Traces",
+        doc.traces
+            .iter()
+            .map(|trace| format!("{} ({})", trace.id, trace.system)),
+    )?;
+    out.list("Given", &doc.given)
+}
+
+fn write_inputs(out: &mut Outline<'_, '_>, doc: &TheoremDoc) -> fmt::Result {
+    out.list(
+        "Forall",
+        doc.forall.iter().map(|(name, ty)| format!("{name}: {ty}")),
+    )?;
+    if !doc.assume.is_empty() {
+        out.line(INDENT, format_args!("Assume:"))?;
+        for assumption in &doc.assume {
+            out.justified(format_args!("{}", assumption.expr), &assumption.because)?;
+        }
+    }
+    out.list(
+        "Let",
+        doc.let_bindings
+            .iter()
+            .map(|(name, binding)| format!("{name} = {}", LetView(binding))),
+    )
+}
+
+fn write_steps_section(out: &mut Outline<'_, '_>, doc: &TheoremDoc) -> fmt::Result {
+    if doc.do_steps.is_empty() {
+        return Ok(());
+    }
+    out.line(INDENT, format_args!("Do:"))?;
+    write_steps(out, &doc.do_steps, 2 * INDENT)
+}
+
+/// Writes `steps` numbered from 1, with nested blocks indented under the
+/// text of their step.
+fn write_steps(out: &mut Outline<'_, '_>, steps: &[Step], indent: usize) -> fmt::Result {
+    for (index, step) in steps.iter().enumerate() {
+        let number = format!("{}. ", index + 1);
+        out.line(indent, format_args!("{number}{}", StepView(step)))?;
+        write_step_body(out, step, indent + number.len())?;
+    }
+    Ok(())
+}
+
+fn write_step_body(out: &mut Outline<'_, '_>, step: &Step, indent: usize) -> fmt::Result {
+    match step {
+        Step::Maybe(block) => {
+            out.line(indent, format_args!("because: {}", block.maybe.because))?;
+            out.line(indent, format_args!("do:"))?;
+            write_steps(out, &block.maybe.do_steps, indent + INDENT)?;
+            if block.maybe.else_steps.is_empty() {
+                return Ok(());
+            }
+            out.line(indent, format_args!("else:"))?;
+            write_steps(out, &block.maybe.else_steps, indent + INDENT)
+        }
+        Step::Repeat(block) => write_steps(out, &block.repeat.do_steps, indent),
+        Step::Foreach(block) => write_steps(out, &block.foreach.do_steps, indent),
+        Step::Assert(block) => out.line(indent, format_args!("because: {}", block.assert.because)),
+        Step::Call(_) | Step::Must(_) | Step::ExpectErr(_) => Ok(()),
+    }
+}
+
+fn write_obligations(out: &mut Outline<'_, '_>, doc: &TheoremDoc) -> fmt::Result {
+    out.line(INDENT, format_args!("Prove:"))?;
+    for assertion in &doc.prove {
+        match &assertion.step {
+            Some(id) => out.justified(
+                format_args!("{} (after step {id})", assertion.assert_expr),
+                &assertion.because,
+            )?,
+            None => out.justified(
+                format_args!("{}", assertion.assert_expr),
+                &assertion.because,
+            )?,
+        }
+    }
+    if doc.witness.is_empty() {
+        return Ok(());
+    }
+    out.line(INDENT, format_args!("Witness:"))?;
+    for witness in &doc.witness {
+        out.justified(format_args!("{}", witness.cover), &witness.because)?;
+    }
+    Ok(())
+}
+
+fn write_evidence(out: &mut Outline<'_, '_>, evidence: &Evidence) -> fmt::Result {
+    let kani = evidence
+        .kani
+        .iter()
+        .map(|config| KaniView(config).to_string());
+    let others = [
+        evidence.verus.as_ref().map(|_| "verus".to_owned()),
+        evidence
+            .stateright
+            .as_ref()
+            .map(|_| "stateright".to_owned()),
+    ];
+    out.list("Evidence", kani.chain(others.into_iter().flatten()))
+}
+
+/// Renders a Kani configuration as its name, bound, and expectation.
+struct KaniView<'a>(&'a KaniEvidence);
+
+impl fmt::Display for KaniView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = self.0;
+        f.write_str("kani")?;
+        if let Some(name) = &config.name {
+            write!(f, " {name}")?;
+        }
+        write!(
+            f,
+            ": unwind {}, expect {}",
+            config.unwind,
+            config.expect.as_str()
+        )?;
+        if config.allow_vacuous {
+            f.write_str(", vacuity allowed")?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a `Let` binding's value as its keyword and call.
+struct LetView<'a>(&'a LetBinding);
+
+impl fmt::Display for LetView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            LetBinding::Call(binding) => write!(f, "call {}", CallView(&binding.call)),
+            LetBinding::Must(binding) => write!(f, "must {}", CallView(&binding.must)),
+        }
+    }
+}
+
+/// Renders the first line of a step: its keyword and call or block header.
+struct StepView<'a>(&'a Step);
+
+impl fmt::Display for StepView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Step::Call(step) => write!(f, "call {}", CallView(&step.call))?,
+            Step::Must(step) => write!(f, "must {}", CallView(&step.must))?,
+            Step::ExpectErr(step) => write!(
+                f,
+                "expect_err {} fails with {}",
+                CallView(&step.expect_err),
+                step.error
+            )?,
+            Step::Maybe(_) => f.write_str("maybe")?,
+            Step::Repeat(step) => write!(f, "repeat {} times", step.repeat.times)?,
+            Step::Foreach(step) => write!(
+                f,
+                "foreach {} in {}",
+                step.foreach.as_binding, step.foreach.source
+            )?,
+            Step::Assert(step) => write!(f, "assert {}", step.assert.expr)?,
+        }
+        self.0.id().map_or(Ok(()), |id| write!(f, " [id: {id}]"))
+    }
+}
+
+/// Renders an action call as `action(param: value, ...)`, followed by its
+/// result binding.
+struct CallView<'a>(&'a ActionCall);
+
+impl fmt::Display for CallView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.0.action)?;
+        for (index, (param, value)) in self.0.args.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{param}: ")?;
+            write_arg(f, value)?;
+        }
+        f.write_char(')')?;
+        self.0
+            .as_binding
+            .as_ref()
+            .map_or(Ok(()), |name| write!(f, " as {name}"))
+    }
+}
+
+/// Writes references and expressions bare and literals as Rust literals.
+fn write_arg(f: &mut fmt::Formatter<'_>, value: &ArgValue) -> fmt::Result {
+    match value {
+        ArgValue::Literal(LiteralValue::String(text)) => write!(f, "{text:?}"),
+        ArgValue::Literal(LiteralValue::Integer(number)) => write!(f, "{number}"),
+        ArgValue::Literal(LiteralValue::Float(number)) => write!(f, "{number}"),
+        ArgValue::Literal(LiteralValue::Bool(flag)) => write!(f, "{flag}"),
+        ArgValue::Reference(name) => f.write_str(name),
+        ArgValue::Expr(source) => f.write_str(source),
+        ArgValue::RawSequence(items) => write_sequence(f, items),
+        ArgValue::RawMap(entries) => write_mapping(f, entries),
+    }
+}
+
+/// Writes a raw value in YAML flow style, quoting strings.
+fn write_value(f: &mut fmt::Formatter<'_>, value: &TheoremValue) -> fmt::Result {
+    match value {
+        TheoremValue::Bool(flag) => write!(f, "{flag}"),
+        TheoremValue::Integer(number) => write!(f, "{number}"),
+        TheoremValue::Float(number) => write!(f, "{number}"),
+        TheoremValue::String(text) => write!(f, "{text:?}"),
+        TheoremValue::Sequence(items) => write_sequence(f, items),
+        TheoremValue::Mapping(entries) => write_mapping(f, entries),
+    }
+}
+
+fn write_sequence(f: &mut fmt::Formatter<'_>, items: &[TheoremValue]) -> fmt::Result {
+    f.write_char('[')?;
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_value(f, item)?;
+    }
+    f.write_char(']')
+}
+
+fn write_mapping(
+    f: &mut fmt::Formatter<'_>,
+    entries: &IndexMap<String, TheoremValue>,
+) -> fmt::Result {
+    f.write_char('{')?;
+    for (index, (key, entry)) in entries.iter().enumerate() {
+        f.write_str(if index > 0 { ", " } else { " " })?;
+        write!(f, "{key}: ")?;
+        write_value(f, entry)?;
+    }
+    f.write_str(if entries.is_empty() { "}" } else { " }" })
+}
+
+#[cfg(test)]
+#[path = "pretty_tests.rs"]
+mod tests;
@@ -0,0 +1,155 @@
+//! Unit tests for the human-readable theorem outline.
+
+use pretty_assertions::assert_eq;
+
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+fn load_one(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("source should load")
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem")
+}
+
+const DEPOSIT: &str = r#"
+Theorem: Deposit
+About: Deposits grow the balance
+Tags: [ledger, smoke]
+Traces:
+  - { id: REQ-1, system: specs }
+Given:
+  - an empty ledger
+Forall:
+  amount: { type: u64, min: 1, max: 10 }
+  items: Vec<u32>
+Actions:
+  ledger.deposit:
+    params: { amount: u64 }
+    returns: "Result<u64, crate::LedgerError>"
+  ledger.tag:
+    params: { labels: "Vec<String>", note: String }
+    returns: "()"
+Assume:
+  - assume: "amount < 10"
+    because: bounded
+Let:
+  start:
+    must:
+      action: ledger.deposit
+      args: { amount: { ref: amount } }
+Do:
+  - must:
+      action: ledger.deposit
+      args: { amount: { expr: "amount + 1" } }
+      as: after
+    id: deposit
+  - maybe:
+      because: sometimes
+      do:
+        - assert: { expr: "after > 0", because: deposits add }
+      else:
+        - repeat:
+            times: 2
+            do:
+              - expect_err:
+                  action: ledger.deposit
+                  args: { amount: 0 }
+                  error: Zero
+  - foreach:
+      in: items
+      as: item
+      do:
+        - call:
+            action: ledger.tag
+            args: { labels: [a, b], note: "first" }
+Prove:
+  - assert: "after > amount"
+    because: deposits add
+    step: deposit
+Evidence:
+  kani:
+    unwind: 4
+    expect: FAILURE
+Witness:
+  - cover: "amount == 1"
+    because: reachable
+"#;
+
+const DEPOSIT_OUTLINE: &str = r#"Theorem Deposit
+  About: Deposits grow the balance
+  Tags: ledger, smoke
+  Traces:
+    - REQ-1 (specs)
+  Given:
+    - an empty ledger
+  Forall:
+    - amount: u64
+    - items: Vec<u32>
+  Assume:
+    - amount < 10
+      because: bounded
+  Let:
+    - start = must ledger.deposit(amount: amount)
+  Do:
+    1. must ledger.deposit(amount: amount + 1) as after [id: deposit]
+    2. maybe
+       because: sometimes
+       do:
+         1. assert after > 0
+            because: deposits add
+       else:
+         1. repeat 2 times
+            1. expect_err ledger.deposit(amount: 0) fails with Zero
+    3. foreach item in items
+       1. call ledger.tag(labels: ["a", "b"], note: "first")
+  Prove:
+    - after > amount (after step deposit)
+      because: deposits add
+  Witness:
+    - amount == 1
+      because: reachable
+  Evidence:
+    - kani: unwind 4, expect FAILURE"#;
+
+#[test]
+fn outline_covers_every_section() {
+    let doc = load_one(DEPOSIT);
+
+    assert_eq!(doc.to_pretty_string(), DEPOSIT_OUTLINE);
+}
+
+#[test]
+fn display_matches_the_pretty_string() {
+    let doc = load_one(DEPOSIT);
+
+    assert_eq!(format!("{doc}"), doc.to_pretty_string());
+}
+
+#[test]
+fn empty_optional_sections_are_omitted() {
+    let doc = load_one(
+        r#"
+Theorem: Minimal
+About: A minimal theorem
+Prove:
+  - assert: "true"
+    because: trivially true
+Evidence:
+  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: nothing to cover }
+"#,
+    );
+
+    assert_eq!(
+        doc.to_pretty_string(),
+        concat!(
+            "Theorem Minimal\n",
+            "  About: A minimal theorem\n",
+            "  Prove:\n",
+            "    - true\n",
+            "      because: trivially true\n",
+            "  Evidence:\n",
+            "    - kani: unwind 1, expect SUCCESS, vacuity allowed",
+        )
+    );
+}
@@ -4,19 +4,27 @@
 //! for selected fields so validation failures can be mapped back to line and
 //! column coordinates deterministically.
 
+use std::sync::Arc;
+
 use indexmap::IndexMap;
-use serde::{Deserialize, Deserializer, de::Error};
+use serde::Deserialize;
 use serde_saphyr::{Location, Spanned};
 
 use super::arg_value::ArgDecodeError;
 use super::newtypes::{ForallVar, TheoremName};
 use super::raw_action::{self, RawLetBinding, RawStep};
-use super::types::{Evidence, KaniEvidence, KaniExpectation, TheoremDoc};
-use super::validation_reason::{IndexedValidationField, ValidationReasonKind};
-use super::value::TheoremValue;
+use super::raw_evidence::RawEvidence;
+use super::raw_fixtures::FixtureOrigin;
+use super::raw_forall::RawForallEntry;
+use super::raw_kani::RawKaniEvidence;
+use super::raw_obligation::{RawAssertion, RawAssumption, RawWitnessCheck, take_entries};
+use super::symbol::Symbol;
+use super::theorem_expr::EntryExprError;
+use super::types::TheoremDoc;
+use super::validation_reason::{IndexedValidationField, TraceField, ValidationReasonKind};
 
 /// Errors raised during the raw-to-public conversion in
-/// [`RawTheoremDoc::to_theorem_doc`].
+/// [`RawTheoremDoc::take_theorem_doc`].
 ///
 /// Each variant identifies the location (binding name or step index)
 /// and wraps the underlying [`ArgDecodeError`] as a `#[source]` so
@@ -44,14 +52,29 @@ pub(crate) enum RawDocDecodeError {
         #[source]
         source: ArgDecodeError,
     },
+
+    /// A required field is absent from both the theorem and its defaults.
+    #[error("{field} is required")]
+    MissingField {
+        /// Dotted path of the missing field.
+        field: &'static str,
+    },
+
+    /// An `Assume`, `Prove`, or `Witness` expression is blank or does not
+    /// parse.
+    #[error("{}", .0.reason)]
+    Expression(EntryExprError),
 }
 
 impl RawDocDecodeError {
-    /// Returns the argument breadcrumb associated with the decode failure.
+    /// Returns the argument breadcrumb, or the missing field path, associated
+    /// with the decode failure.
     #[must_use]
     pub(crate) fn param(&self) -> &str {
         match self {
             Self::LetBinding { source, .. } | Self::DoStep { source, .. } => source.param(),
+            Self::MissingField { field } => field,
+            Self::Expression(_) => "",
         }
     }
 
@@ -60,7 +83,7 @@ impl RawDocDecodeError {
     pub(crate) fn let_binding_name(&self) -> Option<&str> {
         match self {
             Self::LetBinding { name, .. } => Some(name),
-            Self::DoStep { .. } => None,
+            Self::DoStep { .. } | Self::MissingField { .. } | Self::Expression(_) => None,
         }
     }
 
@@ -68,7 +91,7 @@ impl RawDocDecodeError {
     #[must_use]
     pub(crate) const fn do_step_index(&self) -> Option<usize> {
         match self {
-            Self::LetBinding { .. } => None,
+            Self::LetBinding { .. } | Self::MissingField { .. } | Self::Expression(_) => None,
             Self::DoStep { index, .. } => Some(*index),
         }
     }
@@ -79,17 +102,31 @@ impl RawDocDecodeError {
 #[serde(deny_unknown_fields)]
 pub(crate) struct RawTheoremDoc {
     #[serde(rename = "Schema", alias = "schema", default)]
-    pub(crate) schema: Option<u32>,
+    pub(crate) schema: Option<Spanned<u32>>,
     #[serde(rename = "Theorem", alias = "theorem")]
     pub(crate) theorem: Spanned<TheoremName>,
     #[serde(rename = "About", alias = "about")]
     pub(crate) about: Spanned<String>,
     #[serde(rename = "Tags", alias = "tags", default)]
-    pub(crate) tags: Vec<String>,
+    pub(crate) tags: Option<Vec<Symbol>>,
+    #[serde(rename = "Traces", alias = "traces", default)]
+    pub(crate) traces: Vec<RawTraceLink>,
     #[serde(rename = "Given", alias = "given", default)]
     pub(crate) given: Vec<String>,
+    #[serde(rename = "DependsOn", alias = "depends_on", default)]
+    pub(crate) depends_on: Vec<Spanned<TheoremName>>,
+    #[serde(rename = "Extends", alias = "extends", default)]
+    pub(crate) extends: Option<Spanned<TheoremName>>,
+    #[serde(rename = "Include", alias = "include", default)]
+    pub(crate) include: Vec<Spanned<String>>,
+    #[serde(rename = "Use", alias = "use", default)]
+    pub(crate) uses: Vec<Spanned<String>>,
+    #[serde(rename = "UseFixtures", alias = "use_fixtures", default)]
+    pub(crate) use_fixtures: Vec<Spanned<String>>,
+    #[serde(rename = "Types", alias = "types", default)]
+    pub(crate) types: IndexMap<String, String>,
     #[serde(rename = "Forall", alias = "forall", default)]
-    pub(crate) forall: IndexMap<ForallVar, String>,
+    pub(crate) forall: IndexMap<ForallVar, RawForallEntry>,
     #[serde(rename = "Actions", alias = "actions", default)]
     pub(crate) actions: IndexMap<String, super::types::ActionSignature>,
     #[serde(rename = "Assume", alias = "assume", default)]
@@ -102,107 +139,95 @@ pub(crate) struct RawTheoremDoc {
     pub(crate) do_steps: Vec<RawStep>,
     #[serde(rename = "Prove", alias = "prove")]
     pub(crate) prove: Vec<RawAssertion>,
-    #[serde(rename = "Evidence", alias = "evidence")]
-    pub(crate) evidence: RawEvidence,
-}
-
-/// Raw assumption with span-aware fields.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub(crate) struct RawAssumption {
-    #[serde(rename = "assume", alias = "expr")]
-    pub(crate) expr: Spanned<String>,
-    pub(crate) because: Spanned<String>,
-}
-
-/// Raw assertion with span-aware fields.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub(crate) struct RawAssertion {
-    #[serde(rename = "assert")]
-    pub(crate) assert_expr: Spanned<String>,
-    pub(crate) because: Spanned<String>,
-}
-
-/// Raw witness check with span-aware fields.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub(crate) struct RawWitnessCheck {
-    pub(crate) cover: Spanned<String>,
-    pub(crate) because: Spanned<String>,
-}
-
-/// Raw evidence container with span-aware Kani evidence fields.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub(crate) struct RawEvidence {
-    #[serde(default)]
-    pub(crate) kani: Option<RawKaniEvidence>,
-    #[serde(default)]
-    pub(crate) verus: Option<TheoremValue>,
-    #[serde(default)]
-    pub(crate) stateright: Option<TheoremValue>,
+    #[serde(rename = "Evidence", alias = "evidence", default)]
+    pub(crate) evidence: Option<RawEvidence>,
+    #[serde(rename = "Lints", alias = "lints", default)]
+    pub(crate) lints: IndexMap<String, String>,
+    /// Top-level keys in source order, spelled as written.
+    #[serde(skip)]
+    pub(crate) section_keys: Vec<Spanned<String>>,
+    /// Where each `Let` binding merged from a `UseFixtures` entry was
+    /// written.
+    #[serde(skip)]
+    pub(crate) fixture_origins: IndexMap<String, FixtureOrigin>,
 }
 
-/// Raw Kani evidence with span-aware fields used in validation diagnostics.
+/// Raw requirement trace link with span-aware fields.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
-pub(crate) struct RawKaniEvidence {
-    pub(crate) unwind: Spanned<u32>,
-    pub(crate) expect: KaniExpectation,
-    #[serde(default, deserialize_with = "deserialize_optional_allow_vacuous")]
-    pub(crate) allow_vacuous: Option<Spanned<bool>>,
+pub(crate) struct RawTraceLink {
+    pub(crate) id: Spanned<String>,
+    pub(crate) system: Spanned<String>,
     #[serde(default)]
-    pub(crate) vacuity_because: Option<Spanned<String>>,
+    pub(crate) url: Option<Spanned<String>>,
 }
 
 impl RawTheoremDoc {
-    /// Converts this raw document into the public theorem document type,
-    /// decoding argument values from raw YAML into [`ArgValue`] variants.
+    /// Moves this raw document's values into the public theorem document
+    /// type, decoding argument values from raw YAML into [`ArgValue`]
+    /// variants.
+    ///
+    /// Sections without spans are moved out whole and spanned entries give
+    /// up their values, so a loaded document is not held twice while it is
+    /// converted. The theorem name, every span, and the fixture origins stay
+    /// in place for the diagnostics validation may still need.
     ///
     /// # Errors
     ///
     /// Returns [`RawDocDecodeError`] when an argument value fails
-    /// decoding (e.g., an invalid `{ ref: ... }` target).
-    pub(crate) fn to_theorem_doc(&self) -> Result<TheoremDoc, RawDocDecodeError> {
-        let let_bindings = convert_let_bindings(&self.let_bindings)?;
-        let do_steps = convert_steps(&self.do_steps)?;
+    /// decoding (e.g., an invalid `{ ref: ... }` target), or when `Evidence`
+    /// or a required Kani field is missing after defaults have been applied.
+    pub(crate) fn take_theorem_doc(&mut self) -> Result<TheoremDoc, RawDocDecodeError> {
+        let evidence = self
+            .evidence
+            .as_ref()
+            .ok_or(RawDocDecodeError::MissingField { field: "Evidence" })?
+            .to_evidence()?;
+        let actions = std::mem::take(&mut self.actions);
+        let let_bindings = convert_let_bindings(std::mem::take(&mut self.let_bindings), &actions)?;
+        let do_steps = convert_steps(std::mem::take(&mut self.do_steps), &actions)?;
 
         Ok(TheoremDoc {
-            schema: self.schema,
+            schema: self.schema.as_ref().map(|version| version.value),
             theorem: self.theorem.value.clone(),
-            about: self.about.value.clone(),
-            tags: self.tags.clone(),
-            given: self.given.clone(),
-            forall: self.forall.clone(),
-            actions: self.actions.clone(),
-            assume: self
-                .assume
-                .iter()
-                .map(|a| super::types::Assumption {
-                    expr: a.expr.value.clone(),
-                    because: a.because.value.clone(),
+            about: std::mem::take(&mut self.about.value),
+            tags: self.tags.take().unwrap_or_default(),
+            traces: self
+                .traces
+                .iter_mut()
+                .map(|trace| super::types::TraceLink {
+                    id: std::mem::take(&mut trace.id.value),
+                    system: std::mem::take(&mut trace.system.value),
+                    url: trace.url.as_mut().map(|url| std::mem::take(&mut url.value)),
                 })
                 .collect(),
-            witness: self
-                .witness
+            given: std::mem::take(&mut self.given),
+            depends_on: self
+                .depends_on
                 .iter()
-                .map(|w| super::types::WitnessCheck {
-                    cover: w.cover.value.clone(),
-                    because: w.because.value.clone(),
-                })
+                .map(|dependency| dependency.value.clone())
                 .collect(),
-            let_bindings,
-            do_steps,
-            prove: self
-                .prove
+            // Populated by the loader once fragments have been resolved.
+            include: Vec::new(),
+            types: std::mem::take(&mut self.types),
+            forall: self
+                .forall
                 .iter()
-                .map(|p| super::types::Assertion {
-                    assert_expr: p.assert_expr.value.clone(),
-                    because: p.because.value.clone(),
-                })
+                .map(|(name, entry)| (name.clone(), Symbol::from(entry.ty())))
+                .collect(),
+            forall_domains: self
+                .forall
+                .iter()
+                .filter_map(|(name, entry)| Some((name.clone(), entry.domain()?)))
                 .collect(),
-            evidence: self.evidence.to_evidence(),
+            actions,
+            assume: take_entries(&mut self.assume, RawAssumption::take)?,
+            witness: take_entries(&mut self.witness, RawWitnessCheck::take)?,
+            let_bindings: Arc::new(let_bindings),
+            do_steps: Arc::new(do_steps),
+            prove: take_entries(&mut self.prove, RawAssertion::take)?,
+            evidence,
+            lints: std::mem::take(&mut self.lints),
         })
     }
 
@@ -219,8 +244,15 @@ impl RawTheoremDoc {
             .unwrap_or_else(|| self.theorem_location())
     }
 
+    fn kani_evidence(&self, config: usize) -> Option<&RawKaniEvidence> {
+        self.evidence.as_ref()?.kani.as_ref()?.items().get(config)
+    }
+
     fn location_for_reason(&self, reason: ValidationReasonKind) -> Option<Location> {
         match reason {
+            ValidationReasonKind::SchemaVersion => {
+                self.schema.as_ref().map(|version| version.referenced)
+            }
             ValidationReasonKind::AboutEmpty => Some(self.about.referenced),
             ValidationReasonKind::Prove { index, field } => {
                 let prove = self.prove.get(index)?;
@@ -230,6 +262,12 @@ impl RawTheoremDoc {
                     prove.because.referenced,
                 ))
             }
+            ValidationReasonKind::ProveStep { index } => self
+                .prove
+                .get(index)?
+                .step
+                .as_ref()
+                .map(|step| step.referenced),
             ValidationReasonKind::Assume { index, field } => {
                 let assume = self.assume.get(index)?;
                 Some(location_for_indexed_field(
@@ -246,32 +284,22 @@ impl RawTheoremDoc {
                     witness.because.referenced,
                 ))
             }
-            ValidationReasonKind::KaniUnwind => self
-                .evidence
-                .kani
-                .as_ref()
-                .map(|kani| kani.unwind.referenced),
-            ValidationReasonKind::KaniAllowVacuousRequired => {
-                self.evidence.kani.as_ref().and_then(|kani| {
-                    kani.allow_vacuous
-                        .as_ref()
-                        .map(|allow_vacuous| allow_vacuous.referenced)
-                })
-            }
-            ValidationReasonKind::KaniVacuityBecauseNonEmpty => {
-                self.evidence.kani.as_ref().and_then(|kani| {
-                    kani.vacuity_because
-                        .as_ref()
-                        .map(|vacuity_because| vacuity_because.referenced)
-                })
-            }
-            ValidationReasonKind::KaniWitnessRequired => {
-                self.evidence.kani.as_ref().and_then(|kani| {
-                    kani.allow_vacuous
-                        .as_ref()
-                        .map(|allow_vacuous| allow_vacuous.referenced)
-                })
+            ValidationReasonKind::Trace { index, field } => {
+                let trace = self.traces.get(index)?;
+                match field {
+                    TraceField::Id => Some(trace.id.referenced),
+                    TraceField::System => Some(trace.system.referenced),
+                    TraceField::Url => trace.url.as_ref().map(|url| url.referenced),
+                }
             }
+            ValidationReasonKind::ForallDomain { index, field } => self
+                .forall
+                .get_index(index)
+                .and_then(|(_, entry)| entry.location(field)),
+            ValidationReasonKind::Kani {
+                config,
+                reason: kani_reason,
+            } => self.kani_evidence(config)?.location_for(kani_reason),
         }
     }
 }
@@ -287,87 +315,42 @@ const fn location_for_indexed_field(
     }
 }
 
-impl RawEvidence {
-    fn to_evidence(&self) -> Evidence {
-        Evidence {
-            kani: self.kani.as_ref().map(RawKaniEvidence::to_kani_evidence),
-            verus: self.verus.clone(),
-            stateright: self.stateright.clone(),
-        }
-    }
-}
-
-impl RawKaniEvidence {
-    fn to_kani_evidence(&self) -> KaniEvidence {
-        KaniEvidence {
-            unwind: self.unwind.value,
-            expect: self.expect,
-            allow_vacuous: self
-                .allow_vacuous
-                .as_ref()
-                .is_some_and(|allow_vacuous| allow_vacuous.value),
-            vacuity_because: self
-                .vacuity_because
-                .as_ref()
-                .map(|vacuity_because| vacuity_because.value.clone()),
-        }
-    }
-}
-
 // ── Argument decoding helpers ────────────────────────────────────────
 
 /// Converts a map of raw `Let` bindings, decoding argument values.
 fn convert_let_bindings(
-    raw: &IndexMap<String, RawLetBinding>,
+    raw: IndexMap<String, RawLetBinding>,
+    actions: &IndexMap<String, super::types::ActionSignature>,
 ) -> Result<IndexMap<String, super::types::LetBinding>, RawDocDecodeError> {
     let mut out = IndexMap::with_capacity(raw.len());
     for (name, binding) in raw {
-        let converted = raw_action::convert_let_binding(binding).map_err(|source| {
-            RawDocDecodeError::LetBinding {
-                name: name.clone(),
-                source,
-            }
-        })?;
-        out.insert(name.clone(), converted);
+        match raw_action::convert_let_binding(binding, actions) {
+            Ok(converted) => out.insert(name, converted),
+            Err(source) => return Err(RawDocDecodeError::LetBinding { name, source }),
+        };
     }
     Ok(out)
 }
 
-/// Converts a list of raw `Do` steps, decoding argument values.
-fn convert_steps(raw: &[RawStep]) -> Result<Vec<super::types::Step>, RawDocDecodeError> {
+/// Converts a list of raw `Do` steps, decoding argument values and naming
+/// positional arguments after the `actions` signatures.
+fn convert_steps(
+    raw: Vec<RawStep>,
+    actions: &IndexMap<String, super::types::ActionSignature>,
+) -> Result<Vec<super::types::Step>, RawDocDecodeError> {
     let mut out = Vec::with_capacity(raw.len());
-    for (i, step) in raw.iter().enumerate() {
-        let converted =
-            raw_action::convert_step(step).map_err(|source| RawDocDecodeError::DoStep {
+    for (i, step) in raw.into_iter().enumerate() {
+        let converted = raw_action::convert_step(step, actions).map_err(|source| {
+            RawDocDecodeError::DoStep {
                 index: i + 1,
                 source,
-            })?;
+            }
+        })?;
         out.push(converted);
     }
     Ok(out)
 }
 
-/// Deserializes optional `allow_vacuous` values as `Option<Spanned<bool>>`.
-///
-/// This helper is used with `#[serde(default)]`, so omitted fields deserialize
-/// as `None` before this function runs. Explicit YAML `null` values are
-/// rejected, while present values must deserialize as booleans.
-fn deserialize_optional_allow_vacuous<'de, D>(
-    deserializer: D,
-) -> Result<Option<Spanned<bool>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    Option::<Spanned<bool>>::deserialize(deserializer)?.map_or_else(
-        || {
-            Err(D::Error::custom(
-                "allow_vacuous must be a boolean when provided",
-            ))
-        },
-        |value| Ok(Some(value)),
-    )
-}
-
 #[cfg(test)]
 #[path = "raw_location_tests.rs"]
 mod location_tests;
@@ -10,14 +10,36 @@
 use indexmap::IndexMap;
 use serde::Deserialize;
 
-use super::arg_value::{ArgDecodeError, ParamName, decode_arg_value};
+use super::arg_value::{ArgDecodeError, ArgValue, ParamName, decode_arg_value};
+use super::raw_step_entry::{RawLetEntry, RawStepEntry};
+use super::symbol::Symbol;
 use super::types::{
-    ActionCall, LetBinding, LetCall, LetMust, MaybeBlock, Step, StepCall, StepMaybe, StepMust,
+    ActionCall, ActionSignature, ForeachBlock, InlineAssert, LetBinding, LetCall, LetMust,
+    MaybeBlock, RepeatBlock, Step, StepAssert, StepCall, StepExpectErr, StepForeach, StepMaybe,
+    StepMust, StepRepeat,
 };
 use super::value::TheoremValue;
 
+/// `Actions` signatures keyed by canonical action name.
+type ActionSignatures = IndexMap<String, ActionSignature>;
+
+/// The YAML key holding an action call's arguments, used as the diagnostic
+/// breadcrumb for failures that concern the argument list as a whole.
+const ARGS_KEY: &str = "args";
+
 // ── Raw action call ─────────────────────────────────────────────────
 
+/// Raw action-call arguments: either keyed by parameter name or listed in
+/// the order of the action's `Actions` signature parameters.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum RawArgs {
+    /// Arguments keyed by parameter name.
+    Named(IndexMap<String, TheoremValue>),
+    /// Arguments in signature parameter order.
+    Positional(Vec<TheoremValue>),
+}
+
 /// A raw action call as deserialized from YAML, before argument
 /// decoding.
 #[derive(Debug, Clone, Deserialize)]
@@ -26,17 +48,30 @@ pub(crate) struct RawActionCall {
     /// Dot-separated action name.
     pub(crate) action: String,
     /// Raw YAML argument values, not yet decoded.
-    pub(crate) args: IndexMap<String, TheoremValue>,
+    pub(crate) args: RawArgs,
     /// Optional binding name for the action's return value.
     #[serde(rename = "as", default)]
     pub(crate) as_binding: Option<String>,
 }
 
+/// A raw `expect_err` action call: the action and its arguments, plus the
+/// error variant the call must return in place of an `as` binding.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawExpectErrCall {
+    /// Dot-separated action name.
+    pub(crate) action: String,
+    /// Raw YAML argument values, not yet decoded.
+    pub(crate) args: RawArgs,
+    /// Name of the expected error variant.
+    pub(crate) error: String,
+}
+
 // ── Raw Let bindings ────────────────────────────────────────────────
 
 /// Raw `LetBinding` as deserialized from YAML.
 #[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[serde(try_from = "RawLetEntry")]
 pub(crate) enum RawLetBinding {
     /// Invoke an action and bind the result.
     Call(RawLetCall),
@@ -46,15 +81,13 @@ pub(crate) enum RawLetBinding {
 }
 
 /// Raw wrapper for a `call` variant in a `Let` binding.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone)]
 pub(crate) struct RawLetCall {
     pub(crate) call: RawActionCall,
 }
 
 /// Raw wrapper for a `must` variant in a `Let` binding.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone)]
 pub(crate) struct RawLetMust {
     pub(crate) must: RawActionCall,
 }
@@ -63,37 +96,94 @@ pub(crate) struct RawLetMust {
 
 /// Raw `Step` as deserialized from YAML.
 #[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[serde(try_from = "RawStepEntry")]
 pub(crate) enum RawStep {
     /// Invoke an action.
     Call(RawStepCall),
     /// Invoke an action and prove it cannot fail.
     Must(RawStepMust),
+    /// Invoke an action and prove it returns a named error.
+    ExpectErr(RawStepExpectErr),
     /// Symbolic branching.
     Maybe(RawStepMaybe),
+    /// Bounded iteration.
+    Repeat(RawStepRepeat),
+    /// Iteration over a declared sequence.
+    Foreach(RawStepForeach),
+    /// Inline assertion between steps.
+    Assert(RawStepAssert),
 }
 
 /// Raw wrapper for a `call` variant in a `Do` step.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone)]
 pub(crate) struct RawStepCall {
     pub(crate) call: RawActionCall,
+    pub(crate) id: Option<String>,
 }
 
 /// Raw wrapper for a `must` variant in a `Do` step.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone)]
 pub(crate) struct RawStepMust {
     pub(crate) must: RawActionCall,
+    pub(crate) id: Option<String>,
+}
+
+/// Raw wrapper for an `expect_err` variant in a `Do` step.
+#[derive(Debug, Clone)]
+pub(crate) struct RawStepExpectErr {
+    pub(crate) expect_err: RawExpectErrCall,
 }
 
 /// Raw wrapper for a `maybe` variant in a `Do` step.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone)]
 pub(crate) struct RawStepMaybe {
     pub(crate) maybe: RawMaybeBlock,
 }
 
+/// Raw wrapper for an `assert` variant in a `Do` step.
+#[derive(Debug, Clone)]
+pub(crate) struct RawStepAssert {
+    pub(crate) assert: InlineAssert,
+}
+
+/// Raw wrapper for a `repeat` variant in a `Do` step.
+#[derive(Debug, Clone)]
+pub(crate) struct RawStepRepeat {
+    pub(crate) repeat: RawRepeatBlock,
+}
+
+/// Raw bounded loop with nested raw steps.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawRepeatBlock {
+    /// Number of iterations.
+    pub(crate) times: u32,
+    /// The nested raw steps.
+    #[serde(rename = "do")]
+    pub(crate) do_steps: Vec<RawStep>,
+}
+
+/// Raw wrapper for a `foreach` variant in a `Do` step.
+#[derive(Debug, Clone)]
+pub(crate) struct RawStepForeach {
+    pub(crate) foreach: RawForeachBlock,
+}
+
+/// Raw iteration block with nested raw steps.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawForeachBlock {
+    /// Name of the sequence to iterate over.
+    #[serde(rename = "in")]
+    pub(crate) source: String,
+    /// Loop variable bound to each element.
+    #[serde(rename = "as")]
+    pub(crate) as_binding: String,
+    /// The nested raw steps.
+    #[serde(rename = "do")]
+    pub(crate) do_steps: Vec<RawStep>,
+}
+
 /// Raw symbolic branching block with nested raw steps.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -103,73 +193,167 @@ pub(crate) struct RawMaybeBlock {
     /// The nested raw steps.
     #[serde(rename = "do")]
     pub(crate) do_steps: Vec<RawStep>,
+    /// The raw steps of the alternative branch.
+    #[serde(rename = "else", default)]
+    pub(crate) else_steps: Vec<RawStep>,
 }
 
 // ── Conversion functions ────────────────────────────────────────────
 
 /// Converts a [`RawActionCall`] into a public [`ActionCall`] by
-/// decoding each argument value.
-pub(crate) fn convert_action_call(raw: &RawActionCall) -> Result<ActionCall, ArgDecodeError> {
-    let mut args = IndexMap::with_capacity(raw.args.len());
-    for (key, value) in &raw.args {
-        let decoded = decode_arg_value(ParamName::new(key), value.clone())?;
-        args.insert(key.clone(), decoded);
-    }
+/// decoding each argument value. Positional arguments are named after the
+/// parameters of the action's signature in `actions`.
+pub(crate) fn convert_action_call(
+    raw: RawActionCall,
+    actions: &ActionSignatures,
+) -> Result<ActionCall, ArgDecodeError> {
     Ok(ActionCall {
-        action: raw.action.clone(),
-        args,
-        as_binding: raw.as_binding.clone(),
+        args: convert_args(raw.args, &raw.action, actions)?,
+        action: Symbol::from(raw.action),
+        as_binding: raw.as_binding,
     })
 }
 
+/// Decodes each raw argument value into a map keyed by parameter name,
+/// preserving parameter order.
+///
+/// A positional list takes its names from the `Actions` signature of
+/// `action`, so it needs a signature with exactly one parameter per value.
+/// An empty list needs no signature.
+fn convert_args(
+    raw: RawArgs,
+    action: &str,
+    actions: &ActionSignatures,
+) -> Result<IndexMap<String, ArgValue>, ArgDecodeError> {
+    match raw {
+        RawArgs::Named(named) => decode_args(named),
+        RawArgs::Positional(values) if values.is_empty() => Ok(IndexMap::new()),
+        RawArgs::Positional(values) => {
+            let signature = actions.get(action).ok_or_else(|| {
+                ArgDecodeError::PositionalArgsWithoutSignature {
+                    param: ARGS_KEY.to_owned(),
+                    action: action.to_owned(),
+                }
+            })?;
+            if signature.params.len() != values.len() {
+                return Err(ArgDecodeError::PositionalArgCount {
+                    param: ARGS_KEY.to_owned(),
+                    action: action.to_owned(),
+                    expected: signature.params.len(),
+                    found: values.len(),
+                });
+            }
+            decode_args(signature.params.keys().cloned().zip(values))
+        }
+    }
+}
+
+fn decode_args(
+    raw: impl IntoIterator<Item = (String, TheoremValue)>,
+) -> Result<IndexMap<String, ArgValue>, ArgDecodeError> {
+    let mut args = IndexMap::new();
+    for (key, value) in raw {
+        let decoded = decode_arg_value(ParamName::new(&key), value)?;
+        args.insert(key, decoded);
+    }
+    Ok(args)
+}
+
 /// Converts a [`RawLetBinding`] into a public [`LetBinding`].
-pub(crate) fn convert_let_binding(raw: &RawLetBinding) -> Result<LetBinding, ArgDecodeError> {
+pub(crate) fn convert_let_binding(
+    raw: RawLetBinding,
+    actions: &ActionSignatures,
+) -> Result<LetBinding, ArgDecodeError> {
     match raw {
         RawLetBinding::Call(c) => {
-            let call = convert_action_call(&c.call)?;
+            let call = convert_action_call(c.call, actions)?;
             Ok(LetBinding::Call(LetCall { call }))
         }
         RawLetBinding::Must(m) => {
-            let must = convert_action_call(&m.must)?;
+            let must = convert_action_call(m.must, actions)?;
             Ok(LetBinding::Must(LetMust { must }))
         }
     }
 }
 
 /// Converts a [`RawStep`] into a public [`Step`], recursively
-/// converting nested maybe blocks.
-pub(crate) fn convert_step(raw: &RawStep) -> Result<Step, ArgDecodeError> {
+/// converting nested blocks.
+pub(crate) fn convert_step(
+    raw: RawStep,
+    actions: &ActionSignatures,
+) -> Result<Step, ArgDecodeError> {
     match raw {
         RawStep::Call(c) => {
-            let call = convert_action_call(&c.call)?;
-            Ok(Step::Call(StepCall { call }))
+            let call = convert_action_call(c.call, actions)?;
+            Ok(Step::Call(StepCall { call, id: c.id }))
         }
         RawStep::Must(m) => {
-            let must = convert_action_call(&m.must)?;
-            Ok(Step::Must(StepMust { must }))
+            let must = convert_action_call(m.must, actions)?;
+            Ok(Step::Must(StepMust { must, id: m.id }))
+        }
+        RawStep::ExpectErr(e) => {
+            let RawExpectErrCall {
+                action,
+                args,
+                error,
+            } = e.expect_err;
+            let expect_err = ActionCall {
+                args: convert_args(args, &action, actions)?,
+                action: Symbol::from(action),
+                as_binding: None,
+            };
+            Ok(Step::ExpectErr(StepExpectErr { expect_err, error }))
         }
         RawStep::Maybe(m) => {
-            let maybe = convert_maybe_block(&m.maybe)?;
+            let maybe = convert_maybe_block(m.maybe, actions)?;
             Ok(Step::Maybe(StepMaybe { maybe }))
         }
+        RawStep::Repeat(r) => Ok(Step::Repeat(StepRepeat {
+            repeat: RepeatBlock {
+                times: r.repeat.times,
+                do_steps: convert_nested_steps(r.repeat.do_steps, "repeat.do step", actions)?,
+            },
+        })),
+        RawStep::Foreach(f) => Ok(Step::Foreach(StepForeach {
+            foreach: ForeachBlock {
+                source: f.foreach.source,
+                as_binding: f.foreach.as_binding,
+                do_steps: convert_nested_steps(f.foreach.do_steps, "foreach.do step", actions)?,
+            },
+        })),
+        RawStep::Assert(a) => Ok(Step::Assert(StepAssert { assert: a.assert })),
     }
 }
 
 /// Converts a [`RawMaybeBlock`] into a public [`MaybeBlock`],
 /// recursively converting nested steps.
-fn convert_maybe_block(raw: &RawMaybeBlock) -> Result<MaybeBlock, ArgDecodeError> {
-    let mut do_steps = Vec::with_capacity(raw.do_steps.len());
-    for (i, step) in raw.do_steps.iter().enumerate() {
-        do_steps.push(convert_step(step).map_err(|e| {
+fn convert_maybe_block(
+    raw: RawMaybeBlock,
+    actions: &ActionSignatures,
+) -> Result<MaybeBlock, ArgDecodeError> {
+    Ok(MaybeBlock {
+        because: Symbol::from(raw.because),
+        do_steps: convert_nested_steps(raw.do_steps, "maybe.do step", actions)?,
+        else_steps: convert_nested_steps(raw.else_steps, "maybe.else step", actions)?,
+    })
+}
+
+/// Converts the nested steps of a `maybe`, `repeat`, or `foreach` block, prefixing
+/// decode errors with `path` and the 1-based step position.
+fn convert_nested_steps(
+    raw: Vec<RawStep>,
+    path: &str,
+    actions: &ActionSignatures,
+) -> Result<Vec<Step>, ArgDecodeError> {
+    let mut do_steps = Vec::with_capacity(raw.len());
+    for (i, step) in raw.into_iter().enumerate() {
+        do_steps.push(convert_step(step, actions).map_err(|e| {
             // Re-wrap with nested path context so error messages
-            // identify the failing step inside `maybe.do`.
-            e.with_param_prefix(&format!("maybe.do step {}", i + 1))
+            // identify the failing step inside the nested block.
+            e.with_param_prefix(&format!("{path} {}", i + 1))
         })?);
     }
-    Ok(MaybeBlock {
-        because: raw.because.clone(),
-        do_steps,
-    })
+    Ok(do_steps)
 }
 
 #[cfg(test)]
@@ -12,7 +12,7 @@ fn ref_arg(value: TheoremValue) -> TheoremValue {
 fn action_with_arg(arg_name: &str, value: TheoremValue) -> RawActionCall {
     RawActionCall {
         action: "account.deposit".to_owned(),
-        args: IndexMap::from([(arg_name.to_owned(), value)]),
+        args: RawArgs::Named(IndexMap::from([(arg_name.to_owned(), value)])),
         as_binding: None,
     }
 }
@@ -24,11 +24,13 @@ fn nested_maybe_do_decode_error_includes_step_prefix() {
             because: "branch reason".to_owned(),
             do_steps: vec![RawStep::Call(RawStepCall {
                 call: action_with_arg("account", ref_arg(TheoremValue::String(String::new()))),
+                id: None,
             })],
+            else_steps: Vec::new(),
         },
     });
 
-    let error = convert_step(&step).expect_err("empty reference should fail");
+    let error = convert_step(step, &IndexMap::new()).expect_err("empty reference should fail");
 
     assert_that!(
         error,
@@ -41,3 +43,103 @@ fn nested_maybe_do_decode_error_includes_step_prefix() {
         "argument 'maybe.do step 1: account': ref value must not be empty",
     );
 }
+
+#[test]
+fn nested_repeat_do_decode_error_includes_step_prefix() {
+    let step = RawStep::Repeat(RawStepRepeat {
+        repeat: RawRepeatBlock {
+            times: 2,
+            do_steps: vec![RawStep::Call(RawStepCall {
+                call: action_with_arg("account", ref_arg(TheoremValue::String(String::new()))),
+                id: None,
+            })],
+        },
+    });
+
+    let error = convert_step(step, &IndexMap::new()).expect_err("empty reference should fail");
+
+    assert_that!(
+        error,
+        eq(&ArgDecodeError::EmptyRefTarget {
+            param: "repeat.do step 1: account".to_owned(),
+        }),
+    );
+}
+
+fn positional_call(values: Vec<TheoremValue>) -> RawStep {
+    RawStep::Call(RawStepCall {
+        call: RawActionCall {
+            action: "account.deposit".to_owned(),
+            args: RawArgs::Positional(values),
+            as_binding: None,
+        },
+        id: None,
+    })
+}
+
+fn deposit_signature() -> ActionSignatures {
+    IndexMap::from([(
+        "account.deposit".to_owned(),
+        ActionSignature {
+            params: IndexMap::from([
+                ("account".to_owned(), "crate::Account".into()),
+                ("amount".to_owned(), "u64".into()),
+            ]),
+            returns: "()".into(),
+        },
+    )])
+}
+
+#[test]
+fn positional_args_take_signature_parameter_names() {
+    let step = positional_call(vec![
+        ref_arg(TheoremValue::String("account".to_owned())),
+        TheoremValue::Integer(10),
+    ]);
+
+    let converted = convert_step(step, &deposit_signature()).expect("positional args decode");
+
+    let expected = IndexMap::from([
+        (
+            "account".to_owned(),
+            ArgValue::Reference("account".to_owned()),
+        ),
+        (
+            "amount".to_owned(),
+            ArgValue::Literal(super::super::arg_value::LiteralValue::Integer(10)),
+        ),
+    ]);
+    assert!(
+        matches!(&converted, Step::Call(call) if call.call.args == expected),
+        "got: {converted:?}"
+    );
+}
+
+#[test]
+fn empty_positional_args_need_no_signature() {
+    let converted = convert_step(positional_call(Vec::new()), &IndexMap::new())
+        .expect("empty positional args decode");
+
+    assert!(matches!(converted, Step::Call(call) if call.call.args.is_empty()));
+}
+
+#[rstest::rstest]
+#[case::without_signature(
+    IndexMap::new(),
+    "argument 'args': positional args for 'account.deposit' need an Actions signature \
+     to name each parameter"
+)]
+#[case::wrong_count(
+    deposit_signature(),
+    "argument 'args': 'account.deposit' takes 2 parameter(s), but 1 positional args were given"
+)]
+fn positional_args_must_match_a_signature(
+    #[case] actions: ActionSignatures,
+    #[case] expected: &str,
+) {
+    let step = positional_call(vec![TheoremValue::Integer(10)]);
+
+    let error = convert_step(step, &actions).expect_err("positional args should fail");
+
+    pretty_assertions::assert_eq!(error.to_string(), expected);
+}
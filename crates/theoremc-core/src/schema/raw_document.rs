@@ -0,0 +1,302 @@
+//! Document-kind dispatch for multi-document `.theorem` sources.
+//!
+//! A `.theorem` source holds theorem documents, optional `Suite`, `Shared`,
+//! and `Fixtures` documents, and, optionally, a leading `Defaults` document.
+//! The kind is decided by the document's first key: `Defaults` selects a
+//! defaults document, `Suite` selects a suite, `Shared` selects a shared
+//! block, `Fixtures` selects a fixture, and anything else is deserialized as a
+//! theorem. The first key is replayed into the suite, shared, fixture, or
+//! theorem deserializer, so those documents keep strict
+//! unknown-key rejection and span-aware fields. Top-level keys are recorded
+//! as written, so theorem documents know which spelling each section used.
+//! [`LenientDocument`] instead skips keys the document kind does not
+//! declare, at the top level and inside its sections, recording where each
+//! was written.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::value::StringDeserializer;
+use serde::de::{self, DeserializeSeed, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_saphyr::Spanned;
+
+use super::defaults::{RawDefaults, misplaced_defaults};
+use super::error::SchemaError;
+use super::lenient::{LenientSeed, collect_skipped_keys, is_skipping, record_skipped};
+use super::raw::RawTheoremDoc;
+use super::raw_extends::apply_extends;
+use super::raw_fixtures::{RawFixture, apply_fixtures, check_fixtures};
+use super::raw_shared::{RawShared, apply_shared, check_shared};
+use super::raw_suite::{RawSuite, check_suites};
+use super::source_id::SourceId;
+
+/// One YAML document from a `.theorem` source.
+#[derive(Debug, Clone)]
+pub(crate) enum RawDocument {
+    /// A theorem document.
+    Theorem(Box<RawTheoremDoc>),
+    /// A file-level `Defaults` document.
+    Defaults(Box<Spanned<RawDefaults>>),
+    /// A `Suite` document grouping theorems from the same source.
+    Suite(Box<RawSuite>),
+    /// A `Shared` document declaring inputs theorems pull in with `Use`.
+    Shared(Box<RawShared>),
+    /// A `Fixtures` document declaring `Let` bindings theorems pull in with
+    /// `UseFixtures`.
+    Fixtures(Box<RawFixture>),
+}
+
+/// A document whose undeclared keys were skipped, with the keys it skipped
+/// in source order.
+#[derive(Debug)]
+pub(crate) struct LenientDocument {
+    pub(crate) document: RawDocument,
+    pub(crate) unknown_keys: Vec<Spanned<String>>,
+}
+
+/// Suites and theorem documents from one source, with defaults applied.
+#[derive(Debug)]
+pub(crate) struct SourceDocuments {
+    pub(crate) suites: Vec<RawSuite>,
+    pub(crate) theorems: Vec<RawTheoremDoc>,
+}
+
+/// Separates parsed documents by kind, merges used shared blocks and fixtures
+/// into each theorem, merges each `Extends` base into the theorems extending
+/// it, and merges suite and file defaults into each theorem.
+///
+/// Evidence precedence is theorem, then `Extends` base, then suite, then file
+/// `Defaults`.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::Deserialize`] when a `Defaults` document is not
+/// first in the source, [`SchemaError::InvalidSuite`] when a suite fails its
+/// structural checks, [`SchemaError::InvalidShared`] or
+/// [`SchemaError::InvalidFixture`] when a shared block or fixture does, and
+/// [`SchemaError::UseFailed`] or [`SchemaError::UseFixtureFailed`] when a
+/// theorem's `Use` or `UseFixtures` entries cannot be merged, and
+/// [`SchemaError::ExtendsFailed`] when its `Extends` base cannot.
+pub(crate) fn split_documents(
+    source: &SourceId,
+    documents: Vec<RawDocument>,
+) -> Result<SourceDocuments, SchemaError> {
+    let mut defaults = None;
+    let mut suites = Vec::new();
+    let mut shared = Vec::new();
+    let mut fixtures = Vec::new();
+    let mut theorems = Vec::with_capacity(documents.len());
+    for (index, document) in documents.into_iter().enumerate() {
+        match document {
+            RawDocument::Defaults(spanned) if index == 0 => defaults = Some(spanned.value),
+            RawDocument::Defaults(spanned) => return Err(misplaced_defaults(source, &spanned)),
+            RawDocument::Suite(suite) => suites.push(*suite),
+            RawDocument::Shared(block) => shared.push(*block),
+            RawDocument::Fixtures(fixture) => fixtures.push(*fixture),
+            RawDocument::Theorem(raw_doc) => theorems.push(*raw_doc),
+        }
+    }
+    check_suites(source, &suites, &theorems)?;
+    check_shared(source, &shared)?;
+    check_fixtures(source, &fixtures)?;
+
+    for raw_doc in &mut theorems {
+        apply_shared(source, &shared, raw_doc)?;
+        apply_fixtures(source, &fixtures, raw_doc)?;
+    }
+    apply_extends(source, &mut theorems)?;
+    for raw_doc in &mut theorems {
+        let name = raw_doc.theorem.value.as_str().to_owned();
+        if let Some(suite) = suites.iter().find(|suite| suite.contains(&name)) {
+            suite.apply_to(raw_doc);
+        }
+        if let Some(file_defaults) = &defaults {
+            file_defaults.apply_to(raw_doc);
+        }
+    }
+    Ok(SourceDocuments { suites, theorems })
+}
+
+impl<'de> Deserialize<'de> for RawDocument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RawDocumentVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientDocument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (document, unknown_keys) =
+            collect_skipped_keys(|| deserializer.deserialize_map(RawDocumentVisitor));
+        Ok(Self {
+            document: document?,
+            unknown_keys,
+        })
+    }
+}
+
+/// Dispatches a document by kind.
+struct RawDocumentVisitor;
+
+impl<'de> Visitor<'de> for RawDocumentVisitor {
+    type Value = RawDocument;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a theorem, Suite, Shared, Fixtures, or Defaults document")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let Some(first_key) = map.next_key::<Spanned<String>>()? else {
+            return Err(de::Error::missing_field("Theorem"));
+        };
+        let kind = first_key.value.as_str();
+        if !matches!(kind, "Defaults" | "defaults") {
+            let is_suite = matches!(kind, "Suite" | "suite");
+            let is_shared = matches!(kind, "Shared" | "shared");
+            let is_fixture = matches!(kind, "Fixtures" | "fixtures");
+            let mut keys = Vec::new();
+            let replay = ReplayDocument(ReplayFirstKey {
+                first_key: Some(first_key),
+                map,
+                keys: &mut keys,
+                fields: None,
+            });
+            return if is_suite {
+                RawSuite::deserialize(replay).map(|suite| RawDocument::Suite(Box::new(suite)))
+            } else if is_shared {
+                RawShared::deserialize(replay).map(|block| RawDocument::Shared(Box::new(block)))
+            } else if is_fixture {
+                RawFixture::deserialize(replay)
+                    .map(|fixture| RawDocument::Fixtures(Box::new(fixture)))
+            } else {
+                RawTheoremDoc::deserialize(replay).map(|doc| theorem_document(doc, keys))
+            };
+        }
+
+        let defaults = map.next_value_seed(LenientSeed(PhantomData::<Spanned<RawDefaults>>))?;
+        if let Some(extra) = map.next_key::<String>()? {
+            return Err(de::Error::custom(format!(
+                "a Defaults document must contain only the `Defaults` key, found `{extra}`"
+            )));
+        }
+        Ok(RawDocument::Defaults(Box::new(defaults)))
+    }
+}
+
+/// Wraps a theorem document with the top-level keys it was written with.
+fn theorem_document(mut doc: RawTheoremDoc, section_keys: Vec<Spanned<String>>) -> RawDocument {
+    doc.section_keys = section_keys;
+    RawDocument::Theorem(Box::new(doc))
+}
+
+/// Deserializer over a document's replayed keys. Struct deserialization
+/// learns the declared fields here, so undeclared keys can be skipped.
+struct ReplayDocument<'k, A>(ReplayFirstKey<'k, A>);
+
+impl<'de, A> Deserializer<'de> for ReplayDocument<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(self.0)
+    }
+
+    fn deserialize_struct<V>(
+        mut self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.fields = Some(fields);
+        visitor.visit_map(self.0)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Map access that yields an already-consumed first key before delegating,
+/// recording every key it yields. `fields` are the keys the document kind
+/// declares, once its deserializer has asked for them.
+struct ReplayFirstKey<'k, A> {
+    first_key: Option<Spanned<String>>,
+    map: A,
+    keys: &'k mut Vec<Spanned<String>>,
+    fields: Option<&'static [&'static str]>,
+}
+
+impl<'de, A> ReplayFirstKey<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    /// Returns the next key as written, skipping undeclared keys and their
+    /// values when skipping is enabled.
+    fn next_declared_key(&mut self) -> Result<Option<Spanned<String>>, A::Error> {
+        loop {
+            let key = match self.first_key.take() {
+                Some(first_key) => Some(first_key),
+                None => self.map.next_key::<Spanned<String>>()?,
+            };
+            let Some(fields) = self.fields.filter(|_| is_skipping()) else {
+                return Ok(key);
+            };
+            match key {
+                Some(undeclared) if !fields.contains(&undeclared.value.as_str()) => {
+                    record_skipped(undeclared);
+                    self.map.next_value::<IgnoredAny>()?;
+                }
+                declared => return Ok(declared),
+            }
+        }
+    }
+}
+
+impl<'de, A> MapAccess<'de> for ReplayFirstKey<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some(key) = self.next_declared_key()? else {
+            return Ok(None);
+        };
+        let replayed: StringDeserializer<A::Error> = key.value.clone().into_deserializer();
+        self.keys.push(key);
+        seed.deserialize(replayed).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        if is_skipping() {
+            self.map.next_value_seed(LenientSeed(seed))
+        } else {
+            self.map.next_value_seed(seed)
+        }
+    }
+}
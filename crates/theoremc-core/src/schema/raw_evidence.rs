@@ -0,0 +1,152 @@
+//! Raw `Evidence` schema types with span-aware Kani fields.
+//!
+//! Kani fields are optional at this layer so theorem documents can inherit
+//! them from a file-level `Defaults` document. Required fields are enforced
+//! when the merged evidence is converted into the public
+//! [`Evidence`](super::types::Evidence) type. `Evidence.kani` is either one
+//! configuration mapping or a non-empty list of named configurations.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, de::Error};
+
+use super::lenient::Lenient;
+use super::raw::RawDocDecodeError;
+use super::raw_kani::RawKaniEvidence;
+use super::types::Evidence;
+use super::value::TheoremValue;
+
+/// Raw evidence container with span-aware Kani evidence fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawEvidence {
+    #[serde(default)]
+    pub(crate) kani: Option<OneOrMany<RawKaniEvidence>>,
+    #[serde(default)]
+    pub(crate) verus: Option<TheoremValue>,
+    #[serde(default)]
+    pub(crate) stateright: Option<TheoremValue>,
+}
+
+/// A value written either as a single item or as a non-empty list of items.
+#[derive(Debug, Clone)]
+pub(crate) enum OneOrMany<T> {
+    /// A single mapping.
+    One(T),
+    /// A list of mappings.
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    /// Returns the items in declaration order.
+    pub(crate) fn items(&self) -> &[T] {
+        match self {
+            Self::One(item) => std::slice::from_ref(item),
+            Self::Many(items) => items,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneOrMany<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(OneOrManyVisitor(PhantomData))
+    }
+}
+
+struct OneOrManyVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for OneOrManyVisitor<T> {
+    type Value = OneOrMany<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a mapping or a non-empty list of mappings")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        T::deserialize(Lenient(MapAccessDeserializer::new(map))).map(OneOrMany::One)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let items = Vec::<T>::deserialize(SeqAccessDeserializer::new(seq))?;
+        if items.is_empty() {
+            return Err(A::Error::invalid_length(0, &self));
+        }
+        Ok(OneOrMany::Many(items))
+    }
+}
+
+/// Deserializes a single mapping or a non-empty list of mappings as a list.
+pub(crate) fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(item) => vec![item],
+        OneOrMany::Many(items) => items,
+    })
+}
+
+impl RawEvidence {
+    /// Fills every backend and Kani field this evidence leaves unset from
+    /// `defaults`.
+    ///
+    /// A single default Kani configuration is merged field by field into
+    /// every theorem configuration. A default configuration list is only
+    /// inherited wholesale by theorems that declare no Kani evidence.
+    pub(crate) fn inherit(&mut self, defaults: &Self) {
+        match (&mut self.kani, &defaults.kani) {
+            (Some(OneOrMany::One(kani)), Some(OneOrMany::One(default_kani))) => {
+                kani.inherit(default_kani);
+            }
+            (Some(OneOrMany::Many(configs)), Some(OneOrMany::One(default_kani))) => {
+                for kani in configs {
+                    kani.inherit(default_kani);
+                }
+            }
+            (None, Some(default_kani)) => self.kani = Some(default_kani.clone()),
+            (_, Some(OneOrMany::Many(_)) | None) => {}
+        }
+        if self.verus.is_none() {
+            self.verus.clone_from(&defaults.verus);
+        }
+        if self.stateright.is_none() {
+            self.stateright.clone_from(&defaults.stateright);
+        }
+    }
+
+    /// Converts merged raw evidence into the public evidence type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RawDocDecodeError::MissingField`] when Kani evidence lacks
+    /// `unwind` or `expect` after defaults have been applied.
+    pub(crate) fn to_evidence(&self) -> Result<Evidence, RawDocDecodeError> {
+        Ok(Evidence {
+            kani: self
+                .kani
+                .iter()
+                .flat_map(OneOrMany::items)
+                .map(RawKaniEvidence::to_kani_evidence)
+                .collect::<Result<_, _>>()?,
+            verus: self.verus.clone(),
+            stateright: self.stateright.clone(),
+        })
+    }
+}
+
+/// Fills evidence `target` leaves unset from `defaults`, copying the defaults
+/// wholesale when `target` declares no evidence at all.
+pub(crate) fn inherit_evidence(target: &mut Option<RawEvidence>, defaults: Option<&RawEvidence>) {
+    match (target.as_mut(), defaults) {
+        (Some(evidence), Some(inherited)) => evidence.inherit(inherited),
+        (None, Some(inherited)) => *target = Some(inherited.clone()),
+        (_, None) => {}
+    }
+}
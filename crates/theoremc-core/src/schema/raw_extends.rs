@@ -0,0 +1,192 @@
+//! Theorem inheritance through `Extends`.
+//!
+//! A theorem that names a base theorem declared in the same source under
+//! `Extends` inherits the base's `Types`, `Forall`, `Actions`, `Assume`, and
+//! `Let` entries ahead of its own, and its `Evidence` field by field. Names
+//! in those mappings cannot be redeclared, since a changed type or binding
+//! would silently change what the inherited assumptions mean; own evidence
+//! fields override the base's. Bases resolve before the theorems extending
+//! them, so inheritance chains work in any document order, and cycles are
+//! rejected with the theorems that form them.
+
+use std::fmt::Display;
+use std::hash::Hash;
+
+use indexmap::IndexMap;
+use serde_saphyr::Spanned;
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::newtypes::TheoremName;
+use super::raw::RawTheoremDoc;
+use super::raw_evidence::inherit_evidence;
+use super::source_id::SourceId;
+use crate::cycle::cycle_path;
+
+/// Merges each theorem's `Extends` base into it, resolving bases first.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::ExtendsFailed`] when a base is not declared in
+/// `theorems`, when `Extends` entries form a cycle, or when a theorem
+/// redeclares a `Types`, `Forall`, `Actions`, or `Let` name it inherits.
+pub(crate) fn apply_extends(
+    source: &SourceId,
+    theorems: &mut [RawTheoremDoc],
+) -> Result<(), SchemaError> {
+    let mut resolver = ExtendsResolver {
+        source,
+        resolved: vec![false; theorems.len()],
+        chain: Vec::new(),
+    };
+    for index in 0..theorems.len() {
+        resolver.resolve(theorems, index)?;
+    }
+    Ok(())
+}
+
+/// Tracks which theorems have inherited from their bases, and the theorems
+/// whose bases are being resolved.
+struct ExtendsResolver<'s> {
+    source: &'s SourceId,
+    resolved: Vec<bool>,
+    chain: Vec<usize>,
+}
+
+impl ExtendsResolver<'_> {
+    fn resolve(&mut self, theorems: &mut [RawTheoremDoc], index: usize) -> Result<(), SchemaError> {
+        if self.resolved.get(index).copied().unwrap_or(true) {
+            return Ok(());
+        }
+        let Some(derived) = theorems.get(index) else {
+            return Ok(());
+        };
+        let Some(extends) = derived.extends.clone() else {
+            self.mark_resolved(index);
+            return Ok(());
+        };
+        let source = self.source;
+        let theorem = derived.theorem.value.to_string();
+        let fail = |reason: String| extends_failed(source, &theorem, &extends, reason);
+        let base_name = extends.value.as_str();
+        let base_index = theorems
+            .iter()
+            .position(|raw_doc| raw_doc.theorem.value.as_str() == base_name)
+            .ok_or_else(|| {
+                fail(format!(
+                    "base theorem '{base_name}' is not declared in this file"
+                ))
+            })?;
+
+        self.chain.push(index);
+        let name = |entry: usize| {
+            theorems
+                .get(entry)
+                .map_or("", |raw_doc| raw_doc.theorem.value.as_str())
+        };
+        if let Some(cycle) = cycle_path(
+            self.chain.iter().map(|&entry| name(entry)),
+            name(base_index),
+        ) {
+            return Err(fail(format!("Extends cycle detected: {cycle}")).into_cycle());
+        }
+        self.resolve(theorems, base_index)?;
+        self.chain.pop();
+
+        let base = theorems.get(base_index).cloned();
+        if let (Some(base_doc), Some(derived_doc)) = (base, theorems.get_mut(index)) {
+            inherit(&base_doc, derived_doc).map_err(fail)?;
+        }
+        self.mark_resolved(index);
+        Ok(())
+    }
+
+    fn mark_resolved(&mut self, index: usize) {
+        if let Some(resolved) = self.resolved.get_mut(index) {
+            *resolved = true;
+        }
+    }
+}
+
+/// Merges `base`'s inherited sections into `derived`.
+fn inherit(base: &RawTheoremDoc, derived: &mut RawTheoremDoc) -> Result<(), String> {
+    let base_name = base.theorem.value.as_str();
+    derived.types = merged(
+        base_name,
+        "Types entry",
+        &base.types,
+        std::mem::take(&mut derived.types),
+    )?;
+    derived.forall = merged(
+        base_name,
+        "Forall entry",
+        &base.forall,
+        std::mem::take(&mut derived.forall),
+    )?;
+    derived.actions = merged(
+        base_name,
+        "Actions entry",
+        &base.actions,
+        std::mem::take(&mut derived.actions),
+    )?;
+    derived.let_bindings = merged(
+        base_name,
+        "Let binding",
+        &base.let_bindings,
+        std::mem::take(&mut derived.let_bindings),
+    )?;
+    let own_assume = std::mem::replace(&mut derived.assume, base.assume.clone());
+    derived.assume.extend(own_assume);
+    let own_origins = std::mem::replace(&mut derived.fixture_origins, base.fixture_origins.clone());
+    derived.fixture_origins.extend(own_origins);
+    inherit_evidence(&mut derived.evidence, base.evidence.as_ref());
+    Ok(())
+}
+
+/// Returns `base`'s entries followed by `own`, rejecting names `own`
+/// redeclares.
+fn merged<K, V>(
+    base_name: &str,
+    label: &str,
+    base: &IndexMap<K, V>,
+    own: IndexMap<K, V>,
+) -> Result<IndexMap<K, V>, String>
+where
+    K: Clone + Display + Eq + Hash,
+    V: Clone,
+{
+    let mut entries = base.clone();
+    for (key, value) in own {
+        if entries.contains_key(&key) {
+            return Err(format!(
+                "{label} '{key}' is declared by both base theorem '{base_name}' and the theorem"
+            ));
+        }
+        entries.insert(key, value);
+    }
+    Ok(entries)
+}
+
+fn extends_failed(
+    source: &SourceId,
+    theorem: &str,
+    extends: &Spanned<TheoremName>,
+    reason: String,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        extends.referenced,
+    );
+    SchemaError::ExtendsFailed {
+        theorem: theorem.to_owned(),
+        base: extends.value.to_string(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+#[cfg(test)]
+#[path = "raw_extends_tests.rs"]
+mod tests;
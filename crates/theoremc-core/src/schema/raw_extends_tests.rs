@@ -0,0 +1,229 @@
+//! Unit tests for theorem inheritance through `Extends`.
+
+use rstest::rstest;
+
+use crate::schema::{
+    ForallVar, KaniExpectation, SchemaDiagnosticCode, SchemaError, SourceId, Symbol, TheoremDoc,
+    iter_theorem_docs, load_theorem_docs_with_source,
+};
+
+const SOURCE: &str = "theorems/accounts.theorem";
+
+const BASE: &str = concat!(
+    "Theorem: DepositBase\n",
+    "About: Deposits into a funded account\n",
+    "Types:\n",
+    "  Amount: u64\n",
+    "Forall:\n",
+    "  amount: Amount\n",
+    "Actions:\n",
+    "  account.open:\n",
+    "    params: {}\n",
+    "    returns: u64\n",
+    "Assume:\n",
+    "  - expr: 'amount <= 1000'\n",
+    "    because: deposits are bounded\n",
+    "Let:\n",
+    "  account:\n",
+    "    call:\n",
+    "      action: account.open\n",
+    "      args: {}\n",
+    "Prove:\n",
+    "  - assert: 'amount <= 1000'\n",
+    "    because: the assumption bounds it\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 3\n",
+    "    expect: SUCCESS\n",
+    "    allow_vacuous: true\n",
+    "    vacuity_because: the base only checks its assumption\n",
+);
+
+fn derived(name: &str, base: &str, extra: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: {name}\n",
+            "About: differs from its base in one assertion\n",
+            "Extends: {base}\n",
+            "{extra}",
+            "Prove:\n",
+            "  - assert: 'amount < 2000'\n",
+            "    because: the inherited assumption bounds it\n",
+        ),
+        name = name,
+        base = base,
+        extra = extra,
+    )
+}
+
+fn load(documents: &[&str]) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_with_source(&SourceId::new(SOURCE), &documents.join("---\n"))
+}
+
+fn named<'d>(docs: &'d [TheoremDoc], name: &str) -> &'d TheoremDoc {
+    docs.iter()
+        .find(|doc| doc.theorem.as_str() == name)
+        .expect("theorem should load")
+}
+
+fn forall_names(doc: &TheoremDoc) -> Vec<&str> {
+    doc.forall.keys().map(ForallVar::as_str).collect()
+}
+
+fn unwind(doc: &TheoremDoc) -> Option<u32> {
+    doc.evidence.kani.first().map(|kani| kani.unwind)
+}
+
+#[test]
+fn extending_theorems_inherit_the_base_sections() {
+    let docs = load(&[
+        BASE,
+        &derived(
+            "DepositBelowLimit",
+            "DepositBase",
+            "Forall:\n  fee: u8\nAssume:\n  - expr: 'fee < 10'\n    because: fees are small\n",
+        ),
+    ])
+    .expect("extending theorem should load");
+    let doc = named(&docs, "DepositBelowLimit");
+
+    assert_eq!(forall_names(doc), ["amount", "fee"]);
+    assert_eq!(doc.forall.get("amount").map(Symbol::as_str), Some("u64"));
+    assert_eq!(
+        doc.assume
+            .iter()
+            .map(|assumption| assumption.expr.as_str())
+            .collect::<Vec<_>>(),
+        ["amount <= 1000", "fee < 10"]
+    );
+    assert!(doc.actions.contains_key("account.open"));
+    assert!(doc.let_bindings.contains_key("account"));
+    assert_eq!(unwind(doc), Some(3));
+    assert_eq!(doc.prove.len(), 1);
+    assert_eq!(
+        doc.prove
+            .first()
+            .map(|assertion| assertion.assert_expr.as_str()),
+        Some("amount < 2000")
+    );
+}
+
+#[test]
+fn the_base_keeps_only_its_own_entries() {
+    let docs = load(&[
+        BASE,
+        &derived("WithFee", "DepositBase", "Forall:\n  fee: u8\n"),
+    ])
+    .expect("extending theorem should load");
+
+    assert_eq!(forall_names(named(&docs, "DepositBase")), ["amount"]);
+}
+
+#[test]
+fn own_evidence_fields_override_the_base() {
+    let docs = load(&[
+        BASE,
+        &derived(
+            "DeeperUnwind",
+            "DepositBase",
+            "Evidence:\n  kani:\n    unwind: 7\n",
+        ),
+    ])
+    .expect("extending theorem should load");
+    let doc = named(&docs, "DeeperUnwind");
+
+    assert_eq!(unwind(doc), Some(7));
+    assert_eq!(
+        doc.evidence.kani.first().map(|kani| kani.expect),
+        Some(KaniExpectation::Success)
+    );
+}
+
+#[test]
+fn chains_resolve_in_any_document_order() {
+    let docs = load(&[
+        &derived("Outer", "Middle", "Forall:\n  limit: u32\n"),
+        &derived("Middle", "DepositBase", "Forall:\n  fee: u8\n"),
+        BASE,
+    ])
+    .expect("chained theorems should load");
+
+    assert_eq!(
+        forall_names(named(&docs, "Outer")),
+        ["amount", "fee", "limit"]
+    );
+}
+
+#[rstest]
+#[case::unknown_base(
+    &derived("Derived", "Missing", ""),
+    "base theorem 'Missing' is not declared in this file"
+)]
+#[case::itself(
+    &derived("Derived", "Derived", ""),
+    "Extends cycle detected: Derived -> Derived"
+)]
+#[case::redeclared_forall(
+    &derived("Derived", "DepositBase", "Forall:\n  amount: u32\n"),
+    "Forall entry 'amount' is declared by both base theorem 'DepositBase' and the theorem"
+)]
+#[case::redeclared_let(
+    &derived(
+        "Derived",
+        "DepositBase",
+        "Let:\n  account:\n    call:\n      action: account.open\n      args: {}\n",
+    ),
+    "Let binding 'account' is declared by both base theorem 'DepositBase' and the theorem"
+)]
+#[case::redeclared_type(
+    &derived("Derived", "DepositBase", "Types:\n  Amount: u32\n"),
+    "Types entry 'Amount' is declared by both base theorem 'DepositBase' and the theorem"
+)]
+fn invalid_extends_entries_are_rejected(#[case] document: &str, #[case] expected: &str) {
+    let error = load(&[BASE, document]).expect_err("extends should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::ExtendsFailed { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    // `Extends` is the third line of the document after the base.
+    assert_eq!(diagnostic.location.line, BASE.lines().count() + 4);
+}
+
+#[test]
+fn cycles_name_every_theorem_in_them() {
+    let error = load(&[
+        &derived("Leaf", "First", ""),
+        &derived("First", "Second", ""),
+        &derived("Second", "First", ""),
+    ])
+    .expect_err("cycle should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("Extends cycle detected: First -> Second -> First"),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.code, SchemaDiagnosticCode::Cycle);
+}
+
+#[test]
+fn streaming_loads_reject_extends() {
+    let yaml = [BASE, &derived("Derived", "DepositBase", "")].join("---\n");
+    let error = iter_theorem_docs(&yaml)
+        .find_map(Result::err)
+        .expect("extends needs the whole source");
+
+    assert!(
+        matches!(error, SchemaError::ExtendsFailed { .. }),
+        "unexpected error: {error}"
+    );
+}
@@ -0,0 +1,225 @@
+//! Raw `Fixtures` documents and the `UseFixtures` merge into theorem
+//! documents.
+//!
+//! A `Fixtures` document names a sequence of `Let` bindings, such as the
+//! calls that open and fund an account, that theorems declared in the same
+//! source pull in with `UseFixtures`. Used fixtures merge in listed order
+//! ahead of the theorem's own bindings, before include resolution and
+//! semantic validation, and a binding name declared by more than one merged
+//! source is rejected. Each merged binding remembers the fixture and location
+//! it was written at, so later diagnostics about it point into the fixture.
+
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_saphyr::{Location, Spanned};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::identifier::validate_identifier;
+use super::raw::RawTheoremDoc;
+use super::raw_action::RawLetBinding;
+use super::source_id::SourceId;
+
+/// Raw `Fixtures` document declaring a reusable `Let` sequence.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawFixture {
+    #[serde(rename = "Fixtures", alias = "fixtures")]
+    pub(crate) name: Spanned<String>,
+    #[serde(rename = "About", alias = "about")]
+    pub(crate) about: Spanned<String>,
+    #[serde(rename = "Let", alias = "let", default)]
+    pub(crate) let_bindings: IndexMap<String, Spanned<RawLetBinding>>,
+}
+
+/// Where a `Let` binding merged from a fixture was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FixtureOrigin {
+    /// The fixture that declares the binding.
+    pub(crate) fixture: String,
+    /// Location of the binding's value inside the fixture.
+    pub(crate) location: Location,
+}
+
+/// Checks fixture names, descriptions, and contents.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::InvalidFixture`] when a fixture name is not a valid
+/// identifier or is declared twice, when `About` is blank, or when the
+/// fixture declares no `Let` bindings.
+pub(crate) fn check_fixtures(
+    source: &SourceId,
+    fixtures: &[RawFixture],
+) -> Result<(), SchemaError> {
+    let mut names = HashSet::new();
+    for fixture in fixtures {
+        let name = fixture.name.value.as_str();
+        let invalid =
+            |reason: String, location: Location| invalid_fixture(source, name, reason, location);
+        if let Err(error) = validate_identifier(name) {
+            return Err(invalid(error.to_string(), fixture.name.referenced));
+        }
+        if !names.insert(name) {
+            return Err(invalid(
+                "fixture is declared more than once".to_owned(),
+                fixture.name.referenced,
+            ));
+        }
+        if fixture.about.value.trim().is_empty() {
+            return Err(invalid(
+                "About must be non-empty after trimming".to_owned(),
+                fixture.about.referenced,
+            ));
+        }
+        if fixture.let_bindings.is_empty() {
+            return Err(invalid(
+                "a fixture must declare Let bindings".to_owned(),
+                fixture.name.referenced,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Merges the fixtures `raw_doc` lists under `UseFixtures` ahead of its own
+/// `Let` bindings, recording where each merged binding was written.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::UseFixtureFailed`] when a `UseFixtures` entry names
+/// an undeclared fixture or repeats an earlier entry, or when two merged
+/// sources declare the same `Let` binding.
+pub(crate) fn apply_fixtures(
+    source: &SourceId,
+    fixtures: &[RawFixture],
+    raw_doc: &mut RawTheoremDoc,
+) -> Result<(), SchemaError> {
+    if raw_doc.use_fixtures.is_empty() {
+        return Ok(());
+    }
+    let theorem = raw_doc.theorem.value.to_string();
+    let fail = |entry: &Spanned<String>, reason: String| {
+        use_fixture_failed(source, &theorem, entry, reason)
+    };
+    let mut merged = FixtureMerge::default();
+    for (position, entry) in raw_doc.use_fixtures.iter().enumerate() {
+        used_fixture(fixtures, &raw_doc.use_fixtures, position)
+            .and_then(|fixture| merged.add(fixture, entry))
+            .map_err(|reason| fail(entry, reason))?;
+    }
+    for (name, binding) in std::mem::take(&mut raw_doc.let_bindings) {
+        if let Some(entry) = merged.owners.get(&name) {
+            let owner = entry.value.trim();
+            return Err(fail(
+                entry,
+                format!(
+                    "Let binding '{name}' is declared by both fixture '{owner}' and the theorem"
+                ),
+            ));
+        }
+        merged.let_bindings.insert(name, binding);
+    }
+    raw_doc.let_bindings = merged.let_bindings;
+    raw_doc.fixture_origins = merged.origins;
+    Ok(())
+}
+
+/// Returns the fixture named by the `UseFixtures` entry at `position`,
+/// rejecting undeclared fixtures and repeated entries.
+fn used_fixture<'f>(
+    fixtures: &'f [RawFixture],
+    uses: &[Spanned<String>],
+    position: usize,
+) -> Result<&'f RawFixture, String> {
+    let name = uses.get(position).map_or("", |entry| entry.value.trim());
+    let fixture = fixtures
+        .iter()
+        .find(|fixture| fixture.name.value == name)
+        .ok_or_else(|| format!("fixture '{name}' is not declared in this file"))?;
+    if uses
+        .get(..position)
+        .is_some_and(|earlier| earlier.iter().any(|entry| entry.value.trim() == name))
+    {
+        return Err(format!("fixture '{name}' is listed more than once"));
+    }
+    Ok(fixture)
+}
+
+/// Bindings merged from used fixtures so far, with the `UseFixtures` entry
+/// that introduced each name and where the fixture wrote it.
+#[derive(Default)]
+struct FixtureMerge<'u> {
+    let_bindings: IndexMap<String, RawLetBinding>,
+    owners: IndexMap<String, &'u Spanned<String>>,
+    origins: IndexMap<String, FixtureOrigin>,
+}
+
+impl<'u> FixtureMerge<'u> {
+    fn add(&mut self, fixture: &RawFixture, entry: &'u Spanned<String>) -> Result<(), String> {
+        for (name, binding) in &fixture.let_bindings {
+            if let Some(first) = self.owners.insert(name.clone(), entry) {
+                return Err(format!(
+                    "Let binding '{name}' is declared by both fixtures '{}' and '{}'",
+                    first.value.trim(),
+                    entry.value.trim(),
+                ));
+            }
+            self.origins.insert(
+                name.clone(),
+                FixtureOrigin {
+                    fixture: fixture.name.value.clone(),
+                    location: binding.referenced,
+                },
+            );
+            self.let_bindings
+                .insert(name.clone(), binding.value.clone());
+        }
+        Ok(())
+    }
+}
+
+fn invalid_fixture(
+    source: &SourceId,
+    fixture: &str,
+    reason: String,
+    location: Location,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        location,
+    );
+    SchemaError::InvalidFixture {
+        fixture: fixture.to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+fn use_fixture_failed(
+    source: &SourceId,
+    theorem: &str,
+    entry: &Spanned<String>,
+    reason: String,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        entry.referenced,
+    );
+    SchemaError::UseFixtureFailed {
+        theorem: theorem.to_owned(),
+        fixture: entry.value.trim().to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+#[cfg(test)]
+#[path = "raw_fixtures_tests.rs"]
+mod tests;
@@ -0,0 +1,235 @@
+//! Unit tests for `Fixtures` documents and `UseFixtures` merging.
+
+use std::io;
+
+use rstest::rstest;
+
+use crate::actions::load_action_manifest;
+use crate::schema::test_fixtures;
+use crate::schema::{
+    IncludeResolver, SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_manifest,
+    load_theorem_docs_with_source,
+};
+
+const SOURCE: &str = "theorems/accounts.theorem";
+
+const OPEN: &str = concat!(
+    "Fixtures: OpenAccount\n",
+    "About: Opens an empty account\n",
+    "Let:\n",
+    "  account:\n",
+    "    call:\n",
+    "      action: account.open\n",
+    "      args: {}\n",
+);
+
+const FUND: &str = concat!(
+    "Fixtures: FundAccount\n",
+    "About: Deposits an opening balance\n",
+    "Let:\n",
+    "  funded:\n",
+    "    call:\n",
+    "      action: account.fund\n",
+    "      args: { amount: 100 }\n",
+);
+
+const ACTIONS: &str = concat!(
+    "Actions:\n",
+    "  account.open:\n",
+    "    params: {}\n",
+    "    returns: u64\n",
+    "  account.fund:\n",
+    "    params:\n",
+    "      amount: u64\n",
+    "    returns: u64\n",
+);
+
+struct NoFragments;
+
+impl IncludeResolver for NoFragments {
+    fn read_include(&self, path: &str) -> io::Result<String> {
+        Err(io::Error::new(io::ErrorKind::NotFound, path.to_owned()))
+    }
+}
+
+fn theorem(name: &str, extra: &str) -> String {
+    test_fixtures::theorem(name, &format!("{extra}{ACTIONS}"))
+}
+
+fn load(documents: &[&str]) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_with_source(&SourceId::new(SOURCE), &documents.join("---\n"))
+}
+
+fn binding_names(doc: &TheoremDoc) -> Vec<&str> {
+    doc.let_bindings.keys().map(String::as_str).collect()
+}
+
+#[test]
+fn used_fixtures_merge_ahead_of_the_theorems_own_bindings() {
+    let docs = load(&[
+        OPEN,
+        FUND,
+        &theorem(
+            "Deposit",
+            concat!(
+                "UseFixtures: [FundAccount, OpenAccount]\n",
+                "Let:\n",
+                "  reopened:\n",
+                "    call:\n",
+                "      action: account.open\n",
+                "      args: {}\n",
+            ),
+        ),
+    ])
+    .expect("fixtures should merge");
+    let doc = docs.first().expect("one theorem");
+
+    assert_eq!(binding_names(doc), ["funded", "account", "reopened"]);
+}
+
+#[test]
+fn every_theorem_receives_its_own_copy_of_a_fixture() {
+    let docs = load(&[
+        &theorem("First", "UseFixtures: [OpenAccount]\n"),
+        &theorem("Second", "UseFixtures: [OpenAccount]\n"),
+        &theorem("Third", ""),
+        OPEN,
+    ])
+    .expect("fixtures may follow the theorems that use them");
+
+    let merged: Vec<Vec<&str>> = docs.iter().map(binding_names).collect();
+    assert_eq!(merged, [vec!["account"], vec!["account"], vec![]]);
+}
+
+#[rstest]
+#[case::unknown_fixture(
+    "UseFixtures: [Missing]\n",
+    "fixture 'Missing' is not declared in this file"
+)]
+#[case::repeated_entry(
+    "UseFixtures: [OpenAccount, OpenAccount]\n",
+    "fixture 'OpenAccount' is listed more than once"
+)]
+#[case::clash_between_fixtures(
+    "UseFixtures: [OpenAccount, Reopen]\n",
+    "Let binding 'account' is declared by both fixtures 'OpenAccount' and 'Reopen'"
+)]
+#[case::clash_with_theorem(
+    concat!(
+        "UseFixtures: [OpenAccount]\n",
+        "Let:\n  account:\n    call:\n      action: account.open\n      args: {}\n",
+    ),
+    "Let binding 'account' is declared by both fixture 'OpenAccount' and the theorem"
+)]
+fn invalid_use_fixtures_entries_are_rejected(#[case] extra: &str, #[case] expected: &str) {
+    let reopen = OPEN.replace("OpenAccount", "Reopen");
+    let error = load(&[OPEN, FUND, &reopen, &theorem("Deposit", extra)])
+        .expect_err("fixture use should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::UseFixtureFailed { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    // The theorem follows three seven-line fixtures and their separators.
+    assert_eq!(diagnostic.location.line, 27);
+}
+
+#[rstest]
+#[case::invalid_name(
+    "Fixtures: fn\nAbout: x\nLet:\n  a: { call: { action: account.open, args: {} } }\n",
+    "invalid identifier 'fn'",
+    1
+)]
+#[case::blank_about(
+    "Fixtures: Setup\nAbout: ' '\nLet:\n  a: { call: { action: account.open, args: {} } }\n",
+    "About must be",
+    2
+)]
+#[case::empty_fixture("Fixtures: Setup\nAbout: nothing\n", "must declare Let bindings", 1)]
+#[case::duplicate_name(
+    "Fixtures: OpenAccount\nAbout: again\nLet:\n  b: { call: { action: account.open, args: {} } }\n",
+    "fixture is declared more than once",
+    1
+)]
+fn invalid_fixtures_are_rejected(
+    #[case] fixture: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error =
+        load(&[OPEN, fixture, &theorem("Deposit", "")]).expect_err("fixture should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::InvalidFixture { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    // The fixture follows the seven-line `OpenAccount` document and separator.
+    assert_eq!(diagnostic.location.line, line + 8);
+}
+
+#[test]
+fn fixture_decode_failures_point_at_the_fixture_binding() {
+    let broken = concat!(
+        "Fixtures: Broken\n",
+        "About: Refers to an invalid name\n",
+        "Let:\n",
+        "  account:\n",
+        "    call:\n",
+        "      action: account.fund\n",
+        "      args: { amount: { ref: 'not valid' } }\n",
+    );
+    let error = load(&[broken, &theorem("Deposit", "UseFixtures: [Broken]\n")])
+        .expect_err("invalid reference should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        diagnostic.message.contains("(from fixture 'Broken')"),
+        "unexpected diagnostic: {}",
+        diagnostic.message
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    assert_eq!(diagnostic.location.line, 5);
+}
+
+#[test]
+fn fixture_manifest_failures_point_at_the_fixture_binding() {
+    let manifest = load_action_manifest("Actions:\n  account.open:\n    params: {}\n")
+        .expect("manifest should load");
+    let yaml = [FUND, &theorem("Deposit", "UseFixtures: [FundAccount]\n")].join("---\n");
+    let error =
+        load_theorem_docs_with_manifest(&SourceId::new(SOURCE), &yaml, &NoFragments, &manifest)
+            .expect_err("undeclared action should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        diagnostic.message.contains(
+            "action 'account.fund' is not declared in the action manifest (from fixture 'FundAccount')"
+        ),
+        "unexpected diagnostic: {}",
+        diagnostic.message
+    );
+    assert_eq!(diagnostic.location.line, 5);
+}
+
+#[test]
+fn unknown_fixture_keys_are_rejected() {
+    let message = load(&[&format!("{OPEN}Prove: []\n"), &theorem("Deposit", "")])
+        .expect_err("unknown fixture key should be rejected")
+        .to_string();
+
+    assert!(
+        message.contains("unknown field `Prove`"),
+        "unexpected error: {message}"
+    );
+}
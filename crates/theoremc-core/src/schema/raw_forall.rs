@@ -0,0 +1,328 @@
+//! Raw `Forall` entries: a bare type string or a structured domain mapping.
+//!
+//! The entry kind is decided by the YAML node: scalars are type strings and
+//! mappings are decoded strictly and normalized into [`RawForallDomain`],
+//! keeping spans for the `type`, `struct`, or `vec` key and for the `min`,
+//! `max`, `oneof`, `fields`, `len`, and `from` values so domain diagnostics
+//! can point at the offending value. An `optional: true` flag wraps any
+//! non-derived entry in `Option`.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_saphyr::{Location, Spanned};
+
+use super::forall::{ForallChoice, ForallDomain, ForallDomainField, ScalarBound};
+use super::lenient::Lenient;
+
+/// One raw `Forall` value.
+#[derive(Debug, Clone)]
+pub(crate) enum RawForallEntry {
+    /// A bare Rust type string.
+    Type(String),
+    /// A mapping pairing the type with value constraints.
+    Domain(Box<RawForallDomain>),
+}
+
+/// Which key named the type of a structured `Forall` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RawForallShape {
+    /// `type`: a value of the named type, optionally narrowed.
+    Value,
+    /// `struct`: a struct built from `fields`.
+    Struct,
+    /// `vec`: a `Vec` of the named element type, bounded by `len`.
+    Vec,
+}
+
+/// Structured `Forall` mapping with span-aware fields.
+///
+/// `ty` holds the `type`, `struct`, or `vec` value, as recorded by `shape`.
+#[derive(Debug, Clone)]
+pub(crate) struct RawForallDomain {
+    pub(crate) shape: RawForallShape,
+    pub(crate) ty: Spanned<String>,
+    pub(crate) min: Option<Spanned<ScalarBound>>,
+    pub(crate) max: Option<Spanned<ScalarBound>>,
+    pub(crate) oneof: Option<Spanned<Vec<Spanned<ForallChoice>>>>,
+    pub(crate) fields: Option<Spanned<IndexMap<String, Spanned<String>>>>,
+    pub(crate) len: Option<Spanned<RawForallLength>>,
+    pub(crate) from: Option<Spanned<String>>,
+    pub(crate) optional: bool,
+}
+
+/// The `len` mapping of a `vec` entry.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawForallLength {
+    #[serde(default)]
+    pub(crate) min: Option<Spanned<u32>>,
+    pub(crate) max: Spanned<u32>,
+}
+
+/// The mapping as written, before the key combinations are checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawForallMapping {
+    #[serde(rename = "type", default)]
+    ty: Option<Spanned<String>>,
+    #[serde(rename = "struct", default)]
+    struct_ty: Option<Spanned<String>>,
+    #[serde(default)]
+    vec: Option<Spanned<String>>,
+    #[serde(default)]
+    min: Option<Spanned<ScalarBound>>,
+    #[serde(default)]
+    max: Option<Spanned<ScalarBound>>,
+    #[serde(default)]
+    oneof: Option<Spanned<Vec<Spanned<ForallChoice>>>>,
+    #[serde(default)]
+    fields: Option<Spanned<IndexMap<String, Spanned<String>>>>,
+    #[serde(default)]
+    len: Option<Spanned<RawForallLength>>,
+    #[serde(default)]
+    from: Option<Spanned<String>>,
+    #[serde(default)]
+    optional: bool,
+}
+
+impl RawForallMapping {
+    /// Rejects key combinations that do not describe a single domain.
+    fn into_domain(self) -> Result<RawForallDomain, &'static str> {
+        let shape = self.shape()?;
+        self.check_keys(shape)?;
+        let ty = match shape {
+            RawForallShape::Value => self.ty,
+            RawForallShape::Struct => self.struct_ty,
+            RawForallShape::Vec => self.vec,
+        };
+        Ok(RawForallDomain {
+            shape,
+            ty: ty.ok_or(MISSING_SHAPE)?,
+            min: self.min,
+            max: self.max,
+            oneof: self.oneof,
+            fields: self.fields,
+            len: self.len,
+            from: self.from,
+            optional: self.optional,
+        })
+    }
+
+    /// Returns the shape named by exactly one of `type`, `struct`, and `vec`.
+    const fn shape(&self) -> Result<RawForallShape, &'static str> {
+        match (
+            self.ty.is_some(),
+            self.struct_ty.is_some(),
+            self.vec.is_some(),
+        ) {
+            (true, false, false) => Ok(RawForallShape::Value),
+            (false, true, false) => Ok(RawForallShape::Struct),
+            (false, false, true) => Ok(RawForallShape::Vec),
+            (false, false, false) => Err(self.missing_shape()),
+            _ => Err("only one of `type`, `struct`, and `vec` may be set"),
+        }
+    }
+
+    /// Explains which key an entry without `type`, `struct`, or `vec`
+    /// needs. A Rust type is never inferred from the other keys, since
+    /// values such as `Deposit` or `3` do not name one.
+    const fn missing_shape(&self) -> &'static str {
+        if self.oneof.is_some() {
+            "`oneof` requires `type` naming the type of its values, such as \
+             `{ type: crate::Operation, oneof: [Deposit, Withdraw] }`"
+        } else if self.is_bounded() {
+            "`min` and `max` require `type` naming a numeric type"
+        } else if self.from.is_some() {
+            "`from` requires `type`"
+        } else if self.fields.is_some() {
+            "`fields` requires `struct`"
+        } else if self.len.is_some() {
+            "`len` requires `vec`"
+        } else {
+            MISSING_SHAPE
+        }
+    }
+
+    /// Checks that the remaining keys suit `shape`.
+    fn check_keys(&self, shape: RawForallShape) -> Result<(), &'static str> {
+        if self.from.is_some() && shape != RawForallShape::Value {
+            return Err("`from` requires `type`");
+        }
+        if self.from.is_some() && self.optional {
+            return Err("`optional` cannot be combined with `from`");
+        }
+        let narrowed = self.is_bounded() || self.oneof.is_some();
+        match shape {
+            RawForallShape::Struct if self.fields.is_none() => Err("`struct` requires `fields`"),
+            RawForallShape::Struct if narrowed || self.len.is_some() => {
+                Err("`struct` cannot be combined with `min`, `max`, `oneof`, or `len`")
+            }
+            RawForallShape::Vec if self.len.is_none() => Err("`vec` requires `len`"),
+            RawForallShape::Vec if narrowed || self.fields.is_some() => {
+                Err("`vec` cannot be combined with `min`, `max`, `oneof`, or `fields`")
+            }
+            RawForallShape::Value => self.check_value_keys(),
+            RawForallShape::Struct | RawForallShape::Vec => Ok(()),
+        }
+    }
+
+    /// Checks the keys of a `type` entry.
+    const fn check_value_keys(&self) -> Result<(), &'static str> {
+        if self.fields.is_some() {
+            return Err("`fields` requires `struct` instead of `type`");
+        }
+        if self.len.is_some() {
+            return Err("`len` requires `vec` instead of `type`");
+        }
+        if self.from.is_some() && (self.is_bounded() || self.oneof.is_some()) {
+            return Err("`from` cannot be combined with `min`, `max`, or `oneof`");
+        }
+        if self.is_bounded() && self.oneof.is_some() {
+            return Err("`oneof` cannot be combined with `min` or `max`");
+        }
+        Ok(())
+    }
+
+    const fn is_bounded(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+}
+
+const MISSING_SHAPE: &str = "a structured Forall entry needs `type`, `struct`, or `vec`";
+
+impl RawForallDomain {
+    /// Returns the declared type before any `optional` wrapping. A `vec`
+    /// entry declares `Vec<element>`.
+    fn value_ty(&self) -> String {
+        match self.shape {
+            RawForallShape::Vec => format!("Vec<{}>", self.ty.value.trim()),
+            RawForallShape::Value | RawForallShape::Struct => self.ty.value.clone(),
+        }
+    }
+
+    /// Returns the value constraints before any `optional` wrapping.
+    fn value_domain(&self) -> Option<ForallDomain> {
+        if let Some(from) = &self.from {
+            return Some(ForallDomain::Derived {
+                expr: from.value.clone(),
+            });
+        }
+        if let Some(len) = &self.len {
+            return Some(ForallDomain::Length {
+                min: len.value.min.as_ref().map_or(0, |min| min.value),
+                max: len.value.max.value,
+            });
+        }
+        if let Some(fields) = &self.fields {
+            let declared = fields.value.iter();
+            return Some(ForallDomain::Struct {
+                fields: declared
+                    .map(|(name, ty)| (name.clone(), ty.value.clone()))
+                    .collect(),
+            });
+        }
+        if let Some(choices) = &self.oneof {
+            let values = choices.value.iter().map(|choice| choice.value.clone());
+            return Some(ForallDomain::OneOf(values.collect()));
+        }
+        let min = self.min.as_ref().map(|bound| bound.value.0);
+        let max = self.max.as_ref().map(|bound| bound.value.0);
+        (min.is_some() || max.is_some()).then_some(ForallDomain::Range { min, max })
+    }
+}
+
+impl RawForallEntry {
+    /// Returns the declared Rust type string. A `vec` entry declares
+    /// `Vec<element>` and an optional entry declares `Option<type>`.
+    pub(crate) fn ty(&self) -> String {
+        match self {
+            Self::Type(ty) => ty.clone(),
+            Self::Domain(domain) if domain.optional => {
+                format!("Option<{}>", domain.value_ty().trim())
+            }
+            Self::Domain(domain) => domain.value_ty(),
+        }
+    }
+
+    /// Returns the value constraints, or `None` when the entry declares only
+    /// a type.
+    pub(crate) fn domain(&self) -> Option<ForallDomain> {
+        let Self::Domain(domain) = self else {
+            return None;
+        };
+        if domain.optional {
+            return Some(ForallDomain::Optional {
+                ty: domain.value_ty(),
+                inner: domain.value_domain().map(Box::new),
+            });
+        }
+        domain.value_domain()
+    }
+
+    /// Returns the source location of `field`, when the entry declares it.
+    pub(crate) fn location(&self, field: ForallDomainField) -> Option<Location> {
+        let Self::Domain(domain) = self else {
+            return None;
+        };
+        match field {
+            ForallDomainField::Type => Some(domain.ty.referenced),
+            ForallDomainField::Min => domain.min.as_ref().map(|bound| bound.referenced),
+            ForallDomainField::Max => domain.max.as_ref().map(|bound| bound.referenced),
+            ForallDomainField::OneOf => domain.oneof.as_ref().map(|choices| choices.referenced),
+            ForallDomainField::Choice(index) => domain
+                .oneof
+                .as_ref()
+                .and_then(|choices| choices.value.get(index))
+                .map(|choice| choice.referenced),
+            ForallDomainField::Fields => domain.fields.as_ref().map(|fields| fields.referenced),
+            ForallDomainField::Field(index) => domain
+                .fields
+                .as_ref()
+                .and_then(|fields| fields.value.get_index(index))
+                .map(|(_, ty)| ty.referenced),
+            ForallDomainField::LenMax => domain.len.as_ref().map(|len| len.value.max.referenced),
+            ForallDomainField::From => domain.from.as_ref().map(|from| from.referenced),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RawForallEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RawForallEntryVisitor)
+    }
+}
+
+struct RawForallEntryVisitor;
+
+impl<'de> Visitor<'de> for RawForallEntryVisitor {
+    type Value = RawForallEntry;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Rust type string or a structured Forall mapping")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(RawForallEntry::Type(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(RawForallEntry::Type(v))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        RawForallMapping::deserialize(Lenient(MapAccessDeserializer::new(map)))?
+            .into_domain()
+            .map(|domain| RawForallEntry::Domain(Box::new(domain)))
+            .map_err(de::Error::custom)
+    }
+}
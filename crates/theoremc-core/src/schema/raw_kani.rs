@@ -0,0 +1,272 @@
+//! Raw Kani evidence configurations with span-aware fields.
+//!
+//! Each entry of `Evidence.kani` deserializes into a [`RawKaniEvidence`]
+//! whose fields stay optional until `Defaults` have been merged, and whose
+//! spans let validation failures point at the offending field.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{MapAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, de::Error};
+use serde_saphyr::{Location, Spanned};
+
+use super::kani_limits::{KaniTimeout, MemoryLimit};
+use super::raw::RawDocDecodeError;
+use super::types::{CounterexampleConstraint, KaniEvidence, KaniExpectation, KaniSolver, KaniStub};
+use super::validation_reason::KaniReason;
+
+/// Raw Kani evidence with span-aware fields used in validation diagnostics.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawKaniEvidence {
+    #[serde(default)]
+    pub(crate) name: Option<Spanned<String>>,
+    #[serde(default)]
+    pub(crate) unwind: Option<Spanned<RawUnwind>>,
+    #[serde(default)]
+    pub(crate) expect: Option<KaniExpectation>,
+    #[serde(default, deserialize_with = "deserialize_optional_allow_vacuous")]
+    pub(crate) allow_vacuous: Option<Spanned<bool>>,
+    #[serde(default)]
+    pub(crate) vacuity_because: Option<Spanned<String>>,
+    #[serde(default)]
+    pub(crate) solver: Option<KaniSolver>,
+    #[serde(default)]
+    pub(crate) extra_args: Option<Vec<Spanned<String>>>,
+    #[serde(default)]
+    pub(crate) timeout: Option<KaniTimeout>,
+    #[serde(default)]
+    pub(crate) memory_limit: Option<MemoryLimit>,
+    #[serde(default)]
+    pub(crate) stubs: Option<Vec<Spanned<KaniStub>>>,
+    #[serde(default)]
+    pub(crate) contracts: Option<Vec<Spanned<String>>>,
+    #[serde(default)]
+    pub(crate) counterexample: Option<Spanned<IndexMap<String, CounterexampleConstraint>>>,
+    #[serde(default)]
+    pub(crate) playback: Option<bool>,
+    #[serde(default)]
+    pub(crate) coverage: Option<bool>,
+}
+
+/// Kani `unwind` written as one global bound or as a mapping of loop labels
+/// to bounds whose reserved `default` key holds the global bound.
+#[derive(Debug, Clone)]
+pub(crate) struct RawUnwind {
+    default: u32,
+    loops: IndexMap<String, u32>,
+}
+
+/// Key of the global bound in a per-loop `unwind` mapping.
+const DEFAULT_UNWIND_KEY: &str = "default";
+
+impl<'de> Deserialize<'de> for RawUnwind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RawUnwindVisitor)
+    }
+}
+
+struct RawUnwindVisitor;
+
+impl<'de> Visitor<'de> for RawUnwindVisitor {
+    type Value = RawUnwind;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an unwind bound or a mapping of loop labels to unwind bounds")
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        let default = u32::try_from(value)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))?;
+        Ok(RawUnwind {
+            default,
+            loops: IndexMap::new(),
+        })
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        match u64::try_from(value) {
+            Ok(unsigned) => self.visit_u64(unsigned),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let mut loops = IndexMap::<String, u32>::deserialize(MapAccessDeserializer::new(map))?;
+        let default = loops.shift_remove(DEFAULT_UNWIND_KEY).ok_or_else(|| {
+            A::Error::custom("a per-loop unwind mapping must include a 'default' bound")
+        })?;
+        Ok(RawUnwind { default, loops })
+    }
+}
+
+impl RawKaniEvidence {
+    pub(crate) fn inherit(&mut self, defaults: &Self) {
+        if self.unwind.is_none() {
+            self.unwind.clone_from(&defaults.unwind);
+        }
+        if self.expect.is_none() {
+            self.expect = defaults.expect;
+        }
+        if self.allow_vacuous.is_none() {
+            self.allow_vacuous.clone_from(&defaults.allow_vacuous);
+        }
+        if self.vacuity_because.is_none() {
+            self.vacuity_because.clone_from(&defaults.vacuity_because);
+        }
+        if self.counterexample.is_none() {
+            self.counterexample.clone_from(&defaults.counterexample);
+        }
+        self.inherit_tuning(defaults);
+    }
+
+    /// Fills the optional solver, flag, limit, stub, contract, playback, and
+    /// coverage settings.
+    fn inherit_tuning(&mut self, defaults: &Self) {
+        if self.solver.is_none() {
+            self.solver = defaults.solver;
+        }
+        if self.extra_args.is_none() {
+            self.extra_args.clone_from(&defaults.extra_args);
+        }
+        if self.timeout.is_none() {
+            self.timeout = defaults.timeout;
+        }
+        if self.memory_limit.is_none() {
+            self.memory_limit = defaults.memory_limit;
+        }
+        if self.stubs.is_none() {
+            self.stubs.clone_from(&defaults.stubs);
+        }
+        if self.contracts.is_none() {
+            self.contracts.clone_from(&defaults.contracts);
+        }
+        if self.playback.is_none() {
+            self.playback = defaults.playback;
+        }
+        if self.coverage.is_none() {
+            self.coverage = defaults.coverage;
+        }
+    }
+
+    /// Returns the span of the field a Kani validation failure refers to.
+    pub(crate) fn location_for(&self, reason: KaniReason) -> Option<Location> {
+        match reason {
+            KaniReason::Name => self.name.as_ref().map(|name| name.referenced),
+            KaniReason::Unwind => self.unwind.as_ref().map(|unwind| unwind.referenced),
+            KaniReason::AllowVacuousRequired | KaniReason::WitnessRequired => self
+                .allow_vacuous
+                .as_ref()
+                .map(|allow_vacuous| allow_vacuous.referenced),
+            KaniReason::VacuityBecauseNonEmpty => self
+                .vacuity_because
+                .as_ref()
+                .map(|vacuity_because| vacuity_because.referenced),
+            KaniReason::ExtraArg { index } => self
+                .extra_args
+                .as_ref()?
+                .get(index)
+                .map(|arg| arg.referenced),
+            KaniReason::Stub { index } => {
+                self.stubs.as_ref()?.get(index).map(|stub| stub.referenced)
+            }
+            KaniReason::Contract { index } => self
+                .contracts
+                .as_ref()?
+                .get(index)
+                .map(|contract| contract.referenced),
+            KaniReason::Counterexample => self
+                .counterexample
+                .as_ref()
+                .map(|counterexample| counterexample.referenced),
+            KaniReason::StubbedContract { index } => {
+                self.stubs.as_ref()?.get(index).map(|stub| stub.referenced)
+            }
+            KaniReason::VacuousPlayback => self
+                .allow_vacuous
+                .as_ref()
+                .map(|allow_vacuous| allow_vacuous.referenced),
+            KaniReason::UnreachableWithWitness => None,
+        }
+    }
+
+    pub(crate) fn to_kani_evidence(&self) -> Result<KaniEvidence, RawDocDecodeError> {
+        let unwind = self
+            .unwind
+            .as_ref()
+            .ok_or(RawDocDecodeError::MissingField {
+                field: "Evidence.kani.unwind",
+            })?;
+        let expect = self.expect.ok_or(RawDocDecodeError::MissingField {
+            field: "Evidence.kani.expect",
+        })?;
+        Ok(KaniEvidence {
+            name: self.name.as_ref().map(|name| name.value.clone()),
+            unwind: unwind.value.default,
+            loop_unwind: unwind.value.loops.clone(),
+            expect,
+            allow_vacuous: self
+                .allow_vacuous
+                .as_ref()
+                .is_some_and(|allow_vacuous| allow_vacuous.value),
+            vacuity_because: self
+                .vacuity_because
+                .as_ref()
+                .map(|vacuity_because| vacuity_because.value.clone()),
+            solver: self.solver,
+            extra_args: self
+                .extra_args
+                .iter()
+                .flatten()
+                .map(|arg| arg.value.clone())
+                .collect(),
+            timeout: self.timeout,
+            memory_limit: self.memory_limit,
+            stubs: self
+                .stubs
+                .iter()
+                .flatten()
+                .map(|stub| stub.value.clone())
+                .collect(),
+            contracts: self
+                .contracts
+                .iter()
+                .flatten()
+                .map(|contract| contract.value.clone())
+                .collect(),
+            counterexample: self
+                .counterexample
+                .as_ref()
+                .map(|counterexample| counterexample.value.clone())
+                .unwrap_or_default(),
+            playback: self.playback.unwrap_or(false),
+            coverage: self.coverage.unwrap_or(false),
+        })
+    }
+}
+
+/// Deserializes optional `allow_vacuous` values as `Option<Spanned<bool>>`.
+///
+/// This helper is used with `#[serde(default)]`, so omitted fields deserialize
+/// as `None` before this function runs. Explicit YAML `null` values are
+/// rejected, while present values must deserialize as booleans.
+fn deserialize_optional_allow_vacuous<'de, D>(
+    deserializer: D,
+) -> Result<Option<Spanned<bool>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Spanned<bool>>::deserialize(deserializer)?.map_or_else(
+        || {
+            Err(D::Error::custom(
+                "allow_vacuous must be a boolean when provided",
+            ))
+        },
+        |value| Ok(Some(value)),
+    )
+}
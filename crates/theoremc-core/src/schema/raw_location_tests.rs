@@ -1,20 +1,20 @@
 //! Unit tests for typed validation reason source-location lookup.
 
 use super::RawTheoremDoc;
-use crate::schema::validation_reason::{IndexedValidationField, ValidationReasonKind};
+use crate::schema::validation_reason::{IndexedValidationField, KaniReason, ValidationReasonKind};
 use rstest::rstest;
 
 const LOCATION_FIXTURE: &str = "\
 Theorem: T
 About: ''
 Assume:
-  - expr: ''
+  - expr: 'true'
     because: ''
 Prove:
-  - assert: ''
+  - assert: 'true'
     because: ''
 Witness:
-  - cover: ''
+  - cover: 'true'
     because: ''
 Evidence:
   kani:
@@ -76,15 +76,45 @@ fn raw_doc() -> RawTheoremDoc {
     },
     11
 )]
-#[case::kani_unwind(ValidationReasonKind::KaniUnwind, 14)]
-#[case::kani_missing_vacuity_reason(ValidationReasonKind::KaniAllowVacuousRequired, 16)]
-#[case::kani_blank_vacuity_reason(ValidationReasonKind::KaniVacuityBecauseNonEmpty, 17)]
-#[case::kani_witness_required(ValidationReasonKind::KaniWitnessRequired, 16)]
+#[case::kani_unwind(kani(KaniReason::Unwind), 14)]
+#[case::kani_missing_vacuity_reason(kani(KaniReason::AllowVacuousRequired), 16)]
+#[case::kani_blank_vacuity_reason(kani(KaniReason::VacuityBecauseNonEmpty), 17)]
+#[case::kani_witness_required(kani(KaniReason::WitnessRequired), 16)]
 fn validation_reason_kind_selects_location_without_rendered_message(
     #[case] reason: ValidationReasonKind,
     #[case] expected_line: u64,
+    #[values(false, true)] converted: bool,
 ) {
-    let location = raw_doc().location_for_validation_reason(reason);
+    // Converting moves values out but must leave the spans in place.
+    let mut doc = raw_doc();
+    if converted {
+        doc.take_theorem_doc().expect("fixture should convert");
+    }
+    let location = doc.location_for_validation_reason(reason);
 
     assert_eq!(location.line(), expected_line);
 }
+
+const fn kani(reason: KaniReason) -> ValidationReasonKind {
+    ValidationReasonKind::Kani { config: 0, reason }
+}
+
+#[test]
+fn kani_reason_selects_the_failing_configuration() {
+    let yaml = LOCATION_FIXTURE.replace(
+        "  kani:\n    unwind: 0\n",
+        "  kani:\n    - name: quick\n      unwind: 1\n      expect: SUCCESS\n    - name: deep\n      unwind: 0\n",
+    ).replace("\n    expect: SUCCESS\n    allow_vacuous: true\n    vacuity_because: ''\n", "\n      expect: SUCCESS\n");
+    let docs: Vec<RawTheoremDoc> =
+        serde_saphyr::from_multiple(&yaml).expect("fixture should deserialize");
+    let doc = docs
+        .into_iter()
+        .next()
+        .expect("fixture should contain one theorem document");
+    let location = doc.location_for_validation_reason(ValidationReasonKind::Kani {
+        config: 1,
+        reason: KaniReason::Name,
+    });
+
+    assert_eq!(location.line(), 17);
+}
@@ -0,0 +1,106 @@
+//! Raw `Assume`, `Prove`, and `Witness` entries with span-aware fields.
+//!
+//! Each entry keeps the spans of its expression and justification so
+//! validation failures can point at them, even after
+//! [`RawTheoremDoc::take_theorem_doc`](super::raw::RawTheoremDoc::take_theorem_doc)
+//! has moved the values out. Moving an entry out checks its expression, so
+//! a converted document only holds expressions that parse.
+
+use serde::Deserialize;
+use serde_saphyr::Spanned;
+
+use super::raw::RawDocDecodeError;
+use super::symbol::Symbol;
+use super::theorem_expr::{EntryExprError, TheoremExpr};
+use super::types::{Assertion, Assumption, WitnessCheck};
+use super::validation_reason::IndexedValidationSection;
+
+/// Raw assumption with span-aware fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawAssumption {
+    #[serde(rename = "assume", alias = "expr")]
+    pub(crate) expr: Spanned<String>,
+    pub(crate) because: Spanned<String>,
+}
+
+/// Raw assertion with span-aware fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawAssertion {
+    #[serde(rename = "assert")]
+    pub(crate) assert_expr: Spanned<String>,
+    pub(crate) because: Spanned<String>,
+    #[serde(default)]
+    pub(crate) step: Option<Spanned<String>>,
+}
+
+/// Raw witness check with span-aware fields.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawWitnessCheck {
+    pub(crate) cover: Spanned<String>,
+    pub(crate) because: Spanned<String>,
+}
+
+impl RawAssumption {
+    /// Moves the values of `Assume` entry `index` into a public assumption,
+    /// leaving the spans.
+    pub(crate) fn take(&mut self, index: usize) -> Result<Assumption, EntryExprError> {
+        Ok(Assumption {
+            expr: take_expr(&mut self.expr, IndexedValidationSection::Assume, index)?,
+            because: Symbol::from(std::mem::take(&mut self.because.value)),
+        })
+    }
+}
+
+impl RawAssertion {
+    /// Moves the values of `Prove` entry `index` into a public assertion,
+    /// leaving the spans.
+    pub(crate) fn take(&mut self, index: usize) -> Result<Assertion, EntryExprError> {
+        Ok(Assertion {
+            assert_expr: take_expr(
+                &mut self.assert_expr,
+                IndexedValidationSection::Prove,
+                index,
+            )?,
+            because: Symbol::from(std::mem::take(&mut self.because.value)),
+            step: self
+                .step
+                .as_mut()
+                .map(|step| std::mem::take(&mut step.value)),
+        })
+    }
+}
+
+impl RawWitnessCheck {
+    /// Moves the values of `Witness` entry `index` into a public witness
+    /// check, leaving the spans.
+    pub(crate) fn take(&mut self, index: usize) -> Result<WitnessCheck, EntryExprError> {
+        Ok(WitnessCheck {
+            cover: take_expr(&mut self.cover, IndexedValidationSection::Witness, index)?,
+            because: Symbol::from(std::mem::take(&mut self.because.value)),
+        })
+    }
+}
+
+/// Moves every entry of a section out with `take`, stopping at the first
+/// expression that is blank or does not parse.
+pub(crate) fn take_entries<R, T>(
+    entries: &mut [R],
+    take: fn(&mut R, usize) -> Result<T, EntryExprError>,
+) -> Result<Vec<T>, RawDocDecodeError> {
+    entries
+        .iter_mut()
+        .enumerate()
+        .map(|(index, entry)| take(entry, index).map_err(RawDocDecodeError::Expression))
+        .collect()
+}
+
+fn take_expr(
+    expr: &mut Spanned<String>,
+    section: IndexedValidationSection,
+    index: usize,
+) -> Result<TheoremExpr, EntryExprError> {
+    TheoremExpr::for_entry(std::mem::take(&mut expr.value), section, index)
+}
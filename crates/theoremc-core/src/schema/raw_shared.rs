@@ -0,0 +1,207 @@
+//! Raw `Shared` documents and the `Use` merge into theorem documents.
+//!
+//! A `Shared` document names a block of `Forall` and `Assume` entries that
+//! theorems declared in the same source pull in with `Use`. Used blocks merge
+//! in listed order ahead of the theorem's own entries, before include
+//! resolution and semantic validation, and a `Forall` name declared by more
+//! than one merged source is rejected.
+
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_saphyr::{Location, Spanned};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::identifier::validate_identifier;
+use super::newtypes::ForallVar;
+use super::raw::RawTheoremDoc;
+use super::raw_forall::RawForallEntry;
+use super::raw_obligation::RawAssumption;
+use super::source_id::SourceId;
+
+/// Raw `Shared` document declaring reusable theorem inputs.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawShared {
+    #[serde(rename = "Shared", alias = "shared")]
+    pub(crate) name: Spanned<String>,
+    #[serde(rename = "About", alias = "about")]
+    pub(crate) about: Spanned<String>,
+    #[serde(rename = "Forall", alias = "forall", default)]
+    pub(crate) forall: IndexMap<ForallVar, RawForallEntry>,
+    #[serde(rename = "Assume", alias = "assume", default)]
+    pub(crate) assume: Vec<RawAssumption>,
+}
+
+/// Checks shared block names, descriptions, and contents.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::InvalidShared`] when a block name is not a valid
+/// identifier or is declared twice, when `About` is blank, or when the block
+/// declares neither `Forall` nor `Assume` entries.
+pub(crate) fn check_shared(source: &SourceId, blocks: &[RawShared]) -> Result<(), SchemaError> {
+    let mut names = HashSet::new();
+    for block in blocks {
+        let name = block.name.value.as_str();
+        let invalid =
+            |reason: String, location: Location| invalid_shared(source, name, reason, location);
+        if let Err(error) = validate_identifier(name) {
+            return Err(invalid(error.to_string(), block.name.referenced));
+        }
+        if !names.insert(name) {
+            return Err(invalid(
+                "shared block is declared more than once".to_owned(),
+                block.name.referenced,
+            ));
+        }
+        if block.about.value.trim().is_empty() {
+            return Err(invalid(
+                "About must be non-empty after trimming".to_owned(),
+                block.about.referenced,
+            ));
+        }
+        if block.forall.is_empty() && block.assume.is_empty() {
+            return Err(invalid(
+                "a shared block must declare Forall or Assume entries".to_owned(),
+                block.name.referenced,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Merges the shared blocks `raw_doc` lists under `Use` ahead of its own
+/// `Forall` and `Assume` entries.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::UseFailed`] when a `Use` entry names an undeclared
+/// block or repeats an earlier entry, or when two merged sources declare the
+/// same `Forall` name.
+pub(crate) fn apply_shared(
+    source: &SourceId,
+    blocks: &[RawShared],
+    raw_doc: &mut RawTheoremDoc,
+) -> Result<(), SchemaError> {
+    if raw_doc.uses.is_empty() {
+        return Ok(());
+    }
+    let theorem = raw_doc.theorem.value.to_string();
+    let fail =
+        |entry: &Spanned<String>, reason: String| use_failed(source, &theorem, entry, reason);
+    let mut merged = UseMerge::default();
+    for (position, entry) in raw_doc.uses.iter().enumerate() {
+        used_block(blocks, &raw_doc.uses, position)
+            .and_then(|block| merged.add(block, entry))
+            .map_err(|reason| fail(entry, reason))?;
+    }
+    for (var, forall_entry) in std::mem::take(&mut raw_doc.forall) {
+        if let Some(entry) = merged.owners.get(var.as_str()) {
+            let owner = entry.value.trim();
+            return Err(fail(
+                entry,
+                format!(
+                    "Forall entry '{var}' is declared by both shared block '{owner}' and the theorem"
+                ),
+            ));
+        }
+        merged.forall.insert(var, forall_entry);
+    }
+    merged.assume.append(&mut raw_doc.assume);
+    raw_doc.forall = merged.forall;
+    raw_doc.assume = merged.assume;
+    Ok(())
+}
+
+/// Returns the block named by the `Use` entry at `position`, rejecting
+/// undeclared blocks and repeated entries.
+fn used_block<'b>(
+    blocks: &'b [RawShared],
+    uses: &[Spanned<String>],
+    position: usize,
+) -> Result<&'b RawShared, String> {
+    let name = uses.get(position).map_or("", |entry| entry.value.trim());
+    let block = blocks
+        .iter()
+        .find(|block| block.name.value == name)
+        .ok_or_else(|| format!("shared block '{name}' is not declared in this file"))?;
+    if uses
+        .get(..position)
+        .is_some_and(|earlier| earlier.iter().any(|entry| entry.value.trim() == name))
+    {
+        return Err(format!("shared block '{name}' is listed more than once"));
+    }
+    Ok(block)
+}
+
+/// Entries merged from used blocks so far, with the `Use` entry that
+/// introduced each `Forall` name.
+#[derive(Default)]
+struct UseMerge<'u> {
+    forall: IndexMap<ForallVar, RawForallEntry>,
+    owners: IndexMap<String, &'u Spanned<String>>,
+    assume: Vec<RawAssumption>,
+}
+
+impl<'u> UseMerge<'u> {
+    fn add(&mut self, block: &RawShared, entry: &'u Spanned<String>) -> Result<(), String> {
+        for (var, forall_entry) in &block.forall {
+            if let Some(first) = self.owners.insert(var.as_str().to_owned(), entry) {
+                return Err(format!(
+                    "Forall entry '{var}' is declared by both shared blocks '{}' and '{}'",
+                    first.value.trim(),
+                    entry.value.trim(),
+                ));
+            }
+            self.forall.insert(var.clone(), forall_entry.clone());
+        }
+        self.assume.extend(block.assume.iter().cloned());
+        Ok(())
+    }
+}
+
+fn invalid_shared(
+    source: &SourceId,
+    shared: &str,
+    reason: String,
+    location: Location,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        location,
+    );
+    SchemaError::InvalidShared {
+        shared: shared.to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+fn use_failed(
+    source: &SourceId,
+    theorem: &str,
+    entry: &Spanned<String>,
+    reason: String,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        entry.referenced,
+    );
+    SchemaError::UseFailed {
+        theorem: theorem.to_owned(),
+        shared: entry.value.trim().to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
+
+#[cfg(test)]
+#[path = "raw_shared_tests.rs"]
+mod tests;
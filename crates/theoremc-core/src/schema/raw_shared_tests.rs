@@ -0,0 +1,182 @@
+//! Unit tests for `Shared` documents and `Use` merging.
+
+use rstest::rstest;
+
+use crate::schema::test_fixtures::theorem;
+use crate::schema::{ForallVar, SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_source};
+
+const SOURCE: &str = "theorems/accounts.theorem";
+
+const ACCOUNTS: &str = concat!(
+    "Shared: AccountInputs\n",
+    "About: Account inputs shared by deposit theorems\n",
+    "Forall:\n",
+    "  balance: u64\n",
+    "  amount: { type: u64, max: 1000 }\n",
+    "Assume:\n",
+    "  - expr: 'amount <= balance'\n",
+    "    because: deposits never exceed the balance\n",
+);
+
+const FEES: &str = concat!(
+    "Shared: FeeInputs\n",
+    "About: Fee inputs\n",
+    "Forall:\n",
+    "  fee: u8\n",
+);
+
+fn load(documents: &[&str]) -> Result<Vec<TheoremDoc>, SchemaError> {
+    load_theorem_docs_with_source(&SourceId::new(SOURCE), &documents.join("---\n"))
+}
+
+fn forall_names(doc: &TheoremDoc) -> Vec<&str> {
+    doc.forall.keys().map(ForallVar::as_str).collect()
+}
+
+fn assume_exprs(doc: &TheoremDoc) -> Vec<&str> {
+    doc.assume
+        .iter()
+        .map(|assumption| assumption.expr.as_str())
+        .collect()
+}
+
+#[test]
+fn used_blocks_merge_ahead_of_the_theorems_own_entries() {
+    let docs = load(&[
+        ACCOUNTS,
+        FEES,
+        &theorem(
+            "Deposit",
+            concat!(
+                "Use: [FeeInputs, AccountInputs]\n",
+                "Forall:\n  memo: u32\n",
+                "Assume:\n  - expr: 'fee <= 10'\n    because: fees are small\n",
+            ),
+        ),
+    ])
+    .expect("shared blocks should merge");
+    let doc = docs.first().expect("one theorem");
+
+    assert_eq!(forall_names(doc), ["fee", "balance", "amount", "memo"]);
+    assert_eq!(assume_exprs(doc), ["amount <= balance", "fee <= 10"]);
+    assert!(
+        doc.forall_domains
+            .keys()
+            .any(|var| var.as_str() == "amount")
+    );
+}
+
+#[test]
+fn every_theorem_receives_its_own_copy_of_a_block() {
+    let docs = load(&[
+        &theorem("First", "Use: [AccountInputs]\n"),
+        &theorem("Second", "Use: [AccountInputs]\n"),
+        &theorem("Third", ""),
+        ACCOUNTS,
+    ])
+    .expect("shared blocks may follow the theorems that use them");
+
+    let merged: Vec<Vec<&str>> = docs.iter().map(forall_names).collect();
+    assert_eq!(
+        merged,
+        [vec!["balance", "amount"], vec!["balance", "amount"], vec![]]
+    );
+}
+
+#[rstest]
+#[case::unknown_block(
+    "Use: [Missing]\n",
+    "shared block 'Missing' is not declared in this file"
+)]
+#[case::repeated_entry(
+    "Use: [FeeInputs, FeeInputs]\n",
+    "shared block 'FeeInputs' is listed more than once"
+)]
+#[case::clash_between_blocks(
+    "Use: [AccountInputs, Overlap]\n",
+    "Forall entry 'amount' is declared by both shared blocks 'AccountInputs' and 'Overlap'"
+)]
+#[case::clash_with_theorem(
+    "Use: [FeeInputs]\nForall:\n  fee: u16\n",
+    "Forall entry 'fee' is declared by both shared block 'FeeInputs' and the theorem"
+)]
+fn invalid_use_entries_are_rejected(#[case] extra: &str, #[case] expected: &str) {
+    let overlap = "Shared: Overlap\nAbout: overlapping\nForall:\n  amount: u32\n";
+    let error = load(&[ACCOUNTS, FEES, overlap, &theorem("Deposit", extra)])
+        .expect_err("use should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::UseFailed { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    assert_eq!(diagnostic.location.line, 22);
+}
+
+#[rstest]
+#[case::invalid_name(
+    "Shared: fn\nAbout: x\nForall:\n  a: u8\n",
+    "invalid identifier 'fn'",
+    1
+)]
+#[case::blank_about("Shared: Inputs\nAbout: ' '\nForall:\n  a: u8\n", "About must be", 2)]
+#[case::empty_block("Shared: Inputs\nAbout: nothing\n", "must declare Forall or Assume", 1)]
+#[case::duplicate_name(
+    "Shared: FeeInputs\nAbout: again\nAssume:\n  - expr: 'true'\n    because: always\n",
+    "shared block is declared more than once",
+    1
+)]
+fn invalid_shared_blocks_are_rejected(
+    #[case] block: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error =
+        load(&[FEES, block, &theorem("Deposit", "")]).expect_err("shared block should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::InvalidShared { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    // The block follows the five-line `FeeInputs` document and separator.
+    assert_eq!(diagnostic.location.line, line + 5);
+}
+
+#[test]
+fn shared_entries_are_validated_at_their_declaration() {
+    let block = "Shared: Limits\nAbout: limits\nForall:\n  cap: { type: u8, max: 300 }\n";
+    let error = load(&[block, &theorem("Deposit", "Use: [Limits]\n")])
+        .expect_err("out-of-range bound should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains("max 300 is out of range for u8"),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 4);
+}
+
+#[test]
+fn unknown_shared_keys_are_rejected() {
+    let message = load(&[
+        "Shared: Inputs\nAbout: x\nForall:\n  a: u8\nProve: []\n",
+        &theorem("Deposit", ""),
+    ])
+    .expect_err("unknown shared key should be rejected")
+    .to_string();
+
+    assert!(
+        message.contains("unknown field `Prove`"),
+        "unexpected error: {message}"
+    );
+}
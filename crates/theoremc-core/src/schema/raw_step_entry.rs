@@ -0,0 +1,149 @@
+//! Kind dispatch for `Do` steps and `Let` bindings.
+//!
+//! A step or binding is a mapping whose key names its kind, such as `call`
+//! or `maybe`. Each is first read as a struct declaring every kind key, so
+//! unknown keys are reported by name (and skipped by lenient loading like
+//! any other struct key), and then converted into the single kind it
+//! writes.
+
+use serde::Deserialize;
+
+use super::raw_action::{
+    RawActionCall, RawExpectErrCall, RawForeachBlock, RawLetBinding, RawLetCall, RawLetMust,
+    RawMaybeBlock, RawRepeatBlock, RawStep, RawStepAssert, RawStepCall, RawStepExpectErr,
+    RawStepForeach, RawStepMaybe, RawStepMust, RawStepRepeat,
+};
+use super::types::InlineAssert;
+
+/// Kind keys of a `Do` step, in the order diagnostics list them.
+const STEP_KINDS: [&str; 7] = [
+    "call",
+    "must",
+    "expect_err",
+    "maybe",
+    "repeat",
+    "foreach",
+    "assert",
+];
+
+/// A `Do` step mapping as written, before its kind is decided.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawStepEntry {
+    #[serde(default)]
+    call: Option<RawActionCall>,
+    #[serde(default)]
+    must: Option<RawActionCall>,
+    #[serde(default)]
+    expect_err: Option<RawExpectErrCall>,
+    #[serde(default)]
+    maybe: Option<RawMaybeBlock>,
+    #[serde(default)]
+    repeat: Option<RawRepeatBlock>,
+    #[serde(default)]
+    foreach: Option<RawForeachBlock>,
+    #[serde(default)]
+    assert: Option<InlineAssert>,
+    #[serde(default)]
+    id: Option<String>,
+}
+
+impl TryFrom<RawStepEntry> for RawStep {
+    type Error = String;
+
+    fn try_from(entry: RawStepEntry) -> Result<Self, Self::Error> {
+        let written = [
+            entry.call.is_some(),
+            entry.must.is_some(),
+            entry.expect_err.is_some(),
+            entry.maybe.is_some(),
+            entry.repeat.is_some(),
+            entry.foreach.is_some(),
+            entry.assert.is_some(),
+        ];
+        check_one_kind("a Do step", &STEP_KINDS, &written)?;
+        if let Some(call) = entry.call {
+            return Ok(Self::Call(RawStepCall { call, id: entry.id }));
+        }
+        if let Some(must) = entry.must {
+            return Ok(Self::Must(RawStepMust { must, id: entry.id }));
+        }
+        if entry.id.is_some() {
+            return Err("`id` is only allowed on `call` and `must` steps".to_owned());
+        }
+        entry
+            .expect_err
+            .map(|expect_err| Self::ExpectErr(RawStepExpectErr { expect_err }))
+            .or_else(|| entry.maybe.map(|maybe| Self::Maybe(RawStepMaybe { maybe })))
+            .or_else(|| {
+                entry
+                    .repeat
+                    .map(|repeat| Self::Repeat(RawStepRepeat { repeat }))
+            })
+            .or_else(|| {
+                entry
+                    .foreach
+                    .map(|foreach| Self::Foreach(RawStepForeach { foreach }))
+            })
+            .or_else(|| {
+                entry
+                    .assert
+                    .map(|assert| Self::Assert(RawStepAssert { assert }))
+            })
+            .ok_or_else(|| "a Do step needs a kind".to_owned())
+    }
+}
+
+/// A `Let` binding mapping as written, before its kind is decided.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawLetEntry {
+    #[serde(default)]
+    call: Option<RawActionCall>,
+    #[serde(default)]
+    must: Option<RawActionCall>,
+}
+
+impl TryFrom<RawLetEntry> for RawLetBinding {
+    type Error = String;
+
+    fn try_from(entry: RawLetEntry) -> Result<Self, Self::Error> {
+        check_one_kind(
+            "a Let binding",
+            &["call", "must"],
+            &[entry.call.is_some(), entry.must.is_some()],
+        )?;
+        entry
+            .call
+            .map(|call| Self::Call(RawLetCall { call }))
+            .or_else(|| entry.must.map(|must| Self::Must(RawLetMust { must })))
+            .ok_or_else(|| "a Let binding needs a kind".to_owned())
+    }
+}
+
+/// Checks that exactly one of `kinds` is `written`.
+fn check_one_kind(what: &str, kinds: &[&str], written: &[bool]) -> Result<(), String> {
+    let found: Vec<String> = kinds
+        .iter()
+        .zip(written)
+        .filter(|&(_, &is_written)| is_written)
+        .map(|(kind, _)| format!("`{kind}`"))
+        .collect();
+    if found.len() == 1 {
+        return Ok(());
+    }
+    let expected: Vec<String> = kinds.iter().map(|kind| format!("`{kind}`")).collect();
+    let found_text = if found.is_empty() {
+        "none".to_owned()
+    } else {
+        found.join(" and ")
+    };
+    Err(format!(
+        "{what} needs exactly one of {}, found {found_text}",
+        expected.join(", ")
+    ))
+}
+
+#[cfg(test)]
+#[path = "raw_step_entry_tests.rs"]
+mod tests;
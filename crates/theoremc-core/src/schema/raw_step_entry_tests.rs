@@ -0,0 +1,51 @@
+//! Unit tests for `Do` step and `Let` binding kind dispatch.
+
+use rstest::rstest;
+
+use super::super::raw_action::{RawLetBinding, RawStep};
+
+const CALL: &str = "{ action: ledger.deposit, args: {} }";
+
+fn step(yaml: &str) -> Result<RawStep, String> {
+    serde_saphyr::from_str(yaml).map_err(|error| error.to_string())
+}
+
+#[rstest]
+#[case::call(&format!("call: {CALL}\nid: first\n"), "Call")]
+#[case::must(&format!("must: {CALL}\n"), "Must")]
+#[case::repeat(&format!("repeat:\n  times: 2\n  do:\n    - call: {CALL}\n"), "Repeat")]
+#[case::assert("assert: { expr: 'true', because: always }\n", "Assert")]
+fn steps_take_the_kind_they_write(#[case] yaml: &str, #[case] kind: &str) {
+    let parsed = step(yaml).expect("step should parse");
+
+    assert!(format!("{parsed:?}").starts_with(kind), "{parsed:?}");
+}
+
+#[rstest]
+#[case::two_kinds(
+    &format!("call: {CALL}\nmust: {CALL}\n"),
+    "a Do step needs exactly one of `call`, `must`, `expect_err`, `maybe`, `repeat`, \
+     `foreach`, `assert`, found `call` and `must`"
+)]
+#[case::no_kind("id: lonely\n", "found none")]
+#[case::misplaced_id(
+    &format!("maybe:\n  because: sometimes\n  do:\n    - call: {CALL}\nid: branch\n"),
+    "`id` is only allowed on `call` and `must` steps"
+)]
+#[case::unknown_key(&format!("call: {CALL}\nretries: 3\n"), "unknown field `retries`")]
+fn malformed_steps_name_the_problem(#[case] yaml: &str, #[case] expected: &str) {
+    let error = step(yaml).expect_err("step should be rejected");
+
+    assert!(error.contains(expected), "{error}");
+}
+
+#[rstest]
+#[case::call(&format!("call: {CALL}\n"), true)]
+#[case::must(&format!("must: {CALL}\n"), true)]
+#[case::both(&format!("call: {CALL}\nmust: {CALL}\n"), false)]
+#[case::step_only(&format!("call: {CALL}\nid: first\n"), false)]
+fn let_bindings_take_exactly_one_kind(#[case] yaml: &str, #[case] is_accepted: bool) {
+    let parsed: Result<RawLetBinding, _> = serde_saphyr::from_str(yaml);
+
+    assert_eq!(parsed.is_ok(), is_accepted, "{parsed:?}");
+}
@@ -0,0 +1,134 @@
+//! Raw `Suite` documents and their structural checks.
+//!
+//! A `Suite` document names theorems declared in the same source and may
+//! supply default `Evidence` for them. Suites are checked before theorem
+//! decoding so member evidence can be merged ahead of validation; their
+//! `Requires` policies are checked against the validated members in
+//! [`suite_policy`](super::suite_policy).
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+use serde_saphyr::{Location, Spanned};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::identifier::validate_identifier;
+use super::raw::RawTheoremDoc;
+use super::raw_evidence::{RawEvidence, inherit_evidence};
+use super::source_id::SourceId;
+use super::suite_policy::SuiteRequirements;
+use super::symbol::Symbol;
+
+/// Raw `Suite` document grouping theorems from the same source.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawSuite {
+    #[serde(rename = "Suite", alias = "suite")]
+    pub(crate) name: Spanned<String>,
+    #[serde(rename = "About", alias = "about")]
+    pub(crate) about: Spanned<String>,
+    #[serde(rename = "Tags", alias = "tags", default)]
+    pub(crate) tags: Vec<Symbol>,
+    #[serde(rename = "Theorems", alias = "theorems")]
+    pub(crate) theorems: Vec<Spanned<String>>,
+    #[serde(rename = "Evidence", alias = "evidence", default)]
+    pub(crate) evidence: Option<RawEvidence>,
+    #[serde(rename = "Requires", alias = "requires", default)]
+    pub(crate) requires: SuiteRequirements,
+}
+
+impl RawSuite {
+    /// Returns `true` when `theorem` is listed in this suite.
+    pub(crate) fn contains(&self, theorem: &str) -> bool {
+        self.theorems.iter().any(|member| member.value == theorem)
+    }
+
+    /// Fills evidence fields `raw_doc` leaves unset from the suite defaults.
+    pub(crate) fn apply_to(&self, raw_doc: &mut RawTheoremDoc) {
+        inherit_evidence(&mut raw_doc.evidence, self.evidence.as_ref());
+    }
+}
+
+/// Checks suite names, descriptions, and membership against the theorem
+/// documents loaded from the same source.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::InvalidSuite`] when a suite name is not a valid
+/// identifier or is declared twice, when `About` is blank, when `Theorems` is
+/// empty, or when a member is undeclared or already grouped by a suite.
+pub(crate) fn check_suites(
+    source: &SourceId,
+    suites: &[RawSuite],
+    theorems: &[RawTheoremDoc],
+) -> Result<(), SchemaError> {
+    let declared: HashSet<&str> = theorems
+        .iter()
+        .map(|raw_doc| raw_doc.theorem.value.as_str())
+        .collect();
+    let mut suite_names = HashSet::new();
+    let mut owners: HashMap<&str, &str> = HashMap::new();
+
+    for suite in suites {
+        let name = suite.name.value.as_str();
+        let invalid =
+            |reason: String, location: Location| invalid_suite(source, name, reason, location);
+        if let Err(error) = validate_identifier(name) {
+            return Err(invalid(error.to_string(), suite.name.referenced));
+        }
+        if !suite_names.insert(name) {
+            return Err(invalid(
+                "suite is declared more than once".to_owned(),
+                suite.name.referenced,
+            ));
+        }
+        if suite.about.value.trim().is_empty() {
+            return Err(invalid(
+                "About must be non-empty after trimming".to_owned(),
+                suite.about.referenced,
+            ));
+        }
+        if suite.theorems.is_empty() {
+            return Err(invalid(
+                "Theorems must list at least one theorem".to_owned(),
+                suite.name.referenced,
+            ));
+        }
+        for member in &suite.theorems {
+            let theorem = member.value.as_str();
+            if !declared.contains(theorem) {
+                return Err(invalid(
+                    format!("theorem '{theorem}' is not declared in this file"),
+                    member.referenced,
+                ));
+            }
+            if let Some(owner) = owners.insert(theorem, name) {
+                return Err(invalid(
+                    format!("theorem '{theorem}' already belongs to suite '{owner}'"),
+                    member.referenced,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn invalid_suite(
+    source: &SourceId,
+    suite: &str,
+    reason: String,
+    location: Location,
+) -> SchemaError {
+    let diagnostic = create_diagnostic(
+        SchemaDiagnosticCode::ValidationFailure,
+        source,
+        reason.clone(),
+        location,
+    );
+    SchemaError::InvalidSuite {
+        suite: suite.to_owned(),
+        reason,
+        diagnostic: Some(Box::new(diagnostic)),
+    }
+}
@@ -0,0 +1,120 @@
+//! JSON text rendered from a TOML or RON source for the YAML loader.
+//!
+//! The front-ends for formats the YAML parser cannot read render their
+//! parsed value as JSON text with one entry per line, which the YAML loader
+//! then reads like a JSON source. Each rendered line remembers the position
+//! of the key or value it came from in the original source, and diagnostics
+//! are moved back to that position before they are returned.
+
+use super::error::SchemaError;
+use super::include::NoIncludes;
+use super::load_options::LoadOptions;
+use super::loader::{SourceLoad, load_parsed_documents};
+use super::loader_json::parse_documents;
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+
+/// A 1-indexed line and column in the original source.
+pub(crate) type Position = (usize, usize);
+
+/// JSON text with the original source position of each line.
+#[derive(Debug, Default)]
+pub(super) struct RenderedSource {
+    /// The rendered JSON text.
+    pub(super) text: String,
+    /// The original position of each rendered line.
+    pub(super) origins: Vec<Position>,
+}
+
+impl RenderedSource {
+    /// Appends `content` as a line that came from `origin`.
+    pub(super) fn line(&mut self, content: &str, origin: Position) {
+        self.text.push_str(content);
+        self.text.push('\n');
+        self.origins.push(origin);
+    }
+
+    /// Ends the last line with a comma.
+    pub(super) fn separate(&mut self) {
+        self.text.pop();
+        self.text.push_str(",\n");
+    }
+
+    /// Loads the rendered document, or array of documents, as theorem
+    /// documents of `source`, locating errors in the original source.
+    pub(super) fn load(&self, source: &SourceId) -> Result<Vec<TheoremDoc>, SchemaError> {
+        let load = SourceLoad {
+            source,
+            input: &self.text,
+            resolver: &NoIncludes,
+            manifest: None,
+            options: &LoadOptions::default(),
+        };
+        parse_documents(source, &self.text)
+            .and_then(|documents| load_parsed_documents(&load, documents, &[]))
+            .map(|(_, loaded)| loaded.docs)
+            .map_err(|error| self.relocate(source, error))
+    }
+
+    /// Moves the diagnostic of `error` from the rendered text back to the
+    /// original source, rewriting the position a parser message starts
+    /// with.
+    fn relocate(&self, source: &SourceId, mut error: SchemaError) -> SchemaError {
+        let Some(diagnostic) = error.diagnostic_mut() else {
+            return error;
+        };
+        let origin = diagnostic
+            .location
+            .line
+            .checked_sub(1)
+            .and_then(|index| self.origins.get(index));
+        if diagnostic.location.source != source.as_str() {
+            return error;
+        }
+        let Some(&(line, column)) = origin else {
+            return error;
+        };
+        diagnostic.location.line = line;
+        diagnostic.location.column = column;
+        if let Some(reason) = positioned_reason(&diagnostic.message) {
+            diagnostic.message = format!("line {line} column {column}: {reason}");
+        }
+        let relocated = diagnostic.message.clone();
+        if let SchemaError::Deserialize { message, .. } = &mut error {
+            *message = relocated;
+        }
+        error
+    }
+}
+
+/// Returns the reason of a parser message that starts with the position it
+/// concerns, such as `error: line 3 column 1: unknown field`.
+pub(super) fn positioned_reason(message: &str) -> Option<&str> {
+    let located = message.strip_prefix("error: ").unwrap_or(message);
+    let (position, reason) = located.split_once(": ")?;
+    let (line, column) = position.strip_prefix("line ")?.split_once(" column ")?;
+    let numeric = |text: &str| !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+    (numeric(line) && numeric(column)).then_some(reason)
+}
+
+/// Returns the 1-indexed line and column of byte `offset` in `input`.
+pub(crate) fn position(input: &str, offset: usize) -> Position {
+    let before = input.get(..offset).unwrap_or(input);
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .get(line_start..)
+        .map_or(0, |text| text.chars().count())
+        + 1;
+    (line, column)
+}
+
+/// Quotes `text` as a JSON string, which YAML reads as a double-quoted
+/// scalar.
+pub(super) fn quoted(text: &str) -> String {
+    serde_json::Value::from(text).to_string()
+}
+
+#[cfg(test)]
+#[path = "rendered_source_tests.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+//! Unit tests for rendered JSON text and its source positions.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+
+#[rstest]
+#[case::first_line(0, (1, 1))]
+#[case::later_line(8, (2, 3))]
+#[case::after_multibyte(12, (3, 2))]
+fn offsets_become_lines_and_columns(#[case] offset: usize, #[case] expected: Position) {
+    assert_eq!(position("abcde\nxyz\né=1", offset), expected);
+}
+
+#[rstest]
+#[case::parser_error("error: line 3 column 1: unknown field", Some("unknown field"))]
+#[case::bare_position("line 12 column 40: invalid type", Some("invalid type"))]
+#[case::no_position("unknown field `x`", None)]
+#[case::not_numeric("line three column 1: reason", None)]
+fn parser_messages_give_up_their_position(#[case] message: &str, #[case] expected: Option<&str>) {
+    assert_eq!(positioned_reason(message), expected);
+}
+
+#[test]
+fn separators_end_the_previous_line() {
+    let mut rendered = RenderedSource::default();
+    rendered.line("[", (1, 1));
+    rendered.line("1", (2, 3));
+    rendered.separate();
+    rendered.line("2", (3, 3));
+    rendered.line("]", (1, 1));
+
+    assert_eq!(rendered.text, "[\n1,\n2\n]\n");
+    assert_eq!(rendered.origins, [(1, 1), (2, 3), (3, 3), (1, 1)]);
+}
@@ -1,6 +1,7 @@
 //! Shared parsing and canonicalisation for Rust type strings.
 
 use quote::ToTokens;
+use syn::visit::{self, Visit};
 use syn::{
     AngleBracketedGenericArguments, BoundLifetimes, GenericArgument, GenericParam,
     ParenthesizedGenericArguments, PathArguments, ReturnType, Type, TypeArray, TypeBareFn,
@@ -31,6 +32,48 @@ pub(crate) fn canonical_token_stream(ty: &str) -> Option<String> {
         .map(|parsed| parsed.to_token_stream().to_string())
 }
 
+/// Returns `true` when `ty` names a `Result` type, such as `Result<T, E>`,
+/// `std::result::Result<T, E>`, or an alias like `io::Result<T>`.
+pub(crate) fn is_result(ty: &str) -> bool {
+    matches!(
+        parse(ty),
+        Ok(Type::Path(TypePath { qself: None, path }))
+            if path.segments.last().is_some_and(|segment| segment.ident == "Result")
+    )
+}
+
+/// Returns `true` when `ty`, or a type nested inside it such as a generic
+/// argument, is the type `needle`. Both are compared as canonical token
+/// streams, so `Vec<crate::Account>` mentions `crate :: Account`; strings
+/// that do not parse mention nothing.
+pub(crate) fn mentions_type(ty: &str, needle: &str) -> bool {
+    let (Ok(parsed), Some(wanted)) = (parse(ty), canonical_token_stream(needle)) else {
+        return false;
+    };
+    let mut finder = TypeFinder {
+        wanted,
+        found: false,
+    };
+    finder.visit_type(&parsed);
+    finder.found
+}
+
+/// Searches a parsed type for a canonical type token stream.
+struct TypeFinder {
+    wanted: String,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for TypeFinder {
+    fn visit_type(&mut self, node: &'ast Type) {
+        if self.found || node.to_token_stream().to_string() == self.wanted {
+            self.found = true;
+            return;
+        }
+        visit::visit_type(self, node);
+    }
+}
+
 /// Parses a Rust type and returns its first free named lifetime.
 pub(crate) fn parse_with_free_named_lifetime(ty: &str) -> Result<Option<String>, syn::Error> {
     let parsed = parse(ty)?;
@@ -262,9 +305,9 @@ fn is_bound_lifetime(name: &str, scope: LifetimeScope<'_>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    //! Unit tests for Rust type lifetime detection.
+    //! Unit tests for Rust type lifetime detection and `Result` recognition.
 
-    use super::free_named_lifetime;
+    use super::{free_named_lifetime, is_result, mentions_type};
     use rstest::rstest;
 
     #[rstest]
@@ -308,4 +351,26 @@ mod tests {
     fn macro_types_have_no_scanned_lifetimes() {
         assert_eq!(free_named_lifetime("type_macro!()"), None);
     }
+
+    #[rstest]
+    #[case("Result<crate::Account, crate::Error>", true)]
+    #[case("std::result::Result<(), crate::Error>", true)]
+    #[case("std::io::Result<u8>", true)]
+    #[case("Option<crate::Account>", false)]
+    #[case("()", false)]
+    #[case("&Result<u8, u8>", false)]
+    fn result_types_are_recognised(#[case] ty: &str, #[case] expected: bool) {
+        assert_eq!(is_result(ty), expected);
+    }
+
+    #[rstest]
+    #[case("crate::Account", "crate::Account", true)]
+    #[case("Vec<crate :: Account>", "crate::Account", true)]
+    #[case("Option<(u8, Vec<u8>)>", "Vec<u8>", true)]
+    #[case("crate::Accounts", "crate::Account", false)]
+    #[case("Vec<u8>", "u16", false)]
+    #[case("Vec<", "u8", false)]
+    fn nested_types_are_found(#[case] ty: &str, #[case] needle: &str, #[case] expected: bool) {
+        assert_eq!(mentions_type(ty, needle), expected);
+    }
 }
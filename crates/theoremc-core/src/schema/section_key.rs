@@ -0,0 +1,122 @@
+//! Canonical spellings of top-level theorem section keys.
+//!
+//! Every section is written canonically in `TitleCase` and also accepted
+//! under a lowercase alias (`TFS-1` section 1.3). From `Schema` version 2
+//! only the canonical spelling is accepted; earlier documents follow the
+//! loader's [`AliasPolicy`].
+
+use serde_saphyr::Spanned;
+
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::load_options::AliasPolicy;
+use super::raw::RawTheoremDoc;
+use super::source_id::SourceId;
+
+/// Canonical section keys paired with their lowercase aliases.
+pub(crate) const SECTION_KEYS: [(&str, &str); 21] = [
+    ("Schema", "schema"),
+    ("Theorem", "theorem"),
+    ("About", "about"),
+    ("Tags", "tags"),
+    ("Traces", "traces"),
+    ("Given", "given"),
+    ("DependsOn", "depends_on"),
+    ("Extends", "extends"),
+    ("Include", "include"),
+    ("Use", "use"),
+    ("UseFixtures", "use_fixtures"),
+    ("Types", "types"),
+    ("Forall", "forall"),
+    ("Actions", "actions"),
+    ("Assume", "assume"),
+    ("Witness", "witness"),
+    ("Let", "let"),
+    ("Do", "do"),
+    ("Prove", "prove"),
+    ("Evidence", "evidence"),
+    ("Lints", "lints"),
+];
+
+/// Returns the canonical spelling when `key` is a lowercase section alias.
+pub(crate) fn canonical_for_alias(key: &str) -> Option<&'static str> {
+    SECTION_KEYS
+        .iter()
+        .find_map(|&(canonical, alias)| (alias == key).then_some(canonical))
+}
+
+/// Returns the first top-level key `raw_doc` spells with a lowercase alias,
+/// with its canonical spelling.
+pub(crate) fn first_alias_key(raw_doc: &RawTheoremDoc) -> Option<(&Spanned<String>, &'static str)> {
+    raw_doc
+        .section_keys
+        .iter()
+        .find_map(|key| canonical_for_alias(&key.value).map(|canonical| (key, canonical)))
+}
+
+/// Rejects lowercase section aliases in documents declaring `Schema`
+/// version 2 or later, and in every document when `policy` is
+/// [`AliasPolicy::Deny`].
+///
+/// # Errors
+///
+/// Returns [`SchemaError::ValidationFailed`] pointing at the first alias.
+pub(crate) fn check_canonical_keys(
+    source: &SourceId,
+    raw_doc: &RawTheoremDoc,
+    policy: AliasPolicy,
+) -> Result<(), SchemaError> {
+    let version = raw_doc.schema.as_ref().map(|schema| schema.value);
+    let required_by = match version {
+        Some(declared) if declared >= 2 => format!("Schema version {declared}"),
+        _ if policy == AliasPolicy::Deny => "this loader".to_owned(),
+        _ => return Ok(()),
+    };
+    first_alias_key(raw_doc).map_or(Ok(()), |(key, canonical)| {
+        let reason = format!(
+            "{required_by} requires canonical section keys; write '{canonical}' instead of \
+             '{}'",
+            key.value
+        );
+        Err(SchemaError::ValidationFailed {
+            theorem: raw_doc.theorem.value.to_string(),
+            diagnostic: Some(Box::new(create_diagnostic(
+                SchemaDiagnosticCode::ValidationFailure,
+                source,
+                reason.clone(),
+                key.referenced,
+            ))),
+            reason,
+            source: None,
+        })
+    })
+}
+
+/// Returns a deprecation warning for each lowercase section alias in
+/// `raw_doc` when `policy` is [`AliasPolicy::Warn`].
+pub(crate) fn alias_warnings(
+    source: &SourceId,
+    raw_doc: &RawTheoremDoc,
+    policy: AliasPolicy,
+) -> Vec<SchemaDiagnostic> {
+    if policy != AliasPolicy::Warn {
+        return Vec::new();
+    }
+    raw_doc
+        .section_keys
+        .iter()
+        .filter_map(|key| {
+            canonical_for_alias(&key.value).map(|canonical| {
+                create_diagnostic(
+                    SchemaDiagnosticCode::DeprecatedAlias,
+                    source,
+                    format!(
+                        "section key '{}' is deprecated; write '{canonical}' instead",
+                        key.value
+                    ),
+                    key.referenced,
+                )
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,61 @@
+//! Input formats theorem documents can be loaded from besides YAML.
+
+use std::fmt;
+
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, SourceLocation};
+use super::error::SchemaError;
+use super::source_id::SourceId;
+
+/// A non-YAML format a theorem source is read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SourceFormat {
+    /// JSON, read by [`load_theorem_docs_json`](super::load_theorem_docs_json).
+    Json,
+    /// TOML, read by [`load_theorem_docs_toml`](super::load_theorem_docs_toml).
+    Toml,
+    /// RON, read by [`load_theorem_docs_ron`](super::load_theorem_docs_ron).
+    Ron,
+}
+
+impl SourceFormat {
+    /// Returns the format's name as used in messages.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+            Self::Ron => "RON",
+        }
+    }
+}
+
+impl fmt::Display for SourceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Builds the error for a `format` source that is not well-formed, located
+/// at the 1-indexed `line` and `column` of `source`.
+pub(crate) fn syntax_error(
+    format: SourceFormat,
+    source: &SourceId,
+    message: String,
+    (line, column): (usize, usize),
+) -> SchemaError {
+    let diagnostic = SchemaDiagnostic {
+        code: SchemaDiagnosticCode::ParseFailure,
+        location: SourceLocation {
+            source: source.as_str().to_owned(),
+            line,
+            column,
+        },
+        message: message.clone(),
+    };
+    SchemaError::Syntax {
+        format,
+        message,
+        diagnostic: Some(diagnostic),
+    }
+}
@@ -0,0 +1,329 @@
+//! Source locations for every value in a loaded theorem document.
+//!
+//! [`load_spanned_theorem_docs`] loads documents exactly like
+//! [`load_theorem_docs_with_source`] and re-reads the source as a tree of
+//! located YAML nodes, so formatters, language servers, and migration scripts
+//! can map any field of a [`TheoremDoc`] back to where it was written.
+
+use std::fmt;
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_saphyr::{Location, Spanned};
+
+use super::diagnostic::{SourceLocation, location_for_source};
+use super::error::SchemaError;
+use super::include::{IncludeResolver, NoIncludes};
+use super::loader::load_theorem_docs_with_includes;
+use super::loader_parse_failure::deserialize_failure;
+use super::section_key::canonical_for_alias;
+use super::source_id::SourceId;
+use super::types::TheoremDoc;
+use super::yaml_anchor::parse_options;
+
+/// A loaded theorem document paired with the source location of each value
+/// written in it.
+///
+/// Locations are looked up by path: mapping keys and sequence indices from
+/// the document root. Top-level sections use their canonical spelling, so
+/// `["DependsOn", "0"]` matches a source that wrote `depends_on`; nested keys
+/// are matched as written. Values the document did not write itself, such as
+/// those inherited from `Defaults`, `Suite`, `Shared`, or `Fixtures`
+/// documents, have no location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedTheoremDoc {
+    doc: TheoremDoc,
+    root: SpanNode,
+}
+
+/// The location of one YAML node and its located children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SpanNode {
+    location: SourceLocation,
+    children: Vec<(String, Self)>,
+}
+
+impl SpannedTheoremDoc {
+    /// Returns the loaded document.
+    #[must_use]
+    pub const fn doc(&self) -> &TheoremDoc {
+        &self.doc
+    }
+
+    /// Consumes the pair, returning the loaded document.
+    #[must_use]
+    pub fn into_doc(self) -> TheoremDoc {
+        self.doc
+    }
+
+    /// Returns where the value at `path` was written, or `None` when the
+    /// source has no value there. An empty path locates the document itself.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::{SourceId, load_spanned_theorem_docs};
+    ///
+    ///     let yaml = r#"
+    ///     Theorem: T
+    ///     About: Example
+    ///     Prove:
+    ///       - assert: "true"
+    ///         because: trivially true
+    ///     Evidence:
+    ///       kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+    ///     "#;
+    ///     let docs = load_spanned_theorem_docs(&SourceId::new("t.theorem"), yaml).unwrap();
+    ///     let location = docs[0].location(&["Prove", "0", "assert"]).unwrap();
+    ///     assert_eq!((location.line, location.column), (5, 13));
+    #[must_use]
+    pub fn location(&self, path: &[&str]) -> Option<&SourceLocation> {
+        path.iter()
+            .try_fold(&self.root, |node, segment| node.child(segment))
+            .map(|node| &node.location)
+    }
+}
+
+impl SpanNode {
+    fn child(&self, key: &str) -> Option<&Self> {
+        self.children
+            .iter()
+            .find_map(|(name, child)| (name == key).then_some(child))
+    }
+}
+
+/// Loads theorem documents like [`load_theorem_docs_with_source`], pairing
+/// each with the source location of every value it writes.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_theorem_docs_with_source`].
+pub fn load_spanned_theorem_docs(
+    source: &SourceId,
+    input: &str,
+) -> Result<Vec<SpannedTheoremDoc>, SchemaError> {
+    load_spanned_theorem_docs_with_includes(source, input, &NoIncludes)
+}
+
+/// Loads spanned theorem documents like [`load_spanned_theorem_docs`],
+/// reading `Include` fragments through `resolver`.
+///
+/// Locations only cover values written in `input`; merged fragment entries
+/// have none.
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`load_theorem_docs_with_includes`](super::load_theorem_docs_with_includes).
+pub fn load_spanned_theorem_docs_with_includes(
+    source: &SourceId,
+    input: &str,
+    resolver: &dyn IncludeResolver,
+) -> Result<Vec<SpannedTheoremDoc>, SchemaError> {
+    let docs = load_theorem_docs_with_includes(source, input, resolver)?;
+    let nodes: Vec<YamlNode> = serde_saphyr::from_multiple_with_options(input, parse_options())
+        .map_err(|error| deserialize_failure(source, input, &error))?;
+    let theorem_nodes = nodes.into_iter().filter(YamlNode::is_theorem_document);
+    Ok(docs
+        .into_iter()
+        .zip(theorem_nodes)
+        .map(|(doc, node)| SpannedTheoremDoc {
+            doc,
+            root: node.into_document_root(source),
+        })
+        .collect())
+}
+
+/// Returns where each theorem document of `input` writes its `Theorem`
+/// name, in document order, without validating the documents. Documents
+/// that write no name have no location, and a source that does not parse as
+/// YAML has none at all.
+pub(crate) fn theorem_name_locations(
+    source: &SourceId,
+    input: &str,
+) -> Vec<Option<SourceLocation>> {
+    serde_saphyr::from_multiple_with_options::<YamlNode>(input, parse_options())
+        .map(|nodes| {
+            nodes
+                .into_iter()
+                .filter(YamlNode::is_theorem_document)
+                .map(|node| {
+                    node.into_document_root(source)
+                        .child("Theorem")
+                        .map(|name| name.location.clone())
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A YAML node as parsed, carrying its parser location.
+struct YamlNode {
+    location: Location,
+    children: Vec<(String, Self)>,
+}
+
+impl YamlNode {
+    /// Returns whether the node is a theorem document rather than a
+    /// `Defaults`, `Suite`, `Shared`, or `Fixtures` document.
+    fn is_theorem_document(&self) -> bool {
+        !self.children.first().is_some_and(|(key, _)| {
+            matches!(
+                key.as_str(),
+                "Defaults"
+                    | "defaults"
+                    | "Suite"
+                    | "suite"
+                    | "Shared"
+                    | "shared"
+                    | "Fixtures"
+                    | "fixtures"
+            )
+        })
+    }
+
+    fn into_document_root(self, source: &SourceId) -> SpanNode {
+        let mut root = self.into_span_node(source);
+        for entry in &mut root.children {
+            if let Some(canonical) = canonical_for_alias(&entry.0) {
+                canonical.clone_into(&mut entry.0);
+            }
+        }
+        root
+    }
+
+    fn into_span_node(self, source: &SourceId) -> SpanNode {
+        SpanNode {
+            location: location_for_source(source, self.location),
+            children: self
+                .children
+                .into_iter()
+                .map(|(key, child)| (key, child.into_span_node(source)))
+                .collect(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for YamlNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let spanned = Spanned::<YamlChildren>::deserialize(deserializer)?;
+        Ok(Self {
+            location: spanned.referenced,
+            children: spanned.value.0,
+        })
+    }
+}
+
+/// The keyed children of a YAML node; scalars have none.
+struct YamlChildren(Vec<(String, YamlNode)>);
+
+impl<'de> Deserialize<'de> for YamlChildren {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(YamlChildrenVisitor)
+    }
+}
+
+struct YamlChildrenVisitor;
+
+impl<'de> Visitor<'de> for YamlChildrenVisitor {
+    type Value = YamlChildren;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any YAML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _value: bool) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_i64<E: de::Error>(self, _value: i64) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_u64<E: de::Error>(self, _value: u64) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_f64<E: de::Error>(self, _value: f64) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_str<E: de::Error>(self, _value: &str) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(YamlChildren(Vec::new()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut children = Vec::new();
+        while let Some(item) = seq.next_element::<YamlNode>()? {
+            children.push((children.len().to_string(), item));
+        }
+        Ok(YamlChildren(children))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut children = Vec::new();
+        while let Some((YamlKey(key), value)) = map.next_entry::<YamlKey, YamlNode>()? {
+            children.push((key, value));
+        }
+        Ok(YamlChildren(children))
+    }
+}
+
+/// A mapping key, read from any scalar as its text.
+struct YamlKey(String);
+
+impl<'de> Deserialize<'de> for YamlKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(YamlKeyVisitor)
+    }
+}
+
+struct YamlKeyVisitor;
+
+impl Visitor<'_> for YamlKeyVisitor {
+    type Value = YamlKey;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a scalar mapping key")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(YamlKey(value.to_string()))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(YamlKey(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(YamlKey(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(YamlKey(value.to_string()))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(YamlKey(value.to_owned()))
+    }
+}
+
+#[cfg(test)]
+#[path = "spanned_tests.rs"]
+mod tests;
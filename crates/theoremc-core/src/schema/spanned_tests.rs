@@ -0,0 +1,114 @@
+//! Unit tests for loading theorem documents with field locations.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{SpannedTheoremDoc, load_spanned_theorem_docs};
+use crate::schema::{SchemaError, SourceId};
+
+const LEDGER: &str = r#"
+Theorem: Deposit
+About: Deposits grow the balance
+Tags: [ledger]
+Forall:
+  amount: u64
+Actions:
+  ledger.deposit:
+    params: { amount: u64 }
+    returns: "()"
+Do:
+  - call:
+      action: ledger.deposit
+      args: { amount: { ref: amount } }
+Prove:
+  - assert: "amount > 0"
+    because: deposits are positive
+Evidence:
+  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }
+"#;
+
+fn load(input: &str) -> Vec<SpannedTheoremDoc> {
+    load_spanned_theorem_docs(&SourceId::new("ledger.theorem"), input).expect("source should load")
+}
+
+fn line_and_column(doc: &SpannedTheoremDoc, path: &[&str]) -> Option<(usize, usize)> {
+    doc.location(path)
+        .map(|location| (location.line, location.column))
+}
+
+#[rstest]
+#[case::document(&[], (2, 1))]
+#[case::theorem(&["Theorem"], (2, 10))]
+#[case::tag(&["Tags", "0"], (4, 8))]
+#[case::forall_type(&["Forall", "amount"], (6, 11))]
+#[case::action_returns(&["Actions", "ledger.deposit", "returns"], (10, 14))]
+#[case::call_action(&["Do", "0", "call", "action"], (13, 15))]
+#[case::call_argument(&["Do", "0", "call", "args", "amount", "ref"], (14, 30))]
+#[case::prove_expr(&["Prove", "0", "assert"], (16, 13))]
+#[case::prove_because(&["Prove", "0", "because"], (17, 14))]
+#[case::kani_unwind(&["Evidence", "kani", "unwind"], (19, 19))]
+fn every_written_value_is_located(#[case] path: &[&str], #[case] expected: (usize, usize)) {
+    let docs = load(LEDGER);
+    let doc = docs.first().expect("source should hold a theorem");
+
+    assert_eq!(line_and_column(doc, path), Some(expected));
+}
+
+#[test]
+fn locations_name_the_source() {
+    let docs = load(LEDGER);
+    let doc = docs.first().expect("source should hold a theorem");
+
+    assert_eq!(
+        doc.location(&["About"])
+            .map(|location| location.source.as_str()),
+        Some("ledger.theorem")
+    );
+    assert_eq!(doc.doc().theorem.as_str(), "Deposit");
+}
+
+#[test]
+fn missing_paths_have_no_location() {
+    let docs = load(LEDGER);
+    let doc = docs.first().expect("source should hold a theorem");
+
+    assert_eq!(doc.location(&["Witness"]), None);
+    assert_eq!(doc.location(&["Prove", "1"]), None);
+}
+
+#[test]
+fn lowercase_sections_use_canonical_paths() {
+    let docs = load(&LEDGER.replace("Prove:", "prove:"));
+    let doc = docs.first().expect("source should hold a theorem");
+
+    assert_eq!(
+        line_and_column(doc, &["Prove", "0", "assert"]),
+        Some((16, 13))
+    );
+    assert_eq!(doc.location(&["prove"]), None);
+}
+
+#[test]
+fn defaults_documents_are_skipped() {
+    let input = format!(
+        "Defaults:\n  Tags: [shared]\n---{}---{}",
+        LEDGER,
+        LEDGER.replace("Deposit\n", "Withdraw\n")
+    );
+    let docs = load(&input);
+
+    let names: Vec<_> = docs.iter().map(|doc| doc.doc().theorem.as_str()).collect();
+    assert_eq!(names, ["Deposit", "Withdraw"]);
+    let withdraw = docs.last().expect("source should hold two theorems");
+    assert_eq!(line_and_column(withdraw, &["Theorem"]), Some((23, 10)));
+}
+
+#[test]
+fn load_errors_are_reported() {
+    let result = load_spanned_theorem_docs(
+        &SourceId::new("ledger.theorem"),
+        &LEDGER.replace("About: Deposits grow the balance\n", ""),
+    );
+
+    assert!(matches!(result, Err(SchemaError::Deserialize { .. })));
+}
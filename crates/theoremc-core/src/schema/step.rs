@@ -3,14 +3,18 @@
 //!
 //! These checks enforce constraints that `serde` attributes cannot express,
 //! such as "action name must be non-empty", "action names must follow
-//! canonical dot-path grammar", and "maybe.do must contain at least one
-//! step". The functions return `Result<(), String>` so the caller in
+//! canonical dot-path grammar", and "a maybe block must contain at least
+//! one step across its do and else branches". Inline `assert` steps are
+//! checked with the same expression parser as `Prove` assertions. The
+//! functions return `Result<(), String>` so the caller in
 //! [`super::validate`] can attach theorem-level context when constructing
 //! [`super::error::SchemaError`].
 
 use super::action_name::validate_canonical_action_name;
 use super::error::SchemaError;
-use super::types::{ActionCall, Step};
+use super::expr::validate_rust_expr;
+use super::identifier::validate_identifier;
+use super::types::{ActionCall, ForeachBlock, InlineAssert, RepeatBlock, Step, StepExpectErr};
 
 /// Validates that an action call's `action` field is non-empty after
 /// trimming and satisfies canonical dot-path grammar rules.
@@ -24,7 +28,7 @@ use super::types::{ActionCall, Step};
 ///     use indexmap::IndexMap;
 ///
 ///     let good = ActionCall {
-///         action: "account.deposit".to_owned(),
+///         action: "account.deposit".into(),
 ///         args: IndexMap::new(),
 ///         as_binding: None,
 ///     };
@@ -58,10 +62,15 @@ pub(crate) fn validate_step_list(steps: &[Step], path: &str) -> Result<(), Strin
 
 /// Validates a single step's structural constraints.
 ///
-/// For `call` and `must` steps, validates the inner `ActionCall`. For
+/// For `call` and `must` steps, validates the inner `ActionCall`, the
+/// optional `as` binding, and the optional step `id`. For
 /// `maybe` steps, validates that `because` is non-empty after trimming,
 /// `do` contains at least one step, and recursively validates each
-/// nested step.
+/// nested step. For `repeat` steps, validates that `times` is positive,
+/// `do` contains at least one step, and each nested step. For `foreach`
+/// steps, validates the sequence and loop variable names, then the nested
+/// steps. For `expect_err` steps, also validates the expected error name.
+/// For `assert` steps, validates the inline assertion.
 ///
 /// The `path` parameter provides context for error messages (e.g.,
 /// `"Do step"`). The `pos` parameter is the 1-based position within
@@ -70,17 +79,69 @@ fn validate_step(step: &Step, path: &str, pos: usize) -> Result<(), String> {
     match step {
         Step::Call(c) => {
             validate_action_call(&c.call).map_err(|reason| format!("{path} {pos}: {reason}"))?;
+            validate_step_name(c.call.as_binding.as_deref(), "as", path, pos)?;
+            validate_step_name(c.id.as_deref(), "id", path, pos)?;
         }
         Step::Must(m) => {
             validate_action_call(&m.must).map_err(|reason| format!("{path} {pos}: {reason}"))?;
+            validate_step_name(m.must.as_binding.as_deref(), "as", path, pos)?;
+            validate_step_name(m.id.as_deref(), "id", path, pos)?;
         }
+        Step::ExpectErr(e) => validate_expect_err(e, path, pos)?,
         Step::Maybe(m) => validate_maybe_block(&m.maybe, path, pos)?,
+        Step::Repeat(r) => validate_repeat_block(&r.repeat, path, pos)?,
+        Step::Foreach(f) => validate_foreach_block(&f.foreach, path, pos)?,
+        Step::Assert(a) => validate_inline_assert(&a.assert, path, pos)?,
     }
     Ok(())
 }
 
+/// Validates a step's optional `as` binding or `id`, either of which must be
+/// a Rust identifier so generated code can name the step's result.
+fn validate_step_name(
+    name: Option<&str>,
+    field: &str,
+    path: &str,
+    pos: usize,
+) -> Result<(), String> {
+    name.map_or(Ok(()), |value| {
+        validate_identifier(value).map_err(|reason| format!("{path} {pos}: {field} {reason}"))
+    })
+}
+
+/// Validates an `expect_err` step: the inner `ActionCall` must be valid and
+/// `error` must name an error variant with a Rust identifier.
+fn validate_expect_err(step: &StepExpectErr, path: &str, pos: usize) -> Result<(), String> {
+    validate_action_call(&step.expect_err).map_err(|reason| format!("{path} {pos}: {reason}"))?;
+    let error = step.error.trim();
+    if error.is_empty() {
+        return Err(format!(
+            "{path} {pos}: expect_err.error must be non-empty after trimming"
+        ));
+    }
+    validate_identifier(error).map_err(|reason| format!("{path} {pos}: expect_err.error {reason}"))
+}
+
+/// Validates an inline `assert` step: `because` must be non-empty after
+/// trimming and `expr` must be a single Rust expression.
+fn validate_inline_assert(assert: &InlineAssert, path: &str, pos: usize) -> Result<(), String> {
+    if assert.expr.trim().is_empty() {
+        return Err(format!(
+            "{path} {pos}: assert.expr must be non-empty after trimming"
+        ));
+    }
+    if assert.because.trim().is_empty() {
+        return Err(format!(
+            "{path} {pos}: assert.because must be non-empty after trimming"
+        ));
+    }
+    validate_rust_expr(assert.expr.trim())
+        .map_err(|reason| format!("{path} {pos}: assert.expr {reason}"))
+}
+
 /// Validates a `MaybeBlock`'s structural constraints: non-empty
-/// `because`, non-empty `do`, and recursive step validation.
+/// `because`, at least one step across `do` and `else`, and recursive step
+/// validation of both branches.
 fn validate_maybe_block(
     maybe: &super::types::MaybeBlock,
     path: &str,
@@ -96,183 +157,71 @@ fn validate_maybe_block(
             pos = pos
         ));
     }
-    if maybe.do_steps.is_empty() {
+    if maybe.do_steps.is_empty() && maybe.else_steps.is_empty() {
         return Err(format!(
-            concat!("{path} {pos}: maybe.do must contain ", "at least one step"),
+            concat!(
+                "{path} {pos}: maybe.do must contain ",
+                "at least one step unless maybe.else does"
+            ),
             path = path,
             pos = pos
         ));
     }
-    let nested_path = format!("{path} {pos}: maybe.do step");
-    validate_step_list(&maybe.do_steps, &nested_path)
+    validate_step_list(&maybe.do_steps, &format!("{path} {pos}: maybe.do step"))?;
+    validate_step_list(&maybe.else_steps, &format!("{path} {pos}: maybe.else step"))
 }
 
-#[cfg(test)]
-mod tests {
-    //! Unit tests for step and action call structural validation.
-    use super::*;
-    use crate::schema::types::{ActionCall, MaybeBlock, Step, StepCall, StepMaybe, StepMust};
-    use indexmap::IndexMap;
-    use rstest::{fixture, rstest};
-
-    /// Fixture: a valid `ActionCall` with a non-empty dotted action name.
-    #[fixture]
-    fn valid_action() -> ActionCall {
-        ActionCall {
-            action: "a.b".to_owned(),
-            args: IndexMap::new(),
-            as_binding: None,
-        }
-    }
-
-    /// Fixture: a valid `Step::Call` wrapping the default valid action.
-    #[fixture]
-    fn valid_call(valid_action: ActionCall) -> Step {
-        Step::Call(StepCall { call: valid_action })
-    }
-
-    /// Fixture: a valid `Step::Must` wrapping the default valid action.
-    #[fixture]
-    fn valid_must(valid_action: ActionCall) -> Step {
-        Step::Must(StepMust { must: valid_action })
-    }
-
-    /// Builder: an `ActionCall` with a custom action name.
-    fn action(name: &str) -> ActionCall {
-        ActionCall {
-            action: name.to_owned(),
-            args: IndexMap::new(),
-            as_binding: None,
-        }
-    }
-
-    /// Builder: a `Step::Call` with a custom action name.
-    fn call_step(name: &str) -> Step {
-        Step::Call(StepCall { call: action(name) })
-    }
-
-    /// Builder: a `Step::Must` with a custom action name.
-    fn must_step(name: &str) -> Step {
-        Step::Must(StepMust { must: action(name) })
-    }
-
-    /// Builder: a `Step::Maybe` with custom because and steps.
-    fn maybe_step(because: &str, steps: Vec<Step>) -> Step {
-        Step::Maybe(StepMaybe {
-            maybe: MaybeBlock {
-                because: because.to_owned(),
-                do_steps: steps,
-            },
-        })
-    }
-
-    // ── ActionCall validation ─────────────────────────────────────
-
-    #[rstest]
-    #[case::non_empty("account.deposit")]
-    #[case::dotted("hnsw.attach_node")]
-    #[case::with_underscore("hnsw.graph_with_capacity")]
-    fn action_call_with_valid_action_passes(#[case] name: &str) {
-        let ac = action(name);
-        assert!(validate_action_call(&ac).is_ok());
-    }
-
-    #[rstest]
-    #[case::empty("")]
-    #[case::whitespace_only("   ")]
-    #[case::tab_only("\t")]
-    fn action_call_with_blank_action_fails(#[case] name: &str) {
-        let ac = action(name);
-        let err = validate_action_call(&ac).expect_err("should fail");
-        assert!(
-            err.contains("action must be non-empty"),
-            "expected 'action must be non-empty', got: {err}"
-        );
-    }
-
-    #[rstest]
-    #[case::missing_dot("deposit", "dot-separated canonical name")]
-    #[case::double_dot("account..deposit", "segment 2 must be non-empty")]
-    #[case::keyword_segment("account.fn", "Rust reserved keyword")]
-    fn action_call_with_non_canonical_action_fails(#[case] name: &str, #[case] expected: &str) {
-        let ac = action(name);
-        let err = validate_action_call(&ac).expect_err("should fail");
-        assert!(err.contains(expected), "expected '{expected}', got: {err}");
-    }
-
-    // ── Step list validation ──────────────────────────────────────
-
-    #[rstest]
-    fn valid_steps_pass(valid_call: Step, valid_must: Step) {
-        for step in [valid_call, valid_must] {
-            assert!(validate_step_list(&[step], "Do step").is_ok());
-        }
-    }
-
-    #[rstest]
-    fn valid_maybe_step_passes(valid_call: Step) {
-        let steps = vec![maybe_step("optional branch", vec![valid_call])];
-        assert!(validate_step_list(&steps, "Do step").is_ok());
+/// Validates a `RepeatBlock`'s structural constraints: positive `times`,
+/// non-empty `do`, and recursive step validation.
+fn validate_repeat_block(repeat: &RepeatBlock, path: &str, pos: usize) -> Result<(), String> {
+    if repeat.times == 0 {
+        return Err(format!(
+            "{path} {pos}: repeat.times must be a positive integer"
+        ));
     }
-
-    #[rstest]
-    #[case::call_empty(call_step(""))]
-    #[case::call_whitespace(call_step("  "))]
-    #[case::must_empty(must_step(""))]
-    #[case::must_whitespace(must_step("  "))]
-    fn step_with_blank_action_fails(#[case] step: Step) {
-        let steps = vec![step];
-        let err = validate_step_list(&steps, "Do step").expect_err("should fail");
-        assert!(
-            err.contains("Do step 1: action must be non-empty"),
-            "got: {err}"
-        );
+    if repeat.do_steps.is_empty() {
+        return Err(format!(
+            "{path} {pos}: repeat.do must contain at least one step"
+        ));
     }
+    let nested_path = format!("{path} {pos}: repeat.do step");
+    validate_step_list(&repeat.do_steps, &nested_path)
+}
 
-    #[rstest]
-    #[case("")]
-    #[case("   ")]
-    fn maybe_step_with_invalid_because_fails(#[case] because: &str) {
-        let steps = vec![maybe_step(because, vec![call_step("a.b")])];
-        let err = validate_step_list(&steps, "Do step").expect_err("should fail");
-        assert!(
-            err.contains("maybe.because must be non-empty"),
-            "got: {err}"
-        );
+/// Validates a `ForeachBlock`'s structural constraints: `in` and `as` are
+/// distinct Rust identifiers, `do` is non-empty, and each nested step is
+/// valid.
+fn validate_foreach_block(foreach: &ForeachBlock, path: &str, pos: usize) -> Result<(), String> {
+    let source = foreach.source.trim();
+    if source.is_empty() {
+        return Err(format!(
+            "{path} {pos}: foreach.in must be non-empty after trimming"
+        ));
     }
-
-    #[test]
-    fn maybe_step_with_empty_do_fails() {
-        let steps = vec![maybe_step("reason", vec![])];
-        let err = validate_step_list(&steps, "Do step").expect_err("should fail");
-        assert!(
-            err.contains("maybe.do must contain at least one step"),
-            "got: {err}"
-        );
+    validate_identifier(source).map_err(|reason| format!("{path} {pos}: foreach.in {reason}"))?;
+    validate_identifier(&foreach.as_binding)
+        .map_err(|reason| format!("{path} {pos}: foreach.as {reason}"))?;
+    if foreach.as_binding == source {
+        return Err(format!(
+            "{path} {pos}: foreach.as '{source}' must differ from the sequence it iterates"
+        ));
     }
-
-    #[rstest]
-    #[case::blank_because("", vec![call_step("a.b")], "maybe.do step 1: maybe.because must be non-empty")]
-    #[case::empty_do("inner reason", vec![], "maybe.do step 1: maybe.do must contain at least one step")]
-    fn nested_maybe_validation_errors(
-        #[case] inner_because: &str,
-        #[case] inner_do: Vec<Step>,
-        #[case] expected_error: &str,
-    ) {
-        let inner = maybe_step(inner_because, inner_do);
-        let outer = maybe_step("outer reason", vec![inner]);
-        let steps = vec![outer];
-        let err = validate_step_list(&steps, "Do step").expect_err("should fail");
-        assert!(err.contains(expected_error), "got: {err}");
+    if foreach.do_steps.is_empty() {
+        return Err(format!(
+            "{path} {pos}: foreach.do must contain at least one step"
+        ));
     }
+    let nested_path = format!("{path} {pos}: foreach.do step");
+    validate_step_list(&foreach.do_steps, &nested_path)
+}
 
-    #[rstest]
-    fn second_step_error_reports_correct_position(valid_call: Step) {
-        let steps = vec![valid_call, call_step("")];
-        let err = validate_step_list(&steps, "Do step").expect_err("should fail");
-        assert!(
-            err.contains("Do step 2: action must be non-empty"),
-            "got: {err}"
-        );
-    }
+/// Returns the error-message path for the steps in `branch` (`do` or
+/// `else`) of `step`, a `maybe`, `repeat`, or `foreach` block at `pos` in
+/// `path`.
+pub(crate) fn nested_step_path(path: &str, pos: usize, step: &Step, branch: &str) -> String {
+    format!("{path} {pos}: {}.{branch} step", step.keyword())
 }
+
+#[cfg(test)]
+#[path = "step_tests.rs"]
+mod tests;
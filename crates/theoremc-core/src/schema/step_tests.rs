@@ -0,0 +1,394 @@
+//! Unit tests for step and action call structural validation.
+
+use super::*;
+use crate::schema::types::{
+    ActionCall, ForeachBlock, InlineAssert, MaybeBlock, RepeatBlock, Step, StepAssert, StepCall,
+    StepExpectErr, StepForeach, StepMaybe, StepMust, StepRepeat,
+};
+use indexmap::IndexMap;
+use rstest::{fixture, rstest};
+
+/// Fixture: a valid `ActionCall` with a non-empty dotted action name.
+#[fixture]
+fn valid_action() -> ActionCall {
+    ActionCall {
+        action: "a.b".into(),
+        args: IndexMap::new(),
+        as_binding: None,
+    }
+}
+
+/// Fixture: a valid `Step::Call` wrapping the default valid action.
+#[fixture]
+fn valid_call(valid_action: ActionCall) -> Step {
+    Step::Call(StepCall {
+        call: valid_action,
+        id: None,
+    })
+}
+
+/// Fixture: a valid `Step::Must` wrapping the default valid action.
+#[fixture]
+fn valid_must(valid_action: ActionCall) -> Step {
+    Step::Must(StepMust {
+        must: valid_action,
+        id: None,
+    })
+}
+
+/// Builder: an `ActionCall` with a custom action name.
+fn action(name: &str) -> ActionCall {
+    ActionCall {
+        action: name.into(),
+        args: IndexMap::new(),
+        as_binding: None,
+    }
+}
+
+/// Builder: a `Step::Call` with a custom action name.
+fn call_step(name: &str) -> Step {
+    Step::Call(StepCall {
+        call: action(name),
+        id: None,
+    })
+}
+
+/// Builder: a `Step::Must` with a custom action name.
+fn must_step(name: &str) -> Step {
+    Step::Must(StepMust {
+        must: action(name),
+        id: None,
+    })
+}
+
+/// Builder: a `Step::Maybe` with custom because and steps.
+fn maybe_step(because: &str, steps: Vec<Step>) -> Step {
+    Step::Maybe(StepMaybe {
+        maybe: MaybeBlock {
+            because: because.into(),
+            do_steps: steps,
+            else_steps: Vec::new(),
+        },
+    })
+}
+
+/// Builder: a `Step::Maybe` with both a `do` and an `else` branch.
+fn maybe_else_step(do_steps: Vec<Step>, else_steps: Vec<Step>) -> Step {
+    Step::Maybe(StepMaybe {
+        maybe: MaybeBlock {
+            because: "either branch may run".into(),
+            do_steps,
+            else_steps,
+        },
+    })
+}
+
+/// Builder: a `Step::Assert` with custom expression and reason.
+fn assert_step(expr: &str, because: &str) -> Step {
+    Step::Assert(StepAssert {
+        assert: InlineAssert {
+            expr: expr.to_owned(),
+            because: because.into(),
+        },
+    })
+}
+
+/// Builder: a `Step::Repeat` with custom count and steps.
+fn repeat_step(times: u32, steps: Vec<Step>) -> Step {
+    Step::Repeat(StepRepeat {
+        repeat: RepeatBlock {
+            times,
+            do_steps: steps,
+        },
+    })
+}
+
+/// Builder: a `Step::Foreach` with custom sequence, loop variable, and steps.
+fn foreach_step(source: &str, as_binding: &str, steps: Vec<Step>) -> Step {
+    Step::Foreach(StepForeach {
+        foreach: ForeachBlock {
+            source: source.to_owned(),
+            as_binding: as_binding.to_owned(),
+            do_steps: steps,
+        },
+    })
+}
+
+/// Builder: a `Step::ExpectErr` with custom action and error names.
+fn expect_err_step(name: &str, error: &str) -> Step {
+    Step::ExpectErr(StepExpectErr {
+        expect_err: action(name),
+        error: error.to_owned(),
+    })
+}
+
+// ── ActionCall validation ─────────────────────────────────────
+
+#[rstest]
+#[case::non_empty("account.deposit")]
+#[case::dotted("hnsw.attach_node")]
+#[case::with_underscore("hnsw.graph_with_capacity")]
+fn action_call_with_valid_action_passes(#[case] name: &str) {
+    let ac = action(name);
+    assert!(validate_action_call(&ac).is_ok());
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::whitespace_only("   ")]
+#[case::tab_only("\t")]
+fn action_call_with_blank_action_fails(#[case] name: &str) {
+    let ac = action(name);
+    let err = validate_action_call(&ac).expect_err("should fail");
+    assert!(
+        err.contains("action must be non-empty"),
+        "expected 'action must be non-empty', got: {err}"
+    );
+}
+
+#[rstest]
+#[case::missing_dot("deposit", "dot-separated canonical name")]
+#[case::double_dot("account..deposit", "segment 2 must be non-empty")]
+#[case::keyword_segment("account.fn", "Rust reserved keyword")]
+fn action_call_with_non_canonical_action_fails(#[case] name: &str, #[case] expected: &str) {
+    let ac = action(name);
+    let err = validate_action_call(&ac).expect_err("should fail");
+    assert!(err.contains(expected), "expected '{expected}', got: {err}");
+}
+
+// ── Step list validation ──────────────────────────────────────
+
+#[rstest]
+fn valid_steps_pass(valid_call: Step, valid_must: Step) {
+    for step in [valid_call, valid_must] {
+        assert!(validate_step_list(&[step], "Do step").is_ok());
+    }
+}
+
+#[rstest]
+fn valid_maybe_step_passes(valid_call: Step) {
+    let steps = vec![maybe_step("optional branch", vec![valid_call])];
+    assert!(validate_step_list(&steps, "Do step").is_ok());
+}
+
+#[rstest]
+#[case::call_empty(call_step(""))]
+#[case::call_whitespace(call_step("  "))]
+#[case::must_empty(must_step(""))]
+#[case::must_whitespace(must_step("  "))]
+fn step_with_blank_action_fails(#[case] step: Step) {
+    let steps = vec![step];
+    let err = validate_step_list(&steps, "Do step").expect_err("should fail");
+    assert!(
+        err.contains("Do step 1: action must be non-empty"),
+        "got: {err}"
+    );
+}
+
+#[rstest]
+#[case("")]
+#[case("   ")]
+fn maybe_step_with_invalid_because_fails(#[case] because: &str) {
+    let steps = vec![maybe_step(because, vec![call_step("a.b")])];
+    let err = validate_step_list(&steps, "Do step").expect_err("should fail");
+    assert!(
+        err.contains("maybe.because must be non-empty"),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn maybe_step_with_empty_do_fails() {
+    let steps = vec![maybe_step("reason", vec![])];
+    let err = validate_step_list(&steps, "Do step").expect_err("should fail");
+    assert!(
+        err.contains("maybe.do must contain at least one step"),
+        "got: {err}"
+    );
+}
+
+#[rstest]
+#[case::blank_because("", vec![call_step("a.b")], "maybe.do step 1: maybe.because must be non-empty")]
+#[case::empty_do("inner reason", vec![], "maybe.do step 1: maybe.do must contain at least one step")]
+fn nested_maybe_validation_errors(
+    #[case] inner_because: &str,
+    #[case] inner_do: Vec<Step>,
+    #[case] expected_error: &str,
+) {
+    let inner = maybe_step(inner_because, inner_do);
+    let outer = maybe_step("outer reason", vec![inner]);
+    let steps = vec![outer];
+    let err = validate_step_list(&steps, "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
+
+#[rstest]
+fn second_step_error_reports_correct_position(valid_call: Step) {
+    let steps = vec![valid_call, call_step("")];
+    let err = validate_step_list(&steps, "Do step").expect_err("should fail");
+    assert!(
+        err.contains("Do step 2: action must be non-empty"),
+        "got: {err}"
+    );
+}
+
+// ── Inline assert validation ──────────────────────────────────
+
+#[rstest]
+fn valid_assert_steps_pass(valid_call: Step) {
+    let nested = maybe_step("retry", vec![assert_step("ok", "retry kept the total")]);
+    let steps = vec![valid_call, assert_step("total <= limit", "bounded"), nested];
+    assert!(validate_step_list(&steps, "Do step").is_ok());
+}
+
+#[rstest]
+#[case::blank_expr(" ", "r", "Do step 1: assert.expr must be non-empty")]
+#[case::blank_because("ok", "", "Do step 1: assert.because must be non-empty")]
+#[case::statement(
+    "{ let x = 1; x > 0 }",
+    "r",
+    "Do step 1: assert.expr must be a single expression"
+)]
+#[case::bad_syntax("x >", "r", "Do step 1: assert.expr is not a valid Rust expression")]
+fn invalid_assert_step_fails(
+    #[case] expr: &str,
+    #[case] because: &str,
+    #[case] expected_error: &str,
+) {
+    let err =
+        validate_step_list(&[assert_step(expr, because)], "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
+
+// ── expect_err validation ─────────────────────────────────────
+
+#[test]
+fn valid_expect_err_step_passes() {
+    let steps = vec![expect_err_step("account.withdraw", " InsufficientFunds ")];
+    assert!(validate_step_list(&steps, "Do step").is_ok());
+}
+
+#[rstest]
+#[case::blank_action("", "InsufficientFunds", "Do step 1: action must be non-empty")]
+#[case::blank_error(
+    "account.withdraw",
+    "  ",
+    "Do step 1: expect_err.error must be non-empty"
+)]
+#[case::keyword_error(
+    "account.withdraw",
+    "fn",
+    "Do step 1: expect_err.error invalid identifier 'fn'"
+)]
+#[case::path_error(
+    "account.withdraw",
+    "Error::Empty",
+    "Do step 1: expect_err.error invalid identifier"
+)]
+fn invalid_expect_err_step_fails(
+    #[case] name: &str,
+    #[case] error: &str,
+    #[case] expected_error: &str,
+) {
+    let err =
+        validate_step_list(&[expect_err_step(name, error)], "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
+
+// ── repeat validation ─────────────────────────────────────────
+
+#[rstest]
+fn valid_repeat_step_passes(valid_call: Step) {
+    let steps = vec![repeat_step(3, vec![valid_call])];
+    assert!(validate_step_list(&steps, "Do step").is_ok());
+}
+
+#[rstest]
+#[case::zero_times(repeat_step(0, vec![call_step("a.b")]), "Do step 1: repeat.times must be a positive integer")]
+#[case::empty_do(repeat_step(2, vec![]), "Do step 1: repeat.do must contain at least one step")]
+#[case::nested_blank_action(
+    repeat_step(2, vec![call_step("a.b"), must_step(" ")]),
+    "Do step 1: repeat.do step 2: action must be non-empty"
+)]
+#[case::inside_maybe(
+    maybe_step("reason", vec![repeat_step(0, vec![call_step("a.b")])]),
+    "Do step 1: maybe.do step 1: repeat.times must be a positive integer"
+)]
+fn invalid_repeat_step_fails(#[case] step: Step, #[case] expected_error: &str) {
+    let err = validate_step_list(&[step], "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
+
+#[rstest]
+#[case::no_loops(vec![call_step("a.b")], 1)]
+#[case::one_loop(vec![repeat_step(3, vec![call_step("a.b")]), call_step("a.b")], 4)]
+#[case::largest_loop(vec![repeat_step(2, vec![call_step("a.b")]), repeat_step(5, vec![call_step("a.b")])], 6)]
+#[case::nested_loop(vec![repeat_step(2, vec![repeat_step(7, vec![call_step("a.b")])])], 8)]
+fn required_unwind_is_the_largest_loop_bound(#[case] steps: Vec<Step>, #[case] expected: u64) {
+    assert_eq!(Step::required_unwind(&steps), expected);
+}
+
+// ── foreach validation ────────────────────────────────────────
+
+#[rstest]
+fn valid_foreach_step_passes(valid_call: Step) {
+    let steps = vec![foreach_step("deposits", "deposit", vec![valid_call])];
+    assert!(validate_step_list(&steps, "Do step").is_ok());
+}
+
+#[rstest]
+#[case::blank_in(
+    foreach_step(" ", "d", vec![call_step("a.b")]),
+    "Do step 1: foreach.in must be non-empty after trimming"
+)]
+#[case::keyword_in(
+    foreach_step("loop", "d", vec![call_step("a.b")]),
+    "Do step 1: foreach.in invalid identifier 'loop'"
+)]
+#[case::keyword_as(
+    foreach_step("deposits", "fn", vec![call_step("a.b")]),
+    "Do step 1: foreach.as invalid identifier 'fn'"
+)]
+#[case::as_equals_in(
+    foreach_step("deposits", "deposits", vec![call_step("a.b")]),
+    "Do step 1: foreach.as 'deposits' must differ from the sequence it iterates"
+)]
+#[case::empty_do(
+    foreach_step("deposits", "d", vec![]),
+    "Do step 1: foreach.do must contain at least one step"
+)]
+#[case::nested_blank_action(
+    foreach_step("deposits", "d", vec![call_step("a.b"), must_step(" ")]),
+    "Do step 1: foreach.do step 2: action must be non-empty"
+)]
+fn invalid_foreach_step_fails(#[case] step: Step, #[case] expected_error: &str) {
+    let err = validate_step_list(&[step], "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
+
+// ── maybe else validation ─────────────────────────────────────
+
+#[rstest]
+#[case::both_branches(maybe_else_step(vec![call_step("a.b")], vec![must_step("c.d")]))]
+#[case::else_only(maybe_else_step(vec![], vec![call_step("a.b")]))]
+fn maybe_step_with_else_branch_passes(#[case] step: Step) {
+    assert!(validate_step_list(&[step], "Do step").is_ok());
+}
+
+#[rstest]
+#[case::both_empty(
+    maybe_else_step(vec![], vec![]),
+    "Do step 1: maybe.do must contain at least one step unless maybe.else does"
+)]
+#[case::else_blank_action(
+    maybe_else_step(vec![call_step("a.b")], vec![call_step("c.d"), must_step(" ")]),
+    "Do step 1: maybe.else step 2: action must be non-empty"
+)]
+#[case::nested_else(
+    maybe_else_step(vec![], vec![repeat_step(0, vec![call_step("a.b")])]),
+    "Do step 1: maybe.else step 1: repeat.times must be a positive integer"
+)]
+fn invalid_maybe_else_branch_fails(#[case] step: Step, #[case] expected_error: &str) {
+    let err = validate_step_list(&[step], "Do step").expect_err("should fail");
+    assert!(err.contains(expected_error), "got: {err}");
+}
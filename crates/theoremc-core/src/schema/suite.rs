@@ -0,0 +1,133 @@
+//! Suite-level views of loaded theorem documents.
+//!
+//! [`load_suites`] loads a `.theorem` source like
+//! [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source)
+//! and then groups the validated theorems under the `Suite` documents that
+//! list them, so callers can select, run, and report theorems per suite.
+
+use indexmap::IndexMap;
+
+use super::error::SchemaError;
+use super::include::{IncludeResolver, NoIncludes};
+use super::load_options::LoadOptions;
+use super::loader::{SourceLoad, load_source_documents};
+use super::raw_suite::RawSuite;
+use super::source_id::SourceId;
+use super::suite_policy::SuiteRequirements;
+use super::symbol::Symbol;
+use super::types::TheoremDoc;
+
+/// A named group of theorems declared by a `Suite` document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TheoremSuite {
+    /// Suite name (a valid identifier, unique within its source).
+    pub name: String,
+    /// Human-readable description of the suite.
+    pub about: String,
+    /// Suite-level metadata tags.
+    pub tags: Vec<Symbol>,
+    /// Member theorems in the order the suite lists them, with suite evidence
+    /// defaults already applied.
+    pub theorems: Vec<TheoremDoc>,
+    /// Aggregate evidence requirements the members were checked against.
+    pub requires: SuiteRequirements,
+}
+
+/// Theorems loaded from one source, grouped by suite.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadedSuites {
+    /// Suites in declaration order.
+    pub suites: Vec<TheoremSuite>,
+    /// Theorems that no suite lists, in document order.
+    pub ungrouped: Vec<TheoremDoc>,
+}
+
+/// Loads theorem documents from YAML and groups them by `Suite` document.
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`load_theorem_docs_with_source`](super::load_theorem_docs_with_source),
+/// including [`SchemaError::InvalidSuite`] for malformed `Suite` documents
+/// and for members that break a suite's `Requires` policies.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::{SourceId, load_suites};
+///
+///     let yaml = r#"
+///     Suite: Arithmetic
+///     About: Basic arithmetic facts
+///     Theorems: [Positive]
+///     Evidence:
+///       kani:
+///         unwind: 1
+///         expect: SUCCESS
+///     ---
+///     Theorem: Positive
+///     About: One is positive
+///     Prove:
+///       - assert: "1 > 0"
+///         because: "one is positive"
+///     Witness:
+///       - cover: "true"
+///         because: "always reachable"
+///     "#;
+///     let loaded = load_suites(&SourceId::new("arith.theorem"), yaml).unwrap();
+///     assert_eq!(loaded.suites.len(), 1);
+///     assert!(loaded.ungrouped.is_empty());
+pub fn load_suites(source: &SourceId, input: &str) -> Result<LoadedSuites, SchemaError> {
+    load_suites_with_includes(source, input, &NoIncludes)
+}
+
+/// Loads theorem documents from YAML, reading `Include` fragments through
+/// `resolver`, and groups them by `Suite` document.
+///
+/// # Errors
+///
+/// Returns the same errors as
+/// [`load_theorem_docs_with_includes`](super::load_theorem_docs_with_includes).
+pub fn load_suites_with_includes(
+    source: &SourceId,
+    input: &str,
+    resolver: &dyn IncludeResolver,
+) -> Result<LoadedSuites, SchemaError> {
+    let load = SourceLoad {
+        source,
+        input,
+        resolver,
+        manifest: None,
+        options: &LoadOptions::default(),
+    };
+    let (suites, loaded) = load_source_documents(&load)?;
+    Ok(group_by_suite(suites, loaded.docs))
+}
+
+fn group_by_suite(suites: Vec<RawSuite>, docs: Vec<TheoremDoc>) -> LoadedSuites {
+    let mut remaining: IndexMap<String, TheoremDoc> = docs
+        .into_iter()
+        .map(|doc| (doc.theorem.as_str().to_owned(), doc))
+        .collect();
+    let grouped = suites
+        .into_iter()
+        .map(|suite| TheoremSuite {
+            theorems: suite
+                .theorems
+                .iter()
+                .filter_map(|member| remaining.shift_remove(member.value.as_str()))
+                .collect(),
+            name: suite.name.value,
+            about: suite.about.value,
+            tags: suite.tags,
+            requires: suite.requires,
+        })
+        .collect();
+    LoadedSuites {
+        suites: grouped,
+        ungrouped: remaining.into_values().collect(),
+    }
+}
+
+#[cfg(test)]
+#[path = "suite_tests.rs"]
+mod tests;
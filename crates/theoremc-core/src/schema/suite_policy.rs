@@ -0,0 +1,145 @@
+//! Aggregate evidence requirements declared by `Suite` documents.
+//!
+//! A suite's `Requires` section states policies that hold across its members
+//! rather than for any one theorem, such as every member configuring a Kani
+//! harness or the suite keeping at least one `FAILURE` expectation. They are
+//! checked once member evidence is fully merged and validated, so theorem,
+//! `Extends`, suite, and `Defaults` evidence all count.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use super::diagnostic::{SchemaDiagnosticCode, create_diagnostic};
+use super::error::SchemaError;
+use super::raw_suite::RawSuite;
+use super::source_id::SourceId;
+use super::types::{Evidence, KaniExpectation, TheoremDoc};
+
+/// A verification backend a theorem's `Evidence` can configure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvidenceBackend {
+    /// The Kani model checker (`Evidence.kani`).
+    Kani,
+    /// The Verus proof backend (`Evidence.verus`).
+    Verus,
+    /// The Stateright model checker (`Evidence.stateright`).
+    Stateright,
+}
+
+impl EvidenceBackend {
+    /// Returns the backend as written under `Evidence`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Kani => "kani",
+            Self::Verus => "verus",
+            Self::Stateright => "stateright",
+        }
+    }
+
+    /// Returns `true` when `evidence` configures this backend.
+    #[must_use]
+    pub const fn is_configured(self, evidence: &Evidence) -> bool {
+        match self {
+            Self::Kani => !evidence.kani.is_empty(),
+            Self::Verus => evidence.verus.is_some(),
+            Self::Stateright => evidence.stateright.is_some(),
+        }
+    }
+}
+
+/// Aggregate requirements a `Suite` places on its member theorems.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiteRequirements {
+    /// Backends every member theorem must configure.
+    pub backends: Vec<EvidenceBackend>,
+    /// Minimum number of members with a Kani configuration expecting each
+    /// outcome.
+    pub min_expect: IndexMap<KaniExpectation, usize>,
+}
+
+impl SuiteRequirements {
+    /// Returns `true` when the suite declares no requirements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.backends.is_empty() && self.min_expect.is_empty()
+    }
+}
+
+/// Checks each suite's `Requires` section against its validated members.
+///
+/// # Errors
+///
+/// Returns [`SchemaError::InvalidSuite`] pointing at the member's `Theorems`
+/// entry when a member lacks a required backend, or at the suite name when
+/// too few members expect a required Kani outcome.
+pub(crate) fn check_suite_requirements(
+    source: &SourceId,
+    suites: &[RawSuite],
+    docs: &[TheoremDoc],
+) -> Result<(), SchemaError> {
+    let by_name: HashMap<&str, &TheoremDoc> =
+        docs.iter().map(|doc| (doc.theorem.as_str(), doc)).collect();
+    for suite in suites.iter().filter(|suite| !suite.requires.is_empty()) {
+        let invalid = |reason: String, location| {
+            let diagnostic = create_diagnostic(
+                SchemaDiagnosticCode::ValidationFailure,
+                source,
+                reason.clone(),
+                location,
+            );
+            SchemaError::InvalidSuite {
+                suite: suite.name.value.clone(),
+                reason,
+                diagnostic: Some(Box::new(diagnostic)),
+            }
+        };
+        let members: Vec<_> = suite
+            .theorems
+            .iter()
+            .filter_map(|member| Some((member, *by_name.get(member.value.as_str())?)))
+            .collect();
+
+        for &(member, doc) in &members {
+            if let Some(backend) = suite
+                .requires
+                .backends
+                .iter()
+                .find(|backend| !backend.is_configured(&doc.evidence))
+            {
+                return Err(invalid(
+                    format!(
+                        "theorem '{}' has no {} evidence, which the suite requires",
+                        member.value,
+                        backend.as_str()
+                    ),
+                    member.referenced,
+                ));
+            }
+        }
+        for (&expect, &minimum) in &suite.requires.min_expect {
+            let found = members
+                .iter()
+                .filter(|(_, doc)| doc.evidence.kani.iter().any(|kani| kani.expect == expect))
+                .count();
+            if found < minimum {
+                return Err(invalid(
+                    format!(
+                        "suite requires at least {minimum} theorem(s) expecting {}, found {found}",
+                        expect.as_str()
+                    ),
+                    suite.name.referenced,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "suite_policy_tests.rs"]
+mod tests;
@@ -0,0 +1,121 @@
+//! Unit tests for `Suite` `Requires` policies.
+
+use rstest::rstest;
+
+use super::{EvidenceBackend, SuiteRequirements};
+use crate::schema::test_fixtures::theorem_without_evidence;
+use crate::schema::{
+    KaniExpectation, LoadedSuites, SchemaError, SourceId, load_suites, load_theorem_docs,
+};
+
+const SOURCE: &str = "theorems/ledger.theorem";
+
+fn suite(requires: &str) -> String {
+    format!(
+        concat!(
+            "Suite: Ledger\n",
+            "About: Ledger invariants\n",
+            "Theorems: [Holds, Breaks]\n",
+            "Requires:\n",
+            "{requires}",
+        ),
+        requires = requires,
+    )
+}
+
+fn theorem(name: &str, evidence: &str) -> String {
+    format!("{}{evidence}", theorem_without_evidence(name, ""))
+}
+
+fn kani(expect: &str) -> String {
+    format!("Evidence:\n  kani:\n    unwind: 1\n    expect: {expect}\n")
+}
+
+fn load(requires: &str, holds: &str, breaks: &str) -> Result<LoadedSuites, SchemaError> {
+    let documents = [
+        suite(requires),
+        theorem("Holds", holds),
+        theorem("Breaks", breaks),
+    ];
+    load_suites(&SourceId::new(SOURCE), &documents.join("---\n"))
+}
+
+#[test]
+fn satisfied_requirements_are_kept_on_the_suite() {
+    let loaded = load(
+        "  backends: [kani]\n  min_expect:\n    FAILURE: 1\n",
+        &kani("SUCCESS"),
+        &kani("FAILURE"),
+    )
+    .expect("requirements should hold");
+    let suite = loaded.suites.first().expect("one suite");
+
+    assert_eq!(
+        suite.requires,
+        SuiteRequirements {
+            backends: vec![EvidenceBackend::Kani],
+            min_expect: [(KaniExpectation::Failure, 1)].into_iter().collect(),
+        }
+    );
+}
+
+#[test]
+fn suite_evidence_counts_towards_requirements() {
+    let yaml = [
+        format!("{}{}", suite("  backends: [kani]\n"), kani("SUCCESS")),
+        theorem("Holds", ""),
+        theorem("Breaks", ""),
+    ]
+    .join("---\n");
+
+    assert!(load_theorem_docs(&yaml).is_ok());
+}
+
+#[rstest]
+#[case::missing_backend(
+    "  backends: [kani]\n",
+    "Evidence:\n  verus: {}\n",
+    "theorem 'Breaks' has no kani evidence, which the suite requires",
+    3
+)]
+#[case::second_backend(
+    "  backends: [kani, verus]\n",
+    "Evidence:\n  verus: {}\n",
+    "theorem 'Holds' has no verus evidence, which the suite requires",
+    3
+)]
+#[case::too_few_expectations(
+    "  min_expect:\n    FAILURE: 1\n",
+    "Evidence:\n  kani:\n    unwind: 1\n    expect: SUCCESS\n",
+    "suite requires at least 1 theorem(s) expecting FAILURE, found 0",
+    1
+)]
+fn unmet_requirements_are_rejected(
+    #[case] requires: &str,
+    #[case] breaks: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error = load(requires, &kani("SUCCESS"), breaks).expect_err("policy should be violated");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(&error, SchemaError::InvalidSuite { suite, reason, .. }
+            if suite == "Ledger" && reason == expected),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[test]
+fn unknown_requirements_are_rejected() {
+    let message = load("  every: [kani]\n", &kani("SUCCESS"), &kani("SUCCESS"))
+        .expect_err("unknown requirement should be rejected")
+        .to_string();
+
+    assert!(
+        message.contains("unknown field `every`"),
+        "unexpected error: {message}"
+    );
+}
@@ -0,0 +1,162 @@
+//! Unit tests for `Suite` documents and suite grouping.
+
+use rstest::rstest;
+
+use super::{LoadedSuites, load_suites};
+use crate::schema::test_fixtures::theorem_without_evidence;
+use crate::schema::{KaniExpectation, SchemaError, SourceId, TheoremDoc, load_theorem_docs};
+
+const SOURCE: &str = "theorems/ledger.theorem";
+
+const SUITE: &str = concat!(
+    "Suite: Ledger\n",
+    "About: Ledger invariants\n",
+    "Tags: [ledger]\n",
+    "Theorems: [Second, First]\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 3\n",
+    "    expect: SUCCESS\n",
+);
+
+const LOCAL_EVIDENCE: &str = "Evidence:\n  kani:\n    unwind: 1\n    expect: SUCCESS\n";
+
+fn load(documents: &[&str]) -> Result<LoadedSuites, SchemaError> {
+    load_suites(&SourceId::new(SOURCE), &documents.join("---\n"))
+}
+
+fn names(docs: &[TheoremDoc]) -> Vec<&str> {
+    docs.iter().map(|doc| doc.theorem.as_str()).collect()
+}
+
+fn unwind(doc: &TheoremDoc) -> u32 {
+    doc.evidence
+        .kani
+        .first()
+        .expect("kani evidence expected")
+        .unwind
+}
+
+#[test]
+fn suites_group_members_in_listed_order() {
+    let loaded = load(&[
+        &theorem_without_evidence("First", ""),
+        SUITE,
+        &theorem_without_evidence("Second", "Evidence:\n  kani:\n    unwind: 8\n"),
+        &theorem_without_evidence("Loose", LOCAL_EVIDENCE),
+    ])
+    .expect("suite should load");
+    let suite = loaded.suites.first().expect("one suite");
+
+    assert_eq!(suite.name, "Ledger");
+    assert_eq!(suite.about, "Ledger invariants");
+    assert_eq!(suite.tags, ["ledger"]);
+    assert_eq!(names(&suite.theorems), ["Second", "First"]);
+    assert_eq!(
+        suite.theorems.iter().map(unwind).collect::<Vec<_>>(),
+        [8, 3]
+    );
+    assert_eq!(names(&loaded.ungrouped), ["Loose"]);
+}
+
+#[test]
+fn suite_evidence_takes_precedence_over_file_defaults() {
+    let defaults = "Defaults:\n  Evidence:\n    kani:\n      unwind: 9\n      expect: FAILURE\n";
+    let loaded = load(&[
+        defaults,
+        SUITE,
+        &theorem_without_evidence("First", ""),
+        &theorem_without_evidence("Second", ""),
+        &theorem_without_evidence("Loose", ""),
+    ])
+    .expect("suite and defaults should load");
+    let suite = loaded.suites.first().expect("one suite");
+    let loose = loaded.ungrouped.first().expect("one ungrouped theorem");
+
+    assert_eq!(
+        suite.theorems.iter().map(unwind).collect::<Vec<_>>(),
+        [3, 3]
+    );
+    assert_eq!(unwind(loose), 9);
+    assert_eq!(
+        loose.evidence.kani.first().map(|kani| kani.expect),
+        Some(KaniExpectation::Failure)
+    );
+}
+
+#[test]
+fn theorem_loaders_apply_suite_evidence() {
+    let yaml = [
+        SUITE,
+        &theorem_without_evidence("First", ""),
+        &theorem_without_evidence("Second", ""),
+    ]
+    .join("---\n");
+    let docs = load_theorem_docs(&yaml).expect("suite members should load");
+
+    assert_eq!(names(&docs), ["First", "Second"]);
+    assert!(docs.iter().all(|doc| unwind(doc) == 3));
+}
+
+#[rstest]
+#[case::unknown_member(
+    "Suite: Ledger\nAbout: x\nTheorems: [Missing]\n",
+    "theorem 'Missing' is not declared in this file",
+    3
+)]
+#[case::shared_member(
+    "Suite: Ledger\nAbout: x\nTheorems: [First]\n---\nSuite: Audit\nAbout: y\nTheorems: [First]\n",
+    "theorem 'First' already belongs to suite 'Ledger'",
+    7
+)]
+#[case::duplicate_suite(
+    "Suite: Ledger\nAbout: x\nTheorems: [First]\n---\nSuite: Ledger\nAbout: y\nTheorems: []\n",
+    "suite is declared more than once",
+    5
+)]
+#[case::blank_about(
+    "Suite: Ledger\nAbout: '  '\nTheorems: [First]\n",
+    "About must be non-empty after trimming",
+    2
+)]
+#[case::empty_members(
+    "Suite: Ledger\nAbout: x\nTheorems: []\n",
+    "Theorems must list at least one theorem",
+    1
+)]
+#[case::bad_name(
+    "Suite: fn\nAbout: x\nTheorems: [First]\n",
+    "invalid identifier 'fn'",
+    1
+)]
+fn invalid_suites_are_rejected(#[case] suites: &str, #[case] expected: &str, #[case] line: usize) {
+    let error = load(&[suites, &theorem_without_evidence("First", LOCAL_EVIDENCE)])
+        .expect_err("suite should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        matches!(error, SchemaError::InvalidSuite { .. }),
+        "unexpected error: {error}"
+    );
+    assert!(
+        error.to_string().contains(expected),
+        "unexpected error: {error}"
+    );
+    assert_eq!(diagnostic.location.source, SOURCE);
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[test]
+fn unknown_suite_keys_are_rejected() {
+    let message = load(&[
+        "Suite: Ledger\nAbout: x\nTheorems: [First]\nProve: []\n",
+        &theorem_without_evidence("First", LOCAL_EVIDENCE),
+    ])
+    .expect_err("unknown suite key should be rejected")
+    .to_string();
+
+    assert!(
+        message.contains("unknown field `Prove`"),
+        "unexpected error: {message}"
+    );
+}
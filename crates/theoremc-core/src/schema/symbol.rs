@@ -0,0 +1,215 @@
+//! Interned strings for vocabulary repeated across theorem documents.
+//!
+//! Corpora with thousands of documents name the same actions, Rust types,
+//! tags, and `because` boilerplate over and over. A [`Symbol`] shares one
+//! allocation per distinct string across every loaded document, so cloning
+//! and comparing them is a pointer operation and the corpus stores each
+//! spelling once. The interner only forgets strings no live symbol holds, so
+//! two symbols share an allocation exactly when their text is equal.
+
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::de;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Number of interned strings kept before the table first drops symbols no
+/// document still holds.
+const INITIAL_PRUNE_THRESHOLD: usize = 1024;
+
+/// The process-wide table of interned strings.
+struct Interner {
+    symbols: BTreeSet<Arc<str>>,
+    prune_at: usize,
+}
+
+static INTERNER: Mutex<Interner> = Mutex::new(Interner {
+    symbols: BTreeSet::new(),
+    prune_at: INITIAL_PRUNE_THRESHOLD,
+});
+
+impl Interner {
+    fn intern(&mut self, text: &str) -> Arc<str> {
+        if let Some(symbol) = self.symbols.get(text) {
+            return Arc::clone(symbol);
+        }
+        if self.symbols.len() >= self.prune_at {
+            // Only the table holds these, so no document can observe them.
+            self.symbols.retain(|symbol| Arc::strong_count(symbol) > 1);
+            self.prune_at = (self.symbols.len() * 2).max(INITIAL_PRUNE_THRESHOLD);
+        }
+        let symbol: Arc<str> = Arc::from(text);
+        self.symbols.insert(Arc::clone(&symbol));
+        symbol
+    }
+}
+
+/// An interned, immutable string.
+///
+/// Equal symbols share one allocation, so [`Clone`] only bumps a reference
+/// count, and equality compares pointers rather than text. A `Symbol`
+/// dereferences to [`str`] and hashes, orders, and serializes exactly like the
+/// string it holds.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::Symbol;
+///
+///     let first = Symbol::new("hnsw.attach_node");
+///     let second = Symbol::from(String::from("hnsw.attach_node"));
+///     assert_eq!(first, second);
+///     assert_eq!(first, "hnsw.attach_node");
+///     assert!(first.starts_with("hnsw."));
+#[derive(Clone, Eq, PartialOrd, Ord)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Returns the interned symbol for `text`.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        let mut interner = INTERNER.lock().unwrap_or_else(PoisonError::into_inner);
+        Self(interner.intern(text))
+    }
+
+    /// Returns the symbol's text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        // Interning makes equal text share one allocation.
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash the text so lookups through `Borrow<str>` find the symbol.
+        self.0.hash(state);
+    }
+}
+
+impl Default for Symbol {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(text: &String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(text: String) -> Self {
+        Self::new(&text)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.0.as_ref().to_owned()
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Symbol {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl PartialEq<Symbol> for str {
+    fn eq(&self, other: &Symbol) -> bool {
+        self == &*other.0
+    }
+}
+
+impl PartialEq<Symbol> for &str {
+    fn eq(&self, other: &Symbol) -> bool {
+        *self == &*other.0
+    }
+}
+
+impl PartialEq<Symbol> for String {
+    fn eq(&self, other: &Symbol) -> bool {
+        **self == *other.0
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        Ok(Self::from(text))
+    }
+}
+
+#[cfg(test)]
+#[path = "symbol_tests.rs"]
+mod tests;
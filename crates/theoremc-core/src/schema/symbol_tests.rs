@@ -0,0 +1,130 @@
+//! Unit tests for interned symbols.
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{INITIAL_PRUNE_THRESHOLD, Interner, Symbol};
+use crate::schema::{Step, TheoremDoc, load_theorem_docs};
+
+const CORPUS: &str = concat!(
+    "Theorem: First\n",
+    "About: Shared vocabulary\n",
+    "Tags: [ledger]\n",
+    "Forall:\n",
+    "  amount: u64\n",
+    "Actions:\n",
+    "  ledger.deposit:\n",
+    "    params: { amount: u64 }\n",
+    "Do:\n",
+    "  - call:\n",
+    "      action: ledger.deposit\n",
+    "      args: { amount: { ref: amount } }\n",
+    "Prove:\n",
+    "  - assert: 'amount > 0'\n",
+    "    because: deposits are positive\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn action(doc: &TheoremDoc) -> Option<&Symbol> {
+    match doc.do_steps.first()? {
+        Step::Call(call) => Some(&call.call.action),
+        _ => None,
+    }
+}
+
+fn because(doc: &TheoremDoc) -> Option<&Symbol> {
+    doc.prove.first().map(|assertion| &assertion.because)
+}
+
+fn param(doc: &TheoremDoc) -> Option<&Symbol> {
+    doc.actions
+        .get("ledger.deposit")
+        .and_then(|signature| signature.params.get("amount"))
+}
+
+fn shared(first: Option<&Symbol>, second: Option<&Symbol>) -> bool {
+    first
+        .zip(second)
+        .is_some_and(|(left, right)| Arc::ptr_eq(&left.0, &right.0))
+}
+
+#[test]
+fn equal_text_shares_one_allocation() {
+    let first = Symbol::new("ledger.deposit");
+    let second = Symbol::from(String::from("ledger.deposit"));
+
+    assert_eq!(first, second);
+    assert!(Arc::ptr_eq(&first.0, &second.0));
+}
+
+#[rstest]
+#[case::same("u64", "u64")]
+#[case::different("u64", "u32")]
+#[case::prefix("ledger", "ledger.deposit")]
+fn equality_agrees_with_text(#[case] left: &str, #[case] right: &str) {
+    let is_equal_text = left == right;
+
+    assert_eq!(Symbol::new(left) == Symbol::new(right), is_equal_text);
+    assert_eq!(Symbol::new(left).cmp(&Symbol::new(right)), left.cmp(right));
+}
+
+#[test]
+fn documents_share_repeated_vocabulary() {
+    let yaml = [CORPUS, &CORPUS.replace("First", "Second")].join("---\n");
+    let docs = load_theorem_docs(&yaml).expect("corpus should load");
+    let [first, second] = docs.as_slice() else {
+        panic!("expected two documents");
+    };
+    assert!(shared(first.tags.first(), second.tags.first()));
+    assert!(shared(because(first), because(second)));
+    assert!(shared(action(first), action(second)));
+    assert!(shared(first.forall.values().next(), param(second)));
+}
+
+#[rstest]
+#[case::borrowed("ledger")]
+#[case::empty("")]
+fn symbols_behave_like_their_text(#[case] text: &str) {
+    let symbol = Symbol::new(text);
+    let lookup: HashSet<Symbol> = HashSet::from([symbol.clone()]);
+
+    assert_eq!(symbol, text);
+    assert_eq!(text, symbol);
+    assert_eq!(symbol.to_string(), text);
+    assert_eq!(format!("{symbol:?}"), format!("{text:?}"));
+    assert!(lookup.contains(text));
+}
+
+#[test]
+fn symbols_serialize_as_strings() {
+    let symbol: Symbol = serde_json::from_str("\"u64\"").expect("symbol should deserialize");
+
+    assert_eq!(symbol, "u64");
+    assert_eq!(
+        serde_json::to_string(&symbol).expect("symbol should serialize"),
+        "\"u64\""
+    );
+}
+
+#[test]
+fn pruning_drops_only_unreferenced_symbols() {
+    let mut interner = Interner {
+        symbols: BTreeSet::default(),
+        prune_at: INITIAL_PRUNE_THRESHOLD,
+    };
+    let kept = interner.intern("kept");
+    for index in 1..INITIAL_PRUNE_THRESHOLD {
+        interner.intern(&format!("transient{index}"));
+    }
+    let again = interner.intern("kept");
+    let added = interner.intern("added");
+
+    assert!(Arc::ptr_eq(&kept, &again));
+    assert_eq!(interner.symbols.len(), 2);
+    assert!(interner.symbols.contains("added"));
+    drop(added);
+}
@@ -35,3 +35,51 @@ pub const fn bound_lifetime_bare_fn_yaml() -> &'static str {
 pub const fn bound_lifetime_trait_object_yaml() -> &'static str {
     include_str!("../../tests/fixtures/bound_lifetime_trait_object.theorem")
 }
+
+/// A minimal valid theorem named `name`, with `extra` sections placed before
+/// `Prove` and Kani evidence with `unwind: 1`.
+#[must_use]
+pub fn theorem(name: &str, extra: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: {name}\n",
+            "About: example theorem\n",
+            "{extra}",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "{witness}",
+        ),
+        name = name,
+        extra = extra,
+        witness = WITNESS,
+    )
+}
+
+/// Like [`theorem`] without `Evidence`, for theorems that inherit it from
+/// file defaults or a suite, or that append their own.
+#[must_use]
+pub fn theorem_without_evidence(name: &str, extra: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: {name}\n",
+            "About: example theorem\n",
+            "{extra}",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "{witness}",
+        ),
+        name = name,
+        extra = extra,
+        witness = WITNESS,
+    )
+}
+
+/// The `Witness` section shared by [`theorem`] and
+/// [`theorem_without_evidence`].
+const WITNESS: &str = "Witness:\n  - cover: 'true'\n    because: always reachable\n";
@@ -0,0 +1,155 @@
+//! A validated Rust expression for `Assume`, `Prove`, and `Witness`
+//! entries.
+//!
+//! [`TheoremExpr`] keeps the expression as written, so emission reproduces
+//! the source, and every constructor checks that it parses as a single
+//! `syn::Expr`. `syn` syntax trees are neither `Send` nor `Sync`, so the tree
+//! itself is rebuilt on request by [`TheoremExpr::to_syn_expr`] rather than
+//! stored, keeping [`TheoremDoc`](super::TheoremDoc) shareable across
+//! threads.
+
+use std::fmt;
+
+use serde::de;
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::expr::validate_rust_expr;
+use super::validation_reason::{
+    IndexedValidationField, IndexedValidationSection, ValidationReasonKind,
+};
+
+/// A Rust expression that parses as a single, non-statement `syn::Expr`.
+///
+/// Every constructor, including deserialization, checks the trimmed source,
+/// so a `TheoremExpr` never holds an expression that fails to parse.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::TheoremExpr;
+///
+///     let expr = TheoremExpr::new("balance >= amount").unwrap();
+///     assert_eq!(expr, "balance >= amount");
+///     assert!(matches!(expr.to_syn_expr(), Ok(syn::Expr::Binary(_))));
+///
+///     assert!(TheoremExpr::new("{ let x = 1; x }").is_err());
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TheoremExpr(String);
+
+/// Errors produced when a string is not a valid [`TheoremExpr`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("expression '{expr}' {reason}")]
+pub struct TheoremExprError {
+    /// The rejected source text.
+    pub expr: String,
+    /// Why the source was rejected.
+    pub reason: String,
+}
+
+impl TheoremExpr {
+    /// Creates a `TheoremExpr` after checking that the trimmed source parses
+    /// as a single expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TheoremExprError`] when the source does not parse or is a
+    /// statement-like form such as a block, loop, or assignment.
+    pub fn new(source: impl Into<String>) -> Result<Self, TheoremExprError> {
+        let expr = source.into();
+        match validate_rust_expr(expr.trim()) {
+            Ok(()) => Ok(Self(expr)),
+            Err(reason) => Err(TheoremExprError { expr, reason }),
+        }
+    }
+
+    /// Creates the expression of entry `index` of `section`, rejecting it
+    /// with the reason document validation reports for that entry.
+    pub(crate) fn for_entry(
+        source: String,
+        section: IndexedValidationSection,
+        index: usize,
+    ) -> Result<Self, EntryExprError> {
+        let entry = format!(
+            "{} {}: {}",
+            section.label(),
+            index + 1,
+            section.expr_label()
+        );
+        let reason = if source.trim().is_empty() {
+            format!("{entry} must be non-empty after trimming")
+        } else {
+            match Self::new(source) {
+                Ok(expr) => return Ok(expr),
+                Err(error) => format!("{entry} {}", error.reason),
+            }
+        };
+        Err(EntryExprError {
+            reason,
+            kind: section.reason_kind(index, IndexedValidationField::Value),
+        })
+    }
+
+    /// Returns the expression as written.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses the expression into its `syn` syntax tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TheoremExprError`] when the source does not parse, which
+    /// construction already rules out.
+    pub fn to_syn_expr(&self) -> Result<syn::Expr, TheoremExprError> {
+        syn::parse_str(self.0.trim()).map_err(|error| TheoremExprError {
+            expr: self.0.clone(),
+            reason: format!("is not a valid Rust expression: {error}"),
+        })
+    }
+}
+
+/// An entry expression rejected by [`TheoremExpr::for_entry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EntryExprError {
+    /// The validation reason, naming the entry and its expression field.
+    pub(crate) reason: String,
+    /// The entry and field the reason points at.
+    pub(crate) kind: ValidationReasonKind,
+}
+
+impl PartialEq<&str> for TheoremExpr {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl AsRef<str> for TheoremExpr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TheoremExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for TheoremExpr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for TheoremExpr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Self::new(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[path = "theorem_expr_tests.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+//! Unit tests for the validated `TheoremExpr` wrapper.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::TheoremExpr;
+use crate::schema::validation_reason::{IndexedValidationField, IndexedValidationSection};
+
+#[test]
+fn valid_source_is_kept_as_written() {
+    let expr = TheoremExpr::new("  amount <= limit ").expect("expression should be valid");
+
+    assert_eq!(expr.as_str(), "  amount <= limit ");
+    assert_eq!(expr.to_string(), "  amount <= limit ");
+}
+
+#[test]
+fn syntax_tree_is_available() {
+    let expr = TheoremExpr::new("ledger.balance() > 0").expect("expression should be valid");
+
+    let Ok(syn::Expr::Binary(binary)) = expr.to_syn_expr() else {
+        panic!("expected a binary expression");
+    };
+    assert!(matches!(*binary.left, syn::Expr::MethodCall(_)));
+}
+
+#[rstest]
+#[case::empty("", "is not a valid Rust expression")]
+#[case::incomplete("x +", "is not a valid Rust expression")]
+#[case::block(
+    "{ let x = 1; x }",
+    "must be a single expression, not a statement or block"
+)]
+#[case::assignment("x = 1", "must be a single expression, not a statement or block")]
+fn invalid_source_is_rejected(#[case] source: &str, #[case] reason: &str) {
+    let error = TheoremExpr::new(source).expect_err("expression should be rejected");
+
+    assert_eq!(error.expr, source);
+    assert!(error.reason.starts_with(reason), "{error}");
+    assert!(
+        error
+            .to_string()
+            .starts_with(&format!("expression '{source}' {reason}")),
+        "{error}"
+    );
+}
+
+#[test]
+fn deserialization_validates_the_source() {
+    let valid: TheoremExpr = serde_json::from_str(r#""x > 0""#).expect("should deserialize");
+    let invalid = serde_json::from_str::<TheoremExpr>(r#""x >""#);
+
+    assert_eq!(valid, "x > 0");
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn serializes_as_its_source() {
+    let expr = TheoremExpr::new("x > 0").expect("expression should be valid");
+
+    assert_eq!(
+        serde_json::to_string(&expr).ok(),
+        Some(r#""x > 0""#.to_owned())
+    );
+}
+
+#[rstest]
+#[case::blank(
+    IndexedValidationSection::Assume,
+    "  ",
+    "Assume constraint 2: expr must be non-empty after trimming"
+)]
+#[case::unparsable(
+    IndexedValidationSection::Prove,
+    "x >",
+    "Prove assertion 2: assert is not a valid Rust expression"
+)]
+#[case::statement(
+    IndexedValidationSection::Witness,
+    "x = 1",
+    "Witness 2: cover must be a single expression"
+)]
+fn entry_expressions_are_rejected_as_validation_reports_them(
+    #[case] section: IndexedValidationSection,
+    #[case] source: &str,
+    #[case] reason: &str,
+) {
+    let error =
+        TheoremExpr::for_entry(source.to_owned(), section, 1).expect_err("entry should fail");
+
+    assert!(error.reason.starts_with(reason), "{}", error.reason);
+    assert_eq!(
+        error.kind,
+        section.reason_kind(1, IndexedValidationField::Value)
+    );
+}
@@ -0,0 +1,191 @@
+//! Document-local Rust type aliases declared in the `Types` section.
+//!
+//! A `Types` entry maps an alias name to a Rust type string so long generic
+//! types can be written once and referenced by name elsewhere in the same
+//! theorem document. Aliases are expanded in `Forall`, struct field, optional
+//! value, and `Actions` type strings before semantic validation, so validation and code
+//! generation only ever observe concrete Rust types.
+//!
+//! Expansion works on token trees rather than raw text: only standalone
+//! identifiers are replaced, so path segments such as `crate::Balance`,
+//! lifetimes such as `'Balance`, and associated-type bindings such as
+//! `Item = Balance` keep their meaning.
+
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+use super::types::TheoremDoc;
+
+/// Alias table mapping alias names to fully expanded token streams.
+type AliasTable<'a> = IndexMap<&'a str, TokenStream>;
+
+/// An alias whose type refers to itself or to an alias declared after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnresolvedAliasReference<'a> {
+    /// The alias whose type contains the reference.
+    pub(crate) alias: &'a str,
+    /// The alias name referenced before it is declared.
+    pub(crate) referenced: &'a str,
+}
+
+/// Expands `Types` aliases in every `Forall`, struct field, optional value, and
+/// `Actions` type string.
+///
+/// Each alias may refer to aliases declared before it, so expansion resolves
+/// the table in declaration order. Type strings that do not tokenize are left
+/// untouched for semantic validation to report.
+pub(crate) fn expand_type_aliases(doc: &mut TheoremDoc) {
+    if doc.types.is_empty() {
+        return;
+    }
+    let aliases = resolve_alias_table(&doc.types);
+    for ty in doc.forall.values_mut() {
+        expand_in_place(ty, &aliases);
+    }
+    for domain in doc.forall_domains.values_mut() {
+        for ty in domain.type_strings_mut() {
+            expand_in_place(ty, &aliases);
+        }
+    }
+    for signature in doc.actions.values_mut() {
+        for ty in signature.params.values_mut() {
+            expand_in_place(ty, &aliases);
+        }
+        expand_in_place(&mut signature.returns, &aliases);
+    }
+}
+
+/// Returns the first alias whose type refers to itself or to a later alias.
+///
+/// Aliases resolve in declaration order, so such references would otherwise
+/// survive expansion as unknown Rust type names.
+pub(crate) fn first_unresolved_alias_reference(
+    types: &IndexMap<String, String>,
+) -> Option<UnresolvedAliasReference<'_>> {
+    types
+        .iter()
+        .enumerate()
+        .find_map(|(position, (alias, ty))| {
+            let tokens = TokenStream::from_str(ty).ok()?;
+            types
+                .keys()
+                .skip(position)
+                .map(String::as_str)
+                .find(|candidate| mentions_alias(&tokens, candidate))
+                .map(|referenced| UnresolvedAliasReference {
+                    alias: alias.as_str(),
+                    referenced,
+                })
+        })
+}
+
+fn resolve_alias_table(types: &IndexMap<String, String>) -> AliasTable<'_> {
+    let mut aliases = AliasTable::with_capacity(types.len());
+    for (alias, ty) in types {
+        let Ok(tokens) = TokenStream::from_str(ty) else {
+            continue;
+        };
+        let (expanded, _) = substitute(tokens, &aliases);
+        aliases.insert(alias.as_str(), expanded);
+    }
+    aliases
+}
+
+fn expand_in_place<T: AsRef<str> + From<String>>(ty: &mut T, aliases: &AliasTable<'_>) {
+    let Ok(tokens) = TokenStream::from_str(ty.as_ref()) else {
+        return;
+    };
+    let (expanded, changed) = substitute(tokens, aliases);
+    if changed {
+        *ty = T::from(expanded.to_string());
+    }
+}
+
+/// Replaces standalone alias identifiers and reports whether anything changed.
+fn substitute(tokens: TokenStream, aliases: &AliasTable<'_>) -> (TokenStream, bool) {
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut out = Vec::with_capacity(trees.len());
+    let mut changed = false;
+    for (index, tree) in trees.iter().enumerate() {
+        if let Some(replacement) = alias_replacement(&trees, index, aliases) {
+            out.extend(replacement_tokens(replacement));
+            changed = true;
+            continue;
+        }
+        match tree {
+            TokenTree::Group(group) => {
+                let (inner, inner_changed) = substitute(group.stream(), aliases);
+                let mut rebuilt = Group::new(group.delimiter(), inner);
+                rebuilt.set_span(group.span());
+                out.push(TokenTree::Group(rebuilt));
+                changed |= inner_changed;
+            }
+            _ => out.push(tree.clone()),
+        }
+    }
+    (out.into_iter().collect(), changed)
+}
+
+fn alias_replacement<'t>(
+    trees: &[TokenTree],
+    index: usize,
+    aliases: &'t AliasTable<'_>,
+) -> Option<&'t TokenStream> {
+    match trees.get(index)? {
+        TokenTree::Ident(ident) if is_standalone_ident(trees, index) => {
+            aliases.get(ident.to_string().as_str())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the tokens spliced in place of an alias identifier.
+///
+/// Trait-object and `impl Trait` types with several bounds are wrapped in
+/// parentheses so that `&Alias` keeps binding the whole bound list.
+fn replacement_tokens(replacement: &TokenStream) -> TokenStream {
+    let mut trees = replacement.clone().into_iter().peekable();
+    let is_bounded = trees.peek().is_some_and(is_bound_list_keyword);
+    if is_bounded && trees.any(|tree| is_punct(&tree, &['+'])) {
+        TokenStream::from(TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            replacement.clone(),
+        )))
+    } else {
+        replacement.clone()
+    }
+}
+
+fn is_bound_list_keyword(tree: &TokenTree) -> bool {
+    matches!(tree, TokenTree::Ident(ident) if ident == "dyn" || ident == "impl")
+}
+
+fn mentions_alias(tokens: &TokenStream, alias: &str) -> bool {
+    let trees: Vec<TokenTree> = tokens.clone().into_iter().collect();
+    trees.iter().enumerate().any(|(index, tree)| match tree {
+        TokenTree::Ident(ident) => is_standalone_ident(&trees, index) && ident == alias,
+        TokenTree::Group(group) => mentions_alias(&group.stream(), alias),
+        _ => false,
+    })
+}
+
+/// Returns `true` when the identifier at `index` is not part of a path,
+/// lifetime, or associated-type binding.
+fn is_standalone_ident(trees: &[TokenTree], index: usize) -> bool {
+    let previous = index
+        .checked_sub(1)
+        .and_then(|position| trees.get(position));
+    let next = trees.get(index + 1);
+    !previous.is_some_and(|tree| is_punct(tree, &[':', '\'']))
+        && !next.is_some_and(|tree| is_punct(tree, &[':', '=']))
+}
+
+fn is_punct(tree: &TokenTree, chars: &[char]) -> bool {
+    matches!(tree, TokenTree::Punct(punct) if chars.contains(&punct.as_char()))
+}
+
+#[cfg(test)]
+#[path = "type_alias_tests.rs"]
+mod tests;
@@ -0,0 +1,148 @@
+//! Unit tests for `Types` alias expansion.
+
+use indexmap::IndexMap;
+use rstest::rstest;
+
+use super::{UnresolvedAliasReference, first_unresolved_alias_reference};
+use crate::schema::rust_type::canonical_token_stream;
+use crate::schema::test_support::assert_parse_error_contains;
+use crate::schema::{Symbol, TheoremDoc, load_theorem_docs};
+
+fn theorem_with_types(types: &str, forall: &str) -> String {
+    format!(
+        concat!(
+            "Theorem: Aliased\n",
+            "About: uses type aliases\n",
+            "Types:\n{types}",
+            "Forall:\n{forall}",
+            "Prove:\n",
+            "  - assert: 'true'\n",
+            "    because: trivially true\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "Witness:\n",
+            "  - cover: 'true'\n",
+            "    because: always reachable\n",
+        ),
+        types = types,
+        forall = forall,
+    )
+}
+
+fn load_single(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("aliased theorem should load")
+        .into_iter()
+        .next()
+        .expect("one theorem document")
+}
+
+fn assert_forall_type(doc: &TheoremDoc, var: &str, expected: &str) {
+    let actual = doc.forall.get(var).expect("forall variable should exist");
+    assert_eq!(
+        canonical_token_stream(actual),
+        canonical_token_stream(expected),
+        "unexpected expansion for '{var}': {actual}"
+    );
+}
+
+#[rstest]
+#[case::whole_type(
+    "  Ledger: 'BTreeMap<u64, u64>'\n",
+    "  x: Ledger\n",
+    "BTreeMap<u64, u64>"
+)]
+#[case::nested_generic("  Balance: u64\n", "  x: Vec<Balance>\n", "Vec<u64>")]
+#[case::reference("  Balance: u64\n", "  x: '&Balance'\n", "&u64")]
+#[case::earlier_alias(
+    "  Balance: u64\n  Ledger: 'Vec<Balance>'\n",
+    "  x: 'Option<Ledger>'\n",
+    "Option<Vec<u64>>"
+)]
+#[case::path_segment_untouched("  Balance: u64\n", "  x: crate::Balance\n", "crate::Balance")]
+#[case::bound_list_parenthesized(
+    "  Handler: 'dyn Fn() + Send'\n",
+    "  x: '&Handler'\n",
+    "&(dyn Fn() + Send)"
+)]
+fn forall_aliases_expand_to_concrete_types(
+    #[case] types: &str,
+    #[case] forall: &str,
+    #[case] expected: &str,
+) {
+    let doc = load_single(&theorem_with_types(types, forall));
+    assert_forall_type(&doc, "x", expected);
+}
+
+#[test]
+fn declared_aliases_are_preserved_on_the_document() {
+    let doc = load_single(&theorem_with_types("  Balance: u64\n", "  x: Balance\n"));
+    assert_eq!(doc.types.get("Balance").map(String::as_str), Some("u64"));
+}
+
+#[test]
+fn action_signature_types_expand_aliases() {
+    let yaml = theorem_with_types("  Balance: u64\n", "  x: Balance\n").replace(
+        "Prove:\n",
+        concat!(
+            "Actions:\n",
+            "  account.deposit:\n",
+            "    params:\n",
+            "      amount: Balance\n",
+            "    returns: Option<Balance>\n",
+            "Prove:\n",
+        ),
+    );
+    let doc = load_single(&yaml);
+    let signature = doc
+        .actions
+        .get("account.deposit")
+        .expect("signature should exist");
+    assert_eq!(
+        signature.params.get("amount").map(Symbol::as_str),
+        Some("u64")
+    );
+    assert_eq!(
+        canonical_token_stream(&signature.returns),
+        canonical_token_stream("Option<u64>")
+    );
+}
+
+#[rstest]
+#[case::keyword_alias("  type: u64\n", "Types alias 'type': name")]
+#[case::invalid_type(
+    "  Balance: 'not a type'\n",
+    "Types alias 'Balance': type is not a valid Rust type"
+)]
+#[case::free_lifetime(
+    "  Borrowed: \"&'a str\"\n",
+    "Types alias 'Borrowed': type contains a free named lifetime parameter 'a'"
+)]
+#[case::forward_reference(
+    "  Ledger: 'Vec<Balance>'\n  Balance: u64\n",
+    "Types alias 'Ledger' references alias 'Balance' before it is declared"
+)]
+#[case::self_reference(
+    "  Tree: 'Box<Tree>'\n",
+    "Types alias 'Tree' references alias 'Tree' before it is declared"
+)]
+fn invalid_aliases_are_rejected(#[case] types: &str, #[case] expected: &str) {
+    assert_parse_error_contains(&theorem_with_types(types, "  x: u64\n"), expected);
+}
+
+#[test]
+fn unresolved_reference_reports_first_offending_alias() {
+    let types: IndexMap<String, String> = [("A", "u8"), ("B", "Vec<C>"), ("C", "Vec<A>")]
+        .into_iter()
+        .map(|(alias, ty)| (alias.to_owned(), ty.to_owned()))
+        .collect();
+    assert_eq!(
+        first_unresolved_alias_reference(&types),
+        Some(UnresolvedAliasReference {
+            alias: "B",
+            referenced: "C",
+        })
+    );
+}
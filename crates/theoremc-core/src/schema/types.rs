@@ -8,13 +8,29 @@
 //! `serde(deny_unknown_fields)` and support both `TitleCase`
 //! (canonical) and lowercase key aliases.
 
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
 use indexmap::IndexMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use super::arg_value::ArgValue;
+use super::forall::ForallDomain;
 use super::newtypes::{ForallVar, TheoremName};
+use super::symbol::Symbol;
+use super::theorem_expr::TheoremExpr;
 use super::value::TheoremValue;
 
+#[path = "types_kani.rs"]
+mod kani;
+#[path = "types_steps.rs"]
+mod steps;
+
+pub use kani::{CounterexampleConstraint, KaniEvidence, KaniExpectation, KaniSolver, KaniStub};
+pub use steps::{
+    ActionCall, ForeachBlock, InlineAssert, LetBinding, LetCall, LetMust, MaybeBlock, RepeatBlock,
+    Step, StepAssert, StepCall, StepExpectErr, StepForeach, StepMaybe, StepMust, StepRepeat,
+};
+
 // ── Top-level document ──────────────────────────────────────────────
 
 /// A single theorem document parsed from a `.theorem` YAML file.
@@ -23,6 +39,13 @@ use super::value::TheoremValue;
 /// proof obligations, and evidence configuration. A `.theorem` file
 /// may contain multiple documents separated by `---`.
 ///
+/// The `Let` and `Do` sections sit behind [`Arc`], so cloning a document
+/// shares them and snapshots held by watchers or editors stay cheap.
+///
+/// The serde implementations define the stored form used by
+/// [`LoadCache`](crate::cache::LoadCache) and perform no validation; load
+/// theorem sources with [`load_theorem_docs`](super::load_theorem_docs).
+///
 /// # Examples
 ///
 ///     use theoremc_core::schema::load_theorem_docs;
@@ -30,6 +53,8 @@ use super::value::TheoremValue;
 ///     let yaml = r#"
 ///     Theorem: MyTheorem
 ///     About: A simple example
+///     Forall:
+///       x: i32
 ///     Prove:
 ///       - assert: "x > 0"
 ///         because: "x is positive"
@@ -43,12 +68,13 @@ use super::value::TheoremValue;
 ///     "#;
 ///     let docs = load_theorem_docs(yaml).unwrap();
 ///     assert_eq!(docs.len(), 1);
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TheoremDoc {
     /// Schema version for forwards compatibility.
     ///
     /// When omitted in the YAML source the field is `None`, indicating
-    /// "unspecified — treat as current default".
+    /// "unspecified — treat as version 1". Use
+    /// [`TheoremDoc::schema_version`] for the effective version.
     pub schema: Option<u32>,
 
     /// Unique theorem name (must be a valid Rust identifier, not a
@@ -59,13 +85,33 @@ pub struct TheoremDoc {
     pub about: String,
 
     /// Metadata tags for filtering, ownership, and reporting.
-    pub tags: Vec<String>,
+    pub tags: Vec<Symbol>,
+
+    /// Requirements this theorem provides evidence for.
+    pub traces: Vec<TraceLink>,
 
     /// Narrative context (no codegen impact).
     pub given: Vec<String>,
 
+    /// Theorems that must hold before this one is worth checking. Every
+    /// entry names a theorem loaded from the same source.
+    pub depends_on: Vec<TheoremName>,
+
+    /// Every shared fragment merged through `Include`, including nested
+    /// includes, in merge order. Fragment entries are already merged into
+    /// `forall`, `assume`, and `let_bindings`.
+    pub include: Vec<String>,
+
+    /// Document-local Rust type aliases, already expanded in `forall` and
+    /// `actions`.
+    pub types: IndexMap<String, String>,
+
     /// Symbolic quantified variables mapped to Rust types.
-    pub forall: IndexMap<ForallVar, String>,
+    pub forall: IndexMap<ForallVar, Symbol>,
+
+    /// Value constraints for the `forall` variables declared with a
+    /// structured domain, keyed by variable name.
+    pub forall_domains: IndexMap<ForallVar, ForallDomain>,
 
     /// Expected Rust signatures for referenced theorem actions.
     pub actions: IndexMap<String, ActionSignature>,
@@ -77,17 +123,59 @@ pub struct TheoremDoc {
     /// allowed).
     pub witness: Vec<WitnessCheck>,
 
-    /// Named fixtures and derived constants.
-    pub let_bindings: IndexMap<String, LetBinding>,
+    /// Named fixtures and derived constants, shared between clones of the
+    /// document. Use [`Arc::make_mut`] to edit them.
+    pub let_bindings: Arc<IndexMap<String, LetBinding>>,
 
-    /// Ordered sequence of theorem steps.
-    pub do_steps: Vec<Step>,
+    /// Ordered sequence of theorem steps, shared between clones of the
+    /// document. Use [`Arc::make_mut`] to edit them.
+    pub do_steps: Arc<Vec<Step>>,
 
     /// Proof obligations (must be non-empty).
     pub prove: Vec<Assertion>,
 
     /// Backend evidence configuration.
     pub evidence: Evidence,
+
+    /// Lint levels this theorem sets, keyed by lint name, after the levels
+    /// of a file `Defaults` document. The loader keeps them as written;
+    /// the linter checks the names and levels.
+    pub lints: IndexMap<String, String>,
+}
+
+/// The `Schema` versions this loader understands. Documents declaring any
+/// other version are rejected.
+pub const SUPPORTED_SCHEMA_VERSIONS: RangeInclusive<u32> = 1..=LATEST_SCHEMA_VERSION;
+
+/// The newest `Schema` version. Version 2 keeps the version 1 sections but
+/// accepts only their canonical `TitleCase` keys.
+pub const LATEST_SCHEMA_VERSION: u32 = 2;
+
+impl TheoremDoc {
+    /// Returns the declared `Schema` version, or `1` when it was omitted.
+    #[must_use]
+    pub const fn schema_version(&self) -> u32 {
+        match self.schema {
+            Some(version) => version,
+            None => 1,
+        }
+    }
+}
+
+// ── TraceLink ───────────────────────────────────────────────────────
+
+/// A link from a theorem to an external requirement it provides evidence
+/// for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TraceLink {
+    /// Requirement identifier within `system` (for example `REQ-42`).
+    pub id: String,
+    /// Requirements system or document that owns `id`.
+    pub system: String,
+    /// Optional `http` or `https` link to the requirement.
+    #[serde(default)]
+    pub url: Option<String>,
 }
 
 // ── Assumption ──────────────────────────────────────────────────────
@@ -96,14 +184,13 @@ pub struct TheoremDoc {
 ///
 /// Each assumption provides a Rust expression and a human-readable
 /// explanation of why the constraint is necessary.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Assumption {
-    /// A Rust expression that must hold (parsed as `syn::Expr` in
-    /// later validation stages).
-    pub expr: String,
+    /// A Rust expression that must hold.
+    pub expr: TheoremExpr,
     /// Human-readable justification for this assumption.
-    pub because: String,
+    pub because: Symbol,
 }
 
 // ── Assertion ───────────────────────────────────────────────────────
@@ -111,129 +198,32 @@ pub struct Assumption {
 /// A proof obligation that the theorem must satisfy.
 ///
 /// The `assert` field contains a Rust boolean expression; `because`
-/// provides a human-readable explanation.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+/// provides a human-readable explanation. `step` optionally attaches the
+/// assertion to the `Do` step whose result it describes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Assertion {
     /// A Rust boolean expression to assert.
     #[serde(rename = "assert")]
-    pub assert_expr: String,
+    pub assert_expr: TheoremExpr,
     /// Human-readable justification for this assertion.
-    pub because: String,
+    pub because: Symbol,
+    /// The `id` of the `Do` step this assertion describes, if any.
+    #[serde(default)]
+    pub step: Option<String>,
 }
 
 // ── Witness ─────────────────────────────────────────────────────────
 
 /// A non-vacuity witness that ensures the theorem exercises at least
 /// one meaningful execution path.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct WitnessCheck {
     /// A Rust expression used as a coverage marker.
-    pub cover: String,
+    pub cover: TheoremExpr,
     /// Human-readable justification for this witness.
-    pub because: String,
-}
-
-// ── Let bindings ────────────────────────────────────────────────────
-
-/// A named value binding computed before `Do` steps execute.
-///
-/// Only `call` and `must` forms are allowed in `Let` bindings. The
-/// `maybe` form is disallowed because conditional existence of
-/// bindings creates scoping complexity.
-#[derive(Debug, Clone, PartialEq)]
-pub enum LetBinding {
-    /// Invoke an action and bind the result.
-    Call(LetCall),
-    /// Invoke an action, prove it cannot fail, and bind the unwrapped
-    /// success value.
-    Must(LetMust),
-}
-
-/// Wrapper for a `call` variant in a `Let` binding.
-#[derive(Debug, Clone, PartialEq)]
-pub struct LetCall {
-    /// The action call to execute.
-    pub call: ActionCall,
-}
-
-/// Wrapper for a `must` variant in a `Let` binding.
-#[derive(Debug, Clone, PartialEq)]
-pub struct LetMust {
-    /// The action call to execute and prove infallible.
-    pub must: ActionCall,
-}
-
-// ── Steps ───────────────────────────────────────────────────────────
-
-/// A single step in a theorem's `Do` sequence.
-///
-/// Each step is exactly one of `call` (invoke), `must` (invoke and
-/// prove infallible), or `maybe` (symbolic branching).
-#[derive(Debug, Clone, PartialEq)]
-pub enum Step {
-    /// Invoke an action.
-    Call(StepCall),
-    /// Invoke an action and prove it cannot fail.
-    Must(StepMust),
-    /// Symbolic branching — both branches are explored by the model
-    /// checker.
-    Maybe(StepMaybe),
-}
-
-/// Wrapper for a `call` variant in a `Do` step.
-#[derive(Debug, Clone, PartialEq)]
-pub struct StepCall {
-    /// The action call to execute.
-    pub call: ActionCall,
-}
-
-/// Wrapper for a `must` variant in a `Do` step.
-#[derive(Debug, Clone, PartialEq)]
-pub struct StepMust {
-    /// The action call to execute and prove infallible.
-    pub must: ActionCall,
-}
-
-/// Wrapper for a `maybe` variant in a `Do` step.
-#[derive(Debug, Clone, PartialEq)]
-pub struct StepMaybe {
-    /// The maybe block with a reason and nested steps.
-    pub maybe: MaybeBlock,
-}
-
-// ── Maybe block ─────────────────────────────────────────────────────
-
-/// A symbolic branching block within a `Do` sequence.
-///
-/// The model checker explores both the branch where the nested steps
-/// execute and the branch where they do not.
-#[derive(Debug, Clone, PartialEq)]
-pub struct MaybeBlock {
-    /// Human-readable explanation of why this branch exists.
-    pub because: String,
-    /// The nested steps to execute in the "taken" branch.
-    pub do_steps: Vec<Step>,
-}
-
-// ── Action call ─────────────────────────────────────────────────────
-
-/// An invocation of a theorem action with semantically decoded
-/// arguments and optional result binding.
-///
-/// Arguments are decoded from raw YAML values into [`ArgValue`]
-/// variants during the raw-to-public conversion step. Plain YAML
-/// strings are always string literals; variable references require
-/// the explicit `{ ref: <name> }` wrapper (`TFS-5` section 5.2).
-#[derive(Debug, Clone, PartialEq)]
-pub struct ActionCall {
-    /// Dot-separated action name (e.g., `hnsw.attach_node`).
-    pub action: String,
-    /// Semantically decoded arguments, keyed by parameter name.
-    pub args: IndexMap<String, ArgValue>,
-    /// Optional binding name for the action's return value.
-    pub as_binding: Option<String>,
+    pub because: Symbol,
 }
 
 // ── Action signatures ──────────────────────────────────────────────
@@ -242,15 +232,15 @@ pub struct ActionCall {
 ///
 /// `params` preserves YAML insertion order because generated probes use this
 /// order for the bare function pointer parameter list.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct ActionSignature {
     /// Ordered parameter names and Rust type strings.
     #[serde(default)]
-    pub params: IndexMap<String, String>,
+    pub params: IndexMap<String, Symbol>,
     /// Rust return type. Omitted declarations default to unit.
     #[serde(default = "unit_return_type")]
-    pub returns: String,
+    pub returns: Symbol,
 }
 
 impl ActionSignature {
@@ -289,8 +279,8 @@ fn rust_types_equivalent(left: &str, right: &str) -> bool {
     }
 }
 
-fn unit_return_type() -> String {
-    "()".to_owned()
+fn unit_return_type() -> Symbol {
+    Symbol::new("()")
 }
 
 // ── Evidence ────────────────────────────────────────────────────────
@@ -299,12 +289,16 @@ fn unit_return_type() -> String {
 ///
 /// At least one backend must be specified. For v1, Kani is the primary
 /// backend; `verus` and `stateright` are placeholders for future use.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Evidence {
-    /// Kani model-checking backend configuration.
-    #[serde(default)]
-    pub kani: Option<KaniEvidence>,
+    /// Kani model-checking backend configurations: one per harness variant,
+    /// in declaration order. Empty when the theorem has no Kani evidence.
+    #[serde(
+        default,
+        deserialize_with = "super::raw_evidence::deserialize_one_or_many"
+    )]
+    pub kani: Vec<KaniEvidence>,
     /// Verus proof backend configuration (placeholder).
     #[serde(default)]
     pub verus: Option<TheoremValue>,
@@ -317,43 +311,15 @@ impl Evidence {
     /// Returns `true` if at least one backend is configured.
     #[must_use]
     pub const fn has_any_backend(&self) -> bool {
-        self.kani.is_some() || self.verus.is_some() || self.stateright.is_some()
+        !self.kani.is_empty() || self.verus.is_some() || self.stateright.is_some()
     }
-}
-
-// ── Kani evidence ───────────────────────────────────────────────────
 
-/// Configuration for the Kani model-checking backend.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct KaniEvidence {
-    /// Loop unwinding bound (`#[kani::unwind(n)]`).
-    pub unwind: u32,
-    /// Expected verification outcome.
-    pub expect: KaniExpectation,
-    /// Whether vacuous success is permitted (default: `false`).
-    #[serde(default)]
-    pub allow_vacuous: bool,
-    /// Justification required when `allow_vacuous` is `true`.
-    #[serde(default)]
-    pub vacuity_because: Option<String>,
-}
-
-/// Expected outcome of a Kani verification run.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
-pub enum KaniExpectation {
-    /// The proof harness is expected to succeed.
-    #[serde(rename = "SUCCESS")]
-    Success,
-    /// The proof harness is expected to find a counterexample.
-    #[serde(rename = "FAILURE")]
-    Failure,
-    /// The proof harness is expected to be unreachable.
-    #[serde(rename = "UNREACHABLE")]
-    Unreachable,
-    /// The verification outcome is undetermined.
-    #[serde(rename = "UNDETERMINED")]
-    Undetermined,
+    /// Returns the smallest Kani `unwind` bound across all configurations,
+    /// which is the bound every harness variant is guaranteed to have.
+    #[must_use]
+    pub fn min_kani_unwind(&self) -> Option<u32> {
+        self.kani.iter().map(|kani| kani.unwind).min()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,168 @@
+//! Kani backend evidence types for theorem documents.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::kani_limits::{KaniTimeout, MemoryLimit};
+use crate::schema::value::TheoremValue;
+
+// ── Kani evidence ───────────────────────────────────────────────────
+
+/// Configuration for the Kani model-checking backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KaniEvidence {
+    /// Configuration name, required when `Evidence.kani` lists several
+    /// configurations; a named configuration's harness gains a `__<name>`
+    /// suffix.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Global loop unwinding bound (`#[kani::unwind(n)]`).
+    pub unwind: u32,
+    /// Per-loop unwinding bounds keyed by loop label, in declaration order.
+    /// Kani has no per-loop attribute and no runner passes these on yet, so
+    /// the generated harness carries only the global bound; callers can hand
+    /// them to CBMC themselves via [`KaniEvidence::unwindset`].
+    #[serde(default)]
+    pub loop_unwind: IndexMap<String, u32>,
+    /// Expected verification outcome.
+    pub expect: KaniExpectation,
+    /// Whether vacuous success is permitted (default: `false`).
+    #[serde(default)]
+    pub allow_vacuous: bool,
+    /// Justification required when `allow_vacuous` is `true`.
+    #[serde(default)]
+    pub vacuity_because: Option<String>,
+    /// SAT solver for the harness (`#[kani::solver(...)]`); Kani's default
+    /// when absent.
+    #[serde(default)]
+    pub solver: Option<KaniSolver>,
+    /// Extra `cargo kani` flags for the harness, restricted to an allowlist
+    /// at load time.
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// Wall-clock limit for verifying the harness; unlimited when absent.
+    #[serde(default)]
+    pub timeout: Option<KaniTimeout>,
+    /// Memory ceiling for verifying the harness; unlimited when absent.
+    #[serde(default)]
+    pub memory_limit: Option<MemoryLimit>,
+    /// Functions replaced for this harness (`#[kani::stub(...)]`), in
+    /// declaration order.
+    #[serde(default)]
+    pub stubs: Vec<KaniStub>,
+    /// Functions whose Kani contracts the theorem verifies; each yields a
+    /// `#[kani::proof_for_contract(...)]` harness instead of a plain proof.
+    #[serde(default)]
+    pub contracts: Vec<String>,
+    /// Constraints the counterexample of an `expect: FAILURE` run must meet,
+    /// keyed by `Forall` variable, so the theorem cannot pass by failing for
+    /// the wrong reason.
+    #[serde(default)]
+    pub counterexample: IndexMap<String, CounterexampleConstraint>,
+    /// Whether counterexamples are replayed as plain unit tests through
+    /// Kani's concrete playback (default: `false`).
+    #[serde(default)]
+    pub playback: bool,
+    /// Whether the harness is verified in Kani's coverage mode to record
+    /// which `Prove` assertions are reachable (default: `false`).
+    #[serde(default)]
+    pub coverage: bool,
+}
+
+impl KaniEvidence {
+    /// Returns the per-loop bounds as a CBMC `--unwindset` value, e.g.
+    /// `retry_loop:16,drain:4`, or `None` when every loop uses the global
+    /// bound. Nothing in theoremc passes this value to CBMC yet.
+    #[must_use]
+    pub fn unwindset(&self) -> Option<String> {
+        if self.loop_unwind.is_empty() {
+            return None;
+        }
+        Some(
+            self.loop_unwind
+                .iter()
+                .map(|(label, bound)| format!("{label}:{bound}"))
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+/// One `Evidence.kani.stubs` entry replacing a function during verification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KaniStub {
+    /// Path of the function to replace, e.g. `std::time::SystemTime::now`.
+    pub original: String,
+    /// Path of the replacement function.
+    pub stub: String,
+}
+
+/// Expected value of one `Forall` variable in a Kani counterexample.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CounterexampleConstraint {
+    /// The variable's value must satisfy a Rust boolean expression over the
+    /// theorem's `Forall` variables, e.g. `{ satisfies: "amount > balance" }`.
+    Satisfies {
+        /// The predicate expression.
+        satisfies: String,
+    },
+    /// The variable must take exactly this scalar value.
+    Equals(TheoremValue),
+}
+
+/// SAT solver Kani uses to discharge a harness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KaniSolver {
+    /// The `CaDiCaL` solver.
+    Cadical,
+    /// The Kissat solver.
+    Kissat,
+    /// The `MiniSat` solver.
+    Minisat,
+}
+
+impl KaniSolver {
+    /// Returns the solver name as written in `#[kani::solver(...)]`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cadical => "cadical",
+            Self::Kissat => "kissat",
+            Self::Minisat => "minisat",
+        }
+    }
+}
+
+/// Expected outcome of a Kani verification run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KaniExpectation {
+    /// The proof harness is expected to succeed.
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /// The proof harness is expected to find a counterexample.
+    #[serde(rename = "FAILURE")]
+    Failure,
+    /// The proof harness is expected to be unreachable.
+    #[serde(rename = "UNREACHABLE")]
+    Unreachable,
+    /// The verification outcome is undetermined.
+    #[serde(rename = "UNDETERMINED")]
+    Undetermined,
+}
+
+impl KaniExpectation {
+    /// Returns the expectation as written in `Evidence.kani.expect`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "SUCCESS",
+            Self::Failure => "FAILURE",
+            Self::Unreachable => "UNREACHABLE",
+            Self::Undetermined => "UNDETERMINED",
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! `Let` binding, `Do` step, and action call types for theorem documents.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::schema::arg_value::ArgValue;
+use crate::schema::symbol::Symbol;
+
+// ── Let bindings ────────────────────────────────────────────────────
+
+/// A named value binding computed before `Do` steps execute.
+///
+/// Only `call` and `must` forms are allowed in `Let` bindings. The
+/// `maybe` form is disallowed because conditional existence of
+/// bindings creates scoping complexity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LetBinding {
+    /// Invoke an action and bind the result.
+    Call(LetCall),
+    /// Invoke an action, prove it cannot fail, and bind the unwrapped
+    /// success value.
+    Must(LetMust),
+}
+
+/// Wrapper for a `call` variant in a `Let` binding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LetCall {
+    /// The action call to execute.
+    pub call: ActionCall,
+}
+
+/// Wrapper for a `must` variant in a `Let` binding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LetMust {
+    /// The action call to execute and prove infallible.
+    pub must: ActionCall,
+}
+
+// ── Steps ───────────────────────────────────────────────────────────
+
+/// A single step in a theorem's `Do` sequence.
+///
+/// Each step is exactly one of `call` (invoke), `must` (invoke and
+/// prove infallible), `expect_err` (invoke and prove it fails), `maybe`
+/// (symbolic branching), `repeat` (bounded iteration), `foreach` (iteration
+/// over a declared sequence), or `assert` (check an intermediate condition).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Step {
+    /// Invoke an action.
+    Call(StepCall),
+    /// Invoke an action and prove it cannot fail.
+    Must(StepMust),
+    /// Invoke an action and prove it returns a named error variant.
+    ExpectErr(StepExpectErr),
+    /// Symbolic branching — both branches are explored by the model
+    /// checker.
+    Maybe(StepMaybe),
+    /// Run nested steps a fixed number of times.
+    Repeat(StepRepeat),
+    /// Run nested steps once for each element of a declared sequence.
+    Foreach(StepForeach),
+    /// Assert a condition at this point in the sequence.
+    Assert(StepAssert),
+}
+
+impl Step {
+    /// Returns the YAML key that introduces this step (e.g., `must`).
+    #[must_use]
+    pub const fn keyword(&self) -> &'static str {
+        match self {
+            Self::Call(_) => "call",
+            Self::Must(_) => "must",
+            Self::ExpectErr(_) => "expect_err",
+            Self::Maybe(_) => "maybe",
+            Self::Repeat(_) => "repeat",
+            Self::Foreach(_) => "foreach",
+            Self::Assert(_) => "assert",
+        }
+    }
+
+    /// Returns the identifier a `Prove` assertion can use to refer to this
+    /// step, if the step declares one. Only `call` and `must` steps, whose
+    /// results assertions describe, carry an `id`.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::Call(step) => step.id.as_deref(),
+            Self::Must(step) => step.id.as_deref(),
+            Self::ExpectErr(_)
+            | Self::Maybe(_)
+            | Self::Repeat(_)
+            | Self::Foreach(_)
+            | Self::Assert(_) => None,
+        }
+    }
+
+    /// Returns the name this step binds its result to, if any. Only `call`
+    /// and `must` steps bind a result with `as`; a `foreach` loop variable
+    /// is scoped to the loop and is not reported here.
+    #[must_use]
+    pub fn as_binding(&self) -> Option<&str> {
+        match self {
+            Self::Call(step) => step.call.as_binding.as_deref(),
+            Self::Must(step) => step.must.as_binding.as_deref(),
+            Self::ExpectErr(_)
+            | Self::Maybe(_)
+            | Self::Repeat(_)
+            | Self::Foreach(_)
+            | Self::Assert(_) => None,
+        }
+    }
+
+    /// Returns the step lists nested inside a `maybe`, `repeat`, or
+    /// `foreach` block, each paired with the YAML key that introduces it
+    /// (`do` or `else`). Every other step has none.
+    #[must_use]
+    pub fn nested_branches(&self) -> Vec<(&'static str, &[Self])> {
+        match self {
+            Self::Maybe(step) => vec![
+                ("do", &step.maybe.do_steps),
+                ("else", &step.maybe.else_steps),
+            ],
+            Self::Repeat(step) => vec![("do", &step.repeat.do_steps)],
+            Self::Foreach(step) => vec![("do", &step.foreach.do_steps)],
+            Self::Call(_) | Self::Must(_) | Self::ExpectErr(_) | Self::Assert(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the smallest Kani `unwind` that every `repeat` loop in
+    /// `steps`, nested loops included, fits within, or 1 when there are
+    /// none. Kani bounds each loop separately, so this is the largest
+    /// [`RepeatBlock::required_unwind`] rather than a sum.
+    #[must_use]
+    pub fn required_unwind(steps: &[Self]) -> u64 {
+        steps
+            .iter()
+            .flat_map(|step| {
+                let own = match step {
+                    Self::Repeat(repeat) => repeat.repeat.required_unwind(),
+                    _ => 1,
+                };
+                let nested = step
+                    .nested_branches()
+                    .into_iter()
+                    .map(|(_, nested)| Self::required_unwind(nested));
+                std::iter::once(own).chain(nested)
+            })
+            .max()
+            .unwrap_or(1)
+    }
+}
+
+/// Wrapper for a `call` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepCall {
+    /// The action call to execute.
+    pub call: ActionCall,
+    /// Optional identifier that `Prove` assertions use to refer to this
+    /// step.
+    pub id: Option<String>,
+}
+
+/// Wrapper for a `must` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepMust {
+    /// The action call to execute and prove infallible.
+    pub must: ActionCall,
+    /// Optional identifier that `Prove` assertions use to refer to this
+    /// step.
+    pub id: Option<String>,
+}
+
+/// Wrapper for an `expect_err` variant in a `Do` step, the dual of
+/// [`StepMust`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepExpectErr {
+    /// The action call to execute and prove fallible. A failing call has no
+    /// value to bind, so `as_binding` is always `None`.
+    pub expect_err: ActionCall,
+    /// Name of the error variant the call must return (e.g.,
+    /// `InsufficientFunds`).
+    pub error: String,
+}
+
+/// Wrapper for a `maybe` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepMaybe {
+    /// The maybe block with a reason and nested steps.
+    pub maybe: MaybeBlock,
+}
+
+/// Wrapper for a `repeat` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepRepeat {
+    /// The repeat block with an iteration count and nested steps.
+    pub repeat: RepeatBlock,
+}
+
+/// Wrapper for a `foreach` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepForeach {
+    /// The foreach block with its sequence, loop variable, and nested steps.
+    pub foreach: ForeachBlock,
+}
+
+/// Wrapper for an `assert` variant in a `Do` step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepAssert {
+    /// The condition to check and its justification.
+    pub assert: InlineAssert,
+}
+
+/// A condition checked between `Do` steps.
+///
+/// Unlike a `Prove` assertion, which holds once every step has run, an
+/// inline assertion holds at its position in the sequence and may read the
+/// `as` bindings of the steps before it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InlineAssert {
+    /// A Rust boolean expression to assert.
+    pub expr: String,
+    /// Human-readable justification for this assertion.
+    pub because: Symbol,
+}
+
+// ── Maybe block ─────────────────────────────────────────────────────
+
+/// A symbolic branching block within a `Do` sequence.
+///
+/// The model checker explores both the branch where the `do` steps
+/// execute and the branch where the `else` steps execute instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaybeBlock {
+    /// Human-readable explanation of why this branch exists.
+    pub because: Symbol,
+    /// The nested steps to execute in the "taken" branch.
+    pub do_steps: Vec<Step>,
+    /// The nested steps to execute in the "not taken" branch. Empty when
+    /// the block has no `else`.
+    pub else_steps: Vec<Step>,
+}
+
+// ── Repeat block ────────────────────────────────────────────────────
+
+/// A bounded loop within a `Do` sequence.
+///
+/// The nested steps run `times` times in order, so the Kani `unwind` bound
+/// must exceed `times`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepeatBlock {
+    /// Number of iterations (must be positive).
+    pub times: u32,
+    /// The nested steps to execute on each iteration.
+    pub do_steps: Vec<Step>,
+}
+
+impl RepeatBlock {
+    /// Returns the Kani `unwind` bound this loop needs: one more than
+    /// `times`, so Kani can prove the loop exits.
+    #[must_use]
+    pub fn required_unwind(&self) -> u64 {
+        u64::from(self.times) + 1
+    }
+}
+
+// ── Foreach block ───────────────────────────────────────────────────
+
+/// An iteration over a declared sequence within a `Do` sequence.
+///
+/// The nested steps run once per element of the `Let` binding or `Forall`
+/// variable named by `source`, with the element bound to `as_binding`.
+/// Code generation unrolls the loop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeachBlock {
+    /// Name of the `Let` binding or `Forall` variable to iterate over.
+    pub source: String,
+    /// Loop variable bound to each element, visible only to the nested
+    /// steps.
+    pub as_binding: String,
+    /// The nested steps to execute for each element.
+    pub do_steps: Vec<Step>,
+}
+
+// ── Action call ─────────────────────────────────────────────────────
+
+/// An invocation of a theorem action with semantically decoded
+/// arguments and optional result binding.
+///
+/// Arguments are decoded from raw YAML values into [`ArgValue`]
+/// variants during the raw-to-public conversion step. Plain YAML
+/// strings are always string literals; variable references require
+/// the explicit `{ ref: <name> }` wrapper (`TFS-5` section 5.2).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionCall {
+    /// Dot-separated action name (e.g., `hnsw.attach_node`).
+    pub action: Symbol,
+    /// Semantically decoded arguments, keyed by parameter name.
+    pub args: IndexMap<String, ArgValue>,
+    /// Optional binding name for the action's return value.
+    pub as_binding: Option<String>,
+}
@@ -7,11 +7,11 @@ use super::ActionSignature;
 fn signature(params: &[(&str, &str)], returns: &str) -> ActionSignature {
     let mut map = IndexMap::new();
     for (name, ty) in params {
-        map.insert((*name).to_owned(), (*ty).to_owned());
+        map.insert((*name).to_owned(), (*ty).into());
     }
     ActionSignature {
         params: map,
-        returns: returns.to_owned(),
+        returns: returns.into(),
     }
 }
 
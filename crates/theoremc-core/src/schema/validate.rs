@@ -3,8 +3,11 @@
 //! These checks enforce constraints that `serde` attributes cannot express,
 //! such as "non-empty after trimming" and "at least one evidence backend".
 //! The entry point is [`validate_theorem_doc`], called by the loader after
-//! successful YAML deserialization.
+//! successful YAML deserialization; [`TheoremDoc::validate`] runs the same
+//! checks on documents that did not come from YAML.
 
+use super::error::SchemaError;
+use super::type_alias::expand_type_aliases;
 use super::types::TheoremDoc;
 use super::validation_reason::{ValidationFailure, ValidationReasonKind};
 
@@ -12,24 +15,33 @@ use super::validation_reason::{ValidationFailure, ValidationReasonKind};
 mod actions;
 #[path = "validate_evidence.rs"]
 mod evidence;
-#[path = "validate_expressions.rs"]
-mod expressions;
 #[path = "validate_fields.rs"]
 mod fields;
+#[path = "validate_names.rs"]
+mod names;
 #[path = "validate_steps.rs"]
 mod steps;
 #[path = "validate_types.rs"]
 mod types;
 
-use actions::{validate_action_signatures, validate_referenced_action_signatures};
+use actions::{
+    validate_action_signatures, validate_expect_err_signatures,
+    validate_referenced_action_signatures,
+};
 use evidence::validate_evidence;
-use expressions::validate_expressions;
 use fields::{
     validate_about, validate_assertions, validate_assumptions, validate_prove_non_empty,
-    validate_witnesses,
+    validate_schema_version, validate_traces, validate_witnesses,
+};
+pub(crate) use names::arg_variables;
+use names::{validate_as_bindings, validate_expression_names, validate_step_ids};
+use steps::{
+    validate_do_steps, validate_foreach_depth, validate_let_bindings, validate_repeat_unwind,
+};
+use types::{
+    validate_forall_derivations, validate_forall_domains, validate_forall_types,
+    validate_type_aliases,
 };
-use steps::{validate_do_steps, validate_let_bindings};
-use types::validate_forall_types;
 
 type ValidationResult = Result<(), ValidationFailure>;
 
@@ -50,21 +62,47 @@ fn fail(
 /// Validates a deserialized theorem document against semantic constraints that
 /// `serde` attributes cannot express.
 ///
+/// Expression fields (`Assume.expr`, `Prove.assert`, `Witness.cover`) need
+/// no check here: a [`TheoremExpr`](super::TheoremExpr) only holds a single,
+/// non-statement `syn::Expr`, so the loader and the builder reject the rest
+/// as they construct the document.
+///
 /// Checks applied in order:
 ///
+/// - `Schema`, when declared, is one of `SUPPORTED_SCHEMA_VERSIONS`.
 /// - `About` is non-empty after trimming.
+/// - All `Traces` entries have non-blank `id` and `system` fields, an
+///   `http` or `https` `url` when present, and no repeated requirement.
 /// - `Prove` contains at least one assertion.
 /// - All `Assertion` fields are non-empty after trimming.
 /// - All `Assumption` fields are non-empty after trimming.
 /// - All `WitnessCheck` fields are non-empty after trimming.
-/// - All expression fields (`Assume.expr`, `Prove.assert`, `Witness.cover`)
-///   parse as `syn::Expr` and are not statement-like forms.
+/// - All `Types` alias names are valid identifiers, alias types parse as
+///   `syn::Type`, and aliases only reference aliases declared before them.
 /// - All `Forall` type strings parse as `syn::Type` and avoid free named
 ///   lifetime parameters.
+/// - Structured `Forall` bounds suit a primitive numeric type, fit its range,
+///   and keep `min` no greater than `max`; `oneof` values are distinct and fit
+///   the type; struct fields are named and typed; `vec` lengths are ordered
+///   and fit within Kani `unwind`.
+/// - Derived `Forall` `from` expressions parse as `syn::Expr` and refer only
+///   to variables declared before them.
 /// - All `Let` binding and `Do` step `ActionCall.action` fields are non-empty
 ///   after trimming.
 /// - All `MaybeBlock.because` fields are non-empty after trimming and
 ///   `MaybeBlock.do` lists are non-empty.
+/// - `repeat` steps have a positive `times`, a non-empty `do` list, and fit
+///   within Kani `unwind` (`times + 1` unwindings per loop).
+/// - `foreach` steps name their sequence and loop variable with distinct Rust
+///   identifiers, have a non-empty `do` list, and nest at most three deep.
+/// - `expect_err` steps name their error variant with a Rust identifier and
+///   call actions whose signatures return a `Result`.
+/// - Every variable an expression reads is a `Forall` variable, a `Let`
+///   binding, or a `Do` step `as` binding; derived `Forall` expressions read
+///   only `Forall` variables; `foreach` steps iterate a declared name and
+///   bind a fresh loop variable.
+/// - `Do` step `id` values are Rust identifiers, unique across the sequence,
+///   and every `Prove` assertion's `step` names one of them.
 /// - At least one evidence backend is specified.
 /// - Kani `unwind` is positive.
 /// - Kani `vacuity_because` is non-empty after trimming when present.
@@ -76,19 +114,95 @@ fn fail(
 /// Returns [`ValidationFailure`] with the theorem name, deterministic reason
 /// string, and typed diagnostic reason on the first constraint violation.
 pub(crate) fn validate_theorem_doc(doc: &TheoremDoc) -> ValidationResult {
-    validate_about(doc)?;
-    validate_prove_non_empty(doc)?;
-    validate_assertions(doc)?;
-    validate_assumptions(doc)?;
-    validate_witnesses(doc)?;
-    validate_expressions(doc)?;
-    validate_action_signatures(doc)?;
-    validate_forall_types(doc)?;
-    validate_let_bindings(doc)?;
-    validate_do_steps(doc)?;
-    validate_referenced_action_signatures(doc)?;
-    validate_evidence(doc)?;
-    Ok(())
+    CHECKS.iter().try_for_each(|check| check(doc))
+}
+
+/// Runs every check in [`validate_theorem_doc`] order and collects the first
+/// violation each one finds.
+fn collect_validation_failures(doc: &TheoremDoc) -> Vec<ValidationFailure> {
+    CHECKS.iter().filter_map(|check| check(doc).err()).collect()
+}
+
+/// The semantic checks, in the order the loader applies them.
+const CHECKS: [fn(&TheoremDoc) -> ValidationResult; 22] = [
+    validate_schema_version,
+    validate_about,
+    validate_traces,
+    validate_prove_non_empty,
+    validate_assertions,
+    validate_assumptions,
+    validate_witnesses,
+    validate_type_aliases,
+    validate_action_signatures,
+    validate_forall_types,
+    validate_forall_domains,
+    validate_forall_derivations,
+    validate_let_bindings,
+    validate_do_steps,
+    validate_repeat_unwind,
+    validate_foreach_depth,
+    validate_as_bindings,
+    validate_expression_names,
+    validate_step_ids,
+    validate_referenced_action_signatures,
+    validate_expect_err_signatures,
+    validate_evidence,
+];
+
+impl TheoremDoc {
+    /// Re-checks the document against the semantic constraints the loader
+    /// enforces, without a YAML round trip.
+    ///
+    /// Use this for documents assembled in code or deserialized from another
+    /// format. Type aliases are expanded on a copy before the checks run, as
+    /// the loader expands them before validating. Every check runs, so the
+    /// result lists one error for each check that fails rather than only the
+    /// first; a single mistake can still be reported by more than one check.
+    ///
+    /// # Errors
+    ///
+    /// Returns one [`SchemaError::ValidationFailed`] per failing check, in
+    /// the order the loader applies the checks. The errors carry no source
+    /// diagnostic, because the document has no source.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::load_theorem_docs;
+    ///
+    ///     let yaml = r#"
+    ///     Theorem: Revalidated
+    ///     About: Checked again after editing
+    ///     Forall:
+    ///       x: u8
+    ///     Prove:
+    ///       - assert: "x <= 255"
+    ///         because: u8 is bounded
+    ///     Evidence:
+    ///       kani: { unwind: 1, expect: SUCCESS }
+    ///     Witness:
+    ///       - cover: "x == 0"
+    ///         because: zero is reachable
+    ///     "#;
+    ///     let mut doc = load_theorem_docs(yaml).unwrap().remove(0);
+    ///     assert!(doc.validate().is_ok());
+    ///
+    ///     doc.about = "  ".to_owned();
+    ///     doc.prove.clear();
+    ///     let errors = doc.validate().unwrap_err();
+    ///     assert_eq!(errors.len(), 2);
+    pub fn validate(&self) -> Result<(), Vec<SchemaError>> {
+        let mut doc = self.clone();
+        expand_type_aliases(&mut doc);
+        let errors: Vec<SchemaError> = collect_validation_failures(&doc)
+            .into_iter()
+            .map(|failure| failure.into_schema_error(None))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,7 +4,8 @@ use super::{ValidationResult, fail};
 use crate::collision::referenced_actions;
 use crate::schema::action_name::validate_canonical_action_name;
 use crate::schema::identifier::validate_identifier;
-use crate::schema::types::TheoremDoc;
+use crate::schema::types::{Step, TheoremDoc};
+use crate::schema::{rust_type, step};
 
 use super::types::validate_type_without_free_named_lifetime;
 
@@ -47,3 +48,42 @@ pub(super) fn validate_referenced_action_signatures(doc: &TheoremDoc) -> Validat
     }
     Ok(())
 }
+
+/// Every `expect_err` step must call an action whose `Actions` signature
+/// returns a `Result`, since only a `Result` can carry the named error.
+pub(super) fn validate_expect_err_signatures(doc: &TheoremDoc) -> ValidationResult {
+    validate_expect_err_steps(doc, &doc.do_steps, "Do step")
+}
+
+fn validate_expect_err_steps(doc: &TheoremDoc, steps: &[Step], path: &str) -> ValidationResult {
+    for (index, step) in steps.iter().enumerate() {
+        match step {
+            Step::ExpectErr(expect_err) => {
+                let action = expect_err.expect_err.action.as_str();
+                let Some(signature) = doc.actions.get(action) else {
+                    continue;
+                };
+                if !rust_type::is_result(&signature.returns) {
+                    return Err(fail(
+                        doc,
+                        format!(
+                            "{path} {}: expect_err action '{action}' must return a Result, \
+                             but its Actions signature returns '{}'",
+                            index + 1,
+                            signature.returns.trim()
+                        ),
+                        None,
+                    ));
+                }
+            }
+            Step::Maybe(_) | Step::Repeat(_) | Step::Foreach(_) => {
+                for (branch, nested) in step.nested_branches() {
+                    let nested_path = step::nested_step_path(path, index + 1, step, branch);
+                    validate_expect_err_steps(doc, nested, &nested_path)?;
+                }
+            }
+            Step::Call(_) | Step::Must(_) | Step::Assert(_) => {}
+        }
+    }
+    Ok(())
+}
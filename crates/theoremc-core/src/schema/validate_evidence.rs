@@ -1,12 +1,36 @@
 //! Evidence backend policy validation.
 
+use quote::ToTokens;
+
 use super::{ValidationResult, fail, is_blank};
-use crate::schema::types::{KaniEvidence, TheoremDoc};
-use crate::schema::validation_reason::ValidationReasonKind;
+use crate::schema::expr;
+use crate::schema::expr_scope::referenced_variables;
+use crate::schema::types::{
+    CounterexampleConstraint, KaniEvidence, KaniExpectation, KaniStub, TheoremDoc,
+};
+use crate::schema::validation_reason::{KaniReason, ValidationReasonKind};
+use crate::schema::value::TheoremValue;
+
+/// Kani command-line flags `extra_args` may pass through, with the values each
+/// accepts (`None` for switches that take no value). Flags that select
+/// harnesses, move build output, weaken checks, or forward raw solver
+/// arguments are deliberately absent; the solver has its own `solver` field.
+const KANI_EXTRA_ARGS: &[(&str, Option<&[&str]>)] = &[
+    ("--verbose", None),
+    ("--quiet", None),
+    ("--keep-temps", None),
+    ("--output-format", Some(&["regular", "terse", "old"])),
+    ("--concrete-playback", Some(&["print"])),
+];
 
-/// Evidence section must specify at least one backend, and Kani evidence must
-/// satisfy unwind, vacuity, and witness constraints (`TFS-6` section 6.2,
-/// `ADR-4`).
+/// Failure message and reason for one Kani configuration check.
+type KaniFailure = (String, KaniReason);
+
+/// Evidence section must specify at least one backend, every Kani
+/// configuration must satisfy unwind, vacuity, witness, extra-flag, stub,
+/// contract, and counterexample constraints (`TFS-6` section 6.2, `ADR-4`)
+/// without combining contradictory options, and several configurations must
+/// carry unique names.
 pub(super) fn validate_evidence(doc: &TheoremDoc) -> ValidationResult {
     if !doc.evidence.has_any_backend() {
         return Err(fail(
@@ -20,22 +44,102 @@ pub(super) fn validate_evidence(doc: &TheoremDoc) -> ValidationResult {
         ));
     }
 
-    if let Some(kani) = &doc.evidence.kani {
-        validate_kani_unwind(doc, kani)?;
-        validate_kani_vacuity(doc, kani)?;
-        validate_kani_witnesses(doc, kani)?;
+    let configs = &doc.evidence.kani;
+    for (config, kani) in configs.iter().enumerate() {
+        validate_kani_name(configs, config, kani)
+            .and_then(|()| validate_kani_config(doc, kani))
+            .map_err(|(message, reason)| {
+                let rendered = match &kani.name {
+                    Some(name) => format!("Evidence.kani configuration '{name}': {message}"),
+                    None => message,
+                };
+                fail(
+                    doc,
+                    rendered,
+                    Some(ValidationReasonKind::Kani { config, reason }),
+                )
+            })?;
     }
 
     Ok(())
 }
 
-/// Kani `unwind` must be a positive integer (`TFS-6` section 6.2).
-fn validate_kani_unwind(doc: &TheoremDoc, kani: &KaniEvidence) -> ValidationResult {
+fn validate_kani_config(doc: &TheoremDoc, kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    validate_kani_unwind(kani)?;
+    validate_kani_vacuity(kani)?;
+    validate_kani_witnesses(doc, kani)?;
+    validate_kani_extra_args(kani)?;
+    validate_kani_stubs(kani)?;
+    validate_kani_contracts(kani)?;
+    validate_kani_counterexample(doc, kani)?;
+    conflicts::validate_kani_conflicts(doc, kani)
+}
+
+/// A configuration name must be a lower snake-case identifier, since it
+/// suffixes the harness name, and must not repeat an earlier name. When
+/// `Evidence.kani` lists several configurations, each must be named.
+fn validate_kani_name(
+    configs: &[KaniEvidence],
+    config: usize,
+    kani: &KaniEvidence,
+) -> Result<(), KaniFailure> {
+    let Some(name) = kani.name.as_deref() else {
+        if configs.len() > 1 {
+            return Err((
+                format!(
+                    "Evidence.kani entry {}: name is required when several Kani \
+                     configurations are listed",
+                    config + 1
+                ),
+                KaniReason::Name,
+            ));
+        }
+        return Ok(());
+    };
+    let failure = if is_snake_name(name) {
+        configs
+            .iter()
+            .take(config)
+            .position(|earlier| earlier.name.as_deref() == Some(name))
+            .map(|first| format!("name is already used by entry {}", first + 1))
+    } else {
+        Some("name must be a lower snake-case identifier (e.g., 'nightly_deep')".to_owned())
+    };
+    failure.map_or(Ok(()), |reason| Err((reason, KaniReason::Name)))
+}
+
+fn is_snake_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !name.contains("__")
+        && !name.ends_with('_')
+}
+
+/// Kani `unwind` must be a positive integer (`TFS-6` section 6.2), as must
+/// every per-loop bound. Loop labels are joined into a CBMC `--unwindset`
+/// list, so they must be non-empty and free of whitespace, `:`, and `,`.
+fn validate_kani_unwind(kani: &KaniEvidence) -> Result<(), KaniFailure> {
     if kani.unwind == 0 {
-        return Err(fail(
-            doc,
+        return Err((
             "Evidence.kani.unwind must be a positive integer (> 0)".to_owned(),
-            Some(ValidationReasonKind::KaniUnwind),
+            KaniReason::Unwind,
+        ));
+    }
+    for (label, &bound) in &kani.loop_unwind {
+        let reason = if label.is_empty()
+            || label.contains(|c: char| c.is_whitespace() || c == ':' || c == ',')
+        {
+            "label must be non-empty and must not contain whitespace, ':' or ','"
+        } else if bound == 0 {
+            "bound must be a positive integer (> 0)"
+        } else {
+            continue;
+        };
+        return Err((
+            format!("Evidence.kani.unwind loop '{label}': {reason}"),
+            KaniReason::Unwind,
         ));
     }
     Ok(())
@@ -44,24 +148,22 @@ fn validate_kani_unwind(doc: &TheoremDoc, kani: &KaniEvidence) -> ValidationResu
 /// Kani vacuity policy: `allow_vacuous: true` requires a non-empty
 /// `vacuity_because`; when present, `vacuity_because` must be non-empty
 /// regardless of `allow_vacuous` (`ADR-4`).
-fn validate_kani_vacuity(doc: &TheoremDoc, kani: &KaniEvidence) -> ValidationResult {
+fn validate_kani_vacuity(kani: &KaniEvidence) -> Result<(), KaniFailure> {
     let requires_reason = kani.allow_vacuous;
     let has_reason = kani.vacuity_because.is_some();
     let reason_is_blank = kani.vacuity_because.as_deref().is_some_and(is_blank);
 
     if requires_reason && !has_reason {
-        return Err(fail(
-            doc,
+        return Err((
             "vacuity_because is required when allow_vacuous is true".to_owned(),
-            Some(ValidationReasonKind::KaniAllowVacuousRequired),
+            KaniReason::AllowVacuousRequired,
         ));
     }
 
     if has_reason && reason_is_blank {
-        return Err(fail(
-            doc,
+        return Err((
             "Evidence.kani.vacuity_because must be non-empty after trimming".to_owned(),
-            Some(ValidationReasonKind::KaniVacuityBecauseNonEmpty),
+            KaniReason::VacuityBecauseNonEmpty,
         ));
     }
 
@@ -70,18 +172,181 @@ fn validate_kani_vacuity(doc: &TheoremDoc, kani: &KaniEvidence) -> ValidationRes
 
 /// Kani non-vacuity default: `Witness` section must contain at least one
 /// witness when `allow_vacuous` is false (`ADR-4`).
-fn validate_kani_witnesses(doc: &TheoremDoc, kani: &KaniEvidence) -> ValidationResult {
+fn validate_kani_witnesses(doc: &TheoremDoc, kani: &KaniEvidence) -> Result<(), KaniFailure> {
     if !kani.allow_vacuous && doc.witness.is_empty() {
-        return Err(fail(
-            doc,
+        return Err((
             concat!(
                 "Witness section must contain at least one ",
                 "witness when allow_vacuous is false ",
                 "(the default)",
             )
             .to_owned(),
-            Some(ValidationReasonKind::KaniWitnessRequired),
+            KaniReason::WitnessRequired,
+        ));
+    }
+    Ok(())
+}
+
+/// Every Kani `extra_args` entry must be an allowlisted flag, written as
+/// `--flag` or `--flag=value` with an accepted value.
+fn validate_kani_extra_args(kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    for (index, arg) in kani.extra_args.iter().enumerate() {
+        if !is_allowed_kani_arg(arg) {
+            return Err((
+                format!(
+                    "Evidence.kani.extra_args entry {}: '{arg}' is not an allowed Kani flag; \
+                     allowed flags are {}",
+                    index + 1,
+                    allowed_kani_args()
+                ),
+                KaniReason::ExtraArg { index },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Every Kani `stubs` entry must name two Rust paths, and each function may be
+/// stubbed at most once per harness.
+fn validate_kani_stubs(kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    for (index, stub) in kani.stubs.iter().enumerate() {
+        let failure = stub_path_reason(stub).or_else(|| {
+            kani.stubs
+                .iter()
+                .take(index)
+                .any(|earlier| same_path(&earlier.original, &stub.original))
+                .then(|| format!("'{}' is already stubbed", stub.original.trim()))
+        });
+        if let Some(reason) = failure {
+            return Err((
+                format!("Evidence.kani.stubs entry {}: {reason}", index + 1),
+                KaniReason::Stub { index },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Every Kani `contracts` entry must name a Rust path, and each function's
+/// contract may be verified at most once per theorem.
+fn validate_kani_contracts(kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    for (index, contract) in kani.contracts.iter().enumerate() {
+        let failure = path_reason("function", contract).or_else(|| {
+            kani.contracts
+                .iter()
+                .take(index)
+                .any(|earlier| same_path(earlier, contract))
+                .then(|| format!("'{}' is already listed", contract.trim()))
+        });
+        if let Some(reason) = failure {
+            return Err((
+                format!("Evidence.kani.contracts entry {}: {reason}", index + 1),
+                KaniReason::Contract { index },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A Kani `counterexample` block only makes sense when the run is expected to
+/// fail, and each entry must constrain a `Forall` variable with a scalar value
+/// or a predicate over `Forall` variables.
+fn validate_kani_counterexample(doc: &TheoremDoc, kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    if kani.counterexample.is_empty() {
+        return Ok(());
+    }
+    if kani.expect != KaniExpectation::Failure {
+        return Err((
+            "Evidence.kani.counterexample is only allowed when expect is FAILURE".to_owned(),
+            KaniReason::Counterexample,
         ));
     }
+    for (variable, constraint) in &kani.counterexample {
+        if let Some(reason) = counterexample_reason(doc, variable, constraint) {
+            return Err((
+                format!("Evidence.kani.counterexample variable '{variable}': {reason}"),
+                KaniReason::Counterexample,
+            ));
+        }
+    }
     Ok(())
 }
+
+fn counterexample_reason(
+    doc: &TheoremDoc,
+    variable: &str,
+    constraint: &CounterexampleConstraint,
+) -> Option<String> {
+    if !doc.forall.contains_key(variable) {
+        return Some("not declared in Forall".to_owned());
+    }
+    match constraint {
+        CounterexampleConstraint::Satisfies { satisfies } => {
+            expr::validate_rust_expr(satisfies.trim())
+                .err()
+                .map(|reason| format!("satisfies {reason}"))
+                .or_else(|| {
+                    referenced_variables(satisfies.trim())
+                        .into_iter()
+                        .find(|name| !doc.forall.contains_key(name.as_str()))
+                        .map(|name| {
+                            format!("satisfies refers to '{name}', which is not a Forall variable")
+                        })
+                })
+        }
+        CounterexampleConstraint::Equals(TheoremValue::Sequence(_) | TheoremValue::Mapping(_)) => {
+            Some("expected a scalar value or { satisfies: <expression> }".to_owned())
+        }
+        CounterexampleConstraint::Equals(_) => None,
+    }
+}
+
+fn stub_path_reason(stub: &KaniStub) -> Option<String> {
+    path_reason("original", &stub.original).or_else(|| path_reason("stub", &stub.stub))
+}
+
+fn path_reason(field: &str, path: &str) -> Option<String> {
+    syn::parse_str::<syn::Path>(path)
+        .err()
+        .map(|error| format!("{field} '{path}' is not a valid Rust path: {error}"))
+}
+
+/// Compares two paths by tokens, so spacing differences do not hide a repeat.
+fn same_path(left: &str, right: &str) -> bool {
+    let tokens = |path: &str| {
+        syn::parse_str::<syn::Path>(path)
+            .map(|parsed| parsed.to_token_stream().to_string())
+            .ok()
+    };
+    tokens(left) == tokens(right)
+}
+
+fn is_allowed_kani_arg(arg: &str) -> bool {
+    let (flag, value) = arg
+        .split_once('=')
+        .map_or((arg, None), |(flag, value)| (flag, Some(value)));
+    KANI_EXTRA_ARGS
+        .iter()
+        .any(|&(allowed, values)| match (values, value) {
+            (None, None) => flag == allowed,
+            (Some(accepted), Some(given)) => flag == allowed && accepted.contains(&given),
+            (None, Some(_)) | (Some(_), None) => false,
+        })
+}
+
+/// Lists the allowlist for diagnostics, e.g. `--output-format=<regular|terse|old>`.
+fn allowed_kani_args() -> String {
+    KANI_EXTRA_ARGS
+        .iter()
+        .map(|&(flag, values)| {
+            values.map_or_else(
+                || flag.to_owned(),
+                |accepted| format!("{flag}=<{}>", accepted.join("|")),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[path = "validate_kani_conflicts.rs"]
+mod conflicts;
@@ -1,9 +1,11 @@
 //! Required text-field validation for theorem documents.
 
+use std::collections::HashSet;
+
 use super::{ValidationResult, fail, is_blank};
-use crate::schema::types::TheoremDoc;
+use crate::schema::types::{SUPPORTED_SCHEMA_VERSIONS, TheoremDoc};
 use crate::schema::validation_reason::{
-    IndexedValidationField, IndexedValidationSection, ValidationReasonKind,
+    IndexedValidationField, IndexedValidationSection, TraceField, ValidationReasonKind,
 };
 
 /// Validates that all labelled string fields within an indexed section entry
@@ -42,6 +44,27 @@ fn validate_collection_fields<T>(
     Ok(())
 }
 
+/// A declared `Schema` must be a supported version (`TFS-1` section 3.1).
+pub(super) fn validate_schema_version(doc: &TheoremDoc) -> ValidationResult {
+    let Some(version) = doc.schema else {
+        return Ok(());
+    };
+    if SUPPORTED_SCHEMA_VERSIONS.contains(&version) {
+        return Ok(());
+    }
+    let (first, last) = SUPPORTED_SCHEMA_VERSIONS.into_inner();
+    let supported = if first == last {
+        format!("version {first}")
+    } else {
+        format!("versions {first} to {last}")
+    };
+    Err(fail(
+        doc,
+        format!("Schema version {version} is not supported; this loader supports {supported}"),
+        Some(ValidationReasonKind::SchemaVersion),
+    ))
+}
+
 /// `About` must be non-empty after trimming (`TFS-1` section 3.3).
 pub(super) fn validate_about(doc: &TheoremDoc) -> ValidationResult {
     if is_blank(&doc.about) {
@@ -114,3 +137,56 @@ pub(super) fn validate_witnesses(doc: &TheoremDoc) -> ValidationResult {
         ]
     })
 }
+
+/// Every `Traces` entry must have non-empty `id` and `system` fields after
+/// trimming, an `http` or `https` `url` when one is given, and must not
+/// repeat an earlier entry's requirement (`TFS-1` section 3.4.1).
+pub(super) fn validate_traces(doc: &TheoremDoc) -> ValidationResult {
+    let mut seen = HashSet::new();
+    for (index, trace) in doc.traces.iter().enumerate() {
+        let pos = index + 1;
+        let trace_failure = |reason: String, field: TraceField| {
+            fail(
+                doc,
+                format!("Traces entry {pos}: {reason}"),
+                Some(ValidationReasonKind::Trace { index, field }),
+            )
+        };
+        if is_blank(&trace.id) {
+            return Err(trace_failure(
+                "id must be non-empty after trimming".to_owned(),
+                TraceField::Id,
+            ));
+        }
+        if is_blank(&trace.system) {
+            return Err(trace_failure(
+                "system must be non-empty after trimming".to_owned(),
+                TraceField::System,
+            ));
+        }
+        if trace.url.as_deref().is_some_and(|url| !is_web_url(url)) {
+            return Err(trace_failure(
+                "url must be an absolute http or https URL".to_owned(),
+                TraceField::Url,
+            ));
+        }
+        if !seen.insert((trace.system.trim(), trace.id.trim())) {
+            return Err(trace_failure(
+                format!(
+                    "requirement '{}' in '{}' is already listed",
+                    trace.id.trim(),
+                    trace.system.trim()
+                ),
+                TraceField::Id,
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_web_url(url: &str) -> bool {
+    ["https://", "http://"].iter().any(|scheme| {
+        url.strip_prefix(scheme)
+            .is_some_and(|rest| !rest.is_empty() && !rest.contains(char::is_whitespace))
+    })
+}
@@ -0,0 +1,74 @@
+//! Kani evidence option combinations that contradict each other.
+//!
+//! Each conflict is valid field by field but incoherent as a whole, so it is
+//! reported with its own stable diagnostic code (see
+//! [`SchemaDiagnosticCode`](crate::schema::SchemaDiagnosticCode)).
+
+use super::{KaniFailure, same_path};
+use crate::schema::types::{KaniEvidence, KaniExpectation, TheoremDoc};
+use crate::schema::validation_reason::KaniReason;
+
+/// Rejects option combinations that cannot describe a meaningful Kani run.
+pub(super) fn validate_kani_conflicts(
+    doc: &TheoremDoc,
+    kani: &KaniEvidence,
+) -> Result<(), KaniFailure> {
+    validate_unreachable_witnesses(doc, kani)?;
+    validate_stubbed_contracts(kani)?;
+    validate_vacuous_playback(kani)
+}
+
+/// `expect: UNREACHABLE` claims the assertions are never reached, which
+/// contradicts `Witness` covers claiming that they are.
+fn validate_unreachable_witnesses(
+    doc: &TheoremDoc,
+    kani: &KaniEvidence,
+) -> Result<(), KaniFailure> {
+    if kani.expect == KaniExpectation::Unreachable && !doc.witness.is_empty() {
+        return Err((
+            "Evidence.kani.expect UNREACHABLE conflicts with the Witness section; an \
+             unreachable theorem cannot have reachable witnesses, so remove the witnesses \
+             and set allow_vacuous with a vacuity_because"
+                .to_owned(),
+            KaniReason::UnreachableWithWitness,
+        ));
+    }
+    Ok(())
+}
+
+/// A function whose contract is being verified must not be replaced by a
+/// stub, or the contract harness would check the stub instead.
+fn validate_stubbed_contracts(kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    for (index, stub) in kani.stubs.iter().enumerate() {
+        if kani
+            .contracts
+            .iter()
+            .any(|contract| same_path(contract, &stub.original))
+        {
+            return Err((
+                format!(
+                    "Evidence.kani.stubs entry {}: '{}' is listed in contracts and cannot \
+                     also be stubbed",
+                    index + 1,
+                    stub.original.trim()
+                ),
+                KaniReason::StubbedContract { index },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Concrete playback replays a counterexample, but an expected failure that
+/// may be vacuous need not produce one worth replaying.
+fn validate_vacuous_playback(kani: &KaniEvidence) -> Result<(), KaniFailure> {
+    if kani.playback && kani.expect == KaniExpectation::Failure && kani.allow_vacuous {
+        return Err((
+            "Evidence.kani.playback conflicts with allow_vacuous on an expected FAILURE; \
+             a vacuous failure has no counterexample to replay"
+                .to_owned(),
+            KaniReason::VacuousPlayback,
+        ));
+    }
+    Ok(())
+}
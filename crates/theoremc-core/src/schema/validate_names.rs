@@ -0,0 +1,376 @@
+//! Name resolution for the variables theorem expressions read.
+
+use std::collections::{HashMap, HashSet};
+
+use super::{ValidationResult, fail};
+use crate::schema::arg_value::{ArgValue, ParamName, decode_arg_value};
+use crate::schema::expr_scope::referenced_variables;
+use crate::schema::forall::ForallDomainField;
+use crate::schema::newtypes::ForallVar;
+use crate::schema::step;
+use crate::schema::types::{ActionCall, ForeachBlock, LetBinding, Step, TheoremDoc};
+use crate::schema::value::TheoremValue;
+
+/// Hint appended when a `Let` binding argument reads an undeclared name.
+const LET_ARG_HINT: &str = "declare it in Forall or an earlier Let binding";
+
+/// Hint appended when a `Do` step argument reads an undeclared name.
+const STEP_ARG_HINT: &str = "declare it in Forall or Let, or bind it with `as` in an earlier step";
+use crate::schema::validation_reason::{IndexedValidationField, ValidationReasonKind};
+
+/// Every variable an `Assume`, `Prove`, or `Witness` expression reads must be
+/// a `Forall` variable, a `Let` binding, or a `Do` step's `as` binding, and a
+/// derived `Forall` expression may read only `Forall` variables. An inline
+/// `assert` step may read only the `as` bindings of the steps before it, and
+/// a `foreach` step may iterate only a name declared before it. Every
+/// `{ ref: <name> }` or `{ expr: "..." }` action argument must likewise read
+/// only names declared before its `Let` binding or `Do` step.
+pub(super) fn validate_expression_names(doc: &TheoremDoc) -> ValidationResult {
+    let forall: HashSet<&str> = doc.forall.keys().map(ForallVar::as_str).collect();
+    validate_derived_names(doc, &forall)?;
+
+    let mut declared = forall;
+    for (name, binding) in doc.let_bindings.iter() {
+        let call = match binding {
+            LetBinding::Call(let_call) => &let_call.call,
+            LetBinding::Must(let_must) => &let_must.must,
+        };
+        if let Some(reason) = unknown_arg_reason(call, &declared) {
+            return Err(fail(
+                doc,
+                format!("Let binding '{name}': {reason}; {LET_ARG_HINT}"),
+                None,
+            ));
+        }
+        declared.insert(name.as_str());
+    }
+    validate_step_names(doc, &doc.do_steps, "Do step", &mut declared)?;
+    for (label, expr, kind) in expression_sites(doc) {
+        if let Some(unknown) = first_unknown(expr, &declared) {
+            return Err(fail(
+                doc,
+                format!(
+                    "{label} refers to unknown variable '{unknown}'; \
+                     declare it in Forall or Let, or bind it with `as`"
+                ),
+                Some(kind),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_derived_names(doc: &TheoremDoc, forall: &HashSet<&str>) -> ValidationResult {
+    for (index, name) in doc.forall.keys().enumerate() {
+        let derived = doc
+            .forall_domains
+            .get(name)
+            .and_then(|domain| domain.derived_expr());
+        if let Some(unknown) = derived.and_then(|expr| first_unknown(expr, forall)) {
+            return Err(fail(
+                doc,
+                format!(
+                    "Forall entry '{name}': from refers to unknown variable '{unknown}'; \
+                     derived values may only use Forall variables"
+                ),
+                Some(ValidationReasonKind::ForallDomain {
+                    index,
+                    field: ForallDomainField::From,
+                }),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Lists each `Assume`, `Prove`, and `Witness` expression with its
+/// diagnostic label and reason kind.
+fn expression_sites(doc: &TheoremDoc) -> Vec<(String, &str, ValidationReasonKind)> {
+    let field = IndexedValidationField::Value;
+    let assume = doc.assume.iter().enumerate().map(|(index, assumption)| {
+        (
+            format!("Assume constraint {}: expr", index + 1),
+            assumption.expr.as_str(),
+            ValidationReasonKind::Assume { index, field },
+        )
+    });
+    let prove = doc.prove.iter().enumerate().map(|(index, assertion)| {
+        (
+            format!("Prove assertion {}: assert", index + 1),
+            assertion.assert_expr.as_str(),
+            ValidationReasonKind::Prove { index, field },
+        )
+    });
+    let witness = doc.witness.iter().enumerate().map(|(index, witness)| {
+        (
+            format!("Witness {}: cover", index + 1),
+            witness.cover.as_str(),
+            ValidationReasonKind::Witness { index, field },
+        )
+    });
+    assume.chain(prove).chain(witness).collect()
+}
+
+fn first_unknown(expr: &str, declared: &HashSet<&str>) -> Option<String> {
+    referenced_variables(expr.trim())
+        .into_iter()
+        .find(|name| !declared.contains(name.as_str()))
+}
+
+/// Describes the first `ref` or `expr` argument of `call` that reads a name
+/// missing from `declared`.
+fn unknown_arg_reason(call: &ActionCall, declared: &HashSet<&str>) -> Option<String> {
+    call.args.iter().find_map(|(param, value)| {
+        arg_variables(value)
+            .into_iter()
+            .find(|name| !declared.contains(name.as_str()))
+            .map(|unknown| {
+                format!(
+                    "argument '{param}' of '{}' refers to unknown variable '{unknown}'",
+                    call.action
+                )
+            })
+    })
+}
+
+/// Lists the variables an argument reads through `ref` and `expr` wrappers,
+/// including wrappers nested in sequences and struct-literal maps.
+pub(crate) fn arg_variables(value: &ArgValue) -> Vec<String> {
+    match value {
+        ArgValue::Literal(_) => Vec::new(),
+        ArgValue::Reference(name) => vec![name.clone()],
+        ArgValue::Expr(source) => referenced_variables(source),
+        ArgValue::RawSequence(elements) => elements.iter().flat_map(nested_variables).collect(),
+        ArgValue::RawMap(fields) => fields.values().flat_map(nested_variables).collect(),
+    }
+}
+
+/// Decodes a nested value the way argument lowering does. A nested wrapper
+/// that fails to decode reads no names here; lowering reports it.
+fn nested_variables(value: &TheoremValue) -> Vec<String> {
+    decode_arg_value(ParamName::new(""), value.clone())
+        .map(|decoded| arg_variables(&decoded))
+        .unwrap_or_default()
+}
+
+/// Checks inline `assert` steps and action arguments against the names
+/// declared so far and adds each step's `as` binding, including those of
+/// nested `maybe` and `repeat` steps.
+fn validate_step_names<'d>(
+    doc: &'d TheoremDoc,
+    steps: &'d [Step],
+    path: &str,
+    declared: &mut HashSet<&'d str>,
+) -> ValidationResult {
+    for (index, step) in steps.iter().enumerate() {
+        let call = match step {
+            Step::Call(step_call) => &step_call.call,
+            Step::Must(step_must) => &step_must.must,
+            Step::ExpectErr(step_expect_err) => &step_expect_err.expect_err,
+            Step::Maybe(_) => {
+                validate_maybe_names(doc, step, &format!("{path} {}", index + 1), declared)?;
+                continue;
+            }
+            Step::Repeat(_) => {
+                for (branch, nested) in step.nested_branches() {
+                    let nested_path = step::nested_step_path(path, index + 1, step, branch);
+                    validate_step_names(doc, nested, &nested_path, declared)?;
+                }
+                continue;
+            }
+            Step::Foreach(step_foreach) => {
+                let label = format!("{path} {}", index + 1);
+                validate_foreach_names(doc, &step_foreach.foreach, &label, declared)?;
+                continue;
+            }
+            Step::Assert(step_assert) => {
+                if let Some(unknown) = first_unknown(&step_assert.assert.expr, declared) {
+                    return Err(fail(
+                        doc,
+                        format!(
+                            "{path} {}: assert.expr refers to unknown variable '{unknown}'; \
+                             declare it in Forall or Let, or bind it with `as` in an \
+                             earlier step",
+                            index + 1
+                        ),
+                        None,
+                    ));
+                }
+                continue;
+            }
+        };
+        if let Some(reason) = unknown_arg_reason(call, declared) {
+            return Err(fail(
+                doc,
+                format!("{path} {}: {reason}; {STEP_ARG_HINT}", index + 1),
+                None,
+            ));
+        }
+        if let Some(binding) = call.as_binding.as_deref() {
+            if declared.contains(binding) {
+                return Err(fail(
+                    doc,
+                    format!(
+                        "{path} {}: as '{binding}' shadows a variable declared earlier",
+                        index + 1
+                    ),
+                    None,
+                ));
+            }
+            declared.insert(binding);
+        }
+    }
+    Ok(())
+}
+
+/// Only one branch of a `maybe` step runs, so each branch sees just the names
+/// declared before the step. Later steps see the bindings of both branches.
+fn validate_maybe_names<'d>(
+    doc: &'d TheoremDoc,
+    step: &'d Step,
+    label: &str,
+    declared: &mut HashSet<&'d str>,
+) -> ValidationResult {
+    let mut introduced = Vec::new();
+    for (branch, nested) in step.nested_branches() {
+        let mut scope = declared.clone();
+        let nested_path = format!("{label}: {}.{branch} step", step.keyword());
+        validate_step_names(doc, nested, &nested_path, &mut scope)?;
+        introduced.extend(scope);
+    }
+    declared.extend(introduced);
+    Ok(())
+}
+
+/// The sequence a `foreach` step iterates must already be declared, and its
+/// loop variable must not shadow a declared name. The loop variable and the
+/// `as` bindings of the nested steps are visible only inside the loop.
+fn validate_foreach_names<'d>(
+    doc: &'d TheoremDoc,
+    foreach: &'d ForeachBlock,
+    label: &str,
+    declared: &HashSet<&'d str>,
+) -> ValidationResult {
+    let source = foreach.source.trim();
+    if !declared.contains(source) {
+        return Err(fail(
+            doc,
+            format!(
+                "{label}: foreach.in refers to unknown sequence '{source}'; \
+                 declare it in Forall or Let, or bind it with `as` in an earlier step"
+            ),
+            None,
+        ));
+    }
+    let binding = foreach.as_binding.as_str();
+    if declared.contains(binding) {
+        return Err(fail(
+            doc,
+            format!("{label}: foreach.as '{binding}' shadows a variable declared earlier"),
+            None,
+        ));
+    }
+    let mut scope = declared.clone();
+    scope.insert(binding);
+    validate_step_names(
+        doc,
+        &foreach.do_steps,
+        &format!("{label}: foreach.do step"),
+        &mut scope,
+    )
+}
+
+/// Every `Do` step `as` binding must be unique across the whole sequence,
+/// including nested blocks and both branches of a `maybe` step, and must not
+/// shadow a `Forall` variable or `Let` binding.
+pub(super) fn validate_as_bindings(doc: &TheoremDoc) -> ValidationResult {
+    let mut bindings = HashMap::new();
+    collect_as_bindings(doc, &doc.do_steps, "Do step", &mut bindings)
+        .map_err(|r| fail(doc, r, None))
+}
+
+/// Records each `as` binding in `steps` and their nested blocks against its
+/// diagnostic label, rejecting shadowed and repeated names.
+fn collect_as_bindings<'d>(
+    doc: &TheoremDoc,
+    steps: &'d [Step],
+    path: &str,
+    bindings: &mut HashMap<&'d str, String>,
+) -> Result<(), String> {
+    for (index, step) in steps.iter().enumerate() {
+        let label = format!("{path} {}", index + 1);
+        if let Some(binding) = step.as_binding() {
+            if doc.forall.contains_key(binding) {
+                return Err(format!(
+                    "{label}: as '{binding}' shadows the Forall variable of the same name"
+                ));
+            }
+            if doc.let_bindings.contains_key(binding) {
+                return Err(format!(
+                    "{label}: as '{binding}' shadows the Let binding of the same name"
+                ));
+            }
+            if let Some(first) = bindings.get(binding) {
+                return Err(format!(
+                    "{label}: as '{binding}' is already bound by {first}; \
+                     as bindings must be unique"
+                ));
+            }
+            bindings.insert(binding, label);
+        }
+        for (branch, nested) in step.nested_branches() {
+            let nested_path = step::nested_step_path(path, index + 1, step, branch);
+            collect_as_bindings(doc, nested, &nested_path, bindings)?;
+        }
+    }
+    Ok(())
+}
+
+/// Every `Do` step `id` must be unique across the whole sequence, including
+/// nested blocks, and every `Prove` assertion's `step` must name one of them.
+pub(super) fn validate_step_ids(doc: &TheoremDoc) -> ValidationResult {
+    let mut ids = HashMap::new();
+    collect_step_ids(&doc.do_steps, "Do step", &mut ids).map_err(|r| fail(doc, r, None))?;
+    for (index, assertion) in doc.prove.iter().enumerate() {
+        let Some(step_id) = assertion.step.as_deref() else {
+            continue;
+        };
+        if !ids.contains_key(step_id) {
+            return Err(fail(
+                doc,
+                format!(
+                    "Prove assertion {}: step refers to unknown Do step id '{step_id}'; \
+                     give the step an `id` to attach assertions to it",
+                    index + 1
+                ),
+                Some(ValidationReasonKind::ProveStep { index }),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Records each step `id` in `steps` and their nested blocks against its
+/// diagnostic label, rejecting any `id` already recorded.
+fn collect_step_ids<'d>(
+    steps: &'d [Step],
+    path: &str,
+    ids: &mut HashMap<&'d str, String>,
+) -> Result<(), String> {
+    for (index, step) in steps.iter().enumerate() {
+        let label = format!("{path} {}", index + 1);
+        if let Some(step_id) = step.id() {
+            if let Some(first) = ids.get(step_id) {
+                return Err(format!(
+                    "{label}: id '{step_id}' is already used by {first}; step ids must be unique"
+                ));
+            }
+            ids.insert(step_id, label);
+        }
+        for (branch, nested) in step.nested_branches() {
+            let nested_path = step::nested_step_path(path, index + 1, step, branch);
+            collect_step_ids(nested, &nested_path, ids)?;
+        }
+    }
+    Ok(())
+}
@@ -2,12 +2,12 @@
 
 use super::{ValidationResult, fail};
 use crate::schema::step;
-use crate::schema::types::{LetBinding, TheoremDoc};
+use crate::schema::types::{LetBinding, Step, TheoremDoc};
 
 /// Every `Let` binding's `ActionCall.action` must be non-empty
 /// (`TFS-4` section 3.8, `DES-4` section 4.4).
 pub(super) fn validate_let_bindings(doc: &TheoremDoc) -> ValidationResult {
-    for (name, binding) in &doc.let_bindings {
+    for (name, binding) in doc.let_bindings.iter() {
         let ac = match binding {
             LetBinding::Call(c) => &c.call,
             LetBinding::Must(m) => &m.must,
@@ -22,3 +22,60 @@ pub(super) fn validate_let_bindings(doc: &TheoremDoc) -> ValidationResult {
 pub(super) fn validate_do_steps(doc: &TheoremDoc) -> ValidationResult {
     step::validate_step_list(&doc.do_steps, "Do step").map_err(|r| fail(doc, r, None))
 }
+
+/// The deepest chain of `foreach` steps nested inside one another. Each level
+/// multiplies the unrolled code, so deeper nesting is rejected.
+const MAX_FOREACH_DEPTH: usize = 3;
+
+/// Every `repeat` loop must fit within the smallest Kani `unwind` bound, as
+/// given by [`RepeatBlock::required_unwind`](crate::schema::RepeatBlock::required_unwind).
+pub(super) fn validate_repeat_unwind(doc: &TheoremDoc) -> ValidationResult {
+    let Some(unwind) = doc.evidence.min_kani_unwind() else {
+        return Ok(());
+    };
+    check_repeat_unwind(&doc.do_steps, "Do step", unwind).map_err(|r| fail(doc, r, None))
+}
+
+fn check_repeat_unwind(steps: &[Step], path: &str, unwind: u32) -> Result<(), String> {
+    for (index, step) in steps.iter().enumerate() {
+        if let Step::Repeat(repeat) = step {
+            let required = repeat.repeat.required_unwind();
+            if u64::from(unwind) < required {
+                return Err(format!(
+                    "{path} {}: repeat.times {} needs a Kani unwind of at least {required}, \
+                     but unwind is {unwind}",
+                    index + 1,
+                    repeat.repeat.times
+                ));
+            }
+        }
+        for (branch, nested) in step.nested_branches() {
+            let nested_path = step::nested_step_path(path, index + 1, step, branch);
+            check_repeat_unwind(nested, &nested_path, unwind)?;
+        }
+    }
+    Ok(())
+}
+
+/// `foreach` steps must not nest more than [`MAX_FOREACH_DEPTH`] deep.
+pub(super) fn validate_foreach_depth(doc: &TheoremDoc) -> ValidationResult {
+    check_foreach_depth(&doc.do_steps, "Do step", 0).map_err(|r| fail(doc, r, None))
+}
+
+fn check_foreach_depth(steps: &[Step], path: &str, depth: usize) -> Result<(), String> {
+    for (index, step) in steps.iter().enumerate() {
+        let nested_depth = depth + usize::from(matches!(step, Step::Foreach(_)));
+        if nested_depth > MAX_FOREACH_DEPTH {
+            return Err(format!(
+                "{path} {}: foreach is nested {nested_depth} deep, but at most \
+                 {MAX_FOREACH_DEPTH} nested foreach steps are allowed",
+                index + 1
+            ));
+        }
+        for (branch, nested) in step.nested_branches() {
+            let nested_path = step::nested_step_path(path, index + 1, step, branch);
+            check_foreach_depth(nested, &nested_path, nested_depth)?;
+        }
+    }
+    Ok(())
+}
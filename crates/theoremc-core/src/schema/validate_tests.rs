@@ -1,6 +1,6 @@
 //! Unit tests for post-deserialization semantic validation.
 
-use crate::schema::load_theorem_docs;
+use crate::schema::{SUPPORTED_SCHEMA_VERSIONS, load_theorem_docs};
 use rstest::rstest;
 
 /// Helper: load inline YAML and return the error string.
@@ -110,3 +110,276 @@ fn valid_base_parses_successfully() {
     let result = load_theorem_docs(VALID_BASE);
     assert!(result.is_ok(), "VALID_BASE should parse: {result:?}");
 }
+
+#[rstest]
+#[case::blank_id(
+    "  - { id: ' ', system: ledger }\n",
+    "Traces entry 1: id must be non-empty",
+    5
+)]
+#[case::blank_system(
+    "  - id: REQ-1\n    system: ''\n",
+    "Traces entry 1: system must be non-empty",
+    6
+)]
+#[case::relative_url(
+    "  - { id: REQ-1, system: ledger }\n  - id: REQ-2\n    system: ledger\n    url: docs/req-2\n",
+    "Traces entry 2: url must be an absolute http or https URL",
+    8
+)]
+#[case::repeated_requirement(
+    "  - { id: REQ-1, system: ledger }\n  - { id: ' REQ-1', system: ledger }\n",
+    "Traces entry 2: requirement 'REQ-1' in 'ledger' is already listed",
+    6
+)]
+fn invalid_traces_are_rejected_at_the_offending_field(
+    #[case] traces: &str,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let yaml = VALID_BASE.replacen(
+        "About: valid\n",
+        &format!("About: valid\nTraces:\n{traces}"),
+        1,
+    );
+    let error = load_theorem_docs(&yaml).expect_err("traces should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected error containing '{expected_fragment}', got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+/// Declares `amount` in `Forall`, `total` in `Let`, and `receipt` and `retry`
+/// through `as` bindings, the second inside a `maybe` block.
+const NAMES_BASE: &str = concat!(
+    "Theorem: T\n",
+    "About: valid\n",
+    "Forall:\n",
+    "  amount: u64\n",
+    "  doubled: { type: u64, from: 'amount * 2' }\n",
+    "Let:\n",
+    "  total:\n",
+    "    call: { action: ledger.total, args: {} }\n",
+    "Do:\n",
+    "  - call: { action: ledger.deposit, args: {}, as: receipt }\n",
+    "  - maybe:\n",
+    "      because: a retry may happen\n",
+    "      do:\n",
+    "        - must: { action: ledger.retry, args: {}, as: retry }\n",
+    "Assume:\n",
+    "  - expr: 'amount <= MAX_DEPOSIT'\n",
+    "    because: deposits are bounded\n",
+    "Prove:\n",
+    "  - assert: 'receipt.total() == total + amount'\n",
+    "    because: deposits add up\n",
+    "  - assert: 'retry.map_or(true, |r| r.is_ok()) && doubled >= amount'\n",
+    "    because: retries succeed\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 1\n",
+    "    expect: SUCCESS\n",
+    "Witness:\n",
+    "  - cover: 'matches!(receipt.status(), Status::Done { .. })'\n",
+    "    because: deposits complete\n",
+    "Actions:\n",
+    "  ledger.total: { params: {}, returns: u64 }\n",
+    "  ledger.deposit: { params: {}, returns: crate::Receipt }\n",
+    "  ledger.retry: { params: {}, returns: crate::Retry }\n",
+);
+
+#[test]
+fn declared_names_resolve_in_every_expression() {
+    let result = load_theorem_docs(NAMES_BASE);
+    assert!(result.is_ok(), "NAMES_BASE should parse: {result:?}");
+}
+
+#[rstest]
+#[case::assume(
+    "'amount <= MAX_DEPOSIT'",
+    "'amount <= limit'",
+    "Assume constraint 1: expr refers to unknown variable 'limit'; \
+     declare it in Forall or Let, or bind it with `as`",
+    16
+)]
+#[case::prove(
+    "'receipt.total() == total + amount'",
+    "'receipt.total() == balance + amount'",
+    "Prove assertion 1: assert refers to unknown variable 'balance'",
+    19
+)]
+#[case::witness(
+    "'matches!(receipt.status(), Status::Done { .. })'",
+    "'matches!(status, Status::Done { .. })'",
+    "Witness 1: cover refers to unknown variable 'status'",
+    28
+)]
+#[case::derived_from_let(
+    "'amount * 2'",
+    "'total * 2'",
+    "Forall entry 'doubled': from refers to unknown variable 'total'; \
+     derived values may only use Forall variables",
+    5
+)]
+fn unknown_variables_are_rejected_at_the_expression(
+    #[case] original: &str,
+    #[case] replacement: &str,
+    #[case] expected_fragment: &str,
+    #[case] line: usize,
+) {
+    let yaml = NAMES_BASE.replacen(original, replacement, 1);
+    let error = load_theorem_docs(&yaml).expect_err("unknown variable should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(expected_fragment),
+        "expected error containing '{expected_fragment}', got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::forall_ref("{ ref: amount }")]
+#[case::let_ref("{ ref: total }")]
+#[case::expr("{ expr: 'total + amount' }")]
+#[case::nested_in_sequence("[{ ref: amount }, 1]")]
+fn declared_names_resolve_in_action_arguments(#[case] value: &str) {
+    let yaml = NAMES_BASE.replacen(
+        "ledger.deposit, args: {}",
+        &format!("ledger.deposit, args: {{ amount: {value} }}"),
+        1,
+    );
+    let result = load_theorem_docs(&yaml);
+    assert!(
+        result.is_ok(),
+        "argument {value} should resolve: {result:?}"
+    );
+}
+
+#[rstest]
+#[case::undeclared_ref(
+    "ledger.deposit, args: {}",
+    "ledger.deposit, args: { amount: { ref: limit } }",
+    "Do step 1: argument 'amount' of 'ledger.deposit' refers to unknown variable 'limit'; \
+     declare it in Forall or Let, or bind it with `as` in an earlier step"
+)]
+#[case::own_as_binding(
+    "ledger.deposit, args: {}",
+    "ledger.deposit, args: { prior: { ref: receipt } }",
+    "Do step 1: argument 'prior' of 'ledger.deposit' refers to unknown variable 'receipt'"
+)]
+#[case::undeclared_expr(
+    "ledger.retry, args: {}",
+    "ledger.retry, args: { count: { expr: 'attempts + 1' } }",
+    "Do step 2: maybe.do step 1: argument 'count' of 'ledger.retry' refers to unknown \
+     variable 'attempts'"
+)]
+#[case::nested_in_sequence(
+    "ledger.deposit, args: {}",
+    "ledger.deposit, args: { amounts: [1, { ref: limit }] }",
+    "argument 'amounts' of 'ledger.deposit' refers to unknown variable 'limit'"
+)]
+#[case::let_reads_as_binding(
+    "ledger.total, args: {}",
+    "ledger.total, args: { since: { ref: receipt } }",
+    "Let binding 'total': argument 'since' of 'ledger.total' refers to unknown variable \
+     'receipt'; declare it in Forall or an earlier Let binding"
+)]
+fn unknown_variables_are_rejected_in_action_arguments(
+    #[case] original: &str,
+    #[case] replacement: &str,
+    #[case] expected_fragment: &str,
+) {
+    let yaml = NAMES_BASE.replacen(original, replacement, 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[rstest]
+#[case::zero(0)]
+#[case::future(3)]
+fn unsupported_schema_versions_are_rejected(#[case] version: u32) {
+    let yaml = format!("\nSchema: {version}{VALID_BASE}");
+    let error = load_theorem_docs(&yaml).expect_err("schema version should be rejected");
+
+    assert!(
+        error.to_string().contains(&format!(
+            "Schema version {version} is not supported; this loader supports versions 1 to 2"
+        )),
+        "{error}"
+    );
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.code.as_str(), "schema.unsupported_version");
+    assert_eq!(
+        (diagnostic.location.line, diagnostic.location.column),
+        (2, 9)
+    );
+}
+
+#[rstest]
+#[case::omitted("", None, 1)]
+#[case::explicit("Schema: 1\n", Some(1), 1)]
+#[case::latest("Schema: 2\n", Some(2), 2)]
+fn schema_version_defaults_to_one(
+    #[case] schema_line: &str,
+    #[case] declared: Option<u32>,
+    #[case] effective: u32,
+) {
+    let docs = load_theorem_docs(&format!("{schema_line}{VALID_BASE}"))
+        .expect("supported schema version should load");
+    let doc = docs.first().expect("source should hold a theorem");
+
+    assert_eq!(doc.schema, declared);
+    assert_eq!(doc.schema_version(), effective);
+    assert!(SUPPORTED_SCHEMA_VERSIONS.contains(&doc.schema_version()));
+}
+
+#[test]
+fn schema_two_rejects_lowercase_section_keys() {
+    let yaml = format!(
+        "Schema: 2{}",
+        VALID_BASE.replacen("\nProve:", "\nprove:", 1)
+    );
+    let error = load_theorem_docs(&yaml).expect_err("lowercase key should be rejected");
+
+    assert!(
+        error.to_string().contains(
+            "Schema version 2 requires canonical section keys; write 'Prove' instead of 'prove'"
+        ),
+        "{error}"
+    );
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(
+        (diagnostic.location.line, diagnostic.location.column),
+        (4, 1)
+    );
+}
+
+#[test]
+fn schema_one_keeps_lowercase_section_keys() {
+    let yaml = format!(
+        "Schema: 1{}",
+        VALID_BASE.replacen("\nProve:", "\nprove:", 1)
+    );
+
+    assert!(load_theorem_docs(&yaml).is_ok());
+}
+
+#[path = "validate_tests/do_steps.rs"]
+mod do_steps;
+
+#[path = "validate_tests/evidence.rs"]
+mod evidence;
+
+#[path = "validate_tests/evidence_matrix.rs"]
+mod evidence_matrix;
+
+#[path = "validate_tests/counterexample.rs"]
+mod counterexample;
+
+#[path = "validate_tests/kani_conflicts.rs"]
+mod kani_conflicts;
+
+#[path = "validate_tests/revalidate.rs"]
+mod revalidate;
@@ -0,0 +1,96 @@
+//! Validation tests for expected counterexamples of `FAILURE` theorems.
+
+use super::assert_load_err_contains;
+use crate::schema::{CounterexampleConstraint, TheoremValue, load_theorem_docs};
+use rstest::rstest;
+
+/// A theorem expected to fail, with `expect` and `entries` spliced into its
+/// Kani evidence. The counterexample entries start on line 15.
+fn overdraw(expect: &str, entries: &str) -> String {
+    format!(
+        r"
+Theorem: Overdraw
+About: withdrawing more than the balance fails
+Forall:
+  balance: u64
+  amount: u64
+Prove:
+  - assert: 'amount <= balance'
+    because: withdrawals cannot exceed the balance
+Evidence:
+  kani:
+    unwind: 1
+    expect: {expect}
+    counterexample:
+{entries}Witness:
+  - cover: 'true'
+    because: always reachable
+"
+    )
+}
+
+#[test]
+fn counterexample_constraints_are_kept_in_order() {
+    let yaml = overdraw(
+        "FAILURE",
+        "      balance: 0\n      amount: { satisfies: 'amount > balance' }\n",
+    );
+    let docs = load_theorem_docs(&yaml).expect("counterexample should load");
+    let counterexample: Vec<_> = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .map(|kani| kani.counterexample.clone().into_iter().collect())
+        .unwrap_or_default();
+
+    assert_eq!(
+        counterexample,
+        vec![
+            (
+                "balance".to_owned(),
+                CounterexampleConstraint::Equals(TheoremValue::Integer(0))
+            ),
+            (
+                "amount".to_owned(),
+                CounterexampleConstraint::Satisfies {
+                    satisfies: "amount > balance".to_owned(),
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn counterexample_requires_an_expected_failure() {
+    let error = load_theorem_docs(&overdraw("SUCCESS", "      balance: 0\n"))
+        .expect_err("counterexample should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("Evidence.kani.counterexample is only allowed when expect is FAILURE"),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 15);
+}
+
+#[rstest]
+#[case::undeclared("      limit: 3\n", "variable 'limit': not declared in Forall")]
+#[case::invalid_predicate(
+    "      amount: { satisfies: 'amount >' }\n",
+    "variable 'amount': satisfies is not a valid Rust expression"
+)]
+#[case::unknown_name(
+    "      amount: { satisfies: 'amount > limit' }\n",
+    "variable 'amount': satisfies refers to 'limit', which is not a Forall variable"
+)]
+#[case::non_scalar(
+    "      amount: [1, 2]\n",
+    "variable 'amount': expected a scalar value or { satisfies: <expression> }"
+)]
+fn invalid_counterexample_entries_are_rejected(#[case] entries: &str, #[case] expected: &str) {
+    assert_load_err_contains(
+        &overdraw("FAILURE", entries),
+        &format!("Evidence.kani.counterexample {expected}"),
+    );
+}
@@ -0,0 +1,273 @@
+//! Validation tests for `Do` step scoping, signatures, and bounds.
+
+use super::{NAMES_BASE, assert_load_err_contains};
+use crate::schema::load_theorem_docs;
+use rstest::rstest;
+
+#[rstest]
+#[case::later_binding(
+    "  - assert: { expr: 'receipt.is_ok()', because: early }",
+    9,
+    "Do step 1: assert.expr refers to unknown variable 'receipt'"
+)]
+#[case::nested_unknown(
+    "        - assert: { expr: 'retry.is_ok() && missing', because: late }",
+    14,
+    "Do step 2: maybe.do step 2: assert.expr refers to unknown variable 'missing'"
+)]
+fn inline_asserts_read_only_earlier_bindings(
+    #[case] step: &str,
+    #[case] line_index: usize,
+    #[case] expected_fragment: &str,
+) {
+    let mut lines: Vec<&str> = NAMES_BASE.lines().collect();
+    lines.insert(line_index, step);
+    let yaml = lines.join("\n");
+
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+/// `NAMES_BASE` with an `expect_err` step on `ledger.close` inserted after
+/// the `anchor` line at `indent`, and a signature returning `returns`.
+fn names_base_with_close_step(anchor: &str, indent: &str, returns: &str) -> String {
+    let step = "- expect_err: { action: ledger.close, args: {}, error: Closed }";
+    let with_step = NAMES_BASE.replacen(anchor, &format!("{anchor}{indent}{step}\n"), 1);
+    format!("{with_step}  ledger.close: {{ params: {{}}, returns: '{returns}' }}\n")
+}
+
+#[rstest]
+#[case::plain_return(
+    "Do:\n",
+    "  ",
+    "u64",
+    "Do step 1: expect_err action 'ledger.close' must return a Result, \
+     but its Actions signature returns 'u64'"
+)]
+#[case::nested_option(
+    "      do:\n",
+    "        ",
+    "Option<Result<u64, crate::Error>>",
+    "Do step 2: maybe.do step 1: expect_err action 'ledger.close' must return a Result"
+)]
+fn expect_err_actions_must_return_result(
+    #[case] anchor: &str,
+    #[case] indent: &str,
+    #[case] returns: &str,
+    #[case] expected_fragment: &str,
+) {
+    let yaml = names_base_with_close_step(anchor, indent, returns);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[test]
+fn expect_err_steps_bind_nothing() {
+    let yaml = names_base_with_close_step("Do:\n", "  ", "Result<u64, u8>").replacen(
+        "total + amount",
+        "total + amount + closed",
+        1,
+    );
+
+    assert_load_err_contains(
+        &yaml,
+        "Prove assertion 1: assert refers to unknown variable 'closed'",
+    );
+}
+
+#[rstest]
+#[case::top_level(
+    "Do:\n",
+    "  - repeat: { times: 1, do: [{ call: { action: ledger.total, args: {} } }] }\n",
+    "Do step 1: repeat.times 1 needs a Kani unwind of at least 2, but unwind is 1"
+)]
+#[case::nested(
+    "      do:\n",
+    "        - repeat: { times: 4, do: [{ assert: { expr: 'true', because: ok } }] }\n",
+    "Do step 2: maybe.do step 1: repeat.times 4 needs a Kani unwind of at least 5"
+)]
+fn repeat_loops_must_fit_the_unwind_bound(
+    #[case] anchor: &str,
+    #[case] step: &str,
+    #[case] expected_fragment: &str,
+) {
+    let yaml = NAMES_BASE.replacen(anchor, &format!("{anchor}{step}"), 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[rstest]
+#[case::unknown_sequence(
+    "  - foreach: { in: deposits, as: d, do: [{ assert: { expr: 'd > 0', because: ok } }] }\n",
+    "Do step 1: foreach.in refers to unknown sequence 'deposits'"
+)]
+#[case::shadowing_binding(
+    "  - foreach: { in: total, as: amount, do: [{ call: { action: ledger.total, args: {} } }] }\n",
+    "Do step 1: foreach.as 'amount' shadows a variable declared earlier"
+)]
+#[case::nested_unknown(
+    "  - foreach: { in: total, as: t, do: [{ assert: { expr: 't > missing', because: ok } }] }\n",
+    "Do step 1: foreach.do step 1: assert.expr refers to unknown variable 'missing'"
+)]
+#[case::too_deep(
+    concat!(
+        "  - foreach: { in: total, as: a, do: [{ foreach: { in: a, as: b, do: [{ foreach: ",
+        "{ in: b, as: c, do: [{ foreach: { in: c, as: d, do: [{ assert: ",
+        "{ expr: 'd > 0', because: ok } }] } }] } }] } }] }\n",
+    ),
+    "foreach.do step 1: foreach is nested 4 deep, but at most 3 nested foreach steps are allowed"
+)]
+fn foreach_steps_iterate_declared_sequences(#[case] step: &str, #[case] expected_fragment: &str) {
+    let yaml = NAMES_BASE.replacen("Do:\n", &format!("Do:\n{step}"), 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[test]
+fn foreach_loop_variables_stay_inside_the_loop() {
+    let step = "  - foreach: { in: total, as: item, do: [{ assert: { expr: 'item > 0', because: ok } }] }\n";
+    let with_step = NAMES_BASE.replacen("Do:\n", &format!("Do:\n{step}"), 1);
+    assert!(
+        load_theorem_docs(&with_step).is_ok(),
+        "loop variable should resolve in the loop"
+    );
+
+    let yaml = with_step.replacen("total + amount", "total + item", 1);
+    assert_load_err_contains(
+        &yaml,
+        "Prove assertion 1: assert refers to unknown variable 'item'",
+    );
+}
+
+#[rstest]
+#[case::unknown_variable(
+    "        - assert: { expr: 'amount > 0 || missing', because: ok }\n",
+    "Do step 2: maybe.else step 1: assert.expr refers to unknown variable 'missing'"
+)]
+#[case::do_branch_binding(
+    "        - assert: { expr: 'retry.is_ok()', because: ok }\n",
+    "Do step 2: maybe.else step 1: assert.expr refers to unknown variable 'retry'"
+)]
+#[case::unwind(
+    "        - repeat: { times: 2, do: [{ assert: { expr: 'true', because: ok } }] }\n",
+    "Do step 2: maybe.else step 1: repeat.times 2 needs a Kani unwind of at least 3"
+)]
+fn maybe_else_branches_are_validated(#[case] step: &str, #[case] expected_fragment: &str) {
+    let anchor = "as: retry }\n";
+    let yaml = NAMES_BASE.replacen(anchor, &format!("{anchor}      else:\n{step}"), 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[rstest]
+#[case::keyword(
+    "as: receipt }",
+    "as: match }",
+    "Do step 1: as invalid identifier 'match'"
+)]
+#[case::shadows_forall(
+    "as: receipt }",
+    "as: amount }",
+    "Do step 1: as 'amount' shadows the Forall variable of the same name"
+)]
+#[case::shadows_let(
+    "as: receipt }",
+    "as: total }",
+    "Do step 1: as 'total' shadows the Let binding of the same name"
+)]
+#[case::repeated_in_maybe(
+    "as: retry }",
+    "as: receipt }",
+    "Do step 2: maybe.do step 1: as 'receipt' is already bound by Do step 1; \
+     as bindings must be unique"
+)]
+#[case::shadows_loop_variable(
+    "Do:\n",
+    "Do:\n  - foreach: { in: total, as: item, do: [{ call: { action: ledger.total, \
+     args: {}, as: item } }] }\n",
+    "Do step 1: foreach.do step 1: as 'item' shadows a variable declared earlier"
+)]
+fn as_bindings_are_unique_unshadowed_identifiers(
+    #[case] original: &str,
+    #[case] replacement: &str,
+    #[case] expected_fragment: &str,
+) {
+    let yaml = NAMES_BASE.replacen(original, replacement, 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
+
+#[test]
+fn maybe_bindings_are_visible_after_the_maybe_step() {
+    let step = "  - assert: { expr: 'retry.is_ok()', because: ok }\n";
+    let yaml = NAMES_BASE.replacen("Assume:\n", &format!("{step}Assume:\n"), 1);
+    assert!(
+        load_theorem_docs(&yaml).is_ok(),
+        "a maybe binding should resolve after the maybe step"
+    );
+}
+
+/// `NAMES_BASE` with the top-level `ledger.deposit` step given `id: deposit`.
+fn names_base_with_deposit_id() -> String {
+    NAMES_BASE.replacen(
+        "  - call: { action: ledger.deposit",
+        "  - id: deposit\n    call: { action: ledger.deposit",
+        1,
+    )
+}
+
+#[test]
+fn prove_assertions_attach_to_step_ids() {
+    let yaml = names_base_with_deposit_id().replacen(
+        "because: deposits add up\n",
+        "because: deposits add up\n    step: deposit\n",
+        1,
+    );
+    let docs = load_theorem_docs(&yaml).expect("step reference should resolve");
+    let doc = docs.first().expect("one document");
+
+    assert_eq!(
+        doc.do_steps.first().and_then(|step| step.id()),
+        Some("deposit")
+    );
+    assert_eq!(
+        doc.prove
+            .first()
+            .and_then(|assertion| assertion.step.as_deref()),
+        Some("deposit")
+    );
+}
+
+#[test]
+fn prove_step_references_must_name_a_step_id() {
+    let yaml = NAMES_BASE.replacen(
+        "because: deposits add up\n",
+        "because: deposits add up\n    step: deposit\n",
+        1,
+    );
+    let error = load_theorem_docs(&yaml).expect_err("unknown step id should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("Prove assertion 1: step refers to unknown Do step id 'deposit'"),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 21);
+}
+
+#[rstest]
+#[case::nested_duplicate(
+    "        - must: { action: ledger.retry",
+    "        - id: deposit\n          must: { action: ledger.retry",
+    "Do step 2: maybe.do step 1: id 'deposit' is already used by Do step 1; \
+     step ids must be unique"
+)]
+#[case::keyword(
+    "  - id: deposit\n",
+    "  - id: match\n",
+    "Do step 1: id invalid identifier 'match'"
+)]
+fn step_ids_are_unique_identifiers(
+    #[case] original: &str,
+    #[case] replacement: &str,
+    #[case] expected_fragment: &str,
+) {
+    let yaml = names_base_with_deposit_id().replacen(original, replacement, 1);
+    assert_load_err_contains(&yaml, expected_fragment);
+}
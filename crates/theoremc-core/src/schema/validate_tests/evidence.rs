@@ -0,0 +1,299 @@
+//! Validation tests for Kani evidence tuning fields.
+
+use super::{VALID_BASE, assert_load_err_contains};
+use std::time::Duration;
+
+use crate::schema::{KaniSolver, KaniStub, KaniTimeout, MemoryLimit, load_theorem_docs};
+use rstest::rstest;
+
+/// `VALID_BASE` with `fields` appended to its Kani evidence.
+fn with_kani_fields(fields: &str) -> String {
+    VALID_BASE.replacen(
+        "    expect: SUCCESS\n",
+        &format!("    expect: SUCCESS\n{fields}"),
+        1,
+    )
+}
+
+#[rstest]
+#[case::cadical("cadical", KaniSolver::Cadical)]
+#[case::kissat("kissat", KaniSolver::Kissat)]
+#[case::minisat("minisat", KaniSolver::Minisat)]
+fn known_solvers_are_accepted(#[case] name: &str, #[case] expected: KaniSolver) {
+    let docs = load_theorem_docs(&with_kani_fields(&format!("    solver: {name}\n")))
+        .expect("solver should parse");
+    let solver = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .and_then(|kani| kani.solver);
+
+    assert_eq!(solver, Some(expected));
+    assert_eq!(expected.as_str(), name);
+}
+
+#[test]
+fn unknown_solver_is_rejected() {
+    assert_load_err_contains(
+        &with_kani_fields("    solver: z3\n"),
+        "unknown variant `z3`",
+    );
+}
+
+#[test]
+fn allowlisted_extra_args_are_kept_in_order() {
+    let yaml = with_kani_fields(
+        "    extra_args: [--verbose, --output-format=terse, --concrete-playback=print]\n",
+    );
+    let docs = load_theorem_docs(&yaml).expect("extra_args should parse");
+    let extra_args = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .map(|kani| kani.extra_args.clone());
+
+    assert_eq!(
+        extra_args,
+        Some(vec![
+            "--verbose".to_owned(),
+            "--output-format=terse".to_owned(),
+            "--concrete-playback=print".to_owned(),
+        ])
+    );
+}
+
+#[rstest]
+#[case::unlisted_flag("[--harness=other]", "entry 1: '--harness=other'")]
+#[case::weakens_checks("[--quiet, --no-overflow-checks]", "entry 2: '--no-overflow-checks'")]
+#[case::unlisted_value("[--output-format=json]", "entry 1: '--output-format=json'")]
+#[case::missing_value("[--output-format]", "entry 1: '--output-format'")]
+#[case::switch_with_value("[--verbose=1]", "entry 1: '--verbose=1'")]
+fn unlisted_extra_args_are_rejected_at_the_entry(#[case] args: &str, #[case] expected: &str) {
+    let yaml = with_kani_fields(&format!("    extra_args: {args}\n"));
+    let error = load_theorem_docs(&yaml).expect_err("extra_args should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(&format!(
+            "Evidence.kani.extra_args {expected} is not an allowed Kani flag"
+        )),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 11);
+}
+
+#[test]
+fn rejection_lists_the_allowed_flags() {
+    assert_load_err_contains(
+        &with_kani_fields("    extra_args: [--cbmc-args]\n"),
+        "allowed flags are --verbose, --quiet, --keep-temps, \
+         --output-format=<regular|terse|old>, --concrete-playback=<print>",
+    );
+}
+
+#[test]
+fn resource_limits_are_parsed() {
+    let yaml = with_kani_fields("    timeout: 5m\n    memory_limit: 4GiB\n");
+    let docs = load_theorem_docs(&yaml).expect("limits should parse");
+    let kani = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .expect("kani evidence expected");
+
+    assert_eq!(
+        kani.timeout.map(KaniTimeout::as_duration),
+        Some(Duration::from_secs(300))
+    );
+    assert_eq!(kani.memory_limit.map(MemoryLimit::bytes), Some(4 << 30));
+}
+
+#[test]
+fn resource_limits_default_to_unlimited() {
+    let docs = load_theorem_docs(VALID_BASE).expect("base should parse");
+    let kani = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .expect("kani evidence expected");
+
+    assert_eq!(kani.timeout, None);
+    assert_eq!(kani.memory_limit, None);
+}
+
+#[rstest]
+#[case::timeout("    timeout: 30\n", "invalid timeout '30'")]
+#[case::zero_timeout("    timeout: 0s\n", "invalid timeout '0s': must be greater than zero")]
+#[case::memory("    memory_limit: lots\n", "invalid memory_limit 'lots'")]
+fn malformed_resource_limits_are_rejected(#[case] fields: &str, #[case] expected: &str) {
+    assert_load_err_contains(&with_kani_fields(fields), expected);
+}
+
+#[test]
+fn stubs_are_kept_in_order() {
+    let yaml = with_kani_fields(concat!(
+        "    stubs:\n",
+        "      - { original: std::time::SystemTime::now, stub: crate::stubs::fixed_now }\n",
+        "      - { original: getrandom::getrandom, stub: crate::stubs::zeroed }\n",
+    ));
+    let docs = load_theorem_docs(&yaml).expect("stubs should parse");
+    let stubs = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .map(|kani| kani.stubs.clone());
+
+    assert_eq!(
+        stubs,
+        Some(vec![
+            KaniStub {
+                original: "std::time::SystemTime::now".to_owned(),
+                stub: "crate::stubs::fixed_now".to_owned(),
+            },
+            KaniStub {
+                original: "getrandom::getrandom".to_owned(),
+                stub: "crate::stubs::zeroed".to_owned(),
+            },
+        ])
+    );
+}
+
+#[rstest]
+#[case::original(
+    "{ original: 'not a path', stub: crate::stub }",
+    "entry 1: original 'not a path' is not a valid Rust path"
+)]
+#[case::stub(
+    "{ original: crate::now, stub: '1 + 1' }",
+    "entry 1: stub '1 + 1' is not a valid Rust path"
+)]
+fn invalid_stub_paths_are_rejected_at_the_entry(#[case] entry: &str, #[case] expected: &str) {
+    let yaml = with_kani_fields(&format!("    stubs:\n      - {entry}\n"));
+    let error = load_theorem_docs(&yaml).expect_err("stub should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("Evidence.kani.stubs {expected}")),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 12);
+}
+
+#[test]
+fn functions_cannot_be_stubbed_twice() {
+    assert_load_err_contains(
+        &with_kani_fields(concat!(
+            "    stubs:\n",
+            "      - { original: crate::clock::now, stub: crate::stubs::zero }\n",
+            "      - { original: 'crate :: clock :: now', stub: crate::stubs::one }\n",
+        )),
+        "Evidence.kani.stubs entry 2: 'crate :: clock :: now' is already stubbed",
+    );
+}
+
+#[test]
+fn contracts_are_kept_in_order() {
+    let yaml =
+        with_kani_fields("    contracts: [crate::ledger::deposit, crate::ledger::withdraw]\n");
+    let docs = load_theorem_docs(&yaml).expect("contracts should parse");
+    let contracts = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .map(|kani| kani.contracts.clone());
+
+    assert_eq!(
+        contracts,
+        Some(vec![
+            "crate::ledger::deposit".to_owned(),
+            "crate::ledger::withdraw".to_owned(),
+        ])
+    );
+}
+
+#[rstest]
+#[case::invalid_path(
+    "['crate::ledger::deposit()']",
+    "entry 1: function 'crate::ledger::deposit()' is not a valid Rust path"
+)]
+#[case::repeated(
+    "[crate::ledger::deposit, crate::ledger::deposit]",
+    "entry 2: 'crate::ledger::deposit' is already listed"
+)]
+fn invalid_contracts_are_rejected_at_the_entry(#[case] contracts: &str, #[case] expected: &str) {
+    let yaml = with_kani_fields(&format!("    contracts: {contracts}\n"));
+    let error = load_theorem_docs(&yaml).expect_err("contracts should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("Evidence.kani.contracts {expected}")),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 11);
+}
+
+/// `VALID_BASE` with its global `unwind: 1` replaced by `unwind`.
+fn with_unwind(unwind: &str) -> String {
+    VALID_BASE.replacen("    unwind: 1\n", &format!("    unwind: {unwind}\n"), 1)
+}
+
+#[test]
+fn per_loop_unwind_bounds_are_parsed() {
+    let yaml = with_unwind("{ default: 4, drain_queue: 17, retry: 3 }");
+    let docs = load_theorem_docs(&yaml).expect("per-loop unwind should parse");
+    let kani = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .expect("kani evidence expected");
+
+    assert_eq!(kani.unwind, 4);
+    assert_eq!(
+        kani.loop_unwind
+            .iter()
+            .map(|(label, &bound)| (label.as_str(), bound))
+            .collect::<Vec<_>>(),
+        vec![("drain_queue", 17), ("retry", 3)]
+    );
+    assert_eq!(kani.unwindset().as_deref(), Some("drain_queue:17,retry:3"));
+}
+
+#[test]
+fn global_unwind_has_no_unwindset() {
+    let docs = load_theorem_docs(VALID_BASE).expect("base should parse");
+    let kani = docs
+        .first()
+        .and_then(|doc| doc.evidence.kani.first())
+        .expect("kani evidence expected");
+
+    assert!(kani.loop_unwind.is_empty());
+    assert_eq!(kani.unwindset(), None);
+}
+
+#[test]
+fn per_loop_unwind_requires_a_default() {
+    assert_load_err_contains(
+        &with_unwind("{ drain_queue: 17 }"),
+        "a per-loop unwind mapping must include a 'default' bound",
+    );
+}
+
+#[rstest]
+#[case::zero_bound(
+    "{ default: 4, retry: 0 }",
+    "loop 'retry': bound must be a positive integer"
+)]
+#[case::separator("{ default: 4, 'a:b': 2 }", "loop 'a:b': label must be non-empty")]
+#[case::whitespace(
+    "{ default: 4, 'drain queue': 2 }",
+    "loop 'drain queue': label must be"
+)]
+fn invalid_loop_unwind_entries_are_rejected(#[case] unwind: &str, #[case] expected: &str) {
+    let error = load_theorem_docs(&with_unwind(unwind)).expect_err("unwind should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("Evidence.kani.unwind {expected}")),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 9);
+}
@@ -0,0 +1,115 @@
+//! Validation tests for lists of named Kani configurations.
+
+use super::{VALID_BASE, assert_load_err_contains};
+use crate::schema::load_theorem_docs;
+use rstest::rstest;
+
+/// `VALID_BASE` with its Kani mapping replaced by the list `configs`.
+fn with_kani_configs(configs: &str) -> String {
+    VALID_BASE.replacen(
+        "  kani:\n    unwind: 1\n    expect: SUCCESS\n",
+        &format!("  kani:\n{configs}"),
+        1,
+    )
+}
+
+const QUICK_AND_DEEP: &str = concat!(
+    "    - name: quick\n",
+    "      unwind: 2\n",
+    "      expect: SUCCESS\n",
+    "    - name: nightly_deep\n",
+    "      unwind: 16\n",
+    "      expect: SUCCESS\n",
+    "      solver: kissat\n",
+);
+
+#[test]
+fn configurations_are_kept_in_order() {
+    let docs = load_theorem_docs(&with_kani_configs(QUICK_AND_DEEP)).expect("matrix should load");
+    let configs: Vec<_> = docs
+        .first()
+        .map(|doc| {
+            doc.evidence
+                .kani
+                .iter()
+                .map(|kani| (kani.name.clone(), kani.unwind))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    assert_eq!(
+        configs,
+        [
+            (Some("quick".to_owned()), 2),
+            (Some("nightly_deep".to_owned()), 16),
+        ]
+    );
+}
+
+#[test]
+fn a_single_unnamed_configuration_list_is_accepted() {
+    let docs = load_theorem_docs(&with_kani_configs(
+        "    - unwind: 1\n      expect: SUCCESS\n",
+    ))
+    .expect("one-entry list should load");
+
+    assert_eq!(docs.first().map(|doc| doc.evidence.kani.len()), Some(1));
+}
+
+#[test]
+fn an_empty_configuration_list_is_rejected() {
+    assert_load_err_contains(
+        &with_kani_configs("    []\n"),
+        "a mapping or a non-empty list of mappings",
+    );
+}
+
+#[rstest]
+#[case::missing_name(
+    "    - name: quick\n      unwind: 1\n      expect: SUCCESS\n    - unwind: 4\n      expect: SUCCESS\n",
+    "Evidence.kani entry 2: name is required when several Kani configurations are listed"
+)]
+#[case::repeated_name(
+    "    - name: quick\n      unwind: 1\n      expect: SUCCESS\n    - name: quick\n      unwind: 4\n      expect: SUCCESS\n",
+    "Evidence.kani configuration 'quick': name is already used by entry 1"
+)]
+#[case::camel_case_name(
+    "    - name: Quick\n      unwind: 1\n      expect: SUCCESS\n",
+    "Evidence.kani configuration 'Quick': name must be a lower snake-case identifier"
+)]
+#[case::double_underscore_name(
+    "    - name: quick__check\n      unwind: 1\n      expect: SUCCESS\n",
+    "name must be a lower snake-case identifier"
+)]
+fn invalid_configuration_names_are_rejected(#[case] configs: &str, #[case] expected: &str) {
+    assert_load_err_contains(&with_kani_configs(configs), expected);
+}
+
+#[test]
+fn configuration_failures_name_the_configuration() {
+    let yaml = with_kani_configs(&QUICK_AND_DEEP.replace("unwind: 16", "unwind: 0"));
+    let error = load_theorem_docs(&yaml).expect_err("zero unwind should fail");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(
+        error.to_string().contains(
+            "Evidence.kani configuration 'nightly_deep': \
+             Evidence.kani.unwind must be a positive integer"
+        ),
+        "got: {error}"
+    );
+    assert_eq!(diagnostic.location.line, 13);
+}
+
+#[test]
+fn repeat_loops_must_fit_the_smallest_unwind() {
+    let yaml = with_kani_configs(QUICK_AND_DEEP).replace(
+        "Witness:",
+        "Do:\n  - repeat: { times: 4, do: [{ assert: { expr: 'true', because: ok } }] }\nWitness:",
+    );
+
+    assert_load_err_contains(
+        &yaml,
+        "repeat.times 4 needs a Kani unwind of at least 5, but unwind is 2",
+    );
+}
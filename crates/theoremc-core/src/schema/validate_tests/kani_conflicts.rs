@@ -0,0 +1,81 @@
+//! Validation tests for contradictory Kani evidence options.
+
+use super::VALID_BASE;
+use crate::schema::load_theorem_docs;
+use rstest::rstest;
+
+const WITNESS: &str = "Witness:\n  - cover: 'true'\n    because: always reachable\n";
+
+/// `VALID_BASE` expecting `expect`, with `fields` appended to its Kani
+/// evidence from line 11.
+fn with_kani(expect: &str, fields: &str) -> String {
+    VALID_BASE.replacen(
+        "    expect: SUCCESS\n",
+        &format!("    expect: {expect}\n{fields}"),
+        1,
+    )
+}
+
+#[rstest]
+#[case::unreachable_with_witness(
+    with_kani("UNREACHABLE", ""),
+    "schema.kani.unreachable_with_witness",
+    "Evidence.kani.expect UNREACHABLE conflicts with the Witness section",
+    2
+)]
+#[case::stubbed_contract(
+    with_kani(
+        "SUCCESS",
+        concat!(
+            "    contracts: [crate::ledger::deposit]\n",
+            "    stubs:\n",
+            "      - { original: crate::ledger::deposit, stub: crate::stubs::deposit }\n",
+        ),
+    ),
+    "schema.kani.stubbed_contract",
+    "Evidence.kani.stubs entry 1: 'crate::ledger::deposit' is listed in contracts",
+    13
+)]
+#[case::vacuous_playback(
+    with_kani(
+        "FAILURE",
+        "    allow_vacuous: true\n    vacuity_because: smoke test\n    playback: true\n",
+    ),
+    "schema.kani.vacuous_playback",
+    "Evidence.kani.playback conflicts with allow_vacuous on an expected FAILURE",
+    11
+)]
+fn conflicting_options_have_their_own_code(
+    #[case] yaml: String,
+    #[case] code: &str,
+    #[case] expected: &str,
+    #[case] line: usize,
+) {
+    let error = load_theorem_docs(&yaml).expect_err("conflict should be rejected");
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+
+    assert!(error.to_string().contains(expected), "got: {error}");
+    assert_eq!(diagnostic.code.as_str(), code);
+    assert_eq!(diagnostic.location.line, line);
+}
+
+#[rstest]
+#[case::vacuous_unreachable(
+    with_kani(
+        "UNREACHABLE",
+        "    allow_vacuous: true\n    vacuity_because: guarded by an impossible branch\n",
+    )
+    .replacen(WITNESS, "", 1)
+)]
+#[case::separate_stub(with_kani(
+    "SUCCESS",
+    concat!(
+        "    contracts: [crate::ledger::deposit]\n",
+        "    stubs:\n",
+        "      - { original: crate::clock::now, stub: crate::stubs::now }\n",
+    ),
+))]
+#[case::playback_of_real_failure(with_kani("FAILURE", "    playback: true\n"))]
+fn compatible_options_are_accepted(#[case] yaml: String) {
+    load_theorem_docs(&yaml).expect("options should be compatible");
+}
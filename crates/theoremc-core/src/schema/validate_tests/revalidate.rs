@@ -0,0 +1,85 @@
+//! Tests for re-validating documents through `TheoremDoc::validate`.
+
+use pretty_assertions::assert_eq;
+
+use super::{NAMES_BASE, VALID_BASE};
+use crate::schema::{SchemaError, TheoremDoc, TheoremExpr, load_theorem_docs};
+
+fn load_one(yaml: &str) -> TheoremDoc {
+    load_theorem_docs(yaml)
+        .expect("source should load")
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem")
+}
+
+fn reasons(errors: &[SchemaError]) -> Vec<String> {
+    errors.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn loaded_documents_revalidate_cleanly() {
+    assert!(load_one(VALID_BASE).validate().is_ok());
+    assert!(load_one(NAMES_BASE).validate().is_ok());
+}
+
+#[test]
+fn every_failing_check_is_reported_in_loader_order() {
+    let mut doc = load_one(VALID_BASE);
+    doc.about = "  ".to_owned();
+    doc.witness.clear();
+    doc.prove.clear();
+
+    let errors = doc.validate().expect_err("edited document should fail");
+
+    assert_eq!(
+        reasons(&errors),
+        [
+            "validation failed for theorem 'T': About must be non-empty after trimming",
+            "validation failed for theorem 'T': Prove section must contain at least one \
+             assertion",
+            "validation failed for theorem 'T': Witness section must contain at least one \
+             witness when allow_vacuous is false (the default)",
+        ]
+    );
+}
+
+#[test]
+fn revalidation_matches_the_loader_for_one_violation() {
+    let yaml = VALID_BASE.replacen("assert: 'true'", "assert: 'missing > 0'", 1);
+    let loader_error = load_theorem_docs(&yaml)
+        .expect_err("source should fail")
+        .to_string();
+    let mut doc = load_one(VALID_BASE);
+    if let Some(assertion) = doc.prove.first_mut() {
+        assertion.assert_expr =
+            TheoremExpr::new("missing > 0").expect("expression should be valid");
+    }
+
+    let errors = doc.validate().expect_err("edited document should fail");
+
+    assert_eq!(reasons(&errors), [loader_error]);
+}
+
+#[test]
+fn type_aliases_are_expanded_before_checking() {
+    let yaml = VALID_BASE.replacen(
+        "Prove:",
+        "Types:\n  Amount: u64\nForall:\n  amount: { type: Amount, min: 1 }\nProve:",
+        1,
+    );
+    let mut doc = load_one(&yaml);
+    doc.forall.values_mut().for_each(|ty| *ty = "Amount".into());
+
+    assert_eq!(doc.validate().map_err(|errors| reasons(&errors)), Ok(()));
+}
+
+#[test]
+fn validation_errors_carry_no_diagnostic() {
+    let mut doc = load_one(VALID_BASE);
+    doc.about.clear();
+
+    let errors = doc.validate().expect_err("edited document should fail");
+
+    assert!(errors.iter().all(|error| error.diagnostic().is_none()));
+}
@@ -1,11 +1,41 @@
 //! Rust type and lifetime validation for theorem declarations.
 //!
-//! This module centralizes type checks shared by `Forall` declarations and
-//! action signatures while the parent validation module owns check ordering.
+//! This module centralizes type checks shared by `Types` aliases, `Forall`
+//! declarations, and action signatures while the parent validation module owns
+//! check ordering.
 
 use super::{ValidationResult, fail};
+use crate::schema::expr::free_identifiers;
+use crate::schema::forall::{ForallDomain, ForallDomainField, check_domain, check_unwind};
+use crate::schema::identifier::validate_identifier;
 use crate::schema::rust_type;
+use crate::schema::type_alias::first_unresolved_alias_reference;
 use crate::schema::types::TheoremDoc;
+use crate::schema::validation_reason::ValidationReasonKind;
+
+/// Validates `Types` alias names, alias types, and declaration order.
+pub(super) fn validate_type_aliases(doc: &TheoremDoc) -> ValidationResult {
+    for (alias, ty) in &doc.types {
+        validate_identifier(alias)
+            .map_err(|r| fail(doc, format!("Types alias '{alias}': name {r}"), None))?;
+        validate_type_without_free_named_lifetime(
+            doc,
+            ty,
+            &format!("Types alias '{alias}': type"),
+        )?;
+    }
+    if let Some(unresolved) = first_unresolved_alias_reference(&doc.types) {
+        return Err(fail(
+            doc,
+            format!(
+                "Types alias '{}' references alias '{}' before it is declared",
+                unresolved.alias, unresolved.referenced,
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
 
 /// Validates all `Forall` type strings and rejects free named lifetimes.
 pub(super) fn validate_forall_types(doc: &TheoremDoc) -> ValidationResult {
@@ -19,6 +49,67 @@ pub(super) fn validate_forall_types(doc: &TheoremDoc) -> ValidationResult {
     Ok(())
 }
 
+/// Validates that structured `Forall` domains fit their declared types and
+/// that bounded vectors fit within the smallest Kani unwind bound.
+pub(super) fn validate_forall_domains(doc: &TheoremDoc) -> ValidationResult {
+    let unwind = doc.evidence.min_kani_unwind();
+    for (index, (name, ty)) in doc.forall.iter().enumerate() {
+        let Some(domain) = doc.forall_domains.get(name) else {
+            continue;
+        };
+        check_domain(domain, ty)
+            .and_then(|()| unwind.map_or(Ok(()), |bound| check_unwind(domain, bound)))
+            .map_err(|mismatch| {
+                fail(
+                    doc,
+                    format!("Forall entry '{name}': {}", mismatch.reason),
+                    Some(ValidationReasonKind::ForallDomain {
+                        index,
+                        field: mismatch.field,
+                    }),
+                )
+            })?;
+    }
+    Ok(())
+}
+
+/// Validates that derived `Forall` entries refer only to variables declared
+/// before them, so each value can be computed in declaration order.
+pub(super) fn validate_forall_derivations(doc: &TheoremDoc) -> ValidationResult {
+    for (index, name) in doc.forall.keys().enumerate() {
+        let Some(expr) = doc
+            .forall_domains
+            .get(name)
+            .and_then(ForallDomain::derived_expr)
+        else {
+            continue;
+        };
+        let identifiers = free_identifiers(expr);
+        let Some(later) = doc
+            .forall
+            .keys()
+            .skip(index)
+            .find(|candidate| identifiers.iter().any(|ident| ident == candidate.as_str()))
+        else {
+            continue;
+        };
+        let reason = if later == name {
+            format!("Forall entry '{name}': from refers to itself")
+        } else {
+            format!("Forall entry '{name}': from refers to '{later}', which is declared after it")
+        };
+        return Err(fail(
+            doc,
+            reason,
+            Some(ValidationReasonKind::ForallDomain {
+                index,
+                field: ForallDomainField::From,
+            }),
+        ));
+    }
+    Ok(())
+}
+
 /// Validates a Rust type string and rejects free named lifetimes.
 pub(super) fn validate_type_without_free_named_lifetime(
     doc: &TheoremDoc,
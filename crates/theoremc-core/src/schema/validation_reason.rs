@@ -4,8 +4,10 @@
 //! field that failed. This lets diagnostics choose source locations from typed
 //! data instead of reparsing human-readable text.
 
-use super::diagnostic::SchemaDiagnostic;
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode};
 use super::error::SchemaError;
+use super::forall::ForallDomainField;
+use super::source_id::SourceId;
 use super::types::TheoremDoc;
 
 /// Indexed field within a repeated validation section.
@@ -37,6 +39,15 @@ impl IndexedValidationSection {
         }
     }
 
+    /// Returns the key of the section's expression field.
+    pub(crate) const fn expr_label(self) -> &'static str {
+        match self {
+            Self::Prove => "assert",
+            Self::Assume => "expr",
+            Self::Witness => "cover",
+        }
+    }
+
     pub(crate) const fn reason_kind(
         self,
         index: usize,
@@ -50,9 +61,22 @@ impl IndexedValidationSection {
     }
 }
 
+/// Field within one `Traces` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraceField {
+    /// The requirement `id`.
+    Id,
+    /// The requirements `system`.
+    System,
+    /// The optional requirement `url`.
+    Url,
+}
+
 /// Semantic classification for validation diagnostics.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ValidationReasonKind {
+    /// The `Schema` version is outside the supported range.
+    SchemaVersion,
     /// The `About` field is blank.
     AboutEmpty,
     /// A field in one `Prove` entry failed validation.
@@ -62,6 +86,11 @@ pub(crate) enum ValidationReasonKind {
         /// Field within the entry.
         field: IndexedValidationField,
     },
+    /// The `step` reference of one `Prove` entry names no `Do` step.
+    ProveStep {
+        /// Zero-based entry index.
+        index: usize,
+    },
     /// A field in one `Assume` entry failed validation.
     Assume {
         /// Zero-based entry index.
@@ -76,14 +105,92 @@ pub(crate) enum ValidationReasonKind {
         /// Field within the entry.
         field: IndexedValidationField,
     },
+    /// A field in one `Traces` entry failed validation.
+    Trace {
+        /// Zero-based entry index.
+        index: usize,
+        /// Field within the entry.
+        field: TraceField,
+    },
+    /// A structured `Forall` entry's domain does not fit its type.
+    ForallDomain {
+        /// Zero-based position of the entry in `Forall`.
+        index: usize,
+        /// Part of the entry that was rejected.
+        field: ForallDomainField,
+    },
+    /// One Kani configuration failed validation.
+    Kani {
+        /// Zero-based position of the configuration in `Evidence.kani`.
+        config: usize,
+        /// What was rejected.
+        reason: KaniReason,
+    },
+}
+
+/// Validation failure within one Kani configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KaniReason {
+    /// The configuration `name` is missing, malformed, or repeated.
+    Name,
     /// Kani `unwind` is zero.
-    KaniUnwind,
+    Unwind,
     /// Kani `allow_vacuous: true` omitted `vacuity_because`.
-    KaniAllowVacuousRequired,
+    AllowVacuousRequired,
     /// Kani `vacuity_because` is present but blank.
-    KaniVacuityBecauseNonEmpty,
+    VacuityBecauseNonEmpty,
     /// Kani non-vacuous policy requires at least one witness.
-    KaniWitnessRequired,
+    WitnessRequired,
+    /// One Kani `extra_args` entry is not an allowed flag.
+    ExtraArg {
+        /// Zero-based entry index.
+        index: usize,
+    },
+    /// One Kani `stubs` entry has an invalid path or repeats a function.
+    Stub {
+        /// Zero-based entry index.
+        index: usize,
+    },
+    /// One Kani `contracts` entry has an invalid path or repeats a function.
+    Contract {
+        /// Zero-based entry index.
+        index: usize,
+    },
+    /// The Kani `counterexample` block is misplaced or has an invalid entry.
+    Counterexample,
+    /// Kani `expect: UNREACHABLE` is combined with witnesses.
+    UnreachableWithWitness,
+    /// One Kani `stubs` entry replaces a function listed in `contracts`.
+    StubbedContract {
+        /// Zero-based stub entry index.
+        index: usize,
+    },
+    /// Kani `playback` is combined with a possibly vacuous expected failure.
+    VacuousPlayback,
+}
+
+impl ValidationReasonKind {
+    /// Returns the diagnostic code for failures of this kind: a dedicated
+    /// code for unsupported schema versions and for each Kani option
+    /// conflict, and the generic validation code otherwise.
+    pub(crate) const fn diagnostic_code(self) -> SchemaDiagnosticCode {
+        match self {
+            Self::SchemaVersion => SchemaDiagnosticCode::UnsupportedSchemaVersion,
+            Self::Kani {
+                reason: KaniReason::UnreachableWithWitness,
+                ..
+            } => SchemaDiagnosticCode::KaniUnreachableWithWitness,
+            Self::Kani {
+                reason: KaniReason::StubbedContract { .. },
+                ..
+            } => SchemaDiagnosticCode::KaniStubbedContract,
+            Self::Kani {
+                reason: KaniReason::VacuousPlayback,
+                ..
+            } => SchemaDiagnosticCode::KaniVacuousPlayback,
+            _ => SchemaDiagnosticCode::ValidationFailure,
+        }
+    }
 }
 
 /// Internal validation failure before conversion to the public error type.
@@ -107,10 +214,30 @@ impl ValidationFailure {
         }
     }
 
+    /// Creates a failure of the theorem named `theorem` found before its
+    /// document was converted.
+    pub(crate) const fn named(
+        theorem: String,
+        reason: String,
+        reason_kind: Option<ValidationReasonKind>,
+    ) -> Self {
+        Self {
+            theorem,
+            reason,
+            reason_kind,
+        }
+    }
+
     pub(crate) fn reason(&self) -> &str {
         &self.reason
     }
 
+    /// Notes in the reason that the failing entry was merged from the
+    /// `Include` fragment at `path`.
+    pub(crate) fn mark_included_from(&mut self, path: &SourceId) {
+        self.reason = format!("{} (included from '{}')", self.reason, path.as_str());
+    }
+
     pub(crate) const fn reason_kind(&self) -> Option<ValidationReasonKind> {
         self.reason_kind
     }
@@ -6,8 +6,8 @@
 //! level and preserving map insertion order via `IndexMap`.
 
 use indexmap::IndexMap;
-use serde::Deserialize;
 use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
 
 /// A YAML value that may appear in theorem action arguments or placeholder
@@ -31,6 +31,144 @@ pub enum TheoremValue {
     Mapping(IndexMap<String, Self>),
 }
 
+impl TheoremValue {
+    /// Returns the boolean when the value is [`TheoremValue::Bool`].
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer when the value is [`TheoremValue::Integer`].
+    #[must_use]
+    pub const fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the number when the value is [`TheoremValue::Float`] or
+    /// [`TheoremValue::Integer`], so `1` and `1.0` read alike.
+    #[must_use]
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "integers beyond 2^53 are not expected where a float is read"
+            )]
+            Self::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the string when the value is [`TheoremValue::String`].
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the items when the value is [`TheoremValue::Sequence`].
+    #[must_use]
+    pub fn as_sequence(&self) -> Option<&[Self]> {
+        match self {
+            Self::Sequence(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries when the value is [`TheoremValue::Mapping`].
+    #[must_use]
+    pub const fn as_mapping(&self) -> Option<&IndexMap<String, Self>> {
+        match self {
+            Self::Mapping(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Looks up a nested value by a dotted path such as `limits.max` or
+    /// `items.0.name`.
+    ///
+    /// Each segment selects a mapping key or, on a sequence, a 0-based
+    /// index. The empty path selects the value itself. Returns `None` when a
+    /// segment is missing or the value at that point is a scalar.
+    ///
+    /// # Examples
+    ///
+    ///     use theoremc_core::schema::TheoremValue;
+    ///
+    ///     let value: TheoremValue =
+    ///         serde_saphyr::from_str("{ limits: [ { max: 3 } ] }").unwrap();
+    ///     assert_eq!(value.get("limits.0.max").and_then(TheoremValue::as_i64), Some(3));
+    ///     assert_eq!(value.get("limits.1"), None);
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<&Self> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.').try_fold(self, Self::child)
+    }
+
+    /// Iterates over the items of a sequence; other values yield nothing.
+    pub fn items(&self) -> impl Iterator<Item = &Self> {
+        self.as_sequence().unwrap_or_default().iter()
+    }
+
+    /// Iterates over the key-value pairs of a mapping, in insertion order;
+    /// other values yield nothing.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &Self)> {
+        self.as_mapping()
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Returns a human-readable label for the variant, such as
+    /// `an integer`, used in error messages.
+    pub(crate) const fn kind(&self) -> &'static str {
+        match self {
+            Self::Bool(_) => "a boolean",
+            Self::Integer(_) => "an integer",
+            Self::Float(_) => "a float",
+            Self::String(_) => "a string",
+            Self::Sequence(_) => "a sequence",
+            Self::Mapping(_) => "a mapping",
+        }
+    }
+
+    fn child(&self, segment: &str) -> Option<&Self> {
+        match self {
+            Self::Mapping(entries) => entries.get(segment),
+            Self::Sequence(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get(index)),
+            Self::Bool(_) | Self::Integer(_) | Self::Float(_) | Self::String(_) => None,
+        }
+    }
+}
+
+/// Serializes the value as the matching plain scalar, sequence, or mapping,
+/// so it deserializes back to the same variant.
+impl Serialize for TheoremValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Integer(value) => serializer.serialize_i64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Sequence(items) => serializer.collect_seq(items),
+            Self::Mapping(entries) => serializer.collect_map(entries),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for TheoremValue {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -114,3 +252,18 @@ impl<'de> Visitor<'de> for TheoremValueVisitor {
         Ok(TheoremValue::Mapping(entries))
     }
 }
+
+#[path = "value_convert.rs"]
+mod convert;
+
+#[cfg(feature = "json")]
+#[path = "value_json.rs"]
+mod json;
+
+pub use convert::ValueConversionError;
+#[cfg(feature = "json")]
+pub use json::JsonValueError;
+
+#[cfg(test)]
+#[path = "value_tests.rs"]
+mod tests;
@@ -0,0 +1,116 @@
+//! Fallible conversions from [`TheoremValue`] into Rust primitives.
+//!
+//! Each conversion borrows the value and accepts exactly one variant, except
+//! that `f64` also accepts integers, matching [`TheoremValue::as_f64`].
+
+use super::TheoremValue;
+
+/// Errors produced when converting a [`TheoremValue`] into a Rust type.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueConversionError {
+    /// The value is a different variant from the one the type needs.
+    #[error("expected {expected}, found {found}")]
+    TypeMismatch {
+        /// The kind of value the target type needs, such as `an integer`.
+        expected: &'static str,
+        /// The kind of value found.
+        found: &'static str,
+    },
+
+    /// A negative integer was converted to an unsigned type.
+    #[error("expected a non-negative integer, found {value}")]
+    Negative {
+        /// The negative integer.
+        value: i64,
+    },
+
+    /// An item of a sequence failed to convert.
+    #[error("item {index}: {source}")]
+    Item {
+        /// 0-based position of the item in the sequence.
+        index: usize,
+        /// Why the item failed to convert.
+        source: Box<Self>,
+    },
+}
+
+const fn mismatch(expected: &'static str, found: &TheoremValue) -> ValueConversionError {
+    ValueConversionError::TypeMismatch {
+        expected,
+        found: found.kind(),
+    }
+}
+
+impl TryFrom<&TheoremValue> for bool {
+    type Error = ValueConversionError;
+
+    fn try_from(value: &TheoremValue) -> Result<Self, Self::Error> {
+        value.as_bool().ok_or_else(|| mismatch("a boolean", value))
+    }
+}
+
+impl TryFrom<&TheoremValue> for i64 {
+    type Error = ValueConversionError;
+
+    fn try_from(value: &TheoremValue) -> Result<Self, Self::Error> {
+        value.as_i64().ok_or_else(|| mismatch("an integer", value))
+    }
+}
+
+impl TryFrom<&TheoremValue> for u64 {
+    type Error = ValueConversionError;
+
+    fn try_from(value: &TheoremValue) -> Result<Self, Self::Error> {
+        let integer = i64::try_from(value)?;
+        Self::try_from(integer).map_err(|_| ValueConversionError::Negative { value: integer })
+    }
+}
+
+impl TryFrom<&TheoremValue> for f64 {
+    type Error = ValueConversionError;
+
+    fn try_from(value: &TheoremValue) -> Result<Self, Self::Error> {
+        value.as_f64().ok_or_else(|| mismatch("a number", value))
+    }
+}
+
+impl TryFrom<&TheoremValue> for String {
+    type Error = ValueConversionError;
+
+    fn try_from(value: &TheoremValue) -> Result<Self, Self::Error> {
+        value
+            .as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| mismatch("a string", value))
+    }
+}
+
+/// Converts each item of a sequence, reporting the position of the first
+/// item that fails.
+impl<'a, T> TryFrom<&'a TheoremValue> for Vec<T>
+where
+    T: TryFrom<&'a TheoremValue, Error = ValueConversionError>,
+{
+    type Error = ValueConversionError;
+
+    fn try_from(value: &'a TheoremValue) -> Result<Self, Self::Error> {
+        let items = value
+            .as_sequence()
+            .ok_or_else(|| mismatch("a sequence", value))?;
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                T::try_from(item).map_err(|error| ValueConversionError::Item {
+                    index,
+                    source: Box::new(error),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "value_convert_tests.rs"]
+mod tests;
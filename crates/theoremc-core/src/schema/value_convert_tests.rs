@@ -0,0 +1,59 @@
+//! Unit tests for conversions from `TheoremValue` into Rust primitives.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::ValueConversionError;
+use crate::schema::TheoremValue;
+
+fn parse(yaml: &str) -> TheoremValue {
+    serde_saphyr::from_str(yaml).expect("value should parse")
+}
+
+#[test]
+fn scalars_convert_to_matching_primitives() {
+    assert_eq!(bool::try_from(&parse("true")), Ok(true));
+    assert_eq!(i64::try_from(&parse("-4")), Ok(-4));
+    assert_eq!(u64::try_from(&parse("4")), Ok(4));
+    assert_eq!(f64::try_from(&parse("1.5")), Ok(1.5));
+    assert_eq!(f64::try_from(&parse("2")), Ok(2.0));
+    assert_eq!(String::try_from(&parse("alice")), Ok("alice".to_owned()));
+}
+
+#[test]
+fn sequences_convert_item_by_item() {
+    let value = parse("[[1, 2], [3]]");
+
+    assert_eq!(
+        Vec::<Vec<u64>>::try_from(&value),
+        Ok(vec![vec![1, 2], vec![3]])
+    );
+}
+
+#[rstest]
+#[case::bool_from_string(bool::try_from(&parse("alice")).map(drop), "expected a boolean, found a string")]
+#[case::integer_from_float(i64::try_from(&parse("1.5")).map(drop), "expected an integer, found a float")]
+#[case::float_from_bool(f64::try_from(&parse("true")).map(drop), "expected a number, found a boolean")]
+#[case::string_from_mapping(String::try_from(&parse("{ a: 1 }")).map(drop), "expected a string, found a mapping")]
+#[case::negative_unsigned(u64::try_from(&parse("-1")).map(drop), "expected a non-negative integer, found -1")]
+#[case::vec_from_scalar(Vec::<i64>::try_from(&parse("3")).map(drop), "expected a sequence, found an integer")]
+fn mismatched_values_are_described(
+    #[case] result: Result<(), ValueConversionError>,
+    #[case] expected: &str,
+) {
+    let error = result.expect_err("conversion should fail");
+
+    assert_eq!(error.to_string(), expected);
+}
+
+#[test]
+fn failing_items_are_located() {
+    let error =
+        Vec::<Vec<String>>::try_from(&parse("[[a], [b, 1]]")).expect_err("conversion should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "item 1: item 1: expected a string, found an integer"
+    );
+    assert!(matches!(error, ValueConversionError::Item { index: 1, .. }));
+}
@@ -0,0 +1,147 @@
+//! Conversions between [`TheoremValue`] and [`serde_json::Value`].
+//!
+//! Enabled by the `json` feature. The crate always depends on `serde_json`
+//! for caches, ledgers, and JSON sources, but keeps its types out of the
+//! public API unless the feature is on. JSON objects use `serde_json`'s own
+//! map, so keys are sorted unless something else in the build enables its
+//! `preserve_order` feature.
+
+use indexmap::IndexMap;
+use serde_json::{Number, Value};
+
+use super::TheoremValue;
+
+/// Errors produced when converting a [`serde_json::Value`] into a
+/// [`TheoremValue`].
+///
+/// `path` locates the offending value in the dotted form accepted by
+/// [`TheoremValue::get`], and is empty for the root value.
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonValueError {
+    /// A JSON `null`, which theorem values cannot hold.
+    #[error(
+        "{}: null values are not permitted in theorem documents",
+        describe(path)
+    )]
+    Null {
+        /// Dotted path of the null value.
+        path: String,
+    },
+
+    /// A number that fits neither an `i64` nor an `f64`, such as an
+    /// unsigned integer above `i64::MAX`.
+    #[error("{}: number {number} is out of range for i64", describe(path))]
+    NumberOutOfRange {
+        /// Dotted path of the number.
+        path: String,
+        /// The number as written.
+        number: String,
+    },
+}
+
+fn describe(path: &str) -> String {
+    if path.is_empty() {
+        "root value".to_owned()
+    } else {
+        format!("value at '{path}'")
+    }
+}
+
+/// Converts the value to JSON, keeping every mapping entry.
+///
+/// Non-finite floats, which JSON cannot represent, become `null`.
+impl From<TheoremValue> for Value {
+    fn from(value: TheoremValue) -> Self {
+        match value {
+            TheoremValue::Bool(flag) => Self::Bool(flag),
+            TheoremValue::Integer(number) => Self::from(number),
+            TheoremValue::Float(number) => Self::from(number),
+            TheoremValue::String(text) => Self::String(text),
+            TheoremValue::Sequence(items) => items.into_iter().map(Self::from).collect(),
+            TheoremValue::Mapping(entries) => Self::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, entry)| (key, Self::from(entry)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Converts JSON to a theorem value, rejecting `null` anywhere in it.
+///
+/// # Examples
+///
+///     use serde_json::json;
+///     use theoremc_core::schema::TheoremValue;
+///
+///     let value = TheoremValue::try_from(json!({ "limits": [1, 2] })).unwrap();
+///     assert_eq!(value.get("limits.1").and_then(TheoremValue::as_i64), Some(2));
+///
+///     let error = TheoremValue::try_from(json!({ "owner": null })).unwrap_err();
+///     assert_eq!(
+///         error.to_string(),
+///         "value at 'owner': null values are not permitted in theorem documents",
+///     );
+impl TryFrom<Value> for TheoremValue {
+    type Error = JsonValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        from_json(value, "")
+    }
+}
+
+fn from_json(value: Value, path: &str) -> Result<TheoremValue, JsonValueError> {
+    match value {
+        Value::Null => Err(JsonValueError::Null {
+            path: path.to_owned(),
+        }),
+        Value::Bool(flag) => Ok(TheoremValue::Bool(flag)),
+        Value::Number(number) => from_number(&number, path),
+        Value::String(text) => Ok(TheoremValue::String(text)),
+        Value::Array(items) => items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| from_json(item, &child_path(path, &index.to_string())))
+            .collect::<Result<Vec<_>, _>>()
+            .map(TheoremValue::Sequence),
+        Value::Object(entries) => entries
+            .into_iter()
+            .map(|(key, entry)| {
+                let converted = from_json(entry, &child_path(path, &key))?;
+                Ok((key, converted))
+            })
+            .collect::<Result<IndexMap<_, _>, _>>()
+            .map(TheoremValue::Mapping),
+    }
+}
+
+fn from_number(number: &Number, path: &str) -> Result<TheoremValue, JsonValueError> {
+    let out_of_range = || JsonValueError::NumberOutOfRange {
+        path: path.to_owned(),
+        number: number.to_string(),
+    };
+    if let Some(integer) = number.as_i64() {
+        return Ok(TheoremValue::Integer(integer));
+    }
+    if number.is_u64() {
+        return Err(out_of_range());
+    }
+    number
+        .as_f64()
+        .map(TheoremValue::Float)
+        .ok_or_else(out_of_range)
+}
+
+fn child_path(parent: &str, segment: &str) -> String {
+    if parent.is_empty() {
+        segment.to_owned()
+    } else {
+        format!("{parent}.{segment}")
+    }
+}
+
+#[cfg(test)]
+#[path = "value_json_tests.rs"]
+mod tests;
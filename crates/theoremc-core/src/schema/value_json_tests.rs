@@ -0,0 +1,75 @@
+//! Unit tests for `TheoremValue` and `serde_json::Value` conversions.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+use super::JsonValueError;
+use crate::schema::TheoremValue;
+
+fn parse(yaml: &str) -> TheoremValue {
+    serde_saphyr::from_str(yaml).expect("value should parse")
+}
+
+#[test]
+fn values_convert_to_json() {
+    let value = parse("{ name: alice, count: 2, ratio: 0.5, open: true, ids: [1, 2] }");
+
+    assert_eq!(
+        Value::from(value),
+        json!({ "name": "alice", "count": 2, "ratio": 0.5, "open": true, "ids": [1, 2] })
+    );
+}
+
+#[test]
+fn mappings_survive_both_directions() {
+    let value = parse("{ zebra: 1, apple: 2, mango: 3 }");
+
+    let converted = Value::from(value.clone());
+
+    assert_eq!(converted, json!({ "apple": 2, "mango": 3, "zebra": 1 }));
+    assert_eq!(TheoremValue::try_from(converted), Ok(value));
+}
+
+#[test]
+fn non_finite_floats_become_null() {
+    assert_eq!(Value::from(TheoremValue::Float(f64::NAN)), Value::Null);
+}
+
+#[rstest]
+#[case::scalar(json!(-3), TheoremValue::Integer(-3))]
+#[case::float(json!(2.5), TheoremValue::Float(2.5))]
+#[case::nested(
+    json!({ "owner": { "tags": ["a"] } }),
+    parse("{ owner: { tags: [a] } }")
+)]
+fn json_converts_to_values(#[case] input: Value, #[case] expected: TheoremValue) {
+    assert_eq!(TheoremValue::try_from(input), Ok(expected));
+}
+
+#[rstest]
+#[case::root(json!(null), JsonValueError::Null { path: String::new() })]
+#[case::nested(
+    json!({ "accounts": [{ "id": 1 }, { "id": null }] }),
+    JsonValueError::Null { path: "accounts.1.id".to_owned() }
+)]
+#[case::too_large(
+    json!({ "big": u64::MAX }),
+    JsonValueError::NumberOutOfRange {
+        path: "big".to_owned(),
+        number: u64::MAX.to_string(),
+    }
+)]
+fn unrepresentable_json_is_rejected(#[case] input: Value, #[case] expected: JsonValueError) {
+    assert_eq!(TheoremValue::try_from(input), Err(expected));
+}
+
+#[test]
+fn root_errors_name_the_root_value() {
+    let error = TheoremValue::try_from(Value::Null).expect_err("null should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        "root value: null values are not permitted in theorem documents"
+    );
+}
@@ -0,0 +1,102 @@
+//! Unit tests for `TheoremValue` accessors and path lookup.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::TheoremValue;
+
+fn parse(yaml: &str) -> TheoremValue {
+    serde_saphyr::from_str(yaml).expect("value should parse")
+}
+
+#[test]
+fn scalar_accessors_match_only_their_variant() {
+    let flag = parse("true");
+    let count = parse("7");
+    let ratio = parse("0.5");
+    let name = parse("alice");
+
+    assert_eq!(flag.as_bool(), Some(true));
+    assert_eq!(count.as_i64(), Some(7));
+    assert_eq!(ratio.as_f64(), Some(0.5));
+    assert_eq!(name.as_str(), Some("alice"));
+    assert_eq!(flag.as_i64(), None);
+    assert_eq!(count.as_str(), None);
+    assert_eq!(ratio.as_i64(), None);
+    assert_eq!(name.as_bool(), None);
+}
+
+#[test]
+fn integers_read_as_floats() {
+    assert_eq!(parse("3").as_f64(), Some(3.0));
+}
+
+#[test]
+fn collection_accessors_expose_their_contents() {
+    let list = parse("[1, 2]");
+    let map = parse("{ a: 1 }");
+
+    assert_eq!(
+        list.as_sequence(),
+        Some([TheoremValue::Integer(1), TheoremValue::Integer(2)].as_slice())
+    );
+    assert_eq!(
+        map.as_mapping().and_then(|entries| entries.get("a")),
+        Some(&TheoremValue::Integer(1))
+    );
+    assert_eq!(list.as_mapping(), None);
+    assert_eq!(map.as_sequence(), None);
+}
+
+const NESTED: &str = concat!(
+    "label: root\n",
+    "owner: { name: alice }\n",
+    "accounts:\n",
+    "  - { id: acc-1 }\n",
+    "  - { id: acc-2 }\n",
+);
+
+#[rstest]
+#[case::mapping_key("owner.name", Some("alice"))]
+#[case::sequence_index("accounts.1.id", Some("acc-2"))]
+#[case::missing_key("owner.email", None)]
+#[case::index_out_of_range("accounts.2.id", None)]
+#[case::non_numeric_index("accounts.first", None)]
+#[case::through_scalar("label.more", None)]
+fn get_follows_dotted_paths(#[case] path: &str, #[case] expected: Option<&str>) {
+    let value = parse(NESTED);
+
+    assert_eq!(value.get(path).and_then(TheoremValue::as_str), expected);
+}
+
+#[test]
+fn empty_path_selects_the_value_itself() {
+    let value = parse(NESTED);
+
+    assert_eq!(value.get(""), Some(&value));
+}
+
+#[test]
+fn items_and_entries_iterate_in_order() {
+    let list = parse("[a, b]");
+    let map = parse("{ z: 1, a: 2 }");
+
+    let items: Vec<_> = list.items().filter_map(TheoremValue::as_str).collect();
+    let entries: Vec<_> = map
+        .entries()
+        .map(|(key, value)| (key, value.as_i64()))
+        .collect();
+
+    assert_eq!(items, ["a", "b"]);
+    assert_eq!(entries, [("z", Some(1)), ("a", Some(2))]);
+}
+
+#[test]
+fn iterators_are_empty_for_other_variants() {
+    let scalar = parse("1");
+
+    assert_eq!(scalar.items().count(), 0);
+    assert_eq!(scalar.entries().count(), 0);
+    assert_eq!(parse("{ a: 1 }").items().count(), 0);
+    assert_eq!(parse("[1]").entries().count(), 0);
+}
@@ -0,0 +1,184 @@
+//! Execution-order traversal of `Let` bindings and `Do` steps.
+//!
+//! [`walk_theorem`] drives a [`StepVisitor`] over a document's `Let`
+//! bindings and then its `Do` steps, descending into `maybe`, `repeat`, and
+//! `foreach` blocks, so analyses such as code generation, linting, and
+//! metrics implement only the callbacks they need instead of the recursion
+//! over [`Step`].
+
+use std::fmt;
+
+use super::types::{ActionCall, LetBinding, Step, TheoremDoc};
+
+/// Where a visited step sits in the `Do` sequence.
+///
+/// The path reads as validation errors describe steps, such as
+/// `Do step 2` for a top-level step or `Do step 2: maybe.else step 1` for
+/// the first step of that block's `else` branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepPath {
+    label: String,
+    depth: usize,
+}
+
+impl StepPath {
+    /// Returns the path as written in error messages.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns how many blocks enclose the step: zero for a top-level `Do`
+    /// step.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn top_level(position: usize) -> Self {
+        Self {
+            label: format!("Do step {position}"),
+            depth: 0,
+        }
+    }
+
+    fn nested(&self, block: &Step, branch: &str, position: usize) -> Self {
+        Self {
+            label: format!(
+                "{}: {}.{branch} step {position}",
+                self.label,
+                block.keyword()
+            ),
+            depth: self.depth + 1,
+        }
+    }
+}
+
+impl fmt::Display for StepPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Callbacks for [`walk_theorem`] and [`walk_steps`].
+///
+/// Every method does nothing by default, so a visitor implements only what
+/// it needs. The `'doc` lifetime lets a visitor keep references into the
+/// walked document.
+///
+/// # Examples
+///
+///     use theoremc_core::schema::{ActionCall, StepVisitor, load_theorem_docs, walk_theorem};
+///
+///     #[derive(Default)]
+///     struct Actions<'doc>(Vec<&'doc str>);
+///
+///     impl<'doc> StepVisitor<'doc> for Actions<'doc> {
+///         fn visit_action_call(&mut self, call: &'doc ActionCall) {
+///             self.0.push(&call.action);
+///         }
+///     }
+///
+///     let yaml = r#"
+///     Theorem: Visited
+///     About: Walks every call
+///     Actions:
+///       ledger.open: { returns: u64 }
+///       ledger.close: { params: { id: u64 } }
+///     Let:
+///       id: { call: { action: ledger.open, args: {} } }
+///     Do:
+///       - maybe:
+///           because: closing is optional
+///           do:
+///             - call: { action: ledger.close, args: { id: { ref: id } } }
+///     Prove:
+///       - assert: "id >= 0"
+///         because: unsigned
+///     Evidence:
+///       kani: { unwind: 1, expect: SUCCESS }
+///     Witness:
+///       - cover: "id == 0"
+///         because: reachable
+///     "#;
+///     let docs = load_theorem_docs(yaml).unwrap();
+///
+///     let mut actions = Actions::default();
+///     walk_theorem(&docs[0], &mut actions);
+///     assert_eq!(actions.0, ["ledger.open", "ledger.close"]);
+pub trait StepVisitor<'doc> {
+    /// Called for each `Let` binding, in declaration order.
+    fn visit_let_binding(&mut self, name: &'doc str, binding: &'doc LetBinding) {
+        let _ = (name, binding);
+    }
+
+    /// Called for each step before the steps nested inside it.
+    fn enter_step(&mut self, step: &'doc Step, path: &StepPath) {
+        let _ = (step, path);
+    }
+
+    /// Called for each step after the steps nested inside it.
+    fn leave_step(&mut self, step: &'doc Step, path: &StepPath) {
+        let _ = (step, path);
+    }
+
+    /// Called for the action call of each `Let` binding and each `call`,
+    /// `must`, or `expect_err` step, straight after
+    /// [`StepVisitor::visit_let_binding`] or [`StepVisitor::enter_step`].
+    fn visit_action_call(&mut self, call: &'doc ActionCall) {
+        let _ = call;
+    }
+}
+
+/// Walks the `Let` bindings of `doc` in declaration order, then its `Do`
+/// steps with [`walk_steps`].
+pub fn walk_theorem<'doc, V>(doc: &'doc TheoremDoc, visitor: &mut V)
+where
+    V: StepVisitor<'doc> + ?Sized,
+{
+    for (name, binding) in doc.let_bindings.iter() {
+        visitor.visit_let_binding(name, binding);
+        visitor.visit_action_call(match binding {
+            LetBinding::Call(let_call) => &let_call.call,
+            LetBinding::Must(let_must) => &let_must.must,
+        });
+    }
+    walk_steps(&doc.do_steps, visitor);
+}
+
+/// Walks a `Do` sequence depth first, in the order the steps run.
+///
+/// A block's nested steps are visited between its
+/// [`StepVisitor::enter_step`] and [`StepVisitor::leave_step`] calls; a
+/// `maybe` block's `do` branch is visited before its `else` branch.
+pub fn walk_steps<'doc, V>(steps: &'doc [Step], visitor: &mut V)
+where
+    V: StepVisitor<'doc> + ?Sized,
+{
+    for (index, step) in steps.iter().enumerate() {
+        walk_step(step, &StepPath::top_level(index + 1), visitor);
+    }
+}
+
+fn walk_step<'doc, V>(step: &'doc Step, path: &StepPath, visitor: &mut V)
+where
+    V: StepVisitor<'doc> + ?Sized,
+{
+    visitor.enter_step(step, path);
+    match step {
+        Step::Call(step_call) => visitor.visit_action_call(&step_call.call),
+        Step::Must(step_must) => visitor.visit_action_call(&step_must.must),
+        Step::ExpectErr(step_err) => visitor.visit_action_call(&step_err.expect_err),
+        Step::Maybe(_) | Step::Repeat(_) | Step::Foreach(_) | Step::Assert(_) => {}
+    }
+    for (branch, nested) in step.nested_branches() {
+        for (index, nested_step) in nested.iter().enumerate() {
+            walk_step(nested_step, &path.nested(step, branch, index + 1), visitor);
+        }
+    }
+    visitor.leave_step(step, path);
+}
+
+#[cfg(test)]
+#[path = "visit_tests.rs"]
+mod tests;
@@ -0,0 +1,164 @@
+//! Unit tests for step and binding traversal.
+
+use pretty_assertions::assert_eq;
+
+use super::{StepPath, StepVisitor, walk_steps, walk_theorem};
+use crate::schema::{ActionCall, LetBinding, Step, TheoremDoc, load_theorem_docs};
+
+/// Records every callback as one line, in the order it was made.
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl<'doc> StepVisitor<'doc> for Recorder {
+    fn visit_let_binding(&mut self, name: &'doc str, _binding: &'doc LetBinding) {
+        self.0.push(format!("let {name}"));
+    }
+
+    fn enter_step(&mut self, step: &'doc Step, path: &StepPath) {
+        self.0.push(format!("enter {} at {path}", step.keyword()));
+    }
+
+    fn leave_step(&mut self, step: &'doc Step, path: &StepPath) {
+        self.0.push(format!("leave {} at {path}", step.keyword()));
+    }
+
+    fn visit_action_call(&mut self, call: &'doc ActionCall) {
+        self.0.push(format!("action {}", call.action));
+    }
+}
+
+const NESTED: &str = r#"
+Theorem: Walked
+About: Every step kind nests
+Forall:
+  items: { vec: u32, len: { max: 2 } }
+Actions:
+  ledger.open: { returns: u64 }
+  ledger.close: { params: { id: u64 }, returns: "Result<(), crate::Error>" }
+Let:
+  first: { call: { action: ledger.open, args: {} } }
+  second: { must: { action: ledger.close, args: { id: { ref: first } } } }
+Do:
+  - call: { action: ledger.open, args: {}, as: id }
+  - maybe:
+      because: closing is optional
+      do:
+        - must: { action: ledger.close, args: { id: { ref: id } } }
+      else:
+        - repeat:
+            times: 1
+            do:
+              - expect_err:
+                  action: ledger.close
+                  args: { id: 0 }
+                  error: Closed
+  - foreach:
+      in: items
+      as: item
+      do:
+        - assert: { expr: "item >= 0", because: unsigned }
+Prove:
+  - assert: "id >= 0"
+    because: unsigned
+Evidence:
+  kani: { unwind: 3, expect: SUCCESS }
+Witness:
+  - cover: "id == 0"
+    because: reachable
+"#;
+
+fn load_nested() -> TheoremDoc {
+    load_theorem_docs(NESTED)
+        .expect("source should load")
+        .into_iter()
+        .next()
+        .expect("source should hold a theorem")
+}
+
+#[test]
+fn theorem_walk_visits_bindings_then_steps_in_execution_order() {
+    let doc = load_nested();
+    let mut recorder = Recorder::default();
+
+    walk_theorem(&doc, &mut recorder);
+
+    assert_eq!(
+        recorder.0,
+        [
+            "let first",
+            "action ledger.open",
+            "let second",
+            "action ledger.close",
+            "enter call at Do step 1",
+            "action ledger.open",
+            "leave call at Do step 1",
+            "enter maybe at Do step 2",
+            "enter must at Do step 2: maybe.do step 1",
+            "action ledger.close",
+            "leave must at Do step 2: maybe.do step 1",
+            "enter repeat at Do step 2: maybe.else step 1",
+            "enter expect_err at Do step 2: maybe.else step 1: repeat.do step 1",
+            "action ledger.close",
+            "leave expect_err at Do step 2: maybe.else step 1: repeat.do step 1",
+            "leave repeat at Do step 2: maybe.else step 1",
+            "leave maybe at Do step 2",
+            "enter foreach at Do step 3",
+            "enter assert at Do step 3: foreach.do step 1",
+            "leave assert at Do step 3: foreach.do step 1",
+            "leave foreach at Do step 3",
+        ]
+    );
+}
+
+#[test]
+fn step_walk_skips_let_bindings() {
+    let doc = load_nested();
+    let mut recorder = Recorder::default();
+
+    walk_steps(&doc.do_steps, &mut recorder);
+
+    assert_eq!(
+        recorder.0.first().map(String::as_str),
+        Some("enter call at Do step 1")
+    );
+    assert!(!recorder.0.iter().any(|event| event.starts_with("let ")));
+}
+
+#[test]
+fn paths_report_nesting_depth() {
+    /// Collects the depth of each entered step.
+    struct Depths(Vec<usize>);
+
+    impl StepVisitor<'_> for Depths {
+        fn enter_step(&mut self, _step: &Step, path: &StepPath) {
+            self.0.push(path.depth());
+        }
+    }
+
+    let doc = load_nested();
+    let mut depths = Depths(Vec::new());
+
+    walk_steps(&doc.do_steps, &mut depths);
+
+    assert_eq!(depths.0, [0, 0, 1, 1, 2, 0, 1]);
+}
+
+#[test]
+fn visitors_can_borrow_from_the_document() {
+    /// Keeps the `as` binding of every step that declares one.
+    #[derive(Default)]
+    struct Bindings<'doc>(Vec<&'doc str>);
+
+    impl<'doc> StepVisitor<'doc> for Bindings<'doc> {
+        fn enter_step(&mut self, step: &'doc Step, _path: &StepPath) {
+            self.0.extend(step.as_binding());
+        }
+    }
+
+    let doc = load_nested();
+    let mut bindings = Bindings::default();
+
+    walk_theorem(&doc, &mut bindings as &mut dyn StepVisitor<'_>);
+
+    assert_eq!(bindings.0, ["id"]);
+}
@@ -0,0 +1,120 @@
+//! Rejection of YAML anchors, aliases, and merge keys.
+//!
+//! Theorem sources and `Include` fragments are parsed with anchors, aliases,
+//! and `<<` merge keys disabled (`TFS-1` section 1.2). A value replayed from
+//! an anchor or merged into a mapping has no single place in the source, so
+//! located diagnostics, merge provenance, and span-based rewrites such as
+//! `theoremc migrate` could not point at it. `Shared` blocks, `Include`
+//! fragments, and `Defaults` documents provide reuse instead.
+
+use serde_saphyr::budget::BudgetBreach;
+use serde_saphyr::{Budget, Location, Options};
+
+use super::diagnostic::{SchemaDiagnostic, SchemaDiagnosticCode, create_diagnostic};
+use super::source_id::SourceId;
+
+/// Parser options that reject anchors, aliases, and merge keys, leaving the
+/// other parser limits at their defaults.
+pub(crate) fn parse_options() -> Options {
+    Options {
+        budget: Some(Budget {
+            max_anchors: 0,
+            max_aliases: 0,
+            max_merge_keys: 0,
+            ..Budget::default()
+        }),
+        ..Options::default()
+    }
+}
+
+/// The YAML reuse construct a parse rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReuseKind {
+    Anchor,
+    Alias,
+    MergeKey,
+}
+
+/// A parse failure caused by an anchor, an alias, or a merge key, with the
+/// position of the rejected construct.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReuseFailure {
+    kind: ReuseKind,
+    location: Location,
+    line: usize,
+    column: usize,
+}
+
+impl ReuseFailure {
+    /// Recognizes a failure of `input` caused by an anchor, an alias, or a
+    /// merge key.
+    pub(crate) fn of(error: &serde_saphyr::Error, input: &str) -> Option<Self> {
+        let serde_saphyr::Error::Budget { breach, location } = error.without_snippet() else {
+            return None;
+        };
+        let kind = match breach {
+            BudgetBreach::Anchors { .. } => ReuseKind::Anchor,
+            BudgetBreach::Aliases { .. } => ReuseKind::Alias,
+            BudgetBreach::MergeKeys { .. } => ReuseKind::MergeKey,
+            _ => return None,
+        };
+        let line = usize::try_from(location.line()).unwrap_or(usize::MAX);
+        let reported = usize::try_from(location.column()).unwrap_or(usize::MAX);
+        let column = match kind {
+            ReuseKind::Anchor => anchor_column(input, line, reported).unwrap_or(reported),
+            ReuseKind::Alias | ReuseKind::MergeKey => reported,
+        };
+        Some(Self {
+            kind,
+            location: *location,
+            line,
+            column,
+        })
+    }
+
+    /// Returns the failure message, prefixed with its position as parser
+    /// messages are.
+    pub(crate) fn message(&self) -> String {
+        let construct = match self.kind {
+            ReuseKind::Anchor => "YAML anchors are",
+            ReuseKind::Alias => "YAML aliases are",
+            ReuseKind::MergeKey => "YAML merge keys ('<<') are",
+        };
+        format!(
+            "line {} column {}: {construct} not supported in theorem sources; \
+             use Shared blocks, Include fragments, or Defaults to reuse content",
+            self.line, self.column
+        )
+    }
+
+    /// Returns the failure as a parse diagnostic in `source`.
+    pub(crate) fn diagnostic(&self, source: &SourceId) -> SchemaDiagnostic {
+        let mut diagnostic = create_diagnostic(
+            SchemaDiagnosticCode::ParseFailure,
+            source,
+            self.message(),
+            self.location,
+        );
+        diagnostic.location.column = self.column;
+        diagnostic
+    }
+}
+
+/// Returns the column of the `&` introducing the anchor whose value starts
+/// at `line` and `column` of `input`, when it is written on the same line.
+///
+/// The parser reports a rejected anchor at the value it names rather than at
+/// the anchor itself.
+fn anchor_column(input: &str, line: usize, column: usize) -> Option<usize> {
+    let text = input.lines().nth(line.checked_sub(1)?)?;
+    text.chars()
+        .take(column.saturating_sub(1))
+        .enumerate()
+        .filter(|&(_, character)| character == '&')
+        .last()
+        .map(|(index, _)| index + 1)
+}
+
+#[cfg(test)]
+#[path = "yaml_anchor_tests.rs"]
+mod tests;
@@ -0,0 +1,102 @@
+//! Unit tests for the rejection of YAML anchors, aliases, and merge keys.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::*;
+use crate::schema::{SchemaError, iter_theorem_docs, load_theorem_docs};
+
+const PROVE: &str = concat!(
+    "Prove:\n",
+    "  - assert: 'true'\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn theorem(body: &str) -> String {
+    format!("Theorem: T\nAbout: Example\n{body}{PROVE}")
+}
+
+fn rejection(error: &SchemaError) -> (String, usize, usize) {
+    let diagnostic = error.diagnostic().expect("diagnostic expected");
+    assert_eq!(diagnostic.code, SchemaDiagnosticCode::ParseFailure);
+    (
+        diagnostic.message.clone(),
+        diagnostic.location.line,
+        diagnostic.location.column,
+    )
+}
+
+#[rstest]
+#[case::scalar_anchor(
+    "Forall:\n  x: &width u8\n  y: *width\n",
+    "YAML anchors are not supported",
+    (4, 6)
+)]
+#[case::mapping_anchor(
+    "Types: &types { Amount: u64 }\n",
+    "YAML anchors are not supported",
+    (3, 8)
+)]
+#[case::block_anchor_on_its_own_line(
+    "Types: &types\n  Amount: u64\n",
+    "YAML anchors are not supported",
+    (4, 3)
+)]
+#[case::inline_merge_key(
+    "Actions:\n  account.open:\n    <<: { params: {} }\n    returns: u64\n",
+    "YAML merge keys ('<<') are not supported",
+    (5, 5)
+)]
+fn reuse_constructs_are_rejected(
+    #[case] body: &str,
+    #[case] expected: &str,
+    #[case] position: (usize, usize),
+) {
+    let error = load_theorem_docs(&theorem(body)).expect_err("reuse should be rejected");
+    let (message, line, column) = rejection(&error);
+
+    assert!(message.contains(expected), "{message}");
+    assert!(
+        message.ends_with("use Shared blocks, Include fragments, or Defaults to reuse content"),
+        "{message}"
+    );
+    assert_eq!((line, column), position);
+    assert!(matches!(error, SchemaError::Deserialize { .. }));
+}
+
+#[test]
+fn message_names_the_reported_position() {
+    let error = load_theorem_docs(&theorem("Forall:\n  x: &width u8\n"))
+        .expect_err("anchors should be rejected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("line 4 column 6: YAML anchors are not supported"),
+        "{error}"
+    );
+}
+
+#[test]
+fn streamed_documents_reject_anchors_in_later_documents() {
+    let input = format!(
+        "{}---\n{}",
+        theorem(""),
+        theorem("Types: &types { Amount: u64 }\n")
+    );
+    let results: Vec<_> = iter_theorem_docs(&input).collect();
+    let error = results
+        .last()
+        .and_then(|result| result.as_ref().err())
+        .expect("second document should fail");
+
+    assert_eq!(rejection(error).1, 11);
+}
+
+#[test]
+fn anchors_on_an_earlier_line_keep_the_reported_column() {
+    assert_eq!(anchor_column("a: &x\n  b: 1\n", 2, 3), None);
+    assert_eq!(anchor_column("a: &x 1\n", 1, 7), Some(4));
+}
@@ -0,0 +1,225 @@
+//! Boolean expressions over theorem `Tags`.
+//!
+//! A [`TagExpr`] such as `smoke and not slow` or `(kani or verus) and
+//! payments` parses once and then filters any number of theorem documents.
+//! The same filter backs corpus queries in [`crate::discover`] and the
+//! `--tags` option of the `theoremc` command line.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+use crate::schema::TheoremDoc;
+
+/// A boolean expression selecting theorems by their `Tags`, such as
+/// `ledger and not slow` or `(ledger or bank) and smoke`.
+///
+/// A tag is any run of characters other than whitespace and parentheses,
+/// compared exactly. `not` binds tighter than `and`, which binds tighter
+/// than `or`, and parentheses group. The keywords are lowercase and cannot
+/// be used as tags.
+///
+/// # Examples
+///
+///     use theoremc_core::tagexpr::TagExpr;
+///
+///     let expr: TagExpr = "ledger and not slow".parse().unwrap();
+///     assert!(expr.matches(&["ledger"]));
+///     assert!(!expr.matches(&["ledger", "slow"]));
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpr {
+    /// Matches theorems carrying this tag.
+    Tag(String),
+    /// Matches theorems the inner expression does not match.
+    Not(Box<Self>),
+    /// Matches theorems both expressions match.
+    And(Box<Self>, Box<Self>),
+    /// Matches theorems either expression matches.
+    Or(Box<Self>, Box<Self>),
+}
+
+/// Errors produced when parsing a [`TagExpr`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TagExprError {
+    /// The expression holds no tags.
+    #[error("tag expression is empty")]
+    Empty,
+    /// The expression ended before it was complete.
+    #[error("tag expression ended early: expected {expected}")]
+    UnexpectedEnd {
+        /// What the expression needed next.
+        expected: &'static str,
+    },
+    /// A token appeared where it cannot be used.
+    #[error("unexpected '{token}' in tag expression: expected {expected}")]
+    UnexpectedToken {
+        /// The misplaced token.
+        token: String,
+        /// What the expression needed instead.
+        expected: &'static str,
+    },
+}
+
+impl TagExpr {
+    /// Parses a tag expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TagExprError`] when `source` is empty, ends early, or uses
+    /// a keyword or parenthesis out of place.
+    pub fn parse(source: &str) -> Result<Self, TagExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(source).into_iter().peekable(),
+        };
+        if parser.tokens.peek().is_none() {
+            return Err(TagExprError::Empty);
+        }
+        let expr = parser.parse_or()?;
+        parser.tokens.next().map_or(Ok(expr), |token| {
+            Err(unexpected(token, "'and', 'or', or the end"))
+        })
+    }
+
+    /// Returns `true` when a theorem with `tags` satisfies the expression.
+    #[must_use]
+    pub fn matches(&self, tags: &[impl AsRef<str>]) -> bool {
+        match self {
+            Self::Tag(tag) => tags.iter().any(|candidate| candidate.as_ref() == tag),
+            Self::Not(inner) => !inner.matches(tags),
+            Self::And(left, right) => left.matches(tags) && right.matches(tags),
+            Self::Or(left, right) => left.matches(tags) || right.matches(tags),
+        }
+    }
+
+    /// Returns `true` when the `Tags` of `doc` satisfy the expression.
+    #[must_use]
+    pub fn matches_theorem(&self, doc: &TheoremDoc) -> bool {
+        self.matches(&doc.tags)
+    }
+
+    /// Returns the documents of `docs` whose `Tags` satisfy the expression,
+    /// in their original order.
+    pub fn select<'d, I>(&'d self, docs: I) -> impl Iterator<Item = &'d TheoremDoc>
+    where
+        I: IntoIterator<Item = &'d TheoremDoc>,
+        I::IntoIter: 'd,
+    {
+        docs.into_iter().filter(|doc| self.matches_theorem(doc))
+    }
+}
+
+impl FromStr for TagExpr {
+    type Err = TagExprError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Open,
+    Close,
+    Word(&'a str),
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Open => f.write_str("("),
+            Self::Close => f.write_str(")"),
+            Self::Word(word) => f.write_str(word),
+        }
+    }
+}
+
+const KEYWORDS: [&str; 3] = ["and", "or", "not"];
+
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    source.split_whitespace().flat_map(split_parens).collect()
+}
+
+/// Splits a whitespace-free word into parentheses and the words between
+/// them.
+fn split_parens(word: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = word;
+    while let Some((before, paren, after)) = split_at_paren(rest) {
+        if !before.is_empty() {
+            tokens.push(Token::Word(before));
+        }
+        tokens.push(paren);
+        rest = after;
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Word(rest));
+    }
+    tokens
+}
+
+fn split_at_paren(word: &str) -> Option<(&str, Token<'_>, &str)> {
+    let index = word.find(['(', ')'])?;
+    let (before, from_paren) = word.split_at_checked(index)?;
+    let mut chars = from_paren.chars();
+    let paren = if chars.next()? == '(' {
+        Token::Open
+    } else {
+        Token::Close
+    };
+    Some((before, paren, chars.as_str()))
+}
+
+fn unexpected(token: Token<'_>, expected: &'static str) -> TagExprError {
+    TagExprError::UnexpectedToken {
+        token: token.to_string(),
+        expected,
+    }
+}
+
+/// Recursive-descent parser over the tokens of one expression.
+struct Parser<'a> {
+    tokens: Peekable<IntoIter<Token<'a>>>,
+}
+
+impl Parser<'_> {
+    fn parse_or(&mut self) -> Result<TagExpr, TagExprError> {
+        let mut expr = self.parse_and()?;
+        while self.tokens.next_if_eq(&Token::Word("or")).is_some() {
+            expr = TagExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<TagExpr, TagExprError> {
+        let mut expr = self.parse_unary()?;
+        while self.tokens.next_if_eq(&Token::Word("and")).is_some() {
+            expr = TagExpr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<TagExpr, TagExprError> {
+        match self.tokens.next() {
+            None => Err(TagExprError::UnexpectedEnd { expected: "a tag" }),
+            Some(Token::Word("not")) => Ok(TagExpr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::Open) => self.parse_group(),
+            Some(Token::Word(tag)) if !KEYWORDS.contains(&tag) => Ok(TagExpr::Tag(tag.to_owned())),
+            Some(token) => Err(unexpected(token, "a tag")),
+        }
+    }
+
+    /// Parses the rest of a parenthesized group after its `(`.
+    fn parse_group(&mut self) -> Result<TagExpr, TagExprError> {
+        let inner = self.parse_or()?;
+        match self.tokens.next() {
+            Some(Token::Close) => Ok(inner),
+            None => Err(TagExprError::UnexpectedEnd { expected: "')'" }),
+            Some(token) => Err(unexpected(token, "')'")),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tagexpr_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+//! Unit tests for tag expression parsing and matching.
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use super::{TagExpr, TagExprError};
+use crate::schema::test_fixtures;
+use crate::schema::{TheoremDoc, load_theorem_docs};
+
+fn tagged_theorem(name: &str, tags: &str) -> String {
+    test_fixtures::theorem(name, &format!("Tags: {tags}\n"))
+}
+
+fn tag(name: &str) -> TagExpr {
+    TagExpr::Tag(name.to_owned())
+}
+
+#[test]
+fn precedence_binds_not_then_and_then_or() {
+    let expr = TagExpr::parse("a or not b and c").expect("expression should parse");
+
+    assert_eq!(
+        expr,
+        TagExpr::Or(
+            Box::new(tag("a")),
+            Box::new(TagExpr::And(
+                Box::new(TagExpr::Not(Box::new(tag("b")))),
+                Box::new(tag("c"))
+            ))
+        )
+    );
+}
+
+#[test]
+fn parentheses_group_without_surrounding_spaces() {
+    let expr: TagExpr = "(a or b)and(c)".parse().expect("expression should parse");
+
+    assert_eq!(
+        expr,
+        TagExpr::And(
+            Box::new(TagExpr::Or(Box::new(tag("a")), Box::new(tag("b")))),
+            Box::new(tag("c"))
+        )
+    );
+}
+
+#[rstest]
+#[case::single_tag("ledger", &["ledger", "slow"], true)]
+#[case::missing_tag("ledger", &["bank"], false)]
+#[case::negation("ledger and not slow", &["ledger", "slow"], false)]
+#[case::disjunction("(ledger or bank) and smoke", &["bank", "smoke"], true)]
+#[case::exact_comparison("Ledger", &["ledger"], false)]
+#[case::no_tags("not slow", &[], true)]
+fn expressions_match_tag_sets(#[case] source: &str, #[case] tags: &[&str], #[case] expected: bool) {
+    let expr = TagExpr::parse(source).expect("expression should parse");
+
+    assert_eq!(expr.matches(tags), expected);
+}
+
+#[rstest]
+#[case::empty("  ", TagExprError::Empty)]
+#[case::trailing_operator(
+    "ledger and",
+    TagExprError::UnexpectedEnd { expected: "a tag" }
+)]
+#[case::unclosed_group("(ledger", TagExprError::UnexpectedEnd { expected: "')'" })]
+#[case::stray_close(
+    "ledger)",
+    TagExprError::UnexpectedToken { token: ")".to_owned(), expected: "'and', 'or', or the end" }
+)]
+#[case::adjacent_tags(
+    "ledger bank",
+    TagExprError::UnexpectedToken { token: "bank".to_owned(), expected: "'and', 'or', or the end" }
+)]
+#[case::keyword_as_tag(
+    "and",
+    TagExprError::UnexpectedToken { token: "and".to_owned(), expected: "a tag" }
+)]
+fn malformed_expressions_are_rejected(#[case] source: &str, #[case] expected: TagExprError) {
+    assert_eq!(TagExpr::parse(source), Err(expected));
+}
+
+#[test]
+fn select_keeps_matching_theorems_in_order() {
+    let yaml = [
+        tagged_theorem("KaniPayment", "[kani, payments, smoke]"),
+        tagged_theorem("SlowPayment", "[kani, payments, smoke, slow]"),
+        tagged_theorem("VerusPayment", "[verus, payments]"),
+        tagged_theorem("KaniLedger", "[kani, ledger]"),
+    ]
+    .join("---\n");
+    let docs = load_theorem_docs(&yaml).expect("theorems should load");
+    let expr = TagExpr::parse("(kani or verus) and payments and not slow")
+        .expect("expression should parse");
+
+    let selected: Vec<&str> = expr
+        .select(&docs)
+        .map(|doc: &TheoremDoc| doc.theorem.as_str())
+        .collect();
+
+    assert_eq!(selected, ["KaniPayment", "VerusPayment"]);
+    assert!(docs.first().is_some_and(|doc| expr.matches_theorem(doc)));
+}
@@ -4,11 +4,16 @@
 //! for theorem files so proc-macro expansion and any future compile-time
 //! tooling share one IO and diagnostic contract.
 
+use std::path::Path;
+
 use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
-use cap_std::{ambient_authority, fs_utf8::Dir as Utf8Dir};
+use cap_std::{ambient_authority, fs::Dir, fs_utf8::Dir as Utf8Dir};
 
+use crate::file_provider::{CrateIncludes, ManifestIncludes};
 use crate::path_format::normalize_path_separators;
-use crate::schema::{SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_source};
+use crate::schema::{
+    IncludeResolver, SchemaError, SourceId, TheoremDoc, load_theorem_docs_with_includes,
+};
 
 /// Errors raised while loading a crate-relative `.theorem` file.
 #[derive(Debug, thiserror::Error)]
@@ -72,8 +77,9 @@ pub enum TheoremFileLoadError {
 /// Loads one or more theorem documents from a crate-relative theorem file.
 ///
 /// The theorem path is resolved relative to `manifest_dir`, read through
-/// `cap_std`, and then validated with the shared schema loader. Successful
-/// loads must contain at least one theorem document.
+/// `cap_std`, and then validated with the shared schema loader. `Include`
+/// fragments are read the same way and obey the same crate-relative path
+/// rules. Successful loads must contain at least one theorem document.
 ///
 /// # Errors
 ///
@@ -104,14 +110,7 @@ pub fn load_theorem_file_from_manifest_dir(
     manifest_dir: &Utf8Path,
     theorem_path: &Utf8Path,
 ) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
-    let normalized_theorem_path =
-        Utf8PathBuf::from(normalize_path_separators(theorem_path.as_str()));
-    if is_invalid_theorem_path(&normalized_theorem_path) {
-        return Err(TheoremFileLoadError::InvalidTheoremPath {
-            path: normalized_theorem_path.to_path_buf(),
-        });
-    }
-
+    let normalized_theorem_path = relative_theorem_path(theorem_path)?;
     let manifest_root =
         Utf8Dir::open_ambient_dir(manifest_dir, ambient_authority()).map_err(|source| {
             TheoremFileLoadError::OpenManifestDir {
@@ -125,9 +124,40 @@ pub fn load_theorem_file_from_manifest_dir(
             path: normalized_theorem_path.to_path_buf(),
             source,
         })?;
-    let theorem_docs = load_theorem_docs_with_source(
-        &SourceId::new(normalized_theorem_path.as_str()),
+    load_relative_source(
+        &normalized_theorem_path,
         &theorem_source,
+        &ManifestIncludes {
+            root: &manifest_root,
+        },
+    )
+}
+
+/// Normalizes a root-relative theorem path, rejecting paths that could
+/// escape the root.
+pub(crate) fn relative_theorem_path(
+    theorem_path: &Utf8Path,
+) -> Result<Utf8PathBuf, TheoremFileLoadError> {
+    let normalized_theorem_path =
+        Utf8PathBuf::from(normalize_path_separators(theorem_path.as_str()));
+    if is_invalid_theorem_path(&normalized_theorem_path) {
+        return Err(TheoremFileLoadError::InvalidTheoremPath {
+            path: normalized_theorem_path,
+        });
+    }
+    Ok(normalized_theorem_path)
+}
+
+/// Loads the source of a root-relative theorem file.
+pub(crate) fn load_relative_source(
+    normalized_theorem_path: &Utf8Path,
+    theorem_source: &str,
+    includes: &dyn IncludeResolver,
+) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    let theorem_docs = load_theorem_docs_with_includes(
+        &SourceId::new(normalized_theorem_path.as_str()),
+        theorem_source,
+        includes,
     )
     .map_err(|source| TheoremFileLoadError::InvalidTheoremFile {
         path: normalized_theorem_path.to_path_buf(),
@@ -143,6 +173,119 @@ pub fn load_theorem_file_from_manifest_dir(
     Ok(theorem_docs)
 }
 
+/// Loads one or more theorem documents from the theorem file at `path`.
+///
+/// Unlike [`load_theorem_file_from_manifest_dir`], `path` may be absolute.
+/// `Include` entries are read relative to the manifest directory of the
+/// crate holding `path`, the nearest directory at or above it that contains
+/// a `Cargo.toml`, as [`CrateIncludes`] describes. Diagnostics name
+/// `path`, with `/` separators, as their source, and `\r\n` line endings are
+/// read as `\n`. Successful loads must contain at least one theorem document.
+///
+/// # Errors
+///
+/// Returns [`TheoremFileLoadError::ReadTheoremFile`] if the file cannot be
+/// read, [`TheoremFileLoadError::InvalidTheoremFile`] if schema parsing or
+/// validation fails, and [`TheoremFileLoadError::EmptyTheoremFile`] if the
+/// file contains zero theorem documents.
+///
+/// # Examples
+///
+/// ```no_run
+/// use theoremc_core::{load_theorem_file, TheoremFileLoadError};
+///
+/// fn main() -> Result<(), TheoremFileLoadError> {
+///     let docs = load_theorem_file("theorems/ledger.theorem")?;
+///
+///     assert!(!docs.is_empty());
+///     Ok(())
+/// }
+/// ```
+pub fn load_theorem_file(path: impl AsRef<Path>) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    let (display_path, contents) = read_theorem_file(&path)?;
+    parse_theorem_file(path.as_ref(), &display_path, &contents)
+}
+
+/// Reads the theorem file at `path` the way [`load_theorem_file`] does,
+/// without parsing it.
+///
+/// Returns the path diagnostics should name, with `/` separators, and the
+/// file contents with `\r\n` read as `\n`. Tools that rewrite or lint the
+/// source text, rather than load its documents, read files through this.
+///
+/// # Errors
+///
+/// Returns [`TheoremFileLoadError::ReadTheoremFile`] if the file cannot be
+/// read.
+pub fn read_theorem_file(
+    path: impl AsRef<Path>,
+) -> Result<(Utf8PathBuf, String), TheoremFileLoadError> {
+    let display_path =
+        Utf8PathBuf::from(normalize_path_separators(&path.as_ref().to_string_lossy()));
+    match read_file(path.as_ref()) {
+        Ok(contents) => Ok((display_path, contents.replace("\r\n", "\n"))),
+        Err(source) => Err(TheoremFileLoadError::ReadTheoremFile {
+            path: display_path,
+            source,
+        }),
+    }
+}
+
+/// Loads the contents read by [`read_theorem_file`] from the file at `path`.
+pub(crate) fn parse_theorem_file(
+    path: &Path,
+    display_path: &Utf8Path,
+    contents: &str,
+) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    load_relative_source(display_path, contents, &CrateIncludes::for_file(path))
+}
+
+/// Loads the theorem documents of the file at `path` without blocking the
+/// async runtime.
+///
+/// Reading and validation run like [`load_theorem_file`] on Tokio's blocking
+/// thread pool, so a caller such as a language server keeps serving other
+/// tasks meanwhile. Requires the `tokio` feature and a Tokio runtime.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_theorem_file`]. If the blocking task
+/// cannot complete, the failure is reported as
+/// [`TheoremFileLoadError::ReadTheoremFile`].
+#[cfg(feature = "tokio")]
+pub async fn load_theorem_file_async(
+    path: impl AsRef<Path>,
+) -> Result<Vec<TheoremDoc>, TheoremFileLoadError> {
+    let file_path = path.as_ref().to_path_buf();
+    let display_path = Utf8PathBuf::from(normalize_path_separators(&file_path.to_string_lossy()));
+    tokio::task::spawn_blocking(move || load_theorem_file(file_path))
+        .await
+        .unwrap_or_else(|join_error| {
+            Err(TheoremFileLoadError::ReadTheoremFile {
+                path: display_path,
+                source: std::io::Error::other(join_error),
+            })
+        })
+}
+
+/// Reads `path` through its parent directory's capability.
+pub(crate) fn read_file(path: &Path) -> std::io::Result<String> {
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "theorem path does not name a file",
+        )
+    })?;
+    Dir::open_ambient_dir(parent_dir(path), ambient_authority())?.read_to_string(file_name)
+}
+
+/// Returns the directory holding `path`, using `.` for a bare file name.
+pub(crate) fn parent_dir(path: &Path) -> &Path {
+    path.parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."))
+}
+
 fn has_windows_drive_prefix(path: &Utf8Path) -> bool {
     matches!(
         path.as_str().as_bytes(),
@@ -183,11 +326,11 @@ fn theorem_path_violation(path: &Utf8Path) -> Option<TheoremPathViolation> {
     None
 }
 
-fn is_invalid_theorem_path(path: &Utf8Path) -> bool {
+pub(crate) fn is_invalid_theorem_path(path: &Utf8Path) -> bool {
     theorem_path_violation(path).is_some()
 }
 
-const fn io_error_code(kind: std::io::ErrorKind) -> &'static str {
+pub(crate) const fn io_error_code(kind: std::io::ErrorKind) -> &'static str {
     match kind {
         std::io::ErrorKind::NotFound => "io:NotFound",
         std::io::ErrorKind::PermissionDenied => "io:PermissionDenied",
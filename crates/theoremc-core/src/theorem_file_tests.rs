@@ -2,8 +2,8 @@
 
 use super::{
     TheoremDoc, TheoremFileLoadError, TheoremPathViolation, Utf8Dir, Utf8Path, Utf8PathBuf,
-    ambient_authority, is_invalid_theorem_path, load_theorem_file_from_manifest_dir,
-    theorem_path_violation,
+    ambient_authority, is_invalid_theorem_path, load_theorem_file,
+    load_theorem_file_from_manifest_dir, theorem_path_violation,
 };
 use rstest::{fixture, rstest};
 use tempfile::TempDir;
@@ -256,3 +256,259 @@ fn io_error_display_uses_stable_error_codes() {
         "failed to read theorem file 'missing.theorem': io:NotFound",
     );
 }
+
+const INCLUDING_THEOREM: &str = concat!(
+    "Theorem: UsesSharedPreamble\n",
+    "About: Merges a shared preamble\n",
+    "Include:\n",
+    "  - theorems/shared/preamble.yaml\n",
+    "Prove:\n",
+    "  - assert: \"amount <= limit\"\n",
+    "    because: \"the shared assumption bounds amount\"\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 1\n",
+    "    expect: SUCCESS\n",
+    "Witness:\n",
+    "  - cover: \"amount == 0\"\n",
+    "    because: \"zero is within the limit\"\n",
+);
+
+#[test]
+fn include_fragments_resolve_relative_to_manifest_dir() {
+    let temp = temp_dir();
+    let manifest_dir = &temp.manifest_dir;
+    write_fixture(
+        manifest_dir,
+        Utf8Path::new("theorems/shared/preamble.yaml"),
+        concat!(
+            "Include:\n",
+            "  - theorems/shared/limits.yaml\n",
+            "Assume:\n",
+            "  - expr: \"amount <= limit\"\n",
+            "    because: \"amounts never exceed the limit\"\n",
+        ),
+    )
+    .expect("fragment should be written");
+    write_fixture(
+        manifest_dir,
+        Utf8Path::new("theorems/shared/limits.yaml"),
+        "Forall:\n  amount: u64\n  limit: u64\n",
+    )
+    .expect("fragment should be written");
+    let theorem_path = Utf8Path::new("theorems/uses_preamble.theorem");
+    write_fixture(manifest_dir, theorem_path, INCLUDING_THEOREM)
+        .expect("fixture should be written");
+
+    let docs =
+        load_theorem_file_from_manifest_dir(manifest_dir, theorem_path).expect("file should load");
+
+    let doc = docs.first().expect("expected one theorem document");
+    assert_eq!(
+        doc.include,
+        [
+            "theorems/shared/limits.yaml",
+            "theorems/shared/preamble.yaml"
+        ]
+    );
+    assert_eq!(
+        doc.forall.keys().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        ["amount", "limit"]
+    );
+    assert_eq!(doc.assume.len(), 1);
+}
+
+#[rstest]
+#[case::missing("theorems/shared/missing.yaml", "failed to read include: io:NotFound")]
+#[case::traversal(
+    "../outside.yaml",
+    "failed to read include: absolute, drive-prefixed, and traversal ('..') paths are not allowed"
+)]
+fn unreadable_include_fragments_are_rejected(#[case] include_path: &str, #[case] expected: &str) {
+    let temp = temp_dir();
+    let theorem_path = Utf8Path::new("theorems/uses_preamble.theorem");
+    write_fixture(
+        &temp.manifest_dir,
+        theorem_path,
+        &INCLUDING_THEOREM.replace("theorems/shared/preamble.yaml", include_path),
+    )
+    .expect("fixture should be written");
+
+    let result = load_theorem_file_from_manifest_dir(&temp.manifest_dir, theorem_path);
+
+    assert!(
+        matches!(
+            &result,
+            Err(TheoremFileLoadError::InvalidTheoremFile { source, .. })
+                if source.to_string().contains(expected)
+        ),
+        "expected '{expected}' in {result:?}"
+    );
+}
+
+#[test]
+fn path_loading_resolves_includes_against_the_manifest_dir() {
+    let temp = temp_dir();
+    write_fixture(&temp.manifest_dir, Utf8Path::new("suite/Cargo.toml"), "")
+        .expect("manifest should be written");
+    write_fixture(
+        &temp.manifest_dir,
+        Utf8Path::new("suite/theorems/shared/preamble.yaml"),
+        "Forall:\n  amount: u64\n  limit: u64\n",
+    )
+    .expect("fragment should be written");
+    let theorem_path = Utf8Path::new("suite/theorems/uses_preamble.theorem");
+    write_fixture(&temp.manifest_dir, theorem_path, INCLUDING_THEOREM)
+        .expect("fixture should be written");
+
+    let docs = load_theorem_file(temp.manifest_dir.join(theorem_path)).expect("file should load");
+
+    let doc = docs.first().expect("expected one theorem document");
+    assert_eq!(doc.include, ["theorems/shared/preamble.yaml"]);
+    assert_eq!(
+        doc.forall.keys().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        ["amount", "limit"]
+    );
+}
+
+#[test]
+fn path_loading_rejects_includes_outside_any_crate() {
+    let temp = temp_dir();
+    write_fixture(
+        &temp.manifest_dir,
+        Utf8Path::new("theorems/shared/preamble.yaml"),
+        "Forall:\n  amount: u64\n  limit: u64\n",
+    )
+    .expect("fragment should be written");
+    let theorem_path = Utf8Path::new("uses_preamble.theorem");
+    write_fixture(&temp.manifest_dir, theorem_path, INCLUDING_THEOREM)
+        .expect("fixture should be written");
+
+    let result = load_theorem_file(temp.manifest_dir.join(theorem_path));
+
+    assert!(
+        matches!(
+            &result,
+            Err(TheoremFileLoadError::InvalidTheoremFile { source, .. })
+                if source.to_string().contains("no Cargo.toml encloses the theorem file")
+        ),
+        "{result:?}"
+    );
+}
+
+fn temp_dir() -> TempManifestDir {
+    temp_manifest_dir().expect("temp directory should be created")
+}
+
+#[test]
+fn path_loading_reads_crlf_files_by_absolute_path() {
+    let temp = temp_dir();
+    let theorem_path = Utf8Path::new("theorems/crlf.theorem");
+    write_fixture(
+        &temp.manifest_dir,
+        theorem_path,
+        &concat!(
+            "Theorem: CrlfLines\n",
+            "About: Loads Windows line endings\n",
+            "Prove:\n",
+            "  - assert: \"true\"\n",
+            "    because: \"trivial\"\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "Witness:\n",
+            "  - cover: \"true\"\n",
+            "    because: reachable\n",
+        )
+        .replace('\n', "\r\n"),
+    )
+    .expect("fixture should be written");
+
+    let docs = load_theorem_file(temp.manifest_dir.join(theorem_path)).expect("file should load");
+
+    let doc = docs.first().expect("expected one theorem document");
+    assert_eq!(doc.theorem, "CrlfLines");
+    assert_eq!(doc.about, "Loads Windows line endings");
+}
+
+#[test]
+fn path_loading_names_the_file_in_diagnostics() {
+    let temp = temp_dir();
+    let theorem_path = Utf8Path::new("invalid.theorem");
+    write_fixture(
+        &temp.manifest_dir,
+        theorem_path,
+        "Theorem: Invalid\nAbout: \"\"\n",
+    )
+    .expect("fixture should be written");
+    let full_path = temp.manifest_dir.join(theorem_path);
+
+    let error = load_theorem_file(&full_path).expect_err("theorem should be invalid");
+
+    let TheoremFileLoadError::InvalidTheoremFile { path, source } = &error else {
+        panic!("expected InvalidTheoremFile, got {error:?}");
+    };
+    assert_eq!(path, &full_path);
+    let diagnostic = source.diagnostic().expect("expected a diagnostic");
+    assert_eq!(diagnostic.location.source, full_path.as_str());
+}
+
+#[rstest]
+#[case::missing(None, ExpectedErrorKind::ReadTheoremFile)]
+#[case::empty(Some(""), ExpectedErrorKind::EmptyTheoremFile)]
+fn path_loading_reports_unusable_files(
+    #[case] contents: Option<&str>,
+    #[case] expected: ExpectedErrorKind,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let temp = temp_manifest_dir()?;
+    let theorem_path = Utf8Path::new("file.theorem");
+    if let Some(file_contents) = contents {
+        write_fixture(&temp.manifest_dir, theorem_path, file_contents)?;
+    }
+
+    let result = load_theorem_file(temp.manifest_dir.join(theorem_path));
+
+    assert_expected_error(&result, expected)
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn async_path_loading_matches_blocking_loading() {
+    let temp = temp_dir();
+    let theorem_path = Utf8Path::new("theorems/async.theorem");
+    write_fixture(
+        &temp.manifest_dir,
+        theorem_path,
+        concat!(
+            "Theorem: AsyncLoad\n",
+            "About: Loads on the blocking pool\n",
+            "Prove:\n",
+            "  - assert: \"true\"\n",
+            "    because: \"trivial\"\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+            "Witness:\n",
+            "  - cover: \"true\"\n",
+            "    because: reachable\n",
+        ),
+    )
+    .expect("fixture should be written");
+    let full_path = temp.manifest_dir.join(theorem_path);
+
+    let docs = super::load_theorem_file_async(&full_path)
+        .await
+        .expect("file should load");
+
+    assert_eq!(
+        docs,
+        load_theorem_file(&full_path).expect("file should load")
+    );
+    let missing = super::load_theorem_file_async(temp.manifest_dir.join("absent.theorem")).await;
+    assert!(matches!(
+        missing,
+        Err(TheoremFileLoadError::ReadTheoremFile { .. })
+    ));
+}
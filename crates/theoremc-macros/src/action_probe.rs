@@ -0,0 +1,143 @@
+//! Compile-time probes for the actions theorems call.
+//!
+//! Every action a theorem file references gets an anonymous `const` typed
+//! as a function pointer with the action's declared signature and bound to
+//! the function in `crate::theorem_actions`, so a missing or drifted action
+//! fails the build as an ordinary type error.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use theoremc_core::{
+    collision::referenced_actions,
+    mangle::mangle_action_name,
+    schema::{ActionSignature, TheoremDoc},
+};
+
+use super::{MacroExpansionError, identifier};
+
+pub(crate) fn generated_action_probes(
+    theorem_docs: &[TheoremDoc],
+) -> Result<Vec<GeneratedActionProbe>, MacroExpansionError> {
+    let referenced = referenced_actions(theorem_docs);
+    let signature_index = ActionSignatureIndex::for_actions(theorem_docs, &referenced)?;
+    referenced
+        .iter()
+        .map(|canonical| {
+            let signature = signature_index.signature_for(canonical)?;
+            action_probe(canonical, signature)
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub(crate) struct ActionSignatureIndex<'a> {
+    signatures: BTreeMap<&'a str, &'a ActionSignature>,
+}
+
+impl<'a> ActionSignatureIndex<'a> {
+    pub(crate) fn for_actions(
+        theorem_docs: &'a [TheoremDoc],
+        canonical_actions: &[&str],
+    ) -> Result<Self, MacroExpansionError> {
+        let selected = canonical_actions.iter().copied().collect::<BTreeSet<_>>();
+        let mut declared_signatures: BTreeMap<&'a str, &'a ActionSignature> = BTreeMap::new();
+
+        for doc in theorem_docs {
+            for (action, signature) in &doc.actions {
+                let canonical = action.as_str();
+                Self::insert_signature(&mut declared_signatures, canonical, signature)?;
+            }
+        }
+
+        let signatures = declared_signatures
+            .into_iter()
+            .filter(|(action, _)| selected.contains(action))
+            .collect();
+
+        Ok(Self { signatures })
+    }
+
+    fn insert_signature(
+        signatures: &mut BTreeMap<&'a str, &'a ActionSignature>,
+        canonical: &'a str,
+        signature: &'a ActionSignature,
+    ) -> Result<(), MacroExpansionError> {
+        let Some(first) = signatures.get(canonical) else {
+            signatures.insert(canonical, signature);
+            return Ok(());
+        };
+
+        if signature.is_semantically_equivalent(first) {
+            return Ok(());
+        }
+
+        Err(MacroExpansionError::ConflictingActionSignature {
+            action: canonical.to_owned(),
+        })
+    }
+
+    pub(crate) fn signature_for(
+        &self,
+        canonical: &str,
+    ) -> Result<&'a ActionSignature, MacroExpansionError> {
+        self.signatures.get(canonical).copied().ok_or_else(|| {
+            MacroExpansionError::MissingActionSignature {
+                action: canonical.to_owned(),
+            }
+        })
+    }
+}
+
+fn action_probe(
+    canonical: &str,
+    signature: &ActionSignature,
+) -> Result<GeneratedActionProbe, MacroExpansionError> {
+    let param_types = signature
+        .params
+        .values()
+        .map(|param| parse_action_type(canonical, param))
+        .collect::<Result<Vec<_>, _>>()?;
+    let return_type = parse_action_type(canonical, &signature.returns)?;
+
+    Ok(GeneratedActionProbe {
+        ident: identifier(mangle_action_name(canonical).identifier()),
+        param_types,
+        return_type,
+    })
+}
+
+fn parse_action_type(canonical: &str, ty: &str) -> Result<syn::Type, MacroExpansionError> {
+    syn::parse_str(ty).map_err(|source| MacroExpansionError::InvalidActionSignature {
+        action: canonical.to_owned(),
+        message: source.to_string(),
+    })
+}
+
+pub(crate) fn render_action_probes(action_probes: &[GeneratedActionProbe]) -> TokenStream2 {
+    if action_probes.is_empty() {
+        return TokenStream2::new();
+    }
+
+    let probe_idents = action_probes.iter().map(|probe| &probe.ident);
+    let probe_param_types = action_probes.iter().map(|probe| &probe.param_types);
+    let probe_return_types = action_probes.iter().map(|probe| &probe.return_type);
+
+    // Each `const _: fn(...) -> ... = crate::theorem_actions::...;` anchors the
+    // referenced symbol at compile time. Anonymous `_` items bypass dead-code
+    // checks without an `#[allow]`, so a signature mismatch surfaces as a
+    // normal type error rather than a silenced lint.
+    quote! {
+        #(
+            const _: fn(#(#probe_param_types),*) -> #probe_return_types =
+                crate::theorem_actions::#probe_idents;
+        )*
+    }
+}
+
+pub(crate) struct GeneratedActionProbe {
+    ident: Ident,
+    param_types: Vec<syn::Type>,
+    return_type: syn::Type,
+}
@@ -1,14 +1,16 @@
 //! Focused unit tests for the action-signature index.
 
-use super::super::{ActionSignatureIndex, MacroExpansionError};
+use super::super::MacroExpansionError;
+use crate::action_probe::ActionSignatureIndex;
 use googletest::prelude::*;
 use pretty_assertions::assert_eq as pretty_assert_eq;
+use test_helpers::theorem;
 use theoremc_core::schema::load_theorem_docs;
 
 #[test]
 fn action_signature_index_finds_one_action_in_one_document()
 -> Result<(), Box<dyn std::error::Error>> {
-    let docs = load_theorem_docs(&theorem_yaml(
+    let docs = load_theorem_docs(&theorem(
         "IndexedAction",
         concat!(
             "Actions:\n",
@@ -33,7 +35,7 @@ fn action_signature_index_accepts_equivalent_repeated_signatures()
 -> Result<(), Box<dyn std::error::Error>> {
     let docs = load_theorem_docs(&format!(
         "{}---\n{}",
-        theorem_yaml(
+        theorem(
             "FirstIndexedAction",
             concat!(
                 "Actions:\n",
@@ -43,7 +45,7 @@ fn action_signature_index_accepts_equivalent_repeated_signatures()
                 "    returns: u64\n",
             ),
         ),
-        theorem_yaml(
+        theorem(
             "SecondIndexedAction",
             concat!(
                 "Actions:\n",
@@ -73,7 +75,7 @@ fn action_signature_index_rejects_conflicting_signatures() -> Result<(), Box<dyn
 {
     let docs = load_theorem_docs(&format!(
         "{}---\n{}",
-        theorem_yaml(
+        theorem(
             "FirstConflictingAction",
             concat!(
                 "Actions:\n",
@@ -83,7 +85,7 @@ fn action_signature_index_rejects_conflicting_signatures() -> Result<(), Box<dyn
                 "    returns: bool\n",
             ),
         ),
-        theorem_yaml(
+        theorem(
             "SecondConflictingAction",
             concat!(
                 "Actions:\n",
@@ -109,7 +111,7 @@ fn action_signature_index_rejects_conflicting_signatures() -> Result<(), Box<dyn
 #[test]
 fn action_signature_index_reports_missing_selected_signature()
 -> Result<(), Box<dyn std::error::Error>> {
-    let docs = load_theorem_docs(&theorem_yaml("MissingIndexedAction", ""))?;
+    let docs = load_theorem_docs(&theorem("MissingIndexedAction", ""))?;
     let selected = vec!["account.deposit"];
     let index = ActionSignatureIndex::for_actions(&docs, &selected)?;
 
@@ -128,25 +130,3 @@ fn action_signature_index_reports_missing_selected_signature()
     );
     Ok(())
 }
-
-fn theorem_yaml(name: &str, actions: &str) -> String {
-    format!(
-        concat!(
-            "Theorem: {name}\n",
-            "About: Index test fixture\n",
-            "{actions}",
-            "Witness:\n",
-            "  - cover: \"true\"\n",
-            "    because: \"reachable\"\n",
-            "Prove:\n",
-            "  - assert: \"true\"\n",
-            "    because: \"trivial\"\n",
-            "Evidence:\n",
-            "  kani:\n",
-            "    unwind: 1\n",
-            "    expect: SUCCESS\n",
-        ),
-        name = name,
-        actions = actions,
-    )
-}
@@ -0,0 +1,120 @@
+//! Expansion for the `#[theorem_action]` attribute.
+//!
+//! The annotated function is emitted unchanged, followed by an anonymous
+//! `const _` block that submits a one-action manifest document built from its
+//! signature to the `theoremc::actions::RegisteredAction` inventory, so
+//! linking the program registers the action without listing it anywhere else
+//! or adding a name to the caller's module. The document is rendered and
+//! validated with the same code that loads hand-written manifests, so a
+//! function that a manifest could not describe is rejected at the attribute.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::{FnArg, ItemFn, LitStr, Pat, ReturnType, ext::IdentExt};
+use theoremc_core::actions::{
+    ActionManifest, ManifestAction, load_action_manifest, render_action_manifest,
+};
+
+/// Expands `#[theorem_action("name")]` on `item`, reporting a rejected
+/// function as a `compile_error!` next to the unchanged function.
+pub(crate) fn theorem_action(attr: TokenStream2, item: &TokenStream2) -> TokenStream2 {
+    match expand_theorem_action(attr, item.clone()) {
+        Ok(expanded) => expanded,
+        Err(error) => {
+            // Keep the function so the error is not followed by unresolved
+            // uses of it.
+            let compile_error = error.to_compile_error();
+            quote! { #item #compile_error }
+        }
+    }
+}
+
+/// Expands `#[theorem_action("name")]` on `item`.
+pub(crate) fn expand_theorem_action(
+    attr: TokenStream2,
+    item: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let name: LitStr = syn::parse2(attr).map_err(|error| {
+        syn::Error::new(
+            error.span(),
+            "expected a canonical action name such as `#[theorem_action(\"account.deposit\")]`",
+        )
+    })?;
+    let function: ItemFn = syn::parse2(item)?;
+    let registration = registration(&name, &function)?;
+
+    // The paths go through the `theoremc` facade; a crate depending on
+    // `theoremc-core` alone can name it with `extern crate theoremc_core as
+    // theoremc;`.
+    Ok(quote! {
+        #function
+
+        const _: () = {
+            const REGISTRATION: &str = #registration;
+
+            ::theoremc::__private::inventory::submit! {
+                ::theoremc::actions::RegisteredAction::new(REGISTRATION)
+            }
+        };
+    })
+}
+
+/// Renders and validates the manifest document registering `function`.
+fn registration(name: &LitStr, function: &ItemFn) -> syn::Result<String> {
+    let mut manifest = ActionManifest::default();
+    manifest
+        .actions
+        .insert(name.value(), manifest_action(function)?);
+    let rendered = render_action_manifest(&manifest);
+    load_action_manifest(&rendered).map_err(|error| syn::Error::new(name.span(), error))?;
+    Ok(rendered)
+}
+
+fn manifest_action(function: &ItemFn) -> syn::Result<ManifestAction> {
+    let signature = &function.sig;
+    if !signature.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &signature.generics,
+            "theorem actions cannot be generic; register a concrete wrapper instead",
+        ));
+    }
+    if let Some(asyncness) = signature.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "theorem actions cannot be async",
+        ));
+    }
+    let params = signature
+        .inputs
+        .iter()
+        .map(parameter)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let returns = match &signature.output {
+        ReturnType::Default => "()".to_owned(),
+        ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+    };
+    Ok(ManifestAction::new(params, returns))
+}
+
+fn parameter(input: &FnArg) -> syn::Result<(String, String)> {
+    let FnArg::Typed(typed) = input else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "theorem actions must be free functions, not methods taking `self`",
+        ));
+    };
+    match typed.pat.as_ref() {
+        Pat::Ident(binding) if binding.by_ref.is_none() && binding.subpat.is_none() => Ok((
+            binding.ident.unraw().to_string(),
+            typed.ty.to_token_stream().to_string(),
+        )),
+        _ => Err(syn::Error::new_spanned(
+            &typed.pat,
+            "theorem action parameters must be plain identifiers",
+        )),
+    }
+}
+
+#[cfg(test)]
+#[path = "action_registration_tests.rs"]
+mod tests;
@@ -0,0 +1,125 @@
+//! Unit tests for `#[theorem_action]` registration expansion.
+
+use pretty_assertions::assert_eq;
+use quote::quote;
+use rstest::rstest;
+use theoremc_core::actions::collect_registered_actions;
+
+use super::expand_theorem_action;
+
+/// Returns the manifest document registered by an expansion.
+fn registration(expanded: &proc_macro2::TokenStream) -> String {
+    let file: syn::File = syn::parse2(expanded.clone()).expect("expansion parses as items");
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Const(wrapper) => match wrapper.expr.as_ref() {
+                syn::Expr::Block(block) => Some(&block.block.stmts),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flatten()
+        .find_map(|stmt| match stmt {
+            syn::Stmt::Item(syn::Item::Const(registration)) => match registration.expr.as_ref() {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(document),
+                    ..
+                }) => Some(document.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("expansion emits a registration constant")
+}
+
+#[test]
+fn registration_records_the_function_signature() {
+    let expanded = expand_theorem_action(
+        quote!("account.deposit"),
+        quote! {
+            pub fn deposit(account: &mut Account, amount: u64) -> Result<u64, LedgerError> {
+                account.deposit(amount)
+            }
+        },
+    )
+    .expect("attribute expands");
+
+    let document = registration(&expanded);
+
+    assert_eq!(
+        document,
+        concat!(
+            "Actions:\n",
+            "  account.deposit:\n",
+            "    params:\n",
+            "      account: '& mut Account'\n",
+            "      amount: 'u64'\n",
+            "    returns: 'Result < u64 , LedgerError >'\n",
+        )
+    );
+    let manifest = collect_registered_actions(&[&document]).expect("registration loads");
+    assert!(
+        manifest
+            .get("account.deposit")
+            .is_some_and(|action| action.fallible)
+    );
+}
+
+#[test]
+fn registration_keeps_the_function_and_adds_no_name() {
+    let expanded = expand_theorem_action(
+        quote!("audit.flush_all"),
+        quote! { pub(crate) fn r#flush() {} },
+    )
+    .expect("attribute expands");
+
+    let rendered = expanded.to_string();
+
+    assert!(
+        rendered.starts_with("pub (crate) fn r#flush () { }"),
+        "{rendered}"
+    );
+    assert!(rendered.contains("const _ : () = {"), "{rendered}");
+    assert!(!rendered.contains("THEOREM_ACTION"), "{rendered}");
+    assert_eq!(registration(&expanded), "Actions:\n  audit.flush_all: {}\n");
+}
+
+#[test]
+fn registration_is_submitted_to_the_inventory() {
+    let expanded = expand_theorem_action(quote!("audit.flush_all"), quote! { fn flush() {} })
+        .expect("attribute expands");
+
+    let rendered = expanded.to_string();
+
+    assert!(
+        rendered.contains("RegisteredAction :: new (REGISTRATION)"),
+        "{rendered}"
+    );
+}
+
+#[rstest]
+#[case::non_literal_name(quote!(account.deposit), quote!(fn deposit() {}), "expected a canonical action name")]
+#[case::non_canonical_name(quote!("deposit"), quote!(fn deposit() {}), "invalid action name 'deposit'")]
+#[case::method(quote!("account.deposit"), quote!(fn deposit(&mut self) {}), "not methods taking `self`")]
+#[case::generic(quote!("account.deposit"), quote!(fn deposit<T>(value: T) {}), "cannot be generic")]
+#[case::asynchronous(quote!("account.deposit"), quote!(async fn deposit() {}), "cannot be async")]
+#[case::pattern_parameter(
+    quote!("account.deposit"),
+    quote!(fn deposit((left, right): (u8, u8)) {}),
+    "must be plain identifiers"
+)]
+#[case::named_lifetime(
+    quote!("account.deposit"),
+    quote!(fn deposit(name: &'a str) {}),
+    "free named lifetime parameter 'a'"
+)]
+fn unsupported_functions_are_rejected(
+    #[case] attr: proc_macro2::TokenStream,
+    #[case] item: proc_macro2::TokenStream,
+    #[case] expected: &str,
+) {
+    let error = expand_theorem_action(attr, item).expect_err("attribute should be rejected");
+
+    assert!(error.to_string().contains(expected), "{error}");
+}
@@ -0,0 +1,343 @@
+//! Generated Kani harness signatures and bodies.
+//!
+//! Each theorem document becomes one `#[kani::proof]` function. The body
+//! opens with the theorem's symbolic inputs: every `Forall` variable is bound
+//! with `kani::any()` and any range domain is narrowed immediately with
+//! `kani::assume`, while a `oneof` domain binds the variable to a
+//! nondeterministic choice between its listed values, a struct domain builds
+//! the value from one `kani::any()` per field, and a `vec` domain uses
+//! `kani::vec::any_vec` with its maximum length, so later statements only
+//! observe in-domain values. Derived variables are bound to their `from`
+//! expression, which may use any variable bound before them. An optional
+//! variable is `None` or `Some` of a value bound and narrowed as above, chosen
+//! by a symbolic `bool`. A configured Kani `solver` adds a
+//! `#[kani::solver(...)]` attribute alongside `proof` and `unwind`, and each
+//! configured stub adds a `#[kani::stub(original, stub)]` attribute. A
+//! theorem listing Kani `contracts` gets one `#[kani::proof_for_contract(...)]`
+//! harness per contract in place of its plain `#[kani::proof]`. A
+//! configuration with `playback` enabled also gets a plain copy of its
+//! harness under `cfg(test)`, which the unit tests Kani's concrete playback
+//! generates call to replay a counterexample.
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use theoremc_core::{
+    mangle::mangle_theorem_harness,
+    schema::{ForallDomain, ForallVar, KaniEvidence, TheoremDoc},
+};
+
+use super::{MacroExpansionError, identifier};
+
+/// One generated harness function.
+pub(crate) struct GeneratedHarness {
+    pub(crate) ident: Ident,
+    pub(crate) proof_attribute: TokenStream2,
+    pub(crate) unwind_literal: syn::LitInt,
+    pub(crate) attributes: TokenStream2,
+    pub(crate) body: TokenStream2,
+    pub(crate) playback: bool,
+}
+
+/// Builds the harnesses for every theorem document, preserving document
+/// order. Each Kani configuration yields one plain proof, or one contract
+/// proof per `Evidence.kani.contracts` entry, in declaration order.
+pub(crate) fn generated_harnesses(
+    theorem_path: &str,
+    theorem_docs: &[TheoremDoc],
+) -> Result<Vec<GeneratedHarness>, MacroExpansionError> {
+    let mut harnesses = Vec::with_capacity(theorem_docs.len());
+    for doc in theorem_docs {
+        harnesses.extend(theorem_harnesses(theorem_path, doc)?);
+    }
+    Ok(harnesses)
+}
+
+/// Builds the harnesses for one theorem document; every variant shares the
+/// theorem's body. A named Kani configuration suffixes the theorem's harness
+/// name with `__<name>`.
+fn theorem_harnesses(
+    theorem_path: &str,
+    doc: &TheoremDoc,
+) -> Result<Vec<GeneratedHarness>, MacroExpansionError> {
+    if doc.evidence.kani.is_empty() {
+        return Err(MacroExpansionError::MissingKaniEvidence {
+            theorem: doc.theorem.as_str().to_owned(),
+        });
+    }
+    let theorem_name = mangle_theorem_harness(theorem_path, doc.theorem.as_str());
+    let body = forall_bindings(doc)?;
+    let mut harnesses = Vec::with_capacity(doc.evidence.kani.len());
+    for kani in &doc.evidence.kani {
+        let base_name = kani.name.as_ref().map_or_else(
+            || theorem_name.identifier().to_owned(),
+            |name| format!("{}__{name}", theorem_name.identifier()),
+        );
+        let unwind_literal = syn::LitInt::new(&kani.unwind.to_string(), Span::call_site());
+        let attributes = kani_attributes(kani)?;
+        for (ident, proof_attribute) in proof_variants(&base_name, kani)? {
+            harnesses.push(GeneratedHarness {
+                ident,
+                proof_attribute,
+                unwind_literal: unwind_literal.clone(),
+                attributes: attributes.clone(),
+                body: body.clone(),
+                playback: kani.playback,
+            });
+        }
+    }
+    Ok(harnesses)
+}
+
+/// Pairs each harness name with its proof attribute. Contract harnesses
+/// suffix the theorem's harness name with `__contract_<n>`, counting from one
+/// in declaration order, so names stay deterministic and distinct.
+fn proof_variants(
+    base_name: &str,
+    kani: &KaniEvidence,
+) -> Result<Vec<(Ident, TokenStream2)>, MacroExpansionError> {
+    if kani.contracts.is_empty() {
+        return Ok(vec![(identifier(base_name), quote! { #[kani::proof] })]);
+    }
+    kani.contracts
+        .iter()
+        .enumerate()
+        .map(|(index, contract)| {
+            let target = parse_kani_path(contract)?;
+            Ok((
+                identifier(&format!("{base_name}__contract_{}", index + 1)),
+                quote! { #[kani::proof_for_contract(#target)] },
+            ))
+        })
+        .collect()
+}
+
+/// Emits the optional Kani attributes a theorem's evidence configures: the
+/// solver, then one `#[kani::stub(...)]` per stub in declaration order.
+fn kani_attributes(kani: &KaniEvidence) -> Result<TokenStream2, MacroExpansionError> {
+    let mut attributes = kani.solver.map_or_else(TokenStream2::new, |solver| {
+        let solver_ident = identifier(solver.as_str());
+        quote! { #[kani::solver(#solver_ident)] }
+    });
+    for stub in &kani.stubs {
+        let original = parse_kani_path(&stub.original)?;
+        let replacement = parse_kani_path(&stub.stub)?;
+        attributes.extend(quote! { #[kani::stub(#original, #replacement)] });
+    }
+    Ok(attributes)
+}
+
+fn parse_kani_path(path: &str) -> Result<syn::Path, MacroExpansionError> {
+    syn::parse_str(path).map_err(|source| MacroExpansionError::InvalidKaniPath {
+        path: path.to_owned(),
+        message: source.to_string(),
+    })
+}
+
+/// Emits one binding per `Forall` entry, in declaration order.
+fn forall_bindings(doc: &TheoremDoc) -> Result<TokenStream2, MacroExpansionError> {
+    let mut body = TokenStream2::new();
+    for (name, ty) in &doc.forall {
+        body.extend(forall_binding(name, ty, doc.forall_domains.get(name))?);
+    }
+    Ok(body)
+}
+
+/// Emits `let <var>: <type> = <value>;` followed by one `kani::assume(...)`
+/// per range constraint. An optional variable binds and narrows the wrapped
+/// value inside the `Some` branch of a symbolic choice.
+fn forall_binding(
+    name: &ForallVar,
+    ty: &str,
+    domain: Option<&ForallDomain>,
+) -> Result<TokenStream2, MacroExpansionError> {
+    let variable = identifier(name.as_str());
+    let parsed_ty = parse_type(ty)?;
+    if let Some((wrapped_ty, wrapped)) = domain.and_then(ForallDomain::optional_inner) {
+        let wrapped_binding = forall_binding(name, wrapped_ty, wrapped)?;
+        return Ok(quote! {
+            let #variable: #parsed_ty = if kani::any::<bool>() {
+                #wrapped_binding
+                Some(#variable)
+            } else {
+                None
+            };
+        });
+    }
+    let value = binding_value(name, ty, domain)?;
+    let constraints = domain
+        .map(|forall_domain| forall_domain.constraints(name.as_str()))
+        .unwrap_or_default()
+        .iter()
+        .map(|constraint| domain_expr(name, constraint))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! {
+        let #variable: #parsed_ty = #value;
+        #(kani::assume(#constraints);)*
+    })
+}
+
+/// Returns the initial value of a `Forall` variable: a struct literal with
+/// one `kani::any()` per field for struct domains, the `from` expression for
+/// derived variables, a vector of at most `max` arbitrary elements for length
+/// domains, a nondeterministic choice for `oneof` domains, and `kani::any()`
+/// otherwise.
+fn binding_value(
+    name: &ForallVar,
+    ty: &str,
+    domain: Option<&ForallDomain>,
+) -> Result<TokenStream2, MacroExpansionError> {
+    if let Some(fields) = domain.and_then(ForallDomain::struct_fields) {
+        return struct_literal(name, ty, fields);
+    }
+    if let Some(expr) = domain.and_then(ForallDomain::derived_expr) {
+        let derived = domain_expr(name, expr)?;
+        return Ok(quote! { #derived });
+    }
+    if let Some(max) = domain.and_then(ForallDomain::max_len) {
+        let max_literal = proc_macro2::Literal::u32_unsuffixed(max);
+        return Ok(quote! { kani::vec::any_vec::<_, #max_literal>() });
+    }
+    domain
+        .and_then(|forall_domain| forall_domain.choice_exprs(ty))
+        .map_or_else(
+            || Ok(quote! { kani::any() }),
+            |choices| nondeterministic_choice(name, &choices),
+        )
+}
+
+/// Builds `Ty { field: kani::any::<FieldTy>(), ... }`. Generic arguments on
+/// the struct path are rewritten in turbofish form, as expression position
+/// requires, and each field's declared type is checked by the compiler.
+fn struct_literal<'a>(
+    name: &ForallVar,
+    ty: &str,
+    fields: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Result<TokenStream2, MacroExpansionError> {
+    let mut path: syn::Path =
+        syn::parse_str(ty).map_err(|source| MacroExpansionError::InvalidForallDomain {
+            variable: name.as_str().to_owned(),
+            message: source.to_string(),
+        })?;
+    for segment in &mut path.segments {
+        if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            arguments.colon2_token = Some(syn::token::PathSep::default());
+        }
+    }
+    let (field_idents, field_types): (Vec<Ident>, Vec<syn::Type>) = fields
+        .into_iter()
+        .map(|(field, field_ty)| Ok((identifier(field), parse_type(field_ty)?)))
+        .collect::<Result<Vec<_>, MacroExpansionError>>()?
+        .into_iter()
+        .unzip();
+    Ok(quote! {
+        #path { #(#field_idents: kani::any::<#field_types>(),)* }
+    })
+}
+
+fn parse_type(ty: &str) -> Result<syn::Type, MacroExpansionError> {
+    syn::parse_str(ty).map_err(|source| MacroExpansionError::InvalidReferencedType {
+        ty: ty.to_owned(),
+        message: source.to_string(),
+    })
+}
+
+/// Selects one of `choices` with a symbolic index. The final value takes the
+/// wildcard arm, so every index yields a listed value and every listed value
+/// is reachable.
+fn nondeterministic_choice(
+    name: &ForallVar,
+    choices: &[String],
+) -> Result<TokenStream2, MacroExpansionError> {
+    let exprs = choices
+        .iter()
+        .map(|choice| domain_expr(name, choice))
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some((last, rest)) = exprs.split_last() else {
+        return Err(MacroExpansionError::InvalidForallDomain {
+            variable: name.as_str().to_owned(),
+            message: "oneof must list at least one value".to_owned(),
+        });
+    };
+    if rest.is_empty() {
+        return Ok(quote! { #last });
+    }
+    let indices = (0..rest.len()).map(syn::Index::from);
+    Ok(quote! {
+        match kani::any::<usize>() {
+            #(#indices => #rest,)*
+            _ => #last,
+        }
+    })
+}
+
+fn domain_expr(name: &ForallVar, source: &str) -> Result<syn::Expr, MacroExpansionError> {
+    syn::parse_str(source).map_err(|error| MacroExpansionError::InvalidForallDomain {
+        variable: name.as_str().to_owned(),
+        message: error.to_string(),
+    })
+}
+
+fn render_harness(harness: &GeneratedHarness) -> TokenStream2 {
+    let GeneratedHarness {
+        ident,
+        proof_attribute,
+        unwind_literal,
+        attributes,
+        body,
+        ..
+    } = harness;
+    quote! {
+        #proof_attribute
+        #[kani::unwind(#unwind_literal)]
+        #attributes
+        pub(crate) fn #ident() {
+            #body
+        }
+    }
+}
+
+/// Renders the cfg-gated `kani` module holding every harness, plus the const
+/// array of `fn()` pointers that anchors the harness symbols, and the
+/// `kani_playback` module when any harness enables concrete playback.
+pub(crate) fn render_harnesses(harnesses: &[GeneratedHarness]) -> TokenStream2 {
+    let harness_idents: Vec<&Ident> = harnesses.iter().map(|harness| &harness.ident).collect();
+    let harness_fns = harnesses.iter().map(render_harness);
+    let harness_count = syn::LitInt::new(&harness_idents.len().to_string(), Span::call_site());
+    let playback_tokens = render_playback_harnesses(harnesses);
+
+    quote! {
+        #[cfg(kani)]
+        pub(super) mod kani {
+            #(#harness_fns)*
+        }
+
+        #[cfg(kani)]
+        const _: [fn(); #harness_count] = [#(kani::#harness_idents),*];
+
+        #playback_tokens
+    }
+}
+
+/// Renders plain, attribute-free copies of the playback-enabled harnesses in
+/// a `cfg(test)` module, so concrete playback tests can pass them to
+/// `kani::concrete_playback_run` under an ordinary `cargo test`.
+fn render_playback_harnesses(harnesses: &[GeneratedHarness]) -> TokenStream2 {
+    let (idents, bodies): (Vec<&Ident>, Vec<&TokenStream2>) = harnesses
+        .iter()
+        .filter(|harness| harness.playback)
+        .map(|harness| (&harness.ident, &harness.body))
+        .unzip();
+    if idents.is_empty() {
+        return TokenStream2::new();
+    }
+    quote! {
+        #[cfg(all(test, not(kani)))]
+        pub(super) mod kani_playback {
+            #(
+                pub(crate) fn #idents() {
+                    #bodies
+                }
+            )*
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Unit tests for generated harness bodies.
+
+use super::tests_support::{TheoremFixture, expand_fixture, normalize};
+use camino::Utf8Path;
+use rstest::rstest;
+
+fn theorem_with_forall(forall: &str) -> TheoremFixture {
+    TheoremFixture(format!(
+        concat!(
+            "Theorem: BoundedDeposit\n",
+            "About: Deposits stay within bounds\n",
+            "Forall:\n{forall}",
+            "Witness:\n",
+            "  - cover: \"true\"\n",
+            "    because: \"reachable\"\n",
+            "Prove:\n",
+            "  - assert: \"true\"\n",
+            "    because: \"trivial\"\n",
+            "Evidence:\n",
+            "  kani:\n",
+            "    unwind: 1\n",
+            "    expect: SUCCESS\n",
+        ),
+        forall = forall,
+    ))
+}
+
+#[rstest]
+#[case::plain_type("  flag: bool\n", "let flag: bool = kani::any();")]
+#[case::bounded_range(
+    "  amount: { type: u64, min: 1, max: 100 }\n",
+    concat!(
+        "let amount: u64 = kani::any();",
+        "kani::assume(amount >= 1);",
+        "kani::assume(amount <= 100);",
+    )
+)]
+#[case::negative_lower_bound(
+    "  delta: { type: i32, min: -5 }\n",
+    "let delta: i32 = kani::any(); kani::assume(delta >= -5);"
+)]
+#[case::float_upper_bound(
+    "  ratio: { type: f64, max: 0.5 }\n",
+    "let ratio: f64 = kani::any(); kani::assume(ratio <= 0.5);"
+)]
+fn harness_binds_forall_inputs_and_assumes_bounds(
+    #[case] forall: &str,
+    #[case] expected_body: &str,
+) {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/bounded.theorem"),
+        &theorem_with_forall(forall),
+    )
+    .expect("bounded Forall fixture should expand");
+
+    let expected = format!("(){{{}}}", normalize(expected_body));
+    assert!(
+        expanded.contains(&expected),
+        "expected harness body {expected} in {expanded}"
+    );
+}
+
+#[rstest]
+#[case::enum_variants(
+    "  op: { type: crate::Op, oneof: [Deposit, Withdraw, Transfer] }\n",
+    concat!(
+        "let op: crate::Op = match kani::any::<usize>() {",
+        "0 => <crate::Op>::Deposit,",
+        "1 => <crate::Op>::Withdraw,",
+        "_ => <crate::Op>::Transfer,",
+        "};",
+    )
+)]
+#[case::qualified_path(
+    "  op: { type: crate::Op, oneof: [crate::Op::Deposit, Other] }\n",
+    concat!(
+        "let op: crate::Op = match kani::any::<usize>() {",
+        "0 => crate::Op::Deposit,",
+        "_ => <crate::Op>::Other,",
+        "};",
+    )
+)]
+#[case::numbers(
+    "  fee: { type: u8, oneof: [0, 5] }\n",
+    "let fee: u8 = match kani::any::<usize>() { 0 => 0, _ => 5, };"
+)]
+#[case::single_value("  flag: { type: bool, oneof: [true] }\n", "let flag: bool = true;")]
+fn harness_selects_oneof_values_nondeterministically(
+    #[case] forall: &str,
+    #[case] expected_body: &str,
+) {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/oneof.theorem"),
+        &theorem_with_forall(forall),
+    )
+    .expect("oneof Forall fixture should expand");
+
+    let expected = format!("(){{{}}}", normalize(expected_body));
+    assert!(
+        expanded.contains(&expected),
+        "expected harness body {expected} in {expanded}"
+    );
+}
+
+#[rstest]
+#[case::plain_struct(
+    "  params: { struct: crate::AccountParams, fields: { balance: u64, frozen: bool } }\n",
+    concat!(
+        "let params: crate::AccountParams = crate::AccountParams {",
+        "balance: kani::any::<u64>(),",
+        "frozen: kani::any::<bool>(),",
+        "};",
+    )
+)]
+#[case::generic_struct(
+    "  pair: { struct: crate::Pair<u8>, fields: { left: u8, right: u8 } }\n",
+    concat!(
+        "let pair: crate::Pair<u8> = crate::Pair::<u8> {",
+        "left: kani::any::<u8>(),",
+        "right: kani::any::<u8>(),",
+        "};",
+    )
+)]
+fn harness_builds_struct_inputs_field_by_field(#[case] forall: &str, #[case] expected_body: &str) {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/struct.theorem"),
+        &theorem_with_forall(forall),
+    )
+    .expect("struct Forall fixture should expand");
+
+    let expected = format!("(){{{}}}", normalize(expected_body));
+    assert!(
+        expanded.contains(&expected),
+        "expected harness body {expected} in {expanded}"
+    );
+}
+
+#[rstest]
+#[case::empty_allowed(
+    "  xs: { vec: u32, len: { max: 8 } }\n",
+    "let xs: Vec<u32> = kani::vec::any_vec::<_, 8>();"
+)]
+#[case::minimum_length(
+    "  xs: { vec: u8, len: { min: 1, max: 4 } }\n",
+    "let xs: Vec<u8> = kani::vec::any_vec::<_, 4>(); kani::assume(xs.len() >= 1);"
+)]
+fn harness_generates_bounded_vectors(#[case] forall: &str, #[case] expected_body: &str) {
+    let TheoremFixture(yaml) = theorem_with_forall(forall);
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/vec.theorem"),
+        &TheoremFixture(yaml.replace("unwind: 1", "unwind: 9")),
+    )
+    .expect("vec Forall fixture should expand");
+
+    let expected = format!("(){{{}}}", normalize(expected_body));
+    assert!(
+        expanded.contains(&expected),
+        "expected harness body {expected} in {expanded}"
+    );
+}
+
+#[rstest]
+#[case::plain_type(
+    "  memo: { type: u32, optional: true }\n",
+    "let memo: Option<u32> = if kani::any::<bool>() { let memo: u32 = kani::any(); Some(memo) } else { None };"
+)]
+#[case::bounded_range(
+    "  limit: { type: u64, max: 10, optional: true }\n",
+    concat!(
+        "let limit: Option<u64> = if kani::any::<bool>() {",
+        "let limit: u64 = kani::any();",
+        "kani::assume(limit <= 10);",
+        "Some(limit)",
+        "} else { None };",
+    )
+)]
+#[case::struct_value(
+    "  params: { struct: crate::Params, fields: { frozen: bool }, optional: true }\n",
+    concat!(
+        "let params: Option<crate::Params> = if kani::any::<bool>() {",
+        "let params: crate::Params = crate::Params { frozen: kani::any::<bool>(), };",
+        "Some(params)",
+        "} else { None };",
+    )
+)]
+fn harness_chooses_optional_values_nondeterministically(
+    #[case] forall: &str,
+    #[case] expected_body: &str,
+) {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/optional.theorem"),
+        &theorem_with_forall(forall),
+    )
+    .expect("optional Forall fixture should expand");
+
+    let expected = format!("(){{{}}}", normalize(expected_body));
+    assert!(
+        expanded.contains(&expected),
+        "expected harness body {expected} in {expanded}"
+    );
+}
+
+#[test]
+fn derived_variables_are_computed_from_earlier_bindings() {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/derived.theorem"),
+        &theorem_with_forall(concat!(
+            "  a: u32\n",
+            "  b: u32\n",
+            "  total: { type: u64, from: \"u64::from(a) + u64::from(b)\" }\n",
+        )),
+    )
+    .expect("derived Forall fixture should expand");
+
+    let expected = normalize(concat!(
+        "let a: u32 = kani::any();",
+        "let b: u32 = kani::any();",
+        "let total: u64 = u64::from(a) + u64::from(b);",
+    ));
+    assert!(
+        expanded.contains(&expected),
+        "expected {expected} in {expanded}"
+    );
+}
+
+#[test]
+fn forall_bindings_follow_declaration_order() {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/ordered.theorem"),
+        &theorem_with_forall("  second: u8\n  first: { type: u8, max: 3 }\n"),
+    )
+    .expect("ordered Forall fixture should expand");
+
+    let expected = normalize(concat!(
+        "let second: u8 = kani::any();",
+        "let first: u8 = kani::any();",
+        "kani::assume(first <= 3);",
+    ));
+    assert!(
+        expanded.contains(&expected),
+        "expected {expected} in {expanded}"
+    );
+}
+
+#[path = "harness_tests/kani_evidence.rs"]
+mod kani_evidence;
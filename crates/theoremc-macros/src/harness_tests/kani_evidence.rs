@@ -0,0 +1,186 @@
+//! Unit tests for the Kani attributes and harness variants evidence
+//! configures.
+
+use super::super::tests_support::{TheoremFixture, expand_fixture, normalize};
+use super::theorem_with_forall;
+use camino::Utf8Path;
+use rstest::rstest;
+use theoremc_core::mangle::mangle_theorem_harness;
+
+#[rstest]
+#[case::cadical("cadical")]
+#[case::kissat("kissat")]
+#[case::minisat("minisat")]
+fn harness_selects_the_configured_solver(#[case] solver: &str) {
+    let TheoremFixture(yaml) = theorem_with_forall("  flag: bool\n");
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/solver.theorem"),
+        &TheoremFixture(yaml.replace(
+            "    expect: SUCCESS\n",
+            &format!("    expect: SUCCESS\n    solver: {solver}\n"),
+        )),
+    )
+    .expect("solver fixture should expand");
+
+    let expected = normalize(&format!("#[kani::unwind(1)] #[kani::solver({solver})]"));
+    assert!(
+        expanded.contains(&expected),
+        "expected {expected} in {expanded}"
+    );
+}
+
+#[test]
+fn harness_omits_the_solver_attribute_by_default() {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/plain.theorem"),
+        &theorem_with_forall("  flag: bool\n"),
+    )
+    .expect("default solver fixture should expand");
+
+    assert!(
+        !expanded.contains("kani::solver"),
+        "expected no solver attribute in {expanded}"
+    );
+}
+
+#[test]
+fn harness_stubs_each_configured_function_in_order() {
+    let TheoremFixture(yaml) = theorem_with_forall("  flag: bool\n");
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/stubs.theorem"),
+        &TheoremFixture(yaml.replace(
+            "    expect: SUCCESS\n",
+            concat!(
+                "    expect: SUCCESS\n",
+                "    stubs:\n",
+                "      - { original: std::time::SystemTime::now, stub: crate::stubs::fixed_now }\n",
+                "      - { original: rand::random, stub: crate::stubs::zero }\n",
+            ),
+        )),
+    )
+    .expect("stub fixture should expand");
+
+    let expected = normalize(concat!(
+        "#[kani::unwind(1)] ",
+        "#[kani::stub(std::time::SystemTime::now, crate::stubs::fixed_now)] ",
+        "#[kani::stub(rand::random, crate::stubs::zero)]",
+    ));
+    assert!(
+        expanded.contains(&expected),
+        "expected {expected} in {expanded}"
+    );
+}
+
+#[test]
+fn harness_emits_one_contract_proof_per_listed_contract() {
+    let TheoremFixture(yaml) = theorem_with_forall("  flag: bool\n");
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/contracts.theorem"),
+        &TheoremFixture(yaml.replace(
+            "    expect: SUCCESS\n",
+            "    expect: SUCCESS\n    contracts: [crate::ledger::deposit, crate::ledger::withdraw]\n",
+        )),
+    )
+    .expect("contract fixture should expand");
+    let harness = mangle_theorem_harness("theorems/contracts.theorem", "BoundedDeposit");
+
+    for (index, contract) in ["crate::ledger::deposit", "crate::ledger::withdraw"]
+        .iter()
+        .enumerate()
+    {
+        let expected = normalize(&format!(
+            "#[kani::proof_for_contract({contract})] #[kani::unwind(1)] \
+             pub(crate) fn {}__contract_{}()",
+            harness.identifier(),
+            index + 1
+        ));
+        assert!(
+            expanded.contains(&expected),
+            "expected {expected} in {expanded}"
+        );
+    }
+    assert!(
+        !expanded.contains("kani::proof]"),
+        "expected no plain proof in {expanded}"
+    );
+    assert!(expanded.contains(&normalize("const _: [fn(); 2]")));
+}
+
+#[test]
+fn harness_emits_one_variant_per_named_configuration() {
+    let TheoremFixture(yaml) = theorem_with_forall("  flag: bool\n");
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/matrix.theorem"),
+        &TheoremFixture(yaml.replace(
+            "  kani:\n    unwind: 1\n    expect: SUCCESS\n",
+            concat!(
+                "  kani:\n",
+                "    - { name: quick, unwind: 1, expect: SUCCESS }\n",
+                "    - { name: nightly, unwind: 8, expect: SUCCESS, solver: kissat }\n",
+            ),
+        )),
+    )
+    .expect("matrix fixture should expand");
+    let harness = mangle_theorem_harness("theorems/matrix.theorem", "BoundedDeposit");
+
+    for variant in [
+        format!(
+            "#[kani::proof] #[kani::unwind(1)] pub(crate) fn {}__quick()",
+            harness.identifier()
+        ),
+        format!(
+            "#[kani::proof] #[kani::unwind(8)] #[kani::solver(kissat)] \
+             pub(crate) fn {}__nightly()",
+            harness.identifier()
+        ),
+    ] {
+        let expected = normalize(&variant);
+        assert!(
+            expanded.contains(&expected),
+            "expected {expected} in {expanded}"
+        );
+    }
+    assert!(expanded.contains(&normalize("const _: [fn(); 2]")));
+}
+
+#[test]
+fn playback_adds_a_plain_test_copy_of_the_harness() {
+    let TheoremFixture(yaml) = theorem_with_forall("  flag: bool\n");
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/playback.theorem"),
+        &TheoremFixture(yaml.replace(
+            "  kani:\n    unwind: 1\n    expect: SUCCESS\n",
+            concat!(
+                "  kani:\n",
+                "    - { name: quick, unwind: 1, expect: SUCCESS }\n",
+                "    - { name: replay, unwind: 1, expect: SUCCESS, playback: true }\n",
+            ),
+        )),
+    )
+    .expect("playback fixture should expand");
+    let harness = mangle_theorem_harness("theorems/playback.theorem", "BoundedDeposit");
+
+    let expected = normalize(&format!(
+        "#[cfg(all(test, not(kani)))] pub(super) mod kani_playback {{ \
+         pub(crate) fn {}__replay() {{ let flag: bool = kani::any(); }} }}",
+        harness.identifier()
+    ));
+    assert!(
+        expanded.contains(&expected),
+        "expected {expected} in {expanded}"
+    );
+}
+
+#[test]
+fn harness_omits_playback_copies_by_default() {
+    let expanded = expand_fixture(
+        Utf8Path::new("theorems/plain.theorem"),
+        &theorem_with_forall("  flag: bool\n"),
+    )
+    .expect("default playback fixture should expand");
+
+    assert!(
+        !expanded.contains("kani_playback"),
+        "expected no playback module in {expanded}"
+    );
+}
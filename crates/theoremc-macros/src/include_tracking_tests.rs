@@ -0,0 +1,79 @@
+//! Unit tests for compile-time tracking of `Include` fragments.
+
+use super::expand_theorem_file_at;
+use super::tests_support::{TheoremFixture, normalize, temp_fixture_dir, write_fixture};
+use camino::Utf8Path;
+
+const THEOREM: &str = concat!(
+    "Theorem: UsesSharedLimits\n",
+    "About: Includes shared fragments\n",
+    "Include:\n",
+    "  - theorems/shared/amounts.yaml\n",
+    "Witness:\n",
+    "  - cover: \"amount == limit\"\n",
+    "    because: \"the limit is reachable\"\n",
+    "Prove:\n",
+    "  - assert: \"amount <= limit\"\n",
+    "    because: \"the shared assumption bounds amount\"\n",
+    "Evidence:\n",
+    "  kani:\n",
+    "    unwind: 1\n",
+    "    expect: SUCCESS\n",
+);
+
+fn tracking_const(path: &str) -> String {
+    normalize(&format!(
+        "const _: &str = include_str!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/\", \"{path}\"));"
+    ))
+}
+
+#[test]
+fn included_fragments_are_tracked_as_compile_time_dependencies() {
+    let (_temp_dir, fixture_dir) = temp_fixture_dir().expect("fixture directory should be created");
+    write_fixture(
+        &fixture_dir,
+        Utf8Path::new("theorems/shared/limits.yaml"),
+        &TheoremFixture("Forall:\n  limit: u64\n".to_owned()),
+    )
+    .expect("fragment should be written");
+    write_fixture(
+        &fixture_dir,
+        Utf8Path::new("theorems/shared/amounts.yaml"),
+        &TheoremFixture(
+            concat!(
+                "Include:\n  - theorems/shared/limits.yaml\n",
+                "Forall:\n  amount: u64\n",
+                "Assume:\n",
+                "  - expr: \"amount <= limit\"\n",
+                "    because: \"amounts respect the limit\"\n",
+            )
+            .to_owned(),
+        ),
+    )
+    .expect("fragment should be written");
+    let theorem_path = Utf8Path::new("theorems/shared_limits.theorem");
+    write_fixture(
+        &fixture_dir,
+        theorem_path,
+        &TheoremFixture(THEOREM.to_owned()),
+    )
+    .expect("fixture should be written");
+
+    let path_literal = syn::LitStr::new(theorem_path.as_str(), proc_macro2::Span::call_site());
+    let expansion = normalize(
+        &expand_theorem_file_at(&fixture_dir, &path_literal)
+            .expect("theorem file should expand")
+            .to_string(),
+    );
+
+    for tracked in [
+        "theorems/shared_limits.theorem",
+        "theorems/shared/amounts.yaml",
+        "theorems/shared/limits.yaml",
+    ] {
+        assert!(
+            expansion.contains(&tracking_const(tracked)),
+            "expected '{tracked}' to be tracked in {expansion}"
+        );
+    }
+}
@@ -1,9 +1,6 @@
 //! Proc-macro expansion for compile-time theorem integration.
 
-use std::{
-    collections::{BTreeMap, BTreeSet},
-    env,
-};
+use std::{collections::BTreeSet, env};
 
 use camino::{Utf8Path, Utf8PathBuf};
 use proc_macro::TokenStream;
@@ -11,14 +8,17 @@ use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{LitStr, parse_macro_input};
 use theoremc_core::{
-    TheoremFileLoadError,
-    collision::{referenced_actions, referenced_types},
-    load_theorem_file_from_manifest_dir,
-    mangle::{mangle_action_name, mangle_module_path, mangle_theorem_harness},
-    path_format::normalize_path_separators,
-    schema::{ActionSignature, SchemaDiagnostic},
+    TheoremFileLoadError, collision::referenced_types, load_theorem_file_from_manifest_dir,
+    mangle::mangle_module_path, path_format::normalize_path_separators, schema::SchemaDiagnostic,
 };
 
+mod action_probe;
+mod action_registration;
+mod harness;
+
+use action_probe::{generated_action_probes, render_action_probes};
+use harness::{generated_harnesses, render_harnesses};
+
 /// Expands a crate-relative `.theorem` file into a stable private module.
 ///
 /// # Input
@@ -40,10 +40,19 @@ use theoremc_core::{
 ///
 /// - A `const _: &str = include_str!(…)` anchors the theorem source to
 ///   `CARGO_MANIFEST_DIR` so the file is tracked as a compile-time dependency.
+///   Every fragment merged through `Include` is anchored the same way.
 /// - A `#[cfg(kani)] pub(super) mod kani` sub-module contains one
 ///   `#[kani::proof]` and `#[kani::unwind(n)]` `pub(crate) fn` per theorem
 ///   document, named via
 ///   [`theoremc_core::mangle::mangle_theorem_harness`].
+///   Each harness binds every `Forall` variable with `kani::any()` and
+///   narrows structured domains (`min`/`max`) with `kani::assume`.
+///   Evidence-configured solvers and stubs add `#[kani::solver(...)]` and
+///   `#[kani::stub(...)]` attributes, and each listed contract replaces the
+///   plain proof with a `#[kani::proof_for_contract(...)]` harness.
+/// - Harnesses whose evidence enables `playback` are also emitted without
+///   Kani attributes in a `#[cfg(all(test, not(kani)))] pub(super) mod
+///   kani_playback`, for Kani's concrete playback tests to call.
 /// - A cfg-gated const array of `fn()` pointers sized to the harness count
 ///   anchors all generated symbols when Kani is compiling the crate.
 ///
@@ -119,6 +128,35 @@ pub fn theorem_file(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Registers a function as the implementation of a theorem action.
+///
+/// The attribute takes the canonical action name as a string literal and
+/// leaves the function unchanged. Next to it, inside an anonymous `const _`
+/// block that adds no name to the module, it submits a one-action manifest
+/// document recorded from the function's signature to the
+/// `theoremc::actions::RegisteredAction` inventory:
+///
+/// ```ignore
+/// #[theorem_action("account.deposit")]
+/// pub fn deposit(account: &mut Account, amount: u64) -> Result<u64, LedgerError> {
+///     account.deposit(amount)
+/// }
+///
+/// // Every registration linked into the program, sorted by action name.
+/// let manifest = theoremc::actions::registered_actions()?;
+/// ```
+///
+/// # Errors
+///
+/// A `compile_error!` is reported, and the function kept, when the name is
+/// not a canonical action name, or when the function is generic, `async`,
+/// takes `self`, binds a parameter with a pattern, or uses a type an action
+/// manifest rejects.
+#[proc_macro_attribute]
+pub fn theorem_action(attr: TokenStream, item: TokenStream) -> TokenStream {
+    action_registration::theorem_action(attr.into(), &item.into()).into()
+}
+
 fn expand_theorem_file(path_literal: &LitStr) -> Result<TokenStream2, MacroExpansionError> {
     let manifest_dir = manifest_dir_from_env()?;
     expand_theorem_file_at(&manifest_dir, path_literal)
@@ -155,12 +193,8 @@ fn render_expansion(
     let action_probe_tokens = render_action_probes(&action_probes);
     let type_probes = generated_referenced_type_probes(theorem_docs)?;
     let type_probe_tokens = render_referenced_type_probes(&type_probes);
-    let harness_idents: Vec<&Ident> = harnesses.iter().map(|harness| &harness.ident).collect();
-    let unwind_literals: Vec<&syn::LitInt> = harnesses
-        .iter()
-        .map(|harness| &harness.unwind_literal)
-        .collect();
-    let harness_count = syn::LitInt::new(&harness_idents.len().to_string(), Span::call_site());
+    let harness_tokens = render_harnesses(&harnesses);
+    let include_literals = included_fragment_literals(path_literal, theorem_docs);
 
     Ok(quote! {
         #[expect(
@@ -170,25 +204,35 @@ fn render_expansion(
         mod #module_ident {
             const _: &str =
                 include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #path_literal));
+            #(
+                const _: &str =
+                    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #include_literals));
+            )*
 
             #action_probe_tokens
             #type_probe_tokens
 
-            #[cfg(kani)]
-            pub(super) mod kani {
-                #(
-                    #[kani::proof]
-                    #[kani::unwind(#unwind_literals)]
-                    pub(crate) fn #harness_idents() {}
-                )*
-            }
-
-            #[cfg(kani)]
-            const _: [fn(); #harness_count] = [#(kani::#harness_idents),*];
+            #harness_tokens
         }
     })
 }
 
+/// Returns one literal per distinct `Include` fragment so edits to shared
+/// fragments retrigger expansion.
+fn included_fragment_literals(
+    path_literal: &LitStr,
+    theorem_docs: &[theoremc_core::schema::TheoremDoc],
+) -> Vec<LitStr> {
+    let fragments: BTreeSet<&str> = theorem_docs
+        .iter()
+        .flat_map(|doc| doc.include.iter().map(String::as_str))
+        .collect();
+    fragments
+        .into_iter()
+        .map(|fragment| LitStr::new(&normalize_path_separators(fragment), path_literal.span()))
+        .collect()
+}
+
 fn generated_referenced_type_probes(
     theorem_docs: &[theoremc_core::schema::TheoremDoc],
 ) -> Result<Vec<syn::Type>, MacroExpansionError> {
@@ -204,144 +248,6 @@ fn parse_referenced_type(ty: &str) -> Result<syn::Type, MacroExpansionError> {
         message: source.to_string(),
     })
 }
-fn generated_harnesses(
-    theorem_path: &str,
-    theorem_docs: &[theoremc_core::schema::TheoremDoc],
-) -> Result<Vec<GeneratedHarness>, MacroExpansionError> {
-    theorem_docs
-        .iter()
-        .map(|doc| {
-            let kani = doc.evidence.kani.as_ref().ok_or_else(|| {
-                MacroExpansionError::MissingKaniEvidence {
-                    theorem: doc.theorem.as_str().to_owned(),
-                }
-            })?;
-            Ok(GeneratedHarness {
-                ident: identifier(
-                    mangle_theorem_harness(theorem_path, doc.theorem.as_str()).identifier(),
-                ),
-                unwind_literal: syn::LitInt::new(&kani.unwind.to_string(), Span::call_site()),
-            })
-        })
-        .collect()
-}
-
-fn generated_action_probes(
-    theorem_docs: &[theoremc_core::schema::TheoremDoc],
-) -> Result<Vec<GeneratedActionProbe>, MacroExpansionError> {
-    let referenced = referenced_actions(theorem_docs);
-    let signature_index = ActionSignatureIndex::for_actions(theorem_docs, &referenced)?;
-    referenced
-        .iter()
-        .map(|canonical| {
-            let signature = signature_index.signature_for(canonical)?;
-            action_probe(canonical, signature)
-        })
-        .collect()
-}
-
-#[derive(Debug)]
-struct ActionSignatureIndex<'a> {
-    signatures: BTreeMap<&'a str, &'a ActionSignature>,
-}
-
-impl<'a> ActionSignatureIndex<'a> {
-    fn for_actions(
-        theorem_docs: &'a [theoremc_core::schema::TheoremDoc],
-        canonical_actions: &[&str],
-    ) -> Result<Self, MacroExpansionError> {
-        let selected = canonical_actions.iter().copied().collect::<BTreeSet<_>>();
-        let mut declared_signatures: BTreeMap<&'a str, &'a ActionSignature> = BTreeMap::new();
-
-        for doc in theorem_docs {
-            for (action, signature) in &doc.actions {
-                let canonical = action.as_str();
-                Self::insert_signature(&mut declared_signatures, canonical, signature)?;
-            }
-        }
-
-        let signatures = declared_signatures
-            .into_iter()
-            .filter(|(action, _)| selected.contains(action))
-            .collect();
-
-        Ok(Self { signatures })
-    }
-
-    fn insert_signature(
-        signatures: &mut BTreeMap<&'a str, &'a ActionSignature>,
-        canonical: &'a str,
-        signature: &'a ActionSignature,
-    ) -> Result<(), MacroExpansionError> {
-        let Some(first) = signatures.get(canonical) else {
-            signatures.insert(canonical, signature);
-            return Ok(());
-        };
-
-        if signature.is_semantically_equivalent(first) {
-            return Ok(());
-        }
-
-        Err(MacroExpansionError::ConflictingActionSignature {
-            action: canonical.to_owned(),
-        })
-    }
-
-    fn signature_for(&self, canonical: &str) -> Result<&'a ActionSignature, MacroExpansionError> {
-        self.signatures.get(canonical).copied().ok_or_else(|| {
-            MacroExpansionError::MissingActionSignature {
-                action: canonical.to_owned(),
-            }
-        })
-    }
-}
-
-fn action_probe(
-    canonical: &str,
-    signature: &ActionSignature,
-) -> Result<GeneratedActionProbe, MacroExpansionError> {
-    let param_types = signature
-        .params
-        .values()
-        .map(|param| parse_action_type(canonical, param))
-        .collect::<Result<Vec<_>, _>>()?;
-    let return_type = parse_action_type(canonical, &signature.returns)?;
-
-    Ok(GeneratedActionProbe {
-        ident: identifier(mangle_action_name(canonical).identifier()),
-        param_types,
-        return_type,
-    })
-}
-
-fn parse_action_type(canonical: &str, ty: &str) -> Result<syn::Type, MacroExpansionError> {
-    syn::parse_str(ty).map_err(|source| MacroExpansionError::InvalidActionSignature {
-        action: canonical.to_owned(),
-        message: source.to_string(),
-    })
-}
-
-fn render_action_probes(action_probes: &[GeneratedActionProbe]) -> TokenStream2 {
-    if action_probes.is_empty() {
-        return TokenStream2::new();
-    }
-
-    let probe_idents = action_probes.iter().map(|probe| &probe.ident);
-    let probe_param_types = action_probes.iter().map(|probe| &probe.param_types);
-    let probe_return_types = action_probes.iter().map(|probe| &probe.return_type);
-
-    // Each `const _: fn(...) -> ... = crate::theorem_actions::...;` anchors the
-    // referenced symbol at compile time. Anonymous `_` items bypass dead-code
-    // checks without an `#[allow]`, so a signature mismatch surfaces as a
-    // normal type error rather than a silenced lint.
-    quote! {
-        #(
-            const _: fn(#(#probe_param_types),*) -> #probe_return_types =
-                crate::theorem_actions::#probe_idents;
-        )*
-    }
-}
-
 fn render_referenced_type_probes(type_probes: &[syn::Type]) -> TokenStream2 {
     if type_probes.is_empty() {
         return TokenStream2::new();
@@ -360,17 +266,6 @@ fn identifier(name: &str) -> Ident {
     Ident::new(name, Span::call_site())
 }
 
-struct GeneratedHarness {
-    ident: Ident,
-    unwind_literal: syn::LitInt,
-}
-
-struct GeneratedActionProbe {
-    ident: Ident,
-    param_types: Vec<syn::Type>,
-    return_type: syn::Type,
-}
-
 #[derive(Debug, thiserror::Error)]
 enum MacroExpansionError {
     #[error("`CARGO_MANIFEST_DIR` is not set during theorem macro expansion")]
@@ -385,6 +280,10 @@ enum MacroExpansionError {
     InvalidActionSignature { action: String, message: String },
     #[error("referenced type `{ty}` is invalid: {message}")]
     InvalidReferencedType { ty: String, message: String },
+    #[error("Forall variable `{variable}` has an invalid domain constraint: {message}")]
+    InvalidForallDomain { variable: String, message: String },
+    #[error("Kani evidence path `{path}` is invalid: {message}")]
+    InvalidKaniPath { path: String, message: String },
     #[error("{0}")]
     LoadTheoremFile(String),
 }
@@ -419,7 +318,17 @@ mod tests;
 #[path = "action_probe_tests.rs"]
 mod action_probe_tests;
 
+/// Private expansion tests for generated harness bodies.
+#[cfg(test)]
+#[path = "harness_tests.rs"]
+mod harness_tests;
+
 /// Private expansion tests for compile-time referenced-type probe generation.
 #[cfg(test)]
 #[path = "type_probe_tests.rs"]
 mod type_probe_tests;
+
+/// Private expansion tests for `Include` fragment dependency tracking.
+#[cfg(test)]
+#[path = "include_tracking_tests.rs"]
+mod include_tracking_tests;
@@ -22,8 +22,8 @@ use rstest::rstest;
 use theoremc_core::{
     mangle::mangle_theorem_harness,
     schema::{
-        Assertion, Evidence, KaniEvidence, KaniExpectation, TheoremDoc, TheoremName, TheoremValue,
-        WitnessCheck,
+        Assertion, Evidence, KaniEvidence, KaniExpectation, TheoremDoc, TheoremExpr, TheoremName,
+        TheoremValue, WitnessCheck,
     },
 };
 
@@ -146,31 +146,14 @@ fn expansion_snapshot_matches_golden_output() -> Result<(), Box<dyn std::error::
 
 #[test]
 fn generated_harnesses_reports_missing_kani_evidence() {
-    let doc = TheoremDoc {
-        schema: None,
-        theorem: TheoremName::new("NoKaniEvidence".to_owned()).expect("valid theorem name"),
-        about: "Missing Kani evidence coverage".to_owned(),
-        tags: Vec::new(),
-        given: Vec::new(),
-        forall: Default::default(),
-        actions: Default::default(),
-        assume: Vec::new(),
-        witness: vec![WitnessCheck {
-            cover: "true".to_owned(),
-            because: "reachable".to_owned(),
-        }],
-        let_bindings: Default::default(),
-        do_steps: Vec::new(),
-        prove: vec![Assertion {
-            assert_expr: "true".to_owned(),
-            because: "trivial".to_owned(),
-        }],
-        evidence: Evidence {
-            kani: None,
+    let doc = theorem_doc_with_evidence(
+        "NoKaniEvidence".to_owned(),
+        Evidence {
+            kani: Vec::new(),
             verus: Some(TheoremValue::String("future backend".to_owned())),
             stateright: None,
         },
-    };
+    );
 
     let error = generated_harnesses("theorems/no-kani.theorem", &[doc])
         .err()
@@ -184,35 +167,60 @@ fn generated_harnesses_reports_missing_kani_evidence() {
 }
 
 fn theorem_doc_with_unwind(name: String, unwind: u32) -> TheoremDoc {
+    theorem_doc_with_evidence(
+        name,
+        Evidence {
+            kani: vec![KaniEvidence {
+                name: None,
+                unwind,
+                loop_unwind: Default::default(),
+                expect: KaniExpectation::Success,
+                allow_vacuous: false,
+                vacuity_because: None,
+                solver: None,
+                extra_args: Vec::new(),
+                timeout: None,
+                memory_limit: None,
+                stubs: Vec::new(),
+                contracts: Vec::new(),
+                counterexample: Default::default(),
+                playback: false,
+                coverage: false,
+            }],
+            verus: None,
+            stateright: None,
+        },
+    )
+}
+
+fn theorem_doc_with_evidence(name: String, evidence: Evidence) -> TheoremDoc {
     TheoremDoc {
         schema: None,
         theorem: TheoremName::new(name).expect("generated theorem name should be valid"),
         about: "Generated theorem".to_owned(),
         tags: Vec::new(),
+        traces: Vec::new(),
         given: Vec::new(),
+        depends_on: Vec::new(),
+        include: Vec::new(),
+        types: Default::default(),
         forall: Default::default(),
+        forall_domains: Default::default(),
         actions: Default::default(),
         assume: Vec::new(),
         witness: vec![WitnessCheck {
-            cover: "true".to_owned(),
-            because: "reachable".to_owned(),
+            cover: TheoremExpr::new("true").expect("literal expression should be valid"),
+            because: "reachable".into(),
         }],
         let_bindings: Default::default(),
-        do_steps: Vec::new(),
+        do_steps: Default::default(),
         prove: vec![Assertion {
-            assert_expr: "true".to_owned(),
-            because: "trivial".to_owned(),
+            assert_expr: TheoremExpr::new("true").expect("literal expression should be valid"),
+            because: "trivial".into(),
+            step: None,
         }],
-        evidence: Evidence {
-            kani: Some(KaniEvidence {
-                unwind,
-                expect: KaniExpectation::Success,
-                allow_vacuous: false,
-                vacuity_because: None,
-            }),
-            verus: None,
-            stateright: None,
-        },
+        evidence,
+        lints: Default::default(),
     }
 }
 
@@ -279,31 +287,14 @@ proptest! {
                 if present {
                     theorem_doc_with_unwind(name, 1)
                 } else {
-                    TheoremDoc {
-                        schema: None,
-                        theorem: TheoremName::new(name).expect("valid theorem name"),
-                        about: "Missing kani".to_owned(),
-                        tags: Vec::new(),
-                        given: Vec::new(),
-                        forall: Default::default(),
-                        actions: Default::default(),
-                        assume: Vec::new(),
-                        witness: vec![WitnessCheck {
-                            cover: "true".to_owned(),
-                            because: "reachable".to_owned(),
-                        }],
-                        let_bindings: Default::default(),
-                        do_steps: Vec::new(),
-                        prove: vec![Assertion {
-                            assert_expr: "true".to_owned(),
-                            because: "trivial".to_owned(),
-                        }],
-                        evidence: Evidence {
-                            kani: None,
+                    theorem_doc_with_evidence(
+                        name,
+                        Evidence {
+                            kani: Vec::new(),
                             verus: None,
                             stateright: None,
                         },
-                    }
+                    )
                 }
             })
             .collect();
@@ -47,12 +47,14 @@ fn compile_errors() -> Result<(), Box<dyn std::error::Error>> {
     t.pass("tests/expand/typed_action_probe.rs");
     t.pass("tests/expand/valid_theorem.rs");
     t.pass("tests/expand/equivalent_action_signatures.rs");
+    t.pass("tests/expand/registered_action.rs");
     t.compile_fail("tests/expand/conflicting_action_signatures.rs");
     t.compile_fail("tests/expand/invalid_theorem.rs");
     t.compile_fail("tests/expand/missing_action_export.rs");
     t.compile_fail("tests/expand/missing_kani_evidence.rs");
     t.compile_fail("tests/expand/missing_referenced_type.rs");
     t.compile_fail("tests/expand/missing_theorem.rs");
+    t.compile_fail("tests/expand/registered_action_method.rs");
     t.compile_fail("tests/expand/moved_referenced_type.rs");
     t.compile_fail("tests/expand/signature_drift.rs");
     t.compile_fail("tests/expand/zero_unwind.rs");
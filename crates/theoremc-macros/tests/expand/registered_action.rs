@@ -0,0 +1,30 @@
+//! Compile-pass fixture for `#[theorem_action]` registrations.
+
+// Expansions name the `theoremc` facade, which this fixture stands in for.
+extern crate theoremc_core as theoremc;
+
+use theoremc_core::actions::registered_actions;
+use theoremc_macros::theorem_action;
+
+mod ledger {
+    use theoremc_macros::theorem_action;
+
+    pub struct Account(u64);
+
+    #[theorem_action("account.deposit")]
+    pub fn deposit(account: &mut Account, amount: u64) -> Result<u64, String> {
+        account.0 = account.0.checked_add(amount).ok_or("overflow")?;
+        Ok(account.0)
+    }
+}
+
+#[theorem_action("audit.flush_all")]
+fn flush_all() {}
+
+fn main() {
+    flush_all();
+    let manifest = registered_actions().expect("registrations should load");
+    let names: Vec<&str> = manifest.actions.keys().map(String::as_str).collect();
+    assert_eq!(names, ["account.deposit", "audit.flush_all"]);
+    assert!(manifest.get("account.deposit").is_some_and(|action| action.fallible));
+}
@@ -0,0 +1,16 @@
+//! Compile-fail fixture for registering a method as a theorem action.
+
+use theoremc_macros::theorem_action;
+
+struct Account(u64);
+
+impl Account {
+    #[theorem_action("account.deposit")]
+    fn deposit(&mut self, amount: u64) {
+        self.0 += amount;
+    }
+}
+
+fn main() {
+    Account(0).deposit(1);
+}
@@ -36,6 +36,15 @@ pub(crate) enum LoweringError {
         /// Error detail from nested decoding.
         detail: String,
     },
+
+    /// An action step cannot be lowered as declared.
+    #[error("cannot lower {position}: {reason}")]
+    InvalidStep {
+        /// Human-readable step position, such as `Do step 2`.
+        position: String,
+        /// Human-readable reason.
+        reason: String,
+    },
 }
 
 /// Lowers a decoded [`ArgValue`] into a Rust expression token stream.
@@ -51,6 +60,8 @@ pub(crate) enum LoweringError {
 ///   tokens (`true`, `42`, `3.14`, `"hello"`).
 /// - **References** (`ArgValue::Reference`) are emitted as identifier
 ///   path expressions.
+/// - **Expressions** (`ArgValue::Expr`) are emitted verbatim as the
+///   parsed Rust expression.
 /// - **Sequences** (`ArgValue::RawSequence`) are lowered recursively to
 ///   `vec![...]` macro calls.
 /// - **Maps** (`ArgValue::RawMap`) are lowered to struct literals using
@@ -92,6 +103,7 @@ pub(crate) fn lower_arg_value(
     match value {
         ArgValue::Literal(lit) => lower_literal(param_name, lit),
         ArgValue::Reference(name) => lower_reference(param_name, name),
+        ArgValue::Expr(source) => lower_expr(param_name, source),
         ArgValue::RawSequence(elements) => lower_sequence(param_name, elements),
         ArgValue::RawMap(fields) => lower_map(param_name, fields, expected_type),
     }
@@ -148,6 +160,23 @@ fn lower_reference(param_name: &str, name: &str) -> Result<TokenStream, Lowering
     Ok(quote! { #ident })
 }
 
+/// Lowers an `{ expr: "..." }` argument by emitting the parsed expression
+/// tokens verbatim.
+///
+/// # Errors
+///
+/// Returns [`LoweringError::NestedDecodeError`] if the source does not
+/// parse as a Rust expression. Schema decoding already rejects such
+/// values, so this only guards hand-built [`ArgValue`]s.
+fn lower_expr(param_name: &str, source: &str) -> Result<TokenStream, LoweringError> {
+    let expr =
+        syn::parse_str::<syn::Expr>(source).map_err(|err| LoweringError::NestedDecodeError {
+            param: param_name.to_owned(),
+            detail: format!("expr '{source}' is not a valid Rust expression: {err}"),
+        })?;
+    Ok(quote! { #expr })
+}
+
 /// Lowers a sequence of [`TheoremValue`] to a `vec![...]` expression.
 ///
 /// Each element is recursively decoded and lowered. Nested sequences,
@@ -169,8 +198,8 @@ fn lower_sequence(
 /// This helper recursively decodes and lowers nested values that appear
 /// inside sequences and maps. Scalar values are lowered directly; maps
 /// are first decoded via [`decode_arg_value`] so that sentinel wrappers
-/// (`{ ref: <Ident> }`, `{ literal: "..." }`) are recognised and lowered
-/// to references or literals respectively. Only genuinely non-sentinel
+/// (`{ ref: <Ident> }`, `{ literal: "..." }`, `{ expr: "..." }`) are
+/// recognised and lowered to references, literals, or expressions. Only genuinely non-sentinel
 /// maps (decoded as `ArgValue::RawMap`) are rejected, since struct literal
 /// synthesis requires type information not available at this nesting depth.
 fn lower_theorem_value(
@@ -198,6 +227,7 @@ fn lower_theorem_value(
             match decoded {
                 ArgValue::Literal(lit) => lower_literal(param_name, &lit),
                 ArgValue::Reference(name) => lower_reference(param_name, &name),
+                ArgValue::Expr(source) => lower_expr(param_name, &source),
                 // Non-sentinel maps lack the type information needed for
                 // struct literal synthesis at this nesting depth. Phase 3
                 // compile-time type probes will enable field-type
@@ -135,6 +135,12 @@ fn test_lower_reference_non_identifier_returns_error() {
     "Graph",
     quote! { binding }
 )]
+#[case::expr(
+    ArgValue::Expr("base + offset * 2".to_owned()),
+    "amount",
+    "u64",
+    quote! { base + offset * 2 }
+)]
 fn test_lower_arg_value_scalar_cases(
     #[case] arg: ArgValue,
     #[case] param: &str,
@@ -193,6 +199,7 @@ fn test_lower_arg_value_sequence_cases(
     "Vec<String>",
     quote! { vec![("ref").into()] }
 )]
+#[case::nested_expr(("expr", "limit - 1"), "Vec<u64>", quote! { vec![limit - 1] })]
 fn test_lower_arg_value_sequence_with_nested_sentinel(
     #[case] sentinel: (&str, &str),
     sentinel_map: impl Fn(&str, &str) -> IndexMap<String, TheoremValue>,
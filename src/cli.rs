@@ -0,0 +1,307 @@
+//! The `theoremc` command line.
+//!
+//! `theoremc migrate <file>...` rewrites each `.theorem` file in place to the
+//! latest `Schema` version, `theoremc fmt <file>...` rewrites each file into
+//! canonical layout, keeping its comments and blank-line grouping, and
+//! `theoremc lint <file>...` reports the lint findings of each file at the
+//! levels set by `theoremc.toml` in the working directory.
+//! `theoremc list [--tags <expr>] <file>...` prints the theorems of each file
+//! as `<file>#<Theorem>` lines, keeping only those whose `Tags` satisfy the
+//! [`TagExpr`] given with `--tags`.
+//! `theoremc results [--baseline <runs.json>] <kani-output> <file>...` checks
+//! the Kani output saved in `<kani-output>` against the theorems of each
+//! file and prints one [`TheoremRun`](crate::results::TheoremRun) per
+//! theorem as JSON, or, given a baseline file of such runs, the theorems
+//! whose results changed since it.
+//!
+//! The `theoremc` binary runs [`run`] with the built-in lints. A tool that
+//! ships its own [`Lint`](crate::lint::Lint) rules can build a binary
+//! that runs them too:
+//!
+//! ```rust,no_run
+//! use std::process::ExitCode;
+//!
+//! use theoremc::lint::LintRegistry;
+//!
+//! fn main() -> ExitCode {
+//!     let registry = LintRegistry::default();
+//!     // registry.register(...) for each organization-specific lint.
+//!     let args: Vec<String> = std::env::args().skip(1).collect();
+//!     theoremc::cli::run(&args, &registry)
+//! }
+//! ```
+
+use std::error::Error;
+use std::io::{self, ErrorKind};
+use std::process::ExitCode;
+
+use camino::Utf8Path;
+use cap_std::{ambient_authority, fs_utf8::Dir};
+use theoremc_core::file_provider::CrateIncludes;
+use theoremc_core::format::format_source;
+use theoremc_core::lint::{LintConfig, LintLevel, LintRegistry};
+use theoremc_core::migrate::migrate_source;
+use theoremc_core::schema::{SourceId, load_spanned_theorem_docs_with_includes};
+use theoremc_core::tagexpr::TagExpr;
+use theoremc_core::{load_theorem_file, read_theorem_file};
+
+const USAGE: &str = concat!(
+    "usage: theoremc migrate <file.theorem>...\n",
+    "       theoremc fmt <file.theorem>...\n",
+    "       theoremc lint <file.theorem>...\n",
+    "       theoremc list [--tags <expr>] <file.theorem>...\n",
+    "       theoremc results [--baseline <runs.json>] <kani-output> <file.theorem>...",
+);
+
+#[path = "cli_results.rs"]
+mod results;
+
+/// A source rewrite applied by one command.
+type Rewrite = fn(&SourceId, &str) -> Result<String, Box<dyn Error>>;
+
+/// Runs the command named by the first of `args`, linting with the lints of
+/// `registry`, and returns the process exit status.
+///
+/// `args` excludes the program name. Usage errors exit with status 2.
+#[must_use]
+pub fn run(args: &[String], registry: &LintRegistry) -> ExitCode {
+    match args.split_first() {
+        Some((command, paths)) if command == "migrate" && !paths.is_empty() => rewrite_files(
+            paths,
+            |source, input| Ok(migrate_source(source, input)?),
+            ("migrated", "is already current"),
+        ),
+        Some((command, paths)) if command == "fmt" && !paths.is_empty() => rewrite_files(
+            paths,
+            |source, input| Ok(format_source(source, input)?),
+            ("formatted", "is already formatted"),
+        ),
+        Some((command, paths)) if command == "lint" && !paths.is_empty() => {
+            lint_files(paths, registry)
+        }
+        Some((command, options)) if command == "list" => match tag_filter(options) {
+            Ok((filter, paths)) if !paths.is_empty() => list_files(paths, filter.as_ref()),
+            Ok(_) => usage(),
+            Err(error) => {
+                report(&error);
+                ExitCode::from(2)
+            }
+        },
+        Some((command, options)) if command == "results" => results_command(options),
+        _ => usage(),
+    }
+}
+
+/// Runs `results` with `options`, which may start with `--baseline`.
+fn results_command(options: &[String]) -> ExitCode {
+    match option(options, "--baseline", "a runs file") {
+        Ok((baseline, [kani_output, paths @ ..])) if !paths.is_empty() => {
+            results::compare_results(baseline, kani_output, paths)
+        }
+        Ok(_) => usage(),
+        Err(error) => {
+            report(&error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    report(USAGE);
+    ExitCode::from(2)
+}
+
+/// Splits a leading `--tags <expr>` or `--tags=<expr>` option from `args`,
+/// returning the parsed filter, if any, and the remaining arguments.
+fn tag_filter(args: &[String]) -> Result<(Option<TagExpr>, &[String]), String> {
+    let (source, rest) = option(args, "--tags", "a tag expression")?;
+    let filter = source
+        .map(|expr| TagExpr::parse(expr).map_err(|error| format!("--tags: {error}")))
+        .transpose()?;
+    Ok((filter, rest))
+}
+
+/// Splits a leading `<flag> <value>` or `<flag>=<value>` option from `args`,
+/// returning its value, if given, and the remaining arguments. `needs`
+/// describes the value when it is missing.
+fn option<'a>(
+    args: &'a [String],
+    flag: &str,
+    needs: &str,
+) -> Result<(Option<&'a str>, &'a [String]), String> {
+    match args.split_first() {
+        Some((first, rest)) if first == flag => match rest.split_first() {
+            Some((value, remaining)) => Ok((Some(value.as_str()), remaining)),
+            None => Err(format!("{flag} needs {needs}")),
+        },
+        Some((first, rest)) => Ok(first
+            .strip_prefix(flag)
+            .and_then(|joined| joined.strip_prefix('='))
+            .map_or((None, args), |value| (Some(value), rest))),
+        None => Ok((None, args)),
+    }
+}
+
+/// Prints the theorems of every file that `filter` selects, failing when
+/// any file could not be loaded.
+fn list_files(paths: &[String], filter: Option<&TagExpr>) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for path in paths {
+        match load_theorem_file(path) {
+            Ok(docs) => docs
+                .iter()
+                .filter(|doc| filter.is_none_or(|expr| expr.matches_theorem(doc)))
+                .for_each(|doc| output(&format!("{path}#{}", doc.theorem))),
+            Err(error) => {
+                report(&error.to_string());
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Rewrites every file, reporting each outcome with the `(changed,
+/// unchanged)` wording, and fails when any file could not be rewritten.
+fn rewrite_files(paths: &[String], rewrite: Rewrite, wording: (&str, &str)) -> ExitCode {
+    let (changed, unchanged) = wording;
+    let mut status = ExitCode::SUCCESS;
+    for path in paths {
+        match rewrite_file(path, rewrite) {
+            Ok(true) => report(&format!("{changed} {path}")),
+            Ok(false) => report(&format!("{path} {unchanged}")),
+            Err(error) => {
+                report(&error.to_string());
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Rewrites `path` in place, returning whether its contents changed.
+///
+/// The rewritten source replaces the file only once it is fully written, so
+/// a failed write leaves the original in place.
+fn rewrite_file(path: &str, rewrite: Rewrite) -> Result<bool, Box<dyn Error>> {
+    let (source, input) = read_theorem_file(path)?;
+    let rewritten = rewrite(&SourceId::new(source.as_str()), &input)
+        .map_err(|error| format!("{path}: {error}"))?;
+    if rewritten == input {
+        return Ok(false);
+    }
+    write_atomically(path, &rewritten).map_err(|error| format!("{path}: {error}"))?;
+    Ok(true)
+}
+
+/// The lint configuration file read from the working directory.
+const LINT_CONFIG: &str = "theoremc.toml";
+
+/// Reports the lint findings of every file, failing when the configuration
+/// or any file could not be loaded, or when a denied lint fires. Warnings
+/// alone do not fail the command.
+fn lint_files(paths: &[String], registry: &LintRegistry) -> ExitCode {
+    let config = match load_lint_config(registry) {
+        Ok(config) => config,
+        Err(error) => {
+            report(&error.to_string());
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    for path in paths {
+        match lint_file(path, registry, &config) {
+            Ok(false) => {}
+            Ok(true) => status = ExitCode::FAILURE,
+            Err(error) => {
+                report(&error.to_string());
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+/// Reports the lint findings of `path`, returning whether any was denied.
+fn lint_file(
+    path: &str,
+    registry: &LintRegistry,
+    config: &LintConfig,
+) -> Result<bool, Box<dyn Error>> {
+    let (source, input) = read_theorem_file(path)?;
+    let docs = load_spanned_theorem_docs_with_includes(
+        &SourceId::new(source.as_str()),
+        &input,
+        &CrateIncludes::for_file(path),
+    )
+    .map_err(|error| format!("{path}: {error}"))?;
+    let mut denied = false;
+    for doc in &docs {
+        let lints = registry
+            .lint_theorem(doc, config)
+            .map_err(|error| format!("{path}: {error}"))?;
+        for lint in &lints {
+            report(&lint.render());
+        }
+        denied |= lints.iter().any(|lint| lint.level == LintLevel::Deny);
+    }
+    Ok(denied)
+}
+
+/// Reads `theoremc.toml` when the working directory has one, accepting the
+/// names of the lints in `registry`, and otherwise uses the default levels.
+fn load_lint_config(registry: &LintRegistry) -> Result<LintConfig, Box<dyn Error>> {
+    match read_text(LINT_CONFIG) {
+        Ok(input) => Ok(registry.config_from_toml(&SourceId::new(LINT_CONFIG), &input)?),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(LintConfig::default()),
+        Err(error) => Err(format!("{LINT_CONFIG}: {error}").into()),
+    }
+}
+
+/// Reads the UTF-8 file at `path`, resolved against the working directory
+/// unless absolute.
+fn read_text(path: &str) -> io::Result<String> {
+    let (dir, file_name) = open_parent(path)?;
+    dir.read_to_string(file_name)
+}
+
+/// Replaces the contents of `path` by writing a temporary sibling file and
+/// renaming it over `path`.
+fn write_atomically(path: &str, contents: &str) -> io::Result<()> {
+    let (dir, file_name) = open_parent(path)?;
+    let temp_name = format!(".{file_name}.tmp");
+    dir.write(&temp_name, contents)
+        .and_then(|()| dir.rename(&temp_name, &dir, file_name))
+}
+
+/// Opens the directory holding `path`, returning it with the file name.
+fn open_parent(path: &str) -> io::Result<(Dir, &str)> {
+    let file_path = Utf8Path::new(path);
+    let file_name = file_path
+        .file_name()
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "path does not name a file"))?;
+    let parent = file_path
+        .parent()
+        .filter(|parent| !parent.as_str().is_empty())
+        .unwrap_or_else(|| Utf8Path::new("."));
+    Ok((
+        Dir::open_ambient_dir(parent, ambient_authority())?,
+        file_name,
+    ))
+}
+
+#[expect(
+    clippy::print_stderr,
+    reason = "CLI diagnostics are the intended output"
+)]
+fn report(message: &str) {
+    eprintln!("{message}");
+}
+
+#[expect(
+    clippy::print_stdout,
+    reason = "listed theorems are the intended output"
+)]
+fn output(line: &str) {
+    println!("{line}");
+}
@@ -0,0 +1,85 @@
+//! The `theoremc results` command.
+
+use std::error::Error;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use theoremc_core::load_theorem_file;
+use theoremc_core::results::kani::parse_kani_output;
+use theoremc_core::results::{ResultChange, TheoremChange, TheoremRun, diff};
+
+use super::{output, read_text, report};
+
+/// How far a passing theorem's verification time may move before it is
+/// reported as changed.
+const DURATION_THRESHOLD: Duration = Duration::from_secs(30);
+
+/// Checks the Kani output saved at `kani_output` against the theorems of
+/// `paths`, printing the runs as JSON, or the changes since the runs saved
+/// at `baseline` when one is given. Fails when the inputs cannot be read or
+/// when a theorem newly fails.
+pub(super) fn compare_results(
+    baseline: Option<&str>,
+    kani_output: &str,
+    paths: &[String],
+) -> ExitCode {
+    match run_results(baseline, kani_output, paths) {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::FAILURE,
+        Err(error) => {
+            report(&error.to_string());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the runs or the changes since `baseline`, returning whether any
+/// theorem newly fails.
+fn run_results(
+    baseline: Option<&str>,
+    kani_output: &str,
+    paths: &[String],
+) -> Result<bool, Box<dyn Error>> {
+    let current = current_runs(kani_output, paths)?;
+    let Some(baseline_path) = baseline else {
+        output(&serde_json::to_string_pretty(&current)?);
+        return Ok(false);
+    };
+    let previous: Vec<TheoremRun> = read_text(baseline_path)
+        .map_err(|error| format!("{baseline_path}: {error}"))
+        .and_then(|json| {
+            serde_json::from_str(&json).map_err(|error| format!("{baseline_path}: {error}"))
+        })?;
+    let changes = diff(&previous, &current, DURATION_THRESHOLD);
+    for change in &changes {
+        output(&describe(change));
+    }
+    Ok(changes
+        .iter()
+        .any(|change| change.change == ResultChange::NewlyFailing))
+}
+
+/// Condenses the Kani output at `kani_output` into one run per theorem of
+/// `paths` with Kani evidence, in argument and document order.
+fn current_runs(kani_output: &str, paths: &[String]) -> Result<Vec<TheoremRun>, Box<dyn Error>> {
+    let text = read_text(kani_output).map_err(|error| format!("{kani_output}: {error}"))?;
+    let results = parse_kani_output(&text).map_err(|error| format!("{kani_output}: {error}"))?;
+    paths.iter().try_fold(Vec::new(), |mut runs, path| {
+        let docs = load_theorem_file(path)?;
+        runs.extend(TheoremRun::from_kani_results(path, &docs, &results));
+        Ok(runs)
+    })
+}
+
+/// Describes one change as `<theorem>: <change>`.
+fn describe(change: &TheoremChange) -> String {
+    let what = match change.change {
+        ResultChange::NewlyFailing => "newly failing".to_owned(),
+        ResultChange::NewlyPassing => "newly passing".to_owned(),
+        ResultChange::StillFailing => "still failing".to_owned(),
+        ResultChange::DurationChanged { previous, current } => {
+            format!("verification took {current:?}, was {previous:?}")
+        }
+    };
+    format!("{}: {what}", change.theorem)
+}
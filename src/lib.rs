@@ -5,18 +5,87 @@
 //! build integration. Core theorem semantics live in `theoremc-core`, while
 //! `theoremc-macros` owns proc-macro expansion.
 
+/// The `theoremc` command line, reusable with custom lints.
+pub mod cli;
+
+/// Action manifests declaring the Rust signatures theorems may call.
+pub use theoremc_core::actions;
+
+/// Fluent construction of validated theorem documents.
+pub use theoremc_core::builder;
+
+/// Content-addressed caching of validated theorem documents.
+pub use theoremc_core::cache;
+
 /// Mangled-identifier collision detection across loaded theorem documents.
 pub use theoremc_core::collision;
 
+/// Theorem dependency graphs built from `DependsOn` declarations.
+pub use theoremc_core::dependency;
+
+/// Semantic comparison of theorem document versions.
+pub use theoremc_core::diff;
+
+/// Discovery and bulk loading of theorem files below a directory.
+pub use theoremc_core::discover;
+
+/// Minimal textual edits of theorem sources.
+pub use theoremc_core::edit;
+
+/// Layout-preserving formatting of theorem sources.
+pub use theoremc_core::format;
+
+/// Pluggable file access for theorem discovery and loading.
+pub use theoremc_core::file_provider;
+
+/// Append-only history of verification runs.
+pub use theoremc_core::ledger;
+
+/// Lints for theorem documents that load but are likely mistakes.
+pub use theoremc_core::lint;
+
 /// Action name mangling for deterministic, injective resolution.
 pub use theoremc_core::mangle;
 
+/// Migration of theorem documents and sources to the latest schema version.
+pub use theoremc_core::migrate;
+
+/// Reporting views such as requirement traceability matrices.
+pub use theoremc_core::report;
+
+/// Verification results parsed from proof backend output.
+pub use theoremc_core::results;
+
 /// Schema types for `.theorem` document deserialization and validation.
 pub use theoremc_core::schema;
 
+/// Boolean tag expressions that select theorems by their `Tags`.
+pub use theoremc_core::tagexpr;
+
+/// Loads the theorem documents of one `.theorem` file by path.
+pub use theoremc_core::load_theorem_file;
+
+/// Reads the source text of one `.theorem` file by path, without parsing it.
+pub use theoremc_core::read_theorem_file;
+
+/// Loads the theorem documents of one root-relative file through a
+/// `FileProvider`.
+pub use theoremc_core::load_theorem_file_from_provider;
+
+/// Loads one `.theorem` file by path on Tokio's blocking thread pool.
+#[cfg(feature = "tokio")]
+pub use theoremc_core::load_theorem_file_async;
+
 /// The public proc macro that expands one crate-relative `.theorem` file.
 pub use theoremc_macros::theorem_file;
 
+/// The attribute that registers a function as a theorem action.
+pub use theoremc_macros::theorem_action;
+
+/// Re-exports used by `theoremc-macros` expansions; not a stable API.
+#[doc(hidden)]
+pub use theoremc_core::__private;
+
 /// Argument-expression lowering prototype for proof harness code generation.
 #[cfg(test)]
 #[doc(hidden)]
@@ -32,6 +101,11 @@ pub(crate) mod build_discovery;
 #[doc(hidden)]
 pub(crate) mod build_suite;
 
+/// `must` step lowering prototype for proof harness code generation.
+#[cfg(test)]
+#[doc(hidden)]
+pub(crate) mod must_lowering;
+
 #[doc(hidden)]
 mod __theoremc_generated_suite {
     //! Generated theorem suite integration.
@@ -1,8 +1,14 @@
-//! `Theorem Compiler` application entry point.
+//! `Theorem Compiler` command-line entry point.
+//!
+//! The commands are implemented by [`theoremc::cli`], which this binary runs
+//! with the built-in lints.
+
+use std::process::ExitCode;
+
+use theoremc::lint::LintRegistry;
 
-// TODO: Remove this stub and implement actual application functionality.
 /// Application entry point.
-#[expect(clippy::print_stdout, reason = "CLI output is the intended behaviour")]
-fn main() {
-    println!("Hello from Theorem Compiler!");
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    theoremc::cli::run(&args, &LintRegistry::default())
 }
@@ -0,0 +1,155 @@
+//! `must` step lowering for theorem action calls.
+//!
+//! A `must` call proves that the action cannot fail under the theorem's
+//! assumptions. The call result is matched: the failure branch is asserted
+//! unreachable with a message naming the step's position, and the success
+//! value is bound under the `Let` name or the step's `as` name. Actions that
+//! return neither `Result` nor `Option` cannot fail, so their `must` calls
+//! lower to plain calls.
+//!
+//! Like [`arg_lowering`](crate::arg_lowering), this module operates outside
+//! the schema boundary (`ADR-3`) and consumes already validated theorem
+//! documents.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Type, TypePath};
+
+use crate::arg_lowering::{LoweringError, lower_arg_value};
+use crate::mangle::mangle_action_name;
+use crate::schema::{ActionCall, ActionSignature};
+
+/// How a `must` call can fail, by the action's declared return type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fallibility {
+    /// `Result<T, E>`: fails with `Err`.
+    Result,
+    /// `Option<T>`: fails with `None`.
+    Option,
+    /// Any other type: cannot fail.
+    Infallible,
+}
+
+/// One `must` call to lower.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MustStep<'a> {
+    /// Human-readable position used in the failure message, such as
+    /// `Let binding 'balance'` or `Do step 2`.
+    pub(crate) position: &'a str,
+    /// The validated action call.
+    pub(crate) call: &'a ActionCall,
+    /// The signature the theorem declares for the called action.
+    pub(crate) signature: &'a ActionSignature,
+    /// The name the success value is bound to: the `Let` key, or the step's
+    /// `as` name in `Do`.
+    pub(crate) binding: Option<&'a str>,
+}
+
+/// Lowers a `must` call into Rust statements.
+///
+/// With a binding, the output is a `let` statement; without one, it is an
+/// expression statement whose success value is discarded.
+///
+/// # Errors
+///
+/// Returns [`LoweringError::InvalidStep`] when the call omits a declared
+/// parameter, a declared type does not parse, or the binding is not a Rust
+/// identifier, and propagates argument lowering errors.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // must: { action: account.deposit, args: { amount: 10 }, as: balance }
+/// // lowers to:
+/// let balance = match crate::theorem_actions::account__deposit__h05158894bfb4(10) {
+///     ::core::result::Result::Ok(value) => value,
+///     ::core::result::Result::Err(_) => ::core::unreachable!(
+///         "Do step 1: must action 'account.deposit' returned an error"
+///     ),
+/// };
+/// ```
+pub(crate) fn lower_must(step: &MustStep<'_>) -> Result<TokenStream, LoweringError> {
+    let invocation = lower_invocation(step)?;
+    let action = &step.call.action;
+    let position = step.position;
+    let checked = match fallibility(step)? {
+        Fallibility::Result => {
+            let message = format!("{position}: must action '{action}' returned an error");
+            quote! {
+                match #invocation {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => ::core::unreachable!(#message),
+                }
+            }
+        }
+        Fallibility::Option => {
+            let message = format!("{position}: must action '{action}' returned None");
+            quote! {
+                match #invocation {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => ::core::unreachable!(#message),
+                }
+            }
+        }
+        Fallibility::Infallible => invocation,
+    };
+    match step.binding {
+        Some(name) => {
+            let ident = syn::parse_str::<syn::Ident>(name).map_err(|_| {
+                invalid_step(
+                    step,
+                    format!("binding '{name}' is not a valid Rust identifier"),
+                )
+            })?;
+            Ok(quote! { let #ident = #checked; })
+        }
+        None => Ok(quote! { #checked; }),
+    }
+}
+
+/// Lowers the call to the action's mangled `crate::theorem_actions` function,
+/// passing arguments in declared parameter order.
+fn lower_invocation(step: &MustStep<'_>) -> Result<TokenStream, LoweringError> {
+    let path: syn::Path = syn::parse_str(mangle_action_name(&step.call.action).path())
+        .map_err(|error| invalid_step(step, format!("action path does not parse: {error}")))?;
+    let args = step
+        .signature
+        .params
+        .iter()
+        .map(|(param, ty)| {
+            let value = step.call.args.get(param).ok_or_else(|| {
+                invalid_step(step, format!("missing argument for parameter '{param}'"))
+            })?;
+            lower_arg_value(param, value, &parse_type(step, ty)?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! { #path(#(#args),*) })
+}
+
+fn fallibility(step: &MustStep<'_>) -> Result<Fallibility, LoweringError> {
+    let Type::Path(TypePath { qself: None, path }) = parse_type(step, &step.signature.returns)?
+    else {
+        return Ok(Fallibility::Infallible);
+    };
+    Ok(match path.segments.last() {
+        Some(last) if last.ident == "Result" => Fallibility::Result,
+        Some(last) if last.ident == "Option" => Fallibility::Option,
+        _ => Fallibility::Infallible,
+    })
+}
+
+fn parse_type(step: &MustStep<'_>, ty: &str) -> Result<Type, LoweringError> {
+    syn::parse_str(ty)
+        .map_err(|error| invalid_step(step, format!("type '{ty}' does not parse: {error}")))
+}
+
+fn invalid_step(step: &MustStep<'_>, reason: String) -> LoweringError {
+    LoweringError::InvalidStep {
+        position: step.position.to_owned(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+#[path = "must_lowering_tests.rs"]
+mod tests;
@@ -0,0 +1,143 @@
+//! Unit tests for `must` step lowering.
+
+use indexmap::IndexMap;
+use quote::quote;
+use rstest::rstest;
+
+use super::{MustStep, lower_must};
+use crate::arg_lowering::LoweringError;
+use crate::schema::arg_value::{ArgValue, LiteralValue};
+use crate::schema::{ActionCall, ActionSignature};
+
+fn deposit_call() -> ActionCall {
+    ActionCall {
+        action: "account.deposit".into(),
+        args: IndexMap::from([
+            (
+                "amount".to_owned(),
+                ArgValue::Literal(LiteralValue::Integer(10)),
+            ),
+            (
+                "account".to_owned(),
+                ArgValue::Reference("ledger".to_owned()),
+            ),
+        ]),
+        as_binding: None,
+    }
+}
+
+fn signature(returns: &str) -> ActionSignature {
+    ActionSignature {
+        params: IndexMap::from([
+            ("account".to_owned(), "Account".into()),
+            ("amount".to_owned(), "u64".into()),
+        ]),
+        returns: returns.into(),
+    }
+}
+
+fn lower(returns: &str, binding: Option<&str>) -> Result<String, LoweringError> {
+    let call = deposit_call();
+    let declared = signature(returns);
+    let tokens = lower_must(&MustStep {
+        position: "Do step 2",
+        call: &call,
+        signature: &declared,
+        binding,
+    })?;
+    Ok(tokens.to_string())
+}
+
+#[test]
+fn result_success_value_is_bound_and_error_is_unreachable() {
+    let lowered = lower("Result<u64, LedgerError>", Some("balance")).expect("must lowers");
+
+    let expected = quote! {
+        let balance = match crate::theorem_actions::account__deposit__h05158894bfb4(ledger, 10) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => ::core::unreachable!(
+                "Do step 2: must action 'account.deposit' returned an error"
+            ),
+        };
+    };
+    assert_eq!(lowered, expected.to_string());
+}
+
+#[test]
+fn option_none_is_unreachable_without_a_binding() {
+    let lowered = lower("Option<u64>", None).expect("must lowers");
+
+    let expected = quote! {
+        match crate::theorem_actions::account__deposit__h05158894bfb4(ledger, 10) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::unreachable!(
+                "Do step 2: must action 'account.deposit' returned None"
+            ),
+        };
+    };
+    assert_eq!(lowered, expected.to_string());
+}
+
+#[rstest]
+#[case::bound_value(Some("balance"), quote! {
+    let balance = crate::theorem_actions::account__deposit__h05158894bfb4(ledger, 10);
+})]
+#[case::unit(None, quote! {
+    crate::theorem_actions::account__deposit__h05158894bfb4(ledger, 10);
+})]
+fn infallible_actions_lower_to_plain_calls(
+    #[case] binding: Option<&str>,
+    #[case] expected: proc_macro2::TokenStream,
+) {
+    let lowered = lower("u64", binding).expect("must lowers");
+
+    assert_eq!(lowered, expected.to_string());
+}
+
+#[rstest]
+#[case::qualified_result("std::io::Result<u64>", "returned an error")]
+#[case::qualified_option("core::option::Option<u64>", "returned None")]
+fn qualified_return_types_keep_their_failure_branch(#[case] returns: &str, #[case] message: &str) {
+    let lowered = lower(returns, Some("balance")).expect("must lowers");
+
+    assert!(lowered.contains(message), "{lowered}");
+}
+
+#[test]
+fn lowered_statement_parses_as_rust() {
+    let lowered = lower("Result<u64, LedgerError>", Some("balance")).expect("must lowers");
+
+    assert!(syn::parse_str::<syn::Stmt>(&lowered).is_ok(), "{lowered}");
+}
+
+#[rstest]
+#[case::missing_argument(
+    ActionSignature {
+        params: IndexMap::from([("fee".to_owned(), "u64".into())]),
+        returns: "()".into(),
+    },
+    Some("balance"),
+    "cannot lower Do step 2: missing argument for parameter 'fee'"
+)]
+#[case::invalid_binding(
+    signature("u64"),
+    Some("2balance"),
+    "cannot lower Do step 2: binding '2balance' is not a valid Rust identifier"
+)]
+fn invalid_steps_are_rejected(
+    #[case] declared: ActionSignature,
+    #[case] binding: Option<&str>,
+    #[case] expected: &str,
+) {
+    let call = deposit_call();
+
+    let error = lower_must(&MustStep {
+        position: "Do step 2",
+        call: &call,
+        signature: &declared,
+        binding,
+    })
+    .expect_err("step should be rejected");
+
+    assert_eq!(error.to_string(), expected);
+}
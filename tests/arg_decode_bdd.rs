@@ -235,7 +235,11 @@ fn first_do_arg<'a>(
     let ac = match step {
         theoremc::schema::Step::Call(c) => &c.call,
         theoremc::schema::Step::Must(m) => &m.must,
+        theoremc::schema::Step::ExpectErr(e) => &e.expect_err,
         theoremc::schema::Step::Maybe(_) => return Err("unexpected maybe step".into()),
+        theoremc::schema::Step::Repeat(_) => return Err("unexpected repeat step".into()),
+        theoremc::schema::Step::Foreach(_) => return Err("unexpected foreach step".into()),
+        theoremc::schema::Step::Assert(_) => return Err("unexpected assert step".into()),
     };
     ac.args
         .get(arg_name)
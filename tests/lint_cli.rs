@@ -0,0 +1,174 @@
+//! Integration tests for the `theoremc lint` command.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, ExitCode, Output};
+
+use rstest::rstest;
+use theoremc::lint::{Lint, LintCode, LintContext, LintDiagnostic, LintLevel, LintRegistry};
+use theoremc::schema::TheoremDoc;
+
+const UNUSED_FORALL: &str = concat!(
+    "Theorem: Unused\n",
+    "About: Declares a variable it never reads\n",
+    "Forall:\n",
+    "  x: u8\n",
+    "Prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "Evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn theoremc(args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_theoremc"))
+        .args(args)
+        .output()
+}
+
+fn theoremc_in(dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_theoremc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+}
+
+#[rstest]
+fn lint_reports_findings_without_failing() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("unused.theorem");
+    fs::write(&path, UNUSED_FORALL).expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let output = theoremc(&["lint", path_arg]).expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(&format!("unused_forall | {path_arg}:4:6 | ")),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn lint_reads_includes_against_the_manifest_dir() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let theorems = dir.path().join("theorems");
+    fs::create_dir_all(theorems.join("shared")).expect("fixture directory should be created");
+    fs::write(dir.path().join("Cargo.toml"), "").expect("manifest should be written");
+    fs::write(theorems.join("shared/vars.yaml"), "Forall:\n  x: u8\n")
+        .expect("fragment should be written");
+    let path = theorems.join("merged.theorem");
+    fs::write(
+        &path,
+        UNUSED_FORALL.replace(
+            "Forall:\n  x: u8\n",
+            "Include:\n  - theorems/shared/vars.yaml\n",
+        ),
+    )
+    .expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let output = theoremc(&["lint", path_arg]).expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(&format!("constant_assertion | {path_arg}:6:13 | ")),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn lint_fails_for_files_that_do_not_load() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("broken.theorem");
+    fs::write(&path, "Theorem: [").expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let output = theoremc(&["lint", path_arg]).expect("theoremc should run");
+
+    assert!(!output.status.success(), "{output:?}");
+}
+
+#[rstest]
+fn denied_lints_from_theoremc_toml_fail_the_command() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    fs::write(dir.path().join("unused.theorem"), UNUSED_FORALL).expect("fixture should be written");
+    fs::write(
+        dir.path().join("theoremc.toml"),
+        "[lints]\nunused_forall = \"deny\"\n",
+    )
+    .expect("configuration should be written");
+
+    let output = theoremc_in(dir.path(), &["lint", "unused.theorem"]).expect("theoremc should run");
+
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("deny | unused_forall | unused.theorem:4:6 | "),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn invalid_theoremc_toml_fails_the_command() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    fs::write(dir.path().join("unused.theorem"), UNUSED_FORALL).expect("fixture should be written");
+    fs::write(
+        dir.path().join("theoremc.toml"),
+        "[lints]\nunused = \"deny\"\n",
+    )
+    .expect("configuration should be written");
+
+    let output = theoremc_in(dir.path(), &["lint", "unused.theorem"]).expect("theoremc should run");
+
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("theoremc.toml:2:1: unknown lint 'unused'"),
+        "{stderr}"
+    );
+}
+
+/// Reports every theorem without tags.
+struct Untagged;
+
+impl Lint for Untagged {
+    fn name(&self) -> &'static str {
+        "untagged"
+    }
+
+    fn check(&self, doc: &TheoremDoc, ctx: &LintContext<'_>) -> Vec<LintDiagnostic> {
+        if !doc.tags.is_empty() {
+            return Vec::new();
+        }
+        vec![LintDiagnostic {
+            code: LintCode::Custom(self.name()),
+            level: LintLevel::Warn,
+            theorem: doc.theorem.as_str().to_owned(),
+            location: ctx.location(&["Theorem"]),
+            message: "theorem has no tags".to_owned(),
+            suggestion: None,
+        }]
+    }
+}
+
+#[rstest]
+#[case::allowed("allow", ExitCode::SUCCESS)]
+#[case::denied("deny", ExitCode::FAILURE)]
+fn custom_lints_run_at_their_configured_level(#[case] level: &str, #[case] expected: ExitCode) {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("untagged.theorem");
+    let input = format!("{UNUSED_FORALL}Lints:\n  unused_forall: allow\n  untagged: {level}\n");
+    fs::write(&path, input).expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+    let mut registry = LintRegistry::default();
+    registry
+        .register(Untagged)
+        .expect("the name should be free");
+
+    let status = theoremc::cli::run(&["lint".to_owned(), path_arg.to_owned()], &registry);
+
+    assert_eq!(status, expected);
+}
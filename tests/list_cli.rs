@@ -0,0 +1,93 @@
+//! Integration tests for the `theoremc list` command.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use rstest::rstest;
+use test_helpers::theorem;
+
+fn tagged(name: &str, tags: &str) -> String {
+    theorem(name, &format!("Tags: {tags}\n"))
+}
+
+fn theoremc_in(dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_theoremc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+}
+
+fn write_payments(dir: &Path) -> std::io::Result<()> {
+    let yaml = [
+        tagged("KaniSmoke", "[kani, payments, smoke]"),
+        tagged("KaniSlow", "[kani, payments, smoke, slow]"),
+        tagged("VerusPayment", "[verus, payments]"),
+    ]
+    .join("---\n");
+    fs::write(dir.join("payments.theorem"), yaml)
+}
+
+#[rstest]
+#[case::unfiltered(
+    &["list", "payments.theorem"],
+    "payments.theorem#KaniSmoke\npayments.theorem#KaniSlow\npayments.theorem#VerusPayment\n"
+)]
+#[case::separate_value(
+    &["list", "--tags", "smoke and not slow", "payments.theorem"],
+    "payments.theorem#KaniSmoke\n"
+)]
+#[case::joined_value(
+    &["list", "--tags=(kani or verus) and payments and not smoke", "payments.theorem"],
+    "payments.theorem#VerusPayment\n"
+)]
+fn list_prints_the_selected_theorems(#[case] args: &[&str], #[case] expected: &str) {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    write_payments(dir.path()).expect("fixture should be written");
+
+    let output = theoremc_in(dir.path(), args).expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), expected);
+}
+
+#[rstest]
+fn list_resolves_includes_against_the_manifest_dir() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let theorems = dir.path().join("theorems");
+    fs::create_dir_all(theorems.join("shared")).expect("fixture directory should be created");
+    fs::write(dir.path().join("Cargo.toml"), "").expect("manifest should be written");
+    fs::write(
+        theorems.join("shared/accounts.yaml"),
+        "Forall:\n  amount: u64\n",
+    )
+    .expect("fragment should be written");
+    fs::write(
+        theorems.join("ledger.theorem"),
+        theorem("Bounded", "Include: [theorems/shared/accounts.yaml]\n"),
+    )
+    .expect("fixture should be written");
+
+    let output = theoremc_in(&theorems, &["list", "ledger.theorem"]).expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "ledger.theorem#Bounded\n"
+    );
+}
+
+#[rstest]
+#[case::malformed_expression(&["list", "--tags", "smoke and", "payments.theorem"], 2)]
+#[case::missing_expression(&["list", "--tags"], 2)]
+#[case::missing_files(&["list", "--tags", "smoke"], 2)]
+#[case::unloadable_file(&["list", "missing.theorem"], 1)]
+fn list_rejects_invalid_invocations(#[case] args: &[&str], #[case] code: i32) {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    write_payments(dir.path()).expect("fixture should be written");
+
+    let output = theoremc_in(dir.path(), args).expect("theoremc should run");
+
+    assert_eq!(output.status.code(), Some(code), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+}
@@ -0,0 +1,118 @@
+//! Integration tests for the `theoremc migrate` and `theoremc fmt` commands.
+
+use std::fs;
+use std::process::{Command, Output};
+
+use rstest::rstest;
+
+const V1_THEOREM: &str = concat!(
+    "theorem: Trivial\n",
+    "about: Always holds\n",
+    "prove:\n",
+    "  - assert: \"true\"\n",
+    "    because: trivially true\n",
+    "evidence:\n",
+    "  kani: { unwind: 1, expect: SUCCESS, allow_vacuous: true, vacuity_because: none }\n",
+);
+
+fn theoremc(args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_theoremc"))
+        .args(args)
+        .output()
+}
+
+#[rstest]
+fn migrate_rewrites_files_in_place() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("trivial.theorem");
+    fs::write(&path, V1_THEOREM).expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let first = theoremc(&["migrate", path_arg]).expect("theoremc should run");
+    let second = theoremc(&["migrate", path_arg]).expect("theoremc should run");
+
+    assert!(first.status.success(), "{first:?}");
+    let migrated = fs::read_to_string(&path).expect("migrated file should be readable");
+    assert!(
+        migrated.starts_with("Schema: 2\nTheorem: Trivial\nAbout: Always holds\nProve:\n"),
+        "{migrated}"
+    );
+    assert!(String::from_utf8_lossy(&second.stderr).contains("is already current"));
+    let entries: Vec<_> = fs::read_dir(dir.path())
+        .expect("temporary directory should be listed")
+        .map(|entry| entry.expect("entry should be readable").file_name())
+        .collect();
+    assert_eq!(
+        entries,
+        ["trivial.theorem"],
+        "rewrites leave no temporary file"
+    );
+}
+
+#[rstest]
+fn fmt_rewrites_aliases_without_migrating() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("trivial.theorem");
+    fs::write(&path, V1_THEOREM).expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let first = theoremc(&["fmt", path_arg]).expect("theoremc should run");
+    let second = theoremc(&["fmt", path_arg]).expect("theoremc should run");
+
+    assert!(first.status.success(), "{first:?}");
+    let formatted = fs::read_to_string(&path).expect("formatted file should be readable");
+    assert!(
+        formatted.starts_with("Theorem: Trivial\nAbout: Always holds\nProve:\n"),
+        "{formatted}"
+    );
+    assert!(String::from_utf8_lossy(&second.stderr).contains("is already formatted"));
+}
+
+#[rstest]
+fn fmt_keeps_comments_while_reordering_sections() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    let path = dir.path().join("trivial.theorem");
+    let input = V1_THEOREM
+        .replace("about: Always holds\n", "")
+        .replace("evidence:\n", "# Why it is checked\nevidence:\n")
+        + "about: Always holds\n";
+    fs::write(&path, input).expect("fixture should be written");
+    let path_arg = path.to_str().expect("temporary path should be UTF-8");
+
+    let output = theoremc(&["fmt", path_arg]).expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    let formatted = fs::read_to_string(&path).expect("formatted file should be readable");
+    assert!(
+        formatted.starts_with("Theorem: Trivial\nAbout: Always holds\nProve:\n"),
+        "{formatted}"
+    );
+    assert!(
+        formatted.contains("# Why it is checked\nEvidence:\n"),
+        "{formatted}"
+    );
+}
+
+#[rstest]
+#[case::no_command(&[])]
+#[case::no_files(&["migrate"])]
+#[case::no_files_to_format(&["fmt"])]
+#[case::unknown_command(&["format", "a.theorem"])]
+fn invalid_usage_is_rejected(#[case] args: &[&str]) {
+    let output = theoremc(args).expect("theoremc should run");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("usage: theoremc migrate"));
+}
+
+#[rstest]
+fn unreadable_files_fail_the_command() {
+    let output =
+        theoremc(&["migrate", "missing/does-not-exist.theorem"]).expect("theoremc should run");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "failed to read theorem file 'missing/does-not-exist.theorem': io:NotFound\n"
+    );
+}
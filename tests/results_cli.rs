@@ -0,0 +1,103 @@
+//! Integration tests for the `theoremc results` command.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use rstest::rstest;
+use test_helpers::theorem;
+use theoremc::mangle::mangle_theorem_harness;
+use theoremc::results::TheoremRun;
+
+fn checked(name: &str, verdict: &str) -> String {
+    let harness = mangle_theorem_harness("ledger.theorem", name);
+    format!(
+        "Checking harness theorem_ledger::{}...\nVERIFICATION:- {verdict}\nVerification Time: 1.5s\n",
+        harness.identifier()
+    )
+}
+
+fn run(theorem: &str, passed: bool) -> TheoremRun {
+    TheoremRun {
+        theorem: format!("ledger.theorem#{theorem}"),
+        passed,
+        duration_ms: Some(1_500),
+    }
+}
+
+fn theoremc_in(dir: &Path, args: &[&str]) -> std::io::Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_theoremc"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+}
+
+fn write_ledger(dir: &Path) -> std::io::Result<()> {
+    fs::write(
+        dir.join("ledger.theorem"),
+        [theorem("Deposit", ""), theorem("Withdraw", "")].join("---\n"),
+    )?;
+    fs::write(
+        dir.join("kani.txt"),
+        checked("Deposit", "SUCCESSFUL") + &checked("Withdraw", "FAILED"),
+    )
+}
+
+#[rstest]
+fn results_prints_the_runs_as_json() {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    write_ledger(dir.path()).expect("fixtures should be written");
+
+    let output = theoremc_in(dir.path(), &["results", "kani.txt", "ledger.theorem"])
+        .expect("theoremc should run");
+
+    assert!(output.status.success(), "{output:?}");
+    let runs: Vec<TheoremRun> =
+        serde_json::from_slice(&output.stdout).expect("stdout should hold the runs");
+    assert_eq!(runs, vec![run("Deposit", true), run("Withdraw", false)]);
+}
+
+#[rstest]
+#[case::regression(true, "ledger.theorem#Withdraw: newly failing\n", false)]
+#[case::known_failure(false, "ledger.theorem#Withdraw: still failing\n", true)]
+fn results_reports_changes_since_the_baseline(
+    #[case] withdraw_passed: bool,
+    #[case] expected: &str,
+    #[case] succeeds: bool,
+) {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    write_ledger(dir.path()).expect("fixtures should be written");
+    let baseline = vec![run("Deposit", true), run("Withdraw", withdraw_passed)];
+    let json = serde_json::to_string(&baseline).expect("baseline should serialize");
+    fs::write(dir.path().join("baseline.json"), json).expect("baseline should be written");
+
+    let output = theoremc_in(
+        dir.path(),
+        &[
+            "results",
+            "--baseline",
+            "baseline.json",
+            "kani.txt",
+            "ledger.theorem",
+        ],
+    )
+    .expect("theoremc should run");
+
+    assert_eq!(output.status.success(), succeeds, "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), expected);
+}
+
+#[rstest]
+#[case::missing_theorem_files(&["results", "kani.txt"], 2)]
+#[case::missing_baseline(&["results", "--baseline"], 2)]
+#[case::unreadable_baseline(&["results", "--baseline=absent.json", "kani.txt", "ledger.theorem"], 1)]
+#[case::unreadable_output(&["results", "absent.txt", "ledger.theorem"], 1)]
+fn results_rejects_invalid_invocations(#[case] args: &[&str], #[case] code: i32) {
+    let dir = tempfile::tempdir().expect("temporary directory should be created");
+    write_ledger(dir.path()).expect("fixtures should be written");
+
+    let output = theoremc_in(dir.path(), args).expect("theoremc should run");
+
+    assert_eq!(output.status.code(), Some(code), "{output:?}");
+    assert!(output.stdout.is_empty(), "{output:?}");
+}
@@ -19,6 +19,26 @@ use theoremc::schema::load_theorem_docs;
 #[case::multi_document("valid_multi.theorem")]
 #[case::lowercase_aliases("valid_lowercase.theorem")]
 #[case::vacuous_allowed("valid_vacuous.theorem")]
+#[case::type_aliases("valid_type_aliases.theorem")]
+#[case::file_defaults("valid_defaults.theorem")]
+#[case::suite("valid_suite.theorem")]
+#[case::depends_on("valid_depends_on.theorem")]
+#[case::traces("valid_traces.theorem")]
+#[case::forall_domains("valid_forall_domains.theorem")]
+#[case::forall_oneof("valid_forall_oneof.theorem")]
+#[case::forall_struct("valid_forall_struct.theorem")]
+#[case::forall_vec("valid_forall_vec.theorem")]
+#[case::forall_derived("valid_forall_derived.theorem")]
+#[case::forall_optional("valid_forall_optional.theorem")]
+#[case::shared_inputs("valid_shared_inputs.theorem")]
+#[case::assert_step("valid_assert_step.theorem")]
+#[case::expect_err_step("valid_expect_err.theorem")]
+#[case::repeat_step("valid_repeat.theorem")]
+#[case::foreach_step("valid_foreach.theorem")]
+#[case::maybe_else("valid_maybe_else.theorem")]
+#[case::step_ids("valid_step_ids.theorem")]
+#[case::positional_args("valid_positional_args.theorem")]
+#[case::expr_args("valid_expr_args.theorem")]
 fn given_a_valid_theorem_file_when_loaded_then_it_succeeds(
     #[case] fixture: &str,
 ) -> Result<(), String> {
@@ -258,6 +278,10 @@ fn given_empty_or_blank_fields_when_loaded_then_validation_fails(
     "invalid_syntax_witness_cover.theorem",
     "Witness 1: cover is not a valid Rust expression"
 )]
+#[case::unknown_variable_in_assert(
+    "invalid_unknown_variable_assert.theorem",
+    "Prove assertion 1: assert refers to unknown variable 'limit'"
+)]
 fn given_statement_or_bad_syntax_in_expr_when_loaded_then_validation_fails(
     #[case] fixture: &str,
     #[case] expected_fragment: &str,
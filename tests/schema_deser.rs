@@ -6,7 +6,7 @@
 
 use rstest::rstest;
 use test_helpers::{FixtureName, load_fixture};
-use theoremc::schema::{LetBinding, Step, load_theorem_docs};
+use theoremc::schema::{ArgValue, LetBinding, Step, Symbol, load_theorem_docs};
 
 #[rstest::fixture]
 fn fixture_loader() -> impl Fn(&str) -> std::io::Result<String> {
@@ -88,7 +88,7 @@ fn valid_minimal_has_kani_evidence(
     let kani = doc
         .evidence
         .kani
-        .as_ref()
+        .first()
         .expect("should have kani evidence");
     ensure_eq!(kani.unwind, 1);
     ensure_eq!(kani.expect, KaniExpectation::Success);
@@ -178,6 +178,149 @@ fn valid_full_has_maybe_step(
     Ok(())
 }
 
+#[rstest]
+fn valid_assert_step_keeps_inline_assertions_in_sequence(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_assert_step.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Assert(step)) = doc.do_steps.get(1) else {
+        return Err(std::io::Error::other("second step should be an assert"));
+    };
+    ensure_eq!(step.assert.expr.as_str(), "deposited.balance() == amount");
+    ensure_eq!(
+        step.assert.because.as_str(),
+        "the deposit is credited in full"
+    );
+    let Some(Step::Maybe(maybe)) = doc.do_steps.get(2) else {
+        return Err(std::io::Error::other("third step should be a maybe"));
+    };
+    ensure!(matches!(
+        maybe.maybe.do_steps.get(1),
+        Some(Step::Assert(..))
+    ));
+    Ok(())
+}
+
+#[rstest]
+fn valid_expect_err_names_the_expected_error(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_expect_err.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::ExpectErr(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be expect_err"));
+    };
+    ensure_eq!(step.expect_err.action.as_str(), "account.withdraw");
+    ensure_eq!(step.expect_err.args.len(), 2);
+    ensure_eq!(step.error.as_str(), "InsufficientFunds");
+    Ok(())
+}
+
+#[rstest]
+fn valid_repeat_keeps_its_count_and_nested_steps(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_repeat.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Repeat(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be a repeat"));
+    };
+    ensure_eq!(step.repeat.times, 3);
+    ensure!(matches!(
+        step.repeat.do_steps.as_slice(),
+        [Step::Call(..), Step::Assert(..)]
+    ));
+    Ok(())
+}
+
+#[rstest]
+fn valid_foreach_keeps_its_sequence_and_loop_variable(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_foreach.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Foreach(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be a foreach"));
+    };
+    ensure_eq!(step.foreach.source.as_str(), "deposits");
+    ensure_eq!(step.foreach.as_binding.as_str(), "deposit");
+    ensure!(matches!(
+        step.foreach.do_steps.as_slice(),
+        [Step::Call(..), Step::Assert(..)]
+    ));
+    Ok(())
+}
+
+#[rstest]
+fn valid_maybe_else_keeps_both_branches(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_maybe_else.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Maybe(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be a maybe"));
+    };
+    ensure!(matches!(step.maybe.do_steps.as_slice(), [Step::Must(..)]));
+    ensure!(matches!(step.maybe.else_steps.as_slice(), [Step::Call(..)]));
+    Ok(())
+}
+
+#[rstest]
+fn valid_step_ids_attach_prove_assertions_to_steps(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_step_ids.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let ids: Vec<Option<&str>> = doc.do_steps.iter().map(Step::id).collect();
+    ensure_eq!(ids, vec![Some("deposit1"), Some("deposit2")]);
+    let steps: Vec<Option<&str>> = doc.prove.iter().map(|p| p.step.as_deref()).collect();
+    ensure_eq!(steps, vec![Some("deposit1"), Some("deposit2"), None]);
+    Ok(())
+}
+
+#[rstest]
+fn valid_positional_args_are_named_after_signature_params(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_positional_args.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Call(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be a call"));
+    };
+    let names: Vec<&str> = step.call.args.keys().map(String::as_str).collect();
+    ensure_eq!(names, vec!["account", "amount"]);
+    ensure_eq!(
+        step.call.args.get("amount"),
+        Some(&ArgValue::Reference("amount".to_owned()))
+    );
+    Ok(())
+}
+
+#[rstest]
+fn valid_expr_args_keep_the_expression_source(
+    fixture_loader: impl Fn(&str) -> std::io::Result<String>,
+) -> std::io::Result<()> {
+    let yaml = fixture_loader("valid_expr_args.theorem")?;
+    let docs = load_theorem_docs(&yaml).expect("should parse");
+    let doc = docs.first().expect("should have one document");
+    let Some(Step::Call(step)) = doc.do_steps.first() else {
+        return Err(std::io::Error::other("first step should be a call"));
+    };
+    ensure_eq!(
+        step.call.args.get("amount"),
+        Some(&ArgValue::Expr("amount * 2".to_owned()))
+    );
+    Ok(())
+}
+
 #[rstest]
 fn valid_full_has_multiple_prove_assertions(
     fixture_loader: impl Fn(&str) -> std::io::Result<String>,
@@ -222,7 +365,7 @@ fn multi_document_has_independent_sections(
     ensure!(docs.first().is_some_and(|d| d.tags.is_empty()));
     let second_tags: Vec<&str> = docs
         .get(1)
-        .map(|d| d.tags.iter().map(String::as_str).collect())
+        .map(|d| d.tags.iter().map(Symbol::as_str).collect())
         .unwrap_or_default();
     ensure_eq!(second_tags, vec!["smoke"]);
     Ok(())
@@ -254,7 +397,7 @@ fn vacuous_allowed_with_reason(
     let yaml = fixture_loader("valid_vacuous.theorem")?;
     let docs = load_theorem_docs(&yaml).expect("should parse vacuous");
     let doc = docs.first().expect("should have one document");
-    let kani = doc.evidence.kani.as_ref().expect("should have kani");
+    let kani = doc.evidence.kani.first().expect("should have kani");
     ensure!(kani.allow_vacuous);
     ensure!(kani.vacuity_because.is_some());
     Ok(())
@@ -5,7 +5,7 @@
 //! for unknown keys in subordinate structures.
 
 use rstest::rstest;
-use test_helpers::{FixtureName, fixture_error_message};
+use test_helpers::{FixtureName, fixture_error_message, theorem};
 use theoremc::schema::{KaniExpectation, load_theorem_docs};
 
 fn require_message_contains(message: &str, expected: &str) -> Result<(), String> {
@@ -50,7 +50,14 @@ fn rejects_invalid_identifier_starting_with_digit() -> Result<(), String> {
 
 // ── KaniExpectation enum coverage ───────────────────────────────────
 
+/// An `UNREACHABLE` theorem cannot declare witnesses, so it opts into
+/// vacuity instead.
 fn make_doc_with_expect(expect: &str) -> String {
+    let vacuity = if expect == "UNREACHABLE" {
+        "    allow_vacuous: true\n    vacuity_because: nothing is reachable\n"
+    } else {
+        "Witness:\n  - cover: 'true'\n    because: always reachable\n"
+    };
     format!(
         "
 Theorem: Test
@@ -62,10 +69,7 @@ Evidence:
   kani:
     unwind: 1
     expect: {expect}
-Witness:
-  - cover: 'true'
-    because: always reachable
-"
+{vacuity}"
     )
 }
 
@@ -79,7 +83,7 @@ fn kani_expect_variant_roundtrips(#[case] yaml_value: &str, #[case] expected: Ka
     let docs = load_theorem_docs(&yaml).expect("should parse");
     let kani = docs
         .first()
-        .and_then(|d| d.evidence.kani.as_ref())
+        .and_then(|d| d.evidence.kani.first())
         .expect("should have kani evidence");
     assert_eq!(kani.expect, expected);
 }
@@ -188,22 +192,7 @@ Witness:
 #[case::snake("hnsw_smoke")]
 #[case::underscore_prefix("_internal")]
 fn doc_example_identifiers_accepted(#[case] name: &str) {
-    let yaml = format!(
-        "
-Theorem: {name}
-About: testing identifier
-Prove:
-  - assert: 'true'
-    because: trivially true
-Evidence:
-  kani:
-    unwind: 1
-    expect: SUCCESS
-Witness:
-  - cover: 'true'
-    because: always reachable
-"
-    );
+    let yaml = theorem(name, "");
     let result = load_theorem_docs(&yaml);
     assert!(result.is_ok(), "identifier '{name}' should be accepted");
 }
@@ -1,7 +1,9 @@
 //! Regression corpus tests for parser and validator fixtures.
 
+use cap_std::{ambient_authority, fs_utf8::Dir};
 use rstest::rstest;
 use test_helpers::{FIXTURES_DIR, FixtureName, load_fixture};
+use theoremc::lint::{LintCode, lint_source};
 use theoremc::schema::{SourceId, load_theorem_docs_with_source};
 
 fn fixture_source(fixture_name: &str) -> String {
@@ -22,6 +24,26 @@ fn load_from_fixture(fixture_name: &str) -> Result<(), String> {
 #[case::lower_alias_and_must("valid_aliases_and_must.theorem")]
 #[case::nested_maybe("valid_nested_maybe.theorem")]
 #[case::vacuous_policy("valid_vacuous.theorem")]
+#[case::type_aliases("valid_type_aliases.theorem")]
+#[case::file_defaults("valid_defaults.theorem")]
+#[case::suite("valid_suite.theorem")]
+#[case::depends_on("valid_depends_on.theorem")]
+#[case::traces("valid_traces.theorem")]
+#[case::forall_domains("valid_forall_domains.theorem")]
+#[case::forall_oneof("valid_forall_oneof.theorem")]
+#[case::forall_struct("valid_forall_struct.theorem")]
+#[case::forall_vec("valid_forall_vec.theorem")]
+#[case::forall_derived("valid_forall_derived.theorem")]
+#[case::forall_optional("valid_forall_optional.theorem")]
+#[case::shared_inputs("valid_shared_inputs.theorem")]
+#[case::assert_step("valid_assert_step.theorem")]
+#[case::expect_err_step("valid_expect_err.theorem")]
+#[case::repeat_step("valid_repeat.theorem")]
+#[case::foreach_step("valid_foreach.theorem")]
+#[case::maybe_else("valid_maybe_else.theorem")]
+#[case::step_ids("valid_step_ids.theorem")]
+#[case::positional_args("valid_positional_args.theorem")]
+#[case::expr_args("valid_expr_args.theorem")]
 fn valid_fixture_corpus_parses(#[case] fixture_name: &str) {
     let result = load_from_fixture(fixture_name);
     assert!(
@@ -31,6 +53,35 @@ fn valid_fixture_corpus_parses(#[case] fixture_name: &str) {
     );
 }
 
+/// Loading and the `unwind_bound` lint apply the same rule to `repeat`
+/// loops, so no fixture that loads should be told to raise its unwind.
+#[test]
+fn valid_fixtures_fit_their_unwind_bounds() {
+    let fixtures =
+        Dir::open_ambient_dir(FIXTURES_DIR, ambient_authority()).expect("fixtures should open");
+    let mut names: Vec<String> = fixtures
+        .entries()
+        .expect("fixtures should list")
+        .map(|entry| entry.expect("fixture entry should read").file_name())
+        .map(|name| name.expect("fixture names should be UTF-8"))
+        .filter(|name| name.starts_with("valid_") && name.ends_with(".theorem"))
+        .collect();
+    names.sort();
+    assert!(names.iter().any(|name| name == "valid_repeat.theorem"));
+
+    for name in names {
+        let yaml = load_fixture(FixtureName::new(&name)).expect("fixture should read");
+        let lints = lint_source(&SourceId::new(fixture_source(&name)), &yaml)
+            .expect("valid fixture should lint");
+        let unwind: Vec<String> = lints
+            .into_iter()
+            .filter(|lint| lint.code == LintCode::UnwindBound)
+            .map(|lint| lint.message)
+            .collect();
+        assert!(unwind.is_empty(), "{name}: {unwind:?}");
+    }
+}
+
 #[rstest]
 #[case::unknown_key("invalid_unknown_key.theorem")]
 #[case::nested_maybe_blank_because("invalid_nested_maybe_empty_because.theorem")]